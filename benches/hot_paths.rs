@@ -0,0 +1,145 @@
+use hexarch_example::domain::model::{
+    AuthorId, AuthorIdStrategy, AuthorName, CreateAuthorRequest, EmailAddress, FindAuthorRequest,
+};
+use hexarch_example::domain::ports::AuthorRepository;
+use hexarch_example::domain::service::AuthorService;
+use hexarch_example::inbound::http::CreateAuthorHttpRequest;
+use hexarch_example::outbound::events::LogEventPublisher;
+use hexarch_example::outbound::memory::InMemoryRepository;
+use hexarch_example::outbound::sqlite::{
+    ConnectRetryConfig, DefaultAuthorRepository, PoolConfig, establish_pool,
+};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+
+const SAMPLES: u32 = 20;
+const SAMPLE_TIME: Duration = Duration::from_millis(50);
+const AUTHORS: i64 = 1_000;
+
+/// Times `f` and prints the median in libtest's `bench:` format, so existing bench comparison
+/// tools can diff runs.
+fn bench(filter: Option<&str>, name: &str, mut f: impl FnMut()) {
+    if filter.is_some_and(|filter| !name.contains(filter)) {
+        return;
+    }
+
+    let mut iters = 1_u32;
+    let start = Instant::now();
+    f();
+    while start.elapsed() < SAMPLE_TIME / 10 && iters < 1 << 20 {
+        f();
+        iters += 1;
+    }
+    let iters = iters * 10;
+
+    let mut samples: Vec<_> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iters {
+                f();
+            }
+            start.elapsed() / iters
+        })
+        .collect();
+    samples.sort();
+    let median = samples[samples.len() / 2];
+    let spread = samples[samples.len() - 1] - samples[0];
+    println!(
+        "test {name:<48} ... bench: {:>12} ns/iter (+/- {})",
+        median.as_nanos(),
+        spread.as_nanos()
+    );
+}
+
+fn create_request(i: i64) -> CreateAuthorRequest {
+    CreateAuthorRequest::new(
+        AuthorName::new(&format!("Author {i}")).unwrap(),
+        EmailAddress::new(&format!("author{i}@example.com")).unwrap(),
+    )
+}
+
+async fn seed(repo: &impl AuthorRepository) {
+    for i in 1..=AUTHORS {
+        repo.create_author(&create_request(i)).await.unwrap();
+    }
+}
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let filter = filter.as_deref();
+
+    bench(filter, "email_address/simple", || {
+        black_box(EmailAddress::new(black_box("jrr.tolkien@example.com")).ok());
+    });
+    bench(filter, "email_address/quoted", || {
+        black_box(EmailAddress::new(black_box(r#""john doe"@example.com"#)).ok());
+    });
+    bench(filter, "email_address/idn", || {
+        black_box(EmailAddress::new(black_box("user@bücher.de")).ok());
+    });
+    bench(filter, "email_address/invalid", || {
+        black_box(EmailAddress::new(black_box("us..er@example.com")).ok());
+    });
+
+    let body = br#"{"name":"JRR Tolkien","email":"jrr.tolkien@example.com"}"#;
+    bench(filter, "dto/create_author_request", || {
+        let parsed: CreateAuthorHttpRequest = serde_json::from_slice(black_box(body)).unwrap();
+        black_box(CreateAuthorRequest::try_from(parsed).ok());
+    });
+
+    let runtime = Runtime::new().unwrap();
+    let memory = InMemoryRepository::new();
+    runtime.block_on(seed(&memory));
+    let req = FindAuthorRequest::new(AuthorId::new(AUTHORS / 2));
+    bench(filter, "repository/memory/find_author", || {
+        black_box(runtime.block_on(memory.find_author(&req)).unwrap());
+    });
+    let dynamic = AuthorService::new(
+        memory.clone(),
+        memory.clone(),
+        memory.clone(),
+        LogEventPublisher,
+        memory.clone(),
+        memory.clone(),
+        memory.clone(),
+    );
+    bench(filter, "service/dyn/find_author", || {
+        black_box(runtime.block_on(dynamic.find_author(&req)).unwrap());
+    });
+    let generic = AuthorService::new_static(
+        memory.clone(),
+        memory.clone(),
+        memory.clone(),
+        LogEventPublisher,
+        memory.clone(),
+        memory.clone(),
+        memory.clone(),
+    );
+    bench(filter, "service/static/find_author", || {
+        black_box(runtime.block_on(generic.find_author(&req)).unwrap());
+    });
+
+    let db_path =
+        std::env::temp_dir().join(format!("hexarch-example-bench-{}.db", std::process::id()));
+    let url = format!("sqlite://{}?mode=rwc", db_path.display());
+    let retry = ConnectRetryConfig::new(
+        Duration::from_millis(10),
+        Duration::from_millis(10),
+        Duration::from_secs(1),
+    );
+    let pool = runtime
+        .block_on(establish_pool(&url, &retry, &PoolConfig::default()))
+        .unwrap();
+    let sqlite = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+    runtime.block_on(seed(&sqlite));
+    bench(filter, "repository/sqlite/find_author", || {
+        black_box(runtime.block_on(sqlite.find_author(&req)).unwrap());
+    });
+    runtime.block_on(pool.close());
+    for suffix in ["", "-wal", "-shm"] {
+        let mut path = db_path.clone().into_os_string();
+        path.push(suffix);
+        let _ = std::fs::remove_file(path);
+    }
+}
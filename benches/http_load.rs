@@ -0,0 +1,111 @@
+use hexarch_example::domain::model::{AuthorName, CreateAuthorRequest, EmailAddress};
+use hexarch_example::domain::ports::AuthorRepository;
+use hexarch_example::domain::service::AuthorService;
+use hexarch_example::inbound::http::{AppState, HttpServer, HttpServerConfig};
+use hexarch_example::outbound::events::LogEventPublisher;
+use hexarch_example::outbound::memory::InMemoryRepository;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+const AUTHORS: u32 = 100;
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn percentile(sorted: &[Duration], percentile: usize) -> Duration {
+    sorted[(sorted.len() - 1) * percentile / 100]
+}
+
+/// Drives `GET /api/v1/authors/{id}` against an in-memory backed server. Tune the run with
+/// `LOAD_CONCURRENCY` and `LOAD_SECONDS`.
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let concurrency = env_or("LOAD_CONCURRENCY", 32_u32);
+    let duration = Duration::from_secs(env_or("LOAD_SECONDS", 5));
+
+    let repo = InMemoryRepository::new();
+    for i in 1..=AUTHORS {
+        let req = CreateAuthorRequest::new(
+            AuthorName::new(&format!("Author {i}"))?,
+            EmailAddress::new(&format!("author{i}@example.com"))?,
+        );
+        repo.create_author(&req).await?;
+    }
+    let service = AuthorService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        LogEventPublisher,
+        repo.clone(),
+        repo.clone(),
+        repo,
+    );
+    let server = HttpServer::builder(AppState::new(service), HttpServerConfig::new(0))
+        .build()
+        .await?;
+    let base_url = format!("http://127.0.0.1:{}", server.local_addr()?.port());
+    let (shutdown, signal) = oneshot::channel::<()>();
+    let server = tokio::spawn(server.run_until(async {
+        let _ = signal.await;
+    }));
+
+    let client = reqwest::Client::new();
+    let deadline = Instant::now() + duration;
+    let workers: Vec<_> = (0..concurrency)
+        .map(|worker| {
+            let client = client.clone();
+            let base_url = base_url.clone();
+            tokio::spawn(async move {
+                let mut latencies = Vec::new();
+                let mut errors = 0_u64;
+                let mut id = worker % AUTHORS;
+                while Instant::now() < deadline {
+                    id = id % AUTHORS + 1;
+                    let start = Instant::now();
+                    let response = client
+                        .get(format!("{base_url}/api/v1/authors/{id}"))
+                        .send()
+                        .await;
+                    match response {
+                        Ok(response) if response.status().is_success() => {
+                            let _ = response.bytes().await;
+                            latencies.push(start.elapsed());
+                        }
+                        _ => errors += 1,
+                    }
+                }
+                (latencies, errors)
+            })
+        })
+        .collect();
+
+    let mut latencies = Vec::new();
+    let mut errors = 0;
+    for worker in workers {
+        let (worker_latencies, worker_errors) = worker.await?;
+        latencies.extend(worker_latencies);
+        errors += worker_errors;
+    }
+    let _ = shutdown.send(());
+    server.await??;
+
+    latencies.sort();
+    anyhow::ensure!(!latencies.is_empty(), "no request succeeded");
+    let throughput = latencies.len() as f64 / duration.as_secs_f64();
+    println!(
+        "http_load/get_author: {} requests, {errors} errors, {throughput:.0} req/s, concurrency {concurrency}",
+        latencies.len()
+    );
+    for p in [50, 90, 99] {
+        let name = format!("http_load/get_author/p{p}");
+        println!(
+            "test {name:<48} ... bench: {:>12} ns/iter (+/- 0)",
+            percentile(&latencies, p).as_nanos()
+        );
+    }
+    Ok(())
+}
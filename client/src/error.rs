@@ -0,0 +1,143 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorCode {
+    InvalidId,
+    InvalidRequest,
+    AuthorNotFound,
+    DuplicateAuthor,
+    DuplicateEmail,
+    NothingToUpdate,
+    PreconditionFailed,
+    AuthorArchived,
+    InvalidStatusTransition,
+    MethodNotAllowed,
+    RouteNotFound,
+    AvatarNotFound,
+    AvatarTooLarge,
+    UnsupportedMediaType,
+    UnsupportedPatchFormat,
+    Unauthorized,
+    InvalidLogFilter,
+    Internal,
+    Unknown(String),
+}
+
+impl ErrorCode {
+    pub fn from_slug(slug: &str) -> Self {
+        match slug {
+            "invalid-id" => Self::InvalidId,
+            "invalid-request" => Self::InvalidRequest,
+            "author-not-found" => Self::AuthorNotFound,
+            "duplicate-author" => Self::DuplicateAuthor,
+            "duplicate-email" => Self::DuplicateEmail,
+            "nothing-to-update" => Self::NothingToUpdate,
+            "precondition-failed" => Self::PreconditionFailed,
+            "author-archived" => Self::AuthorArchived,
+            "invalid-status-transition" => Self::InvalidStatusTransition,
+            "method-not-allowed" => Self::MethodNotAllowed,
+            "route-not-found" => Self::RouteNotFound,
+            "avatar-not-found" => Self::AvatarNotFound,
+            "avatar-too-large" => Self::AvatarTooLarge,
+            "unsupported-media-type" => Self::UnsupportedMediaType,
+            "unsupported-patch-format" => Self::UnsupportedPatchFormat,
+            "unauthorized" => Self::Unauthorized,
+            "invalid-log-filter" => Self::InvalidLogFilter,
+            "internal" => Self::Internal,
+            _ => Self::Unknown(slug.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    status: StatusCode,
+    code: ErrorCode,
+    message: String,
+    fields: BTreeMap<String, String>,
+    request_id: Option<String>,
+}
+
+impl ApiError {
+    pub(crate) fn from_body(status: StatusCode, body: &[u8]) -> Self {
+        match serde_json::from_slice::<ErrorBody>(body) {
+            Ok(body) => Self {
+                status,
+                code: ErrorCode::from_slug(&body.code),
+                message: body.error,
+                fields: body.fields,
+                request_id: body.request_id,
+            },
+            Err(_) => Self {
+                status,
+                code: ErrorCode::Unknown(String::new()),
+                message: String::from_utf8_lossy(body).into_owned(),
+                fields: BTreeMap::new(),
+                request_id: None,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    #[must_use]
+    pub const fn code(&self) -> &ErrorCode {
+        &self.code
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    #[must_use]
+    pub const fn fields(&self) -> &BTreeMap<String, String> {
+        &self.fields
+    }
+
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.status, self.message)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    code: String,
+    error: String,
+    #[serde(default)]
+    fields: BTreeMap<String, String>,
+    request_id: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("{0}")]
+    Api(ApiError),
+    #[error(transparent)]
+    Transport(#[from] reqwest::Error),
+    #[error("Invalid base url: {0}")]
+    InvalidBaseUrl(String),
+}
+
+impl ClientError {
+    #[must_use]
+    pub const fn code(&self) -> Option<&ErrorCode> {
+        match self {
+            Self::Api(err) => Some(err.code()),
+            _ => None,
+        }
+    }
+}
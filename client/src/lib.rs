@@ -0,0 +1,316 @@
+mod error;
+mod models;
+mod signing;
+
+pub use crate::error::{ApiError, ClientError, ErrorCode};
+pub use crate::models::{
+    AuditEntry, Author, AuthorId, AuthorPatch, AuthorStatus, CreatedAuthor, NewAuthor,
+};
+pub use crate::signing::{
+    CLIENT_ID_HEADER, RequestSigner, SIGNATURE_HEADER, TIMESTAMP_HEADER, string_to_sign,
+};
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Method, RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MERGE_PATCH_JSON: &str = "application/merge-patch+json";
+
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    base_url: String,
+    timeout: Duration,
+    max_retries: u32,
+    retry_backoff: Duration,
+    signer: Option<RequestSigner>,
+}
+
+impl ClientBuilder {
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Signs every request, for servers that check who is calling.
+    #[must_use]
+    pub fn with_signer(mut self, signer: RequestSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    pub fn build(self) -> Result<Client, ClientError> {
+        let mut base_url = Url::parse(&self.base_url)
+            .map_err(|err| ClientError::InvalidBaseUrl(format!("{}: {err}", self.base_url)))?;
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        let http = reqwest::Client::builder().timeout(self.timeout).build()?;
+        Ok(Client {
+            http,
+            base_url,
+            max_retries: self.max_retries,
+            retry_backoff: self.retry_backoff,
+            signer: self.signer,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: Url,
+    max_retries: u32,
+    retry_backoff: Duration,
+    signer: Option<RequestSigner>,
+}
+
+impl Client {
+    pub fn builder(base_url: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            base_url: base_url.into(),
+            timeout: Duration::from_secs(30),
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(100),
+            signer: None,
+        }
+    }
+
+    pub async fn create_author(&self, author: &NewAuthor) -> Result<CreatedAuthor, ClientError> {
+        let request = self.request(Method::POST, "api/v1/authors")?.json(author);
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn find_author(&self, id: AuthorId) -> Result<Author, ClientError> {
+        let request = self.request(Method::GET, &format!("api/v1/authors/{id}"))?;
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn find_all_authors(&self) -> Result<Vec<Author>, ClientError> {
+        let request = self.request(Method::GET, "api/v1/authors")?;
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn update_author(
+        &self,
+        id: AuthorId,
+        patch: &AuthorPatch,
+    ) -> Result<Author, ClientError> {
+        let body = serde_json::to_vec(patch).expect("author patch serializes to JSON");
+        let request = self
+            .request(Method::PATCH, &format!("api/v1/authors/{id}"))?
+            .header(CONTENT_TYPE, MERGE_PATCH_JSON)
+            .body(body);
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn replace_author(
+        &self,
+        id: AuthorId,
+        author: &NewAuthor,
+    ) -> Result<Author, ClientError> {
+        let request = self
+            .request(Method::PUT, &format!("api/v1/authors/{id}"))?
+            .json(author);
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn delete_author(&self, id: AuthorId) -> Result<(), ClientError> {
+        let request = self.request(Method::DELETE, &format!("api/v1/authors/{id}"))?;
+        self.send(request).await.map(drop)
+    }
+
+    pub async fn archive_author(&self, id: AuthorId) -> Result<Author, ClientError> {
+        let request = self.request(Method::POST, &format!("api/v1/authors/{id}/archive"))?;
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn unarchive_author(&self, id: AuthorId) -> Result<Author, ClientError> {
+        let request = self.request(Method::POST, &format!("api/v1/authors/{id}/unarchive"))?;
+        decode(self.send(request).await?).await
+    }
+
+    pub async fn find_audit_log(&self, id: AuthorId) -> Result<Vec<AuditEntry>, ClientError> {
+        let request = self.request(Method::GET, &format!("api/v1/authors/{id}/audit"))?;
+        decode(self.send(request).await?).await
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, ClientError> {
+        let url = self
+            .base_url
+            .join(path)
+            .map_err(|err| ClientError::InvalidBaseUrl(format!("{}: {err}", self.base_url)))?;
+        Ok(self.http.request(method, url))
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, ClientError> {
+        let mut request = request.build()?;
+        if let Some(signer) = &self.signer {
+            sign(signer, &mut request);
+        }
+        let retries = if is_idempotent(request.method()) {
+            self.max_retries
+        } else {
+            0
+        };
+
+        let mut attempt = 0;
+        loop {
+            let Some(current) = request.try_clone().filter(|_| attempt < retries) else {
+                return check_status(self.http.execute(request).await?).await;
+            };
+            match self.http.execute(current).await {
+                Ok(response) if !is_retryable_status(response.status()) => {
+                    return check_status(response).await;
+                }
+                Err(err) if !is_retryable_error(&err) => return Err(err.into()),
+                _ => {}
+            }
+            attempt += 1;
+            tokio::time::sleep(self.backoff(attempt)).await;
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.retry_backoff.saturating_mul(factor)
+    }
+}
+
+/// Retries reuse the signature; their timestamp is still well within what servers accept.
+fn sign(signer: &RequestSigner, request: &mut reqwest::Request) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs().cast_signed());
+    let url = request.url();
+    let path_and_query = match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    };
+    let body = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .unwrap_or_default();
+    let signature = signer.sign(request.method().as_str(), &path_and_query, timestamp, body);
+    let headers = request.headers_mut();
+    for (name, value) in [
+        (CLIENT_ID_HEADER, signer.client_id().to_string()),
+        (TIMESTAMP_HEADER, timestamp.to_string()),
+        (SIGNATURE_HEADER, signature),
+    ] {
+        let value = value
+            .parse()
+            .expect("client ids, timestamps and hex signatures are valid header values");
+        headers.insert(name, value);
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+fn is_retryable_error(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout()
+}
+
+async fn check_status(response: Response) -> Result<Response, ClientError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.bytes().await?;
+    Err(ClientError::Api(ApiError::from_body(status, &body)))
+}
+
+async fn decode<T: DeserializeOwned>(response: Response) -> Result<T, ClientError> {
+    Ok(response.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AuthorId, Client, ClientError, ErrorCode, NewAuthor};
+    use axum::Router;
+    use axum::http::StatusCode;
+    use axum::routing::{get, post};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    async fn serve(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn idempotent_requests_are_retried_on_unavailable() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&attempts);
+        let router = Router::new().route(
+            "/api/v1/authors/1",
+            get(move || async move {
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return (StatusCode::SERVICE_UNAVAILABLE, String::new());
+                }
+                let body = r#"{"id":1,"name":"JRR Tolkien","email":"jrr.tolkien@example.com",
+                    "status":"active","created_at":"2026-10-15T12:00:00Z",
+                    "updated_at":"2026-10-15T12:00:00Z"}"#;
+                (StatusCode::OK, body.to_string())
+            }),
+        );
+        let client = Client::builder(serve(router).await)
+            .with_retries(2, Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        let author = client.find_author(AuthorId::Integer(1)).await.unwrap();
+        assert_eq!("JRR Tolkien", author.name);
+        assert_eq!(2, attempts.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn server_error_codes_are_typed() {
+        let router = Router::new().route(
+            "/api/v1/authors",
+            post(|| async {
+                let body = r#"{"code":"invalid-request","error":"email is not a valid email address",
+                    "fields":{"email":"is not a valid email address"},"request_id":"req-1"}"#;
+                (StatusCode::UNPROCESSABLE_ENTITY, body)
+            }),
+        );
+        let client = Client::builder(serve(router).await).build().unwrap();
+
+        let result = client
+            .create_author(&NewAuthor::new("JRR Tolkien", "not-an-email"))
+            .await;
+        let Err(ClientError::Api(err)) = result else {
+            panic!("expected an API error, but got {result:?}");
+        };
+        assert_eq!(&ErrorCode::InvalidRequest, err.code());
+        assert_eq!(
+            StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+            err.status().as_u16()
+        );
+        assert_eq!("is not a valid email address", err.fields()["email"]);
+        assert_eq!(Some("req-1"), err.request_id());
+    }
+}
@@ -0,0 +1,18 @@
+#![no_main]
+
+use hexarch_example::domain::model::AuthorId;
+use libfuzzer_sys::fuzz_target;
+
+const MAX_INPUT: usize = 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT {
+        return;
+    }
+    let Ok(raw) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(id) = raw.parse::<AuthorId>() {
+        assert_eq!(id, id.to_string().parse::<AuthorId>().unwrap());
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use hexarch_example::inbound::http::CreateAuthorHttpRequest;
+use hexarch_example::domain::model::CreateAuthorRequest;
+use libfuzzer_sys::fuzz_target;
+
+const MAX_INPUT: usize = 64 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT {
+        return;
+    }
+    if let Ok(body) = serde_json::from_slice::<CreateAuthorHttpRequest>(data) {
+        let _ = CreateAuthorRequest::try_from(body);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use hexarch_example::domain::model::EmailAddress;
+use libfuzzer_sys::fuzz_target;
+
+const MAX_INPUT: usize = 4 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT {
+        return;
+    }
+    let Ok(raw) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(email) = EmailAddress::new(raw) {
+        let normalized = email.to_string();
+        assert_eq!(email, EmailAddress::new(&normalized).unwrap());
+    }
+});
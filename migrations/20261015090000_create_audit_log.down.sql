@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS audit_log_author_id_idx;
+DROP TABLE IF EXISTS audit_log;
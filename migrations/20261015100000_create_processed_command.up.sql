@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS processed_command (
+    command_id TEXT PRIMARY KEY,
+    processed_at TEXT NOT NULL
+);
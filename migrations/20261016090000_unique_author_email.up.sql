@@ -0,0 +1,7 @@
+CREATE TEMP TABLE author_email_check (
+    email TEXT CONSTRAINT authors_must_not_share_an_email_address CHECK (email IS NULL)
+);
+INSERT INTO author_email_check SELECT email FROM author GROUP BY email HAVING count(*) > 1;
+DROP TABLE author_email_check;
+DROP INDEX IF EXISTS author_email_idx;
+CREATE UNIQUE INDEX IF NOT EXISTS author_email_idx ON author (email);
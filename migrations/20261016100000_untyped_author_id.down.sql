@@ -0,0 +1,13 @@
+CREATE TABLE author_old (
+    id INTEGER PRIMARY KEY,
+    name TEXT UNIQUE NOT NULL,
+    email TEXT NOT NULL
+);
+
+INSERT INTO author_old (id, name, email) SELECT id, name, email FROM author;
+
+DROP TABLE author;
+
+ALTER TABLE author_old RENAME TO author;
+
+CREATE UNIQUE INDEX IF NOT EXISTS author_email_idx ON author (email);
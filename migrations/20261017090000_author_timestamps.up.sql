@@ -0,0 +1,22 @@
+CREATE TABLE author_new (
+    id NOT NULL PRIMARY KEY,
+    name TEXT UNIQUE NOT NULL,
+    email TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+INSERT INTO author_new (id, name, email, created_at, updated_at)
+SELECT
+    author.id,
+    author.name,
+    author.email,
+    COALESCE((SELECT MIN(recorded_at) FROM audit_log WHERE audit_log.author_id = author.id), datetime('now')),
+    COALESCE((SELECT MAX(recorded_at) FROM audit_log WHERE audit_log.author_id = author.id), datetime('now'))
+FROM author;
+
+DROP TABLE author;
+
+ALTER TABLE author_new RENAME TO author;
+
+CREATE UNIQUE INDEX IF NOT EXISTS author_email_idx ON author (email);
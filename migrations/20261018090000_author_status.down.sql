@@ -0,0 +1 @@
+ALTER TABLE author DROP COLUMN status;
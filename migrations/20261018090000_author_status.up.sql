@@ -0,0 +1 @@
+ALTER TABLE author ADD COLUMN status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'archived'));
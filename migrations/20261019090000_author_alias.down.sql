@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS author_alias_author_id_idx;
+DROP TABLE IF EXISTS author_alias;
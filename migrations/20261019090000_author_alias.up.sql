@@ -0,0 +1,6 @@
+CREATE TABLE author_alias (
+    alias TEXT UNIQUE NOT NULL,
+    author_id NOT NULL REFERENCES author (id) ON DELETE CASCADE
+);
+
+CREATE INDEX author_alias_author_id_idx ON author_alias (author_id);
@@ -0,0 +1,4 @@
+ALTER TABLE author DROP COLUMN country;
+ALTER TABLE author DROP COLUMN website_url;
+ALTER TABLE author DROP COLUMN birth_date;
+ALTER TABLE author DROP COLUMN bio;
@@ -0,0 +1,4 @@
+ALTER TABLE author ADD COLUMN bio TEXT;
+ALTER TABLE author ADD COLUMN birth_date TEXT;
+ALTER TABLE author ADD COLUMN website_url TEXT;
+ALTER TABLE author ADD COLUMN country TEXT;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS author_genre_genre_id_idx;
+DROP TABLE IF EXISTS author_genre;
+DROP TABLE IF EXISTS genre;
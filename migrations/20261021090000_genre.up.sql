@@ -0,0 +1,12 @@
+CREATE TABLE genre (
+    id INTEGER PRIMARY KEY,
+    name TEXT UNIQUE NOT NULL COLLATE NOCASE
+);
+
+CREATE TABLE author_genre (
+    author_id NOT NULL REFERENCES author (id) ON DELETE CASCADE,
+    genre_id INTEGER NOT NULL REFERENCES genre (id),
+    PRIMARY KEY (author_id, genre_id)
+);
+
+CREATE INDEX author_genre_genre_id_idx ON author_genre (genre_id);
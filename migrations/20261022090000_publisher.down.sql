@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS contract_publisher_id_idx;
+DROP INDEX IF EXISTS contract_author_id_idx;
+DROP TABLE IF EXISTS contract;
+DROP TABLE IF EXISTS publisher;
@@ -0,0 +1,17 @@
+CREATE TABLE publisher (
+    id INTEGER PRIMARY KEY,
+    name TEXT UNIQUE NOT NULL COLLATE NOCASE
+);
+
+CREATE TABLE contract (
+    id INTEGER PRIMARY KEY,
+    author_id NOT NULL REFERENCES author (id) ON DELETE CASCADE,
+    publisher_id INTEGER NOT NULL REFERENCES publisher (id),
+    starts_on TEXT NOT NULL,
+    ends_on TEXT,
+    royalty_basis_points INTEGER NOT NULL CHECK (royalty_basis_points BETWEEN 0 AND 10000),
+    CHECK (ends_on IS NULL OR ends_on >= starts_on)
+);
+
+CREATE INDEX contract_author_id_idx ON contract (author_id, starts_on);
+CREATE INDEX contract_publisher_id_idx ON contract (publisher_id, starts_on);
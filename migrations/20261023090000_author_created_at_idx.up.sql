@@ -0,0 +1 @@
+CREATE INDEX author_created_at_idx ON author (created_at);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS author_email_verification_idx;
+ALTER TABLE author DROP COLUMN email_verification;
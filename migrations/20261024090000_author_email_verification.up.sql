@@ -0,0 +1,2 @@
+ALTER TABLE author ADD COLUMN email_verification TEXT NOT NULL DEFAULT 'pending' CHECK (email_verification IN ('pending', 'verified', 'rejected'));
+CREATE INDEX author_email_verification_idx ON author (email_verification);
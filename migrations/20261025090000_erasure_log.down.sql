@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS erasure_log_append_only;
+DROP TABLE IF EXISTS erasure_log;
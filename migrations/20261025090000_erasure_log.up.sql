@@ -0,0 +1,15 @@
+CREATE TABLE erasure_log (
+    id INTEGER PRIMARY KEY,
+    author_id INTEGER NOT NULL,
+    actor TEXT NOT NULL,
+    request_id TEXT,
+    anonymized_entries INTEGER NOT NULL,
+    erased_at TEXT NOT NULL,
+    previous_hash TEXT NOT NULL,
+    hash TEXT UNIQUE NOT NULL
+);
+
+CREATE TRIGGER erasure_log_append_only BEFORE UPDATE ON erasure_log
+BEGIN
+    SELECT RAISE(ABORT, 'erasure_log is append-only');
+END;
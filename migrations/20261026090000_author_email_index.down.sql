@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS author_email_index_idx;
+ALTER TABLE author DROP COLUMN email_domain;
+ALTER TABLE author DROP COLUMN email_index;
+CREATE UNIQUE INDEX IF NOT EXISTS author_email_idx ON author (email);
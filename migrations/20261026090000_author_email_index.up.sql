@@ -0,0 +1,5 @@
+ALTER TABLE author ADD COLUMN email_index TEXT NOT NULL DEFAULT '';
+ALTER TABLE author ADD COLUMN email_domain TEXT NOT NULL DEFAULT '';
+UPDATE author SET email_index = email, email_domain = substr(email, instr(email, '@') + 1);
+DROP INDEX IF EXISTS author_email_idx;
+CREATE UNIQUE INDEX author_email_index_idx ON author (email_index);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS admin_session_expires_at;
+DROP TABLE IF EXISTS admin_session;
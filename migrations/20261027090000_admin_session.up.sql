@@ -0,0 +1,7 @@
+CREATE TABLE admin_session (
+    id_hash TEXT PRIMARY KEY NOT NULL,
+    data TEXT NOT NULL,
+    expires_at TEXT NOT NULL
+);
+
+CREATE INDEX admin_session_expires_at ON admin_session (expires_at);
@@ -0,0 +1,8 @@
+CREATE TABLE security_event (
+    id INTEGER PRIMARY KEY,
+    kind TEXT NOT NULL,
+    client TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    expires_at TEXT,
+    recorded_at TEXT NOT NULL
+);
@@ -0,0 +1,137 @@
+use crate::domain::model::{Blob, GetBlobError};
+use crate::domain::ports::{BackupStore, BlobStorage, DynBackupStore, DynBlobStorage};
+use anyhow::Context;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const BACKUP_CONTENT_TYPE: &str = "application/vnd.sqlite3";
+const MANIFEST_KEY: &str = "backups/manifest.json";
+
+#[derive(Debug, Clone)]
+pub struct BackupScheduleConfig {
+    interval: Duration,
+    retain: usize,
+}
+
+impl BackupScheduleConfig {
+    #[must_use]
+    pub const fn new(interval: Duration, retain: usize) -> Self {
+        Self { interval, retain }
+    }
+}
+
+/// Periodically uploads database snapshots to blob storage, keeping only the newest `retain`.
+/// Uploaded keys are tracked in a manifest blob, since the storage port cannot list keys.
+pub struct BackupJob {
+    backups: Arc<dyn DynBackupStore>,
+    blobs: Arc<dyn DynBlobStorage>,
+    config: BackupScheduleConfig,
+}
+
+impl BackupJob {
+    pub fn new(
+        backups: impl BackupStore,
+        blobs: impl BlobStorage,
+        config: BackupScheduleConfig,
+    ) -> Self {
+        Self {
+            backups: Arc::new(backups),
+            blobs: Arc::new(blobs),
+            config,
+        }
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(key) => tracing::info!(key, "Uploaded database backup"),
+                Err(err) => tracing::error!("Scheduled database backup failed: {err:?}"),
+            }
+        }
+    }
+
+    /// Uploads one snapshot, prunes expired ones and returns the new snapshot's key.
+    pub async fn run_once(&self) -> anyhow::Result<String> {
+        let snapshot = self.backups.snapshot().await?;
+        let key = format!(
+            "backups/authors-{}.sqlite",
+            Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+        );
+        let blob = Blob::new(BACKUP_CONTENT_TYPE.to_string(), snapshot);
+        self.blobs.put(&key, &blob).await?;
+
+        let mut keys = self.manifest().await?;
+        keys.push(key.clone());
+        let expired: Vec<_> = keys
+            .drain(..keys.len().saturating_sub(self.config.retain))
+            .collect();
+        let manifest = Blob::new("application/json".to_string(), serde_json::to_vec(&keys)?);
+        self.blobs.put(MANIFEST_KEY, &manifest).await?;
+        for key in expired {
+            self.blobs.delete(&key).await?;
+        }
+        Ok(key)
+    }
+
+    async fn manifest(&self) -> anyhow::Result<Vec<String>> {
+        match self.blobs.get(MANIFEST_KEY).await {
+            Ok(blob) => serde_json::from_slice(blob.bytes()).context("Invalid backup manifest"),
+            Err(GetBlobError::NotFound { .. }) => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backup::{BackupJob, BackupScheduleConfig, MANIFEST_KEY};
+    use crate::domain::model::GetBlobError;
+    use crate::domain::ports::BlobStorage;
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::sqlite::{Backups, ConnectRetryConfig, PoolConfig, establish_pool};
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn scheduled_backups_keep_only_the_newest_snapshots() {
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let pool = establish_pool(&url, &retry, &PoolConfig::default())
+            .await
+            .unwrap();
+        let blobs = InMemoryRepository::new();
+        let config = BackupScheduleConfig::new(Duration::from_secs(3600), 2);
+        let job = BackupJob::new(Backups::new(pool.clone()), blobs.clone(), config);
+
+        let mut keys = Vec::new();
+        for _ in 0..3 {
+            keys.push(job.run_once().await.unwrap());
+            tokio::time::sleep(Duration::from_millis(2)).await;
+        }
+
+        let first = blobs.get(&keys[0]).await;
+        assert!(
+            matches!(first, Err(GetBlobError::NotFound { .. })),
+            "expected the oldest backup to be pruned, but got {first:?}"
+        );
+        let latest = blobs.get(&keys[2]).await.unwrap();
+        assert!(latest.bytes().starts_with(b"SQLite format 3\0"));
+        let manifest = blobs.get(MANIFEST_KEY).await.unwrap();
+        let manifest: Vec<String> = serde_json::from_slice(manifest.bytes()).unwrap();
+        assert_eq!(keys[1..], manifest[..]);
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use hexarch_example::inbound::cli;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    cli::run(&args).await
+}
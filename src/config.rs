@@ -1,19 +1,476 @@
+use crate::domain::model::{
+    AuthorIdStrategy, NamePolicy, OidcConfig, RetentionPolicy, RoleMapping,
+};
+use crate::inbound::http::{
+    AbuseGuard, Locale, NormalizeMode, RequestSigning, ResponseCache, SameSite, SecurityHeaders,
+    SecurityHeadersConfig, SessionCookieConfig,
+};
+use crate::logging::LogFormat;
+use crate::outbound::blobs::BlobBackend;
+use crate::outbound::cipher::FieldCipherKeys;
+use crate::outbound::events::EventBackend;
+use crate::outbound::replicas::ReplicaSelection;
+use crate::outbound::sqlite::{PoolConfig, WalCheckpointMode};
+use crate::secrets::{SecretBackend, Secrets, VaultConfig, connect_secret_provider};
+use crate::verification::EmailVerifierBackend;
 use anyhow::Context;
+use axum::http::HeaderValue;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqliteAutoVacuum, SqliteSynchronous};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use url::Url;
 
 #[derive(Debug)]
 pub struct Config {
     database_url: String,
+    database_retry_initial_backoff: Duration,
+    database_retry_max_backoff: Duration,
+    database_retry_max_wait: Duration,
+    database_min_connections: u32,
+    database_max_connections: u32,
+    database_acquire_timeout: Duration,
+    database_statement_cache_capacity: usize,
+    database_auto_migrate: bool,
+    database_busy_timeout: Duration,
+    database_synchronous: SqliteSynchronous,
+    database_cache_size: Option<i64>,
+    database_mmap_size: Option<u64>,
+    database_auto_vacuum: SqliteAutoVacuum,
+    database_wal_checkpoint_interval: Option<Duration>,
+    database_wal_checkpoint_mode: WalCheckpointMode,
+    database_write_queue_capacity: Option<usize>,
+    database_replica_urls: Vec<String>,
+    database_replica_selection: ReplicaSelection,
+    database_replica_max_lag: i64,
+    database_replica_health_check_interval: Duration,
+    database_shadow_url: Option<String>,
+    database_shadow_compare_reads: bool,
+    database_breaker_failure_threshold: u32,
+    database_breaker_cooldown: Duration,
+    repository_retry_max_attempts: u32,
+    repository_retry_initial_backoff: Duration,
+    repository_retry_max_backoff: Duration,
+    repository_operation_timeout: Duration,
+    repository_slow_operation_threshold: Duration,
     server_port: u16,
+    server_http2: bool,
+    server_keep_alive: bool,
+    server_keep_alive_timeout: Duration,
+    server_http2_keep_alive_interval: Option<Duration>,
+    server_http2_max_concurrent_streams: u32,
+    server_tcp_nodelay: bool,
+    server_tcp_backlog: u32,
+    server_shutdown_timeout: Duration,
+    server_request_timeout: Duration,
+    server_path_normalization: NormalizeMode,
+    server_lowercase_paths: bool,
+    cache_control_authors: HeaderValue,
+    cache_control_author: HeaderValue,
+    cache_control_audit_log: HeaderValue,
+    cache_control_avatar: HeaderValue,
+    response_cache: ResponseCache,
+    security_headers: SecurityHeadersConfig,
+    request_signing: RequestSigning,
+    abuse_guard: Option<AbuseGuard>,
+    server_tls_cert_path: Option<PathBuf>,
+    server_tls_key_path: Option<PathBuf>,
+    author_id_strategy: AuthorIdStrategy,
+    author_id_node: u16,
+    event_backend: EventBackend,
+    event_brokers: Vec<String>,
+    event_topic_prefix: String,
+    event_username: Option<String>,
+    event_password: Option<String>,
+    commands_enabled: bool,
+    commands_stream: String,
+    commands_subject: String,
+    commands_max_attempts: u32,
+    commands_redelivery_delay: Duration,
+    blob_backend: BlobBackend,
+    blob_path: PathBuf,
+    blob_bucket: Option<String>,
+    blob_endpoint: Option<String>,
+    admin_token: Option<String>,
+    oidc: Option<OidcConfig>,
+    admin_cookies: SessionCookieConfig,
+    admin_session_ttl: Duration,
+    email_encryption: Option<FieldCipherKeys>,
+    default_locale: Locale,
+    api_v1_deprecated_at: Option<DateTime<Utc>>,
+    api_v1_sunset_at: Option<DateTime<Utc>>,
+    assets_dir: PathBuf,
+    feature_flags_path: Option<PathBuf>,
+    feature_flags_check_interval: Duration,
+    log_format: LogFormat,
+    log_redact_fields: Vec<String>,
+    query_log_level: log::LevelFilter,
+    query_log_slow_threshold: Duration,
+    query_log_sample_rate: f64,
+    name_max_length: usize,
+    name_denylist: Vec<String>,
+    authors_create_on_missing: bool,
+    authors_stats_ttl: Duration,
+    book_catalog_url: Option<Url>,
+    book_catalog_timeout: Duration,
+    book_catalog_max_attempts: u32,
+    book_catalog_breaker_failure_threshold: u32,
+    book_catalog_breaker_cooldown: Duration,
+    book_catalog_cache_ttl: Duration,
+    email_verifier: EmailVerifierBackend,
+    email_verification_interval: Duration,
+    email_verification_batch_size: usize,
+    app_env: AppEnv,
+    seed_path: Option<PathBuf>,
+    backup_interval: Option<Duration>,
+    backup_retention: usize,
+    retention_interval: Option<Duration>,
+    retention_policy: RetentionPolicy,
 }
 
 impl Config {
-    pub fn from_env() -> anyhow::Result<Self> {
-        let database_url = load_env("DATABASE_URL")?;
+    /// Sensitive values may also be read from `<KEY>_FILE` or, with `SECRETS_BACKEND=vault`, from
+    /// a Vault secret whose fields are named after the variables.
+    pub async fn from_env() -> anyhow::Result<Self> {
+        let secrets = load_secrets().await?;
+        let database_url = secrets.require("DATABASE_URL").await?;
+        let database_retry_initial_backoff =
+            Duration::from_millis(load_env_or("DATABASE_RETRY_INITIAL_BACKOFF_MS", 100)?);
+        let database_retry_max_backoff =
+            Duration::from_millis(load_env_or("DATABASE_RETRY_MAX_BACKOFF_MS", 5_000)?);
+        let database_retry_max_wait =
+            Duration::from_secs(load_env_or("DATABASE_RETRY_MAX_WAIT_SECS", 30)?);
+        let database_min_connections = load_env_or("DATABASE_MIN_CONNECTIONS", 0)?;
+        let database_max_connections = load_env_or("DATABASE_MAX_CONNECTIONS", 10)?;
+        anyhow::ensure!(
+            database_max_connections > 0 && database_min_connections <= database_max_connections,
+            "DATABASE_MAX_CONNECTIONS must be positive and at least DATABASE_MIN_CONNECTIONS"
+        );
+        let database_acquire_timeout =
+            Duration::from_secs(load_env_or("DATABASE_ACQUIRE_TIMEOUT_SECS", 30)?);
+        let database_statement_cache_capacity = load_env_or(
+            "DATABASE_STATEMENT_CACHE_CAPACITY",
+            PoolConfig::DEFAULT_STATEMENT_CACHE_CAPACITY,
+        )?;
+        let database_auto_migrate = load_env_or("DATABASE_AUTO_MIGRATE", true)?;
+        let database_busy_timeout = load_env_opt("DATABASE_BUSY_TIMEOUT_MS")?
+            .map_or(PoolConfig::DEFAULT_BUSY_TIMEOUT, Duration::from_millis);
+        let database_synchronous = load_env_or("DATABASE_SYNCHRONOUS", SqliteSynchronous::Full)?;
+        let database_cache_size = load_env_opt("DATABASE_CACHE_SIZE")?;
+        let database_mmap_size = load_env_opt("DATABASE_MMAP_SIZE")?;
+        let database_auto_vacuum = load_env_or("DATABASE_AUTO_VACUUM", SqliteAutoVacuum::None)?;
+        let database_wal_checkpoint_interval =
+            load_env_opt("DATABASE_WAL_CHECKPOINT_INTERVAL_SECS")?.map(Duration::from_secs);
+        let database_wal_checkpoint_mode =
+            load_env_or("DATABASE_WAL_CHECKPOINT_MODE", WalCheckpointMode::Passive)?;
+        let database_write_queue_capacity = load_env_opt("DATABASE_WRITE_QUEUE_CAPACITY")?;
+        anyhow::ensure!(
+            database_write_queue_capacity != Some(0),
+            "DATABASE_WRITE_QUEUE_CAPACITY must be positive"
+        );
+        let database_replica_urls = secrets
+            .get("DATABASE_REPLICA_URLS")
+            .await?
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+            .collect();
+        let database_replica_selection =
+            load_env_or("DATABASE_REPLICA_SELECTION", ReplicaSelection::RoundRobin)?;
+        let database_replica_max_lag = load_env_or("DATABASE_REPLICA_MAX_LAG", 0)?;
+        anyhow::ensure!(
+            database_replica_max_lag >= 0,
+            "DATABASE_REPLICA_MAX_LAG must not be negative"
+        );
+        let database_replica_health_check_interval =
+            Duration::from_secs(load_env_or("DATABASE_REPLICA_HEALTH_CHECK_SECS", 5)?);
+        let database_shadow_url = secrets.get("DATABASE_SHADOW_URL").await?;
+        let database_shadow_compare_reads = load_env_or("DATABASE_SHADOW_COMPARE_READS", true)?;
+        let database_breaker_failure_threshold =
+            load_env_or("DATABASE_BREAKER_FAILURE_THRESHOLD", 5)?;
+        anyhow::ensure!(
+            database_breaker_failure_threshold > 0,
+            "DATABASE_BREAKER_FAILURE_THRESHOLD must be positive"
+        );
+        let database_breaker_cooldown =
+            Duration::from_secs(load_env_or("DATABASE_BREAKER_COOLDOWN_SECS", 30)?);
+        let repository_retry_max_attempts = load_env_or("REPOSITORY_RETRY_MAX_ATTEMPTS", 3)?;
+        anyhow::ensure!(
+            repository_retry_max_attempts > 0,
+            "REPOSITORY_RETRY_MAX_ATTEMPTS must be positive"
+        );
+        let repository_retry_initial_backoff =
+            Duration::from_millis(load_env_or("REPOSITORY_RETRY_INITIAL_BACKOFF_MS", 20)?);
+        let repository_retry_max_backoff =
+            Duration::from_millis(load_env_or("REPOSITORY_RETRY_MAX_BACKOFF_MS", 500)?);
+        let repository_operation_timeout =
+            Duration::from_millis(load_env_or("REPOSITORY_OPERATION_TIMEOUT_MS", 5_000)?);
+        let repository_slow_operation_threshold =
+            Duration::from_millis(load_env_or("REPOSITORY_SLOW_OPERATION_MS", 500)?);
         let server_port = load_env("SERVER_PORT")?;
+        let server_http2 = load_env_or("SERVER_HTTP2", true)?;
+        let server_keep_alive = load_env_or("SERVER_KEEP_ALIVE", true)?;
+        let server_keep_alive_timeout =
+            Duration::from_secs(load_env_or("SERVER_KEEP_ALIVE_TIMEOUT_SECS", 75)?);
+        let server_http2_keep_alive_interval =
+            load_env_opt("SERVER_HTTP2_KEEP_ALIVE_INTERVAL_SECS")?.map(Duration::from_secs);
+        let server_http2_max_concurrent_streams =
+            load_env_or("SERVER_HTTP2_MAX_CONCURRENT_STREAMS", 200)?;
+        let server_tcp_nodelay = load_env_or("SERVER_TCP_NODELAY", true)?;
+        let server_tcp_backlog = load_env_or("SERVER_TCP_BACKLOG", 1024)?;
+        let server_shutdown_timeout =
+            Duration::from_secs(load_env_or("SERVER_SHUTDOWN_TIMEOUT_SECS", 30)?);
+        let server_request_timeout =
+            Duration::from_secs(load_env_or("SERVER_REQUEST_TIMEOUT_SECS", 30)?);
+        let server_path_normalization =
+            load_env_or("SERVER_PATH_NORMALIZATION", NormalizeMode::Off)?;
+        let server_lowercase_paths = load_env_or("SERVER_LOWERCASE_PATHS", false)?;
+        let cache_control_authors = load_env_or(
+            "CACHE_CONTROL_AUTHORS",
+            HeaderValue::from_static("no-cache"),
+        )?;
+        let cache_control_author =
+            load_env_or("CACHE_CONTROL_AUTHOR", HeaderValue::from_static("no-cache"))?;
+        let cache_control_audit_log = load_env_or(
+            "CACHE_CONTROL_AUDIT_LOG",
+            HeaderValue::from_static("no-cache"),
+        )?;
+        let cache_control_avatar = load_env_or(
+            "CACHE_CONTROL_AVATAR",
+            HeaderValue::from_static("public, max-age=3600"),
+        )?;
+        let response_cache = match load_env_opt("RESPONSE_CACHE_TTL_SECS")? {
+            Some(ttl) => ResponseCache::new(Duration::from_secs(ttl))
+                .with_max_entries(load_env_or("RESPONSE_CACHE_MAX_ENTRIES", 1_000)?),
+            None => ResponseCache::default(),
+        };
+        let security_headers = SecurityHeadersConfig::new(
+            load_env_or(
+                "SECURITY_HSTS",
+                HeaderValue::from_static("max-age=31536000; includeSubDomains"),
+            )?,
+            load_security_headers("SECURITY_API", SecurityHeaders::api())?,
+            load_security_headers("SECURITY_ADMIN", SecurityHeaders::html())?,
+        )
+        .with_enabled(load_env_or("SECURITY_HEADERS_ENABLED", true)?);
+        let server_tls_cert_path = load_env_opt("SERVER_TLS_CERT_PATH")?;
+        let server_tls_key_path = load_env_opt("SERVER_TLS_KEY_PATH")?;
+        let author_id_strategy = load_env_or("AUTHOR_ID_STRATEGY", AuthorIdStrategy::Integer)?;
+        let author_id_node = load_env_or("AUTHOR_ID_NODE", 0)?;
+        let event_backend = load_env_or("EVENTS_BACKEND", EventBackend::Log)?;
+        let event_brokers = load_env_or("EVENTS_BROKERS", String::new())?
+            .split(',')
+            .map(str::trim)
+            .filter(|broker| !broker.is_empty())
+            .map(str::to_string)
+            .collect();
+        let event_topic_prefix = load_env_or("EVENTS_TOPIC_PREFIX", "authors".to_string())?;
+        let event_username = secrets.get("EVENTS_USERNAME").await?;
+        let event_password = secrets.get("EVENTS_PASSWORD").await?;
+        let commands_enabled = load_env_or("COMMANDS_ENABLED", false)?;
+        let commands_stream = load_env_or("COMMANDS_STREAM", "AUTHOR_COMMANDS".to_string())?;
+        let commands_subject = load_env_or("COMMANDS_SUBJECT", "authors.commands".to_string())?;
+        let commands_max_attempts = load_env_or("COMMANDS_MAX_ATTEMPTS", 5)?;
+        let commands_redelivery_delay =
+            Duration::from_millis(load_env_or("COMMANDS_REDELIVERY_DELAY_MS", 1_000)?);
+        let blob_backend = load_env_or("BLOB_STORAGE_BACKEND", BlobBackend::Local)?;
+        let blob_path = load_env_or("BLOB_STORAGE_PATH", PathBuf::from("./data/blobs"))?;
+        let blob_bucket = load_env_opt("BLOB_STORAGE_BUCKET")?;
+        let blob_endpoint = load_env_opt("BLOB_STORAGE_ENDPOINT")?;
+        let admin_token = secrets.get("ADMIN_TOKEN").await?;
+        let request_signing = load_request_signing(&secrets).await?;
+        let abuse_guard = load_abuse_guard()?;
+        let oidc = load_oidc(&secrets).await?;
+        let admin_cookies = SessionCookieConfig::new(
+            load_env_or("ADMIN_COOKIE_SECURE", true)?,
+            load_env_or("ADMIN_COOKIE_SAME_SITE", SameSite::Lax)?,
+        );
+        anyhow::ensure!(
+            admin_cookies.secure() || admin_cookies.same_site() != SameSite::None,
+            "ADMIN_COOKIE_SAME_SITE=none requires ADMIN_COOKIE_SECURE=true"
+        );
+        let admin_session_ttl =
+            Duration::from_secs(load_env_or("ADMIN_SESSION_TTL_SECS", 8 * 60 * 60)?);
+        let email_encryption = FieldCipherKeys::from_secrets(&secrets).await?;
+        let default_locale = load_env_or("DEFAULT_LOCALE", Locale::ENGLISH)?;
+        let api_v1_deprecated_at = load_env_opt("API_V1_DEPRECATED_AT")?;
+        let api_v1_sunset_at = load_env_opt("API_V1_SUNSET_AT")?;
+        let assets_dir = load_env_or("ASSETS_DIR", PathBuf::from("./assets"))?;
+        let feature_flags_path = load_env_opt("FEATURE_FLAGS_PATH")?;
+        let feature_flags_check_interval =
+            Duration::from_secs(load_env_or("FEATURE_FLAGS_CHECK_SECS", 5)?);
+        let name_max_length = load_env_or("NAME_MAX_LENGTH", NamePolicy::DEFAULT_MAX_LEN)?;
+        let name_denylist = load_env_or("NAME_DENYLIST", String::new())?
+            .split(',')
+            .map(str::trim)
+            .filter(|term| !term.is_empty())
+            .map(str::to_string)
+            .collect();
+        let authors_create_on_missing = load_env_or("AUTHORS_CREATE_ON_MISSING", false)?;
+        let authors_stats_ttl = Duration::from_secs(load_env_or("AUTHORS_STATS_TTL_SECS", 60)?);
+        let book_catalog_url = load_env_opt("BOOK_CATALOG_URL")?;
+        let book_catalog_timeout =
+            Duration::from_millis(load_env_or("BOOK_CATALOG_TIMEOUT_MS", 2_000)?);
+        let book_catalog_max_attempts = load_env_or("BOOK_CATALOG_MAX_ATTEMPTS", 3)?;
+        anyhow::ensure!(
+            book_catalog_max_attempts > 0,
+            "BOOK_CATALOG_MAX_ATTEMPTS must be positive"
+        );
+        let book_catalog_breaker_failure_threshold =
+            load_env_or("BOOK_CATALOG_BREAKER_FAILURE_THRESHOLD", 5)?;
+        anyhow::ensure!(
+            book_catalog_breaker_failure_threshold > 0,
+            "BOOK_CATALOG_BREAKER_FAILURE_THRESHOLD must be positive"
+        );
+        let book_catalog_breaker_cooldown =
+            Duration::from_secs(load_env_or("BOOK_CATALOG_BREAKER_COOLDOWN_SECS", 30)?);
+        let book_catalog_cache_ttl =
+            Duration::from_secs(load_env_or("BOOK_CATALOG_CACHE_TTL_SECS", 3_600)?);
+        let email_verifier = load_env_or("EMAIL_VERIFIER", EmailVerifierBackend::None)?;
+        let email_verification_interval =
+            Duration::from_secs(load_env_or("EMAIL_VERIFICATION_INTERVAL_SECS", 300)?);
+        let email_verification_batch_size = load_env_or("EMAIL_VERIFICATION_BATCH_SIZE", 50)?;
+        anyhow::ensure!(
+            email_verification_batch_size > 0,
+            "EMAIL_VERIFICATION_BATCH_SIZE must be positive"
+        );
+        let app_env = load_env_or("APP_ENV", AppEnv::Production)?;
+        let seed_path = load_env_opt("SEED_PATH")?;
+        let backup_interval = load_env_opt("BACKUP_INTERVAL_SECS")?.map(Duration::from_secs);
+        let backup_retention = load_env_or("BACKUP_RETENTION", 7)?;
+        anyhow::ensure!(backup_retention > 0, "BACKUP_RETENTION must be positive");
+        let retention_interval = load_env_opt("RETENTION_INTERVAL_SECS")?.map(Duration::from_secs);
+        let retention_days = |key| Ok::<_, anyhow::Error>(load_env_opt(key)?.map(days));
+        let retention_policy = RetentionPolicy::default()
+            .with_archived_authors(retention_days("RETENTION_ARCHIVED_AUTHORS_DAYS")?)
+            .with_audit_log(retention_days("RETENTION_AUDIT_LOG_DAYS")?)
+            .with_processed_commands(retention_days("RETENTION_PROCESSED_COMMANDS_DAYS")?);
+        let log_format = load_env_or("LOG_FORMAT", LogFormat::Text)?;
+        let log_redact_fields = load_env_or("LOG_REDACT_FIELDS", "email".to_string())?
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(str::to_string)
+            .collect();
+        let query_log_level = load_env_or("QUERY_LOG_LEVEL", log::LevelFilter::Debug)?;
+        let query_log_slow_threshold =
+            Duration::from_millis(load_env_or("QUERY_LOG_SLOW_MS", 1_000)?);
+        let query_log_sample_rate = load_env_or("QUERY_LOG_SAMPLE_RATE", 1.0)?;
+        anyhow::ensure!(
+            (0.0..=1.0).contains(&query_log_sample_rate),
+            "QUERY_LOG_SAMPLE_RATE must be between 0 and 1"
+        );
         Ok(Self {
             database_url,
+            database_retry_initial_backoff,
+            database_retry_max_backoff,
+            database_retry_max_wait,
+            database_min_connections,
+            database_max_connections,
+            database_acquire_timeout,
+            database_statement_cache_capacity,
+            database_auto_migrate,
+            database_busy_timeout,
+            database_synchronous,
+            database_cache_size,
+            database_mmap_size,
+            database_auto_vacuum,
+            database_wal_checkpoint_interval,
+            database_wal_checkpoint_mode,
+            database_write_queue_capacity,
+            database_replica_urls,
+            database_replica_selection,
+            database_replica_max_lag,
+            database_replica_health_check_interval,
+            database_shadow_url,
+            database_shadow_compare_reads,
+            database_breaker_failure_threshold,
+            database_breaker_cooldown,
+            repository_retry_max_attempts,
+            repository_retry_initial_backoff,
+            repository_retry_max_backoff,
+            repository_operation_timeout,
+            repository_slow_operation_threshold,
             server_port,
+            server_http2,
+            server_keep_alive,
+            server_keep_alive_timeout,
+            server_http2_keep_alive_interval,
+            server_http2_max_concurrent_streams,
+            server_tcp_nodelay,
+            server_tcp_backlog,
+            server_shutdown_timeout,
+            server_request_timeout,
+            server_path_normalization,
+            server_lowercase_paths,
+            cache_control_authors,
+            cache_control_author,
+            cache_control_audit_log,
+            cache_control_avatar,
+            response_cache,
+            security_headers,
+            request_signing,
+            abuse_guard,
+            server_tls_cert_path,
+            server_tls_key_path,
+            author_id_strategy,
+            author_id_node,
+            event_backend,
+            event_brokers,
+            event_topic_prefix,
+            event_username,
+            event_password,
+            commands_enabled,
+            commands_stream,
+            commands_subject,
+            commands_max_attempts,
+            commands_redelivery_delay,
+            blob_backend,
+            blob_path,
+            blob_bucket,
+            blob_endpoint,
+            admin_token,
+            oidc,
+            admin_cookies,
+            admin_session_ttl,
+            email_encryption,
+            default_locale,
+            api_v1_deprecated_at,
+            api_v1_sunset_at,
+            assets_dir,
+            feature_flags_path,
+            feature_flags_check_interval,
+            log_format,
+            log_redact_fields,
+            query_log_level,
+            query_log_slow_threshold,
+            query_log_sample_rate,
+            name_max_length,
+            name_denylist,
+            authors_create_on_missing,
+            authors_stats_ttl,
+            book_catalog_url,
+            book_catalog_timeout,
+            book_catalog_max_attempts,
+            book_catalog_breaker_failure_threshold,
+            book_catalog_breaker_cooldown,
+            book_catalog_cache_ttl,
+            email_verifier,
+            email_verification_interval,
+            email_verification_batch_size,
+            app_env,
+            seed_path,
+            backup_interval,
+            backup_retention,
+            retention_interval,
+            retention_policy,
         })
     }
 
@@ -22,10 +479,678 @@ impl Config {
         &self.database_url
     }
 
+    #[must_use]
+    pub const fn database_retry_initial_backoff(&self) -> Duration {
+        self.database_retry_initial_backoff
+    }
+
+    #[must_use]
+    pub const fn database_retry_max_backoff(&self) -> Duration {
+        self.database_retry_max_backoff
+    }
+
+    #[must_use]
+    pub const fn database_retry_max_wait(&self) -> Duration {
+        self.database_retry_max_wait
+    }
+
+    #[must_use]
+    pub const fn database_min_connections(&self) -> u32 {
+        self.database_min_connections
+    }
+
+    #[must_use]
+    pub const fn database_max_connections(&self) -> u32 {
+        self.database_max_connections
+    }
+
+    #[must_use]
+    pub const fn database_acquire_timeout(&self) -> Duration {
+        self.database_acquire_timeout
+    }
+
+    #[must_use]
+    pub const fn database_statement_cache_capacity(&self) -> usize {
+        self.database_statement_cache_capacity
+    }
+
+    #[must_use]
+    pub const fn database_auto_migrate(&self) -> bool {
+        self.database_auto_migrate
+    }
+
+    #[must_use]
+    pub const fn database_busy_timeout(&self) -> Duration {
+        self.database_busy_timeout
+    }
+
+    #[must_use]
+    pub const fn database_synchronous(&self) -> SqliteSynchronous {
+        self.database_synchronous
+    }
+
+    #[must_use]
+    pub const fn database_cache_size(&self) -> Option<i64> {
+        self.database_cache_size
+    }
+
+    #[must_use]
+    pub const fn database_mmap_size(&self) -> Option<u64> {
+        self.database_mmap_size
+    }
+
+    #[must_use]
+    pub const fn database_auto_vacuum(&self) -> SqliteAutoVacuum {
+        self.database_auto_vacuum
+    }
+
+    #[must_use]
+    pub const fn database_wal_checkpoint_interval(&self) -> Option<Duration> {
+        self.database_wal_checkpoint_interval
+    }
+
+    #[must_use]
+    pub const fn database_wal_checkpoint_mode(&self) -> WalCheckpointMode {
+        self.database_wal_checkpoint_mode
+    }
+
+    /// When set, writes to the primary take turns through a queue of this many waiting writes.
+    #[must_use]
+    pub const fn database_write_queue_capacity(&self) -> Option<usize> {
+        self.database_write_queue_capacity
+    }
+
+    #[must_use]
+    pub fn database_replica_urls(&self) -> &[String] {
+        &self.database_replica_urls
+    }
+
+    #[must_use]
+    pub const fn database_replica_selection(&self) -> ReplicaSelection {
+        self.database_replica_selection
+    }
+
+    #[must_use]
+    pub const fn database_replica_max_lag(&self) -> i64 {
+        self.database_replica_max_lag
+    }
+
+    #[must_use]
+    pub const fn database_replica_health_check_interval(&self) -> Duration {
+        self.database_replica_health_check_interval
+    }
+
+    /// When set, author writes are mirrored to this database and reads are compared against it.
+    #[must_use]
+    pub fn database_shadow_url(&self) -> Option<&str> {
+        self.database_shadow_url.as_deref()
+    }
+
+    #[must_use]
+    pub const fn database_shadow_compare_reads(&self) -> bool {
+        self.database_shadow_compare_reads
+    }
+
+    #[must_use]
+    pub const fn database_breaker_failure_threshold(&self) -> u32 {
+        self.database_breaker_failure_threshold
+    }
+
+    #[must_use]
+    pub const fn database_breaker_cooldown(&self) -> Duration {
+        self.database_breaker_cooldown
+    }
+
+    #[must_use]
+    pub const fn repository_retry_max_attempts(&self) -> u32 {
+        self.repository_retry_max_attempts
+    }
+
+    #[must_use]
+    pub const fn repository_retry_initial_backoff(&self) -> Duration {
+        self.repository_retry_initial_backoff
+    }
+
+    #[must_use]
+    pub const fn repository_retry_max_backoff(&self) -> Duration {
+        self.repository_retry_max_backoff
+    }
+
+    #[must_use]
+    pub const fn repository_operation_timeout(&self) -> Duration {
+        self.repository_operation_timeout
+    }
+
+    #[must_use]
+    pub const fn repository_slow_operation_threshold(&self) -> Duration {
+        self.repository_slow_operation_threshold
+    }
+
     #[must_use]
     pub const fn server_port(&self) -> u16 {
         self.server_port
     }
+
+    #[must_use]
+    pub const fn server_http2(&self) -> bool {
+        self.server_http2
+    }
+
+    #[must_use]
+    pub const fn server_keep_alive(&self) -> bool {
+        self.server_keep_alive
+    }
+
+    #[must_use]
+    pub const fn server_keep_alive_timeout(&self) -> Duration {
+        self.server_keep_alive_timeout
+    }
+
+    #[must_use]
+    pub const fn server_http2_keep_alive_interval(&self) -> Option<Duration> {
+        self.server_http2_keep_alive_interval
+    }
+
+    #[must_use]
+    pub const fn server_http2_max_concurrent_streams(&self) -> u32 {
+        self.server_http2_max_concurrent_streams
+    }
+
+    #[must_use]
+    pub const fn server_tcp_nodelay(&self) -> bool {
+        self.server_tcp_nodelay
+    }
+
+    #[must_use]
+    pub const fn server_tcp_backlog(&self) -> u32 {
+        self.server_tcp_backlog
+    }
+
+    #[must_use]
+    pub const fn server_shutdown_timeout(&self) -> Duration {
+        self.server_shutdown_timeout
+    }
+
+    #[must_use]
+    pub const fn server_request_timeout(&self) -> Duration {
+        self.server_request_timeout
+    }
+
+    #[must_use]
+    pub const fn server_path_normalization(&self) -> NormalizeMode {
+        self.server_path_normalization
+    }
+
+    #[must_use]
+    pub const fn server_lowercase_paths(&self) -> bool {
+        self.server_lowercase_paths
+    }
+
+    #[must_use]
+    pub const fn cache_control_authors(&self) -> &HeaderValue {
+        &self.cache_control_authors
+    }
+
+    #[must_use]
+    pub const fn cache_control_author(&self) -> &HeaderValue {
+        &self.cache_control_author
+    }
+
+    #[must_use]
+    pub const fn cache_control_audit_log(&self) -> &HeaderValue {
+        &self.cache_control_audit_log
+    }
+
+    #[must_use]
+    pub const fn cache_control_avatar(&self) -> &HeaderValue {
+        &self.cache_control_avatar
+    }
+
+    /// Disabled unless `RESPONSE_CACHE_TTL_SECS` is set.
+    #[must_use]
+    pub const fn response_cache(&self) -> &ResponseCache {
+        &self.response_cache
+    }
+
+    #[must_use]
+    pub const fn security_headers(&self) -> &SecurityHeadersConfig {
+        &self.security_headers
+    }
+
+    #[must_use]
+    pub const fn request_signing(&self) -> &RequestSigning {
+        &self.request_signing
+    }
+
+    #[must_use]
+    pub const fn abuse_guard(&self) -> Option<&AbuseGuard> {
+        self.abuse_guard.as_ref()
+    }
+
+    #[must_use]
+    pub fn server_tls_cert_path(&self) -> Option<&Path> {
+        self.server_tls_cert_path.as_deref()
+    }
+
+    #[must_use]
+    pub fn server_tls_key_path(&self) -> Option<&Path> {
+        self.server_tls_key_path.as_deref()
+    }
+
+    #[must_use]
+    pub const fn author_id_strategy(&self) -> AuthorIdStrategy {
+        self.author_id_strategy
+    }
+
+    /// Tells apart the replicas handing out Snowflake ids; each needs its own, below 1024.
+    #[must_use]
+    pub const fn author_id_node(&self) -> u16 {
+        self.author_id_node
+    }
+
+    #[must_use]
+    pub const fn event_backend(&self) -> EventBackend {
+        self.event_backend
+    }
+
+    #[must_use]
+    pub fn event_brokers(&self) -> &[String] {
+        &self.event_brokers
+    }
+
+    #[must_use]
+    pub fn event_topic_prefix(&self) -> &str {
+        &self.event_topic_prefix
+    }
+
+    #[must_use]
+    pub fn event_username(&self) -> Option<&str> {
+        self.event_username.as_deref()
+    }
+
+    #[must_use]
+    pub fn event_password(&self) -> Option<&str> {
+        self.event_password.as_deref()
+    }
+
+    #[must_use]
+    pub const fn commands_enabled(&self) -> bool {
+        self.commands_enabled
+    }
+
+    #[must_use]
+    pub fn commands_stream(&self) -> &str {
+        &self.commands_stream
+    }
+
+    #[must_use]
+    pub fn commands_subject(&self) -> &str {
+        &self.commands_subject
+    }
+
+    #[must_use]
+    pub const fn commands_max_attempts(&self) -> u32 {
+        self.commands_max_attempts
+    }
+
+    #[must_use]
+    pub const fn commands_redelivery_delay(&self) -> Duration {
+        self.commands_redelivery_delay
+    }
+
+    #[must_use]
+    pub const fn blob_backend(&self) -> BlobBackend {
+        self.blob_backend
+    }
+
+    #[must_use]
+    pub fn blob_path(&self) -> &Path {
+        &self.blob_path
+    }
+
+    #[must_use]
+    pub fn blob_bucket(&self) -> Option<&str> {
+        self.blob_bucket.as_deref()
+    }
+
+    #[must_use]
+    pub fn blob_endpoint(&self) -> Option<&str> {
+        self.blob_endpoint.as_deref()
+    }
+
+    #[must_use]
+    pub fn admin_token(&self) -> Option<&str> {
+        self.admin_token.as_deref()
+    }
+
+    /// The provider admins sign in to the HTML pages with, if any.
+    #[must_use]
+    pub const fn oidc(&self) -> Option<&OidcConfig> {
+        self.oidc.as_ref()
+    }
+
+    #[must_use]
+    pub const fn admin_cookies(&self) -> SessionCookieConfig {
+        self.admin_cookies
+    }
+
+    /// How long admins stay signed in to the HTML pages.
+    #[must_use]
+    pub const fn admin_session_ttl(&self) -> Duration {
+        self.admin_session_ttl
+    }
+
+    /// Keys author emails are encrypted with; `None` stores them in plaintext.
+    #[must_use]
+    pub const fn email_encryption(&self) -> Option<&FieldCipherKeys> {
+        self.email_encryption.as_ref()
+    }
+
+    #[must_use]
+    pub const fn default_locale(&self) -> Locale {
+        self.default_locale
+    }
+
+    #[must_use]
+    pub const fn api_v1_deprecated_at(&self) -> Option<DateTime<Utc>> {
+        self.api_v1_deprecated_at
+    }
+
+    #[must_use]
+    pub const fn api_v1_sunset_at(&self) -> Option<DateTime<Utc>> {
+        self.api_v1_sunset_at
+    }
+
+    #[must_use]
+    pub fn assets_dir(&self) -> &Path {
+        &self.assets_dir
+    }
+
+    /// A TOML file of feature flags; without one, every flag has its default.
+    #[must_use]
+    pub fn feature_flags_path(&self) -> Option<&Path> {
+        self.feature_flags_path.as_deref()
+    }
+
+    /// How often the feature flags file is checked for changes.
+    #[must_use]
+    pub const fn feature_flags_check_interval(&self) -> Duration {
+        self.feature_flags_check_interval
+    }
+
+    #[must_use]
+    pub const fn log_format(&self) -> LogFormat {
+        self.log_format
+    }
+
+    #[must_use]
+    pub fn log_redact_fields(&self) -> &[String] {
+        &self.log_redact_fields
+    }
+
+    /// The level SQL statements are logged at. Statements slower than the threshold are logged
+    /// at `WARN` whatever this is.
+    #[must_use]
+    pub const fn query_log_level(&self) -> log::LevelFilter {
+        self.query_log_level
+    }
+
+    #[must_use]
+    pub const fn query_log_slow_threshold(&self) -> Duration {
+        self.query_log_slow_threshold
+    }
+
+    /// The share of statements that make it into the logs, slow ones aside.
+    #[must_use]
+    pub const fn query_log_sample_rate(&self) -> f64 {
+        self.query_log_sample_rate
+    }
+
+    #[must_use]
+    pub fn name_policy(&self) -> NamePolicy {
+        NamePolicy::new(self.name_max_length, self.name_denylist.clone())
+    }
+
+    #[must_use]
+    pub const fn authors_create_on_missing(&self) -> bool {
+        self.authors_create_on_missing
+    }
+
+    #[must_use]
+    pub const fn authors_stats_ttl(&self) -> Duration {
+        self.authors_stats_ttl
+    }
+
+    #[must_use]
+    pub const fn book_catalog_url(&self) -> Option<&Url> {
+        self.book_catalog_url.as_ref()
+    }
+
+    #[must_use]
+    pub const fn book_catalog_timeout(&self) -> Duration {
+        self.book_catalog_timeout
+    }
+
+    #[must_use]
+    pub const fn book_catalog_max_attempts(&self) -> u32 {
+        self.book_catalog_max_attempts
+    }
+
+    #[must_use]
+    pub const fn book_catalog_breaker_failure_threshold(&self) -> u32 {
+        self.book_catalog_breaker_failure_threshold
+    }
+
+    #[must_use]
+    pub const fn book_catalog_breaker_cooldown(&self) -> Duration {
+        self.book_catalog_breaker_cooldown
+    }
+
+    #[must_use]
+    pub const fn book_catalog_cache_ttl(&self) -> Duration {
+        self.book_catalog_cache_ttl
+    }
+
+    #[must_use]
+    pub const fn email_verifier(&self) -> EmailVerifierBackend {
+        self.email_verifier
+    }
+
+    #[must_use]
+    pub const fn email_verification_interval(&self) -> Duration {
+        self.email_verification_interval
+    }
+
+    #[must_use]
+    pub const fn email_verification_batch_size(&self) -> usize {
+        self.email_verification_batch_size
+    }
+
+    #[must_use]
+    pub const fn app_env(&self) -> AppEnv {
+        self.app_env
+    }
+
+    #[must_use]
+    pub fn seed_path(&self) -> Option<&Path> {
+        self.seed_path.as_deref()
+    }
+
+    #[must_use]
+    pub const fn backup_interval(&self) -> Option<Duration> {
+        self.backup_interval
+    }
+
+    #[must_use]
+    pub const fn backup_retention(&self) -> usize {
+        self.backup_retention
+    }
+
+    /// How often expired rows are purged; no purges are scheduled when unset.
+    #[must_use]
+    pub const fn retention_interval(&self) -> Option<Duration> {
+        self.retention_interval
+    }
+
+    #[must_use]
+    pub const fn retention_policy(&self) -> &RetentionPolicy {
+        &self.retention_policy
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEnv {
+    Development,
+    Production,
+}
+
+impl FromStr for AppEnv {
+    type Err = AppEnvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "development" => Ok(Self::Development),
+            "production" => Ok(Self::Production),
+            _ => Err(AppEnvError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid app environment, expected one of "development" or "production""#)]
+pub struct AppEnvError(String);
+
+async fn load_secrets() -> anyhow::Result<Secrets> {
+    let secrets = Secrets::from_env();
+    match load_env_or("SECRETS_BACKEND", SecretBackend::None)? {
+        SecretBackend::None => Ok(secrets),
+        SecretBackend::Vault => {
+            let token = secrets.require("VAULT_TOKEN").await?;
+            let config = VaultConfig::new(
+                load_env("VAULT_ADDR")?,
+                token,
+                load_env("VAULT_SECRET_PATH")?,
+            )
+            .with_mount(load_env_or("VAULT_MOUNT", "secret".to_string())?)
+            .with_timeout(Duration::from_millis(load_env_or(
+                "VAULT_TIMEOUT_MS",
+                5_000,
+            )?));
+            Ok(secrets.with_provider(connect_secret_provider(&config)?))
+        }
+    }
+}
+
+/// Set up by `OIDC_ISSUER_URL`; the client id, redirect URL and `ADMIN_SESSION_KEY`, 32 base64
+/// bytes, are then required.
+async fn load_oidc(secrets: &Secrets) -> anyhow::Result<Option<OidcConfig>> {
+    let Some(issuer) = load_env_opt("OIDC_ISSUER_URL")? else {
+        return Ok(None);
+    };
+    let session_key = STANDARD
+        .decode(secrets.require("ADMIN_SESSION_KEY").await?.trim())
+        .ok()
+        .and_then(|key| <[u8; 32]>::try_from(key).ok())
+        .context("ADMIN_SESSION_KEY must be 32 base64 bytes")?;
+    let roles: RoleMapping = load_env_or("OIDC_ROLE_MAPPING", RoleMapping::default())?;
+    if roles == RoleMapping::default() {
+        tracing::warn!("OIDC_ROLE_MAPPING is empty, so no one can sign in to the admin pages");
+    }
+    let config = OidcConfig::new(
+        issuer,
+        load_env("OIDC_CLIENT_ID")?,
+        load_env("OIDC_REDIRECT_URL")?,
+        session_key,
+    )?
+    .with_client_secret(secrets.get("OIDC_CLIENT_SECRET").await?)
+    .with_scopes(load_env_or(
+        "OIDC_SCOPES",
+        "openid profile email".to_string(),
+    )?)
+    .with_roles(load_env_or("OIDC_ROLE_CLAIM", "roles".to_string())?, roles)
+    .with_timeout(Duration::from_millis(load_env_or(
+        "OIDC_TIMEOUT_MS",
+        5_000,
+    )?));
+    Ok(Some(config))
+}
+
+/// `SIGNING_CLIENTS` names the callers that may sign requests; each one's shared secret is read
+/// from `SIGNING_SECRET_<CLIENT>`, upper-cased with dashes as underscores.
+async fn load_request_signing(secrets: &Secrets) -> anyhow::Result<RequestSigning> {
+    let mut clients = HashMap::new();
+    for client in load_env_or("SIGNING_CLIENTS", String::new())?
+        .split(',')
+        .map(str::trim)
+        .filter(|client| !client.is_empty())
+    {
+        let key = format!(
+            "SIGNING_SECRET_{}",
+            client.to_ascii_uppercase().replace('-', "_")
+        );
+        let secret = secrets.require(&key).await?;
+        clients.insert(client.to_string(), secret.into_bytes());
+    }
+    let required = load_env_or("SIGNING_REQUIRED", false)?;
+    anyhow::ensure!(
+        !required || !clients.is_empty(),
+        "SIGNING_REQUIRED needs at least one client in SIGNING_CLIENTS"
+    );
+    let mut signing = RequestSigning::new(clients)
+        .with_required(required)
+        .with_tolerance(Duration::from_secs(load_env_or(
+            "SIGNING_TOLERANCE_SECS",
+            300,
+        )?));
+    if let Some(max_body_bytes) = load_env_opt("SIGNING_MAX_BODY_BYTES")? {
+        signing = signing.with_max_body_bytes(max_body_bytes);
+    }
+    Ok(signing)
+}
+
+/// Abuse detection is on unless `ABUSE_DETECTION_ENABLED` is false; a limit of zero would ban
+/// every client on its first error, so limits must be positive.
+fn load_abuse_guard() -> anyhow::Result<Option<AbuseGuard>> {
+    if !load_env_or("ABUSE_DETECTION_ENABLED", true)? {
+        return Ok(None);
+    }
+    let auth_failures = load_env_or("ABUSE_AUTH_FAILURES", 10)?;
+    anyhow::ensure!(auth_failures > 0, "ABUSE_AUTH_FAILURES must be positive");
+    let validation_errors = load_env_or("ABUSE_VALIDATION_ERRORS", 50)?;
+    anyhow::ensure!(
+        validation_errors > 0,
+        "ABUSE_VALIDATION_ERRORS must be positive"
+    );
+    Ok(Some(
+        AbuseGuard::new()
+            .with_auth_failures(auth_failures)
+            .with_validation_errors(validation_errors)
+            .with_window(Duration::from_secs(load_env_or("ABUSE_WINDOW_SECS", 60)?))
+            .with_ban(Duration::from_secs(load_env_or("ABUSE_BAN_SECS", 900)?))
+            .with_trust_forwarded(load_env_or("ABUSE_TRUST_FORWARDED", false)?),
+    ))
+}
+
+/// Reads `{prefix}_CSP`, `{prefix}_REFERRER_POLICY` and `{prefix}_FRAME_OPTIONS`; an empty value
+/// leaves that header out.
+fn load_security_headers(
+    prefix: &str,
+    defaults: SecurityHeaders,
+) -> anyhow::Result<SecurityHeaders> {
+    Ok(SecurityHeaders::new(
+        load_env_or(
+            &format!("{prefix}_CSP"),
+            defaults.content_security_policy().clone(),
+        )?,
+        load_env_or(
+            &format!("{prefix}_REFERRER_POLICY"),
+            defaults.referrer_policy().clone(),
+        )?,
+        load_env_or(
+            &format!("{prefix}_FRAME_OPTIONS"),
+            defaults.frame_options().clone(),
+        )?,
+    ))
 }
 
 fn load_env<T>(key: &str) -> anyhow::Result<T>
@@ -38,3 +1163,30 @@ where
     val.parse::<T>()
         .with_context(|| format!("Failed to parse environment variable {key}"))
 }
+
+const fn days(days: u64) -> Duration {
+    Duration::from_secs(days * 24 * 60 * 60)
+}
+
+fn load_env_or<T>(key: &str, default: T) -> anyhow::Result<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(load_env_opt(key)?.unwrap_or(default))
+}
+
+fn load_env_opt<T>(key: &str) -> anyhow::Result<Option<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    match std::env::var(key) {
+        Ok(val) => val
+            .parse::<T>()
+            .map(Some)
+            .with_context(|| format!("Failed to parse environment variable {key}")),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("Failed to load environment variable {key}")),
+    }
+}
@@ -0,0 +1,621 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AttachGenreError, ChangeAuthorStatusError, CreateAuthorError,
+    CreateContractError, CreateGenreError, CreatePublisherError, DeleteAuthorError,
+    DeleteContractError, DeleteGenreError, DeletePublisherError, DetachGenreError,
+    FindAllAuthorsError, FindAllGenresError, FindAllPublishersError, FindAuditLogError,
+    FindAuthorByEmailError, FindAuthorError, FindAvatarError, FindOperationError,
+    FindPublisherError, ImportAuthorsError, NamePolicyError, PurgeAuthorError,
+    RemoveAuthorAliasError, ReplaceAuthorError, StartOperationError, TimedOutError,
+    UnavailableError, UpdateAuthorError, UploadAvatarError, UpsertAuthorError,
+};
+use std::fmt::Display;
+use thiserror::Error;
+
+/// The shared shape of every operation's error. Each operation keeps its own typed error, so
+/// callers match on the outcomes it can have; converting into this kernel is how inbound adapters
+/// turn any of them into a response without knowing the operation.
+#[derive(Error, Debug)]
+pub enum RepositoryError {
+    #[error("{0}")]
+    NotFound(ErrorDetail),
+    #[error("{0}")]
+    Conflict(ErrorDetail),
+    /// The resource changed since the version the caller based its request on.
+    #[error("{0}")]
+    PreconditionFailed(ErrorDetail),
+    #[error("{0}")]
+    Invalid(ErrorDetail),
+    #[error(transparent)]
+    Unavailable(UnavailableError),
+    #[error(transparent)]
+    Timeout(TimedOutError),
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for RepositoryError {
+    /// Recognises refusals and timeouts reported anywhere in the chain, as decorators wrap them in
+    /// the operation's `Other` variant.
+    fn from(err: anyhow::Error) -> Self {
+        if let Some(unavailable) = err.chain().find_map(|err| err.downcast_ref()) {
+            return Self::Unavailable(*unavailable);
+        }
+        if let Some(timed_out) = err.chain().find_map(|err| err.downcast_ref()) {
+            return Self::Timeout(*timed_out);
+        }
+        Self::Other(err)
+    }
+}
+
+/// Names what went wrong independently of how it is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    AuthorNotFound,
+    AuthorEmailNotFound,
+    AuthorNameTaken,
+    AuthorEmailTaken,
+    AuthorIdTaken,
+    InvalidAuthorName,
+    NothingToUpdate,
+    AuthorModified,
+    AuthorArchived,
+    InvalidStatusTransition,
+    AvatarNotFound,
+    AliasTaken,
+    AliasNotFound,
+    InvalidAlias,
+    GenreNameTaken,
+    GenreNotFound,
+    GenreInUse,
+    AuthorGenreNotFound,
+    PublisherNameTaken,
+    PublisherNotFound,
+    PublisherHasContracts,
+    ContractOverlaps,
+    ContractNotFound,
+    OperationNotFound,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct ErrorDetail {
+    code: ErrorCode,
+    message: String,
+    args: Vec<(&'static str, String)>,
+    field: Option<(&'static str, String)>,
+}
+
+impl ErrorDetail {
+    pub fn new(code: ErrorCode, message: impl Display) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+            args: Vec::new(),
+            field: None,
+        }
+    }
+
+    /// Values a translated message may interpolate.
+    #[must_use]
+    pub fn arg(mut self, name: &'static str, value: impl Display) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    /// Attributes the error to one request field.
+    #[must_use]
+    pub fn field(mut self, name: &'static str, message: impl Display) -> Self {
+        self.field = Some((name, message.to_string()));
+        self
+    }
+
+    pub const fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn args(&self) -> &[(&'static str, String)] {
+        &self.args
+    }
+
+    pub fn field_error(&self) -> Option<(&'static str, &str)> {
+        self.field
+            .as_ref()
+            .map(|(name, message)| (*name, message.as_str()))
+    }
+}
+
+impl From<NamePolicyError> for RepositoryError {
+    fn from(err: NamePolicyError) -> Self {
+        let summary = err.summary();
+        Self::Invalid(
+            ErrorDetail::new(
+                ErrorCode::InvalidAuthorName,
+                format!("author name {summary}"),
+            )
+            .field("name", summary),
+        )
+    }
+}
+
+impl From<CreateAuthorError> for RepositoryError {
+    fn from(err: CreateAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            CreateAuthorError::Duplicate { name } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorNameTaken, message).arg("name", name),
+            ),
+            CreateAuthorError::DuplicateEmail { email } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorEmailTaken, message).arg("email", email),
+            ),
+            CreateAuthorError::IdTaken { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorIdTaken, message).arg("id", id))
+            }
+            CreateAuthorError::InvalidName(err) => err.into(),
+            CreateAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAuthorError> for RepositoryError {
+    fn from(err: FindAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            FindAuthorError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            FindAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAuthorByEmailError> for RepositoryError {
+    fn from(err: FindAuthorByEmailError) -> Self {
+        let message = err.to_string();
+        match err {
+            FindAuthorByEmailError::NotFound { email } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::AuthorEmailNotFound, message).arg("email", email),
+            ),
+            FindAuthorByEmailError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAllAuthorsError> for RepositoryError {
+    fn from(err: FindAllAuthorsError) -> Self {
+        err.0.into()
+    }
+}
+
+impl From<ImportAuthorsError> for RepositoryError {
+    fn from(err: ImportAuthorsError) -> Self {
+        err.0.into()
+    }
+}
+
+impl From<FindOperationError> for RepositoryError {
+    fn from(err: FindOperationError) -> Self {
+        let message = err.to_string();
+        match err {
+            FindOperationError::NotFound { id } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::OperationNotFound, message).arg("id", id),
+            ),
+            FindOperationError::Other(err) => err.into(),
+        }
+    }
+}
+
+/// An operation that cannot be started for lack of a store is a deployment fault, not something
+/// the caller can fix.
+impl From<StartOperationError> for RepositoryError {
+    fn from(err: StartOperationError) -> Self {
+        match err {
+            StartOperationError::Disabled => Self::Other(anyhow::Error::new(err)),
+            StartOperationError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<UpdateAuthorError> for RepositoryError {
+    fn from(err: UpdateAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            UpdateAuthorError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            UpdateAuthorError::DuplicateEmail { email } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorEmailTaken, message).arg("email", email),
+            ),
+            UpdateAuthorError::NothingToUpdate { id } => {
+                Self::Invalid(ErrorDetail::new(ErrorCode::NothingToUpdate, message).arg("id", id))
+            }
+            UpdateAuthorError::PreconditionFailed { id } => Self::PreconditionFailed(
+                ErrorDetail::new(ErrorCode::AuthorModified, message).arg("id", id),
+            ),
+            UpdateAuthorError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            UpdateAuthorError::InvalidName(err) => err.into(),
+            UpdateAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<ReplaceAuthorError> for RepositoryError {
+    fn from(err: ReplaceAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            ReplaceAuthorError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            ReplaceAuthorError::Duplicate { name } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorNameTaken, message).arg("name", name),
+            ),
+            ReplaceAuthorError::DuplicateEmail { email } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorEmailTaken, message).arg("email", email),
+            ),
+            ReplaceAuthorError::PreconditionFailed { id } => Self::PreconditionFailed(
+                ErrorDetail::new(ErrorCode::AuthorModified, message).arg("id", id),
+            ),
+            ReplaceAuthorError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            ReplaceAuthorError::InvalidName(err) => err.into(),
+            ReplaceAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<UpsertAuthorError> for RepositoryError {
+    fn from(err: UpsertAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            UpsertAuthorError::Duplicate { name } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::AuthorNameTaken, message).arg("name", name),
+            ),
+            UpsertAuthorError::IdTaken { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorIdTaken, message).arg("id", id))
+            }
+            UpsertAuthorError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            UpsertAuthorError::InvalidName(err) => err.into(),
+            UpsertAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<ChangeAuthorStatusError> for RepositoryError {
+    fn from(err: ChangeAuthorStatusError) -> Self {
+        let message = err.to_string();
+        match err {
+            ChangeAuthorStatusError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            ChangeAuthorStatusError::InvalidTransition(_) => Self::Conflict(ErrorDetail::new(
+                ErrorCode::InvalidStatusTransition,
+                message,
+            )),
+            ChangeAuthorStatusError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<DeleteAuthorError> for RepositoryError {
+    fn from(err: DeleteAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            DeleteAuthorError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            DeleteAuthorError::PreconditionFailed { id } => Self::PreconditionFailed(
+                ErrorDetail::new(ErrorCode::AuthorModified, message).arg("id", id),
+            ),
+            DeleteAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<PurgeAuthorError> for RepositoryError {
+    fn from(err: PurgeAuthorError) -> Self {
+        let message = err.to_string();
+        match err {
+            PurgeAuthorError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            PurgeAuthorError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAuditLogError> for RepositoryError {
+    fn from(err: FindAuditLogError) -> Self {
+        err.0.into()
+    }
+}
+
+impl From<UploadAvatarError> for RepositoryError {
+    fn from(err: UploadAvatarError) -> Self {
+        let message = err.to_string();
+        match err {
+            UploadAvatarError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            UploadAvatarError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            UploadAvatarError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAvatarError> for RepositoryError {
+    fn from(err: FindAvatarError) -> Self {
+        let message = err.to_string();
+        match err {
+            FindAvatarError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AvatarNotFound, message).arg("id", id))
+            }
+            FindAvatarError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<AddAuthorAliasError> for RepositoryError {
+    fn from(err: AddAuthorAliasError) -> Self {
+        let message = err.to_string();
+        match err {
+            AddAuthorAliasError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            AddAuthorAliasError::DuplicateAlias { alias } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AliasTaken, message).arg("alias", alias))
+            }
+            AddAuthorAliasError::InvalidName(err) => {
+                let summary = err.summary();
+                Self::Invalid(
+                    ErrorDetail::new(ErrorCode::InvalidAlias, format!("alias {summary}"))
+                        .field("alias", summary),
+                )
+            }
+            AddAuthorAliasError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            AddAuthorAliasError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<RemoveAuthorAliasError> for RepositoryError {
+    fn from(err: RemoveAuthorAliasError) -> Self {
+        let message = err.to_string();
+        match err {
+            RemoveAuthorAliasError::NotFound { id, alias } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::AliasNotFound, message)
+                    .arg("id", id)
+                    .arg("alias", alias),
+            ),
+            RemoveAuthorAliasError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<CreateGenreError> for RepositoryError {
+    fn from(err: CreateGenreError) -> Self {
+        let message = err.to_string();
+        match err {
+            CreateGenreError::Duplicate { name } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::GenreNameTaken, message).arg("name", name),
+            ),
+            CreateGenreError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAllGenresError> for RepositoryError {
+    fn from(err: FindAllGenresError) -> Self {
+        err.0.into()
+    }
+}
+
+impl From<DeleteGenreError> for RepositoryError {
+    fn from(err: DeleteGenreError) -> Self {
+        let message = err.to_string();
+        match err {
+            DeleteGenreError::NotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::GenreNotFound, message).arg("id", id))
+            }
+            DeleteGenreError::InUse { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::GenreInUse, message).arg("id", id))
+            }
+            DeleteGenreError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<AttachGenreError> for RepositoryError {
+    fn from(err: AttachGenreError) -> Self {
+        let message = err.to_string();
+        match err {
+            AttachGenreError::AuthorNotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            AttachGenreError::GenreNotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::GenreNotFound, message).arg("id", id))
+            }
+            AttachGenreError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            AttachGenreError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<DetachGenreError> for RepositoryError {
+    fn from(err: DetachGenreError) -> Self {
+        let message = err.to_string();
+        match err {
+            DetachGenreError::NotFound {
+                author_id,
+                genre_id,
+            } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::AuthorGenreNotFound, message)
+                    .arg("author_id", author_id)
+                    .arg("genre_id", genre_id),
+            ),
+            DetachGenreError::Archived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            DetachGenreError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<CreatePublisherError> for RepositoryError {
+    fn from(err: CreatePublisherError) -> Self {
+        let message = err.to_string();
+        match err {
+            CreatePublisherError::Duplicate { name } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::PublisherNameTaken, message).arg("name", name),
+            ),
+            CreatePublisherError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindPublisherError> for RepositoryError {
+    fn from(err: FindPublisherError) -> Self {
+        let message = err.to_string();
+        match err {
+            FindPublisherError::NotFound { id } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::PublisherNotFound, message).arg("id", id),
+            ),
+            FindPublisherError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<FindAllPublishersError> for RepositoryError {
+    fn from(err: FindAllPublishersError) -> Self {
+        err.0.into()
+    }
+}
+
+impl From<DeletePublisherError> for RepositoryError {
+    fn from(err: DeletePublisherError) -> Self {
+        let message = err.to_string();
+        match err {
+            DeletePublisherError::NotFound { id } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::PublisherNotFound, message).arg("id", id),
+            ),
+            DeletePublisherError::HasContracts { id } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::PublisherHasContracts, message).arg("id", id),
+            ),
+            DeletePublisherError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<CreateContractError> for RepositoryError {
+    fn from(err: CreateContractError) -> Self {
+        let message = err.to_string();
+        match err {
+            CreateContractError::AuthorNotFound { id } => {
+                Self::NotFound(ErrorDetail::new(ErrorCode::AuthorNotFound, message).arg("id", id))
+            }
+            CreateContractError::AuthorArchived { id } => {
+                Self::Conflict(ErrorDetail::new(ErrorCode::AuthorArchived, message).arg("id", id))
+            }
+            CreateContractError::PublisherNotFound { id } => Self::Invalid(
+                ErrorDetail::new(ErrorCode::PublisherNotFound, message)
+                    .arg("id", id)
+                    .field("publisher_id", "does not exist"),
+            ),
+            CreateContractError::Overlapping {
+                author_id,
+                publisher_id,
+            } => Self::Conflict(
+                ErrorDetail::new(ErrorCode::ContractOverlaps, message)
+                    .arg("author_id", author_id)
+                    .arg("publisher_id", publisher_id),
+            ),
+            CreateContractError::Other(err) => err.into(),
+        }
+    }
+}
+
+impl From<DeleteContractError> for RepositoryError {
+    fn from(err: DeleteContractError) -> Self {
+        let message = err.to_string();
+        match err {
+            DeleteContractError::NotFound {
+                author_id,
+                contract_id,
+            } => Self::NotFound(
+                ErrorDetail::new(ErrorCode::ContractNotFound, message)
+                    .arg("author_id", author_id)
+                    .arg("contract_id", contract_id),
+            ),
+            DeleteContractError::Other(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::error::{ErrorCode, RepositoryError};
+    use crate::domain::model::{
+        AuthorId, CreateContractError, PublisherId, TimedOutError, UnavailableError,
+        UpdateAuthorError,
+    };
+    use anyhow::Context;
+    use std::time::Duration;
+
+    #[test]
+    fn classifies_refusals_and_timeouts_anywhere_in_the_chain() {
+        let refused: anyhow::Result<()> = Err(UnavailableError::new(Duration::from_secs(1)).into());
+        let err = UpdateAuthorError::Other(refused.context("update author").unwrap_err());
+        assert!(matches!(
+            RepositoryError::from(err),
+            RepositoryError::Unavailable(_)
+        ));
+
+        let timed_out = anyhow::Error::from(TimedOutError::new("find_author"));
+        assert!(matches!(
+            RepositoryError::from(timed_out),
+            RepositoryError::Timeout(_)
+        ));
+
+        let other = anyhow::anyhow!("disk full");
+        assert!(matches!(
+            RepositoryError::from(other),
+            RepositoryError::Other(_)
+        ));
+    }
+
+    #[test]
+    fn operation_errors_keep_their_code_and_field() {
+        let err = CreateContractError::PublisherNotFound {
+            id: PublisherId::new(7),
+        };
+        let RepositoryError::Invalid(detail) = RepositoryError::from(err) else {
+            panic!("expected an invalid request");
+        };
+        assert_eq!(ErrorCode::PublisherNotFound, detail.code());
+        assert_eq!(
+            Some(("publisher_id", "does not exist")),
+            detail.field_error()
+        );
+
+        let err = UpdateAuthorError::PreconditionFailed {
+            id: AuthorId::new(1),
+        };
+        assert!(matches!(
+            RepositoryError::from(err),
+            RepositoryError::PreconditionFailed(detail) if detail.code() == ErrorCode::AuthorModified
+        ));
+    }
+}
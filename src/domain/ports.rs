@@ -0,0 +1,2670 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditEntry, Author, AuthorEvent,
+    AuthorGenreRequest, AuthorId, AuthorName, AuthorStats, AuthorStatsRequest, Blob,
+    ChangeAuthorStatusError, CipherError, CommandLogError, Contract, CreateAuthorError,
+    CreateAuthorRequest, CreateBackupError, CreateContractError, CreateContractRequest,
+    CreateGenreError, CreateGenreRequest, CreatePublisherError, CreatePublisherRequest,
+    DeleteAuthorError, DeleteAuthorRequest, DeleteBlobError, DeleteContractError,
+    DeleteContractRequest, DeleteGenreError, DeleteGenreRequest, DeletePublisherError,
+    DeletePublisherRequest, DetachGenreError, EmailAddress, EmailVerification, ErasureRecord,
+    ExternalWork, FeatureFlag, FindAllAuthorsError, FindAllGenresError, FindAllPublishersError,
+    FindAuditLogError, FindAuditLogRequest, FindAuthorByEmailError, FindAuthorByEmailRequest,
+    FindAuthorError, FindAuthorRequest, FindAuthorsByGenreRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindChangesRequest, FindExternalWorksError,
+    FindFeatureFlagsError, FindMigrationsError, FindOperationError, FindProjectedAuthorsRequest,
+    FindPublisherError, FindPublisherRequest, FindSortedAuthorsRequest, Genre, GetBlobError,
+    MigrationStatus, Operation, OperationId, ProjectedAuthor, PublishEventError, Publisher,
+    PurgeExpiredError, PutBlobError, RecordAuditError, RecordAuditRequest, RecordErasureRequest,
+    RecordSecurityEventRequest, RemoveAuthorAliasError, RemoveAuthorAliasRequest,
+    ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor, RestoreBackupError, RetentionReport,
+    SaveOperationError, SearchAuthorsRequest, SecurityEvent, SessionId, SessionStoreError,
+    SetAuthorStatusRequest, SetEmailVerificationError, SetEmailVerificationRequest, StoredSession,
+    TokenResponse, UpdateAuthorError, UpdateAuthorRequest, UpsertAuthorError, VerifyEmailError,
+};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::future::Future;
+use std::time::Duration;
+use url::Url;
+
+pub trait AuthorRepository: Send + Sync + 'static {
+    fn create_author(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> impl Future<Output = Result<Author, CreateAuthorError>> + Send;
+
+    fn find_author(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> impl Future<Output = Result<Author, FindAuthorError>> + Send;
+
+    /// Matches the address exactly, as normalized by [`EmailAddress`].
+    fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> impl Future<Output = Result<Author, FindAuthorByEmailError>> + Send;
+
+    fn find_all_authors(
+        &self,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+
+    fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+
+    fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+
+    fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> impl Future<Output = Result<Vec<ProjectedAuthor>, FindAllAuthorsError>> + Send;
+
+    fn stream_all_authors(
+        &self,
+    ) -> impl Future<Output = BoxStream<'static, Result<Author, FindAllAuthorsError>>> + Send;
+
+    fn count_authors(&self) -> impl Future<Output = Result<u64, FindAllAuthorsError>> + Send;
+
+    fn author_exists(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> impl Future<Output = Result<bool, FindAuthorError>> + Send;
+
+    fn update_author(
+        &self,
+        req: &UpdateAuthorRequest,
+    ) -> impl Future<Output = Result<Author, UpdateAuthorError>> + Send;
+
+    fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> impl Future<Output = Result<Author, ReplaceAuthorError>> + Send;
+
+    /// Inserts the author, or replaces the name and profile of the one with the same email, in
+    /// one statement. The id, status and email verification of an existing author are kept.
+    fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> impl Future<Output = Result<ReplacedAuthor, UpsertAuthorError>> + Send;
+
+    fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> impl Future<Output = Result<(), ChangeAuthorStatusError>> + Send;
+
+    fn delete_author(
+        &self,
+        req: &DeleteAuthorRequest,
+    ) -> impl Future<Output = Result<(), DeleteAuthorError>> + Send;
+
+    fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> impl Future<Output = Result<(), AddAuthorAliasError>> + Send;
+
+    fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> impl Future<Output = Result<(), RemoveAuthorAliasError>> + Send;
+
+    /// Aliases of an existing author, in alphabetical order.
+    fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> impl Future<Output = Result<Vec<AuthorName>, FindAuthorError>> + Send;
+
+    /// Authors whose name or any alias matches, in id order.
+    fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+
+    /// Computed by the store itself rather than by loading every author.
+    fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> impl Future<Output = Result<AuthorStats, FindAllAuthorsError>> + Send;
+
+    fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+
+    /// Changing an author's email address resets its verification to pending.
+    fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> impl Future<Output = Result<(), SetEmailVerificationError>> + Send;
+}
+
+/// Object-safe counterpart of [`AuthorRepository`] for storing repositories behind `dyn`, at the
+/// cost of boxing every returned future. Implemented for every repository.
+pub trait DynAuthorRepository: Send + Sync + 'static {
+    fn create_author<'a>(
+        &'a self,
+        req: &'a CreateAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, CreateAuthorError>>;
+
+    fn find_author<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, FindAuthorError>>;
+
+    fn find_author_by_email<'a>(
+        &'a self,
+        req: &'a FindAuthorByEmailRequest,
+    ) -> BoxFuture<'a, Result<Author, FindAuthorByEmailError>>;
+
+    fn find_all_authors<'a>(&'a self) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+
+    fn find_authors_by_ids<'a>(
+        &'a self,
+        req: &'a FindAuthorsByIdsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+
+    fn find_sorted_authors<'a>(
+        &'a self,
+        req: &'a FindSortedAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+
+    fn find_projected_authors<'a>(
+        &'a self,
+        req: &'a FindProjectedAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<ProjectedAuthor>, FindAllAuthorsError>>;
+
+    fn stream_all_authors<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, BoxStream<'static, Result<Author, FindAllAuthorsError>>>;
+
+    fn count_authors<'a>(&'a self) -> BoxFuture<'a, Result<u64, FindAllAuthorsError>>;
+
+    fn author_exists<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<bool, FindAuthorError>>;
+
+    fn update_author<'a>(
+        &'a self,
+        req: &'a UpdateAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, UpdateAuthorError>>;
+
+    fn upsert_author<'a>(
+        &'a self,
+        req: &'a ReplaceAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, ReplaceAuthorError>>;
+
+    fn upsert_author_by_email<'a>(
+        &'a self,
+        req: &'a CreateAuthorRequest,
+    ) -> BoxFuture<'a, Result<ReplacedAuthor, UpsertAuthorError>>;
+
+    fn set_author_status<'a>(
+        &'a self,
+        req: &'a SetAuthorStatusRequest,
+    ) -> BoxFuture<'a, Result<(), ChangeAuthorStatusError>>;
+
+    fn delete_author<'a>(
+        &'a self,
+        req: &'a DeleteAuthorRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteAuthorError>>;
+
+    fn add_author_alias<'a>(
+        &'a self,
+        req: &'a AddAuthorAliasRequest,
+    ) -> BoxFuture<'a, Result<(), AddAuthorAliasError>>;
+
+    fn remove_author_alias<'a>(
+        &'a self,
+        req: &'a RemoveAuthorAliasRequest,
+    ) -> BoxFuture<'a, Result<(), RemoveAuthorAliasError>>;
+
+    fn find_author_aliases<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuthorName>, FindAuthorError>>;
+
+    fn search_authors<'a>(
+        &'a self,
+        req: &'a SearchAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+
+    fn author_stats<'a>(
+        &'a self,
+        req: &'a AuthorStatsRequest,
+    ) -> BoxFuture<'a, Result<AuthorStats, FindAllAuthorsError>>;
+
+    fn find_authors_by_verification<'a>(
+        &'a self,
+        req: &'a FindAuthorsByVerificationRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+
+    fn set_email_verification<'a>(
+        &'a self,
+        req: &'a SetEmailVerificationRequest,
+    ) -> BoxFuture<'a, Result<(), SetEmailVerificationError>>;
+}
+
+impl<R: AuthorRepository> DynAuthorRepository for R {
+    fn create_author<'a>(
+        &'a self,
+        req: &'a CreateAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, CreateAuthorError>> {
+        Box::pin(AuthorRepository::create_author(self, req))
+    }
+
+    fn find_author<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, FindAuthorError>> {
+        Box::pin(AuthorRepository::find_author(self, req))
+    }
+
+    fn find_author_by_email<'a>(
+        &'a self,
+        req: &'a FindAuthorByEmailRequest,
+    ) -> BoxFuture<'a, Result<Author, FindAuthorByEmailError>> {
+        Box::pin(AuthorRepository::find_author_by_email(self, req))
+    }
+
+    fn find_all_authors<'a>(&'a self) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::find_all_authors(self))
+    }
+
+    fn find_authors_by_ids<'a>(
+        &'a self,
+        req: &'a FindAuthorsByIdsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::find_authors_by_ids(self, req))
+    }
+
+    fn find_sorted_authors<'a>(
+        &'a self,
+        req: &'a FindSortedAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::find_sorted_authors(self, req))
+    }
+
+    fn find_projected_authors<'a>(
+        &'a self,
+        req: &'a FindProjectedAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<ProjectedAuthor>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::find_projected_authors(self, req))
+    }
+
+    fn stream_all_authors<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, BoxStream<'static, Result<Author, FindAllAuthorsError>>> {
+        Box::pin(AuthorRepository::stream_all_authors(self))
+    }
+
+    fn count_authors<'a>(&'a self) -> BoxFuture<'a, Result<u64, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::count_authors(self))
+    }
+
+    fn author_exists<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<bool, FindAuthorError>> {
+        Box::pin(AuthorRepository::author_exists(self, req))
+    }
+
+    fn update_author<'a>(
+        &'a self,
+        req: &'a UpdateAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, UpdateAuthorError>> {
+        Box::pin(AuthorRepository::update_author(self, req))
+    }
+
+    fn upsert_author<'a>(
+        &'a self,
+        req: &'a ReplaceAuthorRequest,
+    ) -> BoxFuture<'a, Result<Author, ReplaceAuthorError>> {
+        Box::pin(AuthorRepository::upsert_author(self, req))
+    }
+
+    fn upsert_author_by_email<'a>(
+        &'a self,
+        req: &'a CreateAuthorRequest,
+    ) -> BoxFuture<'a, Result<ReplacedAuthor, UpsertAuthorError>> {
+        Box::pin(AuthorRepository::upsert_author_by_email(self, req))
+    }
+
+    fn set_author_status<'a>(
+        &'a self,
+        req: &'a SetAuthorStatusRequest,
+    ) -> BoxFuture<'a, Result<(), ChangeAuthorStatusError>> {
+        Box::pin(AuthorRepository::set_author_status(self, req))
+    }
+
+    fn delete_author<'a>(
+        &'a self,
+        req: &'a DeleteAuthorRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteAuthorError>> {
+        Box::pin(AuthorRepository::delete_author(self, req))
+    }
+
+    fn add_author_alias<'a>(
+        &'a self,
+        req: &'a AddAuthorAliasRequest,
+    ) -> BoxFuture<'a, Result<(), AddAuthorAliasError>> {
+        Box::pin(AuthorRepository::add_author_alias(self, req))
+    }
+
+    fn remove_author_alias<'a>(
+        &'a self,
+        req: &'a RemoveAuthorAliasRequest,
+    ) -> BoxFuture<'a, Result<(), RemoveAuthorAliasError>> {
+        Box::pin(AuthorRepository::remove_author_alias(self, req))
+    }
+
+    fn find_author_aliases<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuthorName>, FindAuthorError>> {
+        Box::pin(AuthorRepository::find_author_aliases(self, req))
+    }
+
+    fn search_authors<'a>(
+        &'a self,
+        req: &'a SearchAuthorsRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::search_authors(self, req))
+    }
+
+    fn author_stats<'a>(
+        &'a self,
+        req: &'a AuthorStatsRequest,
+    ) -> BoxFuture<'a, Result<AuthorStats, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::author_stats(self, req))
+    }
+
+    fn find_authors_by_verification<'a>(
+        &'a self,
+        req: &'a FindAuthorsByVerificationRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(AuthorRepository::find_authors_by_verification(self, req))
+    }
+
+    fn set_email_verification<'a>(
+        &'a self,
+        req: &'a SetEmailVerificationRequest,
+    ) -> BoxFuture<'a, Result<(), SetEmailVerificationError>> {
+        Box::pin(AuthorRepository::set_email_verification(self, req))
+    }
+}
+
+/// A type-erased [`AuthorRepository`].
+pub struct BoxedAuthorRepository(Box<dyn DynAuthorRepository>);
+
+impl BoxedAuthorRepository {
+    pub fn new(repo: impl AuthorRepository) -> Self {
+        Self(Box::new(repo))
+    }
+}
+
+impl AuthorRepository for BoxedAuthorRepository {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.0.create_author(req).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.0.find_author(req).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.0.find_author_by_email(req).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.find_all_authors().await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.find_authors_by_ids(req).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.find_sorted_authors(req).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.0.find_projected_authors(req).await
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.0.stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.0.count_authors().await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.0.author_exists(req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.0.update_author(req).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.0.upsert_author(req).await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.0.upsert_author_by_email(req).await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.0.set_author_status(req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.0.delete_author(req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.0.add_author_alias(req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.0.remove_author_alias(req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.0.find_author_aliases(req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.search_authors(req).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.0.author_stats(req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.find_authors_by_verification(req).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.0.set_email_verification(req).await
+    }
+}
+
+pub trait GenreRepository: Send + Sync + 'static {
+    fn create_genre(
+        &self,
+        req: &CreateGenreRequest,
+    ) -> impl Future<Output = Result<Genre, CreateGenreError>> + Send;
+
+    /// All genres, in alphabetical order.
+    fn find_all_genres(
+        &self,
+    ) -> impl Future<Output = Result<Vec<Genre>, FindAllGenresError>> + Send;
+
+    /// Fails with [`DeleteGenreError::InUse`] while any author still has the genre.
+    fn delete_genre(
+        &self,
+        req: &DeleteGenreRequest,
+    ) -> impl Future<Output = Result<(), DeleteGenreError>> + Send;
+
+    /// Attaching a genre the author already has succeeds.
+    fn attach_genre(
+        &self,
+        req: &AuthorGenreRequest,
+    ) -> impl Future<Output = Result<(), AttachGenreError>> + Send;
+
+    fn detach_genre(
+        &self,
+        req: &AuthorGenreRequest,
+    ) -> impl Future<Output = Result<(), DetachGenreError>> + Send;
+
+    /// Genres of an existing author, in alphabetical order.
+    fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> impl Future<Output = Result<Vec<Genre>, FindAuthorError>> + Send;
+
+    /// Authors with the genre, in id order.
+    fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> impl Future<Output = Result<Vec<Author>, FindAllAuthorsError>> + Send;
+}
+
+/// Object-safe counterpart of [`GenreRepository`], implemented for every one.
+pub trait DynGenreRepository: Send + Sync + 'static {
+    fn create_genre<'a>(
+        &'a self,
+        req: &'a CreateGenreRequest,
+    ) -> BoxFuture<'a, Result<Genre, CreateGenreError>>;
+
+    fn find_all_genres<'a>(&'a self) -> BoxFuture<'a, Result<Vec<Genre>, FindAllGenresError>>;
+
+    fn delete_genre<'a>(
+        &'a self,
+        req: &'a DeleteGenreRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteGenreError>>;
+
+    fn attach_genre<'a>(
+        &'a self,
+        req: &'a AuthorGenreRequest,
+    ) -> BoxFuture<'a, Result<(), AttachGenreError>>;
+
+    fn detach_genre<'a>(
+        &'a self,
+        req: &'a AuthorGenreRequest,
+    ) -> BoxFuture<'a, Result<(), DetachGenreError>>;
+
+    fn find_author_genres<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<Genre>, FindAuthorError>>;
+
+    fn find_authors_by_genre<'a>(
+        &'a self,
+        req: &'a FindAuthorsByGenreRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>>;
+}
+
+impl<T: GenreRepository> DynGenreRepository for T {
+    fn create_genre<'a>(
+        &'a self,
+        req: &'a CreateGenreRequest,
+    ) -> BoxFuture<'a, Result<Genre, CreateGenreError>> {
+        Box::pin(GenreRepository::create_genre(self, req))
+    }
+
+    fn find_all_genres<'a>(&'a self) -> BoxFuture<'a, Result<Vec<Genre>, FindAllGenresError>> {
+        Box::pin(GenreRepository::find_all_genres(self))
+    }
+
+    fn delete_genre<'a>(
+        &'a self,
+        req: &'a DeleteGenreRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteGenreError>> {
+        Box::pin(GenreRepository::delete_genre(self, req))
+    }
+
+    fn attach_genre<'a>(
+        &'a self,
+        req: &'a AuthorGenreRequest,
+    ) -> BoxFuture<'a, Result<(), AttachGenreError>> {
+        Box::pin(GenreRepository::attach_genre(self, req))
+    }
+
+    fn detach_genre<'a>(
+        &'a self,
+        req: &'a AuthorGenreRequest,
+    ) -> BoxFuture<'a, Result<(), DetachGenreError>> {
+        Box::pin(GenreRepository::detach_genre(self, req))
+    }
+
+    fn find_author_genres<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<Genre>, FindAuthorError>> {
+        Box::pin(GenreRepository::find_author_genres(self, req))
+    }
+
+    fn find_authors_by_genre<'a>(
+        &'a self,
+        req: &'a FindAuthorsByGenreRequest,
+    ) -> BoxFuture<'a, Result<Vec<Author>, FindAllAuthorsError>> {
+        Box::pin(GenreRepository::find_authors_by_genre(self, req))
+    }
+}
+
+impl GenreRepository for Box<dyn DynGenreRepository> {
+    async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        self.as_ref().create_genre(req).await
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        self.as_ref().find_all_genres().await
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        self.as_ref().delete_genre(req).await
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        self.as_ref().attach_genre(req).await
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        self.as_ref().detach_genre(req).await
+    }
+
+    async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        self.as_ref().find_author_genres(req).await
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.as_ref().find_authors_by_genre(req).await
+    }
+}
+
+/// Publishers and the contracts between them and authors. Invariants that span both aggregates,
+/// such as overlapping terms, are enforced by the service, not here.
+pub trait PublisherRepository: Send + Sync + 'static {
+    fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> impl Future<Output = Result<Publisher, CreatePublisherError>> + Send;
+
+    fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> impl Future<Output = Result<Publisher, FindPublisherError>> + Send;
+
+    /// All publishers, in alphabetical order.
+    fn find_all_publishers(
+        &self,
+    ) -> impl Future<Output = Result<Vec<Publisher>, FindAllPublishersError>> + Send;
+
+    /// Fails with [`DeletePublisherError::HasContracts`] while any contract references it.
+    fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> impl Future<Output = Result<(), DeletePublisherError>> + Send;
+
+    fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> impl Future<Output = Result<Contract, CreateContractError>> + Send;
+
+    /// Contracts of an existing author, ordered by start date.
+    fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> impl Future<Output = Result<Vec<Contract>, FindAuthorError>> + Send;
+
+    /// Contracts of an existing publisher, ordered by start date.
+    fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> impl Future<Output = Result<Vec<Contract>, FindPublisherError>> + Send;
+
+    fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> impl Future<Output = Result<(), DeleteContractError>> + Send;
+}
+
+/// Object-safe counterpart of [`PublisherRepository`], implemented for every one.
+pub trait DynPublisherRepository: Send + Sync + 'static {
+    fn create_publisher<'a>(
+        &'a self,
+        req: &'a CreatePublisherRequest,
+    ) -> BoxFuture<'a, Result<Publisher, CreatePublisherError>>;
+
+    fn find_publisher<'a>(
+        &'a self,
+        req: &'a FindPublisherRequest,
+    ) -> BoxFuture<'a, Result<Publisher, FindPublisherError>>;
+
+    fn find_all_publishers<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, Result<Vec<Publisher>, FindAllPublishersError>>;
+
+    fn delete_publisher<'a>(
+        &'a self,
+        req: &'a DeletePublisherRequest,
+    ) -> BoxFuture<'a, Result<(), DeletePublisherError>>;
+
+    fn create_contract<'a>(
+        &'a self,
+        req: &'a CreateContractRequest,
+    ) -> BoxFuture<'a, Result<Contract, CreateContractError>>;
+
+    fn find_author_contracts<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<Contract>, FindAuthorError>>;
+
+    fn find_publisher_contracts<'a>(
+        &'a self,
+        req: &'a FindPublisherRequest,
+    ) -> BoxFuture<'a, Result<Vec<Contract>, FindPublisherError>>;
+
+    fn delete_contract<'a>(
+        &'a self,
+        req: &'a DeleteContractRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteContractError>>;
+}
+
+impl<T: PublisherRepository> DynPublisherRepository for T {
+    fn create_publisher<'a>(
+        &'a self,
+        req: &'a CreatePublisherRequest,
+    ) -> BoxFuture<'a, Result<Publisher, CreatePublisherError>> {
+        Box::pin(PublisherRepository::create_publisher(self, req))
+    }
+
+    fn find_publisher<'a>(
+        &'a self,
+        req: &'a FindPublisherRequest,
+    ) -> BoxFuture<'a, Result<Publisher, FindPublisherError>> {
+        Box::pin(PublisherRepository::find_publisher(self, req))
+    }
+
+    fn find_all_publishers<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, Result<Vec<Publisher>, FindAllPublishersError>> {
+        Box::pin(PublisherRepository::find_all_publishers(self))
+    }
+
+    fn delete_publisher<'a>(
+        &'a self,
+        req: &'a DeletePublisherRequest,
+    ) -> BoxFuture<'a, Result<(), DeletePublisherError>> {
+        Box::pin(PublisherRepository::delete_publisher(self, req))
+    }
+
+    fn create_contract<'a>(
+        &'a self,
+        req: &'a CreateContractRequest,
+    ) -> BoxFuture<'a, Result<Contract, CreateContractError>> {
+        Box::pin(PublisherRepository::create_contract(self, req))
+    }
+
+    fn find_author_contracts<'a>(
+        &'a self,
+        req: &'a FindAuthorRequest,
+    ) -> BoxFuture<'a, Result<Vec<Contract>, FindAuthorError>> {
+        Box::pin(PublisherRepository::find_author_contracts(self, req))
+    }
+
+    fn find_publisher_contracts<'a>(
+        &'a self,
+        req: &'a FindPublisherRequest,
+    ) -> BoxFuture<'a, Result<Vec<Contract>, FindPublisherError>> {
+        Box::pin(PublisherRepository::find_publisher_contracts(self, req))
+    }
+
+    fn delete_contract<'a>(
+        &'a self,
+        req: &'a DeleteContractRequest,
+    ) -> BoxFuture<'a, Result<(), DeleteContractError>> {
+        Box::pin(PublisherRepository::delete_contract(self, req))
+    }
+}
+
+impl PublisherRepository for Box<dyn DynPublisherRepository> {
+    async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        self.as_ref().create_publisher(req).await
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        self.as_ref().find_publisher(req).await
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        self.as_ref().find_all_publishers().await
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        self.as_ref().delete_publisher(req).await
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        self.as_ref().create_contract(req).await
+    }
+
+    async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        self.as_ref().find_author_contracts(req).await
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        self.as_ref().find_publisher_contracts(req).await
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        self.as_ref().delete_contract(req).await
+    }
+}
+
+pub trait AuditRecorder: Send + Sync + 'static {
+    fn record(
+        &self,
+        req: &RecordAuditRequest,
+    ) -> impl Future<Output = Result<AuditEntry, RecordAuditError>> + Send;
+
+    fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> impl Future<Output = Result<Vec<AuditEntry>, FindAuditLogError>> + Send;
+
+    fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> impl Future<Output = Result<Vec<AuditEntry>, FindAuditLogError>> + Send;
+
+    fn latest_change_id(
+        &self,
+    ) -> impl Future<Output = Result<Option<i64>, FindAuditLogError>> + Send;
+
+    /// Clears the before and after snapshots of every entry about the author, returning how many
+    /// entries there are.
+    fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> impl Future<Output = Result<u64, RecordAuditError>> + Send;
+
+    /// Appends to the erasure log, chaining the record onto the hash of the latest one.
+    fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> impl Future<Output = Result<ErasureRecord, RecordAuditError>> + Send;
+
+    /// The erasure log, oldest first.
+    fn find_erasures(
+        &self,
+    ) -> impl Future<Output = Result<Vec<ErasureRecord>, FindAuditLogError>> + Send;
+
+    /// Appends to the security log, which is kept apart from the audit log of each author.
+    fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> impl Future<Output = Result<SecurityEvent, RecordAuditError>> + Send;
+
+    /// The latest `limit` security events, newest first.
+    fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<SecurityEvent>, FindAuditLogError>> + Send;
+}
+
+/// Object-safe counterpart of [`AuditRecorder`], implemented for every one.
+pub trait DynAuditRecorder: Send + Sync + 'static {
+    fn record<'a>(
+        &'a self,
+        req: &'a RecordAuditRequest,
+    ) -> BoxFuture<'a, Result<AuditEntry, RecordAuditError>>;
+
+    fn find_audit_log<'a>(
+        &'a self,
+        req: &'a FindAuditLogRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuditEntry>, FindAuditLogError>>;
+
+    fn find_changes<'a>(
+        &'a self,
+        req: &'a FindChangesRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuditEntry>, FindAuditLogError>>;
+
+    fn latest_change_id<'a>(&'a self) -> BoxFuture<'a, Result<Option<i64>, FindAuditLogError>>;
+
+    fn erase_audit_snapshots<'a>(
+        &'a self,
+        req: &'a FindAuditLogRequest,
+    ) -> BoxFuture<'a, Result<u64, RecordAuditError>>;
+
+    fn record_erasure<'a>(
+        &'a self,
+        req: &'a RecordErasureRequest,
+    ) -> BoxFuture<'a, Result<ErasureRecord, RecordAuditError>>;
+
+    fn find_erasures<'a>(&'a self) -> BoxFuture<'a, Result<Vec<ErasureRecord>, FindAuditLogError>>;
+
+    fn record_security_event<'a>(
+        &'a self,
+        req: &'a RecordSecurityEventRequest,
+    ) -> BoxFuture<'a, Result<SecurityEvent, RecordAuditError>>;
+
+    fn find_security_events<'a>(
+        &'a self,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<SecurityEvent>, FindAuditLogError>>;
+}
+
+impl<T: AuditRecorder> DynAuditRecorder for T {
+    fn record<'a>(
+        &'a self,
+        req: &'a RecordAuditRequest,
+    ) -> BoxFuture<'a, Result<AuditEntry, RecordAuditError>> {
+        Box::pin(AuditRecorder::record(self, req))
+    }
+
+    fn find_audit_log<'a>(
+        &'a self,
+        req: &'a FindAuditLogRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuditEntry>, FindAuditLogError>> {
+        Box::pin(AuditRecorder::find_audit_log(self, req))
+    }
+
+    fn find_changes<'a>(
+        &'a self,
+        req: &'a FindChangesRequest,
+    ) -> BoxFuture<'a, Result<Vec<AuditEntry>, FindAuditLogError>> {
+        Box::pin(AuditRecorder::find_changes(self, req))
+    }
+
+    fn latest_change_id<'a>(&'a self) -> BoxFuture<'a, Result<Option<i64>, FindAuditLogError>> {
+        Box::pin(AuditRecorder::latest_change_id(self))
+    }
+
+    fn erase_audit_snapshots<'a>(
+        &'a self,
+        req: &'a FindAuditLogRequest,
+    ) -> BoxFuture<'a, Result<u64, RecordAuditError>> {
+        Box::pin(AuditRecorder::erase_audit_snapshots(self, req))
+    }
+
+    fn record_erasure<'a>(
+        &'a self,
+        req: &'a RecordErasureRequest,
+    ) -> BoxFuture<'a, Result<ErasureRecord, RecordAuditError>> {
+        Box::pin(AuditRecorder::record_erasure(self, req))
+    }
+
+    fn find_erasures<'a>(&'a self) -> BoxFuture<'a, Result<Vec<ErasureRecord>, FindAuditLogError>> {
+        Box::pin(AuditRecorder::find_erasures(self))
+    }
+
+    fn record_security_event<'a>(
+        &'a self,
+        req: &'a RecordSecurityEventRequest,
+    ) -> BoxFuture<'a, Result<SecurityEvent, RecordAuditError>> {
+        Box::pin(AuditRecorder::record_security_event(self, req))
+    }
+
+    fn find_security_events<'a>(
+        &'a self,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<SecurityEvent>, FindAuditLogError>> {
+        Box::pin(AuditRecorder::find_security_events(self, limit))
+    }
+}
+
+impl AuditRecorder for Box<dyn DynAuditRecorder> {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        self.as_ref().record(req).await
+    }
+
+    async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        self.as_ref().find_audit_log(req).await
+    }
+
+    async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        self.as_ref().find_changes(req).await
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        self.as_ref().latest_change_id().await
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        self.as_ref().erase_audit_snapshots(req).await
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        self.as_ref().record_erasure(req).await
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        self.as_ref().find_erasures().await
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        self.as_ref().record_security_event(req).await
+    }
+
+    async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        self.as_ref().find_security_events(limit).await
+    }
+}
+
+pub trait EventPublisher: Send + Sync + 'static {
+    fn publish(
+        &self,
+        event: &AuthorEvent,
+    ) -> impl Future<Output = Result<(), PublishEventError>> + Send;
+}
+
+/// Object-safe counterpart of [`EventPublisher`], implemented for every one.
+pub trait DynEventPublisher: Send + Sync + 'static {
+    fn publish<'a>(
+        &'a self,
+        event: &'a AuthorEvent,
+    ) -> BoxFuture<'a, Result<(), PublishEventError>>;
+}
+
+impl<T: EventPublisher> DynEventPublisher for T {
+    fn publish<'a>(
+        &'a self,
+        event: &'a AuthorEvent,
+    ) -> BoxFuture<'a, Result<(), PublishEventError>> {
+        Box::pin(EventPublisher::publish(self, event))
+    }
+}
+
+impl EventPublisher for Box<dyn DynEventPublisher> {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        self.as_ref().publish(event).await
+    }
+}
+
+pub trait CommandLog: Send + Sync + 'static {
+    fn is_processed(
+        &self,
+        command_id: &str,
+    ) -> impl Future<Output = Result<bool, CommandLogError>> + Send;
+
+    fn mark_processed(
+        &self,
+        command_id: &str,
+    ) -> impl Future<Output = Result<(), CommandLogError>> + Send;
+}
+
+/// Object-safe counterpart of [`CommandLog`], implemented for every one.
+pub trait DynCommandLog: Send + Sync + 'static {
+    fn is_processed<'a>(
+        &'a self,
+        command_id: &'a str,
+    ) -> BoxFuture<'a, Result<bool, CommandLogError>>;
+
+    fn mark_processed<'a>(
+        &'a self,
+        command_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), CommandLogError>>;
+}
+
+impl<T: CommandLog> DynCommandLog for T {
+    fn is_processed<'a>(
+        &'a self,
+        command_id: &'a str,
+    ) -> BoxFuture<'a, Result<bool, CommandLogError>> {
+        Box::pin(CommandLog::is_processed(self, command_id))
+    }
+
+    fn mark_processed<'a>(
+        &'a self,
+        command_id: &'a str,
+    ) -> BoxFuture<'a, Result<(), CommandLogError>> {
+        Box::pin(CommandLog::mark_processed(self, command_id))
+    }
+}
+
+impl CommandLog for Box<dyn DynCommandLog> {
+    async fn is_processed(&self, command_id: &str) -> Result<bool, CommandLogError> {
+        self.as_ref().is_processed(command_id).await
+    }
+
+    async fn mark_processed(&self, command_id: &str) -> Result<(), CommandLogError> {
+        self.as_ref().mark_processed(command_id).await
+    }
+}
+
+pub trait BlobStorage: Send + Sync + 'static {
+    fn put(&self, key: &str, blob: &Blob) -> impl Future<Output = Result<(), PutBlobError>> + Send;
+
+    fn get(&self, key: &str) -> impl Future<Output = Result<Blob, GetBlobError>> + Send;
+
+    /// Removing a key that does not exist succeeds.
+    fn delete(&self, key: &str) -> impl Future<Output = Result<(), DeleteBlobError>> + Send;
+}
+
+/// Object-safe counterpart of [`BlobStorage`], implemented for every one.
+pub trait DynBlobStorage: Send + Sync + 'static {
+    fn put<'a>(&'a self, key: &'a str, blob: &'a Blob) -> BoxFuture<'a, Result<(), PutBlobError>>;
+
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Blob, GetBlobError>>;
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), DeleteBlobError>>;
+}
+
+impl<T: BlobStorage> DynBlobStorage for T {
+    fn put<'a>(&'a self, key: &'a str, blob: &'a Blob) -> BoxFuture<'a, Result<(), PutBlobError>> {
+        Box::pin(BlobStorage::put(self, key, blob))
+    }
+
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Blob, GetBlobError>> {
+        Box::pin(BlobStorage::get(self, key))
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), DeleteBlobError>> {
+        Box::pin(BlobStorage::delete(self, key))
+    }
+}
+
+impl BlobStorage for Box<dyn DynBlobStorage> {
+    async fn put(&self, key: &str, blob: &Blob) -> Result<(), PutBlobError> {
+        self.as_ref().put(key, blob).await
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, GetBlobError> {
+        self.as_ref().get(key).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DeleteBlobError> {
+        self.as_ref().delete(key).await
+    }
+}
+
+/// Looks up an author's works in a catalog outside this service, matching on the author's name.
+pub trait BookCatalogClient: Send + Sync + 'static {
+    fn find_works(
+        &self,
+        author: &AuthorName,
+    ) -> impl Future<Output = Result<Vec<ExternalWork>, FindExternalWorksError>> + Send;
+}
+
+/// Object-safe counterpart of [`BookCatalogClient`], implemented for every one.
+pub trait DynBookCatalogClient: Send + Sync + 'static {
+    fn find_works<'a>(
+        &'a self,
+        author: &'a AuthorName,
+    ) -> BoxFuture<'a, Result<Vec<ExternalWork>, FindExternalWorksError>>;
+}
+
+impl<T: BookCatalogClient> DynBookCatalogClient for T {
+    fn find_works<'a>(
+        &'a self,
+        author: &'a AuthorName,
+    ) -> BoxFuture<'a, Result<Vec<ExternalWork>, FindExternalWorksError>> {
+        Box::pin(BookCatalogClient::find_works(self, author))
+    }
+}
+
+impl BookCatalogClient for Box<dyn DynBookCatalogClient> {
+    async fn find_works(
+        &self,
+        author: &AuthorName,
+    ) -> Result<Vec<ExternalWork>, FindExternalWorksError> {
+        self.as_ref().find_works(author).await
+    }
+}
+
+/// Checks whether an address can receive mail. Answering [`EmailVerification::Pending`] means the
+/// check was inconclusive and should be repeated later.
+pub trait EmailVerifier: Send + Sync + 'static {
+    fn verify_email(
+        &self,
+        email: &EmailAddress,
+    ) -> impl Future<Output = Result<EmailVerification, VerifyEmailError>> + Send;
+}
+
+/// Object-safe counterpart of [`EmailVerifier`], implemented for every one.
+pub trait DynEmailVerifier: Send + Sync + 'static {
+    fn verify_email<'a>(
+        &'a self,
+        email: &'a EmailAddress,
+    ) -> BoxFuture<'a, Result<EmailVerification, VerifyEmailError>>;
+}
+
+impl<T: EmailVerifier> DynEmailVerifier for T {
+    fn verify_email<'a>(
+        &'a self,
+        email: &'a EmailAddress,
+    ) -> BoxFuture<'a, Result<EmailVerification, VerifyEmailError>> {
+        Box::pin(EmailVerifier::verify_email(self, email))
+    }
+}
+
+impl EmailVerifier for Box<dyn DynEmailVerifier> {
+    async fn verify_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<EmailVerification, VerifyEmailError> {
+        self.as_ref().verify_email(email).await
+    }
+}
+
+/// Keeps long-running operations so clients can poll them while a background task runs them.
+pub trait OperationStore: Send + Sync + 'static {
+    /// Inserts the operation or replaces the one with the same id.
+    fn save_operation(
+        &self,
+        operation: &Operation,
+    ) -> impl Future<Output = Result<(), SaveOperationError>> + Send;
+
+    fn find_operation(
+        &self,
+        id: OperationId,
+    ) -> impl Future<Output = Result<Operation, FindOperationError>> + Send;
+}
+
+/// Object-safe counterpart of [`OperationStore`], implemented for every one.
+pub trait DynOperationStore: Send + Sync + 'static {
+    fn save_operation<'a>(
+        &'a self,
+        operation: &'a Operation,
+    ) -> BoxFuture<'a, Result<(), SaveOperationError>>;
+
+    fn find_operation<'a>(
+        &'a self,
+        id: OperationId,
+    ) -> BoxFuture<'a, Result<Operation, FindOperationError>>;
+}
+
+impl<T: OperationStore> DynOperationStore for T {
+    fn save_operation<'a>(
+        &'a self,
+        operation: &'a Operation,
+    ) -> BoxFuture<'a, Result<(), SaveOperationError>> {
+        Box::pin(OperationStore::save_operation(self, operation))
+    }
+
+    fn find_operation<'a>(
+        &'a self,
+        id: OperationId,
+    ) -> BoxFuture<'a, Result<Operation, FindOperationError>> {
+        Box::pin(OperationStore::find_operation(self, id))
+    }
+}
+
+impl OperationStore for Box<dyn DynOperationStore> {
+    async fn save_operation(&self, operation: &Operation) -> Result<(), SaveOperationError> {
+        self.as_ref().save_operation(operation).await
+    }
+
+    async fn find_operation(&self, id: OperationId) -> Result<Operation, FindOperationError> {
+        self.as_ref().find_operation(id).await
+    }
+}
+
+/// Keeps browser sessions on the server, so their cookies carry nothing but the id and signing out
+/// ends them for good.
+pub trait SessionStore: Send + Sync + 'static {
+    /// Inserts the session or replaces the one with the same id.
+    fn save_session(
+        &self,
+        session: &StoredSession,
+    ) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
+
+    /// Expired sessions are not returned.
+    fn find_session(
+        &self,
+        id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Option<StoredSession>, SessionStoreError>> + Send;
+
+    fn delete_session(
+        &self,
+        id: &SessionId,
+    ) -> impl Future<Output = Result<(), SessionStoreError>> + Send;
+}
+
+/// Object-safe counterpart of [`SessionStore`], implemented for every one.
+pub trait DynSessionStore: Send + Sync + 'static {
+    fn save_session<'a>(
+        &'a self,
+        session: &'a StoredSession,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>>;
+
+    fn find_session<'a>(
+        &'a self,
+        id: &'a SessionId,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<StoredSession>, SessionStoreError>>;
+
+    fn delete_session<'a>(
+        &'a self,
+        id: &'a SessionId,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>>;
+}
+
+impl<T: SessionStore> DynSessionStore for T {
+    fn save_session<'a>(
+        &'a self,
+        session: &'a StoredSession,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        Box::pin(SessionStore::save_session(self, session))
+    }
+
+    fn find_session<'a>(
+        &'a self,
+        id: &'a SessionId,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<StoredSession>, SessionStoreError>> {
+        Box::pin(SessionStore::find_session(self, id, now))
+    }
+
+    fn delete_session<'a>(
+        &'a self,
+        id: &'a SessionId,
+    ) -> BoxFuture<'a, Result<(), SessionStoreError>> {
+        Box::pin(SessionStore::delete_session(self, id))
+    }
+}
+
+impl SessionStore for Box<dyn DynSessionStore> {
+    async fn save_session(&self, session: &StoredSession) -> Result<(), SessionStoreError> {
+        self.as_ref().save_session(session).await
+    }
+
+    async fn find_session(
+        &self,
+        id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Result<Option<StoredSession>, SessionStoreError> {
+        self.as_ref().find_session(id, now).await
+    }
+
+    async fn delete_session(&self, id: &SessionId) -> Result<(), SessionStoreError> {
+        self.as_ref().delete_session(id).await
+    }
+}
+
+/// An OpenID provider the admin pages sign in with, using the authorization code flow with PKCE.
+/// The ID tokens it returns have had their signature checked against the provider's keys.
+pub trait IdentityProvider: Send + Sync + 'static {
+    /// Where to send the browser to sign in.
+    fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> impl Future<Output = anyhow::Result<Url>> + Send;
+
+    fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> impl Future<Output = anyhow::Result<TokenResponse>> + Send;
+
+    fn refresh(
+        &self,
+        refresh_token: &str,
+    ) -> impl Future<Output = anyhow::Result<TokenResponse>> + Send;
+}
+
+/// Object-safe counterpart of [`IdentityProvider`], implemented for every one.
+pub trait DynIdentityProvider: Send + Sync + 'static {
+    fn authorization_url<'a>(
+        &'a self,
+        state: &'a str,
+        nonce: &'a str,
+        code_challenge: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<Url>>;
+
+    fn exchange_code<'a>(
+        &'a self,
+        code: &'a str,
+        code_verifier: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<TokenResponse>>;
+
+    fn refresh<'a>(
+        &'a self,
+        refresh_token: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<TokenResponse>>;
+}
+
+impl<T: IdentityProvider> DynIdentityProvider for T {
+    fn authorization_url<'a>(
+        &'a self,
+        state: &'a str,
+        nonce: &'a str,
+        code_challenge: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<Url>> {
+        Box::pin(IdentityProvider::authorization_url(
+            self,
+            state,
+            nonce,
+            code_challenge,
+        ))
+    }
+
+    fn exchange_code<'a>(
+        &'a self,
+        code: &'a str,
+        code_verifier: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<TokenResponse>> {
+        Box::pin(IdentityProvider::exchange_code(self, code, code_verifier))
+    }
+
+    fn refresh<'a>(
+        &'a self,
+        refresh_token: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<TokenResponse>> {
+        Box::pin(IdentityProvider::refresh(self, refresh_token))
+    }
+}
+
+impl IdentityProvider for Box<dyn DynIdentityProvider> {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> anyhow::Result<Url> {
+        self.as_ref()
+            .authorization_url(state, nonce, code_challenge)
+            .await
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> anyhow::Result<TokenResponse> {
+        self.as_ref().exchange_code(code, code_verifier).await
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> anyhow::Result<TokenResponse> {
+        self.as_ref().refresh(refresh_token).await
+    }
+}
+
+/// Flags that switch new behavior on, read on every request so a change applies without a
+/// restart.
+pub trait FeatureFlags: Send + Sync + 'static {
+    fn find_flags(
+        &self,
+    ) -> impl Future<Output = Result<Vec<FeatureFlag>, FindFeatureFlagsError>> + Send;
+}
+
+/// Object-safe counterpart of [`FeatureFlags`], implemented for every one.
+pub trait DynFeatureFlags: Send + Sync + 'static {
+    fn find_flags<'a>(&'a self) -> BoxFuture<'a, Result<Vec<FeatureFlag>, FindFeatureFlagsError>>;
+}
+
+impl<T: FeatureFlags> DynFeatureFlags for T {
+    fn find_flags<'a>(&'a self) -> BoxFuture<'a, Result<Vec<FeatureFlag>, FindFeatureFlagsError>> {
+        Box::pin(FeatureFlags::find_flags(self))
+    }
+}
+
+impl FeatureFlags for Box<dyn DynFeatureFlags> {
+    async fn find_flags(&self) -> Result<Vec<FeatureFlag>, FindFeatureFlagsError> {
+        self.as_ref().find_flags().await
+    }
+}
+
+/// Purges rows that have outlived a [`RetentionPolicy`](crate::domain::model::RetentionPolicy).
+pub trait RetentionStore: Send + Sync + 'static {
+    /// Counts what [`Self::purge_expired`] would delete at `now`, without deleting it.
+    fn preview_purge(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<RetentionReport, PurgeExpiredError>> + Send;
+
+    /// Deletes everything past its window at `now` in one transaction.
+    fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<RetentionReport, PurgeExpiredError>> + Send;
+}
+
+/// Object-safe counterpart of [`RetentionStore`], implemented for every one.
+pub trait DynRetentionStore: Send + Sync + 'static {
+    fn preview_purge<'a>(
+        &'a self,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<RetentionReport, PurgeExpiredError>>;
+
+    fn purge_expired<'a>(
+        &'a self,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<RetentionReport, PurgeExpiredError>>;
+}
+
+impl<T: RetentionStore> DynRetentionStore for T {
+    fn preview_purge<'a>(
+        &'a self,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<RetentionReport, PurgeExpiredError>> {
+        Box::pin(RetentionStore::preview_purge(self, now))
+    }
+
+    fn purge_expired<'a>(
+        &'a self,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<RetentionReport, PurgeExpiredError>> {
+        Box::pin(RetentionStore::purge_expired(self, now))
+    }
+}
+
+impl RetentionStore for Box<dyn DynRetentionStore> {
+    async fn preview_purge(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<RetentionReport, PurgeExpiredError> {
+        self.as_ref().preview_purge(now).await
+    }
+
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<RetentionReport, PurgeExpiredError> {
+        self.as_ref().purge_expired(now).await
+    }
+}
+
+/// Reports on the schema migrations the application ships with.
+pub trait MigrationStore: Send + Sync + 'static {
+    /// Every migration in version order, applied or not.
+    fn find_migrations(
+        &self,
+    ) -> impl Future<Output = Result<Vec<MigrationStatus>, FindMigrationsError>> + Send;
+}
+
+/// Object-safe counterpart of [`MigrationStore`], implemented for every one.
+pub trait DynMigrationStore: Send + Sync + 'static {
+    fn find_migrations<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, Result<Vec<MigrationStatus>, FindMigrationsError>>;
+}
+
+impl<T: MigrationStore> DynMigrationStore for T {
+    fn find_migrations<'a>(
+        &'a self,
+    ) -> BoxFuture<'a, Result<Vec<MigrationStatus>, FindMigrationsError>> {
+        Box::pin(MigrationStore::find_migrations(self))
+    }
+}
+
+impl MigrationStore for Box<dyn DynMigrationStore> {
+    async fn find_migrations(&self) -> Result<Vec<MigrationStatus>, FindMigrationsError> {
+        self.as_ref().find_migrations().await
+    }
+}
+
+/// Copies the whole database out and back in, for disaster recovery rather than data exchange.
+pub trait BackupStore: Send + Sync + 'static {
+    /// A consistent copy of every table, never containing a partial write.
+    fn snapshot(&self) -> impl Future<Output = Result<Vec<u8>, CreateBackupError>> + Send;
+
+    /// Replaces every table's rows with those in `snapshot`, which must come from a database at
+    /// the same migration version.
+    fn restore(
+        &self,
+        snapshot: &[u8],
+    ) -> impl Future<Output = Result<(), RestoreBackupError>> + Send;
+}
+
+/// Object-safe counterpart of [`BackupStore`], implemented for every one.
+pub trait DynBackupStore: Send + Sync + 'static {
+    fn snapshot<'a>(&'a self) -> BoxFuture<'a, Result<Vec<u8>, CreateBackupError>>;
+
+    fn restore<'a>(&'a self, snapshot: &'a [u8]) -> BoxFuture<'a, Result<(), RestoreBackupError>>;
+}
+
+impl<T: BackupStore> DynBackupStore for T {
+    fn snapshot<'a>(&'a self) -> BoxFuture<'a, Result<Vec<u8>, CreateBackupError>> {
+        Box::pin(BackupStore::snapshot(self))
+    }
+
+    fn restore<'a>(&'a self, snapshot: &'a [u8]) -> BoxFuture<'a, Result<(), RestoreBackupError>> {
+        Box::pin(BackupStore::restore(self, snapshot))
+    }
+}
+
+impl BackupStore for Box<dyn DynBackupStore> {
+    async fn snapshot(&self) -> Result<Vec<u8>, CreateBackupError> {
+        self.as_ref().snapshot().await
+    }
+
+    async fn restore(&self, snapshot: &[u8]) -> Result<(), RestoreBackupError> {
+        self.as_ref().restore(snapshot).await
+    }
+}
+
+/// Picks the ids of new authors before they are stored, so they do not depend on the database
+/// that ends up holding them.
+pub trait IdGenerator: Send + Sync + 'static {
+    /// `None` leaves the choice to the repository.
+    fn next_author_id(&self) -> Option<AuthorId>;
+}
+
+impl IdGenerator for Box<dyn IdGenerator> {
+    fn next_author_id(&self) -> Option<AuthorId> {
+        self.as_ref().next_author_id()
+    }
+}
+
+/// Protects personal data stored by the adapters. Encryption is randomized, so stores match on
+/// the blind index instead: a keyed hash that is the same for equal values and reveals nothing
+/// else about them.
+pub trait FieldCipher: Send + Sync + 'static {
+    fn encrypt(&self, plaintext: &str) -> Result<String, CipherError>;
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String, CipherError>;
+
+    fn blind_index(&self, plaintext: &str) -> String;
+
+    /// Whether `ciphertext` was not encrypted with the current key and should be re-encrypted.
+    fn needs_rotation(&self, ciphertext: &str) -> bool;
+}
+
+impl std::fmt::Debug for dyn FieldCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FieldCipher")
+    }
+}
+
+pub trait UnitOfWork: Send + Sync + 'static {
+    fn begin(&self) -> impl Future<Output = anyhow::Result<Box<dyn Transaction>>> + Send;
+
+    /// How long to wait before running the whole unit of work again in a new transaction, after
+    /// its `attempt`th try failed with `err`. `None` gives up, which is the default.
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        let _ = (attempt, err);
+        None
+    }
+}
+
+/// Object-safe counterpart of [`UnitOfWork`], implemented for every one.
+pub trait DynUnitOfWork: Send + Sync + 'static {
+    fn begin<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<Box<dyn Transaction>>>;
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration>;
+}
+
+impl<T: UnitOfWork> DynUnitOfWork for T {
+    fn begin<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<Box<dyn Transaction>>> {
+        Box::pin(UnitOfWork::begin(self))
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        UnitOfWork::retry_delay(self, attempt, err)
+    }
+}
+
+impl UnitOfWork for Box<dyn DynUnitOfWork> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        self.as_ref().begin().await
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        self.as_ref().retry_delay(attempt, err)
+    }
+}
+
+/// Work begun by a [`UnitOfWork`], which is only ever handled behind `dyn`, so committing and
+/// rolling back return boxed futures.
+pub trait Transaction: Send + Sync {
+    fn authors(&self) -> &dyn DynAuthorRepository;
+
+    fn genres(&self) -> &dyn DynGenreRepository;
+
+    fn audit(&self) -> &dyn DynAuditRecorder;
+
+    fn publishers(&self) -> &dyn DynPublisherRepository;
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>>;
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>>;
+}
+
+/// The authors of a [`Transaction`] as a repository of their own, so the decorators that wrap
+/// repositories can wrap them too. [`Self::into_inner`] hands the transaction back to commit.
+pub struct TransactionAuthors(Box<dyn Transaction>);
+
+impl TransactionAuthors {
+    #[must_use]
+    pub fn new(tx: Box<dyn Transaction>) -> Self {
+        Self(tx)
+    }
+
+    #[must_use]
+    pub fn transaction(&self) -> &dyn Transaction {
+        self.0.as_ref()
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Box<dyn Transaction> {
+        self.0
+    }
+}
+
+impl AuthorRepository for TransactionAuthors {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.0.authors().create_author(req).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.0.authors().find_author(req).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.0.authors().find_author_by_email(req).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.authors().find_all_authors().await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.authors().find_authors_by_ids(req).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.authors().find_sorted_authors(req).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.0.authors().find_projected_authors(req).await
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.0.authors().stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.0.authors().count_authors().await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.0.authors().author_exists(req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.0.authors().update_author(req).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.0.authors().upsert_author(req).await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.0.authors().upsert_author_by_email(req).await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.0.authors().set_author_status(req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.0.authors().delete_author(req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.0.authors().add_author_alias(req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.0.authors().remove_author_alias(req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.0.authors().find_author_aliases(req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.authors().search_authors(req).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.0.authors().author_stats(req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.0.authors().find_authors_by_verification(req).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.0.authors().set_email_verification(req).await
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+pub mod contract {
+    use crate::domain::model::{
+        AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, Author, AuthorGenreRequest,
+        AuthorId, AuthorName, AuthorProfile, AuthorStatsRequest, AuthorStatus, Biography,
+        ChangeAuthorStatusError, ContractId, ContractTerm, CountryCode, CreateAuthorError,
+        CreateAuthorRequest, CreateContractRequest, CreateGenreError, CreateGenreRequest,
+        CreatePublisherError, CreatePublisherRequest, DeleteAuthorError, DeleteAuthorRequest,
+        DeleteContractError, DeleteContractRequest, DeleteGenreError, DeleteGenreRequest,
+        DeletePublisherError, DeletePublisherRequest, DetachGenreError, EmailAddress, FieldUpdate,
+        FindAuthorError, FindAuthorRequest, FindAuthorsByGenreRequest, FindAuthorsByIdsRequest,
+        FindPublisherError, FindPublisherRequest, GenreId, GenreName, PublisherId, PublisherName,
+        RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest,
+        ReplacedAuthor, RoyaltyPercent, SearchAuthorsRequest, SetAuthorStatusRequest,
+        UpdateAuthorError, UpdateAuthorRequest, UpsertAuthorError, WebsiteUrl,
+    };
+    use crate::domain::model::{
+        EmailVerification, FindAuthorByEmailError, FindAuthorByEmailRequest,
+        FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+        SetEmailVerificationRequest,
+    };
+    use crate::domain::ports::{AuthorRepository, GenreRepository, PublisherRepository};
+    use chrono::{Days, Utc};
+    use futures::StreamExt;
+    use futures::future::join_all;
+    use std::collections::BTreeMap;
+
+    fn create_request(name: &str, email: &str) -> CreateAuthorRequest {
+        CreateAuthorRequest::new(
+            AuthorName::new(name).unwrap(),
+            EmailAddress::new(email).unwrap(),
+        )
+    }
+
+    async fn find(repo: &impl AuthorRepository, id: AuthorId) -> Result<Author, FindAuthorError> {
+        repo.find_author(&FindAuthorRequest::new(id)).await
+    }
+
+    /// Exercises the behavioral contract of the `AuthorRepository` port against an empty `repo`,
+    /// panicking on the first violation. Adapters assign integer ids.
+    pub async fn repository_contract_tests(repo: impl AuthorRepository) {
+        let repo = &repo;
+        let tolkien = repo
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let lewis = repo
+            .create_author(&create_request("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        assert_ne!(tolkien.id(), lewis.id(), "expected distinct ids");
+        assert_eq!(AuthorStatus::Active, tolkien.status());
+
+        let found = find(repo, tolkien.id()).await.unwrap();
+        assert_eq!("JRR Tolkien", found.name().to_string());
+        assert_eq!("jrr.tolkien@example.com", found.email().to_string());
+        let by_email =
+            FindAuthorByEmailRequest::new(EmailAddress::new("CS.Lewis@Example.com").unwrap());
+        let found = repo.find_author_by_email(&by_email).await.unwrap();
+        assert_eq!(lewis.id(), found.id());
+        let by_email =
+            FindAuthorByEmailRequest::new(EmailAddress::new("nobody@example.com").unwrap());
+        let missing = repo.find_author_by_email(&by_email).await;
+        assert!(
+            matches!(&missing, Err(FindAuthorByEmailError::NotFound { email }) if email == "nobody@example.com"),
+            "expected author not found, but got {missing:?}"
+        );
+        let ids: Vec<_> = repo
+            .find_all_authors()
+            .await
+            .unwrap()
+            .iter()
+            .map(|author| author.id())
+            .collect();
+        assert_eq!(
+            vec![tolkien.id(), lewis.id()],
+            ids,
+            "expected authors in id order"
+        );
+        let streamed: Vec<_> = repo
+            .stream_all_authors()
+            .await
+            .map(|author| author.unwrap().id())
+            .collect()
+            .await;
+        assert_eq!(
+            ids, streamed,
+            "expected streamed authors to match find_all_authors"
+        );
+        let batch =
+            FindAuthorsByIdsRequest::new([lewis.id(), AuthorId::Integer(404), tolkien.id()]);
+        let by_ids = repo.find_authors_by_ids(&batch).await.unwrap();
+        let found: Vec<_> = by_ids.iter().map(|author| author.id()).collect();
+        assert_eq!(ids, found, "expected authors found by id in id order");
+        assert_eq!(vec![AuthorId::Integer(404)], batch.missing(&by_ids));
+        let sorted = |sort: &str| {
+            let req = FindSortedAuthorsRequest::new(sort.parse().unwrap());
+            async move {
+                let authors = repo.find_sorted_authors(&req).await.unwrap();
+                authors.iter().map(|author| author.id()).collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(vec![tolkien.id(), lewis.id()], sorted("-name").await);
+        assert_eq!(vec![lewis.id(), tolkien.id()], sorted("-id").await);
+        let projected = FindProjectedAuthorsRequest::new("name,bio".parse().unwrap());
+        let projected = repo.find_projected_authors(&projected).await.unwrap();
+        assert_eq!(
+            ids,
+            projected
+                .iter()
+                .map(|author| author.id())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some("JRR Tolkien"),
+            projected[0].name().map(AuthorName::as_str)
+        );
+        assert_eq!(None, projected[0].email(), "expected only the asked fields");
+        assert_eq!(None, projected[0].created_at());
+        assert_eq!(
+            ids,
+            sorted("country,-birth_date").await,
+            "expected authors equal on every sort field in id order"
+        );
+        assert_eq!(2, repo.count_authors().await.unwrap());
+        let exists = FindAuthorRequest::new(tolkien.id());
+        assert!(repo.author_exists(&exists).await.unwrap());
+        let exists = FindAuthorRequest::new(AuthorId::Integer(404));
+        assert!(!repo.author_exists(&exists).await.unwrap());
+
+        let duplicate = repo
+            .create_author(&create_request("JRR Tolkien", "tolkien@example.com"))
+            .await;
+        assert!(
+            matches!(&duplicate, Err(CreateAuthorError::Duplicate { name }) if name == "JRR Tolkien"),
+            "expected duplicate name, but got {duplicate:?}"
+        );
+        let duplicate = repo
+            .create_author(&create_request("John Tolkien", "cs.lewis@example.com"))
+            .await;
+        assert!(
+            matches!(&duplicate, Err(CreateAuthorError::DuplicateEmail { .. })),
+            "expected duplicate email, but got {duplicate:?}"
+        );
+
+        let update = UpdateAuthorRequest::builder(tolkien.id())
+            .name(AuthorName::new("J.R.R. Tolkien").unwrap())
+            .build()
+            .unwrap();
+        let returned = repo.update_author(&update).await.unwrap();
+        let updated = find(repo, tolkien.id()).await.unwrap();
+        assert_eq!(updated.name(), returned.name());
+        assert_eq!(updated.updated_at(), returned.updated_at());
+        assert_eq!("J.R.R. Tolkien", updated.name().to_string());
+        assert_eq!("jrr.tolkien@example.com", updated.email().to_string());
+        let update = UpdateAuthorRequest::builder(tolkien.id())
+            .email(EmailAddress::new("cs.lewis@example.com").unwrap())
+            .build()
+            .unwrap();
+        let actual = repo.update_author(&update).await;
+        assert!(
+            matches!(&actual, Err(UpdateAuthorError::DuplicateEmail { .. })),
+            "expected duplicate email, but got {actual:?}"
+        );
+        let missing = AuthorId::Integer(404);
+        let update = UpdateAuthorRequest::builder(missing)
+            .name(AuthorName::new("Nobody").unwrap())
+            .build()
+            .unwrap();
+        let actual = repo.update_author(&update).await;
+        assert!(
+            matches!(&actual, Err(UpdateAuthorError::NotFound { id }) if *id == missing),
+            "expected not found, but got {actual:?}"
+        );
+        let bio = Biography::new("Philologist and *author*.").unwrap();
+        let update = UpdateAuthorRequest::builder(tolkien.id())
+            .bio(FieldUpdate::Set(bio.clone()))
+            .birth_date(FieldUpdate::Set("1892-01-03".parse().unwrap()))
+            .website(FieldUpdate::Set(
+                WebsiteUrl::new("https://tolkien.example").unwrap(),
+            ))
+            .country(FieldUpdate::Set(CountryCode::new("GB").unwrap()))
+            .build()
+            .unwrap();
+        repo.update_author(&update).await.unwrap();
+        let update = UpdateAuthorRequest::builder(tolkien.id())
+            .website(FieldUpdate::Clear)
+            .build()
+            .unwrap();
+        let returned = repo.update_author(&update).await.unwrap();
+        let profile = find(repo, tolkien.id()).await.unwrap().profile().clone();
+        assert_eq!(&profile, returned.profile());
+        assert_eq!(Some(&bio), profile.bio());
+        assert_eq!(
+            Some("1892-01-03".to_string()),
+            profile.birth_date().map(|date| date.to_string())
+        );
+        assert_eq!(
+            None,
+            profile.website(),
+            "expected the website to be cleared"
+        );
+        assert_eq!(
+            Some("GB"),
+            profile.country().as_ref().map(CountryCode::as_str)
+        );
+
+        let replace = ReplaceAuthorRequest::new(
+            AuthorId::Integer(42),
+            AuthorName::new("Ursula K. Le Guin").unwrap(),
+            EmailAddress::new("ursula@example.com").unwrap(),
+        )
+        .with_profile(AuthorProfile::default().with_country(Some(CountryCode::new("US").unwrap())));
+        let created = repo.upsert_author(&replace).await.unwrap();
+        assert_eq!(AuthorId::Integer(42), created.id());
+        let stored = find(repo, created.id()).await.unwrap();
+        assert_eq!(replace.profile(), stored.profile());
+        let replace = ReplaceAuthorRequest::new(
+            AuthorId::Integer(42),
+            AuthorName::new("CS Lewis").unwrap(),
+            EmailAddress::new("ursula@example.com").unwrap(),
+        );
+        let actual = repo.upsert_author(&replace).await;
+        assert!(
+            matches!(&actual, Err(ReplaceAuthorError::Duplicate { .. })),
+            "expected duplicate name, but got {actual:?}"
+        );
+        let next = repo
+            .create_author(&create_request("Terry Pratchett", "terry@example.com"))
+            .await
+            .unwrap();
+        assert_ne!(
+            AuthorId::Integer(42),
+            next.id(),
+            "expected ids not to collide"
+        );
+
+        let archive = SetAuthorStatusRequest::new(lewis.id(), AuthorStatus::Archived);
+        repo.set_author_status(&archive).await.unwrap();
+        assert_eq!(
+            AuthorStatus::Archived,
+            find(repo, lewis.id()).await.unwrap().status()
+        );
+        let archive = SetAuthorStatusRequest::new(missing, AuthorStatus::Archived);
+        let actual = repo.set_author_status(&archive).await;
+        assert!(
+            matches!(&actual, Err(ChangeAuthorStatusError::NotFound { id }) if *id == missing),
+            "expected not found, but got {actual:?}"
+        );
+
+        let today = Utc::now().date_naive();
+        let stats = repo
+            .author_stats(&AuthorStatsRequest::new(today - Days::new(29)))
+            .await
+            .unwrap();
+        let authors = repo.find_all_authors().await.unwrap();
+        let total = authors.len() as u64;
+        assert_eq!(
+            (total, total - 1, 1),
+            (stats.total(), stats.active(), stats.archived())
+        );
+        let mut domains = BTreeMap::new();
+        for author in &authors {
+            let email = author.email().to_string();
+            let (_, domain) = email.split_once('@').unwrap();
+            *domains.entry(domain.to_string()).or_insert(0) += 1;
+        }
+        let mut expected: Vec<_> = domains.into_iter().collect();
+        expected.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        assert_eq!(expected, stats.by_email_domain());
+        assert_eq!(&[(today, total)], stats.created_per_day());
+        let stats = repo
+            .author_stats(&AuthorStatsRequest::new(today + Days::new(1)))
+            .await
+            .unwrap();
+        assert!(
+            stats.created_per_day().is_empty(),
+            "expected no future creations"
+        );
+
+        let by_verification = |states: &[EmailVerification]| {
+            let req = FindAuthorsByVerificationRequest::new(states.iter().copied());
+            async move {
+                let authors = repo.find_authors_by_verification(&req).await.unwrap();
+                authors.iter().map(Author::id).collect::<Vec<_>>()
+            }
+        };
+        let pending = FindAuthorsByVerificationRequest::new([EmailVerification::Pending]);
+        let first = repo
+            .find_authors_by_verification(&pending.clone().with_limit(2))
+            .await
+            .unwrap();
+        let first: Vec<_> = first.iter().map(Author::id).collect();
+        assert_eq!(vec![tolkien.id(), lewis.id()], first);
+        let email = |address: &str| EmailAddress::new(address).unwrap();
+        for (id, address) in [
+            (tolkien.id(), "jrr.tolkien@example.com"),
+            (lewis.id(), "stale@example.com"),
+            (AuthorId::Integer(42), "ursula@example.com"),
+        ] {
+            let req =
+                SetEmailVerificationRequest::new(id, email(address), EmailVerification::Verified);
+            repo.set_email_verification(&req).await.unwrap();
+        }
+        assert_eq!(
+            vec![tolkien.id(), AuthorId::Integer(42)],
+            by_verification(&[EmailVerification::Verified]).await,
+            "expected verdicts for a previous address to be ignored"
+        );
+        assert!(find(repo, tolkien.id()).await.unwrap().is_verified());
+        let update = UpdateAuthorRequest::builder(tolkien.id())
+            .email(email("tolkien@example.org"))
+            .build()
+            .unwrap();
+        repo.update_author(&update).await.unwrap();
+        let replace = ReplaceAuthorRequest::new(
+            AuthorId::Integer(42),
+            AuthorName::new("Ursula K. Le Guin").unwrap(),
+            email("ursula@example.com"),
+        );
+        repo.upsert_author(&replace).await.unwrap();
+        assert_eq!(
+            vec![AuthorId::Integer(42)],
+            by_verification(&[EmailVerification::Verified]).await,
+            "expected a new address to reset the verification"
+        );
+        assert_eq!(
+            EmailVerification::Pending,
+            find(repo, tolkien.id()).await.unwrap().email_verification()
+        );
+        assert!(by_verification(&[]).await.is_empty());
+
+        let alias = |name: &str| AuthorName::new(name).unwrap();
+        for name in ["N. W. Clerk", "Clive Hamilton"] {
+            let req = AddAuthorAliasRequest::new(lewis.id(), alias(name));
+            repo.add_author_alias(&req).await.unwrap();
+        }
+        let req = AddAuthorAliasRequest::new(tolkien.id(), alias("Clive Hamilton"));
+        let actual = repo.add_author_alias(&req).await;
+        assert!(
+            matches!(&actual, Err(AddAuthorAliasError::DuplicateAlias { alias }) if alias.to_string() == "Clive Hamilton"),
+            "expected duplicate alias, but got {actual:?}"
+        );
+        let req = AddAuthorAliasRequest::new(missing, alias("Nobody"));
+        let actual = repo.add_author_alias(&req).await;
+        assert!(
+            matches!(&actual, Err(AddAuthorAliasError::NotFound { id }) if *id == missing),
+            "expected not found, but got {actual:?}"
+        );
+        let aliases = repo
+            .find_author_aliases(&FindAuthorRequest::new(lewis.id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![alias("Clive Hamilton"), alias("N. W. Clerk")], aliases);
+        let actual = repo
+            .find_author_aliases(&FindAuthorRequest::new(missing))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindAuthorError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let search = |query: &str| {
+            let req = SearchAuthorsRequest::new(query);
+            async move {
+                let found = repo.search_authors(&req).await.unwrap();
+                found.iter().map(Author::id).collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(vec![lewis.id()], search("hamilton").await);
+        assert_eq!(vec![tolkien.id()], search("TOLKIEN").await);
+        assert_eq!(
+            vec![lewis.id()],
+            search("cl").await,
+            "expected each author once"
+        );
+        assert!(
+            search("%").await.is_empty(),
+            "expected wildcards to match literally"
+        );
+        let req = RemoveAuthorAliasRequest::new(lewis.id(), alias("N. W. Clerk"));
+        repo.remove_author_alias(&req).await.unwrap();
+        let actual = repo.remove_author_alias(&req).await;
+        assert!(
+            matches!(&actual, Err(RemoveAuthorAliasError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+
+        repo.delete_author(&DeleteAuthorRequest::new(lewis.id()))
+            .await
+            .unwrap();
+        let req = AddAuthorAliasRequest::new(tolkien.id(), alias("Clive Hamilton"));
+        repo.add_author_alias(&req).await.unwrap();
+        let req = RemoveAuthorAliasRequest::new(tolkien.id(), alias("Clive Hamilton"));
+        repo.remove_author_alias(&req).await.unwrap();
+        let actual = find(repo, lewis.id()).await;
+        assert!(
+            matches!(&actual, Err(FindAuthorError::NotFound { .. })),
+            "expected deleted author to be gone, but got {actual:?}"
+        );
+        let actual = repo
+            .delete_author(&DeleteAuthorRequest::new(lewis.id()))
+            .await;
+        assert!(
+            matches!(&actual, Err(DeleteAuthorError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        assert_eq!(3, repo.find_all_authors().await.unwrap().len());
+
+        let creates = (0..8).map(|i| {
+            let req = create_request(&format!("Author {i}"), &format!("author{i}@example.com"));
+            async move { repo.create_author(&req).await }
+        });
+        let mut ids: Vec<_> = join_all(creates)
+            .await
+            .into_iter()
+            .map(|author| author.unwrap().id())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(
+            8,
+            ids.len(),
+            "expected concurrent creates to get distinct ids"
+        );
+
+        let creates = (0..4).map(|i| {
+            let req = create_request("Racing Author", &format!("racer{i}@example.com"));
+            async move { repo.create_author(&req).await }
+        });
+        let created = join_all(creates)
+            .await
+            .into_iter()
+            .filter(Result::is_ok)
+            .count();
+        assert_eq!(
+            1, created,
+            "expected exactly one concurrent duplicate to succeed"
+        );
+        assert_eq!(12, repo.find_all_authors().await.unwrap().len());
+
+        let upsert = create_request("Octavia Butler", "octavia@example.com");
+        let upserted = repo.upsert_author_by_email(&upsert).await.unwrap();
+        let ReplacedAuthor::Created(created) = upserted else {
+            panic!("expected a new email to create an author, but got {upserted:?}");
+        };
+        let bio = Biography::new("Wrote the Parable novels.").unwrap();
+        let upsert = create_request("Octavia E. Butler", "octavia@example.com")
+            .with_profile(AuthorProfile::default().with_bio(Some(bio.clone())));
+        let upserted = repo.upsert_author_by_email(&upsert).await.unwrap();
+        let ReplacedAuthor::Replaced(replaced) = upserted else {
+            panic!("expected a known email to replace the author, but got {upserted:?}");
+        };
+        assert_eq!(created.id(), replaced.id());
+        assert_eq!(created.created_at(), replaced.created_at());
+        assert_eq!("Octavia E. Butler", replaced.name().to_string());
+        assert_eq!(
+            Some(&bio),
+            find(repo, created.id()).await.unwrap().profile().bio()
+        );
+        let upsert = create_request("Racing Author", "octavia@example.com");
+        let actual = repo.upsert_author_by_email(&upsert).await;
+        assert!(
+            matches!(&actual, Err(UpsertAuthorError::Duplicate { name }) if name == "Racing Author"),
+            "expected duplicate name, but got {actual:?}"
+        );
+        assert_eq!(13, repo.find_all_authors().await.unwrap().len());
+
+        let chosen = AuthorId::new(7_000_000_000);
+        let create = create_request("Chosen Id", "chosen@example.com").with_id(chosen);
+        let created = repo.create_author(&create).await.unwrap();
+        assert_eq!(chosen, created.id());
+        let found = repo.find_author(&FindAuthorRequest::new(chosen)).await;
+        assert_eq!("Chosen Id", found.unwrap().name().as_str());
+        let taken = create_request("Taken Id", "taken@example.com").with_id(chosen);
+        let actual = repo.create_author(&taken).await;
+        assert!(
+            matches!(actual, Err(CreateAuthorError::IdTaken { id }) if id == chosen),
+            "expected the id to be taken, but got {actual:?}"
+        );
+    }
+
+    /// Exercises the behavioral contract of the `GenreRepository` port against empty `authors`
+    /// and `genres` that share a store.
+    pub async fn genre_repository_contract_tests(
+        authors: &impl AuthorRepository,
+        genres: &impl GenreRepository,
+    ) {
+        let tolkien = authors
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let lewis = authors
+            .create_author(&create_request("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        let genre = |name: &str| CreateGenreRequest::new(GenreName::new(name).unwrap());
+        let fantasy = genres.create_genre(&genre("Fantasy")).await.unwrap();
+        let apologetics = genres.create_genre(&genre("Apologetics")).await.unwrap();
+        assert_ne!(fantasy.id(), apologetics.id(), "expected distinct ids");
+        let actual = genres.create_genre(&genre("FANTASY")).await;
+        assert!(
+            matches!(&actual, Err(CreateGenreError::Duplicate { .. })),
+            "expected duplicate name ignoring case, but got {actual:?}"
+        );
+        let names: Vec<_> = genres
+            .find_all_genres()
+            .await
+            .unwrap()
+            .iter()
+            .map(|genre| genre.name().to_string())
+            .collect();
+        assert_eq!(vec!["Apologetics", "Fantasy"], names);
+
+        for (author, genre) in [
+            (tolkien.id(), fantasy.id()),
+            (lewis.id(), fantasy.id()),
+            (lewis.id(), apologetics.id()),
+            (lewis.id(), apologetics.id()),
+        ] {
+            let req = AuthorGenreRequest::new(author, genre);
+            genres.attach_genre(&req).await.unwrap();
+        }
+        let missing = AuthorId::Integer(404);
+        let actual = genres
+            .attach_genre(&AuthorGenreRequest::new(missing, fantasy.id()))
+            .await;
+        assert!(
+            matches!(&actual, Err(AttachGenreError::AuthorNotFound { id }) if *id == missing),
+            "expected author not found, but got {actual:?}"
+        );
+        let unknown = GenreId::new(404);
+        let actual = genres
+            .attach_genre(&AuthorGenreRequest::new(tolkien.id(), unknown))
+            .await;
+        assert!(
+            matches!(&actual, Err(AttachGenreError::GenreNotFound { id }) if *id == unknown),
+            "expected genre not found, but got {actual:?}"
+        );
+        let found = genres
+            .find_author_genres(&FindAuthorRequest::new(lewis.id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![apologetics.clone(), fantasy.clone()], found);
+        let actual = genres
+            .find_author_genres(&FindAuthorRequest::new(missing))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindAuthorError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let by_genre = |id: GenreId| async move {
+            let req = FindAuthorsByGenreRequest::new(id);
+            let found = genres.find_authors_by_genre(&req).await.unwrap();
+            found.iter().map(Author::id).collect::<Vec<_>>()
+        };
+        assert_eq!(vec![tolkien.id(), lewis.id()], by_genre(fantasy.id()).await);
+        assert_eq!(vec![lewis.id()], by_genre(apologetics.id()).await);
+        assert!(by_genre(unknown).await.is_empty());
+
+        let actual = genres
+            .delete_genre(&DeleteGenreRequest::new(apologetics.id()))
+            .await;
+        assert!(
+            matches!(&actual, Err(DeleteGenreError::InUse { .. })),
+            "expected genre in use, but got {actual:?}"
+        );
+        let req = AuthorGenreRequest::new(lewis.id(), apologetics.id());
+        genres.detach_genre(&req).await.unwrap();
+        let actual = genres.detach_genre(&req).await;
+        assert!(
+            matches!(&actual, Err(DetachGenreError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let req = DeleteGenreRequest::new(apologetics.id());
+        genres.delete_genre(&req).await.unwrap();
+        let actual = genres.delete_genre(&req).await;
+        assert!(
+            matches!(&actual, Err(DeleteGenreError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+
+        authors
+            .delete_author(&DeleteAuthorRequest::new(lewis.id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![tolkien.id()], by_genre(fantasy.id()).await);
+        let req = AuthorGenreRequest::new(tolkien.id(), fantasy.id());
+        genres.detach_genre(&req).await.unwrap();
+        genres
+            .delete_genre(&DeleteGenreRequest::new(fantasy.id()))
+            .await
+            .unwrap();
+        assert!(genres.find_all_genres().await.unwrap().is_empty());
+    }
+
+    pub async fn publisher_repository_contract_tests(
+        authors: &impl AuthorRepository,
+        publishers: &impl PublisherRepository,
+    ) {
+        let tolkien = authors
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let publisher = |name: &str| CreatePublisherRequest::new(PublisherName::new(name).unwrap());
+        let unwin = publishers
+            .create_publisher(&publisher("Allen & Unwin"))
+            .await
+            .unwrap();
+        let harper = publishers
+            .create_publisher(&publisher("HarperCollins"))
+            .await
+            .unwrap();
+        assert_ne!(unwin.id(), harper.id(), "expected distinct ids");
+        let actual = publishers
+            .create_publisher(&publisher("harpercollins"))
+            .await;
+        assert!(
+            matches!(&actual, Err(CreatePublisherError::Duplicate { .. })),
+            "expected duplicate name ignoring case, but got {actual:?}"
+        );
+        let found = publishers
+            .find_publisher(&FindPublisherRequest::new(harper.id()))
+            .await
+            .unwrap();
+        assert_eq!(harper, found);
+        let unknown = PublisherId::new(404);
+        let actual = publishers
+            .find_publisher(&FindPublisherRequest::new(unknown))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindPublisherError::NotFound { id }) if *id == unknown),
+            "expected not found, but got {actual:?}"
+        );
+        let all = publishers.find_all_publishers().await.unwrap();
+        assert_eq!(vec![unwin.clone(), harper.clone()], all);
+
+        let date = |s: &str| s.parse().unwrap();
+        let contract = |publisher_id, starts_on, ends_on: Option<&str>, royalty: &str| {
+            CreateContractRequest::new(
+                tolkien.id(),
+                publisher_id,
+                ContractTerm::new(date(starts_on), ends_on.map(date)).unwrap(),
+                royalty.parse::<RoyaltyPercent>().unwrap(),
+            )
+        };
+        let later = publishers
+            .create_contract(&contract(harper.id(), "1990-01-01", None, "15"))
+            .await
+            .unwrap();
+        let earlier = publishers
+            .create_contract(&contract(
+                unwin.id(),
+                "1937-09-21",
+                Some("1989-12-31"),
+                "12.5",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(tolkien.id(), earlier.author_id());
+        assert_eq!(unwin.id(), earlier.publisher_id());
+        assert_eq!(date("1937-09-21"), earlier.term().starts_on());
+        assert_eq!(Some(date("1989-12-31")), earlier.term().ends_on());
+        assert_eq!("12.50", earlier.royalty().to_string());
+        let found = publishers
+            .find_author_contracts(&FindAuthorRequest::new(tolkien.id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![earlier.clone(), later.clone()], found);
+        let found = publishers
+            .find_publisher_contracts(&FindPublisherRequest::new(harper.id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![later.clone()], found);
+        let actual = publishers
+            .find_author_contracts(&FindAuthorRequest::new(AuthorId::Integer(404)))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindAuthorError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let actual = publishers
+            .find_publisher_contracts(&FindPublisherRequest::new(unknown))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindPublisherError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+
+        let actual = publishers
+            .delete_publisher(&DeletePublisherRequest::new(harper.id()))
+            .await;
+        assert!(
+            matches!(&actual, Err(DeletePublisherError::HasContracts { .. })),
+            "expected publisher with contracts, but got {actual:?}"
+        );
+        let req = DeleteContractRequest::new(tolkien.id(), later.id());
+        publishers.delete_contract(&req).await.unwrap();
+        let actual = publishers.delete_contract(&req).await;
+        assert!(
+            matches!(&actual, Err(DeleteContractError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let req = DeleteContractRequest::new(tolkien.id(), ContractId::new(404));
+        let actual = publishers.delete_contract(&req).await;
+        assert!(
+            matches!(&actual, Err(DeleteContractError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+        let req = DeletePublisherRequest::new(harper.id());
+        publishers.delete_publisher(&req).await.unwrap();
+        let actual = publishers.delete_publisher(&req).await;
+        assert!(
+            matches!(&actual, Err(DeletePublisherError::NotFound { .. })),
+            "expected not found, but got {actual:?}"
+        );
+
+        authors
+            .delete_author(&DeleteAuthorRequest::new(tolkien.id()))
+            .await
+            .unwrap();
+        let found = publishers
+            .find_publisher_contracts(&FindPublisherRequest::new(unwin.id()))
+            .await
+            .unwrap();
+        assert!(
+            found.is_empty(),
+            "expected contracts removed with the author"
+        );
+        publishers
+            .delete_publisher(&DeletePublisherRequest::new(unwin.id()))
+            .await
+            .unwrap();
+    }
+}
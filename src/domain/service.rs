@@ -0,0 +1,2234 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditAction, AuditContext,
+    AuditEntry, Author, AuthorDataExport, AuthorEvent, AuthorGenreRequest, AuthorId, AuthorInclude,
+    AuthorIncludes, AuthorName, AuthorStats, AuthorStatsRequest, AuthorStatus, AuthorWithRelated,
+    Blob, ChangeAuthorStatusError, ChangeAuthorStatusRequest, Contract, CreateAuthorError,
+    CreateAuthorRequest, CreateContractError, CreateContractRequest, CreateGenreError,
+    CreateGenreRequest, CreatePublisherError, CreatePublisherRequest, DeleteAuthorError,
+    DeleteAuthorRequest, DeleteContractError, DeleteContractRequest, DeleteGenreError,
+    DeleteGenreRequest, DeletePublisherError, DeletePublisherRequest, DetachGenreError,
+    EmailVerification, ErasureRecord, ExternalWork, FindAllAuthorsError, FindAllGenresError,
+    FindAllPublishersError, FindAuditLogError, FindAuditLogRequest, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByGenreRequest,
+    FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest, FindAvatarError, FindAvatarRequest,
+    FindChangesRequest, FindExternalWorksError, FindOperationError, FindProjectedAuthorsRequest,
+    FindPublisherError, FindPublisherRequest, FindSortedAuthorsRequest, Genre, GetBlobError,
+    ImportAuthorsError, ImportAuthorsRequest, ImportReport, NamePolicy, Operation, OperationId,
+    OperationKind, ProjectedAuthor, Publisher, PurgeAuthorError, PurgeAuthorRequest,
+    PurgeExpiredError, RecordAuditError, RecordAuditRequest, RecordErasureRequest,
+    RecordSecurityEventRequest, RemoveAuthorAliasError, RemoveAuthorAliasRequest,
+    ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor, RetentionReport,
+    SearchAuthorsRequest, SecurityEvent, SetAuthorStatusRequest, SetEmailVerificationRequest,
+    StartOperationError, UpdateAuthorError, UpdateAuthorRequest, UploadAvatarError,
+    UploadAvatarRequest, UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, BlobStorage, BookCatalogClient, BoxedAuthorRepository,
+    DynAuditRecorder, DynBlobStorage, DynBookCatalogClient, DynEmailVerifier, DynEventPublisher,
+    DynGenreRepository, DynOperationStore, DynPublisherRepository, DynUnitOfWork, EmailVerifier,
+    EventPublisher, GenreRepository, IdGenerator, OperationStore, PublisherRepository,
+    RetentionStore, Transaction, UnitOfWork,
+};
+use chrono::{Days, Utc};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use serde_json::json;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Days of creations covered by [`AuthorService::author_stats`], today included.
+pub const STATS_DAYS: u64 = 30;
+
+/// External works by author name, with the time they were fetched.
+type WorksCache = HashMap<String, (Instant, Vec<ExternalWork>)>;
+
+/// Generic over the author repository so embedders can dispatch to it statically; the default
+/// type-erases it.
+pub struct AuthorService<R = BoxedAuthorRepository> {
+    repo: Arc<R>,
+    audit: Arc<dyn DynAuditRecorder>,
+    uow: Arc<dyn DynUnitOfWork>,
+    events: Arc<dyn DynEventPublisher>,
+    blobs: Arc<dyn DynBlobStorage>,
+    genres: Arc<dyn DynGenreRepository>,
+    publishers: Arc<dyn DynPublisherRepository>,
+    name_policy: Arc<NamePolicy>,
+    create_on_missing: bool,
+    stats_ttl: Duration,
+    stats_cache: Arc<Mutex<Option<(Instant, AuthorStats)>>>,
+    book_catalog: Option<Arc<dyn DynBookCatalogClient>>,
+    works_ttl: Duration,
+    works_cache: Arc<Mutex<WorksCache>>,
+    email_verifier: Option<Arc<dyn DynEmailVerifier>>,
+    verification_requested: Arc<Notify>,
+    operations: Option<Arc<dyn DynOperationStore>>,
+    ids: Option<Arc<dyn IdGenerator>>,
+}
+
+impl<R> Clone for AuthorService<R> {
+    fn clone(&self) -> Self {
+        Self {
+            repo: Arc::clone(&self.repo),
+            audit: Arc::clone(&self.audit),
+            uow: Arc::clone(&self.uow),
+            events: Arc::clone(&self.events),
+            blobs: Arc::clone(&self.blobs),
+            genres: Arc::clone(&self.genres),
+            publishers: Arc::clone(&self.publishers),
+            name_policy: Arc::clone(&self.name_policy),
+            create_on_missing: self.create_on_missing,
+            stats_ttl: self.stats_ttl,
+            stats_cache: Arc::clone(&self.stats_cache),
+            book_catalog: self.book_catalog.clone(),
+            works_ttl: self.works_ttl,
+            works_cache: Arc::clone(&self.works_cache),
+            email_verifier: self.email_verifier.clone(),
+            verification_requested: Arc::clone(&self.verification_requested),
+            operations: self.operations.clone(),
+            ids: self.ids.clone(),
+        }
+    }
+}
+
+impl AuthorService {
+    pub fn new(
+        repo: impl AuthorRepository,
+        audit: impl AuditRecorder,
+        uow: impl UnitOfWork,
+        events: impl EventPublisher,
+        blobs: impl BlobStorage,
+        genres: impl GenreRepository,
+        publishers: impl PublisherRepository,
+    ) -> Self {
+        Self::new_static(
+            BoxedAuthorRepository::new(repo),
+            audit,
+            uow,
+            events,
+            blobs,
+            genres,
+            publishers,
+        )
+    }
+}
+
+impl<R: AuthorRepository> AuthorService<R> {
+    pub fn new_static(
+        repo: R,
+        audit: impl AuditRecorder,
+        uow: impl UnitOfWork,
+        events: impl EventPublisher,
+        blobs: impl BlobStorage,
+        genres: impl GenreRepository,
+        publishers: impl PublisherRepository,
+    ) -> Self {
+        Self {
+            repo: Arc::new(repo),
+            audit: Arc::new(audit),
+            uow: Arc::new(uow),
+            events: Arc::new(events),
+            blobs: Arc::new(blobs),
+            genres: Arc::new(genres),
+            publishers: Arc::new(publishers),
+            name_policy: Arc::new(NamePolicy::default()),
+            create_on_missing: false,
+            stats_ttl: Duration::ZERO,
+            stats_cache: Arc::new(Mutex::new(None)),
+            book_catalog: None,
+            works_ttl: Duration::ZERO,
+            works_cache: Arc::new(Mutex::new(HashMap::new())),
+            email_verifier: None,
+            verification_requested: Arc::new(Notify::new()),
+            operations: None,
+            ids: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_name_policy(mut self, name_policy: NamePolicy) -> Self {
+        self.name_policy = Arc::new(name_policy);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_create_on_missing(mut self, create_on_missing: bool) -> Self {
+        self.create_on_missing = create_on_missing;
+        self
+    }
+
+    /// Serves statistics from memory for `stats_ttl` after computing them. Zero disables caching.
+    #[must_use]
+    pub const fn with_stats_ttl(mut self, stats_ttl: Duration) -> Self {
+        self.stats_ttl = stats_ttl;
+        self
+    }
+
+    /// Looks up works in `catalog`, remembering each author's works for `works_ttl`.
+    #[must_use]
+    pub fn with_book_catalog(
+        mut self,
+        catalog: impl BookCatalogClient,
+        works_ttl: Duration,
+    ) -> Self {
+        self.book_catalog = Some(Arc::new(catalog));
+        self.works_ttl = works_ttl;
+        self
+    }
+
+    /// Keeps background operations in `store`; without one, they cannot be started.
+    #[must_use]
+    pub fn with_operation_store(mut self, store: impl OperationStore) -> Self {
+        self.operations = Some(Arc::new(store));
+        self
+    }
+
+    /// Picks the ids of new authors with `ids`; without one, the repository picks them.
+    #[must_use]
+    pub fn with_id_generator(mut self, ids: impl IdGenerator) -> Self {
+        self.ids = Some(Arc::new(ids));
+        self
+    }
+
+    /// The request with an id from the generator, unless it already has one.
+    fn assign_id<'a>(&self, req: &'a CreateAuthorRequest) -> Cow<'a, CreateAuthorRequest> {
+        let id = req
+            .id()
+            .is_none()
+            .then(|| self.ids.as_ref()?.next_author_id())
+            .flatten();
+        id.map_or(Cow::Borrowed(req), |id| Cow::Owned(req.clone().with_id(id)))
+    }
+
+    pub async fn create_author(
+        &self,
+        req: &CreateAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<Author, CreateAuthorError> {
+        self.name_policy.check(req.name())?;
+        let req = self.assign_id(req);
+        let author = self
+            .transact(|tx| Box::pin(create_author(tx, &req, ctx)))
+            .await?;
+        self.verification_requested.notify_one();
+        self.publish(AuthorEvent::Created(author.clone())).await;
+        Ok(author)
+    }
+
+    pub async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.repo.find_author(req).await
+    }
+
+    /// The related resources asked for are loaded concurrently, one query each however many
+    /// rows they hold, once the author is known to exist.
+    pub async fn find_author_with_related(
+        &self,
+        req: &FindAuthorRequest,
+        includes: &AuthorIncludes,
+    ) -> Result<AuthorWithRelated, FindAuthorError> {
+        let author = self.repo.find_author(req).await?;
+        let (aliases, genres, contracts) = futures::try_join!(
+            related(includes.contains(AuthorInclude::Aliases), || {
+                self.repo.find_author_aliases(req)
+            }),
+            related(includes.contains(AuthorInclude::Genres), || {
+                self.genres.find_author_genres(req)
+            }),
+            related(includes.contains(AuthorInclude::Contracts), || {
+                self.publishers.find_author_contracts(req)
+            }),
+        )?;
+        Ok(AuthorWithRelated::new(author, aliases, genres, contracts))
+    }
+
+    pub async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.repo.find_author_by_email(req).await
+    }
+
+    pub async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.repo.find_all_authors().await
+    }
+
+    pub async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.repo.find_authors_by_ids(req).await
+    }
+
+    pub async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.repo.find_sorted_authors(req).await
+    }
+
+    pub async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.repo.find_projected_authors(req).await
+    }
+
+    pub async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.repo.find_authors_by_verification(req).await
+    }
+
+    pub async fn stream_all_authors(
+        &self,
+    ) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.repo.stream_all_authors().await
+    }
+
+    pub async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.repo.count_authors().await
+    }
+
+    pub async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.repo.author_exists(req).await
+    }
+
+    /// Creates the authors one by one, as [`Self::create_author`] would, skipping those already
+    /// there and those the name policy rejects.
+    pub async fn import_authors(
+        &self,
+        req: &ImportAuthorsRequest,
+        ctx: &AuditContext,
+    ) -> Result<ImportReport, ImportAuthorsError> {
+        self.import(req, ctx, None).await
+    }
+
+    /// Runs [`Self::import_authors`] in a background task and answers right away with the
+    /// operation tracking it, which is saved again after each author.
+    pub async fn start_import_authors(
+        &self,
+        req: ImportAuthorsRequest,
+        ctx: AuditContext,
+    ) -> Result<Operation, StartOperationError> {
+        let Some(store) = self.operations.clone() else {
+            return Err(StartOperationError::Disabled);
+        };
+        let operation = Operation::new(OperationKind::ImportAuthors, Utc::now());
+        store
+            .save_operation(&operation)
+            .await
+            .map_err(|err| err.0)?;
+
+        let service = self.clone();
+        let mut running = operation.clone();
+        tokio::spawn(async move {
+            running.start(Utc::now());
+            let result = match store.save_operation(&running).await {
+                Ok(()) => {
+                    service
+                        .import(&req, &ctx, Some((store.as_ref(), &mut running)))
+                        .await
+                }
+                Err(err) => Err(err.0.into()),
+            };
+            match result {
+                Ok(report) => running.succeed(json!(report), Utc::now()),
+                Err(err) => {
+                    tracing::warn!(id = %running.id(), "Import failed: {:?}", err.0);
+                    running.fail(err.to_string(), Utc::now());
+                }
+            }
+            if let Err(err) = store.save_operation(&running).await {
+                tracing::error!(id = %running.id(), "Failed to save operation: {:?}", err.0);
+            }
+        });
+        Ok(operation)
+    }
+
+    pub const fn runs_operations(&self) -> bool {
+        self.operations.is_some()
+    }
+
+    /// Unknown when no operation store is configured, as then none can have been started.
+    pub async fn find_operation(&self, id: OperationId) -> Result<Operation, FindOperationError> {
+        match &self.operations {
+            Some(store) => store.find_operation(id).await,
+            None => Err(FindOperationError::NotFound { id }),
+        }
+    }
+
+    async fn import(
+        &self,
+        req: &ImportAuthorsRequest,
+        ctx: &AuditContext,
+        mut tracking: Option<(&dyn DynOperationStore, &mut Operation)>,
+    ) -> Result<ImportReport, ImportAuthorsError> {
+        let mut report = ImportReport::default();
+        let total = req.authors().len();
+        for (index, author) in req.authors().iter().enumerate() {
+            match self.create_author(author, ctx).await {
+                Ok(_) => report.record_created(),
+                Err(
+                    CreateAuthorError::Duplicate { .. } | CreateAuthorError::DuplicateEmail { .. },
+                ) => {
+                    report.record_skipped();
+                }
+                Err(CreateAuthorError::InvalidName(_)) => report.record_rejected(),
+                Err(err @ CreateAuthorError::IdTaken { .. }) => {
+                    return Err(anyhow::Error::new(err)
+                        .context(format!("Failed to import author {index}"))
+                        .into());
+                }
+                Err(CreateAuthorError::Other(err)) => {
+                    return Err(err
+                        .context(format!("Failed to import author {index}"))
+                        .into());
+                }
+            }
+            if let Some((store, operation)) = tracking.as_mut() {
+                operation.advance(index + 1, total, Utc::now());
+                store.save_operation(operation).await.map_err(|err| err.0)?;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Without a verifier, addresses stay pending and [`Self::verify_pending_emails`] does nothing.
+    #[must_use]
+    pub fn with_email_verifier(mut self, verifier: impl EmailVerifier) -> Self {
+        self.email_verifier = Some(Arc::new(verifier));
+        self
+    }
+
+    /// Resolves once an author was created or changed address since the last call returned.
+    pub async fn email_verification_requested(&self) {
+        self.verification_requested.notified().await;
+    }
+
+    /// Checks up to `limit` pending addresses, oldest authors first, and returns how many got a
+    /// verdict. Inconclusive checks are logged and left pending for the next run.
+    pub async fn verify_pending_emails(&self, limit: usize) -> Result<usize, FindAllAuthorsError> {
+        let Some(verifier) = &self.email_verifier else {
+            return Ok(0);
+        };
+        let req =
+            FindAuthorsByVerificationRequest::new([EmailVerification::Pending]).with_limit(limit);
+        let mut verified = 0;
+        for author in self.repo.find_authors_by_verification(&req).await? {
+            let verification = match verifier.verify_email(author.email()).await {
+                Ok(EmailVerification::Pending) => continue,
+                Ok(verification) => verification,
+                Err(err) => {
+                    tracing::warn!(id = %author.id(), "Email verification failed: {:?}", err.0);
+                    continue;
+                }
+            };
+            metrics::counter!("email_verifications_total", "outcome" => verification.as_str())
+                .increment(1);
+            let req =
+                SetEmailVerificationRequest::new(author.id(), author.email().clone(), verification);
+            match self.repo.set_email_verification(&req).await {
+                Ok(()) => verified += 1,
+                Err(err) => tracing::warn!(id = %author.id(), "{:?}", err.0),
+            }
+        }
+        Ok(verified)
+    }
+
+    /// Works are cached by author name, so renaming an author looks them up again.
+    pub async fn find_external_works(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<ExternalWork>, FindExternalWorksError> {
+        let Some(catalog) = &self.book_catalog else {
+            return Err(FindExternalWorksError::Disabled);
+        };
+        let author = self.repo.find_author(req).await?;
+        let key = author.name().to_string();
+        let cached = self
+            .works_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .cloned();
+        if let Some((fetched_at, works)) = cached
+            && fetched_at.elapsed() < self.works_ttl
+        {
+            return Ok(works);
+        }
+
+        let works = catalog.find_works(author.name()).await?;
+        let mut cache = self
+            .works_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < self.works_ttl);
+        cache.insert(key, (Instant::now(), works.clone()));
+        Ok(works)
+    }
+
+    /// Creations per day cover the last [`STATS_DAYS`] UTC days, with zero for quiet days.
+    pub async fn author_stats(&self) -> Result<AuthorStats, FindAllAuthorsError> {
+        let cached = self
+            .stats_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some((computed_at, stats)) = cached
+            && computed_at.elapsed() < self.stats_ttl
+        {
+            return Ok(stats);
+        }
+
+        let today = Utc::now().date_naive();
+        let since = today - Days::new(STATS_DAYS - 1);
+        let stats = self
+            .repo
+            .author_stats(&AuthorStatsRequest::new(since))
+            .await?;
+        let counts: HashMap<_, _> = stats.created_per_day().iter().copied().collect();
+        let created_per_day = since
+            .iter_days()
+            .take_while(|day| *day <= today)
+            .map(|day| (day, counts.get(&day).copied().unwrap_or_default()))
+            .collect();
+        let stats = stats.with_created_per_day(created_per_day);
+        *self
+            .stats_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some((Instant::now(), stats.clone()));
+        Ok(stats)
+    }
+
+    pub async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.repo.search_authors(req).await
+    }
+
+    pub async fn update_author(
+        &self,
+        req: &UpdateAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<Author, UpdateAuthorError> {
+        if let Some(name) = req.name() {
+            self.name_policy.check(name)?;
+        }
+        let author = self
+            .transact(|tx| Box::pin(update_author(tx, req, ctx)))
+            .await?;
+        if req.email().is_some() {
+            self.verification_requested.notify_one();
+        }
+        self.publish(AuthorEvent::Updated(author.clone())).await;
+        Ok(author)
+    }
+
+    pub async fn replace_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<ReplacedAuthor, ReplaceAuthorError> {
+        self.name_policy.check(req.name())?;
+        let replaced = self
+            .transact(|tx| Box::pin(replace_author(tx, req, ctx, self.create_on_missing)))
+            .await?;
+        self.verification_requested.notify_one();
+        let event = match &replaced {
+            ReplacedAuthor::Created(author) => AuthorEvent::Created(author.clone()),
+            ReplacedAuthor::Replaced(author) => AuthorEvent::Updated(author.clone()),
+        };
+        self.publish(event).await;
+        Ok(replaced)
+    }
+
+    /// Creates the author, or replaces the name and profile of the one with the same email.
+    pub async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.name_policy.check(req.name())?;
+        let req = self.assign_id(req);
+        let upserted = self
+            .transact(|tx| Box::pin(upsert_author_by_email(tx, &req, ctx)))
+            .await?;
+        let event = match &upserted {
+            ReplacedAuthor::Created(author) => {
+                self.verification_requested.notify_one();
+                AuthorEvent::Created(author.clone())
+            }
+            ReplacedAuthor::Replaced(author) => AuthorEvent::Updated(author.clone()),
+        };
+        self.publish(event).await;
+        Ok(upserted)
+    }
+
+    pub async fn change_author_status(
+        &self,
+        req: &ChangeAuthorStatusRequest,
+        ctx: &AuditContext,
+    ) -> Result<Author, ChangeAuthorStatusError> {
+        let author = self
+            .transact(|tx| Box::pin(change_author_status(tx, req, ctx)))
+            .await?;
+        self.publish(AuthorEvent::Updated(author.clone())).await;
+        Ok(author)
+    }
+
+    pub async fn delete_author(
+        &self,
+        req: &DeleteAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), DeleteAuthorError> {
+        self.transact(|tx| Box::pin(delete_author(tx, req, ctx)))
+            .await?;
+        self.publish(AuthorEvent::Deleted { id: req.id() }).await;
+        Ok(())
+    }
+
+    pub async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        self.audit.find_audit_log(req).await
+    }
+
+    pub async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        self.audit.find_changes(req).await
+    }
+
+    pub async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        self.audit.latest_change_id().await
+    }
+
+    pub async fn export_author_data(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<AuthorDataExport, FindAuthorError> {
+        let author = self.repo.find_author(req).await?;
+        let aliases = self.repo.find_author_aliases(req).await?;
+        let genres = self.genres.find_author_genres(req).await?;
+        let contracts = self.publishers.find_author_contracts(req).await?;
+        let audit_log = self
+            .audit
+            .find_audit_log(&FindAuditLogRequest::new(req.id()))
+            .await
+            .map_err(|err| err.0)?;
+        Ok(AuthorDataExport::new(
+            author, aliases, genres, contracts, audit_log,
+        ))
+    }
+
+    /// Deletes the author and their avatar, strips their data from the audit log and records the
+    /// purge in the erasure log. Purging an author that is already gone but still has audit
+    /// entries erases those, so a purge that failed part way can be repeated.
+    pub async fn purge_author(
+        &self,
+        req: &PurgeAuthorRequest,
+        ctx: &AuditContext,
+    ) -> Result<ErasureRecord, PurgeAuthorError> {
+        let (record, deleted) = self
+            .transact(|tx| Box::pin(purge_author(tx, req, ctx)))
+            .await?;
+        self.blobs
+            .delete(&avatar_key(req.id()))
+            .await
+            .map_err(|err| err.0)?;
+        if deleted {
+            self.publish(AuthorEvent::Deleted { id: req.id() }).await;
+        }
+        Ok(record)
+    }
+
+    pub async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        self.audit.find_erasures().await
+    }
+
+    pub async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        self.audit.record_security_event(req).await
+    }
+
+    /// The latest security events, newest first.
+    pub async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        self.audit.find_security_events(limit).await
+    }
+
+    pub async fn upload_avatar(
+        &self,
+        req: &UploadAvatarRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), UploadAvatarError> {
+        let author = self
+            .transact(|tx| Box::pin(upload_avatar(tx, self.blobs.as_ref(), req, ctx)))
+            .await?;
+        self.publish(AuthorEvent::Updated(author)).await;
+        Ok(())
+    }
+
+    pub async fn find_avatar(&self, req: &FindAvatarRequest) -> Result<Blob, FindAvatarError> {
+        self.blobs
+            .get(&avatar_key(req.author_id()))
+            .await
+            .map_err(|err| match err {
+                GetBlobError::NotFound { .. } => FindAvatarError::NotFound {
+                    id: req.author_id(),
+                },
+                GetBlobError::Other(err) => FindAvatarError::Other(err),
+            })
+    }
+
+    pub async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.name_policy.check(req.alias())?;
+        self.transact(|tx| Box::pin(add_author_alias(tx, req, ctx)))
+            .await
+    }
+
+    pub async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.transact(|tx| Box::pin(remove_author_alias(tx, req, ctx)))
+            .await
+    }
+
+    pub async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.repo.find_author_aliases(req).await
+    }
+
+    pub async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        self.genres.create_genre(req).await
+    }
+
+    pub async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        self.genres.find_all_genres().await
+    }
+
+    pub async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        self.genres.delete_genre(req).await
+    }
+
+    pub async fn attach_genre(
+        &self,
+        req: &AuthorGenreRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), AttachGenreError> {
+        self.transact(|tx| Box::pin(attach_genre(tx, req, ctx)))
+            .await
+    }
+
+    pub async fn detach_genre(
+        &self,
+        req: &AuthorGenreRequest,
+        ctx: &AuditContext,
+    ) -> Result<(), DetachGenreError> {
+        self.transact(|tx| Box::pin(detach_genre(tx, req, ctx)))
+            .await
+    }
+
+    pub async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        self.genres.find_author_genres(req).await
+    }
+
+    pub async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.genres.find_authors_by_genre(req).await
+    }
+
+    pub async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        self.publishers.create_publisher(req).await
+    }
+
+    pub async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        self.publishers.find_publisher(req).await
+    }
+
+    pub async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        self.publishers.find_all_publishers().await
+    }
+
+    pub async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        self.publishers.delete_publisher(req).await
+    }
+
+    /// Signs a contract, provided the author is not archived and has no other contract with the
+    /// same publisher whose term overlaps.
+    pub async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        self.transact(|tx| Box::pin(create_contract(tx, req))).await
+    }
+
+    pub async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        self.publishers.find_author_contracts(req).await
+    }
+
+    pub async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        self.publishers.find_publisher_contracts(req).await
+    }
+
+    pub async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        self.publishers.delete_contract(req).await
+    }
+
+    /// Runs `work` in a transaction, committing it on success. A unit of work that fails with an
+    /// unexpected error is run again in a new transaction for as long as the unit of work allows.
+    /// `'a` lets `work` borrow from the caller while the transaction is lent to it.
+    async fn transact<'a, T, E>(
+        &self,
+        work: impl for<'t> Fn(&'t (dyn Transaction + 'a)) -> BoxFuture<'t, Result<T, E>>,
+    ) -> Result<T, E>
+    where
+        E: From<anyhow::Error> + TransactionError,
+    {
+        let mut attempt = 1;
+        loop {
+            let result = match self.uow.begin().await {
+                Ok(tx) => {
+                    let result = work(tx.as_ref()).await;
+                    complete(tx, result).await
+                }
+                Err(err) => Err(err.into()),
+            };
+            let delay = match &result {
+                Err(err) => err
+                    .unexpected()
+                    .and_then(|err| self.uow.retry_delay(attempt, err)),
+                Ok(_) => None,
+            };
+            let Some(delay) = delay else {
+                return result;
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn publish(&self, event: AuthorEvent) {
+        if let Err(err) = self.events.publish(&event).await {
+            tracing::error!("{:?}", err.0);
+        }
+    }
+}
+
+async fn create_author(
+    tx: &dyn Transaction,
+    req: &CreateAuthorRequest,
+    ctx: &AuditContext,
+) -> Result<Author, CreateAuthorError> {
+    let author = tx.authors().create_author(req).await?;
+
+    let audit = RecordAuditRequest::new(
+        author.id(),
+        AuditAction::Create,
+        ctx.clone(),
+        None,
+        Some(snapshot(&author)),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(author)
+}
+
+async fn update_author(
+    tx: &dyn Transaction,
+    req: &UpdateAuthorRequest,
+    ctx: &AuditContext,
+) -> Result<Author, UpdateAuthorError> {
+    let find = FindAuthorRequest::new(req.id());
+    let before = tx.authors().find_author(&find).await?;
+    if let Some(since) = req.unmodified_since()
+        && before.is_modified_since(since)
+    {
+        return Err(UpdateAuthorError::PreconditionFailed { id: req.id() });
+    }
+    if before.status() == AuthorStatus::Archived {
+        return Err(UpdateAuthorError::Archived { id: req.id() });
+    }
+    let after = tx.authors().update_author(req).await?;
+
+    let audit = RecordAuditRequest::new(
+        req.id(),
+        AuditAction::Update,
+        ctx.clone(),
+        Some(snapshot(&before)),
+        Some(snapshot(&after)),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(after)
+}
+
+async fn replace_author(
+    tx: &dyn Transaction,
+    req: &ReplaceAuthorRequest,
+    ctx: &AuditContext,
+    create_on_missing: bool,
+) -> Result<ReplacedAuthor, ReplaceAuthorError> {
+    let find = FindAuthorRequest::new(req.id());
+    let before = match tx.authors().find_author(&find).await {
+        Ok(author) => Some(author),
+        Err(FindAuthorError::NotFound { .. }) if create_on_missing => None,
+        Err(FindAuthorError::NotFound { id }) => return Err(ReplaceAuthorError::NotFound { id }),
+        Err(FindAuthorError::Other(err)) => return Err(ReplaceAuthorError::Other(err)),
+    };
+    if let (Some(before), Some(since)) = (&before, req.unmodified_since())
+        && before.is_modified_since(since)
+    {
+        return Err(ReplaceAuthorError::PreconditionFailed { id: req.id() });
+    }
+    if before
+        .as_ref()
+        .is_some_and(|before| before.status() == AuthorStatus::Archived)
+    {
+        return Err(ReplaceAuthorError::Archived { id: req.id() });
+    }
+    let after = tx.authors().upsert_author(req).await?;
+
+    let action = if before.is_some() {
+        AuditAction::Update
+    } else {
+        AuditAction::Create
+    };
+    let audit = RecordAuditRequest::new(
+        req.id(),
+        action,
+        ctx.clone(),
+        before.as_ref().map(snapshot),
+        Some(snapshot(&after)),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(match before {
+        Some(_) => ReplacedAuthor::Replaced(after),
+        None => ReplacedAuthor::Created(after),
+    })
+}
+
+async fn upsert_author_by_email(
+    tx: &dyn Transaction,
+    req: &CreateAuthorRequest,
+    ctx: &AuditContext,
+) -> Result<ReplacedAuthor, UpsertAuthorError> {
+    let find = FindAuthorByEmailRequest::new(req.email().clone());
+    let before = match tx.authors().find_author_by_email(&find).await {
+        Ok(author) => Some(author),
+        Err(FindAuthorByEmailError::NotFound { .. }) => None,
+        Err(FindAuthorByEmailError::Other(err)) => return Err(UpsertAuthorError::Other(err)),
+    };
+    if let Some(before) = &before
+        && before.status() == AuthorStatus::Archived
+    {
+        return Err(UpsertAuthorError::Archived { id: before.id() });
+    }
+    let upserted = tx.authors().upsert_author_by_email(req).await?;
+
+    let action = match upserted {
+        ReplacedAuthor::Created(_) => AuditAction::Create,
+        ReplacedAuthor::Replaced(_) => AuditAction::Update,
+    };
+    let after = upserted.author();
+    let audit = RecordAuditRequest::new(
+        after.id(),
+        action,
+        ctx.clone(),
+        before.as_ref().map(snapshot),
+        Some(snapshot(after)),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(upserted)
+}
+
+async fn change_author_status(
+    tx: &dyn Transaction,
+    req: &ChangeAuthorStatusRequest,
+    ctx: &AuditContext,
+) -> Result<Author, ChangeAuthorStatusError> {
+    let find = FindAuthorRequest::new(req.id());
+    let before = tx.authors().find_author(&find).await?;
+    let status = before.status().transition(req.transition())?;
+    let set = SetAuthorStatusRequest::new(req.id(), status);
+    tx.authors().set_author_status(&set).await?;
+    let after = tx.authors().find_author(&find).await?;
+
+    let audit = RecordAuditRequest::new(
+        req.id(),
+        AuditAction::Update,
+        ctx.clone(),
+        Some(snapshot(&before)),
+        Some(snapshot(&after)),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(after)
+}
+
+async fn delete_author(
+    tx: &dyn Transaction,
+    req: &DeleteAuthorRequest,
+    ctx: &AuditContext,
+) -> Result<(), DeleteAuthorError> {
+    let find = FindAuthorRequest::new(req.id());
+    let before = tx.authors().find_author(&find).await?;
+    if let Some(since) = req.unmodified_since()
+        && before.is_modified_since(since)
+    {
+        return Err(DeleteAuthorError::PreconditionFailed { id: req.id() });
+    }
+    tx.authors().delete_author(req).await?;
+
+    let audit = RecordAuditRequest::new(
+        req.id(),
+        AuditAction::Delete,
+        ctx.clone(),
+        Some(snapshot(&before)),
+        None,
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+
+    Ok(())
+}
+
+/// Returns the erasure record and whether the author still existed.
+async fn purge_author(
+    tx: &dyn Transaction,
+    req: &PurgeAuthorRequest,
+    ctx: &AuditContext,
+) -> Result<(ErasureRecord, bool), PurgeAuthorError> {
+    let find = FindAuthorRequest::new(req.id());
+    let deleted = match tx.authors().find_author(&find).await {
+        Ok(_) => true,
+        Err(FindAuthorError::NotFound { .. }) => false,
+        Err(err) => return Err(err.into()),
+    };
+    if deleted {
+        let delete = DeleteAuthorRequest::new(req.id());
+        tx.authors()
+            .delete_author(&delete)
+            .await
+            .map_err(anyhow::Error::from)?;
+    }
+
+    let audit = FindAuditLogRequest::new(req.id());
+    let anonymized = tx
+        .audit()
+        .erase_audit_snapshots(&audit)
+        .await
+        .map_err(|err| err.0)?;
+    if !deleted && anonymized == 0 {
+        return Err(PurgeAuthorError::NotFound { id: req.id() });
+    }
+    if deleted {
+        let audit = RecordAuditRequest::new(req.id(), AuditAction::Delete, ctx.clone(), None, None);
+        tx.audit().record(&audit).await.map_err(|err| err.0)?;
+    }
+
+    let erasure = RecordErasureRequest::new(req.id(), ctx.clone(), anonymized);
+    let record = tx
+        .audit()
+        .record_erasure(&erasure)
+        .await
+        .map_err(|err| err.0)?;
+
+    Ok((record, deleted))
+}
+
+async fn add_author_alias(
+    tx: &dyn Transaction,
+    req: &AddAuthorAliasRequest,
+    ctx: &AuditContext,
+) -> Result<(), AddAuthorAliasError> {
+    let find = FindAuthorRequest::new(req.author_id());
+    let author = tx.authors().find_author(&find).await?;
+    if author.status() == AuthorStatus::Archived {
+        return Err(AddAuthorAliasError::Archived { id: author.id() });
+    }
+    let before = tx.authors().find_author_aliases(&find).await?;
+    tx.authors().add_author_alias(req).await?;
+    let after = tx
+        .authors()
+        .find_author_aliases(&find)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    let (before, after) = (aliases_snapshot(&before), aliases_snapshot(&after));
+    record_update(tx, req.author_id(), ctx, before, after).await?;
+
+    Ok(())
+}
+
+async fn remove_author_alias(
+    tx: &dyn Transaction,
+    req: &RemoveAuthorAliasRequest,
+    ctx: &AuditContext,
+) -> Result<(), RemoveAuthorAliasError> {
+    let find = FindAuthorRequest::new(req.author_id());
+    let before = match tx.authors().find_author_aliases(&find).await {
+        Ok(aliases) => aliases,
+        Err(FindAuthorError::NotFound { id }) => {
+            let alias = req.alias().clone();
+            return Err(RemoveAuthorAliasError::NotFound { id, alias });
+        }
+        Err(FindAuthorError::Other(err)) => return Err(err.into()),
+    };
+    tx.authors().remove_author_alias(req).await?;
+    let after = tx
+        .authors()
+        .find_author_aliases(&find)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    let (before, after) = (aliases_snapshot(&before), aliases_snapshot(&after));
+    record_update(tx, req.author_id(), ctx, before, after).await?;
+
+    Ok(())
+}
+
+async fn attach_genre(
+    tx: &dyn Transaction,
+    req: &AuthorGenreRequest,
+    ctx: &AuditContext,
+) -> Result<(), AttachGenreError> {
+    let find = FindAuthorRequest::new(req.author_id());
+    let author = tx.authors().find_author(&find).await?;
+    if author.status() == AuthorStatus::Archived {
+        return Err(AttachGenreError::Archived { id: author.id() });
+    }
+    let before = tx.genres().find_author_genres(&find).await?;
+    tx.genres().attach_genre(req).await?;
+    let after = tx
+        .genres()
+        .find_author_genres(&find)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    let (before, after) = (genres_snapshot(&before), genres_snapshot(&after));
+    record_update(tx, req.author_id(), ctx, before, after).await?;
+
+    Ok(())
+}
+
+async fn detach_genre(
+    tx: &dyn Transaction,
+    req: &AuthorGenreRequest,
+    ctx: &AuditContext,
+) -> Result<(), DetachGenreError> {
+    let find = FindAuthorRequest::new(req.author_id());
+    let author = match tx.authors().find_author(&find).await {
+        Ok(author) => author,
+        Err(FindAuthorError::NotFound { id }) => {
+            let genre_id = req.genre_id();
+            return Err(DetachGenreError::NotFound {
+                author_id: id,
+                genre_id,
+            });
+        }
+        Err(FindAuthorError::Other(err)) => return Err(err.into()),
+    };
+    if author.status() == AuthorStatus::Archived {
+        return Err(DetachGenreError::Archived { id: author.id() });
+    }
+    let before = tx
+        .genres()
+        .find_author_genres(&find)
+        .await
+        .map_err(anyhow::Error::from)?;
+    tx.genres().detach_genre(req).await?;
+    let after = tx
+        .genres()
+        .find_author_genres(&find)
+        .await
+        .map_err(anyhow::Error::from)?;
+
+    let (before, after) = (genres_snapshot(&before), genres_snapshot(&after));
+    record_update(tx, req.author_id(), ctx, before, after).await?;
+
+    Ok(())
+}
+
+/// Records an update whose snapshots only hold what it changed, such as an author's aliases.
+/// The blob is stored last, so it is only replaced once the audit entry is recorded.
+async fn upload_avatar(
+    tx: &dyn Transaction,
+    blobs: &dyn DynBlobStorage,
+    req: &UploadAvatarRequest,
+    ctx: &AuditContext,
+) -> Result<Author, UploadAvatarError> {
+    let author = tx
+        .authors()
+        .find_author(&FindAuthorRequest::new(req.author_id()))
+        .await?;
+    if author.status() == AuthorStatus::Archived {
+        return Err(UploadAvatarError::Archived { id: author.id() });
+    }
+    let key = avatar_key(req.author_id());
+    let before = match blobs.get(&key).await {
+        Ok(blob) => Some(blob),
+        Err(GetBlobError::NotFound { .. }) => None,
+        Err(GetBlobError::Other(err)) => return Err(err.into()),
+    };
+
+    let (before, after) = (
+        avatar_snapshot(before.as_ref()),
+        avatar_snapshot(Some(req.image().blob())),
+    );
+    record_update(tx, req.author_id(), ctx, before, after).await?;
+    blobs
+        .put(&key, req.image().blob())
+        .await
+        .map_err(|err| err.0)?;
+
+    Ok(author)
+}
+
+async fn record_update(
+    tx: &dyn Transaction,
+    id: AuthorId,
+    ctx: &AuditContext,
+    before: serde_json::Value,
+    after: serde_json::Value,
+) -> anyhow::Result<()> {
+    let audit = RecordAuditRequest::new(
+        id,
+        AuditAction::Update,
+        ctx.clone(),
+        Some(before),
+        Some(after),
+    );
+    tx.audit().record(&audit).await.map_err(|err| err.0)?;
+    Ok(())
+}
+
+fn aliases_snapshot(aliases: &[AuthorName]) -> serde_json::Value {
+    let aliases: Vec<_> = aliases.iter().map(AuthorName::as_str).collect();
+    json!({ "aliases": aliases })
+}
+
+fn avatar_snapshot(avatar: Option<&Blob>) -> serde_json::Value {
+    let avatar = avatar.map(
+        |avatar| json!({ "content_type": avatar.content_type(), "size": avatar.bytes().len() }),
+    );
+    json!({ "avatar": avatar })
+}
+
+fn genres_snapshot(genres: &[Genre]) -> serde_json::Value {
+    let genres: Vec<_> = genres
+        .iter()
+        .map(|genre| genre.name().to_string())
+        .collect();
+    json!({ "genres": genres })
+}
+
+fn avatar_key(author_id: AuthorId) -> String {
+    format!("avatars/{author_id}")
+}
+
+fn snapshot(author: &Author) -> serde_json::Value {
+    json!({
+        "id": author.id(),
+        "name": author.name().to_string(),
+        "email": author.email().to_string(),
+        "status": author.status().as_str(),
+        "bio": author.profile().bio().map(ToString::to_string),
+        "birth_date": author.profile().birth_date().map(|date| date.to_string()),
+        "website_url": author.profile().website().map(ToString::to_string),
+        "country": author.profile().country().map(|code| code.to_string()),
+    })
+}
+
+async fn create_contract(
+    tx: &dyn Transaction,
+    req: &CreateContractRequest,
+) -> Result<Contract, CreateContractError> {
+    let find = FindAuthorRequest::new(req.author_id());
+    let author = tx.authors().find_author(&find).await?;
+    if author.status() == AuthorStatus::Archived {
+        return Err(CreateContractError::AuthorArchived { id: author.id() });
+    }
+    let find_publisher = FindPublisherRequest::new(req.publisher_id());
+    tx.publishers().find_publisher(&find_publisher).await?;
+    let overlapping = tx
+        .publishers()
+        .find_author_contracts(&find)
+        .await?
+        .iter()
+        .any(|contract| {
+            contract.publisher_id() == req.publisher_id() && contract.term().overlaps(req.term())
+        });
+    if overlapping {
+        return Err(CreateContractError::Overlapping {
+            author_id: req.author_id(),
+            publisher_id: req.publisher_id(),
+        });
+    }
+
+    tx.publishers().create_contract(req).await
+}
+
+/// Loads a related resource only if it was asked for.
+async fn related<T, F: Future<Output = Result<T, FindAuthorError>>>(
+    wanted: bool,
+    load: impl FnOnce() -> F,
+) -> Result<Option<T>, FindAuthorError> {
+    if wanted {
+        load().await.map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Purges rows past their retention window every `interval`, counting them in
+/// `retention_purged_rows_total` by table.
+pub struct RetentionJob<S> {
+    store: S,
+    interval: Duration,
+}
+
+impl<S: RetentionStore> RetentionJob<S> {
+    #[must_use]
+    pub const fn new(store: S, interval: Duration) -> Self {
+        Self { store, interval }
+    }
+
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.interval);
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(report) if report.total() > 0 => tracing::info!(
+                    archived_authors = report.archived_authors(),
+                    audit_log = report.audit_log(),
+                    processed_commands = report.processed_commands(),
+                    "Purged expired rows"
+                ),
+                Ok(_) => {}
+                Err(err) => tracing::error!("Scheduled retention purge failed: {err:?}"),
+            }
+        }
+    }
+
+    pub async fn run_once(&self) -> Result<RetentionReport, PurgeExpiredError> {
+        let report = self.store.purge_expired(Utc::now()).await?;
+        for (table, purged) in [
+            ("author", report.archived_authors()),
+            ("audit_log", report.audit_log()),
+            ("processed_command", report.processed_commands()),
+        ] {
+            metrics::counter!("retention_purged_rows_total", "table" => table).increment(purged);
+        }
+        Ok(report)
+    }
+}
+
+/// Errors of a unit of work, which may be retried when they are unexpected.
+trait TransactionError {
+    fn unexpected(&self) -> Option<&anyhow::Error>;
+}
+
+impl TransactionError for CreateAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for UpdateAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for ReplaceAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for UpsertAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for ChangeAuthorStatusError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for DeleteAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for PurgeAuthorError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for UploadAvatarError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for AddAuthorAliasError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for RemoveAuthorAliasError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for AttachGenreError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for DetachGenreError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl TransactionError for CreateContractError {
+    fn unexpected(&self) -> Option<&anyhow::Error> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+async fn complete<T, E>(tx: Box<dyn Transaction>, result: Result<T, E>) -> Result<T, E>
+where
+    E: From<anyhow::Error>,
+{
+    match result {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(err) => {
+            if let Err(rollback_err) = tx.rollback().await {
+                tracing::warn!("{rollback_err:?}");
+            }
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuthorGenreRequest,
+        CreateGenreRequest, DetachGenreError, GenreName, RemoveAuthorAliasRequest,
+    };
+    use crate::domain::model::{
+        AuditAction, AuditContext, AuthorEvent, AuthorId, AuthorName, AuthorStats, AuthorStatus,
+        AuthorTransition, AvatarImage, ChangeAuthorStatusError, ChangeAuthorStatusRequest,
+        ContractTerm, CreateAuthorError, CreateAuthorRequest, CreateContractError,
+        CreateContractRequest, CreatePublisherRequest, DeleteAuthorRequest, EmailAddress,
+        ExternalWork, FindAuditLogRequest, FindAuthorRequest, FindAvatarError, FindAvatarRequest,
+        FindExternalWorksError, NamePolicy, PublisherId, PublisherName, ReplaceAuthorError,
+        ReplaceAuthorRequest, ReplacedAuthor, RoyaltyPercent, UpdateAuthorError,
+        UpdateAuthorRequest, UploadAvatarError, UploadAvatarRequest,
+    };
+    use crate::domain::model::{
+        EmailVerification, ErasureRecord, FindAuthorError, PurgeAuthorError, PurgeAuthorRequest,
+        VerifyEmailError,
+    };
+    use crate::domain::ports::{BookCatalogClient, EmailVerifier};
+    use crate::domain::service::{AuthorService, STATS_DAYS};
+    use crate::outbound::events::LogEventPublisher;
+    use crate::outbound::ids::SequentialIds;
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use chrono::{Days, Utc};
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use url::Url;
+
+    #[tokio::test]
+    async fn new_authors_get_ids_from_the_generator() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        )
+        .with_id_generator(SequentialIds::starting_at(1_000));
+        let ctx = AuditContext::new("admin".into(), None);
+
+        let create = |name: &str, email: &str| {
+            CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            )
+        };
+        let tolkien = create("JRR Tolkien", "jrr.tolkien@example.com");
+        let tolkien = service.create_author(&tolkien, &ctx).await.unwrap();
+        assert_eq!(AuthorId::new(1_000), tolkien.id());
+        let lewis = create("CS Lewis", "cs.lewis@example.com");
+        let upserted = service.upsert_author_by_email(&lewis, &ctx).await.unwrap();
+        assert_eq!(AuthorId::new(1_001), upserted.author().id());
+        let chosen = create("Ursula K. Le Guin", "ursula@example.com").with_id(AuthorId::new(7));
+        let le_guin = service.create_author(&chosen, &ctx).await.unwrap();
+        assert_eq!(AuthorId::new(7), le_guin.id());
+    }
+
+    #[tokio::test]
+    async fn mutations_are_recorded_in_audit_log() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let ctx = AuditContext::new("admin".into(), Some("req-1".into()));
+
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let update = UpdateAuthorRequest::builder(author.id())
+            .name(AuthorName::new("J.R.R. Tolkien").unwrap())
+            .build()
+            .unwrap();
+        service.update_author(&update, &ctx).await.unwrap();
+        let delete = DeleteAuthorRequest::new(author.id());
+        service.delete_author(&delete, &ctx).await.unwrap();
+
+        let entries = service
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        let actions: Vec<_> = entries.iter().map(|entry| entry.action()).collect();
+        assert_eq!(
+            vec![
+                AuditAction::Create,
+                AuditAction::Update,
+                AuditAction::Delete
+            ],
+            actions,
+            "unexpected audit actions"
+        );
+        let update = &entries[1];
+        assert_eq!(Some("req-1"), update.context().request_id());
+        assert_eq!(
+            Some("JRR Tolkien"),
+            update.before().and_then(|before| before["name"].as_str())
+        );
+        assert_eq!(
+            Some("J.R.R. Tolkien"),
+            update.after().and_then(|after| after["name"].as_str())
+        );
+
+        let events: Vec<_> = repo
+            .published_events()
+            .await
+            .iter()
+            .map(AuthorEvent::name)
+            .collect();
+        assert_eq!(
+            vec!["created", "updated", "deleted"],
+            events,
+            "unexpected published events"
+        );
+    }
+
+    #[tokio::test]
+    async fn alias_and_genre_changes_are_recorded_in_audit_log() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("CS Lewis").unwrap(),
+            EmailAddress::new("cs.lewis@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let genre = service
+            .create_genre(&CreateGenreRequest::new(GenreName::new("Fantasy").unwrap()))
+            .await
+            .unwrap();
+
+        let alias = AuthorName::new("Clive Hamilton").unwrap();
+        let add = AddAuthorAliasRequest::new(author.id(), alias.clone());
+        service.add_author_alias(&add, &ctx).await.unwrap();
+        let remove = RemoveAuthorAliasRequest::new(author.id(), alias);
+        service.remove_author_alias(&remove, &ctx).await.unwrap();
+        let genre = AuthorGenreRequest::new(author.id(), genre.id());
+        service.attach_genre(&genre, &ctx).await.unwrap();
+        service.detach_genre(&genre, &ctx).await.unwrap();
+
+        let entries = service
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        let changes: Vec<_> = entries[1..]
+            .iter()
+            .map(|entry| {
+                (
+                    entry.action(),
+                    entry.before().cloned(),
+                    entry.after().cloned(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    AuditAction::Update,
+                    Some(json!({ "aliases": [] })),
+                    Some(json!({ "aliases": ["Clive Hamilton"] }))
+                ),
+                (
+                    AuditAction::Update,
+                    Some(json!({ "aliases": ["Clive Hamilton"] })),
+                    Some(json!({ "aliases": [] }))
+                ),
+                (
+                    AuditAction::Update,
+                    Some(json!({ "genres": [] })),
+                    Some(json!({ "genres": ["Fantasy"] }))
+                ),
+                (
+                    AuditAction::Update,
+                    Some(json!({ "genres": ["Fantasy"] })),
+                    Some(json!({ "genres": [] }))
+                ),
+            ],
+            changes
+        );
+    }
+
+    #[tokio::test]
+    async fn avatars_are_stored_per_author() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let image = AvatarImage::new(b"GIF89a\x01\x00\x01\x00".to_vec()).unwrap();
+
+        let missing = service
+            .find_avatar(&FindAvatarRequest::new(author.id()))
+            .await;
+        assert!(matches!(missing, Err(FindAvatarError::NotFound { .. })));
+
+        let unknown = service
+            .upload_avatar(
+                &UploadAvatarRequest::new(AuthorId::new_v7(), image.clone()),
+                &ctx,
+            )
+            .await;
+        assert!(matches!(unknown, Err(UploadAvatarError::NotFound { .. })));
+
+        service
+            .upload_avatar(&UploadAvatarRequest::new(author.id(), image.clone()), &ctx)
+            .await
+            .unwrap();
+        let blob = service
+            .find_avatar(&FindAvatarRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!(image.blob(), &blob);
+        assert_eq!("image/gif", blob.content_type());
+
+        let entries = service
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        let upload = entries.last().unwrap();
+        assert_eq!(AuditAction::Update, upload.action());
+        assert_eq!(Some(&json!({ "avatar": null })), upload.before());
+        assert_eq!(
+            Some("image/gif"),
+            upload
+                .after()
+                .and_then(|after| after["avatar"]["content_type"].as_str())
+        );
+        let events: Vec<_> = repo
+            .published_events()
+            .await
+            .iter()
+            .map(AuthorEvent::name)
+            .collect();
+        assert_eq!(vec!["created", "updated"], events);
+    }
+
+    #[tokio::test]
+    async fn purging_erases_the_author_and_their_audit_snapshots() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let ctx = AuditContext::new("admin".into(), Some("req-1".into()));
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let image = AvatarImage::new(b"GIF89a\x01\x00\x01\x00".to_vec()).unwrap();
+        service
+            .upload_avatar(&UploadAvatarRequest::new(author.id(), image), &ctx)
+            .await
+            .unwrap();
+
+        let export = service
+            .export_author_data(&FindAuthorRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!(author.id(), export.author().id());
+        assert_eq!(2, export.audit_log().len());
+
+        let record = service
+            .purge_author(&PurgeAuthorRequest::new(author.id()), &ctx)
+            .await
+            .unwrap();
+        assert_eq!(2, record.anonymized_entries());
+        let missing = service
+            .find_author(&FindAuthorRequest::new(author.id()))
+            .await;
+        assert!(matches!(missing, Err(FindAuthorError::NotFound { .. })));
+        let avatar = service
+            .find_avatar(&FindAvatarRequest::new(author.id()))
+            .await;
+        assert!(matches!(avatar, Err(FindAvatarError::NotFound { .. })));
+        let entries = service
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        let actions: Vec<_> = entries.iter().map(|entry| entry.action()).collect();
+        assert_eq!(
+            vec![
+                AuditAction::Create,
+                AuditAction::Update,
+                AuditAction::Delete
+            ],
+            actions
+        );
+        assert!(
+            entries
+                .iter()
+                .all(|entry| entry.before().is_none() && entry.after().is_none()),
+            "expected every snapshot to be erased"
+        );
+
+        let unknown = service
+            .purge_author(&PurgeAuthorRequest::new(AuthorId::new_v7()), &ctx)
+            .await;
+        assert!(matches!(unknown, Err(PurgeAuthorError::NotFound { .. })));
+        let erasures = service.find_erasures().await.unwrap();
+        assert_eq!(1, erasures.len());
+        ErasureRecord::verify_chain(&erasures).unwrap();
+    }
+
+    #[tokio::test]
+    async fn names_violating_the_policy_are_rejected() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        )
+        .with_name_policy(NamePolicy::new(10, vec!["darn".to_string()]));
+        let ctx = AuditContext::new("admin".into(), None);
+
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("Darn Long Author Name").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let result = service.create_author(&create, &ctx).await;
+        let Err(CreateAuthorError::InvalidName(err)) = result else {
+            panic!("expected the name to be rejected, but got {result:?}");
+        };
+        assert_eq!(2, err.violations().len());
+        assert!(service.find_all_authors().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn replacing_a_missing_author_requires_create_on_missing() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let id = AuthorId::new(7);
+        let replace = |name: &str| {
+            ReplaceAuthorRequest::new(
+                id,
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            )
+        };
+
+        let result = service.replace_author(&replace("JRR Tolkien"), &ctx).await;
+        assert!(matches!(result, Err(ReplaceAuthorError::NotFound { .. })));
+
+        let service = service.with_create_on_missing(true);
+        let created = service
+            .replace_author(&replace("JRR Tolkien"), &ctx)
+            .await
+            .unwrap();
+        assert!(matches!(created, ReplacedAuthor::Created(_)));
+        let replaced = service
+            .replace_author(&replace("J.R.R. Tolkien"), &ctx)
+            .await
+            .unwrap();
+        let ReplacedAuthor::Replaced(author) = replaced else {
+            panic!("expected the author to be replaced, but got {replaced:?}");
+        };
+        assert_eq!(id, author.id());
+        assert_eq!("J.R.R. Tolkien", author.name().to_string());
+
+        let entries = service
+            .find_audit_log(&FindAuditLogRequest::new(id))
+            .await
+            .unwrap();
+        let actions: Vec<_> = entries.iter().map(|entry| entry.action()).collect();
+        assert_eq!(vec![AuditAction::Create, AuditAction::Update], actions);
+    }
+
+    #[tokio::test]
+    async fn archived_authors_cannot_be_updated() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let archive = ChangeAuthorStatusRequest::new(author.id(), AuthorTransition::Archive);
+
+        let archived = service.change_author_status(&archive, &ctx).await.unwrap();
+        assert_eq!(AuthorStatus::Archived, archived.status());
+        let again = service.change_author_status(&archive, &ctx).await;
+        assert!(matches!(
+            again,
+            Err(ChangeAuthorStatusError::InvalidTransition(_))
+        ));
+
+        let update = UpdateAuthorRequest::builder(author.id())
+            .name(AuthorName::new("J.R.R. Tolkien").unwrap())
+            .build()
+            .unwrap();
+        let result = service.update_author(&update, &ctx).await;
+        assert!(matches!(result, Err(UpdateAuthorError::Archived { .. })));
+
+        let unarchive = ChangeAuthorStatusRequest::new(author.id(), AuthorTransition::Unarchive);
+        service
+            .change_author_status(&unarchive, &ctx)
+            .await
+            .unwrap();
+        service.update_author(&update, &ctx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn archived_authors_keep_their_avatar_aliases_and_genres() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let fantasy = service
+            .create_genre(&CreateGenreRequest::new(GenreName::new("Fantasy").unwrap()))
+            .await
+            .unwrap();
+        let poetry = service
+            .create_genre(&CreateGenreRequest::new(GenreName::new("Poetry").unwrap()))
+            .await
+            .unwrap();
+        let fantasy = AuthorGenreRequest::new(author.id(), fantasy.id());
+        service.attach_genre(&fantasy, &ctx).await.unwrap();
+        let archive = ChangeAuthorStatusRequest::new(author.id(), AuthorTransition::Archive);
+        service.change_author_status(&archive, &ctx).await.unwrap();
+
+        let image = AvatarImage::new(b"GIF89a\x01\x00\x01\x00".to_vec()).unwrap();
+        let upload = UploadAvatarRequest::new(author.id(), image);
+        let result = service.upload_avatar(&upload, &ctx).await;
+        assert!(matches!(result, Err(UploadAvatarError::Archived { .. })));
+        let alias = AuthorName::new("John Ronald Reuel Tolkien").unwrap();
+        let add = AddAuthorAliasRequest::new(author.id(), alias);
+        let result = service.add_author_alias(&add, &ctx).await;
+        assert!(matches!(result, Err(AddAuthorAliasError::Archived { .. })));
+        let poetry = AuthorGenreRequest::new(author.id(), poetry.id());
+        let result = service.attach_genre(&poetry, &ctx).await;
+        assert!(matches!(result, Err(AttachGenreError::Archived { .. })));
+        let result = service.detach_genre(&fantasy, &ctx).await;
+        assert!(matches!(result, Err(DetachGenreError::Archived { .. })));
+
+        let find = FindAuthorRequest::new(author.id());
+        assert!(service.find_author_aliases(&find).await.unwrap().is_empty());
+        assert_eq!(1, service.find_author_genres(&find).await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn contracts_require_an_active_author_and_a_free_term() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let publisher = service
+            .create_publisher(&CreatePublisherRequest::new(
+                PublisherName::new("Allen & Unwin").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let contract = |publisher_id, starts_on: &str, ends_on: Option<&str>| {
+            let date = |s: &str| s.parse().unwrap();
+            CreateContractRequest::new(
+                author.id(),
+                publisher_id,
+                ContractTerm::new(date(starts_on), ends_on.map(date)).unwrap(),
+                RoyaltyPercent::from_basis_points(1000).unwrap(),
+            )
+        };
+
+        let req = contract(publisher.id(), "1937-01-01", Some("1954-12-31"));
+        service.create_contract(&req).await.unwrap();
+        let req = contract(publisher.id(), "1954-12-31", None);
+        let result = service.create_contract(&req).await;
+        assert!(matches!(
+            result,
+            Err(CreateContractError::Overlapping { .. })
+        ));
+        let req = contract(PublisherId::new(404), "1955-01-01", None);
+        let result = service.create_contract(&req).await;
+        assert!(matches!(
+            result,
+            Err(CreateContractError::PublisherNotFound { .. })
+        ));
+        let req = contract(publisher.id(), "1955-01-01", None);
+        service.create_contract(&req).await.unwrap();
+
+        let archive = ChangeAuthorStatusRequest::new(author.id(), AuthorTransition::Archive);
+        service.change_author_status(&archive, &ctx).await.unwrap();
+        let req = contract(publisher.id(), "1920-01-01", Some("1920-12-31"));
+        let result = service.create_contract(&req).await;
+        assert!(matches!(
+            result,
+            Err(CreateContractError::AuthorArchived { .. })
+        ));
+        let contracts = service
+            .find_author_contracts(&FindAuthorRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!(2, contracts.len(), "expected rejected contracts not saved");
+    }
+
+    #[tokio::test]
+    async fn author_stats_cover_every_day_and_are_cached() {
+        let repo = MockAuthorRepository::new();
+        let today = Utc::now().date_naive();
+        let yesterday = today - Days::new(1);
+        let stats = repo
+            .expect_stats()
+            .returning(move |req| {
+                assert_eq!(today - Days::new(STATS_DAYS - 1), req.since());
+                Ok(AuthorStats::new(3, 2, 1).with_created_per_day(vec![(yesterday, 3)]))
+            })
+            .times(1);
+        let memory = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            LogEventPublisher,
+            memory.clone(),
+            memory.clone(),
+            memory,
+        )
+        .with_stats_ttl(Duration::from_secs(60));
+
+        let first = service.author_stats().await.unwrap();
+        let days = first.created_per_day();
+        assert_eq!(usize::try_from(STATS_DAYS).unwrap(), days.len());
+        assert_eq!(Some(&(today, 0)), days.last());
+        assert_eq!(Some(&(yesterday, 3)), days.iter().rev().nth(1));
+        assert_eq!(3, days.iter().map(|(_, count)| count).sum::<u64>());
+        let second = service.author_stats().await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(1, stats.calls(), "expected the second call to be cached");
+        repo.verify();
+    }
+
+    struct CountingCatalog(Arc<AtomicU32>);
+
+    impl BookCatalogClient for CountingCatalog {
+        async fn find_works(
+            &self,
+            author: &AuthorName,
+        ) -> Result<Vec<ExternalWork>, FindExternalWorksError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            let url = Url::parse("https://openlibrary.org/works/OL1W").unwrap();
+            Ok(vec![ExternalWork::new(format!("By {author}"), None, url)])
+        }
+    }
+
+    #[tokio::test]
+    async fn external_works_are_cached_per_author() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let missing = FindAuthorRequest::new(AuthorId::new(1));
+        assert!(matches!(
+            service.find_external_works(&missing).await,
+            Err(FindExternalWorksError::Disabled)
+        ));
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let service =
+            service.with_book_catalog(CountingCatalog(Arc::clone(&calls)), Duration::from_secs(60));
+        assert!(matches!(
+            service.find_external_works(&missing).await,
+            Err(FindExternalWorksError::AuthorNotFound { .. })
+        ));
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("Ursula K. Le Guin").unwrap(),
+            EmailAddress::new("ursula@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+        let find = FindAuthorRequest::new(author.id());
+
+        let works = service.find_external_works(&find).await.unwrap();
+        assert_eq!("By Ursula K. Le Guin", works[0].title());
+        service.find_external_works(&find).await.unwrap();
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    struct DomainVerifier;
+
+    impl EmailVerifier for DomainVerifier {
+        async fn verify_email(
+            &self,
+            email: &EmailAddress,
+        ) -> Result<EmailVerification, VerifyEmailError> {
+            match email.domain() {
+                "invalid.test" => Ok(EmailVerification::Rejected),
+                "flaky.test" => Err(VerifyEmailError(anyhow::anyhow!("timed out"))),
+                _ => Ok(EmailVerification::Verified),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn pending_emails_are_verified() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let mut ids = Vec::new();
+        for (name, email) in [
+            ("Ursula K. Le Guin", "ursula@example.com"),
+            ("Terry Pratchett", "terry@invalid.test"),
+            ("Iain M. Banks", "iain@flaky.test"),
+        ] {
+            let create = CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            );
+            ids.push(service.create_author(&create, &ctx).await.unwrap().id());
+        }
+        assert_eq!(0, service.verify_pending_emails(10).await.unwrap());
+
+        let service = service.with_email_verifier(DomainVerifier);
+        assert_eq!(2, service.verify_pending_emails(10).await.unwrap());
+        let mut states = Vec::new();
+        for id in ids {
+            let req = FindAuthorRequest::new(id);
+            let author = service.find_author(&req).await.unwrap();
+            states.push(author.email_verification());
+        }
+        assert_eq!(
+            vec![
+                EmailVerification::Verified,
+                EmailVerification::Rejected,
+                EmailVerification::Pending,
+            ],
+            states
+        );
+        assert_eq!(0, service.verify_pending_emails(10).await.unwrap());
+    }
+}
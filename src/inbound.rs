@@ -0,0 +1,5 @@
+pub mod cli;
+pub mod commands;
+pub mod http;
+#[cfg(feature = "serverless")]
+pub mod serverless;
@@ -0,0 +1,264 @@
+use crate::domain::model::AuthorIdStrategy;
+use crate::domain::ports::{FieldCipher, MigrationStore};
+use crate::outbound::cipher::{FieldCipherKeys, field_cipher};
+use crate::outbound::sqlite::{
+    ConnectRetryConfig, DefaultAuthorRepository, Migrations, PoolConfig, establish_pool,
+};
+use crate::outbound::transfer::{TransferOptions, transfer_authors};
+use crate::secrets::Secrets;
+use crate::seed;
+use anyhow::Context;
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+const USAGE: &str = "usage: authorctl migrate [up|down|status] | authorctl seed | authorctl \
+    migrate-data --from <url> --to <url> [--batch-size <n>] [--dry-run] | authorctl \
+    rotate-email-key";
+
+enum Command {
+    Migrate(MigrateCommand),
+    Seed,
+    MigrateData(MigrateDataCommand),
+    RotateEmailKey,
+}
+
+struct MigrateDataCommand {
+    from: String,
+    to: String,
+    options: TransferOptions,
+}
+
+enum MigrateCommand {
+    Up,
+    Down,
+    Status,
+}
+
+fn parse_args(args: &[String]) -> anyhow::Result<Command> {
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["migrate"] | ["migrate", "up"] => Ok(Command::Migrate(MigrateCommand::Up)),
+        ["migrate", "down"] => Ok(Command::Migrate(MigrateCommand::Down)),
+        ["migrate", "status"] => Ok(Command::Migrate(MigrateCommand::Status)),
+        ["seed"] => Ok(Command::Seed),
+        ["migrate-data", flags @ ..] => parse_migrate_data(flags).map(Command::MigrateData),
+        ["rotate-email-key"] => Ok(Command::RotateEmailKey),
+        _ => anyhow::bail!(USAGE),
+    }
+}
+
+fn parse_migrate_data(flags: &[&str]) -> anyhow::Result<MigrateDataCommand> {
+    let (mut from, mut to) = (None, None);
+    let mut batch_size = TransferOptions::DEFAULT_BATCH_SIZE;
+    let mut dry_run = false;
+    let mut flags = flags.iter();
+    while let Some(flag) = flags.next() {
+        let mut value = || {
+            flags
+                .next()
+                .with_context(|| format!("{flag} needs a value\n{USAGE}"))
+        };
+        match *flag {
+            "--from" => from = Some(value()?.to_string()),
+            "--to" => to = Some(value()?.to_string()),
+            "--batch-size" => {
+                batch_size = value()?
+                    .parse()
+                    .ok()
+                    .filter(|size| *size > 0)
+                    .context("--batch-size must be a positive number")?;
+            }
+            "--dry-run" => dry_run = true,
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+    Ok(MigrateDataCommand {
+        from: from.context(USAGE)?,
+        to: to.context(USAGE)?,
+        options: TransferOptions::new(batch_size).with_dry_run(dry_run),
+    })
+}
+
+fn load_env_opt(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+fn id_strategy() -> anyhow::Result<AuthorIdStrategy> {
+    load_env_opt("AUTHOR_ID_STRATEGY").map_or(Ok(AuthorIdStrategy::Integer), |value| {
+        value.parse().map_err(anyhow::Error::from)
+    })
+}
+
+async fn email_keys() -> anyhow::Result<Option<FieldCipherKeys>> {
+    FieldCipherKeys::from_secrets(&Secrets::from_env()).await
+}
+
+async fn cipher() -> anyhow::Result<Arc<dyn FieldCipher>> {
+    Ok(field_cipher(email_keys().await?.as_ref()))
+}
+
+async fn connect(database_url: &str, auto_migrate: bool) -> anyhow::Result<SqlitePool> {
+    let retry_config = ConnectRetryConfig::new(
+        Duration::from_millis(100),
+        Duration::from_secs(5),
+        Duration::from_secs(30),
+    );
+    let pool_config =
+        PoolConfig::new(0, 1, Duration::from_secs(30)).with_auto_migrate(auto_migrate);
+    establish_pool(database_url, &retry_config, &pool_config).await
+}
+
+/// Connects to the database named by `DATABASE_URL`.
+async fn connect_env() -> anyhow::Result<SqlitePool> {
+    let database_url =
+        load_env_opt("DATABASE_URL").context("Failed to load environment variable DATABASE_URL")?;
+    connect(&database_url, false).await
+}
+
+/// Runs `authorctl` with `args`, excluding the program name.
+pub async fn run(args: &[String]) -> anyhow::Result<()> {
+    match parse_args(args)? {
+        Command::Migrate(command) => migrate(Migrations::new(connect_env().await?), command).await,
+        Command::Seed => seed_authors(connect_env().await?).await,
+        Command::MigrateData(command) => migrate_data(command).await,
+        Command::RotateEmailKey => rotate_email_key(connect_env().await?).await,
+    }
+}
+
+async fn seed_authors(pool: SqlitePool) -> anyhow::Result<()> {
+    let repo = DefaultAuthorRepository::new(pool, id_strategy()?).with_cipher(cipher().await?);
+    let path = load_env_opt("SEED_PATH").map(PathBuf::from);
+    let authors = seed::load_seed(path.as_deref())?;
+    let report = seed::seed_authors(&repo, &authors).await?;
+    println!(
+        "Seeded {} author(s), skipped {} existing",
+        report.created(),
+        report.skipped()
+    );
+    Ok(())
+}
+
+/// The target's schema is migrated first, except in a dry run, which leaves the target untouched
+/// and so needs its schema to exist already.
+async fn migrate_data(command: MigrateDataCommand) -> anyhow::Result<()> {
+    let dry_run = command.options.dry_run();
+    let source = connect(&command.from, false)
+        .await
+        .context("Failed to connect to the source database")?;
+    let target = connect(&command.to, !dry_run)
+        .await
+        .context("Failed to connect to the target database")?;
+    let id_strategy = id_strategy()?;
+    let cipher = cipher().await?;
+    let report = transfer_authors(
+        &DefaultAuthorRepository::new(source, id_strategy).with_cipher(Arc::clone(&cipher)),
+        &DefaultAuthorRepository::new(target, id_strategy).with_cipher(cipher),
+        &command.options,
+    )
+    .await?;
+
+    let verb = if dry_run { "Would copy" } else { "Copied" };
+    println!(
+        "{verb} {} author(s), {} already up to date",
+        report.copied(),
+        report.unchanged()
+    );
+    println!("source checksum {}", report.source_checksum());
+    println!("target checksum {}", report.target_checksum());
+    anyhow::ensure!(
+        dry_run || report.is_verified(),
+        "Checksums differ; the target may hold authors the source does not"
+    );
+    Ok(())
+}
+
+/// Re-encrypts every email still under an older key, or not yet encrypted, with the first key in
+/// `EMAIL_ENCRYPTION_KEYS`. Older keys can be dropped from the list once this has run.
+async fn rotate_email_key(pool: SqlitePool) -> anyhow::Result<()> {
+    let keys = email_keys()
+        .await?
+        .context("EMAIL_ENCRYPTION_KEYS must be set to rotate the email key")?;
+    let repo =
+        DefaultAuthorRepository::new(pool, id_strategy()?).with_cipher(field_cipher(Some(&keys)));
+    let rewritten = repo.reencrypt_emails().await?;
+    println!(
+        "Re-encrypted {rewritten} email(s) with key {}",
+        keys.current_key_id()
+    );
+    Ok(())
+}
+
+async fn migrate(migrations: Migrations, command: MigrateCommand) -> anyhow::Result<()> {
+    match command {
+        MigrateCommand::Up => {
+            migrations.run().await?;
+            println!("Database is up to date");
+        }
+        MigrateCommand::Down => match migrations.undo_last().await? {
+            Some(version) => println!("Reverted migration {version}"),
+            None => println!("No migrations to revert"),
+        },
+        MigrateCommand::Status => {
+            for migration in migrations.find_migrations().await? {
+                let state = if migration.applied() {
+                    "applied"
+                } else {
+                    "pending"
+                };
+                println!(
+                    "{} {state:<7} {}",
+                    migration.version(),
+                    migration.description()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::cli::{Command, MigrateCommand, parse_args};
+
+    #[test]
+    fn commands_are_parsed_from_args() {
+        let parse = |args: &[&str]| {
+            let args: Vec<_> = args.iter().map(ToString::to_string).collect();
+            parse_args(&args)
+        };
+        assert!(matches!(
+            parse(&["migrate"]),
+            Ok(Command::Migrate(MigrateCommand::Up))
+        ));
+        assert!(matches!(
+            parse(&["migrate", "status"]),
+            Ok(Command::Migrate(MigrateCommand::Status))
+        ));
+        assert!(matches!(parse(&["seed"]), Ok(Command::Seed)));
+        assert!(matches!(
+            parse(&["rotate-email-key"]),
+            Ok(Command::RotateEmailKey)
+        ));
+        assert!(parse(&["migrate", "sideways"]).is_err());
+
+        let Ok(Command::MigrateData(command)) = parse(&[
+            "migrate-data",
+            "--from",
+            "sqlite://old.db",
+            "--to",
+            "sqlite://new.db",
+            "--dry-run",
+        ]) else {
+            panic!("expected a data migration");
+        };
+        assert_eq!(
+            ("sqlite://old.db", "sqlite://new.db"),
+            (command.from.as_str(), command.to.as_str())
+        );
+        assert!(command.options.dry_run());
+        assert!(parse(&["migrate-data", "--from", "sqlite://old.db"]).is_err());
+        assert!(parse(&["migrate-data", "--batch-size", "0"]).is_err());
+    }
+}
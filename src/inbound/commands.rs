@@ -0,0 +1,273 @@
+use crate::domain::model::{
+    AuditContext, AuthorId, AuthorName, CreateAuthorError, CreateAuthorRequest, DeleteAuthorError,
+    DeleteAuthorRequest, EmailAddress,
+};
+use crate::domain::ports::{CommandLog, DynCommandLog};
+use crate::domain::service::AuthorService;
+use crate::outbound::events::EventPublisherConfig;
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub trait CommandQueue: Send + Sync + 'static {
+    fn receive(
+        &self,
+    ) -> impl Future<Output = anyhow::Result<Option<Box<dyn CommandDelivery>>>> + Send;
+}
+
+/// Object-safe counterpart of [`CommandQueue`], implemented for every one.
+pub trait DynCommandQueue: Send + Sync + 'static {
+    fn receive<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<Option<Box<dyn CommandDelivery>>>>;
+}
+
+impl<T: CommandQueue> DynCommandQueue for T {
+    fn receive<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<Option<Box<dyn CommandDelivery>>>> {
+        Box::pin(CommandQueue::receive(self))
+    }
+}
+
+impl CommandQueue for Box<dyn DynCommandQueue> {
+    async fn receive(&self) -> anyhow::Result<Option<Box<dyn CommandDelivery>>> {
+        self.as_ref().receive().await
+    }
+}
+
+/// A received command, only ever handled behind `dyn`, so settling it returns a boxed future.
+pub trait CommandDelivery: Send {
+    fn payload(&self) -> &[u8];
+
+    fn attempt(&self) -> u32;
+
+    fn ack(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>>;
+
+    fn retry(self: Box<Self>, delay: Duration) -> BoxFuture<'static, anyhow::Result<()>>;
+}
+
+pub async fn connect_command_queue(
+    config: &EventPublisherConfig,
+    stream: &str,
+    subject: &str,
+) -> anyhow::Result<Box<dyn DynCommandQueue>> {
+    #[cfg(feature = "nats")]
+    {
+        let queue =
+            crate::outbound::nats::NatsCommandQueue::connect(config, stream, subject).await?;
+        Ok(Box::new(queue))
+    }
+    #[cfg(not(feature = "nats"))]
+    {
+        let _ = (config, stream, subject);
+        anyhow::bail!("Consuming author commands requires the nats feature")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthorCommand {
+    CreateAuthor(CreateAuthorCommand),
+    DeleteAuthor(DeleteAuthorCommand),
+}
+
+impl AuthorCommand {
+    #[must_use]
+    pub fn command_id(&self) -> &str {
+        match self {
+            Self::CreateAuthor(cmd) => &cmd.command_id,
+            Self::DeleteAuthor(cmd) => &cmd.command_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAuthorCommand {
+    command_id: String,
+    name: String,
+    email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteAuthorCommand {
+    command_id: String,
+    id: AuthorId,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandConsumerConfig {
+    max_attempts: u32,
+    redelivery_delay: Duration,
+}
+
+impl CommandConsumerConfig {
+    #[must_use]
+    pub const fn new(max_attempts: u32, redelivery_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            redelivery_delay,
+        }
+    }
+}
+
+enum Outcome {
+    Processed,
+    Rejected(String),
+    Failed(anyhow::Error),
+}
+
+pub struct CommandConsumer {
+    service: AuthorService,
+    queue: Arc<dyn DynCommandQueue>,
+    log: Arc<dyn DynCommandLog>,
+    config: CommandConsumerConfig,
+}
+
+impl CommandConsumer {
+    pub fn new(
+        service: AuthorService,
+        queue: impl CommandQueue,
+        log: impl CommandLog,
+        config: CommandConsumerConfig,
+    ) -> Self {
+        Self {
+            service,
+            queue: Arc::new(queue),
+            log: Arc::new(log),
+            config,
+        }
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        while let Some(delivery) = self.queue.receive().await? {
+            self.handle(delivery).await?;
+        }
+        Ok(())
+    }
+
+    async fn handle(&self, delivery: Box<dyn CommandDelivery>) -> anyhow::Result<()> {
+        let outcome = match serde_json::from_slice::<AuthorCommand>(delivery.payload()) {
+            Ok(cmd) => self.execute(&cmd).await,
+            Err(err) => Outcome::Rejected(format!("Malformed author command: {err}")),
+        };
+
+        match outcome {
+            Outcome::Processed => delivery.ack().await,
+            Outcome::Rejected(reason) => {
+                tracing::warn!("Discarding author command: {reason}");
+                delivery.ack().await
+            }
+            Outcome::Failed(err) if delivery.attempt() >= self.config.max_attempts => {
+                tracing::error!(
+                    attempt = delivery.attempt(),
+                    "Giving up on author command: {err:?}"
+                );
+                delivery.ack().await
+            }
+            Outcome::Failed(err) => {
+                tracing::warn!(
+                    attempt = delivery.attempt(),
+                    "Author command failed, scheduling redelivery: {err:?}"
+                );
+                delivery.retry(self.config.redelivery_delay).await
+            }
+        }
+    }
+
+    async fn execute(&self, cmd: &AuthorCommand) -> Outcome {
+        let command_id = cmd.command_id();
+        match self.log.is_processed(command_id).await {
+            Ok(true) => return Outcome::Processed,
+            Ok(false) => {}
+            Err(err) => return Outcome::Failed(err.0),
+        }
+
+        let ctx = AuditContext::new("command-queue".into(), Some(command_id.to_string()));
+        let outcome = match cmd {
+            AuthorCommand::CreateAuthor(cmd) => self.create_author(cmd, &ctx).await,
+            AuthorCommand::DeleteAuthor(cmd) => self.delete_author(cmd, &ctx).await,
+        };
+
+        if matches!(outcome, Outcome::Processed)
+            && let Err(err) = self.log.mark_processed(command_id).await
+        {
+            return Outcome::Failed(err.0);
+        }
+        outcome
+    }
+
+    async fn create_author(&self, cmd: &CreateAuthorCommand, ctx: &AuditContext) -> Outcome {
+        let name = match AuthorName::new(&cmd.name) {
+            Ok(name) => name,
+            Err(err) => return Outcome::Rejected(err.to_string()),
+        };
+        let email = match EmailAddress::new(&cmd.email) {
+            Ok(email) => email,
+            Err(err) => return Outcome::Rejected(err.to_string()),
+        };
+
+        let req = CreateAuthorRequest::new(name, email);
+        match self.service.create_author(&req, ctx).await {
+            Ok(_) => Outcome::Processed,
+            Err(
+                err @ (CreateAuthorError::Duplicate { .. }
+                | CreateAuthorError::DuplicateEmail { .. }
+                | CreateAuthorError::InvalidName(_)),
+            ) => Outcome::Rejected(err.to_string()),
+            // Redelivery asks the id generator again, which should not pick a taken id twice.
+            Err(err @ CreateAuthorError::IdTaken { .. }) => Outcome::Failed(err.into()),
+            Err(CreateAuthorError::Other(err)) => Outcome::Failed(err),
+        }
+    }
+
+    async fn delete_author(&self, cmd: &DeleteAuthorCommand, ctx: &AuditContext) -> Outcome {
+        let req = DeleteAuthorRequest::new(cmd.id);
+        match self.service.delete_author(&req, ctx).await {
+            Ok(()) | Err(DeleteAuthorError::NotFound { .. }) => Outcome::Processed,
+            Err(err @ DeleteAuthorError::PreconditionFailed { .. }) => {
+                Outcome::Rejected(err.to_string())
+            }
+            Err(DeleteAuthorError::Other(err)) => Outcome::Failed(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::ports::AuthorRepository;
+    use crate::domain::service::AuthorService;
+    use crate::inbound::commands::{CommandConsumer, CommandConsumerConfig};
+    use crate::outbound::memory::{InMemoryCommandQueue, InMemoryRepository};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn duplicate_commands_are_processed_once() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let queue = InMemoryCommandQueue::new();
+        let payload = br#"{"type":"create_author","command_id":"cmd-1","name":"JRR Tolkien","email":"jrr.tolkien@example.com"}"#;
+        queue.send(payload.to_vec());
+        queue.send(payload.to_vec());
+        queue.send(b"not json".to_vec());
+        queue.close();
+
+        let config = CommandConsumerConfig::new(3, Duration::ZERO);
+        CommandConsumer::new(service, queue, repo.clone(), config)
+            .run()
+            .await
+            .unwrap();
+
+        let authors = repo.find_all_authors().await.unwrap();
+        assert_eq!(
+            1,
+            authors.len(),
+            "expected exactly one author to be created"
+        );
+    }
+}
@@ -0,0 +1,1648 @@
+mod abuse;
+mod admin;
+mod assets;
+mod caching;
+#[cfg(feature = "chaos")]
+mod chaos;
+mod dashboard;
+mod deadline;
+mod events;
+mod export;
+mod flags;
+mod handlers;
+mod i18n;
+mod json;
+mod normalize;
+mod not_found;
+mod patch;
+mod problem;
+mod request_id;
+mod response_cache;
+mod runtime_metrics;
+mod security;
+mod session;
+mod signing;
+#[cfg(feature = "tls")]
+mod tls;
+mod versioning;
+mod ws;
+
+pub use crate::inbound::http::abuse::{AbuseGuard, Ban, BanReason};
+pub use crate::inbound::http::assets::Assets;
+#[cfg(feature = "chaos")]
+pub use crate::inbound::http::chaos::{Chaos, ChaosSettings};
+pub use crate::inbound::http::flags::{RequestFlags, V2_ENVELOPE};
+pub use crate::inbound::http::handlers::CreateAuthorHttpRequest;
+pub use crate::inbound::http::i18n::Locale;
+pub use crate::inbound::http::normalize::{NormalizeMode, PathNormalization};
+pub use crate::inbound::http::response_cache::ResponseCache;
+pub use crate::inbound::http::runtime_metrics::{
+    InFlightRequest, PoolStats, RouteCounters, RuntimeMetrics, RuntimeSnapshot, RuntimeStats,
+    WorkerStats,
+};
+pub use crate::inbound::http::security::{SecurityHeaders, SecurityHeadersConfig};
+pub use crate::inbound::http::session::{AdminSessions, SameSite, SessionCookieConfig};
+pub use crate::inbound::http::signing::{RequestSigning, SignedClient};
+#[cfg(feature = "tls")]
+pub use crate::inbound::http::tls::certificate_validity;
+pub use crate::inbound::http::versioning::ApiDeprecation;
+
+use crate::domain::model::{AuthorEvent, AvatarImage};
+use crate::domain::ports::{
+    AuthorRepository, BackupStore, BoxedAuthorRepository, DynBackupStore, DynFeatureFlags,
+    DynMigrationStore, DynRetentionStore, FeatureFlags, MigrationStore, RetentionStore,
+};
+use crate::inbound::http::abuse::detect_abuse;
+use crate::inbound::http::admin::{
+    create_backup, find_bans, find_feature_flags, find_log_level, find_migrations, find_retention,
+    find_runtime_metrics, restore_backup, track_in_flight, update_log_level,
+};
+use crate::inbound::http::assets::serve_asset;
+use crate::inbound::http::caching::conditional_get;
+#[cfg(feature = "chaos")]
+use crate::inbound::http::chaos::{find_chaos, inject_faults, update_chaos};
+use crate::inbound::http::dashboard::{create_author_form, dashboard, delete_author_form};
+use crate::inbound::http::deadline::apply_deadline;
+use crate::inbound::http::events::stream_author_events;
+use crate::inbound::http::export::{export_authors_csv, export_authors_ndjson};
+use crate::inbound::http::flags::evaluate_feature_flags;
+use crate::inbound::http::handlers::{
+    add_author_alias, allowed_methods, archive_author, attach_genre, author_exists, author_stats,
+    count_authors, create_author, create_contract, create_genre, create_publisher, delete_author,
+    delete_contract, delete_genre, delete_publisher, detach_genre, export_author_data,
+    find_audit_log, find_author_aliases, find_author_contracts, find_author_genres, find_avatar,
+    find_external_works, find_operation, find_publisher, find_publisher_contracts, get_author,
+    import_authors, list_authors, list_genres, list_publishers, method_not_allowed, purge_author,
+    remove_author_alias, replace_author, unarchive_author, update_author, upload_avatar,
+    upsert_author,
+};
+use crate::inbound::http::i18n::negotiate_locale;
+use crate::inbound::http::normalize::normalize_path;
+use crate::inbound::http::not_found::route_not_found;
+use crate::inbound::http::patch::{ACCEPT_PATCH, PATCH_FORMATS};
+use crate::inbound::http::problem::negotiate_error_format;
+use crate::inbound::http::request_id::{RequestId, propagate_request_id, trace_id};
+use crate::inbound::http::response_cache::{invalidate_on_mutation, serve_cached};
+use crate::inbound::http::security::apply_security_headers;
+use crate::inbound::http::session::{callback, login, logout};
+use crate::inbound::http::signing::verify_signature;
+use crate::inbound::http::versioning::{envelope, track_api_version};
+use crate::inbound::http::ws::author_updates;
+use crate::logging::LogFilterHandle;
+
+use crate::domain::service::AuthorService;
+use anyhow::Context;
+use axum::Router;
+use axum::extract::{ConnectInfo, DefaultBodyLimit, Request};
+use axum::handler::Handler;
+use axum::http::HeaderValue;
+use axum::middleware;
+use axum::response::IntoResponse;
+use axum::routing::{Route, delete, get, post, put};
+use futures::future::BoxFuture;
+use hyper::server::conn::http1;
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto;
+use hyper_util::service::TowerToHyperService;
+use std::convert::Infallible;
+use std::future::Future;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpSocket, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tower::{Layer, Service};
+use tower_http::add_extension::AddExtension;
+use tower_http::trace::TraceLayer;
+
+pub struct AppState<R = BoxedAuthorRepository> {
+    author_service: AuthorService<R>,
+    author_events: broadcast::Sender<AuthorEvent>,
+    shutdown: Arc<watch::Sender<bool>>,
+    admin_token: Option<Arc<str>>,
+    log_filter: Option<LogFilterHandle>,
+    migrations: Option<Arc<dyn DynMigrationStore>>,
+    backups: Option<Arc<dyn DynBackupStore>>,
+    retention: Option<Arc<dyn DynRetentionStore>>,
+    admin_sessions: Option<Arc<AdminSessions>>,
+    assets: Option<Assets>,
+    abuse: Option<AbuseGuard>,
+    feature_flags: Option<Arc<dyn DynFeatureFlags>>,
+    runtime_metrics: RuntimeMetrics,
+    #[cfg(feature = "chaos")]
+    chaos: Chaos,
+}
+
+impl<R> Clone for AppState<R> {
+    fn clone(&self) -> Self {
+        Self {
+            author_service: self.author_service.clone(),
+            author_events: self.author_events.clone(),
+            shutdown: Arc::clone(&self.shutdown),
+            admin_token: self.admin_token.clone(),
+            log_filter: self.log_filter.clone(),
+            migrations: self.migrations.clone(),
+            backups: self.backups.clone(),
+            retention: self.retention.clone(),
+            admin_sessions: self.admin_sessions.clone(),
+            assets: self.assets.clone(),
+            abuse: self.abuse.clone(),
+            feature_flags: self.feature_flags.clone(),
+            runtime_metrics: self.runtime_metrics.clone(),
+            #[cfg(feature = "chaos")]
+            chaos: self.chaos.clone(),
+        }
+    }
+}
+
+impl<R: AuthorRepository> AppState<R> {
+    #[must_use]
+    pub fn new(author_service: AuthorService<R>) -> Self {
+        let (author_events, _) = broadcast::channel(1);
+        let (shutdown, _) = watch::channel(false);
+        Self {
+            author_service,
+            author_events,
+            shutdown: Arc::new(shutdown),
+            admin_token: None,
+            log_filter: None,
+            migrations: None,
+            backups: None,
+            retention: None,
+            admin_sessions: None,
+            assets: None,
+            abuse: None,
+            feature_flags: None,
+            runtime_metrics: RuntimeMetrics::new(),
+            #[cfg(feature = "chaos")]
+            chaos: Chaos::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_author_events(mut self, author_events: broadcast::Sender<AuthorEvent>) -> Self {
+        self.author_events = author_events;
+        self
+    }
+
+    #[must_use]
+    pub fn with_admin_token(mut self, admin_token: Option<Arc<str>>) -> Self {
+        self.admin_token = admin_token;
+        self
+    }
+
+    #[must_use]
+    pub fn with_log_filter(mut self, log_filter: LogFilterHandle) -> Self {
+        self.log_filter = Some(log_filter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_migrations(mut self, migrations: impl MigrationStore) -> Self {
+        self.migrations = Some(Arc::new(migrations));
+        self
+    }
+
+    #[must_use]
+    pub fn with_backups(mut self, backups: impl BackupStore) -> Self {
+        self.backups = Some(Arc::new(backups));
+        self
+    }
+
+    #[must_use]
+    pub fn with_retention(mut self, retention: impl RetentionStore) -> Self {
+        self.retention = Some(Arc::new(retention));
+        self
+    }
+
+    /// Lets admins sign in to the HTML pages through OIDC, besides using the admin token.
+    #[must_use]
+    pub fn with_admin_sessions(mut self, admin_sessions: AdminSessions) -> Self {
+        self.admin_sessions = Some(Arc::new(admin_sessions));
+        self
+    }
+
+    #[must_use]
+    pub fn with_assets(mut self, assets: Assets) -> Self {
+        self.assets = Some(assets);
+        self
+    }
+
+    /// Bans clients that keep failing authentication or validation.
+    #[must_use]
+    pub fn with_abuse_guard(mut self, abuse: AbuseGuard) -> Self {
+        self.abuse = Some(abuse);
+        self
+    }
+
+    /// Without flags, every flag a handler asks for has the default it asked with.
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: impl FeatureFlags) -> Self {
+        self.feature_flags = Some(Arc::new(feature_flags));
+        self
+    }
+
+    #[must_use]
+    pub fn with_runtime_metrics(mut self, runtime_metrics: RuntimeMetrics) -> Self {
+        self.runtime_metrics = runtime_metrics;
+        self
+    }
+
+    #[cfg(feature = "chaos")]
+    #[must_use]
+    pub fn with_chaos(mut self, chaos: Chaos) -> Self {
+        self.chaos = chaos;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpServerConfig {
+    port: u16,
+    http2: bool,
+    keep_alive: bool,
+    keep_alive_timeout: Duration,
+    http2_keep_alive_interval: Option<Duration>,
+    http2_max_concurrent_streams: u32,
+    tcp_nodelay: bool,
+    tcp_backlog: u32,
+    shutdown_timeout: Duration,
+    request_timeout: Duration,
+    default_locale: Locale,
+    api_deprecation: ApiDeprecation,
+    cache_control: CacheControlConfig,
+    path_normalization: PathNormalization,
+    security_headers: SecurityHeadersConfig,
+    request_signing: RequestSigning,
+    tls: Option<TlsConfig>,
+}
+
+impl HttpServerConfig {
+    #[must_use]
+    pub fn new(port: u16) -> Self {
+        Self {
+            port,
+            http2: true,
+            keep_alive: true,
+            keep_alive_timeout: Duration::from_secs(75),
+            http2_keep_alive_interval: None,
+            http2_max_concurrent_streams: 200,
+            tcp_nodelay: true,
+            tcp_backlog: 1024,
+            shutdown_timeout: Duration::from_secs(30),
+            request_timeout: Duration::from_secs(30),
+            default_locale: Locale::ENGLISH,
+            api_deprecation: ApiDeprecation::default(),
+            cache_control: CacheControlConfig::default(),
+            path_normalization: PathNormalization::default(),
+            security_headers: SecurityHeadersConfig::default(),
+            request_signing: RequestSigning::default(),
+            tls: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_http2(mut self, enabled: bool) -> Self {
+        self.http2 = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_keep_alive(mut self, enabled: bool, timeout: Duration) -> Self {
+        self.keep_alive = enabled;
+        self.keep_alive_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_http2_keep_alive_interval(mut self, interval: Option<Duration>) -> Self {
+        self.http2_keep_alive_interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_http2_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.http2_max_concurrent_streams = max;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_tcp_nodelay(mut self, enabled: bool) -> Self {
+        self.tcp_nodelay = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_tcp_backlog(mut self, backlog: u32) -> Self {
+        self.tcp_backlog = backlog;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// The longest a request may wait on the repository; `Request-Timeout` can only shorten it.
+    #[must_use]
+    pub const fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// The language for error messages when `Accept-Language` names none we have a catalog for.
+    #[must_use]
+    pub const fn with_default_locale(mut self, locale: Locale) -> Self {
+        self.default_locale = locale;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_api_deprecation(mut self, deprecation: ApiDeprecation) -> Self {
+        self.api_deprecation = deprecation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_cache_control(mut self, cache_control: CacheControlConfig) -> Self {
+        self.cache_control = cache_control;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_path_normalization(mut self, normalization: PathNormalization) -> Self {
+        self.path_normalization = normalization;
+        self
+    }
+
+    #[must_use]
+    pub fn with_security_headers(mut self, security_headers: SecurityHeadersConfig) -> Self {
+        self.security_headers = security_headers;
+        self
+    }
+
+    #[must_use]
+    pub fn with_request_signing(mut self, request_signing: RequestSigning) -> Self {
+        self.request_signing = request_signing;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tls(mut self, tls: Option<TlsConfig>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    fn connection_builder(&self) -> ConnectionBuilder {
+        if !self.http2 {
+            let mut builder = http1::Builder::new();
+            builder
+                .timer(TokioTimer::new())
+                .keep_alive(self.keep_alive)
+                .header_read_timeout(self.keep_alive_timeout);
+            return ConnectionBuilder::Http1(builder);
+        }
+
+        let mut builder = auto::Builder::new(TokioExecutor::new());
+        builder
+            .http1()
+            .timer(TokioTimer::new())
+            .keep_alive(self.keep_alive)
+            .header_read_timeout(self.keep_alive_timeout);
+        builder
+            .http2()
+            .timer(TokioTimer::new())
+            .max_concurrent_streams(self.http2_max_concurrent_streams)
+            .keep_alive_interval(self.http2_keep_alive_interval)
+            .keep_alive_timeout(self.keep_alive_timeout);
+        ConnectionBuilder::Auto(builder)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheControlConfig {
+    authors: HeaderValue,
+    author: HeaderValue,
+    audit_log: HeaderValue,
+    avatar: HeaderValue,
+    response_cache: ResponseCache,
+}
+
+impl CacheControlConfig {
+    #[must_use]
+    pub fn new(
+        authors: HeaderValue,
+        author: HeaderValue,
+        audit_log: HeaderValue,
+        avatar: HeaderValue,
+    ) -> Self {
+        Self {
+            authors,
+            author,
+            audit_log,
+            avatar,
+            response_cache: ResponseCache::default(),
+        }
+    }
+
+    /// Keeps author listings and stats in memory besides telling clients how to cache them.
+    #[must_use]
+    pub fn with_response_cache(mut self, response_cache: ResponseCache) -> Self {
+        self.response_cache = response_cache;
+        self
+    }
+}
+
+impl Default for CacheControlConfig {
+    fn default() -> Self {
+        Self::new(
+            HeaderValue::from_static("no-cache"),
+            HeaderValue::from_static("no-cache"),
+            HeaderValue::from_static("no-cache"),
+            HeaderValue::from_static("public, max-age=3600"),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+}
+
+impl TlsConfig {
+    #[must_use]
+    pub const fn new(cert_path: PathBuf, key_path: PathBuf) -> Self {
+        Self {
+            cert_path,
+            key_path,
+        }
+    }
+
+    #[must_use]
+    pub fn cert_path(&self) -> &Path {
+        &self.cert_path
+    }
+
+    #[must_use]
+    pub fn key_path(&self) -> &Path {
+        &self.key_path
+    }
+}
+
+#[derive(Clone)]
+enum ConnectionBuilder {
+    Auto(auto::Builder<TokioExecutor>),
+    Http1(http1::Builder),
+}
+
+/// The router with the peer address of the connection it serves, for [`ConnectInfo`].
+type ConnectionService = TowerToHyperService<AddExtension<Router, ConnectInfo<SocketAddr>>>;
+type RouterHook<R> = Box<dyn FnOnce(Router<AppState<R>>) -> Router<AppState<R>> + Send>;
+type LifecycleHook = BoxFuture<'static, anyhow::Result<()>>;
+
+/// Builds the API with its middleware but without binding a listener, for mounting under another
+/// axum application, serving through a serverless adapter, or calling with `oneshot` in tests.
+/// Connection settings such as the port, TLS and timeouts are left to whoever serves the router.
+pub fn build_router<R: AuthorRepository>(state: AppState<R>, config: &HttpServerConfig) -> Router {
+    assemble_router(state, config, Vec::new())
+}
+
+fn assemble_router<R: AuthorRepository>(
+    state: AppState<R>,
+    config: &HttpServerConfig,
+    routers: Vec<RouterHook<R>>,
+) -> Router {
+    let trace_layer =
+        TraceLayer::new_for_http().make_span_with(|request: &axum::extract::Request<_>| {
+            let uri = request.uri().to_string();
+            let request_id = request
+                .extensions()
+                .get::<RequestId>()
+                .map(ToString::to_string);
+            let trace_id = trace_id(request.headers());
+            tracing::info_span!(
+                "http_request",
+                method = ?request.method(),
+                uri,
+                request_id,
+                trace_id
+            )
+        });
+
+    let router = routers
+        .into_iter()
+        .fold(routes(&config.cache_control), |router, hook| hook(router));
+    #[cfg(feature = "chaos")]
+    let router = router.layer(middleware::from_fn_with_state(
+        state.chaos.clone(),
+        inject_faults,
+    ));
+    let router = router
+        .layer(middleware::from_fn_with_state(
+            state.runtime_metrics.clone(),
+            track_in_flight,
+        ))
+        .layer(middleware::from_fn_with_state(
+            config.request_timeout,
+            apply_deadline,
+        ))
+        .layer(middleware::from_fn_with_state(
+            config.cache_control.response_cache.clone(),
+            invalidate_on_mutation,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            evaluate_feature_flags,
+        ))
+        .layer(middleware::from_fn_with_state(
+            config.request_signing.clone(),
+            verify_signature,
+        ))
+        .layer(middleware::from_fn_with_state(state.clone(), detect_abuse))
+        .layer(middleware::from_fn(negotiate_error_format))
+        .layer(middleware::from_fn_with_state(
+            config.api_deprecation,
+            track_api_version,
+        ))
+        .layer(middleware::from_fn_with_state(
+            config.default_locale,
+            negotiate_locale,
+        ))
+        .layer(trace_layer)
+        .layer(middleware::from_fn(propagate_request_id))
+        .layer(middleware::from_fn_with_state(
+            config.security_headers.clone(),
+            apply_security_headers,
+        ))
+        .with_state(state);
+    if !config.path_normalization.is_enabled() {
+        return router;
+    }
+    Router::new()
+        .fallback_service(router)
+        .layer(middleware::from_fn_with_state(
+            config.path_normalization,
+            normalize_path,
+        ))
+}
+
+/// Extends the API router before binding, so the server can be embedded in a larger application.
+pub struct HttpServerBuilder<R = BoxedAuthorRepository> {
+    state: AppState<R>,
+    config: HttpServerConfig,
+    routers: Vec<RouterHook<R>>,
+    on_startup: Vec<LifecycleHook>,
+    on_shutdown: Vec<LifecycleHook>,
+}
+
+impl<R: AuthorRepository> HttpServerBuilder<R> {
+    /// Adds `router`'s routes next to the API's; both get the built-in middleware.
+    #[must_use]
+    pub fn merge(mut self, router: Router<AppState<R>>) -> Self {
+        self.routers.push(Box::new(move |app| app.merge(router)));
+        self
+    }
+
+    /// Wraps every route, inside the built-in middleware, so requests already carry their request
+    /// id, locale and deadline.
+    #[must_use]
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.routers.push(Box::new(move |app| app.layer(layer)));
+        self
+    }
+
+    /// Replaces the problem details answer for unknown routes.
+    #[must_use]
+    pub fn fallback<H, T>(mut self, handler: H) -> Self
+    where
+        H: Handler<T, AppState<R>>,
+        T: 'static,
+    {
+        self.routers
+            .push(Box::new(move |app| app.fallback(handler)));
+        self
+    }
+
+    /// Runs before the first connection is accepted; an error stops the server from serving.
+    #[must_use]
+    pub fn on_startup(
+        mut self,
+        hook: impl Future<Output = anyhow::Result<()>> + Send + 'static,
+    ) -> Self {
+        self.on_startup.push(Box::pin(hook));
+        self
+    }
+
+    /// Runs once open connections have closed or the shutdown timeout elapsed.
+    #[must_use]
+    pub fn on_shutdown(
+        mut self,
+        hook: impl Future<Output = anyhow::Result<()>> + Send + 'static,
+    ) -> Self {
+        self.on_shutdown.push(Box::pin(hook));
+        self
+    }
+
+    /// Returns the router without binding; startup and shutdown hooks are dropped.
+    pub fn into_router(self) -> Router {
+        assemble_router(self.state, &self.config, self.routers)
+    }
+
+    pub async fn build(self) -> anyhow::Result<HttpServer> {
+        let Self {
+            state,
+            config,
+            routers,
+            on_startup,
+            on_shutdown,
+        } = self;
+        let shutdown = Arc::clone(&state.shutdown);
+        let router = assemble_router(state, &config, routers);
+
+        let listener = bind(config.port, config.tcp_backlog)
+            .with_context(|| format!("Failed to bind to port {}", config.port))?;
+
+        #[cfg(feature = "tls")]
+        let tls = config
+            .tls
+            .as_ref()
+            .map(|tls| tls::acceptor(tls, config.http2))
+            .transpose()?;
+        #[cfg(not(feature = "tls"))]
+        if config.tls.is_some() {
+            anyhow::bail!("TLS is not enabled in this build");
+        }
+
+        Ok(HttpServer {
+            router,
+            listener,
+            builder: config.connection_builder(),
+            tcp_nodelay: config.tcp_nodelay,
+            shutdown,
+            shutdown_timeout: config.shutdown_timeout,
+            on_startup,
+            on_shutdown,
+            #[cfg(feature = "tls")]
+            tls,
+        })
+    }
+}
+
+pub struct HttpServer {
+    router: Router,
+    listener: TcpListener,
+    builder: ConnectionBuilder,
+    tcp_nodelay: bool,
+    shutdown: Arc<watch::Sender<bool>>,
+    shutdown_timeout: Duration,
+    on_startup: Vec<LifecycleHook>,
+    on_shutdown: Vec<LifecycleHook>,
+    #[cfg(feature = "tls")]
+    tls: Option<tokio_rustls::TlsAcceptor>,
+}
+
+impl HttpServer {
+    #[must_use]
+    pub fn builder<R: AuthorRepository>(
+        state: AppState<R>,
+        config: HttpServerConfig,
+    ) -> HttpServerBuilder<R> {
+        HttpServerBuilder {
+            state,
+            config,
+            routers: Vec::new(),
+            on_startup: Vec::new(),
+            on_shutdown: Vec::new(),
+        }
+    }
+
+    /// Gives up the bound listener and returns the router it would have served.
+    pub fn into_router(self) -> Router {
+        self.router
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        self.run_until(shutdown_signal()).await
+    }
+
+    pub async fn run_until(mut self, signal: impl Future<Output = ()>) -> anyhow::Result<()> {
+        for hook in std::mem::take(&mut self.on_startup) {
+            hook.await.context("Startup hook failed")?;
+        }
+        tracing::info!("Listening on {}", self.local_addr()?);
+        tokio::pin!(signal);
+        loop {
+            let accepted = tokio::select! {
+                accepted = self.listener.accept() => accepted,
+                () = &mut signal => break,
+            };
+            let (stream, remote) = match accepted {
+                Ok(accepted) => accepted,
+                Err(err) => {
+                    tracing::warn!("Failed to accept connection: {err}");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            if let Err(err) = stream.set_nodelay(self.tcp_nodelay) {
+                tracing::warn!(%remote, "Failed to set TCP_NODELAY: {err}");
+            }
+            self.spawn_connection(stream, remote);
+        }
+
+        let Self {
+            listener,
+            shutdown,
+            shutdown_timeout,
+            on_shutdown,
+            ..
+        } = self;
+        drop(listener);
+        tracing::info!("Shutting down, waiting up to {shutdown_timeout:?} for open connections");
+        shutdown.send_replace(true);
+        if tokio::time::timeout(shutdown_timeout, shutdown.closed())
+            .await
+            .is_err()
+        {
+            tracing::warn!("Timed out waiting for open connections to close");
+        }
+        for hook in on_shutdown {
+            if let Err(err) = hook.await {
+                tracing::warn!("Shutdown hook failed: {err:?}");
+            }
+        }
+        Ok(())
+    }
+
+    fn spawn_connection(&self, stream: TcpStream, remote: SocketAddr) {
+        let service = AddExtension::new(self.router.clone(), ConnectInfo(remote));
+        let service = TowerToHyperService::new(service);
+        let builder = self.builder.clone();
+        let shutdown = self.shutdown.subscribe();
+        #[cfg(feature = "tls")]
+        let tls = self.tls.clone();
+
+        tokio::spawn(async move {
+            #[cfg(feature = "tls")]
+            if let Some(acceptor) = tls {
+                match acceptor.accept(stream).await {
+                    Ok(stream) => {
+                        let io = TokioIo::new(stream);
+                        serve_connection(&builder, io, service, remote, shutdown).await;
+                    }
+                    Err(err) => tracing::debug!(%remote, "TLS handshake failed: {err}"),
+                }
+                return;
+            }
+            serve_connection(&builder, TokioIo::new(stream), service, remote, shutdown).await;
+        });
+    }
+}
+
+fn bind(port: u16, backlog: u32) -> io::Result<TcpListener> {
+    let socket = TcpSocket::new_v4()?;
+    socket.set_reuseaddr(true)?;
+    socket.bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)))?;
+    socket.listen(backlog)
+}
+
+async fn serve_connection<I>(
+    builder: &ConnectionBuilder,
+    io: I,
+    service: ConnectionService,
+    remote: SocketAddr,
+    shutdown: watch::Receiver<bool>,
+) where
+    I: hyper::rt::Read + hyper::rt::Write + Unpin + Send + 'static,
+{
+    let result = match builder {
+        ConnectionBuilder::Auto(builder) => {
+            let connection = builder.serve_connection_with_upgrades(io, service);
+            until_shutdown(connection, shutdown, |connection| {
+                connection.graceful_shutdown();
+            })
+            .await
+        }
+        ConnectionBuilder::Http1(builder) => {
+            let connection = builder.serve_connection(io, service).with_upgrades();
+            until_shutdown(connection, shutdown, |connection| {
+                connection.graceful_shutdown();
+            })
+            .await
+            .map_err(Into::into)
+        }
+    };
+    if let Err(err) = result {
+        tracing::debug!(%remote, "Connection closed with error: {err}");
+    }
+}
+
+async fn until_shutdown<C>(
+    connection: C,
+    mut shutdown: watch::Receiver<bool>,
+    graceful_shutdown: impl FnOnce(Pin<&mut C>),
+) -> C::Output
+where
+    C: Future,
+{
+    tokio::pin!(connection);
+    tokio::select! {
+        output = connection.as_mut() => return output,
+        () = shutdown_requested(&mut shutdown) => graceful_shutdown(connection.as_mut()),
+    }
+    connection.await
+}
+
+async fn shutdown_requested(shutdown: &mut watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|stop| *stop).await;
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {err}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(err) => {
+                tracing::error!("Failed to listen for SIGTERM: {err}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// The largest body any route accepts.
+pub(crate) const MAX_RESTORE_BYTES: usize = 512 * 1024 * 1024;
+
+/// An avatar with room for the multipart framing around it.
+pub(crate) const MAX_AVATAR_BODY_BYTES: usize = AvatarImage::MAX_BYTES + 64 * 1024;
+
+const ROUTES: &[&str] = &[
+    "/api/v1/authors",
+    "/api/v1/authors/count",
+    "/api/v1/authors/import",
+    "/api/v1/authors/events",
+    "/api/v1/authors/stats",
+    "/api/v1/authors/export.csv",
+    "/api/v1/authors/export.ndjson",
+    "/api/v1/authors/ws",
+    "/api/v1/authors/{id}",
+    "/api/v1/authors/{id}/archive",
+    "/api/v1/authors/{id}/unarchive",
+    "/api/v1/authors/{id}/audit",
+    "/api/v1/authors/{id}/export",
+    "/api/v1/authors/{id}/purge",
+    "/api/v1/authors/{id}/avatar",
+    "/api/v1/authors/{id}/aliases",
+    "/api/v1/authors/{id}/aliases/{alias}",
+    "/api/v1/authors/{id}/genres",
+    "/api/v1/authors/{id}/genres/{genre_id}",
+    "/api/v1/authors/{id}/contracts",
+    "/api/v1/authors/{id}/contracts/{contract_id}",
+    "/api/v1/authors/{id}/external-works",
+    "/api/v1/genres",
+    "/api/v1/genres/{genre_id}",
+    "/api/v1/publishers",
+    "/api/v1/publishers/{publisher_id}",
+    "/api/v1/publishers/{publisher_id}/contracts",
+    "/api/v1/operations/{id}",
+    "/api/v1/admin/backup",
+    "/api/v1/admin/bans",
+    #[cfg(feature = "chaos")]
+    "/api/v1/admin/chaos",
+    "/api/v1/admin/flags",
+    "/api/v1/admin/loglevel",
+    "/api/v1/admin/migrations",
+    "/api/v1/admin/retention",
+    "/api/v1/admin/runtime",
+    "/api/v1/admin/restore",
+    "/api/v2/authors",
+    "/api/v2/authors/count",
+    "/api/v2/authors/{id}",
+    "/admin",
+    "/admin/authors",
+    "/admin/authors/{id}/delete",
+    "/admin/login",
+    "/admin/callback",
+    "/admin/logout",
+    "/assets/{file}",
+];
+
+fn routes<R: AuthorRepository>(cache_control: &CacheControlConfig) -> Router<AppState<R>> {
+    Router::new()
+        .nest("/api/v1", api_routes(cache_control))
+        .nest("/api/v2", api_v2_routes(cache_control))
+        .nest("/admin", dashboard_routes())
+        .route(
+            "/assets/{file}",
+            get(serve_asset).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .method_not_allowed_fallback(method_not_allowed)
+        .fallback(route_not_found)
+}
+
+/// Server-rendered pages for operators; they call the service layer just like the JSON handlers.
+fn dashboard_routes<R: AuthorRepository>() -> Router<AppState<R>> {
+    Router::new()
+        .route(
+            "/",
+            get(dashboard).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/authors",
+            post(create_author_form).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/authors/{id}/delete",
+            post(delete_author_form).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/login",
+            get(login).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/callback",
+            get(callback).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/logout",
+            post(logout).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .method_not_allowed_fallback(method_not_allowed)
+}
+
+/// Reuses the v1 handlers; only the response shape differs, so far just the envelope.
+fn api_v2_routes<R: AuthorRepository>(cache_control: &CacheControlConfig) -> Router<AppState<R>> {
+    let cached =
+        |value: &HeaderValue| middleware::from_fn_with_state(value.clone(), conditional_get);
+    let response_cached =
+        middleware::from_fn_with_state(cache_control.response_cache.clone(), serve_cached);
+    let author_routes = Router::new()
+        .route(
+            "/",
+            get(list_authors)
+                .layer(response_cached)
+                .post(create_author)
+                .options(|| allowed_methods("GET,HEAD,POST,OPTIONS"))
+                .layer(cached(&cache_control.authors)),
+        )
+        .route(
+            "/count",
+            get(count_authors)
+                .options(|| allowed_methods("GET,HEAD,OPTIONS"))
+                .layer(cached(&cache_control.authors)),
+        )
+        .route(
+            "/{id}",
+            get(get_author)
+                .delete(delete_author)
+                .options(|| allowed_methods("GET,HEAD,DELETE,OPTIONS"))
+                .layer(cached(&cache_control.author))
+                .head(author_exists),
+        )
+        .method_not_allowed_fallback(method_not_allowed);
+    Router::new()
+        .nest("/authors", author_routes)
+        .layer(middleware::from_fn(envelope))
+}
+
+fn api_routes<R: AuthorRepository>(cache_control: &CacheControlConfig) -> Router<AppState<R>> {
+    let cached =
+        |value: &HeaderValue| middleware::from_fn_with_state(value.clone(), conditional_get);
+    let response_cached =
+        middleware::from_fn_with_state(cache_control.response_cache.clone(), serve_cached);
+    let author_routes = Router::new()
+        .route(
+            "/",
+            get(list_authors)
+                .layer(response_cached.clone())
+                .post(create_author)
+                .put(upsert_author)
+                .options(|| allowed_methods("GET,HEAD,POST,PUT,OPTIONS"))
+                .layer(cached(&cache_control.authors)),
+        )
+        .route(
+            "/count",
+            get(count_authors)
+                .options(|| allowed_methods("GET,HEAD,OPTIONS"))
+                .layer(cached(&cache_control.authors)),
+        )
+        .route(
+            "/import",
+            post(import_authors).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/stats",
+            get(author_stats)
+                .layer(response_cached)
+                .options(|| allowed_methods("GET,HEAD,OPTIONS"))
+                .layer(cached(&cache_control.authors)),
+        )
+        .route(
+            "/events",
+            get(stream_author_events).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/export.csv",
+            get(export_authors_csv).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/export.ndjson",
+            get(export_authors_ndjson).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/ws",
+            get(author_updates).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/{id}",
+            get(get_author)
+                .put(replace_author)
+                .patch(update_author)
+                .delete(delete_author)
+                .options(|| async {
+                    let allow = allowed_methods("GET,HEAD,PUT,PATCH,DELETE,OPTIONS").await;
+                    ([(ACCEPT_PATCH, PATCH_FORMATS)], allow)
+                })
+                .layer(cached(&cache_control.author))
+                // Registered after the cache layer: an empty existence response has no ETag.
+                .head(author_exists),
+        )
+        .route(
+            "/{id}/archive",
+            post(archive_author).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/{id}/unarchive",
+            post(unarchive_author).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/{id}/audit",
+            get(find_audit_log)
+                .options(|| allowed_methods("GET,HEAD,OPTIONS"))
+                .layer(cached(&cache_control.audit_log)),
+        )
+        .route(
+            "/{id}/export",
+            get(export_author_data).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/{id}/purge",
+            delete(purge_author).options(|| allowed_methods("DELETE,OPTIONS")),
+        )
+        .route(
+            "/{id}/avatar",
+            get(find_avatar)
+                .put(upload_avatar)
+                .options(|| allowed_methods("GET,HEAD,PUT,OPTIONS"))
+                .layer(DefaultBodyLimit::max(MAX_AVATAR_BODY_BYTES))
+                .layer(cached(&cache_control.avatar)),
+        )
+        .route(
+            "/{id}/aliases",
+            get(find_author_aliases)
+                .post(add_author_alias)
+                .options(|| allowed_methods("GET,HEAD,POST,OPTIONS")),
+        )
+        .route(
+            "/{id}/aliases/{alias}",
+            delete(remove_author_alias).options(|| allowed_methods("DELETE,OPTIONS")),
+        )
+        .route(
+            "/{id}/genres",
+            get(find_author_genres).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/{id}/genres/{genre_id}",
+            put(attach_genre)
+                .delete(detach_genre)
+                .options(|| allowed_methods("PUT,DELETE,OPTIONS")),
+        )
+        .route(
+            "/{id}/contracts",
+            get(find_author_contracts)
+                .post(create_contract)
+                .options(|| allowed_methods("GET,HEAD,POST,OPTIONS")),
+        )
+        .route(
+            "/{id}/contracts/{contract_id}",
+            delete(delete_contract).options(|| allowed_methods("DELETE,OPTIONS")),
+        )
+        .route(
+            "/{id}/external-works",
+            get(find_external_works)
+                .options(|| allowed_methods("GET,HEAD,OPTIONS"))
+                .layer(cached(&cache_control.author)),
+        )
+        .method_not_allowed_fallback(method_not_allowed);
+    let genre_routes = Router::new()
+        .route(
+            "/",
+            get(list_genres)
+                .post(create_genre)
+                .options(|| allowed_methods("GET,HEAD,POST,OPTIONS")),
+        )
+        .route(
+            "/{genre_id}",
+            delete(delete_genre).options(|| allowed_methods("DELETE,OPTIONS")),
+        )
+        .method_not_allowed_fallback(method_not_allowed);
+    let publisher_routes = Router::new()
+        .route(
+            "/",
+            get(list_publishers)
+                .post(create_publisher)
+                .options(|| allowed_methods("GET,HEAD,POST,OPTIONS")),
+        )
+        .route(
+            "/{publisher_id}",
+            get(find_publisher)
+                .delete(delete_publisher)
+                .options(|| allowed_methods("GET,HEAD,DELETE,OPTIONS")),
+        )
+        .route(
+            "/{publisher_id}/contracts",
+            get(find_publisher_contracts).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .method_not_allowed_fallback(method_not_allowed);
+    let admin_routes = Router::new()
+        .route(
+            "/flags",
+            get(find_feature_flags).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/loglevel",
+            get(find_log_level)
+                .put(update_log_level)
+                .options(|| allowed_methods("GET,HEAD,PUT,OPTIONS")),
+        )
+        .route(
+            "/bans",
+            get(find_bans).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/backup",
+            post(create_backup).options(|| allowed_methods("POST,OPTIONS")),
+        )
+        .route(
+            "/migrations",
+            get(find_migrations).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/retention",
+            get(find_retention).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .route(
+            "/runtime",
+            get(find_runtime_metrics).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        );
+    #[cfg(feature = "chaos")]
+    let admin_routes = admin_routes.route(
+        "/chaos",
+        get(find_chaos)
+            .put(update_chaos)
+            .options(|| allowed_methods("GET,HEAD,PUT,OPTIONS")),
+    );
+    let admin_routes = admin_routes
+        .route(
+            "/restore",
+            post(restore_backup)
+                .options(|| allowed_methods("POST,OPTIONS"))
+                .layer(DefaultBodyLimit::max(MAX_RESTORE_BYTES)),
+        )
+        .method_not_allowed_fallback(method_not_allowed);
+    Router::new()
+        .nest("/authors", author_routes)
+        .nest("/genres", genre_routes)
+        .nest("/publishers", publisher_routes)
+        .route(
+            "/operations/{id}",
+            get(find_operation).options(|| allowed_methods("GET,HEAD,OPTIONS")),
+        )
+        .nest("/admin", admin_routes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{
+        AppState, CacheControlConfig, HttpServer, HttpServerConfig, ROUTES, ResponseCache,
+        build_router, routes,
+    };
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{HeaderValue, Method, StatusCode, Version, header};
+    use axum::middleware;
+    use axum::response::Response;
+    use axum::routing::get;
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use std::collections::BTreeSet;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+    use tokio::net::TcpStream;
+    use tokio::sync::oneshot;
+    use tower::ServiceExt;
+
+    fn state() -> AppState {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        AppState::new(service)
+    }
+
+    fn router() -> Router {
+        routes(&CacheControlConfig::default()).with_state(state())
+    }
+
+    async fn spawn_server(config: HttpServerConfig) -> SocketAddr {
+        let server = HttpServer::builder(state(), config).build().await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(server.run());
+        addr
+    }
+
+    async fn send_http2(addr: SocketAddr) -> hyper::Result<Response<hyper::body::Incoming>> {
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, connection) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                .await?;
+        tokio::spawn(connection);
+        let request = Request::builder()
+            .uri(format!("http://{addr}/api/v1/authors"))
+            .body(Body::empty())
+            .unwrap();
+        sender.send_request(request).await
+    }
+
+    async fn send(method: Method, uri: &str) -> Response {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        router().oneshot(request).await.unwrap()
+    }
+
+    fn allow(response: &Response) -> BTreeSet<String> {
+        response.headers()[header::ALLOW]
+            .to_str()
+            .unwrap()
+            .split(',')
+            .map(|method| method.trim().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn options_and_method_not_allowed_agree_on_allowed_methods() {
+        for route in ROUTES {
+            let uri = &route
+                .replace("{id}", "1")
+                .replace("{alias}", "Alias")
+                .replace("{genre_id}", "1")
+                .replace("{contract_id}", "1")
+                .replace("{publisher_id}", "1")
+                .replace("{file}", "admin.css");
+            let options = send(Method::OPTIONS, uri).await;
+            assert_eq!(StatusCode::NO_CONTENT, options.status(), "OPTIONS {uri}");
+
+            let not_allowed = send(Method::TRACE, uri).await;
+            assert_eq!(
+                StatusCode::METHOD_NOT_ALLOWED,
+                not_allowed.status(),
+                "TRACE {uri}"
+            );
+            assert_eq!(allow(&options), allow(&not_allowed), "Allow for {uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn head_is_answered_for_get_routes() {
+        let response = send(Method::HEAD, "/api/v1/authors").await;
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn authors_are_counted_and_probed_without_bodies() {
+        let router = router();
+        let create = Request::post("/api/v1/authors")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"name":"JRR Tolkien","email":"jrr.tolkien@example.com"}"#,
+            ))
+            .unwrap();
+        let created = router.clone().oneshot(create).await.unwrap();
+        assert_eq!(StatusCode::CREATED, created.status());
+
+        let request = Request::get("/api/v1/authors/count")
+            .body(Body::empty())
+            .unwrap();
+        let count = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, count.status());
+        let body = to_bytes(count.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(r#"{"count":1}"#, body);
+
+        for (uri, expected) in [
+            ("/api/v1/authors/1", StatusCode::OK),
+            ("/api/v1/authors/2", StatusCode::NOT_FOUND),
+        ] {
+            let request = Request::head(uri).body(Body::empty()).unwrap();
+            let response = router.clone().oneshot(request).await.unwrap();
+            assert_eq!(expected, response.status(), "HEAD {uri}");
+            assert!(!response.headers().contains_key(header::ETAG), "HEAD {uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn changing_a_missing_author_is_not_found() {
+        let update = Request::patch("/api/v1/authors/404")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":"Nobody"}"#))
+            .unwrap();
+        let response = router().oneshot(update).await.unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, response.status(), "PATCH");
+
+        for (method, uri) in [
+            (Method::DELETE, "/api/v1/authors/404"),
+            (Method::POST, "/api/v1/authors/404/archive"),
+        ] {
+            let response = send(method.clone(), uri).await;
+            assert_eq!(StatusCode::NOT_FOUND, response.status(), "{method} {uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_listings_are_kept_apart_by_accept_and_client() {
+        let cache = ResponseCache::new(Duration::from_secs(60));
+        let config = CacheControlConfig::default().with_response_cache(cache);
+        let router = routes(&config).with_state(state());
+        let list = |accept: &str, client: &str| {
+            Request::get("/api/v1/authors")
+                .header(header::ACCEPT, accept)
+                .header("x-client-id", client)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let content_type = |response: &Response| {
+            let value = &response.headers()[header::CONTENT_TYPE];
+            value.to_str().unwrap().to_string()
+        };
+
+        let json = router.clone().oneshot(list("application/json", "a")).await;
+        assert_eq!("application/json", content_type(&json.unwrap()));
+        let json = router.clone().oneshot(list("application/json", "a")).await;
+        assert_eq!("hit", json.unwrap().headers()["x-cache"]);
+        let ndjson = router
+            .clone()
+            .oneshot(list("application/x-ndjson", "a"))
+            .await;
+        assert_eq!("application/x-ndjson", content_type(&ndjson.unwrap()));
+        let other = router.oneshot(list("application/json", "b")).await.unwrap();
+        assert_eq!("miss", other.headers()["x-cache"]);
+    }
+
+    #[tokio::test]
+    async fn upserting_by_email_creates_then_replaces() {
+        let router = router();
+        let upsert = |name: &str| {
+            let body = format!(r#"{{"name":"{name}","email":"upsert@example.com"}}"#);
+            Request::put("/api/v1/authors?key=email")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+        let response = router.clone().oneshot(upsert("Upserted")).await.unwrap();
+        assert_eq!(StatusCode::CREATED, response.status());
+        let response = router
+            .clone()
+            .oneshot(upsert("Upserted Again"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+
+        let unkeyed = Request::put("/api/v1/authors")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"name":"Unkeyed","email":"unkeyed@example.com"}"#,
+            ))
+            .unwrap();
+        let response = router.oneshot(unkeyed).await.unwrap();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, response.status());
+    }
+
+    #[tokio::test]
+    async fn unknown_routes_fall_back_to_structured_not_found() {
+        let response = send(Method::GET, "/api/v1/author").await;
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+        assert_eq!(
+            "application/json",
+            response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn http2_prior_knowledge_is_served_when_enabled() {
+        let addr = spawn_server(HttpServerConfig::new(0)).await;
+        let response = send_http2(addr).await.unwrap();
+        assert_eq!(Version::HTTP_2, response.version());
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn http2_is_refused_when_disabled() {
+        let addr = spawn_server(HttpServerConfig::new(0).with_http2(false)).await;
+        assert!(send_http2(addr).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn statically_dispatched_state_serves_the_same_routes() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new_static(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let router: Router =
+            routes(&CacheControlConfig::default()).with_state(AppState::new(service));
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/authors")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"name":"Ursula K. Le Guin","email":"ursula@example.com"}"#,
+            ))
+            .unwrap();
+        let created = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::CREATED, created.status());
+        let request = Request::builder()
+            .uri("/api/v1/authors/1")
+            .body(Body::empty())
+            .unwrap();
+        let found = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, found.status());
+    }
+
+    #[tokio::test]
+    async fn builder_adds_routes_layers_fallback_and_hooks() {
+        let started = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let server = HttpServer::builder(state(), HttpServerConfig::new(0))
+            .merge(Router::new().route("/plugin", get(|| async { "plugin" })))
+            .layer(middleware::map_response(|mut response: Response| async {
+                response
+                    .headers_mut()
+                    .insert("x-plugin", HeaderValue::from_static("on"));
+                response
+            }))
+            .fallback(|| async { StatusCode::IM_A_TEAPOT })
+            .on_startup({
+                let started = Arc::clone(&started);
+                async move {
+                    started.store(true, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .on_shutdown({
+                let stopped = Arc::clone(&stopped);
+                async move {
+                    stopped.store(true, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .build()
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (stop, signal) = oneshot::channel::<()>();
+        let running = tokio::spawn(server.run_until(async {
+            let _ = signal.await;
+        }));
+
+        let plugin = reqwest::get(format!("http://{addr}/plugin")).await.unwrap();
+        assert!(started.load(Ordering::SeqCst));
+        assert_eq!(StatusCode::OK, plugin.status());
+        assert_eq!("on", plugin.headers()["x-plugin"]);
+        assert!(plugin.headers().contains_key("x-request-id"));
+        assert_eq!("plugin", plugin.text().await.unwrap());
+        let authors = reqwest::get(format!("http://{addr}/api/v1/authors"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, authors.status());
+        assert_eq!("on", authors.headers()["x-plugin"]);
+        let missing = reqwest::get(format!("http://{addr}/missing"))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::IM_A_TEAPOT, missing.status());
+
+        stop.send(()).unwrap();
+        running.await.unwrap().unwrap();
+        assert!(stopped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn built_router_can_be_nested_without_binding() {
+        let api = build_router(state(), &HttpServerConfig::new(0));
+        let app = Router::new().nest_service("/authors-api", api);
+        let request = Request::builder()
+            .uri("/authors-api/api/v1/authors")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert!(response.headers().contains_key("x-request-id"));
+
+        let router = HttpServer::builder(state(), HttpServerConfig::new(0))
+            .merge(Router::new().route("/plugin", get(|| async { "plugin" })))
+            .into_router();
+        let request = Request::builder()
+            .uri("/plugin")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn runtime_metrics_count_requests_per_route_template() {
+        let state = state().with_admin_token(Some("secret".into()));
+        let router = build_router(state, &HttpServerConfig::new(0));
+        for id in [1, 2] {
+            let request = Request::get(format!("/api/v1/authors/{id}"))
+                .body(Body::empty())
+                .unwrap();
+            router.clone().oneshot(request).await.unwrap();
+        }
+
+        let request = Request::get("/api/v1/admin/runtime")
+            .header(header::AUTHORIZATION, "Bearer secret")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(2, body["routes"]["/api/v1/authors/{id}"]["completed"]);
+        assert_eq!(1, body["routes"]["/api/v1/admin/runtime"]["in_flight"]);
+        assert!(
+            body["workers"]
+                .as_array()
+                .is_some_and(|workers| !workers.is_empty())
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_startup_hook_stops_the_server() {
+        let server = HttpServer::builder(state(), HttpServerConfig::new(0))
+            .on_startup(async { anyhow::bail!("cache warm-up failed") })
+            .build()
+            .await
+            .unwrap();
+        let err = server.run().await.unwrap_err();
+        assert!(format!("{err:#}").contains("cache warm-up failed"));
+    }
+}
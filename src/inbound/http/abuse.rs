@@ -0,0 +1,331 @@
+use crate::domain::model::{RecordSecurityEventRequest, SecurityEventKind};
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::handlers::HttpError;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Clients tracked before those with nothing left in their windows are forgotten.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BanReason {
+    AuthFailures,
+    ValidationErrors,
+}
+
+impl BanReason {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AuthFailures => "auth_failures",
+            Self::ValidationErrors => "validation_errors",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ban {
+    client: String,
+    reason: BanReason,
+    expires_at: DateTime<Utc>,
+}
+
+impl Ban {
+    #[must_use]
+    pub fn client(&self) -> &str {
+        &self.client
+    }
+
+    #[must_use]
+    pub const fn reason(&self) -> BanReason {
+        self.reason
+    }
+
+    #[must_use]
+    pub const fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, Default)]
+struct ClientWindows {
+    auth_failures: VecDeque<Instant>,
+    validation_errors: VecDeque<Instant>,
+    banned: Option<(Instant, Ban)>,
+}
+
+impl ClientWindows {
+    fn is_idle(&self, now: Instant) -> bool {
+        self.auth_failures.is_empty()
+            && self.validation_errors.is_empty()
+            && self.banned.as_ref().is_none_or(|(until, _)| *until <= now)
+    }
+}
+
+/// Counts failed authentications and validation errors per client over a sliding window, and
+/// bans a client that goes over either limit for a while. Counts live in this process only, so
+/// each replica bans on its own.
+#[derive(Debug, Clone)]
+pub struct AbuseGuard {
+    auth_failures: usize,
+    validation_errors: usize,
+    window: Duration,
+    ban: Duration,
+    trust_forwarded: bool,
+    clients: Arc<Mutex<HashMap<String, ClientWindows>>>,
+}
+
+impl Default for AbuseGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbuseGuard {
+    /// Bans for 15 minutes after 10 failed authentications or 50 validation errors in a minute.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            auth_failures: 10,
+            validation_errors: 50,
+            window: Duration::from_secs(60),
+            ban: Duration::from_secs(15 * 60),
+            trust_forwarded: false,
+            clients: Arc::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_auth_failures(mut self, limit: usize) -> Self {
+        self.auth_failures = limit;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_validation_errors(mut self, limit: usize) -> Self {
+        self.validation_errors = limit;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_ban(mut self, ban: Duration) -> Self {
+        self.ban = ban;
+        self
+    }
+
+    /// Identifies clients by the last `X-Forwarded-For` address rather than the peer, which is
+    /// only safe behind a proxy that appends to the header.
+    #[must_use]
+    pub const fn with_trust_forwarded(mut self, trust_forwarded: bool) -> Self {
+        self.trust_forwarded = trust_forwarded;
+        self
+    }
+
+    /// Bans still in force, soonest to expire first.
+    #[must_use]
+    pub fn bans(&self) -> Vec<Ban> {
+        let now = Instant::now();
+        let clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut bans: Vec<_> = clients
+            .values()
+            .filter_map(|windows| windows.banned.as_ref())
+            .filter(|(until, _)| *until > now)
+            .map(|(_, ban)| ban.clone())
+            .collect();
+        bans.sort_by(|a, b| (a.expires_at, &a.client).cmp(&(b.expires_at, &b.client)));
+        bans
+    }
+
+    fn client(&self, request: &Request) -> Option<String> {
+        if self.trust_forwarded {
+            let forwarded = request
+                .headers()
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .map(str::trim)
+                .rfind(|address| !address.is_empty());
+            if let Some(address) = forwarded {
+                return Some(address.to_string());
+            }
+        }
+        request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(remote)| remote.ip().to_string())
+    }
+
+    /// How long the client's ban has left, if it has one.
+    fn banned_for(&self, client: &str, now: Instant) -> Option<Duration> {
+        let clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        let (until, _) = clients.get(client)?.banned.as_ref()?;
+        until
+            .checked_duration_since(now)
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Counts the response against the client and returns the ban it earned, if any.
+    fn record(&self, client: &str, status: StatusCode, now: Instant) -> Option<Ban> {
+        let (reason, limit) = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                (BanReason::AuthFailures, self.auth_failures)
+            }
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+                (BanReason::ValidationErrors, self.validation_errors)
+            }
+            _ => return None,
+        };
+        let mut clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        if clients.len() >= MAX_TRACKED_CLIENTS && !clients.contains_key(client) {
+            let window = self.window;
+            for windows in clients.values_mut() {
+                expire(&mut windows.auth_failures, now, window);
+                expire(&mut windows.validation_errors, now, window);
+            }
+            clients.retain(|_, windows| !windows.is_idle(now));
+        }
+        let windows = clients.entry(client.to_string()).or_default();
+        let events = match reason {
+            BanReason::AuthFailures => &mut windows.auth_failures,
+            BanReason::ValidationErrors => &mut windows.validation_errors,
+        };
+        expire(events, now, self.window);
+        events.push_back(now);
+        if events.len() < limit {
+            return None;
+        }
+        events.clear();
+        let ban = Ban {
+            client: client.to_string(),
+            reason,
+            expires_at: Utc::now() + self.ban,
+        };
+        windows.banned = Some((now + self.ban, ban.clone()));
+        Some(ban)
+    }
+}
+
+fn expire(events: &mut VecDeque<Instant>, now: Instant, window: Duration) {
+    while events
+        .front()
+        .is_some_and(|at| now.saturating_duration_since(*at) >= window)
+    {
+        events.pop_front();
+    }
+}
+
+/// Turns banned clients away with `429 Too Many Requests` and watches everyone else's error
+/// responses. New bans are written to the security event log.
+pub async fn detect_abuse<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(guard) = state.abuse.as_ref() else {
+        return next.run(request).await;
+    };
+    let Some(client) = guard.client(&request) else {
+        return next.run(request).await;
+    };
+    if let Some(left) = guard.banned_for(&client, Instant::now()) {
+        let message = "too many failed requests, try again later".to_string();
+        return HttpError::client_banned(message, left).into_response();
+    }
+    let response = next.run(request).await;
+    if let Some(ban) = guard.record(&client, response.status(), Instant::now()) {
+        tracing::warn!(
+            client = ban.client(),
+            reason = ban.reason().as_str(),
+            "Banning client until {}",
+            ban.expires_at()
+        );
+        metrics::counter!("abuse_bans_total", "reason" => ban.reason().as_str()).increment(1);
+        let event = RecordSecurityEventRequest::new(
+            SecurityEventKind::ClientBanned,
+            ban.client().to_string(),
+            ban.reason().as_str().to_string(),
+            Some(ban.expires_at()),
+        );
+        if let Err(err) = state.author_service.record_security_event(&event).await {
+            tracing::warn!("Failed to record the ban of {}: {:?}", ban.client(), err.0);
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::AppState;
+    use crate::inbound::http::abuse::{AbuseGuard, detect_abuse};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::body::Body;
+    use axum::extract::{ConnectInfo, Request};
+    use axum::http::{StatusCode, header};
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use std::net::SocketAddr;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    async fn send(router: &Router, remote: &str, uri: &str) -> axum::response::Response {
+        let mut request = Request::get(uri).body(Body::empty()).unwrap();
+        let remote: SocketAddr = remote.parse().unwrap();
+        request.extensions_mut().insert(ConnectInfo(remote));
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn clients_are_banned_after_repeated_auth_failures() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let guard = AbuseGuard::new()
+            .with_auth_failures(3)
+            .with_ban(Duration::from_secs(60));
+        let state = AppState::new(service).with_abuse_guard(guard.clone());
+        let router = Router::new()
+            .route("/denied", get(|| async { StatusCode::UNAUTHORIZED }))
+            .route("/ok", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(state.clone(), detect_abuse))
+            .with_state(state.clone());
+
+        for _ in 0..3 {
+            let response = send(&router, "10.0.0.1:4000", "/denied").await;
+            assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+        }
+        let banned = send(&router, "10.0.0.1:4001", "/ok").await;
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, banned.status());
+        assert!(banned.headers().contains_key(header::RETRY_AFTER));
+        let other = send(&router, "10.0.0.2:4000", "/ok").await;
+        assert_eq!(StatusCode::OK, other.status());
+
+        let bans = guard.bans();
+        assert_eq!(1, bans.len());
+        assert_eq!("10.0.0.1", bans[0].client());
+        let events = state.author_service.find_security_events(10).await.unwrap();
+        assert_eq!(1, events.len());
+        assert_eq!("auth_failures", events[0].reason());
+    }
+}
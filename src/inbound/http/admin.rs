@@ -0,0 +1,628 @@
+use crate::backup::BACKUP_CONTENT_TYPE;
+use crate::domain::model::{
+    FeatureFlag, MigrationStatus, RestoreBackupError, RetentionReport, SecurityEvent,
+};
+use crate::domain::ports::{AuthorRepository, DynBackupStore};
+use crate::inbound::http::AppState;
+use crate::inbound::http::abuse::Ban;
+use crate::inbound::http::handlers::{HttpError, HttpSuccess};
+use crate::inbound::http::json::StrictJson;
+use crate::inbound::http::runtime_metrics::{RuntimeMetrics, RuntimeSnapshot};
+use crate::logging::LogFilterHandle;
+use anyhow::anyhow;
+use axum::body::Bytes;
+use axum::extract::{FromRequestParts, MatchedPath, Request, State};
+use axum::http::request::Parts;
+use axum::http::{StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use tracing_subscriber::EnvFilter;
+
+pub struct AdminAuth;
+
+impl<R: AuthorRepository> FromRequestParts<AppState<R>> for AdminAuth {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState<R>) -> Result<Self, Response> {
+        let Some(token) = state.admin_token.as_deref() else {
+            let message = "admin endpoints are disabled".to_string();
+            return Err(HttpError::route_not_found(message).into_response());
+        };
+        let provided = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match provided {
+            Some(provided) if tokens_match(provided, token) => Ok(Self),
+            _ => {
+                let err = HttpError::unauthorized("a valid admin bearer token is required".into());
+                Err(([(header::WWW_AUTHENTICATE, "Bearer")], err).into_response())
+            }
+        }
+    }
+}
+
+pub(crate) fn tokens_match(provided: &str, expected: &str) -> bool {
+    let provided = Sha256::digest(provided.as_bytes());
+    let expected = Sha256::digest(expected.as_bytes());
+    provided
+        .iter()
+        .zip(expected.iter())
+        .fold(0, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct LogLevelHttpResponse {
+    filter: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateLogLevelHttpRequest {
+    filter: String,
+}
+
+fn log_filter<R: AuthorRepository>(state: &AppState<R>) -> Result<&LogFilterHandle, HttpError> {
+    state
+        .log_filter
+        .as_ref()
+        .ok_or_else(|| HttpError::route_not_found("log level reloading is disabled".into()))
+}
+
+fn current_filter(handle: &LogFilterHandle) -> Result<String, HttpError> {
+    handle.with_current(ToString::to_string).map_err(|err| {
+        HttpError::internal(&anyhow!(err).context("Failed to read the current log filter"))
+    })
+}
+
+pub async fn find_log_level<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<LogLevelHttpResponse>, HttpError> {
+    let filter = current_filter(log_filter(&state)?)?;
+    Ok(HttpSuccess::new(
+        StatusCode::OK,
+        LogLevelHttpResponse { filter },
+    ))
+}
+
+pub async fn update_log_level<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+    StrictJson(body): StrictJson<UpdateLogLevelHttpRequest>,
+) -> Result<HttpSuccess<LogLevelHttpResponse>, HttpError> {
+    let handle = log_filter(&state)?;
+    let filter = EnvFilter::try_new(&body.filter)
+        .map_err(|err| HttpError::invalid_log_filter(format!("{}: {err}", body.filter)))?;
+
+    let previous = current_filter(handle)?;
+    tracing::warn!(from = %previous, to = %filter, "Changing log filter");
+    handle.reload(filter).map_err(|err| {
+        HttpError::internal(&anyhow!(err).context("Failed to reload the log filter"))
+    })?;
+
+    let filter = current_filter(handle)?;
+    Ok(HttpSuccess::new(
+        StatusCode::OK,
+        LogLevelHttpResponse { filter },
+    ))
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct MigrationHttpResponse {
+    version: i64,
+    description: String,
+    applied: bool,
+}
+
+impl From<MigrationStatus> for MigrationHttpResponse {
+    fn from(status: MigrationStatus) -> Self {
+        Self {
+            version: status.version(),
+            description: status.description().to_string(),
+            applied: status.applied(),
+        }
+    }
+}
+
+pub async fn find_migrations<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<Vec<MigrationHttpResponse>>, HttpError> {
+    let migrations = state
+        .migrations
+        .as_ref()
+        .ok_or_else(|| HttpError::route_not_found("migration status is unavailable".into()))?;
+    let status = migrations.find_migrations().await.map_err(|err| {
+        HttpError::internal(&err.0.context("Failed to read the migration status"))
+    })?;
+    Ok(HttpSuccess::new(
+        StatusCode::OK,
+        status
+            .into_iter()
+            .map(MigrationHttpResponse::from)
+            .collect(),
+    ))
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct RetentionHttpResponse {
+    dry_run: bool,
+    archived_authors: u64,
+    audit_log: u64,
+    processed_commands: u64,
+}
+
+impl From<RetentionReport> for RetentionHttpResponse {
+    fn from(report: RetentionReport) -> Self {
+        Self {
+            dry_run: report.dry_run(),
+            archived_authors: report.archived_authors(),
+            audit_log: report.audit_log(),
+            processed_commands: report.processed_commands(),
+        }
+    }
+}
+
+/// Reports how many rows the next scheduled purge would delete, without deleting them.
+pub async fn find_retention<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<RetentionHttpResponse>, HttpError> {
+    let retention = state
+        .retention
+        .as_ref()
+        .ok_or_else(|| HttpError::route_not_found("retention is not configured".into()))?;
+    let report = retention.preview_purge(Utc::now()).await.map_err(|err| {
+        HttpError::internal(&err.0.context("Failed to preview the retention purge"))
+    })?;
+    Ok(HttpSuccess::new(StatusCode::OK, report.into()))
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeHttpResponse {
+    uptime_secs: u64,
+    in_flight_requests: u64,
+    tasks: TasksHttpResponse,
+    workers: Vec<WorkerHttpResponse>,
+    pool: Option<PoolHttpResponse>,
+    routes: BTreeMap<String, RouteHttpResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TasksHttpResponse {
+    alive: usize,
+    global_queue_depth: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkerHttpResponse {
+    busy_secs: f64,
+    utilization: f64,
+    parks: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PoolHttpResponse {
+    size: u32,
+    idle: usize,
+    active: usize,
+    acquire_wait_ms: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RouteHttpResponse {
+    in_flight: u64,
+    peak: u64,
+    completed: u64,
+}
+
+impl From<RuntimeSnapshot> for RuntimeHttpResponse {
+    fn from(snapshot: RuntimeSnapshot) -> Self {
+        let runtime = snapshot.runtime();
+        Self {
+            uptime_secs: snapshot.uptime().as_secs(),
+            in_flight_requests: snapshot.in_flight(),
+            tasks: TasksHttpResponse {
+                alive: runtime.alive_tasks(),
+                global_queue_depth: runtime.global_queue_depth(),
+            },
+            workers: runtime
+                .workers()
+                .iter()
+                .map(|worker| WorkerHttpResponse {
+                    busy_secs: worker.busy().as_secs_f64(),
+                    utilization: worker.utilization(),
+                    parks: worker.parks(),
+                })
+                .collect(),
+            pool: snapshot.pool().map(|pool| PoolHttpResponse {
+                size: pool.size(),
+                idle: pool.idle(),
+                active: pool.active(),
+                acquire_wait_ms: pool.acquire_wait().map(|wait| wait.as_secs_f64() * 1000.0),
+            }),
+            routes: snapshot
+                .routes()
+                .iter()
+                .map(|(route, counters)| {
+                    let counters = RouteHttpResponse {
+                        in_flight: counters.in_flight(),
+                        peak: counters.peak(),
+                        completed: counters.completed(),
+                    };
+                    (route.clone(), counters)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BansHttpResponse {
+    bans: Vec<BanHttpResponse>,
+    events: Vec<SecurityEventHttpResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BanHttpResponse {
+    client: String,
+    reason: &'static str,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SecurityEventHttpResponse {
+    id: i64,
+    kind: &'static str,
+    client: String,
+    reason: String,
+    expires_at: Option<DateTime<Utc>>,
+    recorded_at: DateTime<Utc>,
+}
+
+impl From<&Ban> for BanHttpResponse {
+    fn from(ban: &Ban) -> Self {
+        Self {
+            client: ban.client().to_string(),
+            reason: ban.reason().as_str(),
+            expires_at: ban.expires_at(),
+        }
+    }
+}
+
+impl From<&SecurityEvent> for SecurityEventHttpResponse {
+    fn from(event: &SecurityEvent) -> Self {
+        Self {
+            id: event.id(),
+            kind: event.kind().as_str(),
+            client: event.client().to_string(),
+            reason: event.reason().to_string(),
+            expires_at: event.expires_at(),
+            recorded_at: event.recorded_at(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeatureFlagHttpResponse {
+    name: String,
+    enabled: bool,
+    rollout: u8,
+}
+
+impl From<&FeatureFlag> for FeatureFlagHttpResponse {
+    fn from(flag: &FeatureFlag) -> Self {
+        Self {
+            name: flag.name().to_string(),
+            enabled: flag.enabled(),
+            rollout: flag.rollout(),
+        }
+    }
+}
+
+/// Lists the flags as currently loaded; flags handlers ask for but nobody set are not listed.
+pub async fn find_feature_flags<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<Vec<FeatureFlagHttpResponse>>, HttpError> {
+    let flags = match state.feature_flags.as_ref() {
+        Some(feature_flags) => feature_flags
+            .find_flags()
+            .await
+            .map_err(|err| HttpError::internal(&err.0))?,
+        None => Vec::new(),
+    };
+    let flags = flags.iter().map(Into::into).collect();
+    Ok(HttpSuccess::new(StatusCode::OK, flags))
+}
+
+/// Security events listed next to the bans in force.
+const RECENT_SECURITY_EVENTS: u32 = 50;
+
+/// Lists the bans in force on this replica, with the latest security events from all of them.
+pub async fn find_bans<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<BansHttpResponse>, HttpError> {
+    let guard = state
+        .abuse
+        .as_ref()
+        .ok_or_else(|| HttpError::route_not_found("abuse detection is disabled".into()))?;
+    let events = state
+        .author_service
+        .find_security_events(RECENT_SECURITY_EVENTS)
+        .await
+        .map_err(|err| HttpError::internal(&err.0))?;
+    let response = BansHttpResponse {
+        bans: guard.bans().iter().map(Into::into).collect(),
+        events: events.iter().map(Into::into).collect(),
+    };
+    Ok(HttpSuccess::new(StatusCode::OK, response))
+}
+
+pub async fn find_runtime_metrics<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> HttpSuccess<RuntimeHttpResponse> {
+    let snapshot = state.runtime_metrics.snapshot().await;
+    HttpSuccess::new(StatusCode::OK, snapshot.into())
+}
+
+/// Counts the request against its route template, so `/authors/1` and `/authors/2` share a
+/// counter. Unmatched requests are not counted.
+pub async fn track_in_flight(
+    State(metrics): State<RuntimeMetrics>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(route) = request.extensions().get::<MatchedPath>() else {
+        return next.run(request).await;
+    };
+    let _in_flight = metrics.start_request(route.as_str());
+    next.run(request).await
+}
+
+fn backups<R: AuthorRepository>(state: &AppState<R>) -> Result<&dyn DynBackupStore, HttpError> {
+    state
+        .backups
+        .as_deref()
+        .ok_or_else(|| HttpError::route_not_found("database backups are unavailable".into()))
+}
+
+pub async fn create_backup<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> Result<Response, HttpError> {
+    let snapshot = backups(&state)?
+        .snapshot()
+        .await
+        .map_err(|err| HttpError::internal(&err.0))?;
+    let disposition = format!(
+        r#"attachment; filename="authors-{}.sqlite""#,
+        Utc::now().format("%Y%m%dT%H%M%SZ")
+    );
+    let headers = [
+        (header::CONTENT_TYPE, BACKUP_CONTENT_TYPE.to_string()),
+        (header::CONTENT_DISPOSITION, disposition),
+    ];
+    Ok((headers, snapshot).into_response())
+}
+
+pub async fn restore_backup<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+    snapshot: Bytes,
+) -> Result<HttpSuccess<()>, HttpError> {
+    backups(&state)?
+        .restore(&snapshot)
+        .await
+        .map_err(|err| match err {
+            RestoreBackupError::Invalid(_) => HttpError::invalid_request(err.to_string()),
+            RestoreBackupError::Other(err) => HttpError::internal(&err),
+        })?;
+    tracing::warn!(
+        bytes = snapshot.len(),
+        "Restored the database from a backup"
+    );
+    Ok(HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::sqlite::{
+        Backups, ConnectRetryConfig, Migrations, PoolConfig, establish_pool,
+    };
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{Method, StatusCode, header};
+    use axum::response::Response;
+    use std::time::Duration;
+    use tower::ServiceExt;
+    use tracing_subscriber::{EnvFilter, reload};
+    use uuid::Uuid;
+
+    async fn send(router: &Router, method: Method, token: Option<&str>, body: &str) -> Response {
+        let mut request = Request::builder()
+            .method(method)
+            .uri("/api/v1/admin/loglevel")
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    async fn filter(response: Response) -> String {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["filter"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn log_level_is_read_and_updated_by_admins() {
+        let (_layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let state = AppState::new(service)
+            .with_admin_token(Some("secret".into()))
+            .with_log_filter(handle);
+        let router = routes(&CacheControlConfig::default()).with_state(state);
+
+        let anonymous = send(&router, Method::GET, None, "").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, anonymous.status());
+        assert_eq!("Bearer", anonymous.headers()[header::WWW_AUTHENTICATE]);
+        let wrong = send(&router, Method::GET, Some("guess"), "").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, wrong.status());
+
+        let current = send(&router, Method::GET, Some("secret"), "").await;
+        assert_eq!(StatusCode::OK, current.status());
+        assert_eq!("info", filter(current).await);
+
+        let body = r#"{"filter":"warn,hexarch_example=debug"}"#;
+        let updated = send(&router, Method::PUT, Some("secret"), body).await;
+        assert_eq!(StatusCode::OK, updated.status());
+        assert_eq!("hexarch_example=debug,warn", filter(updated).await);
+
+        let invalid = send(&router, Method::PUT, Some("secret"), r#"{"filter":"[="}"#).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, invalid.status());
+        let current = send(&router, Method::GET, Some("secret"), "").await;
+        assert_eq!("hexarch_example=debug,warn", filter(current).await);
+    }
+
+    #[tokio::test]
+    async fn migration_status_is_listed_for_admins() {
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool_config = PoolConfig::new(1, 1, Duration::from_secs(1));
+        let pool = establish_pool("sqlite::memory:", &retry, &pool_config)
+            .await
+            .unwrap();
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let state = AppState::new(service)
+            .with_admin_token(Some("secret".into()))
+            .with_migrations(Migrations::new(pool));
+        let router = routes(&CacheControlConfig::default()).with_state(state);
+
+        let request = Request::get("/api/v1/admin/migrations")
+            .header(header::AUTHORIZATION, "Bearer secret")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let migrations: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(20_240_113_083_736_i64, migrations[0]["version"]);
+        assert_eq!("create authors", migrations[0]["description"]);
+        assert!(
+            migrations
+                .iter()
+                .all(|migration| migration["applied"] == true)
+        );
+    }
+
+    #[tokio::test]
+    async fn backups_round_trip_through_admin_endpoints() {
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let pool = establish_pool(&url, &retry, &PoolConfig::default())
+            .await
+            .unwrap();
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let state = AppState::new(service)
+            .with_admin_token(Some("secret".into()))
+            .with_backups(Backups::new(pool.clone()));
+        let router = routes(&CacheControlConfig::default()).with_state(state);
+        let post = |uri: &str, body: Body| {
+            Request::post(uri)
+                .header(header::AUTHORIZATION, "Bearer secret")
+                .body(body)
+                .unwrap()
+        };
+
+        let backup = post("/api/v1/admin/backup", Body::empty());
+        let response = router.clone().oneshot(backup).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "application/vnd.sqlite3",
+            response.headers()[header::CONTENT_TYPE]
+        );
+        let snapshot = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(snapshot.starts_with(b"SQLite format 3\0"));
+
+        let restore = post("/api/v1/admin/restore", Body::from(snapshot));
+        let response = router.clone().oneshot(restore).await.unwrap();
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+        let restore = post("/api/v1/admin/restore", Body::from("not a database"));
+        let response = router.oneshot(restore).await.unwrap();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, response.status());
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[tokio::test]
+    async fn admin_routes_are_hidden_without_a_token() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let router = routes(&CacheControlConfig::default()).with_state(AppState::new(service));
+
+        let response = send(&router, Method::GET, Some("secret"), "").await;
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::handlers::HttpError;
+use anyhow::Context;
+use axum::extract::{Path, Request, State};
+use axum::http::{HeaderValue, StatusCode, Uri, header};
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tower_http::services::ServeDir;
+
+pub const ASSETS_PREFIX: &str = "/assets";
+
+const IMMUTABLE: HeaderValue = HeaderValue::from_static("public, max-age=31536000, immutable");
+
+/// Static files named by a digest of their contents, so they can be cached forever.
+#[derive(Debug, Clone)]
+pub struct Assets {
+    hashed: Arc<HashMap<String, String>>,
+    originals: Arc<HashMap<String, String>>,
+    serve_dir: ServeDir,
+}
+
+impl Assets {
+    /// Hashes every file in `dir`; a sibling `<name>.gz` is served to clients that accept gzip.
+    pub fn load(dir: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let dir = dir.into();
+        let mut hashed = HashMap::new();
+        let mut originals = HashMap::new();
+        let entries = std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read the asset directory {}", dir.display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !path.is_file() || name.ends_with(".gz") {
+                continue;
+            }
+            let contents = std::fs::read(&path)
+                .with_context(|| format!("Failed to read the asset {}", path.display()))?;
+            let name_with_hash = hashed_name(name, &contents);
+            originals.insert(name_with_hash.clone(), name.to_string());
+            hashed.insert(name.to_string(), name_with_hash);
+        }
+        Ok(Self {
+            hashed: Arc::new(hashed),
+            originals: Arc::new(originals),
+            serve_dir: ServeDir::new(dir).precompressed_gzip(),
+        })
+    }
+
+    /// The URL of `name`, including its content hash when the asset is known.
+    pub fn url(&self, name: &str) -> String {
+        let name = self.hashed.get(name).map_or(name, String::as_str);
+        format!("{ASSETS_PREFIX}/{name}")
+    }
+}
+
+fn hashed_name(name: &str, contents: &[u8]) -> String {
+    let digest = Sha256::digest(contents);
+    let hash: String = digest[..8]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    match name.rsplit_once('.') {
+        Some((stem, extension)) => format!("{stem}.{hash}.{extension}"),
+        None => format!("{name}.{hash}"),
+    }
+}
+
+pub async fn serve_asset<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    Path(file): Path<String>,
+    request: Request,
+) -> Response {
+    let not_found = || HttpError::route_not_found(format!("no asset named {file}")).into_response();
+    let Some(assets) = state.assets.as_ref() else {
+        return not_found();
+    };
+    let Some(original) = assets.originals.get(&file) else {
+        return not_found();
+    };
+    let (mut parts, body) = request.into_parts();
+    parts.uri = match Uri::try_from(format!("/{original}")) {
+        Ok(uri) => uri,
+        Err(_) => return not_found(),
+    };
+    let mut response = match assets
+        .serve_dir
+        .clone()
+        .try_call(Request::from_parts(parts, body))
+        .await
+    {
+        Ok(response) => response.into_response(),
+        Err(err) => {
+            let err = anyhow::Error::new(err).context(format!("Failed to serve the asset {file}"));
+            return HttpError::internal(&err).into_response();
+        }
+    };
+    if response.status() == StatusCode::OK {
+        response
+            .headers_mut()
+            .insert(header::CACHE_CONTROL, IMMUTABLE);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::assets::Assets;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{StatusCode, header};
+    use axum::response::Response;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn router(assets: Assets) -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let state = AppState::new(service).with_assets(assets);
+        routes(&CacheControlConfig::default()).with_state(state)
+    }
+
+    async fn get(router: &Router, uri: &str, encoding: Option<&str>) -> Response {
+        let mut request = Request::get(uri);
+        if let Some(encoding) = encoding {
+            request = request.header(header::ACCEPT_ENCODING, encoding);
+        }
+        let request = request.body(Body::empty()).unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn assets_are_served_under_content_hashed_names() {
+        let dir = std::env::temp_dir().join(format!("hexarch-assets-{}", Uuid::now_v7()));
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("admin.css"), "body { margin: 0; }").unwrap();
+        std::fs::write(dir.join("admin.css.gz"), "pretend gzip").unwrap();
+        let assets = Assets::load(&dir).unwrap();
+        let url = assets.url("admin.css");
+        assert!(url.starts_with("/assets/admin."), "{url}");
+        assert!(url.ends_with(".css"), "{url}");
+        assert_eq!("/assets/missing.css", assets.url("missing.css"));
+        let router = router(assets);
+
+        let plain = get(&router, &url, None).await;
+        assert_eq!(StatusCode::OK, plain.status());
+        assert_eq!(
+            "public, max-age=31536000, immutable",
+            plain.headers()[header::CACHE_CONTROL]
+        );
+        assert_eq!("text/css", plain.headers()[header::CONTENT_TYPE]);
+        let body = to_bytes(plain.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&b"body { margin: 0; }"[..], &body[..]);
+
+        let gzipped = get(&router, &url, Some("gzip")).await;
+        assert_eq!(StatusCode::OK, gzipped.status());
+        assert_eq!("gzip", gzipped.headers()[header::CONTENT_ENCODING]);
+
+        let unhashed = get(&router, "/assets/admin.css", None).await;
+        assert_eq!(StatusCode::NOT_FOUND, unhashed.status());
+        let stale = get(&router, "/assets/admin.0000000000000000.css", None).await;
+        assert_eq!(StatusCode::NOT_FOUND, stale.status());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
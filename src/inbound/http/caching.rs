@@ -0,0 +1,217 @@
+use crate::inbound::http::handlers::HttpError;
+use axum::body::{Body, HttpBody, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, IntoResponseParts, Response, ResponseParts};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LastModified(pub DateTime<Utc>);
+
+impl IntoResponseParts for LastModified {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let date = self.0.format(HTTP_DATE_FORMAT).to_string();
+        let value = HeaderValue::from_str(&date).expect("HTTP date is a valid header value");
+        res.headers_mut().insert(header::LAST_MODIFIED, value);
+        Ok(res)
+    }
+}
+
+pub fn if_unmodified_since(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    http_date(headers.get(header::IF_UNMODIFIED_SINCE)?)
+}
+
+fn http_date(value: &HeaderValue) -> Option<DateTime<Utc>> {
+    let date = DateTime::parse_from_rfc2822(value.to_str().ok()?).ok()?;
+    Some(date.with_timezone(&Utc))
+}
+
+pub async fn conditional_get(
+    State(cache_control): State<HeaderValue>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return next.run(request).await;
+    }
+
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let if_modified_since = request
+        .headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(http_date);
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let (etag, body) = match parts.headers.get(header::ETAG) {
+        Some(etag) => (etag.clone(), body),
+        // Hashing a streamed body would mean buffering all of it first.
+        None if body.size_hint().exact().is_none() => return Response::from_parts(parts, body),
+        None => match to_bytes(body, usize::MAX).await {
+            Ok(bytes) => (strong_etag(&bytes), Body::from(bytes)),
+            Err(err) => {
+                let cause = anyhow::Error::from(err).context("Failed to buffer response body");
+                return HttpError::internal(&cause).into_response();
+            }
+        },
+    };
+    parts
+        .headers
+        .entry(header::CACHE_CONTROL)
+        .or_insert(cache_control);
+
+    let not_modified_since = || {
+        let last_modified = parts.headers.get(header::LAST_MODIFIED).and_then(http_date);
+        matches!((last_modified, if_modified_since), (Some(modified), Some(since)) if modified <= since)
+    };
+    let fresh = match &if_none_match {
+        Some(value) => etag_matches(value, &etag),
+        None => not_modified_since(),
+    };
+    if fresh {
+        return not_modified(&parts.headers, etag);
+    }
+    parts.headers.insert(header::ETAG, etag);
+    Response::from_parts(parts, body)
+}
+
+fn strong_etag(bytes: &[u8]) -> HeaderValue {
+    let etag = format!("\"{:x}\"", Sha256::digest(bytes));
+    HeaderValue::from_str(&etag).expect("hex digest is a valid header value")
+}
+
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(if_none_match) = if_none_match.to_str() else {
+        return false;
+    };
+    let etag = opaque_tag(etag.to_str().unwrap_or_default());
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || opaque_tag(candidate) == etag)
+}
+
+fn opaque_tag(etag: &str) -> &str {
+    etag.strip_prefix("W/").unwrap_or(etag)
+}
+
+fn not_modified(headers: &HeaderMap, etag: HeaderValue) -> Response {
+    let mut response = StatusCode::NOT_MODIFIED.into_response();
+    response.headers_mut().insert(header::ETAG, etag);
+    for name in [header::CACHE_CONTROL, header::LAST_MODIFIED, header::VARY] {
+        if let Some(value) = headers.get(&name) {
+            response.headers_mut().insert(name, value.clone());
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuditContext, AuthorName, CreateAuthorRequest, EmailAddress};
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::caching::etag_matches;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum::response::Response;
+    use tower::ServiceExt;
+
+    async fn router() -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        service.create_author(&create, &ctx).await.unwrap();
+        routes(&CacheControlConfig::default()).with_state(AppState::new(service))
+    }
+
+    async fn send(router: &Router, uri: &str, if_none_match: Option<&str>) -> Response {
+        let mut request = Request::get(uri);
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        let request = request.body(Body::empty()).unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn unchanged_representations_are_not_modified() {
+        let router = router().await;
+
+        let first = send(&router, "/api/v1/authors/1", None).await;
+        assert_eq!(StatusCode::OK, first.status());
+        assert_eq!("no-cache", first.headers()[header::CACHE_CONTROL]);
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+
+        let second = send(&router, "/api/v1/authors/1", Some(&etag)).await;
+        assert_eq!(StatusCode::NOT_MODIFIED, second.status());
+        assert_eq!(etag, second.headers()[header::ETAG]);
+        let body = to_bytes(second.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+
+        let list = send(&router, "/api/v1/authors", Some(&etag)).await;
+        assert_eq!(StatusCode::OK, list.status());
+        assert_ne!(etag, list.headers()[header::ETAG]);
+    }
+
+    #[tokio::test]
+    async fn if_modified_since_is_honored_without_etag() {
+        let router = router().await;
+
+        let first = send(&router, "/api/v1/authors/1", None).await;
+        let last_modified = first.headers()[header::LAST_MODIFIED].clone();
+
+        let request = Request::get("/api/v1/authors/1")
+            .header(header::IF_MODIFIED_SINCE, last_modified.clone())
+            .body(Body::empty())
+            .unwrap();
+        let second = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::NOT_MODIFIED, second.status());
+        assert_eq!(last_modified, second.headers()[header::LAST_MODIFIED]);
+
+        let request = Request::get("/api/v1/authors/1")
+            .header(header::IF_MODIFIED_SINCE, "Sun, 06 Nov 1994 08:49:37 GMT")
+            .body(Body::empty())
+            .unwrap();
+        let third = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, third.status());
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let etag = HeaderValue::from_static("\"abc\"");
+        assert!(etag_matches(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("W/\"abc\""), &etag));
+        assert!(etag_matches(
+            &HeaderValue::from_static("\"xyz\", \"abc\""),
+            &etag
+        ));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("\"xyz\""), &etag));
+    }
+}
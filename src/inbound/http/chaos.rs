@@ -0,0 +1,244 @@
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::admin::AdminAuth;
+use crate::inbound::http::handlers::{FieldErrors, HttpError, HttpSuccess};
+use crate::inbound::http::json::StrictJson;
+use anyhow::anyhow;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+const EXEMPT_PREFIX: &str = "/api/v1/admin/";
+const MAX_LATENCY: Duration = Duration::from_secs(60);
+
+/// Which faults to inject, each on a percentage of requests. Everything is off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChaosSettings {
+    latency: Duration,
+    latency_percent: u8,
+    error_percent: u8,
+    drop_percent: u8,
+}
+
+impl ChaosSettings {
+    #[must_use]
+    pub const fn with_latency(mut self, latency: Duration, percent: u8) -> Self {
+        self.latency = latency;
+        self.latency_percent = percent;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_errors(mut self, percent: u8) -> Self {
+        self.error_percent = percent;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_dropped_connections(mut self, percent: u8) -> Self {
+        self.drop_percent = percent;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.latency_percent > 0 || self.error_percent > 0 || self.drop_percent > 0
+    }
+}
+
+/// Fault injection settings shared between the middleware and the admin endpoint. Clones share
+/// the same settings.
+#[derive(Debug, Clone, Default)]
+pub struct Chaos(Arc<RwLock<ChaosSettings>>);
+
+impl Chaos {
+    #[must_use]
+    pub fn new(settings: ChaosSettings) -> Self {
+        Self(Arc::new(RwLock::new(settings)))
+    }
+
+    #[must_use]
+    pub fn settings(&self) -> ChaosSettings {
+        *self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn set(&self, settings: ChaosSettings) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = settings;
+    }
+}
+
+fn roll(percent: u8) -> bool {
+    percent > 0 && rand::thread_rng().gen_range(0..100) < percent
+}
+
+/// Delays, fails or drops a share of requests. Admin API routes are exempt so faults can always
+/// be switched off again.
+pub async fn inject_faults(State(chaos): State<Chaos>, request: Request, next: Next) -> Response {
+    let settings = chaos.settings();
+    if !settings.is_enabled() || request.uri().path().starts_with(EXEMPT_PREFIX) {
+        return next.run(request).await;
+    }
+    if roll(settings.latency_percent) {
+        tracing::debug!(latency = ?settings.latency, "Injecting latency");
+        tokio::time::sleep(settings.latency).await;
+    }
+    if roll(settings.drop_percent) {
+        tracing::debug!("Injecting a dropped connection");
+        metrics::counter!("chaos_faults_total", "fault" => "drop").increment(1);
+        return dropped_connection();
+    }
+    if roll(settings.error_percent) {
+        metrics::counter!("chaos_faults_total", "fault" => "error").increment(1);
+        return HttpError::internal(&anyhow!("Injected fault")).into_response();
+    }
+    next.run(request).await
+}
+
+/// A body that fails before yielding anything makes the server abort the connection instead of
+/// finishing the response.
+fn dropped_connection() -> Response {
+    let stream = futures::stream::once(async {
+        Err::<Vec<u8>, _>(io::Error::new(
+            io::ErrorKind::ConnectionAborted,
+            "injected dropped connection",
+        ))
+    });
+    Response::new(Body::from_stream(stream))
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChaosHttpBody {
+    #[serde(default)]
+    latency_ms: u64,
+    #[serde(default)]
+    latency_percent: u8,
+    #[serde(default)]
+    error_percent: u8,
+    #[serde(default)]
+    drop_percent: u8,
+}
+
+impl From<ChaosSettings> for ChaosHttpBody {
+    fn from(settings: ChaosSettings) -> Self {
+        Self {
+            latency_ms: u64::try_from(settings.latency.as_millis()).unwrap_or(u64::MAX),
+            latency_percent: settings.latency_percent,
+            error_percent: settings.error_percent,
+            drop_percent: settings.drop_percent,
+        }
+    }
+}
+
+impl TryFrom<ChaosHttpBody> for ChaosSettings {
+    type Error = HttpError;
+
+    fn try_from(body: ChaosHttpBody) -> Result<Self, Self::Error> {
+        let mut fields = FieldErrors::new();
+        let latency = Duration::from_millis(body.latency_ms);
+        if latency > MAX_LATENCY {
+            let max = MAX_LATENCY.as_millis();
+            fields.insert("latency_ms", format!("must be at most {max}"));
+        }
+        for (field, percent) in [
+            ("latency_percent", body.latency_percent),
+            ("error_percent", body.error_percent),
+            ("drop_percent", body.drop_percent),
+        ] {
+            if percent > 100 {
+                fields.insert(field, "must be between 0 and 100".to_string());
+            }
+        }
+        if !fields.is_empty() {
+            return Err(HttpError::invalid_fields(fields));
+        }
+        Ok(Self::default()
+            .with_latency(latency, body.latency_percent)
+            .with_errors(body.error_percent)
+            .with_dropped_connections(body.drop_percent))
+    }
+}
+
+pub async fn find_chaos<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+) -> HttpSuccess<ChaosHttpBody> {
+    HttpSuccess::new(StatusCode::OK, state.chaos.settings().into())
+}
+
+pub async fn update_chaos<R: AuthorRepository>(
+    _: AdminAuth,
+    State(state): State<AppState<R>>,
+    StrictJson(body): StrictJson<ChaosHttpBody>,
+) -> Result<HttpSuccess<ChaosHttpBody>, HttpError> {
+    let settings = ChaosSettings::try_from(body)?;
+    tracing::warn!(?settings, "Changing fault injection settings");
+    state.chaos.set(settings);
+    Ok(HttpSuccess::new(StatusCode::OK, settings.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::chaos::{Chaos, ChaosSettings, inject_faults};
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use axum::middleware;
+    use axum::routing::get;
+    use std::time::{Duration, Instant};
+    use tower::ServiceExt;
+
+    async fn send(chaos: &Chaos, uri: &str) -> (StatusCode, bool) {
+        let router = Router::new()
+            .route("/api/v1/authors", get(|| async { "ok" }))
+            .route("/api/v1/admin/chaos", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(chaos.clone(), inject_faults));
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let completed = to_bytes(response.into_body(), usize::MAX).await.is_ok();
+        (status, completed)
+    }
+
+    #[tokio::test]
+    async fn faults_are_injected_on_api_routes_but_not_admin_routes() {
+        let chaos = Chaos::default();
+        assert_eq!(
+            (StatusCode::OK, true),
+            send(&chaos, "/api/v1/authors").await
+        );
+
+        chaos.set(ChaosSettings::default().with_errors(100));
+        assert_eq!(
+            (StatusCode::INTERNAL_SERVER_ERROR, true),
+            send(&chaos, "/api/v1/authors").await
+        );
+        assert_eq!(
+            (StatusCode::OK, true),
+            send(&chaos, "/api/v1/admin/chaos").await
+        );
+
+        chaos.set(ChaosSettings::default().with_dropped_connections(100));
+        assert!(!send(&chaos, "/api/v1/authors").await.1);
+    }
+
+    #[tokio::test]
+    async fn latency_is_injected_before_the_handler_runs() {
+        let latency = Duration::from_millis(50);
+        let chaos = Chaos::new(ChaosSettings::default().with_latency(latency, 100));
+        let started = Instant::now();
+        assert_eq!(
+            (StatusCode::OK, true),
+            send(&chaos, "/api/v1/authors").await
+        );
+        assert!(started.elapsed() >= latency);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::domain::model::{
+    AdminRole, AuditContext, Author, AuthorId, CreateAuthorRequest, DeleteAuthorRequest,
+};
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::handlers::{CreateAuthorHttpRequest, HttpError};
+use crate::inbound::http::session::{AdminForm, AdminSession, CSRF_FIELD, EmptyForm};
+use axum::extract::State;
+use axum::response::{IntoResponse, Redirect, Response};
+use maud::{DOCTYPE, Markup, html};
+
+const DASHBOARD_PATH: &str = "/admin";
+
+fn csrf_field(session: &AdminSession) -> Markup {
+    html! {
+        @if let Some(token) = session.csrf_token() {
+            input type="hidden" name=(CSRF_FIELD) value=(token);
+        }
+    }
+}
+
+fn page<R: AuthorRepository>(
+    state: &AppState<R>,
+    session: &AdminSession,
+    authors: &[Author],
+    error: Option<&str>,
+) -> Markup {
+    let editor = session.can(AdminRole::Editor);
+    html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                title { "Authors · Admin" }
+                @if let Some(assets) = &state.assets {
+                    link rel="stylesheet" href=(assets.url("admin.css"));
+                }
+            }
+            body {
+                @if let Some(name) = session.name() {
+                    form method="post" action="/admin/logout" {
+                        (csrf_field(session))
+                        "Signed in as " (name) " "
+                        button type="submit" { "Sign out" }
+                    }
+                }
+                h1 { "Authors" }
+                @if let Some(error) = error {
+                    p role="alert" { (error) }
+                }
+                @if editor {
+                    form method="post" action="/admin/authors" {
+                        (csrf_field(session))
+                        label { "Name " input type="text" name="name" required; }
+                        " "
+                        label { "Email " input type="email" name="email" required; }
+                        " "
+                        button type="submit" { "Create" }
+                    }
+                }
+                table {
+                    thead {
+                        tr { th { "Id" } th { "Name" } th { "Email" } th { "Status" } th {} }
+                    }
+                    tbody {
+                        @for author in authors {
+                            tr {
+                                td { (author.id()) }
+                                td { (author.name()) }
+                                td { (author.email()) }
+                                td { (author.status().as_str()) }
+                                td {
+                                    @if editor {
+                                        form method="post" action={ "/admin/authors/" (author.id()) "/delete" } {
+                                            (csrf_field(session))
+                                            button type="submit" { "Delete" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Renders the author list again with the error, so a failed form submission stays on the page.
+async fn render_error<R: AuthorRepository>(
+    state: &AppState<R>,
+    session: AdminSession,
+    err: HttpError,
+) -> Response {
+    match state.author_service.find_all_authors().await {
+        Ok(authors) => {
+            let page = page(state, &session, &authors, Some(&err.message()));
+            (err.status(), page).into_response()
+        }
+        Err(list_err) => HttpError::from(list_err).into_response(),
+    }
+}
+
+pub async fn dashboard<R: AuthorRepository>(
+    session: AdminSession,
+    State(state): State<AppState<R>>,
+) -> Result<Response, HttpError> {
+    let authors = state
+        .author_service
+        .find_all_authors()
+        .await
+        .map_err(HttpError::from)?;
+    let page = page(&state, &session, &authors, None);
+    Ok(page.into_response())
+}
+
+/// Changes need the editor role.
+pub async fn create_author_form<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    AdminForm(session, body): AdminForm<CreateAuthorHttpRequest>,
+) -> Response {
+    if let Err(err) = session.require(AdminRole::Editor) {
+        return err.into_response();
+    }
+    let req = match CreateAuthorRequest::try_from(body) {
+        Ok(req) => req,
+        Err(err) => return render_error(&state, session, err.into()).await,
+    };
+    let ctx = session.audit_context(ctx);
+    match state.author_service.create_author(&req, &ctx).await {
+        Ok(_) => Redirect::to(DASHBOARD_PATH).into_response(),
+        Err(err) => render_error(&state, session, err.into()).await,
+    }
+}
+
+pub async fn delete_author_form<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    AdminForm(session, EmptyForm {}): AdminForm<EmptyForm>,
+) -> Response {
+    if let Err(err) = session.require(AdminRole::Editor) {
+        return err.into_response();
+    }
+    let req = DeleteAuthorRequest::new(id);
+    let ctx = session.audit_context(ctx);
+    match state.author_service.delete_author(&req, &ctx).await {
+        Ok(()) => Redirect::to(DASHBOARD_PATH).into_response(),
+        Err(err) => render_error(&state, session, err.into()).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{Method, StatusCode, header};
+    use axum::response::Response;
+    use tower::ServiceExt;
+
+    fn router() -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let state = AppState::new(service).with_admin_token(Some("secret".into()));
+        routes(&CacheControlConfig::default()).with_state(state)
+    }
+
+    async fn send(router: &Router, method: Method, uri: &str, form: &str) -> Response {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::AUTHORIZATION, "Bearer secret")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(form.to_string()))
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    async fn text(response: Response) -> String {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn authors_are_listed_created_and_deleted_through_html_forms() {
+        let router = router();
+        let anonymous = router
+            .clone()
+            .oneshot(Request::get("/admin").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::UNAUTHORIZED, anonymous.status());
+
+        let form = "name=Ada+%3CLovelace%3E&email=ada%40example.com";
+        let created = send(&router, Method::POST, "/admin/authors", form).await;
+        assert_eq!(StatusCode::SEE_OTHER, created.status());
+        assert_eq!("/admin", created.headers()[header::LOCATION]);
+
+        let listed = send(&router, Method::GET, "/admin", "").await;
+        assert_eq!(StatusCode::OK, listed.status());
+        assert!(
+            listed.headers()[header::CONTENT_TYPE]
+                .to_str()
+                .unwrap()
+                .starts_with("text/html")
+        );
+        let html = text(listed).await;
+        assert!(html.contains("Ada &lt;Lovelace&gt;"), "{html}");
+        assert!(
+            html.contains(r#"action="/admin/authors/1/delete""#),
+            "{html}"
+        );
+
+        let invalid = send(&router, Method::POST, "/admin/authors", "name=&email=nope").await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, invalid.status());
+        let html = text(invalid).await;
+        assert!(html.contains(r#"role="alert""#), "{html}");
+        assert!(html.contains("ada@example.com"), "{html}");
+
+        let deleted = send(&router, Method::POST, "/admin/authors/1/delete", "").await;
+        assert_eq!(StatusCode::SEE_OTHER, deleted.status());
+        let missing = send(&router, Method::POST, "/admin/authors/1/delete", "").await;
+        assert_eq!(StatusCode::NOT_FOUND, missing.status());
+        assert!(!text(missing).await.contains("ada@example.com"));
+    }
+}
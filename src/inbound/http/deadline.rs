@@ -0,0 +1,96 @@
+use crate::domain::model::Deadline;
+use axum::extract::{Request, State};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::time::Duration;
+
+pub const REQUEST_TIMEOUT_HEADER: &str = "request-timeout";
+
+/// Runs the request under a deadline taken from `Request-Timeout`, in seconds, capped by the
+/// server default so clients can only ask for less time.
+pub async fn apply_deadline(
+    State(default): State<Duration>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let timeout = request
+        .headers()
+        .get(REQUEST_TIMEOUT_HEADER)
+        .and_then(parse_timeout)
+        .map_or(default, |timeout| timeout.min(default));
+    Deadline::after(timeout).scope(next.run(request)).await
+}
+
+fn parse_timeout(value: &HeaderValue) -> Option<Duration> {
+    let secs: f64 = value.to_str().ok()?.trim().parse().ok()?;
+    Duration::try_from_secs_f64(secs)
+        .ok()
+        .filter(|timeout| !timeout.is_zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::Deadline;
+    use crate::inbound::http::deadline::{REQUEST_TIMEOUT_HEADER, apply_deadline, parse_timeout};
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::HeaderValue;
+    use axum::middleware;
+    use axum::routing::get;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    #[test]
+    fn request_timeouts_are_parsed_as_positive_seconds() {
+        let parse = |value| parse_timeout(&HeaderValue::from_static(value));
+        assert_eq!(Some(Duration::from_millis(2_500)), parse("2.5"));
+        assert_eq!(Some(Duration::from_secs(10)), parse(" 10 "));
+        assert_eq!(None, parse("0"));
+        assert_eq!(None, parse("-1"));
+        assert_eq!(None, parse("soon"));
+    }
+
+    #[tokio::test]
+    async fn request_timeout_header_can_only_shorten_the_deadline() {
+        let router = Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    let remaining = Deadline::current().map(Deadline::remaining);
+                    remaining
+                        .map_or(0, |remaining| remaining.as_millis())
+                        .to_string()
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                Duration::from_secs(10),
+                apply_deadline,
+            ));
+        let remaining = |timeout: Option<&'static str>| {
+            let router = router.clone();
+            async move {
+                let mut request = Request::builder().uri("/");
+                if let Some(timeout) = timeout {
+                    request = request.header(REQUEST_TIMEOUT_HEADER, timeout);
+                }
+                let response = router
+                    .oneshot(request.body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                String::from_utf8(body.to_vec())
+                    .unwrap()
+                    .parse::<u128>()
+                    .unwrap()
+            }
+        };
+
+        assert!((1..=500).contains(&remaining(Some("0.5")).await));
+        assert!((9_000..=10_000).contains(&remaining(Some("60")).await));
+        assert!((9_000..=10_000).contains(&remaining(None).await));
+    }
+}
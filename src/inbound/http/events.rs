@@ -0,0 +1,185 @@
+use crate::domain::model::{AuditAction, AuditEntry, AuthorEvent, AuthorId, FindChangesRequest};
+use crate::domain::ports::AuthorRepository;
+use crate::domain::service::AuthorService;
+use crate::inbound::http::handlers::HttpError;
+use crate::inbound::http::{AppState, shutdown_requested};
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, watch};
+
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+const BATCH_SIZE: u32 = 100;
+
+#[derive(Debug, Serialize)]
+struct AuthorChangeHttpResponse {
+    author_id: AuthorId,
+    author: Option<serde_json::Value>,
+    occurred_at: DateTime<Utc>,
+}
+
+struct ChangeStream<R> {
+    service: AuthorService<R>,
+    receiver: broadcast::Receiver<AuthorEvent>,
+    shutdown: watch::Receiver<bool>,
+    cursor: i64,
+    pending: VecDeque<AuditEntry>,
+}
+
+impl<R: AuthorRepository> ChangeStream<R> {
+    async fn next(&mut self) -> Option<AuditEntry> {
+        while self.pending.is_empty() {
+            let req = FindChangesRequest::new(self.cursor, BATCH_SIZE);
+            let entries = match self.service.find_changes(&req).await {
+                Ok(entries) => entries,
+                Err(err) => {
+                    tracing::error!("{:?}", err.0);
+                    return None;
+                }
+            };
+            if entries.is_empty() {
+                tokio::select! {
+                    received = self.receiver.recv() => {
+                        if let Err(RecvError::Closed) = received {
+                            return None;
+                        }
+                    }
+                    () = shutdown_requested(&mut self.shutdown) => return None,
+                }
+            }
+            self.pending.extend(entries);
+        }
+
+        let entry = self.pending.pop_front()?;
+        self.cursor = entry.id();
+        Some(entry)
+    }
+}
+
+fn event(entry: AuditEntry) -> Result<Event, axum::Error> {
+    let name = match entry.action() {
+        AuditAction::Create => "created",
+        AuditAction::Update => "updated",
+        AuditAction::Delete => "deleted",
+    };
+    let body = AuthorChangeHttpResponse {
+        author_id: entry.author_id(),
+        author: entry.after().cloned(),
+        occurred_at: entry.recorded_at(),
+    };
+    Event::default()
+        .id(entry.id().to_string())
+        .event(name)
+        .json_data(body)
+}
+
+fn last_event_id(headers: &HeaderMap) -> Option<i64> {
+    headers
+        .get(LAST_EVENT_ID_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+pub async fn stream_author_events<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, HttpError> {
+    let receiver = state.author_events.subscribe();
+    let cursor = match last_event_id(&headers) {
+        Some(id) => id,
+        None => state.author_service.latest_change_id().await?.unwrap_or(0),
+    };
+    let changes = ChangeStream {
+        service: state.author_service,
+        receiver,
+        shutdown: state.shutdown.subscribe(),
+        cursor,
+        pending: VecDeque::new(),
+    };
+
+    let stream = futures::stream::unfold(changes, |mut changes| async move {
+        let entry = changes.next().await?;
+        Some((event(entry), changes))
+    });
+    let keep_alive = KeepAlive::new()
+        .interval(HEARTBEAT_INTERVAL)
+        .text("heartbeat");
+    Ok(Sse::new(stream).keep_alive(keep_alive))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AuditContext, AuthorName, CreateAuthorRequest, DeleteAuthorRequest, EmailAddress,
+    };
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::events::BroadcastEventPublisher;
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::body::{Body, BodyDataStream};
+    use axum::extract::Request;
+    use axum::http::header;
+    use futures::StreamExt;
+    use tower::ServiceExt;
+
+    async fn next_event(body: &mut BodyDataStream) -> String {
+        let chunk = body.next().await.unwrap().unwrap();
+        String::from_utf8(chunk.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn changes_are_streamed_after_last_event_id() {
+        let repo = InMemoryRepository::new();
+        let events = BroadcastEventPublisher::new(repo.clone());
+        let sender = events.sender();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            events,
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let create = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let author = service.create_author(&create, &ctx).await.unwrap();
+
+        let router = routes(&CacheControlConfig::default())
+            .with_state(AppState::new(service.clone()).with_author_events(sender));
+        let request = Request::get("/api/v1/authors/events")
+            .header("last-event-id", "0")
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(
+            "text/event-stream",
+            response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+        );
+        let mut body = response.into_body().into_data_stream();
+
+        let created = next_event(&mut body).await;
+        assert!(created.contains("event: created\n"), "{created}");
+        assert!(created.contains("id: 1\n"), "{created}");
+        assert!(created.contains("JRR Tolkien"), "{created}");
+
+        let delete = DeleteAuthorRequest::new(author.id());
+        service.delete_author(&delete, &ctx).await.unwrap();
+        let deleted = next_event(&mut body).await;
+        assert!(deleted.contains("event: deleted\n"), "{deleted}");
+        assert!(deleted.contains("id: 2\n"), "{deleted}");
+    }
+}
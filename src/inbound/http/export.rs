@@ -0,0 +1,232 @@
+use crate::domain::model::{Author, FindAllAuthorsError};
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::handlers::FindAuthorHttpResponse;
+use axum::body::{Body, Bytes};
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
+
+const CSV_HEADER: &str = "id,name,email,status,created_at,updated_at\r\n";
+pub const NDJSON: &str = "application/x-ndjson";
+
+pub async fn export_authors_csv<R: AuthorRepository>(State(state): State<AppState<R>>) -> Response {
+    let header_row = stream::once(async { Ok(Bytes::from_static(CSV_HEADER.as_bytes())) });
+    let rows = state
+        .author_service
+        .stream_all_authors()
+        .await
+        .map_ok(|author| Bytes::from(csv_row(&author)));
+    let headers = [
+        (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+        (
+            header::CONTENT_DISPOSITION,
+            r#"attachment; filename="authors.csv""#,
+        ),
+    ];
+    (headers, body(header_row.chain(rows))).into_response()
+}
+
+pub async fn export_authors_ndjson<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Response {
+    stream_authors_ndjson(&state).await
+}
+
+/// Writes one author per line as rows arrive, so clients never wait for the full set.
+pub async fn stream_authors_ndjson<R: AuthorRepository>(state: &AppState<R>) -> Response {
+    let rows = state
+        .author_service
+        .stream_all_authors()
+        .await
+        .map_ok(|author| {
+            let mut line = serde_json::to_vec(&FindAuthorHttpResponse::from(author))
+                .expect("author response serializes to JSON");
+            line.push(b'\n');
+            Bytes::from(line)
+        });
+    let headers = [(header::CONTENT_TYPE, NDJSON)];
+    (headers, body(rows)).into_response()
+}
+
+/// Once the first chunk is sent the status is fixed, so a failure mid-export aborts the body and
+/// the client sees a truncated transfer instead of a short file.
+fn body(rows: impl Stream<Item = Result<Bytes, FindAllAuthorsError>> + Send + 'static) -> Body {
+    Body::from_stream(rows.inspect_err(|err| {
+        tracing::error!(error = ?err, "Failed to export authors");
+    }))
+}
+
+fn csv_row(author: &Author) -> String {
+    let fields = [
+        author.id().to_string(),
+        author.name().to_string(),
+        author.email().to_string(),
+        author.status().as_str().to_string(),
+        timestamp(author.created_at()),
+        timestamp(author.updated_at()),
+    ];
+    let mut row = fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuditContext, AuthorName, CreateAuthorRequest, EmailAddress};
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::export::csv_field;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::{StatusCode, header};
+    use tower::ServiceExt;
+
+    async fn router() -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("anonymous".into(), None);
+        for (name, email) in [
+            ("JRR Tolkien", "jrr.tolkien@example.com"),
+            (r#"Tolkien, "The Professor""#, "professor@example.com"),
+        ] {
+            let req = CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            );
+            service.create_author(&req, &ctx).await.unwrap();
+        }
+        routes(&CacheControlConfig::default()).with_state(AppState::new(service))
+    }
+
+    async fn export(path: &str) -> (StatusCode, String, String) {
+        let request = Request::get(path).body(Body::empty()).unwrap();
+        let response = router().await.oneshot(request).await.unwrap();
+        let status = response.status();
+        let content_type = response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            content_type,
+            String::from_utf8(body.to_vec()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!("JRR Tolkien", csv_field("JRR Tolkien"));
+        assert_eq!(r#""Tolkien, JRR""#, csv_field("Tolkien, JRR"));
+        assert_eq!(r#""say ""hi""""#, csv_field(r#"say "hi""#));
+        assert_eq!("\"line\nbreak\"", csv_field("line\nbreak"));
+    }
+
+    #[tokio::test]
+    async fn authors_export_as_csv() {
+        let (status, content_type, body) = export("/api/v1/authors/export.csv").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("text/csv; charset=utf-8", content_type);
+        let lines: Vec<_> = body.split("\r\n").collect();
+        assert_eq!("id,name,email,status,created_at,updated_at", lines[0]);
+        assert!(lines[1].starts_with("1,JRR Tolkien,jrr.tolkien@example.com,active,"));
+        assert!(lines[2].starts_with(r#"2,"Tolkien, ""The Professor""",professor@example.com,"#));
+        assert_eq!([""], lines[3..]);
+    }
+
+    #[tokio::test]
+    async fn authors_export_as_ndjson() {
+        let (status, content_type, body) = export("/api/v1/authors/export.ndjson").await;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("application/x-ndjson", content_type);
+        let authors: Vec<serde_json::Value> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(2, authors.len());
+        assert_eq!("JRR Tolkien", authors[0]["name"]);
+        assert_eq!(r#"Tolkien, "The Professor""#, authors[1]["name"]);
+    }
+
+    #[tokio::test]
+    async fn author_list_streams_ndjson_when_negotiated() {
+        let requests = [
+            Request::get("/api/v1/authors?format=ndjson"),
+            Request::get("/api/v1/authors").header(header::ACCEPT, "application/x-ndjson"),
+        ];
+        for request in requests {
+            let request = request.body(Body::empty()).unwrap();
+            let response = router().await.oneshot(request).await.unwrap();
+            assert_eq!(StatusCode::OK, response.status());
+            assert_eq!(
+                "application/x-ndjson",
+                response.headers()[header::CONTENT_TYPE]
+            );
+            assert_eq!("accept", response.headers()[header::VARY]);
+            assert!(!response.headers().contains_key(header::ETAG));
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let names: Vec<_> = String::from_utf8(body.to_vec())
+                .unwrap()
+                .lines()
+                .map(|line| {
+                    serde_json::from_str::<serde_json::Value>(line).unwrap()["name"].clone()
+                })
+                .collect();
+            assert_eq!(["JRR Tolkien", r#"Tolkien, "The Professor""#], names[..]);
+        }
+    }
+
+    #[tokio::test]
+    async fn author_list_defaults_to_json() {
+        let requests = [
+            Request::get("/api/v1/authors"),
+            Request::get("/api/v1/authors").header(header::ACCEPT, "application/json, */*"),
+            Request::get("/api/v1/authors?format=json")
+                .header(header::ACCEPT, "application/x-ndjson"),
+        ];
+        for request in requests {
+            let request = request.body(Body::empty()).unwrap();
+            let response = router().await.oneshot(request).await.unwrap();
+            assert_eq!(StatusCode::OK, response.status());
+            assert_eq!("application/json", response.headers()[header::CONTENT_TYPE]);
+            assert!(response.headers().contains_key(header::ETAG));
+        }
+
+        let (status, _, body) = export("/api/v1/authors?format=xml").await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        assert!(body.contains(r#"Unsupported format \"xml\""#), "{body}");
+    }
+}
@@ -0,0 +1,151 @@
+use crate::domain::model::FeatureFlag;
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::signing::{CLIENT_ID_HEADER, SignedClient};
+use axum::extract::{FromRequestParts, Request, State};
+use axum::http::request::Parts;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::convert::Infallible;
+use std::sync::Arc;
+
+/// Wraps v2 bodies in `{"data": ..., "meta": {...}}`.
+pub const V2_ENVELOPE: &str = "v2_envelope";
+
+/// The flags as they stood when the request came in, and the client they are evaluated for.
+/// Handlers take it as an extractor; without the middleware every flag has its default.
+#[derive(Debug, Clone, Default)]
+pub struct RequestFlags {
+    flags: Arc<[FeatureFlag]>,
+    client: Option<String>,
+}
+
+impl RequestFlags {
+    /// Flags nobody has configured fall back to `default`.
+    #[must_use]
+    pub fn is_enabled(&self, name: &str, default: bool) -> bool {
+        self.flags
+            .iter()
+            .find(|flag| flag.name() == name)
+            .map_or(default, |flag| flag.is_enabled_for(self.client.as_deref()))
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for RequestFlags {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Infallible> {
+        Ok(parts.extensions.get::<Self>().cloned().unwrap_or_default())
+    }
+}
+
+/// The signing client when the request was signed, and otherwise the unverified client id header,
+/// which is good enough to keep a client on one side of a rollout or in its own cache entries.
+pub(crate) fn calling_client(request: &Request) -> Option<String> {
+    if let Some(SignedClient(client)) = request.extensions().get() {
+        return Some(client.clone());
+    }
+    request
+        .headers()
+        .get(CLIENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
+
+/// Reads the flags once per request, so a reload half way through does not change its answers.
+/// Flags that cannot be read are logged and left at their defaults.
+pub async fn evaluate_feature_flags<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(feature_flags) = state.feature_flags.as_ref() else {
+        return next.run(request).await;
+    };
+    let flags = match feature_flags.find_flags().await {
+        Ok(flags) => flags,
+        Err(err) => {
+            tracing::warn!("Failed to read feature flags: {:?}", err.0);
+            Vec::new()
+        }
+    };
+    let flags = RequestFlags {
+        flags: flags.into(),
+        client: calling_client(&request),
+    };
+    request.extensions_mut().insert(flags);
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::FeatureFlag;
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::AppState;
+    use crate::inbound::http::flags::{RequestFlags, evaluate_feature_flags};
+    use crate::outbound::flags::InMemoryFeatureFlags;
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use tower::ServiceExt;
+
+    async fn send(router: &Router, client: &str) -> String {
+        let request = Request::get("/")
+            .header("x-client-id", client)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn handlers_see_flags_evaluated_for_the_calling_client() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let flags = InMemoryFeatureFlags::new([
+            FeatureFlag::new("on".into(), true),
+            FeatureFlag::new("half".into(), true).with_rollout(50),
+        ]);
+        let state = AppState::new(service).with_feature_flags(flags.clone());
+        let router = Router::new()
+            .route(
+                "/",
+                get(|flags: RequestFlags| async move {
+                    let on = flags.is_enabled("on", false);
+                    let half = flags.is_enabled("half", false);
+                    let unknown = flags.is_enabled("unknown", true);
+                    format!("{on} {half} {unknown}")
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                evaluate_feature_flags,
+            ))
+            .with_state(state);
+
+        let answers: Vec<_> = futures::future::join_all((0..40).map(|n| {
+            let router = router.clone();
+            async move { send(&router, &format!("client-{n}")).await }
+        }))
+        .await;
+        assert!(answers.iter().all(|answer| answer.starts_with("true ")));
+        assert!(answers.iter().all(|answer| answer.ends_with(" true")));
+        assert!(answers.contains(&"true true true".to_string()));
+        assert!(answers.contains(&"true false true".to_string()));
+        assert_eq!(answers[7], send(&router, "client-7").await);
+
+        flags.set(FeatureFlag::new("on".into(), false));
+        assert!(send(&router, "client-1").await.starts_with("false "));
+    }
+}
@@ -0,0 +1,3336 @@
+use crate::domain::error::{ErrorCode, RepositoryError};
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditAction, AuditContext,
+    AuditEntry, Author, AuthorDataExport, AuthorField, AuthorFields, AuthorFieldsError,
+    AuthorGenreRequest, AuthorId, AuthorIncludeError, AuthorIncludes, AuthorName, AuthorProfile,
+    AuthorSort, AuthorStats, AuthorStatus, AuthorTransition, AuthorWithRelated, AvatarImage,
+    AvatarImageError, Biography, Blob, ChangeAuthorStatusError, ChangeAuthorStatusRequest,
+    Contract, ContractId, ContractTerm, CountryCode, CreateAuthorError, CreateAuthorRequest,
+    CreateContractError, CreateContractRequest, CreateGenreError, CreateGenreRequest,
+    CreatePublisherError, CreatePublisherRequest, DeleteAuthorError, DeleteAuthorRequest,
+    DeleteContractError, DeleteContractRequest, DeleteGenreError, DeleteGenreRequest,
+    DeletePublisherError, DeletePublisherRequest, DetachGenreError, EmailAddress,
+    EmailVerification, ErasureRecord, ExternalWork, FieldUpdate, FindAllAuthorsError,
+    FindAllGenresError, FindAllPublishersError, FindAuditLogError, FindAuditLogRequest,
+    FindAuthorByEmailError, FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest,
+    FindAuthorsByGenreRequest, FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest,
+    FindAvatarError, FindAvatarRequest, FindExternalWorksError, FindOperationError,
+    FindProjectedAuthorsRequest, FindPublisherError, FindPublisherRequest,
+    FindSortedAuthorsRequest, Genre, GenreId, GenreName, ImportAuthorsError, ImportAuthorsRequest,
+    NamePolicyError, Operation, OperationId, ParseAuthorIdError, ProjectedAuthor, Publisher,
+    PublisherId, PublisherName, PurgeAuthorError, PurgeAuthorRequest, RemoveAuthorAliasError,
+    RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor,
+    RoyaltyPercent, SearchAuthorsRequest, StartOperationError, TimedOutError, UnavailableError,
+    UpdateAuthorError, UpdateAuthorRequest, UpdateAuthorRequestBuilder, UploadAvatarError,
+    UploadAvatarRequest, UpsertAuthorError, WebsiteUrl,
+};
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::AppState;
+use crate::inbound::http::admin::AdminAuth;
+use crate::inbound::http::caching::{LastModified, if_unmodified_since};
+use crate::inbound::http::export::{NDJSON, stream_authors_ndjson};
+use crate::inbound::http::i18n::{Locale, Message, translate_fields};
+use crate::inbound::http::json::StrictJson;
+use crate::inbound::http::patch::{AuthorPatch, PatchField};
+use crate::inbound::http::problem::{ErrorFormat, ProblemDetails, ProblemType, quality};
+use crate::inbound::http::request_id::{REQUEST_ID_HEADER, RequestId};
+use crate::inbound::http::signing::SignedClient;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{FromRequestParts, Json, Multipart, Path, Query, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri, header};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct HttpSuccess<T>(StatusCode, T);
+
+impl<T: Serialize> HttpSuccess<T> {
+    pub const fn new(status: StatusCode, data: T) -> Self {
+        Self(status, data)
+    }
+}
+
+impl<T: Serialize> IntoResponse for HttpSuccess<T> {
+    fn into_response(self) -> axum::response::Response {
+        (self.0, Json(self.1)).into_response()
+    }
+}
+
+pub type FieldErrors = BTreeMap<&'static str, String>;
+
+#[derive(Error, Debug)]
+#[error("{2}")]
+pub struct HttpError(
+    StatusCode,
+    ProblemType,
+    Message,
+    FieldErrors,
+    Option<Duration>,
+);
+
+#[derive(Debug, Serialize)]
+struct ErrorHttpResponse {
+    code: &'static str,
+    error: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: FieldErrors,
+    request_id: Option<String>,
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> axum::response::Response {
+        let request_id = RequestId::current().map(|id| id.to_string());
+        let retry_after = self.4;
+        let locale = Locale::current();
+        let message = self.2.render(locale);
+        let fields = translate_fields(self.3, locale);
+        let mut response = match ErrorFormat::current() {
+            ErrorFormat::Legacy => {
+                let body = ErrorHttpResponse {
+                    code: self.1.slug(),
+                    error: message,
+                    fields,
+                    request_id,
+                };
+                (self.0, Json(body)).into_response()
+            }
+            ErrorFormat::Problem { instance } => {
+                let body = ProblemDetails::new(self.1, self.0, message, instance, request_id);
+                body.with_fields(fields).into_response()
+            }
+        };
+        if let Some(retry_after) = retry_after {
+            let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+        }
+        response
+    }
+}
+
+impl From<ParseCreateAuthorHttpRequestError> for HttpError {
+    fn from(err: ParseCreateAuthorHttpRequestError) -> Self {
+        Self::invalid_fields(err.0)
+    }
+}
+
+impl From<ParseUpdateAuthorHttpRequestError> for HttpError {
+    fn from(err: ParseUpdateAuthorHttpRequestError) -> Self {
+        Self::invalid_fields(err.0)
+    }
+}
+
+impl From<RepositoryError> for HttpError {
+    fn from(err: RepositoryError) -> Self {
+        let (status, detail) = match err {
+            RepositoryError::NotFound(detail) => (StatusCode::NOT_FOUND, detail),
+            RepositoryError::Conflict(detail) => (StatusCode::CONFLICT, detail),
+            RepositoryError::PreconditionFailed(detail) => {
+                (StatusCode::PRECONDITION_FAILED, detail)
+            }
+            RepositoryError::Invalid(detail) => (StatusCode::UNPROCESSABLE_ENTITY, detail),
+            RepositoryError::Unavailable(err) => return Self::unavailable(&err),
+            RepositoryError::Timeout(err) => return Self::timed_out(&err),
+            RepositoryError::Other(cause) => return Self::internal(&cause),
+        };
+        let (problem, key) = problem_for(detail.code());
+        let message = match key {
+            Some(key) => detail
+                .args()
+                .iter()
+                .fold(Message::new(key), |message, (name, value)| {
+                    message.arg(name, value)
+                }),
+            None => detail.message().to_string().into(),
+        };
+        let err = Self::new(status, problem, message);
+        match detail.field_error() {
+            Some((field, message)) => err.with_field(field, message.to_string()),
+            None => err,
+        }
+    }
+}
+
+/// Codes without a catalog key are reported with the domain's own message.
+const fn problem_for(code: ErrorCode) -> (ProblemType, Option<&'static str>) {
+    match code {
+        ErrorCode::AuthorNotFound => (ProblemType::AuthorNotFound, Some("author-not-found")),
+        ErrorCode::AuthorEmailNotFound => {
+            (ProblemType::AuthorNotFound, Some("author-email-not-found"))
+        }
+        ErrorCode::AuthorNameTaken => (ProblemType::DuplicateAuthor, Some("author-name-taken")),
+        ErrorCode::AuthorEmailTaken => (ProblemType::DuplicateEmail, Some("author-email-taken")),
+        ErrorCode::AuthorIdTaken => (ProblemType::DuplicateAuthorId, Some("author-id-taken")),
+        ErrorCode::InvalidAuthorName | ErrorCode::InvalidAlias => {
+            (ProblemType::InvalidRequest, None)
+        }
+        ErrorCode::NothingToUpdate => (ProblemType::NothingToUpdate, Some("nothing-to-update")),
+        ErrorCode::AuthorModified => (ProblemType::PreconditionFailed, Some("author-modified")),
+        ErrorCode::AuthorArchived => (ProblemType::AuthorArchived, Some("author-archived")),
+        ErrorCode::InvalidStatusTransition => (ProblemType::InvalidStatusTransition, None),
+        ErrorCode::AvatarNotFound => (ProblemType::AvatarNotFound, Some("avatar-not-found")),
+        ErrorCode::AliasTaken => (ProblemType::DuplicateAlias, Some("alias-taken")),
+        ErrorCode::AliasNotFound => (ProblemType::AliasNotFound, Some("alias-not-found")),
+        ErrorCode::GenreNameTaken => (ProblemType::DuplicateGenre, Some("genre-name-taken")),
+        ErrorCode::GenreNotFound => (ProblemType::GenreNotFound, Some("genre-not-found")),
+        ErrorCode::GenreInUse => (ProblemType::GenreInUse, Some("genre-in-use")),
+        ErrorCode::AuthorGenreNotFound => {
+            (ProblemType::GenreNotFound, Some("author-genre-not-found"))
+        }
+        ErrorCode::PublisherNameTaken => (
+            ProblemType::DuplicatePublisher,
+            Some("publisher-name-taken"),
+        ),
+        ErrorCode::PublisherNotFound => {
+            (ProblemType::PublisherNotFound, Some("publisher-not-found"))
+        }
+        ErrorCode::PublisherHasContracts => (
+            ProblemType::PublisherHasContracts,
+            Some("publisher-has-contracts"),
+        ),
+        ErrorCode::ContractOverlaps => {
+            (ProblemType::OverlappingContract, Some("contract-overlaps"))
+        }
+        ErrorCode::ContractNotFound => (ProblemType::ContractNotFound, Some("contract-not-found")),
+        ErrorCode::OperationNotFound => {
+            (ProblemType::OperationNotFound, Some("operation-not-found"))
+        }
+    }
+}
+
+impl From<FindAuthorByEmailError> for HttpError {
+    fn from(err: FindAuthorByEmailError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<NamePolicyError> for HttpError {
+    fn from(err: NamePolicyError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<CreateAuthorError> for HttpError {
+    fn from(err: CreateAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAuthorError> for HttpError {
+    fn from(err: FindAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAllAuthorsError> for HttpError {
+    fn from(err: FindAllAuthorsError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<ImportAuthorsError> for HttpError {
+    fn from(err: ImportAuthorsError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<StartOperationError> for HttpError {
+    fn from(err: StartOperationError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindOperationError> for HttpError {
+    fn from(err: FindOperationError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<UpdateAuthorError> for HttpError {
+    fn from(err: UpdateAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<ReplaceAuthorError> for HttpError {
+    fn from(err: ReplaceAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<UpsertAuthorError> for HttpError {
+    fn from(err: UpsertAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<ChangeAuthorStatusError> for HttpError {
+    fn from(err: ChangeAuthorStatusError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<DeleteAuthorError> for HttpError {
+    fn from(err: DeleteAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<PurgeAuthorError> for HttpError {
+    fn from(err: PurgeAuthorError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAuditLogError> for HttpError {
+    fn from(err: FindAuditLogError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<UploadAvatarError> for HttpError {
+    fn from(err: UploadAvatarError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAvatarError> for HttpError {
+    fn from(err: FindAvatarError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<AddAuthorAliasError> for HttpError {
+    fn from(err: AddAuthorAliasError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<RemoveAuthorAliasError> for HttpError {
+    fn from(err: RemoveAuthorAliasError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<CreateGenreError> for HttpError {
+    fn from(err: CreateGenreError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAllGenresError> for HttpError {
+    fn from(err: FindAllGenresError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<DeleteGenreError> for HttpError {
+    fn from(err: DeleteGenreError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<AttachGenreError> for HttpError {
+    fn from(err: AttachGenreError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<DetachGenreError> for HttpError {
+    fn from(err: DetachGenreError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<CreatePublisherError> for HttpError {
+    fn from(err: CreatePublisherError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindPublisherError> for HttpError {
+    fn from(err: FindPublisherError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<FindAllPublishersError> for HttpError {
+    fn from(err: FindAllPublishersError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<DeletePublisherError> for HttpError {
+    fn from(err: DeletePublisherError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<CreateContractError> for HttpError {
+    fn from(err: CreateContractError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<DeleteContractError> for HttpError {
+    fn from(err: DeleteContractError) -> Self {
+        RepositoryError::from(err).into()
+    }
+}
+
+impl From<ParseUploadAvatarHttpRequestError> for HttpError {
+    fn from(err: ParseUploadAvatarHttpRequestError) -> Self {
+        let (status, problem) = match err {
+            ParseUploadAvatarHttpRequestError::Image(AvatarImageError::TooLarge { .. }) => {
+                (StatusCode::PAYLOAD_TOO_LARGE, ProblemType::AvatarTooLarge)
+            }
+            ParseUploadAvatarHttpRequestError::Image(AvatarImageError::UnsupportedType) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                ProblemType::UnsupportedMediaType,
+            ),
+            ParseUploadAvatarHttpRequestError::Multipart(_)
+            | ParseUploadAvatarHttpRequestError::Missing => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ProblemType::InvalidRequest,
+            ),
+        };
+        Self::new(status, problem, err.to_string())
+    }
+}
+
+impl From<ParseAuthorIdError> for HttpError {
+    fn from(err: ParseAuthorIdError) -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            ProblemType::InvalidId,
+            Message::new("invalid-id").arg("id", err.id()),
+        )
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for AuthorId {
+    type Rejection = HttpError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(id) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| {
+                HttpError::new(
+                    StatusCode::BAD_REQUEST,
+                    ProblemType::InvalidId,
+                    rejection.body_text(),
+                )
+            })?;
+        Ok(id.parse()?)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for AuditContext {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        // Signed callers are recorded under their client id unless they act for someone.
+        let actor = header("x-actor")
+            .or_else(|| {
+                let client = parts.extensions.get::<SignedClient>();
+                client.map(|client| client.0.clone())
+            })
+            .unwrap_or_else(|| "anonymous".to_string());
+        let request_id = parts
+            .extensions
+            .get::<RequestId>()
+            .map(ToString::to_string)
+            .or_else(|| header(REQUEST_ID_HEADER));
+        Ok(Self::new(actor, request_id))
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAuthorHttpRequest {
+    name: String,
+    email: String,
+    #[serde(default)]
+    bio: Option<String>,
+    #[serde(default)]
+    birth_date: Option<String>,
+    #[serde(default)]
+    website_url: Option<String>,
+    #[serde(default)]
+    country: Option<String>,
+}
+
+#[derive(Error, Debug)]
+#[error("{}", describe_fields(.0))]
+pub struct ParseCreateAuthorHttpRequestError(FieldErrors);
+
+impl TryFrom<CreateAuthorHttpRequest> for CreateAuthorRequest {
+    type Error = ParseCreateAuthorHttpRequestError;
+
+    fn try_from(value: CreateAuthorHttpRequest) -> Result<Self, Self::Error> {
+        let mut fields = FieldErrors::new();
+        let name = parse_name(&value.name, &mut fields);
+        let email = parse_email(&value.email, &mut fields);
+        let profile = AuthorProfile::default()
+            .with_bio(parse_optional(
+                value.bio,
+                "bio",
+                Biography::new,
+                &mut fields,
+            ))
+            .with_birth_date(parse_optional(
+                value.birth_date,
+                "birth_date",
+                str::parse,
+                &mut fields,
+            ))
+            .with_website(parse_optional(
+                value.website_url,
+                "website_url",
+                WebsiteUrl::new,
+                &mut fields,
+            ))
+            .with_country(parse_optional(
+                value.country,
+                "country",
+                parse_country,
+                &mut fields,
+            ));
+        match (name, email) {
+            (Some(name), Some(email)) if fields.is_empty() => {
+                Ok(Self::new(name, email).with_profile(profile))
+            }
+            _ => Err(ParseCreateAuthorHttpRequestError(fields)),
+        }
+    }
+}
+
+impl TryFrom<(AuthorId, CreateAuthorHttpRequest)> for ReplaceAuthorRequest {
+    type Error = ParseCreateAuthorHttpRequestError;
+
+    fn try_from((id, value): (AuthorId, CreateAuthorHttpRequest)) -> Result<Self, Self::Error> {
+        let req = CreateAuthorRequest::try_from(value)?;
+        Ok(Self::new(id, req.name().clone(), req.email().clone())
+            .with_profile(req.profile().clone()))
+    }
+}
+
+fn parse_name(raw: &str, fields: &mut FieldErrors) -> Option<AuthorName> {
+    AuthorName::new(raw)
+        .inspect_err(|_| {
+            fields.insert("name", "cannot be empty".to_string());
+        })
+        .ok()
+}
+
+fn parse_email(raw: &str, fields: &mut FieldErrors) -> Option<EmailAddress> {
+    EmailAddress::new(raw)
+        .inspect_err(|_| {
+            fields.insert("email", "is not a valid email address".to_string());
+        })
+        .ok()
+}
+
+fn parse_optional<T, E: std::fmt::Display>(
+    raw: Option<String>,
+    field: &'static str,
+    parse: impl Fn(&str) -> Result<T, E>,
+    fields: &mut FieldErrors,
+) -> Option<T> {
+    parse(&raw?)
+        .inspect_err(|err| {
+            fields.insert(field, err.to_string());
+        })
+        .ok()
+}
+
+fn parse_country(raw: &str) -> Result<CountryCode, &'static str> {
+    CountryCode::new(raw).map_err(|_| "is not an ISO 3166-1 alpha-2 country code")
+}
+
+/// Maps a nullable patch field onto a profile update: `null` clears the stored value.
+fn optional_field<T, E: std::fmt::Display>(
+    value: PatchField<String>,
+    field: &'static str,
+    parse: impl Fn(&str) -> Result<T, E>,
+    fields: &mut FieldErrors,
+) -> FieldUpdate<T> {
+    match value {
+        PatchField::Absent => FieldUpdate::Unchanged,
+        PatchField::Null => FieldUpdate::Clear,
+        PatchField::Value(raw) => match parse(&raw) {
+            Ok(value) => FieldUpdate::Set(value),
+            Err(err) => {
+                fields.insert(field, err.to_string());
+                FieldUpdate::Unchanged
+            }
+        },
+    }
+}
+
+fn required_field(
+    value: PatchField<String>,
+    field: &'static str,
+    fields: &mut FieldErrors,
+) -> Option<String> {
+    match value {
+        PatchField::Absent => None,
+        PatchField::Null => {
+            fields.insert(field, "cannot be removed".to_string());
+            None
+        }
+        PatchField::Value(value) => Some(value),
+    }
+}
+
+pub fn describe_fields(fields: &FieldErrors) -> String {
+    fields
+        .iter()
+        .map(|(field, message)| format!("{field} {message}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct CreateAuthorHttpResponse {
+    id: AuthorId,
+}
+
+impl From<Author> for CreateAuthorHttpResponse {
+    fn from(value: Author) -> Self {
+        Self { id: value.id() }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct FindAuthorHttpResponse {
+    id: AuthorId,
+    name: AuthorName,
+    email: EmailAddress,
+    verified: bool,
+    status: &'static str,
+    bio: Option<String>,
+    birth_date: Option<String>,
+    website_url: Option<String>,
+    country: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<Author> for FindAuthorHttpResponse {
+    fn from(value: Author) -> Self {
+        let profile = value.profile();
+        Self {
+            id: value.id(),
+            name: value.name().clone(),
+            email: value.email().clone(),
+            verified: value.is_verified(),
+            status: value.status().as_str(),
+            bio: profile.bio().map(ToString::to_string),
+            birth_date: profile.birth_date().map(|date| date.to_string()),
+            website_url: profile.website().map(ToString::to_string),
+            country: profile.country().map(|code| code.to_string()),
+            created_at: value.created_at(),
+            updated_at: value.updated_at(),
+        }
+    }
+}
+
+/// An author with its related resources under `included`, each only if it was asked for.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct AuthorWithRelatedHttpResponse {
+    #[serde(flatten)]
+    author: FindAuthorHttpResponse,
+    included: IncludedHttpResponse,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct IncludedHttpResponse {
+    #[serde(flatten)]
+    aliases: Option<AuthorAliasesHttpResponse>,
+    #[serde(flatten)]
+    genres: Option<GenresHttpResponse>,
+    #[serde(flatten)]
+    contracts: Option<ContractsHttpResponse>,
+}
+
+impl From<AuthorWithRelated> for AuthorWithRelatedHttpResponse {
+    fn from(value: AuthorWithRelated) -> Self {
+        Self {
+            author: value.author().clone().into(),
+            included: IncludedHttpResponse {
+                aliases: value.aliases().map(|aliases| AuthorAliasesHttpResponse {
+                    aliases: aliases.iter().map(ToString::to_string).collect(),
+                }),
+                genres: value.genres().map(|genres| genres.to_vec().into()),
+                contracts: value.contracts().map(|contracts| contracts.to_vec().into()),
+            },
+        }
+    }
+}
+
+/// An author with only the fields the client asked for, in the order asked. Fields asked for
+/// but unset, like a missing bio, are written as `null` rather than left out.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProjectedAuthorHttpResponse {
+    fields: AuthorFields,
+    author: ProjectedAuthor,
+}
+
+impl Serialize for ProjectedAuthorHttpResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_projected(&self.fields, &self.author, serializer)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProjectedAuthorsHttpResponse {
+    fields: AuthorFields,
+    authors: Vec<ProjectedAuthor>,
+}
+
+impl Serialize for ProjectedAuthorsHttpResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Entry<'a>(&'a AuthorFields, &'a ProjectedAuthor);
+
+        impl Serialize for Entry<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_projected(self.0, self.1, serializer)
+            }
+        }
+
+        serializer.collect_seq(
+            self.authors
+                .iter()
+                .map(|author| Entry(&self.fields, author)),
+        )
+    }
+}
+
+/// Writes the same values [`FindAuthorHttpResponse`] would for each field asked for.
+fn serialize_projected<S: Serializer>(
+    fields: &AuthorFields,
+    author: &ProjectedAuthor,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let profile = author.profile();
+    let mut map = serializer.serialize_map(Some(fields.fields().len()))?;
+    for field in fields.fields() {
+        let key = field.as_str();
+        match field {
+            AuthorField::Id => map.serialize_entry(key, &author.id())?,
+            AuthorField::Name => map.serialize_entry(key, &author.name())?,
+            AuthorField::Email => map.serialize_entry(key, &author.email())?,
+            AuthorField::Verified => map.serialize_entry(key, &author.is_verified())?,
+            AuthorField::Status => {
+                map.serialize_entry(key, &author.status().map(AuthorStatus::as_str))?;
+            }
+            AuthorField::Bio => {
+                map.serialize_entry(key, &profile.bio().map(ToString::to_string))?;
+            }
+            AuthorField::BirthDate => {
+                map.serialize_entry(key, &profile.birth_date().map(|date| date.to_string()))?;
+            }
+            AuthorField::WebsiteUrl => {
+                map.serialize_entry(key, &profile.website().map(ToString::to_string))?;
+            }
+            AuthorField::Country => {
+                map.serialize_entry(key, &profile.country().map(|code| code.to_string()))?;
+            }
+            AuthorField::CreatedAt => map.serialize_entry(key, &author.created_at())?,
+            AuthorField::UpdatedAt => map.serialize_entry(key, &author.updated_at())?,
+        }
+    }
+    map.end()
+}
+
+/// `result` is only there once the operation succeeded, `error` once it failed.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct OperationHttpResponse {
+    id: OperationId,
+    kind: &'static str,
+    status: &'static str,
+    progress: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<Operation> for OperationHttpResponse {
+    fn from(value: Operation) -> Self {
+        Self {
+            id: value.id(),
+            kind: value.kind().as_str(),
+            status: value.status().as_str(),
+            progress: value.progress(),
+            result: value.result().cloned(),
+            error: value.error().map(ToString::to_string),
+            created_at: value.created_at(),
+            updated_at: value.updated_at(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct FindAllAuthorsHttpResponse(Vec<FindAuthorHttpResponse>);
+
+impl From<Vec<Author>> for FindAllAuthorsHttpResponse {
+    fn from(values: Vec<Author>) -> Self {
+        let vec = values
+            .into_iter()
+            .map(FindAuthorHttpResponse::from)
+            .collect();
+        Self(vec)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct CountAuthorsHttpResponse {
+    count: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct AuthorStatsHttpResponse {
+    total: u64,
+    active: u64,
+    archived: u64,
+    by_email_domain: Vec<EmailDomainCountHttpResponse>,
+    created_per_day: Vec<DailyCountHttpResponse>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct EmailDomainCountHttpResponse {
+    domain: String,
+    count: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct DailyCountHttpResponse {
+    date: String,
+    count: u64,
+}
+
+impl From<AuthorStats> for AuthorStatsHttpResponse {
+    fn from(value: AuthorStats) -> Self {
+        Self {
+            total: value.total(),
+            active: value.active(),
+            archived: value.archived(),
+            by_email_domain: value
+                .by_email_domain()
+                .iter()
+                .map(|(domain, count)| EmailDomainCountHttpResponse {
+                    domain: domain.clone(),
+                    count: *count,
+                })
+                .collect(),
+            created_per_day: value
+                .created_per_day()
+                .iter()
+                .map(|(date, count)| DailyCountHttpResponse {
+                    date: date.to_string(),
+                    count: *count,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct ExternalWorksHttpResponse {
+    works: Vec<ExternalWorkHttpResponse>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct ExternalWorkHttpResponse {
+    title: String,
+    first_published_year: Option<i32>,
+    url: String,
+}
+
+impl From<Vec<ExternalWork>> for ExternalWorksHttpResponse {
+    fn from(value: Vec<ExternalWork>) -> Self {
+        Self {
+            works: value
+                .into_iter()
+                .map(|work| ExternalWorkHttpResponse {
+                    title: work.title().to_string(),
+                    first_published_year: work.first_published_year(),
+                    url: work.url().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<FindExternalWorksError> for HttpError {
+    fn from(err: FindExternalWorksError) -> Self {
+        match err {
+            FindExternalWorksError::AuthorNotFound { id } => Self::new(
+                StatusCode::NOT_FOUND,
+                ProblemType::AuthorNotFound,
+                Message::new("author-not-found").arg("id", id),
+            ),
+            FindExternalWorksError::Disabled => Self::new(
+                StatusCode::NOT_FOUND,
+                ProblemType::RouteNotFound,
+                Message::new("book-catalog-disabled"),
+            ),
+            FindExternalWorksError::Unavailable { retry_after } => Self(
+                StatusCode::SERVICE_UNAVAILABLE,
+                ProblemType::BookCatalogUnavailable,
+                Message::new("book-catalog-unavailable").arg("seconds", retry_after.as_secs()),
+                BTreeMap::new(),
+                Some(retry_after),
+            ),
+            FindExternalWorksError::TimedOut => Self::new(
+                StatusCode::GATEWAY_TIMEOUT,
+                ProblemType::BookCatalogTimedOut,
+                Message::new("book-catalog-timed-out"),
+            ),
+            FindExternalWorksError::Other(cause) => Self::internal(&cause),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct FindAuthorsByIdsHttpResponse {
+    authors: Vec<FindAuthorHttpResponse>,
+    missing: Vec<AuthorId>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorAliasHttpBody {
+    alias: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct AuthorAliasesHttpResponse {
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateGenreHttpRequest {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct GenreHttpResponse {
+    id: GenreId,
+    name: String,
+}
+
+impl From<Genre> for GenreHttpResponse {
+    fn from(value: Genre) -> Self {
+        Self {
+            id: value.id(),
+            name: value.name().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct GenresHttpResponse {
+    genres: Vec<GenreHttpResponse>,
+}
+
+impl From<Vec<Genre>> for GenresHttpResponse {
+    fn from(value: Vec<Genre>) -> Self {
+        Self {
+            genres: value.into_iter().map(GenreHttpResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreatePublisherHttpRequest {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct PublisherHttpResponse {
+    id: PublisherId,
+    name: String,
+}
+
+impl From<Publisher> for PublisherHttpResponse {
+    fn from(value: Publisher) -> Self {
+        Self {
+            id: value.id(),
+            name: value.name().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct PublishersHttpResponse {
+    publishers: Vec<PublisherHttpResponse>,
+}
+
+impl From<Vec<Publisher>> for PublishersHttpResponse {
+    fn from(value: Vec<Publisher>) -> Self {
+        Self {
+            publishers: value.into_iter().map(PublisherHttpResponse::from).collect(),
+        }
+    }
+}
+
+/// Royalties travel as decimal strings such as `"12.50"`, so no precision is lost to floats.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateContractHttpRequest {
+    publisher_id: PublisherId,
+    starts_on: String,
+    #[serde(default)]
+    ends_on: Option<String>,
+    royalty_percent: String,
+}
+
+impl CreateContractHttpRequest {
+    fn try_into_domain(self, author_id: AuthorId) -> Result<CreateContractRequest, HttpError> {
+        let mut fields = FieldErrors::new();
+        let date = |raw: &str| raw.parse().map_err(|_| "is not a valid date");
+        let starts_on = parse_optional(Some(self.starts_on), "starts_on", date, &mut fields);
+        let ends_on = parse_optional(self.ends_on, "ends_on", date, &mut fields);
+        let royalty = parse_optional(
+            Some(self.royalty_percent),
+            "royalty_percent",
+            str::parse::<RoyaltyPercent>,
+            &mut fields,
+        );
+        let term = starts_on.and_then(|starts_on| {
+            ContractTerm::new(starts_on, ends_on)
+                .inspect_err(|err| {
+                    fields.entry("ends_on").or_insert_with(|| err.to_string());
+                })
+                .ok()
+        });
+        match (term, royalty) {
+            (Some(term), Some(royalty)) if fields.is_empty() => Ok(CreateContractRequest::new(
+                author_id,
+                self.publisher_id,
+                term,
+                royalty,
+            )),
+            _ => Err(HttpError::invalid_fields(fields)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct ContractHttpResponse {
+    id: ContractId,
+    author_id: AuthorId,
+    publisher_id: PublisherId,
+    starts_on: String,
+    ends_on: Option<String>,
+    royalty_percent: String,
+}
+
+impl From<Contract> for ContractHttpResponse {
+    fn from(value: Contract) -> Self {
+        Self {
+            id: value.id(),
+            author_id: value.author_id(),
+            publisher_id: value.publisher_id(),
+            starts_on: value.term().starts_on().to_string(),
+            ends_on: value.term().ends_on().map(|date| date.to_string()),
+            royalty_percent: value.royalty().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct ContractsHttpResponse {
+    contracts: Vec<ContractHttpResponse>,
+}
+
+impl From<Vec<Contract>> for ContractsHttpResponse {
+    fn from(value: Vec<Contract>) -> Self {
+        Self {
+            contracts: value.into_iter().map(ContractHttpResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateAuthorHttpRequest {
+    #[serde(default)]
+    pub(super) name: PatchField<String>,
+    #[serde(default)]
+    pub(super) email: PatchField<String>,
+    #[serde(default)]
+    pub(super) bio: PatchField<String>,
+    #[serde(default)]
+    pub(super) birth_date: PatchField<String>,
+    #[serde(default)]
+    pub(super) website_url: PatchField<String>,
+    #[serde(default)]
+    pub(super) country: PatchField<String>,
+}
+
+#[derive(Error, Debug)]
+#[error("{}", describe_fields(.0))]
+pub struct ParseUpdateAuthorHttpRequestError(FieldErrors);
+
+impl TryFrom<(AuthorId, UpdateAuthorHttpRequest)> for UpdateAuthorRequestBuilder {
+    type Error = ParseUpdateAuthorHttpRequestError;
+    fn try_from((id, parts): (AuthorId, UpdateAuthorHttpRequest)) -> Result<Self, Self::Error> {
+        let mut fields = FieldErrors::new();
+        let name = required_field(parts.name, "name", &mut fields)
+            .map(|name| parse_name(&name, &mut fields));
+        let email = required_field(parts.email, "email", &mut fields)
+            .map(|email| parse_email(&email, &mut fields));
+        let bio = optional_field(parts.bio, "bio", Biography::new, &mut fields);
+        let birth_date = optional_field(parts.birth_date, "birth_date", str::parse, &mut fields);
+        let website = optional_field(
+            parts.website_url,
+            "website_url",
+            WebsiteUrl::new,
+            &mut fields,
+        );
+        let country = optional_field(parts.country, "country", parse_country, &mut fields);
+        if !fields.is_empty() {
+            return Err(ParseUpdateAuthorHttpRequestError(fields));
+        }
+
+        let mut builder = UpdateAuthorRequest::builder(id)
+            .bio(bio)
+            .birth_date(birth_date)
+            .website(website)
+            .country(country);
+        if let Some(name) = name.flatten() {
+            builder = builder.name(name);
+        }
+        if let Some(email) = email.flatten() {
+            builder = builder.email(email);
+        }
+
+        Ok(builder)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AuditEntryHttpResponse {
+    id: i64,
+    action: String,
+    actor: String,
+    request_id: Option<String>,
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+    recorded_at: DateTime<Utc>,
+}
+
+impl From<AuditEntry> for AuditEntryHttpResponse {
+    fn from(value: AuditEntry) -> Self {
+        Self {
+            id: value.id(),
+            action: value.action().to_string(),
+            actor: value.context().actor().to_string(),
+            request_id: value.context().request_id().map(str::to_string),
+            before: value.before().cloned(),
+            after: value.after().cloned(),
+            recorded_at: value.recorded_at(),
+        }
+    }
+}
+
+/// Change feed events derived from the audit log, as the events stream delivers them.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AuthorEventHttpResponse {
+    id: i64,
+    event: &'static str,
+    occurred_at: DateTime<Utc>,
+}
+
+impl From<&AuditEntry> for AuthorEventHttpResponse {
+    fn from(value: &AuditEntry) -> Self {
+        let event = match value.action() {
+            AuditAction::Create => "created",
+            AuditAction::Update => "updated",
+            AuditAction::Delete => "deleted",
+        };
+        Self {
+            id: value.id(),
+            event,
+            occurred_at: value.recorded_at(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AuthorDataExportHttpResponse {
+    exported_at: DateTime<Utc>,
+    profile: FindAuthorHttpResponse,
+    aliases: Vec<String>,
+    genres: Vec<GenreHttpResponse>,
+    contracts: Vec<ContractHttpResponse>,
+    audit_log: Vec<AuditEntryHttpResponse>,
+    events: Vec<AuthorEventHttpResponse>,
+}
+
+impl From<AuthorDataExport> for AuthorDataExportHttpResponse {
+    fn from(value: AuthorDataExport) -> Self {
+        Self {
+            exported_at: Utc::now(),
+            profile: value.author().clone().into(),
+            aliases: value.aliases().iter().map(ToString::to_string).collect(),
+            genres: value.genres().iter().cloned().map(Into::into).collect(),
+            contracts: value.contracts().iter().cloned().map(Into::into).collect(),
+            audit_log: value.audit_log().iter().cloned().map(Into::into).collect(),
+            events: value.audit_log().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ErasureRecordHttpResponse {
+    id: i64,
+    author_id: AuthorId,
+    anonymized_entries: u64,
+    erased_at: DateTime<Utc>,
+    previous_hash: String,
+    hash: String,
+}
+
+impl From<ErasureRecord> for ErasureRecordHttpResponse {
+    fn from(value: ErasureRecord) -> Self {
+        Self {
+            id: value.id(),
+            author_id: value.author_id(),
+            anonymized_entries: value.anonymized_entries(),
+            erased_at: value.erased_at(),
+            previous_hash: value.previous_hash().to_string(),
+            hash: value.hash().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct AuditLogHttpResponse(Vec<AuditEntryHttpResponse>);
+
+impl From<Vec<AuditEntry>> for AuditLogHttpResponse {
+    fn from(values: Vec<AuditEntry>) -> Self {
+        let vec = values
+            .into_iter()
+            .map(AuditEntryHttpResponse::from)
+            .collect();
+        Self(vec)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ParseUploadAvatarHttpRequestError {
+    #[error(transparent)]
+    Multipart(#[from] MultipartError),
+    #[error(r#"multipart field "avatar" is missing"#)]
+    Missing,
+    #[error(transparent)]
+    Image(#[from] AvatarImageError),
+}
+
+impl TryFrom<(AuthorId, Vec<u8>)> for UploadAvatarRequest {
+    type Error = ParseUploadAvatarHttpRequestError;
+
+    fn try_from((id, bytes): (AuthorId, Vec<u8>)) -> Result<Self, Self::Error> {
+        let image = AvatarImage::new(bytes)?;
+        Ok(Self::new(id, image))
+    }
+}
+
+#[derive(Debug)]
+pub struct AvatarHttpResponse(Blob);
+
+impl IntoResponse for AvatarHttpResponse {
+    fn into_response(self) -> axum::response::Response {
+        let etag = format!("\"{:x}\"", Sha256::digest(self.0.bytes()));
+        let headers = [
+            (header::CONTENT_TYPE, self.0.content_type().to_string()),
+            (header::ETAG, etag),
+        ];
+        (StatusCode::OK, headers, self.0.into_bytes()).into_response()
+    }
+}
+
+pub async fn create_author<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    StrictJson(body): StrictJson<CreateAuthorHttpRequest>,
+) -> Result<HttpSuccess<CreateAuthorHttpResponse>, HttpError> {
+    let req = body.try_into()?;
+    state
+        .author_service
+        .create_author(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|author| HttpSuccess::new(StatusCode::CREATED, author.into()))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct UpsertAuthorParams {
+    key: Option<String>,
+}
+
+/// Creates the author in the body, or replaces the one matched on `?key=`, answering
+/// `201 Created` or `200 OK`. Email is the only key authors can be matched on.
+pub async fn upsert_author<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    uri: Uri,
+    StrictJson(body): StrictJson<CreateAuthorHttpRequest>,
+) -> Result<(LastModified, HttpSuccess<FindAuthorHttpResponse>), HttpError> {
+    let Query(params) = Query::<UpsertAuthorParams>::try_from_uri(&uri)
+        .map_err(|rejection| HttpError::invalid_request(rejection.body_text()))?;
+    if params.key.as_deref() != Some("email") {
+        return Err(HttpError::invalid_request(
+            "Authors can only be upserted by email, with ?key=email".to_string(),
+        ));
+    }
+    let req = body.try_into()?;
+    let upserted = state
+        .author_service
+        .upsert_author_by_email(&req, &ctx)
+        .await
+        .map_err(HttpError::from)?;
+    let status = match upserted {
+        ReplacedAuthor::Created(_) => StatusCode::CREATED,
+        ReplacedAuthor::Replaced(_) => StatusCode::OK,
+    };
+    let author = upserted.into_author();
+    let last_modified = LastModified(author.updated_at());
+    Ok((last_modified, HttpSuccess::new(status, author.into())))
+}
+
+/// At most this many authors per import, so one request cannot hold up writes for long.
+const MAX_IMPORT_AUTHORS: usize = 1_000;
+
+/// Creates the authors in the body, an array shaped like the body of [`create_author`], skipping
+/// those already there. With `Prefer: respond-async` the import runs in the background, answering
+/// `202 Accepted` with the operation to poll in `Location`; without an operation store the
+/// preference is ignored.
+pub async fn import_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    headers: HeaderMap,
+    StrictJson(body): StrictJson<Vec<CreateAuthorHttpRequest>>,
+) -> Result<Response, HttpError> {
+    if body.len() > MAX_IMPORT_AUTHORS {
+        return Err(HttpError::invalid_request(format!(
+            "At most {MAX_IMPORT_AUTHORS} authors can be imported at once"
+        )));
+    }
+    let authors = body
+        .into_iter()
+        .enumerate()
+        .map(|(index, author)| {
+            CreateAuthorRequest::try_from(author).map_err(|err| {
+                HttpError::invalid_request(format!("Author {index} is invalid: {err}"))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let req = ImportAuthorsRequest::new(authors);
+
+    if prefers_async(&headers) && state.author_service.runs_operations() {
+        let operation = state.author_service.start_import_authors(req, ctx).await?;
+        let location = format!("/api/v1/operations/{}", operation.id());
+        return Ok((
+            [
+                (header::LOCATION, location),
+                (PREFERENCE_APPLIED, "respond-async".to_string()),
+            ],
+            HttpSuccess::new(StatusCode::ACCEPTED, OperationHttpResponse::from(operation)),
+        )
+            .into_response());
+    }
+    state
+        .author_service
+        .import_authors(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|report| HttpSuccess::new(StatusCode::OK, report).into_response())
+}
+
+const PREFER: HeaderName = HeaderName::from_static("prefer");
+const PREFERENCE_APPLIED: HeaderName = HeaderName::from_static("preference-applied");
+
+/// Whether a `Prefer` header asks for `respond-async`, as defined in RFC 7240.
+fn prefers_async(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(PREFER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|preference| {
+            preference
+                .split(';')
+                .next()
+                .is_some_and(|name| name.trim().eq_ignore_ascii_case("respond-async"))
+        })
+}
+
+pub async fn find_operation<R: AuthorRepository>(
+    Path(id): Path<String>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<OperationHttpResponse>, HttpError> {
+    let id = id.parse().map_err(|_| {
+        HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ProblemType::InvalidId,
+            Message::new("invalid-operation-id").arg("id", &id),
+        )
+    })?;
+    state
+        .author_service
+        .find_operation(id)
+        .await
+        .map_err(HttpError::from)
+        .map(|operation| HttpSuccess::new(StatusCode::OK, operation.into()))
+}
+
+pub async fn find_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<(LastModified, HttpSuccess<FindAuthorHttpResponse>), HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_author(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|author| {
+            let last_modified = LastModified(author.updated_at());
+            (
+                last_modified,
+                HttpSuccess::new(StatusCode::OK, author.into()),
+            )
+        })
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FindAuthorParams {
+    fields: Option<String>,
+    include: Option<String>,
+}
+
+/// Serves one author, or with `?fields=id,name` only those fields of it. `?include=genres,aliases`
+/// embeds those related resources under `included`.
+pub async fn get_author<R: AuthorRepository>(
+    id: AuthorId,
+    state: State<AppState<R>>,
+    uri: Uri,
+) -> Result<Response, HttpError> {
+    let Query(params) = Query::<FindAuthorParams>::try_from_uri(&uri)
+        .map_err(|rejection| HttpError::invalid_request(rejection.body_text()))?;
+    match (params.fields, params.include) {
+        (Some(_), Some(_)) => Err(HttpError::invalid_request(
+            "Fields cannot be chosen for an author with related resources included".to_string(),
+        )),
+        (Some(fields), None) => Ok(find_projected_author(id, state, &fields)
+            .await?
+            .into_response()),
+        (None, Some(include)) => Ok(find_author_with_related(id, state, &include)
+            .await?
+            .into_response()),
+        (None, None) => Ok(find_author(id, state).await?.into_response()),
+    }
+}
+
+async fn find_projected_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    fields: &str,
+) -> Result<(LastModified, HttpSuccess<ProjectedAuthorHttpResponse>), HttpError> {
+    let fields = parse_fields(fields)?;
+    let req = FindAuthorRequest::new(id);
+    let author = state
+        .author_service
+        .find_author(&req)
+        .await
+        .map_err(HttpError::from)?;
+    let last_modified = LastModified(author.updated_at());
+    let author = ProjectedAuthorHttpResponse {
+        author: fields.project(&author),
+        fields,
+    };
+    Ok((last_modified, HttpSuccess::new(StatusCode::OK, author)))
+}
+
+async fn find_author_with_related<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    include: &str,
+) -> Result<(LastModified, HttpSuccess<AuthorWithRelatedHttpResponse>), HttpError> {
+    let includes: AuthorIncludes = include
+        .parse()
+        .map_err(|err: AuthorIncludeError| HttpError::invalid_request(err.to_string()))?;
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_author_with_related(&req, &includes)
+        .await
+        .map_err(HttpError::from)
+        .map(|related| {
+            let last_modified = LastModified(related.author().updated_at());
+            (
+                last_modified,
+                HttpSuccess::new(StatusCode::OK, related.into()),
+            )
+        })
+}
+
+fn parse_fields(fields: &str) -> Result<AuthorFields, HttpError> {
+    fields
+        .parse()
+        .map_err(|err: AuthorFieldsError| HttpError::invalid_request(err.to_string()))
+}
+
+/// Answers `HEAD` without loading the author, so clients can probe for existence cheaply.
+pub async fn author_exists<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<StatusCode, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    match state.author_service.author_exists(&req).await {
+        Ok(true) => Ok(StatusCode::OK),
+        Ok(false) => Err(FindAuthorError::NotFound { id }.into()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+pub async fn count_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<CountAuthorsHttpResponse>, HttpError> {
+    state
+        .author_service
+        .count_authors()
+        .await
+        .map_err(HttpError::from)
+        .map(|count| HttpSuccess::new(StatusCode::OK, CountAuthorsHttpResponse { count }))
+}
+
+pub async fn author_stats<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<AuthorStatsHttpResponse>, HttpError> {
+    state
+        .author_service
+        .author_stats()
+        .await
+        .map_err(HttpError::from)
+        .map(|stats| HttpSuccess::new(StatusCode::OK, stats.into()))
+}
+
+pub async fn find_external_works<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<ExternalWorksHttpResponse>, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_external_works(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|works| HttpSuccess::new(StatusCode::OK, works.into()))
+}
+
+const MAX_BATCH_IDS: usize = 100;
+
+#[derive(Debug, Default, Deserialize)]
+struct ListAuthorsParams {
+    email: Option<String>,
+    ids: Option<String>,
+    q: Option<String>,
+    genre: Option<String>,
+    verified: Option<bool>,
+    sort: Option<String>,
+    fields: Option<String>,
+    format: Option<String>,
+}
+
+/// Serves the author list as a JSON array, or streams it as NDJSON when asked for with
+/// `?format=ndjson` or an `Accept` header preferring `application/x-ndjson`. With
+/// `?ids=1,2,3` only those authors are fetched, along with the ids that do not exist; with
+/// `?q=` only authors whose name or an alias contains the query; with `?genre=` only authors
+/// with that genre; with `?verified=` only authors whose email address was or was not verified.
+/// `?email=` answers with the one author with exactly that address, like a lookup by id.
+/// `?sort=name,-created_at` orders the whole list by up to four fields, a leading `-` meaning
+/// descending; authors equal on every field are ordered by id so pages stay stable.
+/// `?fields=id,name` writes only those fields of each author, and loads only those.
+pub async fn list_authors<R: AuthorRepository>(
+    state: State<AppState<R>>,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Result<Response, HttpError> {
+    let Query(params) = Query::<ListAuthorsParams>::try_from_uri(&uri)
+        .map_err(|rejection| HttpError::invalid_request(rejection.body_text()))?;
+    if let Some(fields) = params.fields {
+        if params.sort.is_some()
+            || params.email.is_some()
+            || params.verified.is_some()
+            || params.genre.is_some()
+            || params.q.is_some()
+            || params.ids.is_some()
+        {
+            return Err(HttpError::invalid_request(
+                "Fields can only be chosen for the whole author list".to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Authors with chosen fields are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_projected_authors(state, &fields)
+            .await?
+            .into_response());
+    }
+    if let Some(sort) = params.sort {
+        if params.email.is_some()
+            || params.verified.is_some()
+            || params.genre.is_some()
+            || params.q.is_some()
+            || params.ids.is_some()
+        {
+            return Err(HttpError::invalid_request(
+                "Authors cannot be sorted and filtered at once".to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Sorted authors are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_sorted_authors(state, &sort).await?.into_response());
+    }
+    if let Some(email) = params.email {
+        if params.verified.is_some()
+            || params.genre.is_some()
+            || params.q.is_some()
+            || params.ids.is_some()
+        {
+            return Err(HttpError::invalid_request(
+                "Authors cannot be looked up by email and filtered at once".to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Authors looked up by email are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_author_by_email(state, &email).await?.into_response());
+    }
+    if let Some(verified) = params.verified {
+        if params.genre.is_some() || params.q.is_some() || params.ids.is_some() {
+            return Err(HttpError::invalid_request(
+                "Authors cannot be filtered by verification and by anything else at once"
+                    .to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Authors filtered by verification are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_authors_by_verification(state, verified)
+            .await?
+            .into_response());
+    }
+    if let Some(genre) = params.genre {
+        if params.q.is_some() || params.ids.is_some() {
+            return Err(HttpError::invalid_request(
+                "Authors cannot be filtered by genre and searched or requested by id at once"
+                    .to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Authors filtered by genre are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_authors_by_genre(state, &genre).await?.into_response());
+    }
+    if let Some(query) = params.q {
+        if params.ids.is_some() {
+            return Err(HttpError::invalid_request(
+                "Authors cannot be searched and requested by id at once".to_string(),
+            ));
+        }
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Author search results are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(search_authors(state, &query).await?.into_response());
+    }
+    if let Some(ids) = params.ids {
+        if params.format.is_some_and(|format| format != "json") {
+            return Err(HttpError::invalid_request(
+                "Authors requested by id are only available as JSON".to_string(),
+            ));
+        }
+        return Ok(find_authors_by_ids(state, &ids).await?.into_response());
+    }
+    let ndjson = match params.format.as_deref() {
+        Some("ndjson") => true,
+        Some("json") => false,
+        Some(format) => {
+            return Err(HttpError::invalid_request(format!(
+                r#"Unsupported format "{format}", expected "json" or "ndjson""#
+            )));
+        }
+        None => prefers_ndjson(&headers),
+    };
+    let vary = [(header::VARY, "accept")];
+    if ndjson {
+        Ok((vary, stream_authors_ndjson(&state).await).into_response())
+    } else {
+        Ok((vary, find_all_authors(state).await?).into_response())
+    }
+}
+
+async fn find_author_by_email<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    email: &str,
+) -> Result<(LastModified, HttpSuccess<FindAuthorHttpResponse>), HttpError> {
+    let email =
+        EmailAddress::new(email).map_err(|err| HttpError::invalid_request(err.to_string()))?;
+    let req = FindAuthorByEmailRequest::new(email);
+    state
+        .author_service
+        .find_author_by_email(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|author| {
+            let last_modified = LastModified(author.updated_at());
+            (
+                last_modified,
+                HttpSuccess::new(StatusCode::OK, author.into()),
+            )
+        })
+}
+
+async fn find_authors_by_ids<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    ids: &str,
+) -> Result<HttpSuccess<FindAuthorsByIdsHttpResponse>, HttpError> {
+    let ids = ids
+        .split(',')
+        .map(|id| id.trim().parse::<AuthorId>())
+        .collect::<Result<Vec<_>, _>>()?;
+    if ids.len() > MAX_BATCH_IDS {
+        return Err(HttpError::invalid_request(format!(
+            "At most {MAX_BATCH_IDS} ids can be requested at once"
+        )));
+    }
+    let req = FindAuthorsByIdsRequest::new(ids);
+    let authors = state
+        .author_service
+        .find_authors_by_ids(&req)
+        .await
+        .map_err(HttpError::from)?;
+    let missing = req.missing(&authors);
+    Ok(HttpSuccess::new(
+        StatusCode::OK,
+        FindAuthorsByIdsHttpResponse {
+            authors: authors
+                .into_iter()
+                .map(FindAuthorHttpResponse::from)
+                .collect(),
+            missing,
+        },
+    ))
+}
+
+async fn find_authors_by_verification<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    verified: bool,
+) -> Result<HttpSuccess<FindAllAuthorsHttpResponse>, HttpError> {
+    let req = if verified {
+        FindAuthorsByVerificationRequest::new([EmailVerification::Verified])
+    } else {
+        FindAuthorsByVerificationRequest::new([
+            EmailVerification::Pending,
+            EmailVerification::Rejected,
+        ])
+    };
+    state
+        .author_service
+        .find_authors_by_verification(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|authors| HttpSuccess::new(StatusCode::OK, authors.into()))
+}
+
+async fn find_authors_by_genre<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    genre: &str,
+) -> Result<HttpSuccess<FindAllAuthorsHttpResponse>, HttpError> {
+    let req = FindAuthorsByGenreRequest::new(parse_genre_id(genre)?);
+    state
+        .author_service
+        .find_authors_by_genre(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|authors| HttpSuccess::new(StatusCode::OK, authors.into()))
+}
+
+async fn search_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    query: &str,
+) -> Result<HttpSuccess<FindAllAuthorsHttpResponse>, HttpError> {
+    let req = SearchAuthorsRequest::new(query);
+    if req.query().is_empty() {
+        return Err(HttpError::invalid_request(
+            "Search query cannot be empty".to_string(),
+        ));
+    }
+    state
+        .author_service
+        .search_authors(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|authors| HttpSuccess::new(StatusCode::OK, authors.into()))
+}
+
+async fn find_sorted_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    sort: &str,
+) -> Result<HttpSuccess<FindAllAuthorsHttpResponse>, HttpError> {
+    let sort = sort
+        .parse::<AuthorSort>()
+        .map_err(|err| HttpError::invalid_request(err.to_string()))?;
+    let req = FindSortedAuthorsRequest::new(sort);
+    state
+        .author_service
+        .find_sorted_authors(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|authors| HttpSuccess::new(StatusCode::OK, authors.into()))
+}
+
+async fn find_projected_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    fields: &str,
+) -> Result<HttpSuccess<ProjectedAuthorsHttpResponse>, HttpError> {
+    let req = FindProjectedAuthorsRequest::new(parse_fields(fields)?);
+    let authors = state
+        .author_service
+        .find_projected_authors(&req)
+        .await
+        .map_err(HttpError::from)?;
+    Ok(HttpSuccess::new(
+        StatusCode::OK,
+        ProjectedAuthorsHttpResponse {
+            fields: req.fields().clone(),
+            authors,
+        },
+    ))
+}
+
+pub async fn find_all_authors<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<FindAllAuthorsHttpResponse>, HttpError> {
+    state
+        .author_service
+        .find_all_authors()
+        .await
+        .map_err(HttpError::from)
+        .map(|authors| HttpSuccess::new(StatusCode::OK, authors.into()))
+}
+
+fn prefers_ndjson(headers: &HeaderMap) -> bool {
+    let accept = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+    let ndjson = quality(&accept, NDJSON);
+    ndjson > 0.0 && ndjson > quality(&accept, "application/json")
+}
+
+pub async fn update_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    headers: HeaderMap,
+    AuthorPatch(body): AuthorPatch,
+) -> Result<(LastModified, HttpSuccess<FindAuthorHttpResponse>), HttpError> {
+    let req = UpdateAuthorRequestBuilder::try_from((id, body))?
+        .unmodified_since(if_unmodified_since(&headers))
+        .build()?;
+    let author = state
+        .author_service
+        .update_author(&req, &ctx)
+        .await
+        .map_err(HttpError::from)?;
+    let last_modified = LastModified(author.updated_at());
+    Ok((
+        last_modified,
+        HttpSuccess::new(StatusCode::OK, author.into()),
+    ))
+}
+
+pub async fn replace_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    headers: HeaderMap,
+    StrictJson(body): StrictJson<CreateAuthorHttpRequest>,
+) -> Result<(LastModified, HttpSuccess<FindAuthorHttpResponse>), HttpError> {
+    let req = ReplaceAuthorRequest::try_from((id, body))?
+        .with_unmodified_since(if_unmodified_since(&headers));
+    let replaced = state
+        .author_service
+        .replace_author(&req, &ctx)
+        .await
+        .map_err(HttpError::from)?;
+    let status = match replaced {
+        ReplacedAuthor::Created(_) => StatusCode::CREATED,
+        ReplacedAuthor::Replaced(_) => StatusCode::OK,
+    };
+    let author = replaced.into_author();
+    let last_modified = LastModified(author.updated_at());
+    Ok((last_modified, HttpSuccess::new(status, author.into())))
+}
+
+pub async fn archive_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<FindAuthorHttpResponse>, HttpError> {
+    change_author_status(id, &state, &ctx, AuthorTransition::Archive).await
+}
+
+pub async fn unarchive_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<FindAuthorHttpResponse>, HttpError> {
+    change_author_status(id, &state, &ctx, AuthorTransition::Unarchive).await
+}
+
+async fn change_author_status<R: AuthorRepository>(
+    id: AuthorId,
+    state: &AppState<R>,
+    ctx: &AuditContext,
+    transition: AuthorTransition,
+) -> Result<HttpSuccess<FindAuthorHttpResponse>, HttpError> {
+    let req = ChangeAuthorStatusRequest::new(id, transition);
+    state
+        .author_service
+        .change_author_status(&req, ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|author| HttpSuccess::new(StatusCode::OK, author.into()))
+}
+
+pub async fn delete_author<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    headers: HeaderMap,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let req = DeleteAuthorRequest::new(id).with_unmodified_since(if_unmodified_since(&headers));
+    state
+        .author_service
+        .delete_author(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+pub async fn find_audit_log<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<AuditLogHttpResponse>, HttpError> {
+    let req = FindAuditLogRequest::new(id);
+    state
+        .author_service
+        .find_audit_log(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|entries| HttpSuccess::new(StatusCode::OK, entries.into()))
+}
+
+pub async fn export_author_data<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<AuthorDataExportHttpResponse>, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .export_author_data(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|export| HttpSuccess::new(StatusCode::OK, export.into()))
+}
+
+/// Irreversible, so only admins may purge.
+pub async fn purge_author<R: AuthorRepository>(
+    _: AdminAuth,
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<ErasureRecordHttpResponse>, HttpError> {
+    let req = PurgeAuthorRequest::new(id);
+    state
+        .author_service
+        .purge_author(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|record| HttpSuccess::new(StatusCode::OK, record.into()))
+}
+
+pub async fn upload_avatar<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    multipart: Multipart,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let bytes = read_avatar_field(multipart).await?;
+    let req: UploadAvatarRequest = (id, bytes).try_into()?;
+    state
+        .author_service
+        .upload_avatar(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+async fn read_avatar_field(
+    mut multipart: Multipart,
+) -> Result<Vec<u8>, ParseUploadAvatarHttpRequestError> {
+    while let Some(field) = multipart.next_field().await? {
+        if field.name() == Some("avatar") {
+            return Ok(field.bytes().await?.to_vec());
+        }
+    }
+    Err(ParseUploadAvatarHttpRequestError::Missing)
+}
+
+pub async fn find_avatar<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<AvatarHttpResponse, HttpError> {
+    let req = FindAvatarRequest::new(id);
+    state
+        .author_service
+        .find_avatar(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(AvatarHttpResponse)
+}
+
+pub async fn find_author_aliases<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<AuthorAliasesHttpResponse>, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_author_aliases(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|aliases| {
+            let aliases = aliases.iter().map(ToString::to_string).collect();
+            HttpSuccess::new(StatusCode::OK, AuthorAliasesHttpResponse { aliases })
+        })
+}
+
+pub async fn add_author_alias<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+    StrictJson(body): StrictJson<AuthorAliasHttpBody>,
+) -> Result<HttpSuccess<AuthorAliasHttpBody>, HttpError> {
+    let mut fields = FieldErrors::new();
+    let Ok(alias) = AuthorName::new(&body.alias) else {
+        fields.insert("alias", "cannot be empty".to_string());
+        return Err(HttpError::invalid_fields(fields));
+    };
+    let req = AddAuthorAliasRequest::new(id, alias);
+    state
+        .author_service
+        .add_author_alias(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| {
+            let alias = req.alias().to_string();
+            HttpSuccess::new(StatusCode::CREATED, AuthorAliasHttpBody { alias })
+        })
+}
+
+/// Takes both path segments itself, as the [`AuthorId`] extractor expects a lone `{id}`.
+pub async fn remove_author_alias<R: AuthorRepository>(
+    Path((id, alias)): Path<(String, String)>,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let id = id.parse::<AuthorId>()?;
+    let Ok(alias) = AuthorName::new(&alias) else {
+        return Err(RemoveAuthorAliasError::NotFound {
+            id,
+            alias: AuthorName::new_unchecked(&alias),
+        }
+        .into());
+    };
+    let req = RemoveAuthorAliasRequest::new(id, alias);
+    state
+        .author_service
+        .remove_author_alias(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+fn parse_genre_id(raw: &str) -> Result<GenreId, HttpError> {
+    raw.parse().map_err(|_| {
+        HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ProblemType::InvalidId,
+            Message::new("invalid-genre-id").arg("id", raw),
+        )
+    })
+}
+
+pub async fn list_genres<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<GenresHttpResponse>, HttpError> {
+    state
+        .author_service
+        .find_all_genres()
+        .await
+        .map_err(HttpError::from)
+        .map(|genres| HttpSuccess::new(StatusCode::OK, genres.into()))
+}
+
+pub async fn create_genre<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    StrictJson(body): StrictJson<CreateGenreHttpRequest>,
+) -> Result<HttpSuccess<GenreHttpResponse>, HttpError> {
+    let name = GenreName::new(&body.name).map_err(|err| {
+        HttpError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            format!("name {err}"),
+        )
+        .with_field("name", err.to_string())
+    })?;
+    let req = CreateGenreRequest::new(name);
+    state
+        .author_service
+        .create_genre(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|genre| HttpSuccess::new(StatusCode::CREATED, genre.into()))
+}
+
+pub async fn delete_genre<R: AuthorRepository>(
+    Path(genre_id): Path<String>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let req = DeleteGenreRequest::new(parse_genre_id(&genre_id)?);
+    state
+        .author_service
+        .delete_genre(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+pub async fn find_author_genres<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<GenresHttpResponse>, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_author_genres(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|genres| HttpSuccess::new(StatusCode::OK, genres.into()))
+}
+
+/// Takes both path segments itself, as the [`AuthorId`] extractor expects a lone `{id}`.
+pub async fn attach_genre<R: AuthorRepository>(
+    Path((id, genre_id)): Path<(String, String)>,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let req = AuthorGenreRequest::new(id.parse()?, parse_genre_id(&genre_id)?);
+    state
+        .author_service
+        .attach_genre(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+pub async fn detach_genre<R: AuthorRepository>(
+    Path((id, genre_id)): Path<(String, String)>,
+    State(state): State<AppState<R>>,
+    ctx: AuditContext,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let req = AuthorGenreRequest::new(id.parse()?, parse_genre_id(&genre_id)?);
+    state
+        .author_service
+        .detach_genre(&req, &ctx)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+fn parse_publisher_id(raw: &str) -> Result<PublisherId, HttpError> {
+    raw.parse().map_err(|_| {
+        HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ProblemType::InvalidId,
+            Message::new("invalid-publisher-id").arg("id", raw),
+        )
+    })
+}
+
+pub async fn list_publishers<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<PublishersHttpResponse>, HttpError> {
+    state
+        .author_service
+        .find_all_publishers()
+        .await
+        .map_err(HttpError::from)
+        .map(|publishers| HttpSuccess::new(StatusCode::OK, publishers.into()))
+}
+
+pub async fn create_publisher<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    StrictJson(body): StrictJson<CreatePublisherHttpRequest>,
+) -> Result<HttpSuccess<PublisherHttpResponse>, HttpError> {
+    let name = PublisherName::new(&body.name).map_err(|err| {
+        HttpError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            format!("name {err}"),
+        )
+        .with_field("name", err.to_string())
+    })?;
+    let req = CreatePublisherRequest::new(name);
+    state
+        .author_service
+        .create_publisher(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|publisher| HttpSuccess::new(StatusCode::CREATED, publisher.into()))
+}
+
+pub async fn find_publisher<R: AuthorRepository>(
+    Path(publisher_id): Path<String>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<PublisherHttpResponse>, HttpError> {
+    let req = FindPublisherRequest::new(parse_publisher_id(&publisher_id)?);
+    state
+        .author_service
+        .find_publisher(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|publisher| HttpSuccess::new(StatusCode::OK, publisher.into()))
+}
+
+pub async fn delete_publisher<R: AuthorRepository>(
+    Path(publisher_id): Path<String>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let req = DeletePublisherRequest::new(parse_publisher_id(&publisher_id)?);
+    state
+        .author_service
+        .delete_publisher(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+pub async fn find_publisher_contracts<R: AuthorRepository>(
+    Path(publisher_id): Path<String>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<ContractsHttpResponse>, HttpError> {
+    let req = FindPublisherRequest::new(parse_publisher_id(&publisher_id)?);
+    state
+        .author_service
+        .find_publisher_contracts(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|contracts| HttpSuccess::new(StatusCode::OK, contracts.into()))
+}
+
+pub async fn find_author_contracts<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<ContractsHttpResponse>, HttpError> {
+    let req = FindAuthorRequest::new(id);
+    state
+        .author_service
+        .find_author_contracts(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|contracts| HttpSuccess::new(StatusCode::OK, contracts.into()))
+}
+
+pub async fn create_contract<R: AuthorRepository>(
+    id: AuthorId,
+    State(state): State<AppState<R>>,
+    StrictJson(body): StrictJson<CreateContractHttpRequest>,
+) -> Result<HttpSuccess<ContractHttpResponse>, HttpError> {
+    let req = body.try_into_domain(id)?;
+    state
+        .author_service
+        .create_contract(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|contract| HttpSuccess::new(StatusCode::CREATED, contract.into()))
+}
+
+/// Takes both path segments itself, as the [`AuthorId`] extractor expects a lone `{id}`.
+pub async fn delete_contract<R: AuthorRepository>(
+    Path((id, contract_id)): Path<(String, String)>,
+    State(state): State<AppState<R>>,
+) -> Result<HttpSuccess<()>, HttpError> {
+    let id = id.parse::<AuthorId>()?;
+    let Ok(contract_id) = contract_id.parse() else {
+        return Err(HttpError::new(
+            StatusCode::BAD_REQUEST,
+            ProblemType::InvalidId,
+            Message::new("invalid-contract-id").arg("id", contract_id),
+        ));
+    };
+    let req = DeleteContractRequest::new(id, contract_id);
+    state
+        .author_service
+        .delete_contract(&req)
+        .await
+        .map_err(HttpError::from)
+        .map(|()| HttpSuccess::new(StatusCode::NO_CONTENT, ()))
+}
+
+pub async fn allowed_methods(methods: &'static str) -> impl IntoResponse {
+    (StatusCode::NO_CONTENT, [(header::ALLOW, methods)])
+}
+
+impl HttpError {
+    fn new(status: StatusCode, problem: ProblemType, message: impl Into<Message>) -> Self {
+        Self(status, problem, message.into(), BTreeMap::new(), None)
+    }
+
+    pub const fn status(&self) -> StatusCode {
+        self.0
+    }
+
+    /// The message in the language negotiated for the current request.
+    pub fn message(&self) -> String {
+        self.2.render(Locale::current())
+    }
+
+    #[must_use]
+    pub fn with_field(mut self, field: &'static str, message: String) -> Self {
+        self.3.insert(field, message);
+        self
+    }
+
+    pub fn route_not_found(message: String) -> Self {
+        Self::new(StatusCode::NOT_FOUND, ProblemType::RouteNotFound, message)
+    }
+
+    pub fn invalid_request(message: String) -> Self {
+        Self::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            message,
+        )
+    }
+
+    pub fn invalid_body(message: Message) -> Self {
+        Self::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            message,
+        )
+    }
+
+    pub fn invalid_fields(fields: FieldErrors) -> Self {
+        Self(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            Message::Fields(fields.clone()),
+            fields,
+            None,
+        )
+    }
+
+    pub fn unsupported_content_type(message: String) -> Self {
+        Self::new(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ProblemType::UnsupportedContentType,
+            message,
+        )
+    }
+
+    pub fn payload_too_large(message: String) -> Self {
+        Self::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            ProblemType::PayloadTooLarge,
+            message,
+        )
+    }
+
+    pub fn unsupported_patch_format(message: String) -> Self {
+        Self::new(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ProblemType::UnsupportedPatchFormat,
+            message,
+        )
+    }
+
+    pub fn unauthorized(message: String) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, ProblemType::Unauthorized, message)
+    }
+
+    pub fn forbidden(message: String) -> Self {
+        Self::new(StatusCode::FORBIDDEN, ProblemType::Forbidden, message)
+    }
+
+    pub fn client_banned(message: String, retry_after: Duration) -> Self {
+        Self(
+            StatusCode::TOO_MANY_REQUESTS,
+            ProblemType::ClientBanned,
+            message.into(),
+            BTreeMap::new(),
+            Some(retry_after),
+        )
+    }
+
+    pub fn invalid_log_filter(message: String) -> Self {
+        Self::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidLogFilter,
+            message,
+        )
+    }
+
+    fn unavailable(err: &UnavailableError) -> Self {
+        Self(
+            StatusCode::SERVICE_UNAVAILABLE,
+            ProblemType::Unavailable,
+            err.to_string().into(),
+            BTreeMap::new(),
+            Some(err.retry_after()),
+        )
+    }
+
+    fn timed_out(err: &TimedOutError) -> Self {
+        tracing::warn!("{err}");
+        Self::new(
+            StatusCode::GATEWAY_TIMEOUT,
+            ProblemType::TimedOut,
+            err.to_string(),
+        )
+    }
+
+    pub fn internal(cause: &anyhow::Error) -> Self {
+        if let Some(err) = cause
+            .chain()
+            .find_map(|err| err.downcast_ref::<UnavailableError>())
+        {
+            return Self::unavailable(err);
+        }
+        if let Some(err) = cause
+            .chain()
+            .find_map(|err| err.downcast_ref::<TimedOutError>())
+        {
+            return Self::timed_out(err);
+        }
+        tracing::error!("{cause:?}\n{}", cause.backtrace());
+        Self::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            ProblemType::Internal,
+            "Internal server error".to_string(),
+        )
+    }
+}
+
+pub async fn method_not_allowed(method: Method) -> HttpError {
+    HttpError::new(
+        StatusCode::METHOD_NOT_ALLOWED,
+        ProblemType::MethodNotAllowed,
+        format!("method {method} is not allowed for this resource"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::strategies::{author, raw_name, valid_address};
+    use crate::domain::model::{
+        AddAuthorAliasError, AuditContext, Author, AuthorGenreRequest, AuthorId, AuthorName,
+        AuthorSortField, CreateAuthorRequest, CreateContractError, CreateGenreRequest,
+        EmailAddress, FindAuthorByEmailError, FindAuthorError, GenreName, PublisherId,
+        TimedOutError, UnavailableError, UpdateAuthorError,
+    };
+    use crate::domain::ports::{AuthorRepository, GenreRepository};
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::AppState;
+    use crate::inbound::http::caching::LastModified;
+    use crate::inbound::http::handlers::{
+        AuthorAliasHttpBody, CreateAuthorHttpRequest, CreateAuthorHttpResponse,
+        FindAllAuthorsHttpResponse, FindAuthorHttpResponse, FindAuthorsByIdsHttpResponse,
+        HttpError, HttpSuccess, UpdateAuthorHttpRequest, add_author_alias, create_author,
+        create_contract, delete_author, delete_genre, find_all_authors, find_author,
+        find_author_by_email, find_author_with_related, find_authors_by_ids, find_operation,
+        find_projected_authors, find_sorted_authors, import_authors, replace_author, update_author,
+    };
+    use crate::inbound::http::json::StrictJson;
+    use crate::inbound::http::patch::{AuthorPatch, PatchField};
+    use crate::inbound::http::problem::ProblemType;
+    use crate::outbound::events::LogEventPublisher;
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use axum::extract::{Path, State};
+    use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+    use axum::response::IntoResponse;
+    use chrono::{DateTime, TimeDelta, Utc};
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    fn app_state(repo: MockAuthorRepository) -> AppState {
+        AppState::new(AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            LogEventPublisher,
+            InMemoryRepository::new(),
+            InMemoryRepository::new(),
+            InMemoryRepository::new(),
+        ))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_author_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let author_name = AuthorName::new("JRR Tolkien").unwrap();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            author_id,
+            author_name.clone(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_create().returning(move |_| Ok(author.clone()));
+        let state = State(app_state(repo));
+        let body = StrictJson(CreateAuthorHttpRequest {
+            name: author_name.to_string(),
+            email: author_email.to_string(),
+            ..Default::default()
+        });
+        let expected = HttpSuccess::new(
+            StatusCode::CREATED,
+            CreateAuthorHttpResponse { id: author_id },
+        );
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = create_author(state, ctx, body).await;
+        assert!(
+            actual.is_ok(),
+            "expected create author to succeed, but got {actual:?}",
+        );
+        let actual = actual.unwrap();
+        assert_eq!(
+            expected, actual,
+            "expected ApiSuccess {expected:?}, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_author_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let author_name = AuthorName::new("JRR Tolkien").unwrap();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            author_id,
+            author_name.clone(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| Ok(author.clone()));
+        let state = State(app_state(repo));
+        let expected = (
+            LastModified(now),
+            HttpSuccess::new(
+                StatusCode::OK,
+                FindAuthorHttpResponse {
+                    id: author_id,
+                    name: author_name.clone(),
+                    email: author_email.clone(),
+                    verified: false,
+                    status: "active",
+                    bio: None,
+                    birth_date: None,
+                    website_url: None,
+                    country: None,
+                    created_at: now,
+                    updated_at: now,
+                },
+            ),
+        );
+        let actual = find_author(author_id, state).await;
+        assert!(
+            actual.is_ok(),
+            "expected find author to succeed, but got {actual:?}",
+        );
+        let actual = actual.unwrap();
+        assert_eq!(
+            expected, actual,
+            "expected ApiSuccess {expected:?}, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_author_by_email_handler_matches_the_normalized_address() {
+        let now = Utc::now();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            AuthorId::new(1),
+            AuthorName::new("JRR Tolkien").unwrap(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find_by_email().returning(move |req| {
+            if req.email() == author.email() {
+                Ok(author.clone())
+            } else {
+                Err(FindAuthorByEmailError::NotFound {
+                    email: req.email().to_string(),
+                })
+            }
+        });
+        let state = State(app_state(repo));
+
+        let (_, actual) = find_author_by_email(state.clone(), " JRR.Tolkien@Example.com ")
+            .await
+            .unwrap();
+        assert_eq!(author_email, actual.1.email);
+        let actual = find_author_by_email(state.clone(), "cs.lewis@example.com").await;
+        assert!(
+            matches!(&actual, Err(HttpError(StatusCode::NOT_FOUND, ..))),
+            "expected an unknown email to be not found, but got {actual:?}"
+        );
+        let actual = find_author_by_email(state, "not an email").await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(StatusCode::UNPROCESSABLE_ENTITY, ..))
+            ),
+            "expected an invalid email to be rejected, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_projected_authors_handler_writes_only_the_asked_fields() {
+        let now = Utc::now();
+        let author = Author::new(
+            AuthorId::new(1),
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find_projected()
+            .returning(move |req| Ok(vec![req.fields().project(&author)]));
+        let state = State(app_state(repo));
+
+        let actual = find_projected_authors(state.clone(), "name,id,bio")
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::json!([{ "name": "JRR Tolkien", "id": 1, "bio": null }]),
+            serde_json::to_value(&actual.1).unwrap()
+        );
+        let actual = find_projected_authors(state, "name,password").await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(StatusCode::UNPROCESSABLE_ENTITY, ..))
+            ),
+            "expected an unknown field to be rejected, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_author_with_related_handler_embeds_only_the_included() {
+        let now = Utc::now();
+        let author = Author::new(
+            AuthorId::new(1),
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| Ok(author.clone()));
+        let aliases = repo
+            .expect_find_aliases()
+            .returning(|_| Ok(vec![AuthorName::new("John Ronald Reuel").unwrap()]));
+        let state = State(app_state(repo));
+
+        let (_, actual) = find_author_with_related(AuthorId::new(1), state.clone(), "aliases")
+            .await
+            .unwrap();
+        let json = serde_json::to_value(&actual.1).unwrap();
+        assert_eq!("JRR Tolkien", json["name"]);
+        assert_eq!(
+            serde_json::json!({ "aliases": ["John Ronald Reuel"] }),
+            json["included"]
+        );
+        assert_eq!(1, aliases.calls());
+
+        for include in ["books", "contracts.publisher", "aliases,"] {
+            let actual = find_author_with_related(AuthorId::new(1), state.clone(), include).await;
+            assert!(
+                matches!(
+                    &actual,
+                    Err(HttpError(StatusCode::UNPROCESSABLE_ENTITY, ..))
+                ),
+                "expected {include:?} to be rejected, but got {actual:?}"
+            );
+        }
+        assert_eq!(1, aliases.calls());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn import_authors_handler_runs_in_the_background_when_asked() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            LogEventPublisher,
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let state = State(AppState::new(
+            service.with_operation_store(InMemoryRepository::new()),
+        ));
+        let author = |name: &str, email: &str| CreateAuthorHttpRequest {
+            name: name.to_string(),
+            email: email.to_string(),
+            ..Default::default()
+        };
+        let body = || {
+            StrictJson(vec![
+                author("JRR Tolkien", "jrr.tolkien@example.com"),
+                author("CS Lewis", "cs.lewis@example.com"),
+            ])
+        };
+        let ctx = || AuditContext::new("anonymous".into(), None);
+        let mut headers = HeaderMap::new();
+        headers.insert("prefer", HeaderValue::from_static("respond-async, wait=5"));
+
+        let response = import_authors(state.clone(), ctx(), headers, body())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::ACCEPTED, response.status());
+        assert_eq!(
+            Some("respond-async"),
+            response
+                .headers()
+                .get("preference-applied")
+                .and_then(|value| value.to_str().ok())
+        );
+        let location = response.headers()[header::LOCATION].to_str().unwrap();
+        let id = location
+            .strip_prefix("/api/v1/operations/")
+            .unwrap()
+            .to_string();
+
+        let operation = loop {
+            let operation = find_operation(Path(id.clone()), state.clone())
+                .await
+                .unwrap()
+                .1;
+            if operation.status != "running" && operation.status != "pending" {
+                break operation;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        assert_eq!(("succeeded", 100), (operation.status, operation.progress));
+        assert_eq!(
+            Some(serde_json::json!({ "created": 2, "skipped": 0, "rejected": 0 })),
+            operation.result
+        );
+
+        let response = import_authors(state.clone(), ctx(), HeaderMap::new(), body())
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+
+        let actual = find_operation(Path("not-an-id".to_string()), state).await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(
+                    StatusCode::BAD_REQUEST,
+                    ProblemType::InvalidId,
+                    ..
+                ))
+            ),
+            "expected an invalid id error, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_sorted_authors_handler_passes_the_parsed_sort() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find_sorted().returning(|req| {
+            let keys: Vec<_> = req
+                .sort()
+                .keys()
+                .iter()
+                .map(|key| (key.field(), key.descending()))
+                .collect();
+            assert_eq!(
+                vec![(AuthorSortField::Name, true), (AuthorSortField::Id, false)],
+                keys
+            );
+            Ok(Vec::new())
+        });
+        let state = State(app_state(repo));
+
+        let actual = find_sorted_authors(state.clone(), "-name, id")
+            .await
+            .unwrap();
+        assert!(actual.1.0.is_empty());
+        let actual = find_sorted_authors(state, "email").await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(StatusCode::UNPROCESSABLE_ENTITY, ..))
+            ),
+            "expected an unknown sort field to be rejected, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_authors_by_ids_handler_reports_missing_ids() {
+        let now = Utc::now();
+        let author_name = AuthorName::new("JRR Tolkien").unwrap();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            AuthorId::new(2),
+            author_name.clone(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        let expectation = repo
+            .expect_find_by_ids()
+            .returning(move |_| Ok(vec![author.clone()]))
+            .times(1);
+        let state = State(app_state(repo.clone()));
+        let expected = HttpSuccess::new(
+            StatusCode::OK,
+            FindAuthorsByIdsHttpResponse {
+                authors: vec![FindAuthorHttpResponse {
+                    id: AuthorId::new(2),
+                    name: author_name.clone(),
+                    email: author_email.clone(),
+                    verified: false,
+                    status: "active",
+                    bio: None,
+                    birth_date: None,
+                    website_url: None,
+                    country: None,
+                    created_at: now,
+                    updated_at: now,
+                }],
+                missing: vec![AuthorId::new(1), AuthorId::new(3)],
+            },
+        );
+        let actual = find_authors_by_ids(state.clone(), "1, 2,3,1")
+            .await
+            .unwrap();
+        assert_eq!(expected, actual);
+        repo.verify();
+
+        let actual = find_authors_by_ids(state.clone(), "1,two").await;
+        assert!(
+            matches!(&actual, Err(HttpError(StatusCode::BAD_REQUEST, ..))),
+            "expected an unparsable id to be rejected, but got {actual:?}"
+        );
+        let ids = (1..=101)
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let actual = find_authors_by_ids(state, &ids).await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(StatusCode::UNPROCESSABLE_ENTITY, ..))
+            ),
+            "expected too many ids to be rejected, but got {actual:?}"
+        );
+        assert_eq!(1, expectation.calls());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn unavailable_repository_maps_to_service_unavailable() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(|_| {
+            let err = UnavailableError::new(Duration::from_millis(1_500));
+            Err(FindAuthorError::Other(err.into()))
+        });
+        let state = State(app_state(repo));
+        let response = find_author(AuthorId::new(1), state)
+            .await
+            .unwrap_err()
+            .into_response();
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
+        assert_eq!(
+            Some(&HeaderValue::from_static("2")),
+            response.headers().get(header::RETRY_AFTER)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn timed_out_repository_maps_to_gateway_timeout() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(|_| {
+            Err(FindAuthorError::Other(
+                TimedOutError::new("find_author").into(),
+            ))
+        });
+        let state = State(app_state(repo));
+        let actual = find_author(AuthorId::new(1), state).await;
+        assert!(
+            matches!(&actual, Err(HttpError(StatusCode::GATEWAY_TIMEOUT, ..))),
+            "expected a gateway timeout, but got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn operation_errors_map_through_the_shared_kernel() {
+        let err = HttpError::from(UpdateAuthorError::PreconditionFailed {
+            id: AuthorId::new(1),
+        });
+        assert_eq!(StatusCode::PRECONDITION_FAILED, err.status());
+        assert_eq!(ProblemType::PreconditionFailed, err.1);
+
+        let err = HttpError::from(CreateContractError::PublisherNotFound {
+            id: PublisherId::new(7),
+        });
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, err.status());
+        assert_eq!(ProblemType::PublisherNotFound, err.1);
+        assert_eq!(
+            Some(&"does not exist".to_string()),
+            err.3.get("publisher_id")
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_all_authors_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let author_name = AuthorName::new("JRR Tolkien").unwrap();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            author_id,
+            author_name.clone(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find_all()
+            .returning(move |_| Ok(vec![author.clone()]));
+        let state = State(app_state(repo));
+        let expected = HttpSuccess::new(
+            StatusCode::OK,
+            FindAllAuthorsHttpResponse(vec![FindAuthorHttpResponse {
+                id: author_id,
+                name: author_name.clone(),
+                email: author_email.clone(),
+                verified: false,
+                status: "active",
+                bio: None,
+                birth_date: None,
+                website_url: None,
+                country: None,
+                created_at: now,
+                updated_at: now,
+            }]),
+        );
+        let actual = find_all_authors(state).await;
+        assert!(
+            actual.is_ok(),
+            "expected find author to succeed, but got {actual:?}",
+        );
+        let actual = actual.unwrap();
+        assert_eq!(
+            expected, actual,
+            "expected ApiSuccess {expected:?}, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_author_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| {
+            Ok(Author::new(
+                author_id,
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+                now,
+                now,
+            ))
+        });
+        let later = now + TimeDelta::seconds(1);
+        let updated_name = AuthorName::new("Barry Allen").unwrap();
+        let updated_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let updated = Author::new(
+            author_id,
+            updated_name.clone(),
+            updated_email.clone(),
+            now,
+            later,
+        );
+        repo.expect_update()
+            .returning(move |_| Ok(updated.clone()))
+            .times(1);
+        let state = State(app_state(repo.clone()));
+        let body = AuthorPatch(UpdateAuthorHttpRequest {
+            name: PatchField::Value("Barry Allen".into()),
+            ..Default::default()
+        });
+        let expected = HttpSuccess::new(
+            StatusCode::OK,
+            FindAuthorHttpResponse {
+                id: author_id,
+                name: updated_name,
+                email: updated_email,
+                verified: false,
+                status: "active",
+                bio: None,
+                birth_date: None,
+                website_url: None,
+                country: None,
+                created_at: now,
+                updated_at: later,
+            },
+        );
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = update_author(author_id, state, ctx, HeaderMap::new(), body).await;
+        assert!(
+            actual.is_ok(),
+            "expected update author to succeed, but got {actual:?}",
+        );
+        let (last_modified, actual) = actual.unwrap();
+        assert_eq!(later, last_modified.0);
+        assert_eq!(
+            expected, actual,
+            "expected ApiSuccess {expected:?}, but got {actual:?}",
+        );
+        repo.verify();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_author_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| {
+            Ok(Author::new(
+                author_id,
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+                now,
+                now,
+            ))
+        });
+        repo.expect_delete().returning(|_| Ok(()));
+        let state = State(app_state(repo));
+        let expected = HttpSuccess::new(StatusCode::NO_CONTENT, ());
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = delete_author(author_id, state, ctx, HeaderMap::new()).await;
+        assert!(
+            actual.is_ok(),
+            "expected delete author to succeed, but got {actual:?}",
+        );
+        let actual = actual.unwrap();
+        assert_eq!(
+            expected, actual,
+            "expected ApiSuccess {expected:?}, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_author_handler_rejects_stale_precondition() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| {
+            Ok(Author::new(
+                author_id,
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+                now,
+                now,
+            ))
+        });
+        repo.expect_delete().returning(|_| Ok(()));
+        let state = State(app_state(repo));
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_UNMODIFIED_SINCE,
+            HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"),
+        );
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = delete_author(author_id, state, ctx, headers).await;
+        assert!(
+            matches!(&actual, Err(err) if err.0 == StatusCode::PRECONDITION_FAILED),
+            "expected delete author to be rejected, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replace_author_handler_success() {
+        let now = Utc::now();
+        let author_id = AuthorId::new(1);
+        let author_name = AuthorName::new("J.R.R. Tolkien").unwrap();
+        let author_email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = Author::new(
+            author_id,
+            author_name.clone(),
+            author_email.clone(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        let found = author.clone();
+        repo.expect_find().returning(move |_| Ok(found.clone()));
+        let replaced = author.clone();
+        repo.expect_upsert()
+            .returning(move |_| Ok(replaced.clone()));
+        let state = State(app_state(repo));
+        let body = StrictJson(CreateAuthorHttpRequest {
+            name: author_name.to_string(),
+            email: author_email.to_string(),
+            ..Default::default()
+        });
+        let expected = (
+            LastModified(now),
+            HttpSuccess::new(StatusCode::OK, FindAuthorHttpResponse::from(author)),
+        );
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = replace_author(author_id, state, ctx, HeaderMap::new(), body).await;
+        assert!(
+            actual.is_ok(),
+            "expected replace author to succeed, but got {actual:?}",
+        );
+        assert_eq!(expected, actual.unwrap());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn update_author_handler_rejects_empty_update() {
+        let author_id = AuthorId::new(1);
+        let state = State(app_state(MockAuthorRepository::new()));
+        let body = AuthorPatch(UpdateAuthorHttpRequest::default());
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let actual = update_author(author_id, state, ctx, HeaderMap::new(), body).await;
+        assert!(
+            matches!(&actual, Err(err) if err.0 == StatusCode::UNPROCESSABLE_ENTITY),
+            "expected update author to be rejected, but got {actual:?}",
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn create_author_handler_reports_every_invalid_field() {
+        let state = State(app_state(MockAuthorRepository::new()));
+        let body = StrictJson(CreateAuthorHttpRequest {
+            name: " ".into(),
+            email: "not-an-email".into(),
+            ..Default::default()
+        });
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let err = create_author(state, ctx, body).await.unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, err.0);
+        assert_eq!(
+            Some("cannot be empty"),
+            err.3.get("name").map(String::as_str)
+        );
+        assert_eq!(
+            Some("is not a valid email address"),
+            err.3.get("email").map(String::as_str)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn add_author_alias_handler_reports_duplicate_alias() {
+        let now = Utc::now();
+        let author = Author::new(
+            AuthorId::new(1),
+            AuthorName::new("C. S. Lewis").unwrap(),
+            EmailAddress::new("cs.lewis@example.com").unwrap(),
+            now,
+            now,
+        );
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().returning(move |_| Ok(author.clone()));
+        repo.expect_add_alias().returning(|req| {
+            Err(AddAuthorAliasError::DuplicateAlias {
+                alias: req.alias().clone(),
+            })
+        });
+        repo.expect_find_aliases().returning(|_| Ok(Vec::new()));
+        let state = State(app_state(repo));
+        let ctx = AuditContext::new("anonymous".into(), None);
+        let body = StrictJson(AuthorAliasHttpBody {
+            alias: "Clive Hamilton".into(),
+        });
+        let actual = add_author_alias(AuthorId::new(1), state, ctx, body).await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(
+                    StatusCode::CONFLICT,
+                    ProblemType::DuplicateAlias,
+                    ..
+                ))
+            ),
+            "expected a duplicate alias conflict, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_genre_handler_reports_genre_in_use() {
+        let repo = InMemoryRepository::new();
+        let author = repo
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let genre = repo
+            .create_genre(&CreateGenreRequest::new(GenreName::new("Fantasy").unwrap()))
+            .await
+            .unwrap();
+        repo.attach_genre(&AuthorGenreRequest::new(author.id(), genre.id()))
+            .await
+            .unwrap();
+        let state = State(AppState::new(AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            LogEventPublisher,
+            repo.clone(),
+            repo.clone(),
+            repo,
+        )));
+
+        let actual = delete_genre(Path(genre.id().to_string()), state).await;
+        assert!(
+            matches!(
+                &actual,
+                Err(HttpError(StatusCode::CONFLICT, ProblemType::GenreInUse, ..))
+            ),
+            "expected a genre in use conflict, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn create_contract_handler_reports_every_invalid_field() {
+        let state = State(app_state(MockAuthorRepository::new()));
+        let body = serde_json::from_value(serde_json::json!({
+            "publisher_id": 1,
+            "starts_on": "1990-01-01",
+            "ends_on": "1989-12-31",
+            "royalty_percent": "12.125",
+        }))
+        .unwrap();
+
+        let actual = create_contract(AuthorId::new(1), state, StrictJson(body)).await;
+        let Err(HttpError(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::InvalidRequest,
+            _,
+            fields,
+            _,
+        )) = actual
+        else {
+            panic!("expected invalid fields, but got {actual:?}");
+        };
+        assert_eq!(
+            vec!["ends_on", "royalty_percent"],
+            fields.keys().copied().collect::<Vec<_>>()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn find_author_response_serializes_every_field(author in author()) {
+            let json = serde_json::to_value(FindAuthorHttpResponse::from(author.clone())).unwrap();
+            let id: AuthorId = serde_json::from_value(json["id"].clone()).unwrap();
+            prop_assert_eq!(author.id(), id);
+            prop_assert_eq!(author.name().to_string(), json["name"].as_str().unwrap());
+            prop_assert_eq!(author.email().to_string(), json["email"].as_str().unwrap());
+            prop_assert_eq!(author.status().as_str(), json["status"].as_str().unwrap());
+            let created_at: DateTime<Utc> =
+                serde_json::from_value(json["created_at"].clone()).unwrap();
+            prop_assert_eq!(author.created_at(), created_at);
+            let updated_at: DateTime<Utc> =
+                serde_json::from_value(json["updated_at"].clone()).unwrap();
+            prop_assert_eq!(author.updated_at(), updated_at);
+        }
+
+        #[test]
+        fn create_author_request_parses_into_value_objects(
+            name in raw_name(),
+            email in valid_address(),
+        ) {
+            let json = serde_json::json!({ "name": name, "email": email });
+            let body: CreateAuthorHttpRequest = serde_json::from_value(json).unwrap();
+            let req = CreateAuthorRequest::try_from(body).unwrap();
+            prop_assert_eq!(&AuthorName::new(&name).unwrap(), req.name());
+            prop_assert_eq!(&EmailAddress::new(&email).unwrap(), req.email());
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::inbound::http::handlers::{FieldErrors, describe_fields};
+use crate::inbound::http::problem::ProblemType;
+use axum::extract::{Request, State};
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+tokio::task_local! {
+    static CURRENT: Locale;
+}
+
+const BUNDLES: &[(&str, &str)] = &[
+    ("en", include_str!("../../../locales/en.toml")),
+    ("de", include_str!("../../../locales/de.toml")),
+];
+
+#[derive(Debug, Default, Deserialize)]
+struct Bundle {
+    #[serde(default)]
+    messages: HashMap<String, String>,
+    #[serde(default)]
+    fields: HashMap<String, String>,
+    #[serde(default)]
+    titles: HashMap<String, String>,
+}
+
+static CATALOG: LazyLock<HashMap<&'static str, Bundle>> = LazyLock::new(|| {
+    BUNDLES
+        .iter()
+        .map(|(tag, source)| {
+            let bundle = toml::from_str(source)
+                .unwrap_or_else(|err| panic!("message bundle {tag} is invalid: {err}"));
+            (*tag, bundle)
+        })
+        .collect()
+});
+
+/// A language with a bundled message catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale(&'static str);
+
+impl Locale {
+    pub const ENGLISH: Self = Self("en");
+
+    pub fn current() -> Self {
+        CURRENT.try_with(|locale| *locale).unwrap_or(Self::ENGLISH)
+    }
+
+    fn bundle(self) -> Option<&'static Bundle> {
+        CATALOG.get(self.0)
+    }
+
+    /// Picks the supported language the client prefers most, matching on the primary subtag.
+    fn negotiate(accept_language: &str, default: Self) -> Self {
+        let mut best: Option<(f32, Self)> = None;
+        for range in accept_language.split(',') {
+            let mut params = range.split(';').map(str::trim);
+            let Some(tag) = params.next().filter(|tag| !tag.is_empty()) else {
+                continue;
+            };
+            let q = params
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            let locale = if tag == "*" {
+                Some(default)
+            } else {
+                let primary = tag.split('-').next().unwrap_or(tag);
+                primary.parse().ok()
+            };
+            if let Some(locale) = locale
+                && q > 0.0
+                && best.is_none_or(|(best_q, _)| q > best_q)
+            {
+                best = Some((q, locale));
+            }
+        }
+        best.map_or(default, |(_, locale)| locale)
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("no message catalog for locale \"{0}\"")]
+pub struct UnsupportedLocaleError(String);
+
+impl FromStr for Locale {
+    type Err = UnsupportedLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BUNDLES
+            .iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(s))
+            .map(|(tag, _)| Self(tag))
+            .ok_or_else(|| UnsupportedLocaleError(s.to_string()))
+    }
+}
+
+/// A user-facing message, either looked up in the catalog or passed through as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Catalog {
+        key: &'static str,
+        args: Vec<(&'static str, String)>,
+    },
+    /// Summarises validation failures as "field message, ...".
+    Fields(FieldErrors),
+}
+
+impl Message {
+    pub const fn new(key: &'static str) -> Self {
+        Self::Catalog {
+            key,
+            args: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn arg(mut self, name: &'static str, value: impl Display) -> Self {
+        if let Self::Catalog { args, .. } = &mut self {
+            args.push((name, value.to_string()));
+        }
+        self
+    }
+
+    pub fn render(&self, locale: Locale) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Fields(fields) => describe_fields(&translate_fields(fields.clone(), locale)),
+            Self::Catalog { key, args } => {
+                let template = [locale, Locale::ENGLISH]
+                    .into_iter()
+                    .filter_map(Locale::bundle)
+                    .find_map(|bundle| bundle.messages.get(*key));
+                let Some(template) = template else {
+                    return (*key).to_string();
+                };
+                args.iter().fold(template.clone(), |text, (name, value)| {
+                    text.replace(&format!("{{{name}}}"), value)
+                })
+            }
+        }
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(Locale::current()))
+    }
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// Field messages are keyed by their English text, since most come from domain error `Display`s.
+pub fn translate_fields(fields: FieldErrors, locale: Locale) -> FieldErrors {
+    let Some(bundle) = locale.bundle() else {
+        return fields;
+    };
+    fields
+        .into_iter()
+        .map(|(field, message)| {
+            let message = bundle.fields.get(&message).cloned().unwrap_or(message);
+            (field, message)
+        })
+        .collect()
+}
+
+pub fn title(problem: ProblemType, locale: Locale) -> Cow<'static, str> {
+    locale
+        .bundle()
+        .and_then(|bundle| bundle.titles.get(problem.slug()))
+        .map_or(Cow::Borrowed(problem.title()), |title| {
+            Cow::Owned(title.clone())
+        })
+}
+
+pub async fn negotiate_locale(
+    State(default): State<Locale>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let accept_language = request
+        .headers()
+        .get_all(header::ACCEPT_LANGUAGE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+    let locale = Locale::negotiate(&accept_language, default);
+    CURRENT.scope(locale, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::i18n::{BUNDLES, CATALOG, Locale, Message, negotiate_locale};
+    use crate::inbound::http::problem::negotiate_error_format;
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{StatusCode, header};
+    use axum::{Router, middleware};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn router() -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        routes(&CacheControlConfig::default())
+            .layer(middleware::from_fn(negotiate_error_format))
+            .layer(middleware::from_fn_with_state(
+                Locale::ENGLISH,
+                negotiate_locale,
+            ))
+            .with_state(AppState::new(service))
+    }
+
+    async fn send(request: Request) -> (StatusCode, Value) {
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[test]
+    fn locale_follows_accept_language_preferences() {
+        let german: Locale = "de".parse().unwrap();
+        let english = Locale::ENGLISH;
+        assert_eq!(
+            german,
+            Locale::negotiate("de-DE,de;q=0.9,en;q=0.8", english)
+        );
+        assert_eq!(
+            english,
+            Locale::negotiate("fr-CH, fr;q=0.9, en;q=0.5", german)
+        );
+        assert_eq!(german, Locale::negotiate("en;q=0.3, de;q=0.7", english));
+        assert_eq!(german, Locale::negotiate("fr, *;q=0.5", german));
+        assert_eq!(german, Locale::negotiate("", german));
+        assert_eq!(english, Locale::negotiate("de;q=0, en;q=0.1", german));
+        assert!("xx".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn every_bundle_translates_only_known_messages() {
+        let english = &CATALOG["en"];
+        for (tag, _) in BUNDLES {
+            for key in CATALOG[tag].messages.keys() {
+                assert!(
+                    english.messages.contains_key(key),
+                    "{tag} has unknown {key}"
+                );
+            }
+        }
+        let message = Message::new("author-not-found").arg("id", 7);
+        assert_eq!(
+            r#"author with id "7" does not exist"#,
+            message.render(Locale::ENGLISH)
+        );
+        assert_eq!(
+            r#"Autor mit der ID "7" existiert nicht"#,
+            message.render("de".parse().unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn error_messages_follow_accept_language_but_codes_do_not() {
+        let request = |language: &str| {
+            Request::get("/api/v1/authors/7")
+                .header(header::ACCEPT_LANGUAGE, language)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let (status, english) = send(request("fr, en;q=0.5")).await;
+        assert_eq!(StatusCode::NOT_FOUND, status);
+        assert_eq!(
+            json!({"code": "author-not-found", "error": r#"author with id "7" does not exist"#, "request_id": null}),
+            english
+        );
+        let (_, german) = send(request("de-AT")).await;
+        assert_eq!("author-not-found", german["code"]);
+        assert_eq!(r#"Autor mit der ID "7" existiert nicht"#, german["error"]);
+
+        let invalid = Request::post("/api/v1/authors")
+            .header(header::ACCEPT_LANGUAGE, "de")
+            .header(header::ACCEPT, "application/problem+json")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":"","email":"nope"}"#))
+            .unwrap();
+        let (status, problem) = send(invalid).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        assert_eq!(
+            "urn:hexarch-example:problem:invalid-request",
+            problem["type"]
+        );
+        assert_eq!("Der Anfragetext ist ungültig", problem["title"]);
+        assert_eq!(
+            "email ist keine gültige E-Mail-Adresse, name darf nicht leer sein",
+            problem["detail"]
+        );
+        assert_eq!("darf nicht leer sein", problem["fields"]["name"]);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::inbound::http::handlers::HttpError;
+use crate::inbound::http::i18n::Message;
+use axum::body::Bytes;
+use axum::extract::rejection::BytesRejection;
+use axum::extract::{FromRequest, Request};
+use axum::http::{HeaderMap, StatusCode, header};
+use serde::de::DeserializeOwned;
+
+const JSON: &str = "application/json";
+
+/// A JSON request body, used in place of [`axum::Json`] by every handler so that bodies are
+/// checked the same way: the content type must be `application/json`, and malformed or
+/// mistyped bodies are rejected with a 422 naming the offending field.
+#[derive(Debug)]
+pub struct StrictJson<T>(pub T);
+
+impl<S: Send + Sync, T: DeserializeOwned> FromRequest<S> for StrictJson<T> {
+    type Rejection = HttpError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match media_type(req.headers()).as_deref() {
+            Some(JSON) => {}
+            Some(media_type) => {
+                return Err(HttpError::unsupported_content_type(format!(
+                    "{media_type} is not supported, the request body must be {JSON}"
+                )));
+            }
+            None => {
+                return Err(HttpError::unsupported_content_type(format!(
+                    "the request must include a Content-Type header of {JSON}"
+                )));
+            }
+        }
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(body_rejection)?;
+        from_slice(&body).map(Self)
+    }
+}
+
+pub fn media_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+}
+
+/// Parses a whole JSON document, reporting the path to the value that failed to deserialize.
+pub fn from_slice<T: DeserializeOwned>(body: &[u8]) -> Result<T, HttpError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let err = err.into_inner();
+        let message = if err.is_data() && path != "." {
+            Message::new("invalid-json-field")
+                .arg("path", path)
+                .arg("reason", err)
+        } else {
+            Message::new("invalid-json").arg("reason", err)
+        };
+        HttpError::invalid_body(message)
+    })?;
+    deserializer
+        .end()
+        .map_err(|err| HttpError::invalid_body(Message::new("invalid-json").arg("reason", err)))?;
+    Ok(value)
+}
+
+pub fn body_rejection(rejection: BytesRejection) -> HttpError {
+    if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        HttpError::payload_too_large(rejection.body_text())
+    } else {
+        HttpError::invalid_request(rejection.body_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::json::StrictJson;
+    use axum::body::Body;
+    use axum::extract::{FromRequest, Request};
+    use axum::http::{StatusCode, header};
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Payload {
+        name: String,
+        #[serde(default)]
+        tags: Vec<u8>,
+    }
+
+    async fn extract(
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Result<Payload, (StatusCode, String)> {
+        let mut request = Request::post("/");
+        if let Some(content_type) = content_type {
+            request = request.header(header::CONTENT_TYPE, content_type);
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        StrictJson::from_request(request, &())
+            .await
+            .map(|json| json.0)
+            .map_err(|err| (err.status(), err.message()))
+    }
+
+    #[tokio::test]
+    async fn accepts_json_with_parameters() {
+        let body = extract(
+            Some("application/json; charset=utf-8"),
+            r#"{"name":"Barry","tags":[1]}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!("Barry", body.name);
+        assert_eq!(vec![1], body.tags);
+    }
+
+    #[tokio::test]
+    async fn rejects_other_content_types() {
+        let (status, _) = extract(Some("text/plain"), r#"{"name":"Barry"}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, status);
+        let (status, _) = extract(None, r#"{"name":"Barry"}"#).await.unwrap_err();
+        assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, status);
+    }
+
+    #[tokio::test]
+    async fn reports_the_path_of_invalid_values() {
+        let (status, message) = extract(
+            Some("application/json"),
+            r#"{"name":"Barry","tags":[1,"x"]}"#,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        assert!(message.starts_with("tags[1]: invalid type"), "{message}");
+
+        let (status, message) = extract(Some("application/json"), r#"{"name":"Barry","nmae":1}"#)
+            .await
+            .unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        assert!(message.contains("unknown field `nmae`"), "{message}");
+
+        let (status, _) = extract(Some("application/json"), r#"{"name":"Barry"} trailing"#)
+            .await
+            .unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::inbound::http::ROUTES;
+use crate::inbound::http::not_found::{is_placeholder, segments};
+use axum::extract::{Request, State};
+use axum::http::Uri;
+use axum::http::uri::PathAndQuery;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Redirect, Response};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// What to do with a request whose path is not in canonical form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NormalizeMode {
+    /// Paths are matched exactly as sent.
+    #[default]
+    Off,
+    /// Answers with a 308 to the canonical path, so clients learn it.
+    Redirect,
+    /// Routes the request as if the canonical path had been sent.
+    Rewrite,
+}
+
+impl FromStr for NormalizeMode {
+    type Err = NormalizeModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "redirect" => Ok(Self::Redirect),
+            "rewrite" => Ok(Self::Rewrite),
+            _ => Err(NormalizeModeError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid path normalization mode, expected one of "off", "redirect" or "rewrite""#)]
+pub struct NormalizeModeError(String);
+
+/// The canonical path has no trailing slash and no empty segments. When `lowercase` is set, the
+/// static segments of a known route are lowercased too; ids, aliases and other parameters are
+/// kept as sent, since they are case-sensitive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PathNormalization {
+    mode: NormalizeMode,
+    lowercase: bool,
+}
+
+impl PathNormalization {
+    #[must_use]
+    pub const fn new(mode: NormalizeMode) -> Self {
+        Self {
+            mode,
+            lowercase: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        !matches!(self.mode, NormalizeMode::Off)
+    }
+
+    /// Returns the canonical form of `path`, or `None` when it already is canonical.
+    fn normalize(&self, path: &str) -> Option<String> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let mut actual: Vec<_> = segments(path).collect();
+        if self.lowercase
+            && let Some(route) = matching_route(&actual)
+        {
+            for (segment, expected) in actual.iter_mut().zip(segments(route)) {
+                if !is_placeholder(expected) {
+                    *segment = expected;
+                }
+            }
+        }
+        let mut normalized = String::with_capacity(path.len());
+        for segment in actual {
+            normalized.push('/');
+            normalized.push_str(segment);
+        }
+        if normalized.is_empty() {
+            normalized.push('/');
+        }
+        (normalized != path).then_some(normalized)
+    }
+}
+
+/// The route `actual` would match if static segments were compared case-insensitively. Like the
+/// router, prefers static segments over parameters when several match.
+fn matching_route(actual: &[&str]) -> Option<&'static str> {
+    ROUTES
+        .iter()
+        .copied()
+        .filter(|route| {
+            let template: Vec<_> = segments(route).collect();
+            template.len() == actual.len()
+                && template.iter().zip(actual).all(|(expected, actual)| {
+                    is_placeholder(expected) || expected.eq_ignore_ascii_case(actual)
+                })
+        })
+        .max_by_key(|route| {
+            segments(route)
+                .filter(|segment| !is_placeholder(segment))
+                .count()
+        })
+}
+
+/// Runs before routing, so it must wrap the whole router rather than be added as a route layer.
+pub async fn normalize_path(
+    State(normalization): State<PathNormalization>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(path) = normalization.normalize(request.uri().path()) else {
+        return next.run(request).await;
+    };
+    let path_and_query = match request.uri().query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path,
+    };
+    if normalization.mode == NormalizeMode::Redirect {
+        return Redirect::permanent(&path_and_query).into_response();
+    }
+    let mut parts = request.uri().clone().into_parts();
+    parts.path_and_query = PathAndQuery::from_str(&path_and_query).ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *request.uri_mut() = uri;
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::normalize::{NormalizeMode, PathNormalization, normalize_path};
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::{StatusCode, Uri, header};
+    use axum::middleware;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    fn app(normalization: PathNormalization) -> Router {
+        let routes = Router::new().route(
+            "/api/v1/authors",
+            get(|uri: Uri| async move { uri.to_string() }),
+        );
+        Router::new()
+            .fallback_service(routes)
+            .layer(middleware::from_fn_with_state(
+                normalization,
+                normalize_path,
+            ))
+    }
+
+    async fn get_path(app: Router, path: &str) -> (StatusCode, String) {
+        let response = app
+            .oneshot(Request::get(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let location = response
+            .headers()
+            .get(header::LOCATION)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            location.unwrap_or_else(|| String::from_utf8(body.to_vec()).unwrap()),
+        )
+    }
+
+    #[test]
+    fn canonical_paths_are_left_alone() {
+        let normalization = PathNormalization::new(NormalizeMode::Rewrite);
+        assert_eq!(None, normalization.normalize("/api/v1/authors"));
+        assert_eq!(None, normalization.normalize("/"));
+        assert_eq!(None, normalization.normalize("/authors/7/aliases/JRRT"));
+        assert_eq!(
+            Some("/api/v1/authors".to_string()),
+            normalization.normalize("/api//v1/authors//")
+        );
+        assert_eq!(Some("/".to_string()), normalization.normalize("//"));
+        assert_eq!(None, PathNormalization::default().normalize("/api/"));
+    }
+
+    #[test]
+    fn only_static_segments_are_lowercased() {
+        let normalization = PathNormalization::new(NormalizeMode::Rewrite).with_lowercase(true);
+        assert_eq!(
+            Some("/api/v1/authors".to_string()),
+            normalization.normalize("/API/v1/Authors/")
+        );
+        assert_eq!(
+            Some("/api/v1/authors/0198F3A2/aliases/JRRT".to_string()),
+            normalization.normalize("/Api/V1/Authors/0198F3A2/Aliases/JRRT")
+        );
+        assert_eq!(
+            None,
+            normalization.normalize("/api/v1/authors/7/aliases/JRRT")
+        );
+        assert_eq!(None, normalization.normalize("/Not/A/Route"));
+    }
+
+    #[tokio::test]
+    async fn extra_slashes_are_rewritten_or_redirected() {
+        let rewrite = PathNormalization::new(NormalizeMode::Rewrite);
+        assert_eq!(
+            (StatusCode::OK, "/api/v1/authors?limit=2".to_string()),
+            get_path(app(rewrite), "/api/v1/authors/?limit=2").await
+        );
+
+        let redirect = PathNormalization::new(NormalizeMode::Redirect);
+        assert_eq!(
+            (
+                StatusCode::PERMANENT_REDIRECT,
+                "/api/v1/authors?limit=2".to_string()
+            ),
+            get_path(app(redirect), "//api/v1/authors/?limit=2").await
+        );
+
+        let lowercase = PathNormalization::new(NormalizeMode::Redirect).with_lowercase(true);
+        assert_eq!(
+            (
+                StatusCode::PERMANENT_REDIRECT,
+                "/api/v1/authors?limit=2".to_string()
+            ),
+            get_path(app(lowercase), "/Api/V1/Authors/?limit=2").await
+        );
+
+        let off = PathNormalization::default();
+        assert_eq!(
+            StatusCode::NOT_FOUND,
+            get_path(app(off), "/api/v1/authors/").await.0
+        );
+    }
+}
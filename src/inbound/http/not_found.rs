@@ -0,0 +1,110 @@
+use crate::inbound::http::ROUTES;
+use crate::inbound::http::handlers::HttpError;
+use axum::http::Uri;
+
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+pub async fn route_not_found(uri: Uri) -> HttpError {
+    let path = uri.path();
+    metrics::counter!("http_unmatched_requests_total").increment(1);
+    tracing::debug!(path, "No route matched request");
+
+    let message = match suggest(path) {
+        Some(suggestion) => format!("no route matches {path}, did you mean {suggestion}?"),
+        None => format!("no route matches {path}"),
+    };
+    HttpError::route_not_found(message)
+}
+
+fn suggest(path: &str) -> Option<String> {
+    let actual: Vec<_> = segments(path).collect();
+    ROUTES
+        .iter()
+        .map(|route| {
+            let template: Vec<_> = segments(route).collect();
+            (distance(&actual, &template), render(&actual, &template))
+        })
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, suggestion)| suggestion)
+}
+
+pub(crate) fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+pub(crate) fn is_placeholder(segment: &str) -> bool {
+    segment.starts_with('{') && segment.ends_with('}')
+}
+
+fn distance(segments: &[&str], template: &[&str]) -> usize {
+    let aligned: usize = segments
+        .iter()
+        .zip(template)
+        .filter(|(_, expected)| !is_placeholder(expected))
+        .map(|(actual, expected)| levenshtein(actual, expected))
+        .sum();
+    let unaligned: usize = if segments.len() > template.len() {
+        segments[template.len()..].iter().map(|s| s.len()).sum()
+    } else {
+        template[segments.len()..].iter().map(|s| s.len()).sum()
+    };
+    aligned + unaligned
+}
+
+fn render(segments: &[&str], template: &[&str]) -> String {
+    template
+        .iter()
+        .enumerate()
+        .map(|(i, expected)| match segments.get(i) {
+            Some(actual) if is_placeholder(expected) => format!("/{actual}"),
+            _ => format!("/{expected}"),
+        })
+        .collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::not_found::{levenshtein, suggest};
+
+    #[test]
+    fn levenshtein_counts_single_character_edits() {
+        assert_eq!(0, levenshtein("authors", "authors"));
+        assert_eq!(1, levenshtein("author", "authors"));
+        assert_eq!(2, levenshtein("atuhors", "authors"));
+        assert_eq!(7, levenshtein("", "authors"));
+    }
+
+    #[test]
+    fn near_miss_routes_are_suggested() {
+        assert_eq!(
+            Some("/api/v1/authors".to_string()),
+            suggest("/api/v1/author")
+        );
+        assert_eq!(
+            Some("/api/v1/authors/42/audit".to_string()),
+            suggest("/api/v1/authors/42/audits")
+        );
+        assert_eq!(
+            Some("/api/v1/authors/42/avatar".to_string()),
+            suggest("/api/v1/author/42/avatar")
+        );
+        assert_eq!(None, suggest("/api/v1/books"));
+        assert_eq!(None, suggest("/completely/unrelated/path"));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::inbound::http::handlers::{FieldErrors, HttpError, UpdateAuthorHttpRequest};
+use crate::inbound::http::json::{body_rejection, from_slice, media_type};
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Request};
+use axum::http::{HeaderName, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+pub const ACCEPT_PATCH: HeaderName = HeaderName::from_static("accept-patch");
+pub const PATCH_FORMATS: &str = "application/json-patch+json, application/merge-patch+json";
+
+const JSON: &str = "application/json";
+const MERGE_PATCH_JSON: &str = "application/merge-patch+json";
+const JSON_PATCH_JSON: &str = "application/json-patch+json";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum PatchField<T> {
+    #[default]
+    Absent,
+    Null,
+    Value(T),
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for PatchField<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<T>::deserialize(deserializer)?.map_or(Self::Null, Self::Value))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum PatchOperation {
+    Add { path: String, value: Value },
+    Replace { path: String, value: Value },
+    Remove { path: String },
+    Test,
+    Move,
+    Copy,
+}
+
+pub struct AuthorPatch(pub UpdateAuthorHttpRequest);
+
+impl<S: Send + Sync> FromRequest<S> for AuthorPatch {
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let media_type = media_type(req.headers());
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|rejection| body_rejection(rejection).into_response())?;
+
+        let patch = match media_type.as_deref() {
+            Some(JSON | MERGE_PATCH_JSON) => from_slice(&body),
+            Some(JSON_PATCH_JSON) => from_slice(&body).and_then(apply_operations),
+            _ => return Err(unsupported_media_type(media_type.as_deref())),
+        };
+        patch.map(Self).map_err(IntoResponse::into_response)
+    }
+}
+
+fn apply_operations(operations: Vec<PatchOperation>) -> Result<UpdateAuthorHttpRequest, HttpError> {
+    let mut patch = UpdateAuthorHttpRequest::default();
+    let mut fields = FieldErrors::new();
+    for operation in operations {
+        let (path, value) = match operation {
+            PatchOperation::Add { path, value } | PatchOperation::Replace { path, value } => {
+                (path, Some(value))
+            }
+            PatchOperation::Remove { path } => (path, None),
+            PatchOperation::Test | PatchOperation::Move | PatchOperation::Copy => {
+                return Err(HttpError::invalid_request(
+                    "only add, replace and remove operations are supported".into(),
+                ));
+            }
+        };
+        let (field, target) = match path.as_str() {
+            "/name" => ("name", &mut patch.name),
+            "/email" => ("email", &mut patch.email),
+            "/bio" => ("bio", &mut patch.bio),
+            "/birth_date" => ("birth_date", &mut patch.birth_date),
+            "/website_url" => ("website_url", &mut patch.website_url),
+            "/country" => ("country", &mut patch.country),
+            _ => {
+                return Err(HttpError::invalid_request(format!(
+                    "path {path} does not refer to a patchable field"
+                )));
+            }
+        };
+        *target = match value {
+            Some(Value::String(value)) => PatchField::Value(value),
+            Some(Value::Null) | None => PatchField::Null,
+            Some(_) => {
+                fields.insert(field, "must be a string".to_string());
+                continue;
+            }
+        };
+    }
+
+    if fields.is_empty() {
+        Ok(patch)
+    } else {
+        Err(HttpError::invalid_fields(fields))
+    }
+}
+
+fn unsupported_media_type(media_type: Option<&str>) -> Response {
+    let message = match media_type {
+        Some(media_type) => format!("{media_type} is not a supported patch format"),
+        None => "the request must include a Content-Type header".to_string(),
+    };
+    (
+        [(ACCEPT_PATCH, HeaderValue::from_static(PATCH_FORMATS))],
+        HttpError::unsupported_patch_format(message),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::handlers::UpdateAuthorHttpRequest;
+    use crate::inbound::http::patch::{AuthorPatch, PatchField};
+    use axum::body::Body;
+    use axum::extract::{FromRequest, Request};
+    use axum::http::{StatusCode, header};
+
+    async fn extract(
+        content_type: &str,
+        body: &str,
+    ) -> Result<UpdateAuthorHttpRequest, StatusCode> {
+        let request = Request::patch("/api/v1/authors/1")
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        AuthorPatch::from_request(request, &())
+            .await
+            .map(|patch| patch.0)
+            .map_err(|response| response.status())
+    }
+
+    #[tokio::test]
+    async fn merge_patch_distinguishes_absent_from_null() {
+        let patch = extract(
+            "application/merge-patch+json",
+            r#"{"name":"Barry Allen","email":null}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(PatchField::Value("Barry Allen".to_string()), patch.name);
+        assert_eq!(PatchField::Null, patch.email);
+
+        let patch = extract("application/merge-patch+json", "{}").await.unwrap();
+        assert_eq!(PatchField::Absent, patch.name);
+        assert_eq!(PatchField::Absent, patch.email);
+    }
+
+    #[tokio::test]
+    async fn json_patch_operations_are_applied_in_order() {
+        let body = r#"[
+            {"op":"add","path":"/name","value":"Barry"},
+            {"op":"replace","path":"/name","value":"Barry Allen"},
+            {"op":"remove","path":"/email"}
+        ]"#;
+        let patch = extract("application/json-patch+json", body).await.unwrap();
+        assert_eq!(PatchField::Value("Barry Allen".to_string()), patch.name);
+        assert_eq!(PatchField::Null, patch.email);
+
+        let body = r#"[{"op":"replace","path":"/id","value":2}]"#;
+        let err = extract("application/json-patch+json", body)
+            .await
+            .unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, err);
+
+        let body = r#"[{"op":"test","path":"/name","value":"Barry"}]"#;
+        let err = extract("application/json-patch+json", body)
+            .await
+            .unwrap_err();
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, err);
+    }
+
+    #[tokio::test]
+    async fn unsupported_patch_formats_are_rejected() {
+        let err = extract("text/plain", "name=Barry").await.unwrap_err();
+        assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, err);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::inbound::http::handlers::FieldErrors;
+use crate::inbound::http::i18n::{Locale, title};
+use axum::Json;
+use axum::extract::Request;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+tokio::task_local! {
+    static CURRENT: ErrorFormat;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemType {
+    InvalidId,
+    InvalidRequest,
+    AuthorNotFound,
+    DuplicateAuthor,
+    DuplicateEmail,
+    DuplicateAuthorId,
+    DuplicateAlias,
+    NothingToUpdate,
+    PreconditionFailed,
+    AuthorArchived,
+    InvalidStatusTransition,
+    MethodNotAllowed,
+    RouteNotFound,
+    AvatarNotFound,
+    AvatarTooLarge,
+    AliasNotFound,
+    GenreNotFound,
+    DuplicateGenre,
+    GenreInUse,
+    PublisherNotFound,
+    DuplicatePublisher,
+    PublisherHasContracts,
+    ContractNotFound,
+    OverlappingContract,
+    OperationNotFound,
+    UnsupportedMediaType,
+    UnsupportedContentType,
+    UnsupportedPatchFormat,
+    PayloadTooLarge,
+    Unauthorized,
+    Forbidden,
+    ClientBanned,
+    InvalidLogFilter,
+    Unavailable,
+    TimedOut,
+    BookCatalogUnavailable,
+    BookCatalogTimedOut,
+    Internal,
+}
+
+impl ProblemType {
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::InvalidId => "invalid-id",
+            Self::InvalidRequest => "invalid-request",
+            Self::AuthorNotFound => "author-not-found",
+            Self::DuplicateAuthor => "duplicate-author",
+            Self::DuplicateEmail => "duplicate-email",
+            Self::DuplicateAuthorId => "duplicate-author-id",
+            Self::DuplicateAlias => "duplicate-alias",
+            Self::NothingToUpdate => "nothing-to-update",
+            Self::PreconditionFailed => "precondition-failed",
+            Self::AuthorArchived => "author-archived",
+            Self::InvalidStatusTransition => "invalid-status-transition",
+            Self::MethodNotAllowed => "method-not-allowed",
+            Self::RouteNotFound => "route-not-found",
+            Self::AvatarNotFound => "avatar-not-found",
+            Self::AvatarTooLarge => "avatar-too-large",
+            Self::AliasNotFound => "alias-not-found",
+            Self::GenreNotFound => "genre-not-found",
+            Self::DuplicateGenre => "duplicate-genre",
+            Self::GenreInUse => "genre-in-use",
+            Self::PublisherNotFound => "publisher-not-found",
+            Self::DuplicatePublisher => "duplicate-publisher",
+            Self::PublisherHasContracts => "publisher-has-contracts",
+            Self::ContractNotFound => "contract-not-found",
+            Self::OverlappingContract => "overlapping-contract",
+            Self::OperationNotFound => "operation-not-found",
+            Self::UnsupportedMediaType => "unsupported-media-type",
+            Self::UnsupportedContentType => "unsupported-content-type",
+            Self::UnsupportedPatchFormat => "unsupported-patch-format",
+            Self::PayloadTooLarge => "payload-too-large",
+            Self::Unauthorized => "unauthorized",
+            Self::Forbidden => "forbidden",
+            Self::ClientBanned => "client-banned",
+            Self::InvalidLogFilter => "invalid-log-filter",
+            Self::Unavailable => "unavailable",
+            Self::TimedOut => "timed-out",
+            Self::BookCatalogUnavailable => "book-catalog-unavailable",
+            Self::BookCatalogTimedOut => "book-catalog-timed-out",
+            Self::Internal => "internal",
+        }
+    }
+
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::InvalidId => "An id in the request is not a valid id for its resource",
+            Self::InvalidRequest => "The request body failed validation",
+            Self::AuthorNotFound => "No author exists with the given id",
+            Self::DuplicateAuthor => "An author with the same name already exists",
+            Self::DuplicateEmail => "An author with the same email address already exists",
+            Self::DuplicateAuthorId => "An author with the same id already exists",
+            Self::DuplicateAlias => "The alias is already in use by an author",
+            Self::NothingToUpdate => "The update does not change any fields",
+            Self::PreconditionFailed => "The author was modified after the given date",
+            Self::AuthorArchived => "The author is archived and cannot be modified",
+            Self::InvalidStatusTransition => "The author cannot make the requested status change",
+            Self::MethodNotAllowed => "The resource does not support the request method",
+            Self::RouteNotFound => "No resource exists at the requested path",
+            Self::AvatarNotFound => "The author has not uploaded an avatar",
+            Self::AvatarTooLarge => "The avatar image exceeds the maximum upload size",
+            Self::AliasNotFound => "The author does not have the given alias",
+            Self::GenreNotFound => "No such genre exists, or the author does not have it",
+            Self::DuplicateGenre => "A genre with the same name already exists",
+            Self::GenreInUse => "The genre is still assigned to authors",
+            Self::PublisherNotFound => "No publisher exists with the given id",
+            Self::DuplicatePublisher => "A publisher with the same name already exists",
+            Self::PublisherHasContracts => "The publisher still has contracts with authors",
+            Self::ContractNotFound => "The author does not have the given contract",
+            Self::OverlappingContract => {
+                "The author already has a contract with the publisher for part of that term"
+            }
+            Self::OperationNotFound => "No operation exists with the given id",
+            Self::UnsupportedMediaType => "The avatar image is not a supported image format",
+            Self::UnsupportedContentType => "The request body must be sent as application/json",
+            Self::UnsupportedPatchFormat => "The patch document media type is not supported",
+            Self::PayloadTooLarge => "The request body exceeds the maximum size",
+            Self::Unauthorized => "The request lacks valid admin credentials",
+            Self::Forbidden => "The signed-in admin lacks the role this needs",
+            Self::ClientBanned => "The client is temporarily banned after too many failed requests",
+            Self::InvalidLogFilter => "The log filter is not a valid tracing directive",
+            Self::Unavailable => "The author store is temporarily unavailable",
+            Self::TimedOut => "The author store did not respond before the deadline",
+            Self::BookCatalogUnavailable => "The external book catalog is temporarily unavailable",
+            Self::BookCatalogTimedOut => "The external book catalog did not respond in time",
+            Self::Internal => "An unexpected error occurred on the server",
+        }
+    }
+
+    pub fn uri(self) -> String {
+        format!("urn:hexarch-example:problem:{}", self.slug())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: Cow<'static, str>,
+    status: u16,
+    detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: FieldErrors,
+}
+
+impl ProblemDetails {
+    pub fn new(
+        problem: ProblemType,
+        status: StatusCode,
+        detail: String,
+        instance: Option<String>,
+        request_id: Option<String>,
+    ) -> Self {
+        Self {
+            problem_type: problem.uri(),
+            title: title(problem, Locale::current()),
+            status: status.as_u16(),
+            detail,
+            instance,
+            request_id,
+            fields: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_fields(mut self, fields: FieldErrors) -> Self {
+        self.fields = fields;
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, [(header::CONTENT_TYPE, PROBLEM_JSON)], Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Legacy,
+    Problem { instance: Option<String> },
+}
+
+impl ErrorFormat {
+    pub fn current() -> Self {
+        CURRENT.try_with(Clone::clone).unwrap_or(Self::Legacy)
+    }
+
+    fn negotiate(headers: &HeaderMap, instance: String) -> Self {
+        let accept = headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect::<Vec<_>>()
+            .join(",");
+        let problem = quality(&accept, PROBLEM_JSON);
+        if problem > 0.0 && problem >= quality(&accept, "application/json") {
+            Self::Problem {
+                instance: Some(instance),
+            }
+        } else {
+            Self::Legacy
+        }
+    }
+}
+
+pub(super) fn quality(accept: &str, media_type: &str) -> f32 {
+    accept
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';').map(str::trim);
+            let name = params.next()?;
+            if !name.eq_ignore_ascii_case(media_type) {
+                return None;
+            }
+            let q = params
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            Some(q)
+        })
+        .fold(0.0, f32::max)
+}
+
+pub async fn negotiate_error_format(request: Request, next: Next) -> Response {
+    let format = ErrorFormat::negotiate(request.headers(), request.uri().path().to_string());
+    CURRENT.scope(format, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::problem::{ErrorFormat, PROBLEM_JSON};
+    use axum::http::{HeaderMap, HeaderValue, header};
+
+    fn negotiate(accept: &'static str) -> ErrorFormat {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static(accept));
+        ErrorFormat::negotiate(&headers, "/api/v1/authors/1".into())
+    }
+
+    #[test]
+    fn problem_json_is_negotiated_from_accept_header() {
+        let problem = ErrorFormat::Problem {
+            instance: Some("/api/v1/authors/1".into()),
+        };
+        assert_eq!(problem, negotiate(PROBLEM_JSON));
+        assert_eq!(
+            problem,
+            negotiate("application/problem+json, application/json;q=0.9")
+        );
+        assert_eq!(
+            ErrorFormat::Legacy,
+            negotiate("application/problem+json;q=0.5, application/json")
+        );
+        assert_eq!(ErrorFormat::Legacy, negotiate("application/json"));
+        assert_eq!(ErrorFormat::Legacy, negotiate("*/*"));
+        assert_eq!(
+            ErrorFormat::Legacy,
+            negotiate("application/problem+json;q=0")
+        );
+        assert_eq!(
+            ErrorFormat::Legacy,
+            ErrorFormat::negotiate(&HeaderMap::new(), String::new())
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use rand::Rng;
+use std::fmt;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+const TRACEPARENT_HEADER: &str = "traceparent";
+
+const MAX_LEN: usize = 128;
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    fn generate() -> Self {
+        let bytes: [u8; 16] = rand::thread_rng().r#gen();
+        Self(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    fn parse(value: &HeaderValue) -> Option<Self> {
+        let value = value.to_str().ok()?.trim();
+        let valid = !value.is_empty()
+            && value.len() <= MAX_LEN
+            && value.bytes().all(|byte| byte.is_ascii_graphic());
+        valid.then(|| Self(value.to_string()))
+    }
+
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+pub fn trace_id(headers: &HeaderMap) -> Option<String> {
+    let traceparent = headers.get(TRACEPARENT_HEADER)?.to_str().ok()?;
+    let mut parts = traceparent.trim().split('-');
+    let (_version, trace_id) = (parts.next()?, parts.next()?);
+    let valid = trace_id.len() == 32
+        && trace_id.bytes().all(|byte| byte.is_ascii_hexdigit())
+        && trace_id.bytes().any(|byte| byte != b'0');
+    valid.then(|| trace_id.to_ascii_lowercase())
+}
+
+pub async fn propagate_request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(RequestId::parse)
+        .unwrap_or_else(RequestId::generate);
+    request.extensions_mut().insert(id.clone());
+
+    let mut response = CURRENT.scope(id.clone(), next.run(request)).await;
+    if let Ok(value) = HeaderValue::from_str(id.as_str()) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::request_id::{RequestId, trace_id};
+    use axum::http::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn incoming_request_ids_are_validated() {
+        let valid = HeaderValue::from_static("req-123");
+        assert_eq!(
+            Some("req-123"),
+            RequestId::parse(&valid).as_ref().map(RequestId::as_str)
+        );
+
+        let blank = HeaderValue::from_static("  ");
+        assert_eq!(None, RequestId::parse(&blank));
+
+        let spaced = HeaderValue::from_static("two words");
+        assert_eq!(None, RequestId::parse(&spaced));
+
+        let long = HeaderValue::from_str(&"a".repeat(129)).unwrap();
+        assert_eq!(None, RequestId::parse(&long));
+    }
+
+    #[test]
+    fn generated_request_ids_are_unique() {
+        let first = RequestId::generate();
+        let second = RequestId::generate();
+        assert_eq!(32, first.as_str().len());
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn trace_ids_are_read_from_traceparent() {
+        let traceparent = |value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("traceparent", HeaderValue::from_static(value));
+            trace_id(&headers)
+        };
+        assert_eq!(
+            Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string()),
+            traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+        );
+        assert_eq!(
+            None,
+            traceparent("00-00000000000000000000000000000000-00f067aa0ba902b7-01")
+        );
+        assert_eq!(None, traceparent("00-not-a-trace"));
+        assert_eq!(None, trace_id(&HeaderMap::new()));
+    }
+}
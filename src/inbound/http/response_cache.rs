@@ -0,0 +1,325 @@
+use crate::domain::model::AuthorEvent;
+use crate::inbound::http::flags::calling_client;
+use axum::body::{Body, Bytes, HttpBody, to_bytes};
+use axum::extract::{OriginalUri, Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+
+pub const X_CACHE: HeaderName = HeaderName::from_static("x-cache");
+
+/// Bodies larger than this are served but not kept.
+const MAX_CACHED_BODY_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug)]
+struct CachedResponse {
+    stored_at: Instant,
+    /// The request headers the response's `Vary` names, as the request that filled it sent them.
+    vary: Vec<(HeaderName, Option<HeaderValue>)>,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl CachedResponse {
+    fn matches(&self, request: &HeaderMap) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value)| request.get(name) == value.as_ref())
+    }
+}
+
+/// The request headers `headers` says the response varies on, or `None` for `Vary: *`.
+fn varied_headers(
+    headers: &HeaderMap,
+    request: &HeaderMap,
+) -> Option<Vec<(HeaderName, Option<HeaderValue>)>> {
+    let mut vary = Vec::new();
+    let names = headers
+        .get_all(header::VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+    for name in names {
+        let name = HeaderName::try_from(name).ok()?;
+        vary.push((name.clone(), request.get(&name).cloned()));
+    }
+    Some(vary)
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Every variant stored for a key, one per set of values of the headers it varies on.
+    responses: HashMap<String, Vec<CachedResponse>>,
+    /// Bumped on every invalidation, so a response computed before one is not stored after it.
+    generation: u64,
+}
+
+/// Keeps successful `GET` responses in memory for a while, keyed by the calling client, path and
+/// query, and told apart by the request headers their `Vary` names. Any mutation that succeeds,
+/// through this server or announced on the author event stream, empties it.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    enabled: bool,
+    ttl: Duration,
+    max_entries: usize,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30)).with_enabled(false)
+    }
+}
+
+impl ResponseCache {
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            enabled: true,
+            ttl,
+            max_entries: 1_000,
+            entries: Arc::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Expired entries are dropped once the cache is full; if that frees nothing, all are.
+    #[must_use]
+    pub const fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn invalidate(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.responses.clear();
+        entries.generation += 1;
+    }
+
+    /// Empties the cache on every author event until the stream closes. Missed events empty it
+    /// as well, since what they changed is unknown.
+    pub async fn invalidate_on(self, mut events: broadcast::Receiver<AuthorEvent>) {
+        loop {
+            match events.recv().await {
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => self.invalidate(),
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
+    fn lookup(&self, key: &str, request: &HeaderMap) -> Result<Response, u64> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let generation = entries.generation;
+        let Some(variants) = entries.responses.get_mut(key) else {
+            return Err(generation);
+        };
+        let ttl = self.ttl;
+        variants.retain(|cached| cached.stored_at.elapsed() < ttl);
+        let Some(cached) = variants.iter().find(|cached| cached.matches(request)) else {
+            if variants.is_empty() {
+                entries.responses.remove(key);
+            }
+            return Err(generation);
+        };
+        let mut response = Response::new(Body::from(cached.body.clone()));
+        *response.headers_mut() = cached.headers.clone();
+        Ok(response)
+    }
+
+    fn store(&self, key: String, generation: u64, cached: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        if entries.generation != generation {
+            return;
+        }
+        let len = |entries: &Entries| entries.responses.values().map(Vec::len).sum::<usize>();
+        if len(&entries) >= self.max_entries {
+            let ttl = self.ttl;
+            entries.responses.retain(|_, variants| {
+                variants.retain(|cached| cached.stored_at.elapsed() < ttl);
+                !variants.is_empty()
+            });
+            if len(&entries) >= self.max_entries {
+                entries.responses.clear();
+            }
+        }
+        let variants = entries.responses.entry(key).or_default();
+        variants.retain(|variant| variant.vary != cached.vary);
+        variants.push(cached);
+    }
+}
+
+/// Answers `GET` requests from the cache, and fills it with `200 OK` responses whose length is
+/// known up front, unless they vary on `*`. `X-Cache` tells which one happened.
+pub async fn serve_cached(
+    State(cache): State<ResponseCache>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !cache.enabled || request.method() != Method::GET {
+        return next.run(request).await;
+    }
+    // Nested routers see their path without the prefix, which v1 and v2 share.
+    let uri = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map_or_else(|| request.uri().clone(), |original| original.0.clone());
+    let path = uri
+        .path_and_query()
+        .map_or_else(|| uri.path().to_string(), ToString::to_string);
+    let key = format!("{}\n{path}", calling_client(&request).unwrap_or_default());
+    let request_headers = request.headers().clone();
+    let generation = match cache.lookup(&key, &request_headers) {
+        Ok(mut response) => {
+            metrics::counter!("http_response_cache_total", "result" => "hit").increment(1);
+            response
+                .headers_mut()
+                .insert(X_CACHE, HeaderValue::from_static("hit"));
+            return response;
+        }
+        Err(generation) => generation,
+    };
+    metrics::counter!("http_response_cache_total", "result" => "miss").increment(1);
+    let response = next.run(request).await;
+    let cacheable = response.status() == StatusCode::OK
+        && response.body().size_hint().exact().is_some_and(|size| {
+            usize::try_from(size).is_ok_and(|size| size <= MAX_CACHED_BODY_BYTES)
+        });
+    if !cacheable {
+        return response;
+    }
+    let Some(vary) = varied_headers(response.headers(), &request_headers) else {
+        return response;
+    };
+    let (mut parts, body) = response.into_parts();
+    let Ok(body) = to_bytes(body, MAX_CACHED_BODY_BYTES).await else {
+        return Response::from_parts(parts, Body::empty());
+    };
+    let cached = CachedResponse {
+        stored_at: Instant::now(),
+        vary,
+        headers: parts.headers.clone(),
+        body: body.clone(),
+    };
+    cache.store(key, generation, cached);
+    parts
+        .headers
+        .insert(X_CACHE, HeaderValue::from_static("miss"));
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Empties the cache after any request that may have changed something succeeds.
+pub async fn invalidate_on_mutation(
+    State(cache): State<ResponseCache>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let safe = matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    );
+    let response = next.run(request).await;
+    if cache.enabled && !safe && response.status().is_success() {
+        cache.invalidate();
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuthorEvent, AuthorId};
+    use crate::inbound::http::response_cache::{
+        ResponseCache, invalidate_on_mutation, serve_cached,
+    };
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::routing::get;
+    use axum::{Router, middleware};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio::sync::broadcast;
+    use tower::ServiceExt;
+
+    async fn send(router: &Router, method: &str, uri: &str) -> (String, String) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let cache = response
+            .headers()
+            .get("x-cache")
+            .map_or("none", |value| value.to_str().unwrap())
+            .to_string();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (cache, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn responses_are_reused_until_something_changes() {
+        let cache = ResponseCache::new(Duration::from_secs(60));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let router = Router::new()
+            .route(
+                "/authors",
+                get(move || async move { counter.fetch_add(1, Ordering::SeqCst).to_string() })
+                    .layer(middleware::from_fn_with_state(cache.clone(), serve_cached))
+                    .post(|| async { "created" }),
+            )
+            .layer(middleware::from_fn_with_state(
+                cache.clone(),
+                invalidate_on_mutation,
+            ));
+
+        assert_eq!(
+            ("miss".into(), "0".into()),
+            send(&router, "GET", "/authors").await
+        );
+        assert_eq!(
+            ("hit".into(), "0".into()),
+            send(&router, "GET", "/authors").await
+        );
+        let other_page = send(&router, "GET", "/authors?page=2").await;
+        assert_eq!(("miss".into(), "1".into()), other_page);
+
+        send(&router, "POST", "/authors").await;
+        assert_eq!(
+            ("miss".into(), "2".into()),
+            send(&router, "GET", "/authors").await
+        );
+
+        let (sender, receiver) = broadcast::channel(4);
+        let watcher = tokio::spawn(cache.clone().invalidate_on(receiver));
+        let id = AuthorId::new_v7();
+        sender.send(AuthorEvent::Deleted { id }).unwrap();
+        drop(sender);
+        watcher.await.unwrap();
+        assert_eq!(
+            ("miss".into(), "3".into()),
+            send(&router, "GET", "/authors").await
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::runtime::Handle;
+
+/// Counts requests in flight per route and reads runtime and connection pool statistics on
+/// demand, for the admin runtime endpoint. Clones share the same counters.
+#[derive(Debug, Clone)]
+pub struct RuntimeMetrics {
+    started: Instant,
+    routes: Arc<Mutex<BTreeMap<String, RouteCounters>>>,
+    pool: Option<SqlitePool>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RouteCounters {
+    in_flight: u64,
+    peak: u64,
+    completed: u64,
+}
+
+impl RouteCounters {
+    #[must_use]
+    pub const fn in_flight(&self) -> u64 {
+        self.in_flight
+    }
+
+    #[must_use]
+    pub const fn peak(&self) -> u64 {
+        self.peak
+    }
+
+    #[must_use]
+    pub const fn completed(&self) -> u64 {
+        self.completed
+    }
+}
+
+impl Default for RuntimeMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuntimeMetrics {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            routes: Arc::default(),
+            pool: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pool(mut self, pool: SqlitePool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Counts a request to `route` as in flight until the returned guard is dropped.
+    #[must_use]
+    pub fn start_request(&self, route: &str) -> InFlightRequest {
+        let mut routes = self.routes.lock().unwrap_or_else(PoisonError::into_inner);
+        let counters = routes.entry(route.to_string()).or_default();
+        counters.in_flight += 1;
+        counters.peak = counters.peak.max(counters.in_flight);
+        InFlightRequest {
+            routes: Arc::clone(&self.routes),
+            route: route.to_string(),
+        }
+    }
+
+    /// Probing the pool waits for a connection like any query would, bounded by the pool's
+    /// acquire timeout, so the reported wait reflects current contention.
+    pub async fn snapshot(&self) -> RuntimeSnapshot {
+        let routes = self
+            .routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let pool = match &self.pool {
+            Some(pool) => Some(pool_stats(pool).await),
+            None => None,
+        };
+        RuntimeSnapshot {
+            uptime: self.started.elapsed(),
+            runtime: runtime_stats(self.started.elapsed()),
+            pool,
+            routes,
+        }
+    }
+}
+
+/// Decrements the route's in-flight count when dropped, even if the handler panicked.
+#[derive(Debug)]
+pub struct InFlightRequest {
+    routes: Arc<Mutex<BTreeMap<String, RouteCounters>>>,
+    route: String,
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        let mut routes = self.routes.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(counters) = routes.get_mut(&self.route) {
+            counters.in_flight -= 1;
+            counters.completed += 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimeSnapshot {
+    uptime: Duration,
+    runtime: RuntimeStats,
+    pool: Option<PoolStats>,
+    routes: BTreeMap<String, RouteCounters>,
+}
+
+impl RuntimeSnapshot {
+    #[must_use]
+    pub const fn uptime(&self) -> Duration {
+        self.uptime
+    }
+
+    #[must_use]
+    pub const fn runtime(&self) -> &RuntimeStats {
+        &self.runtime
+    }
+
+    #[must_use]
+    pub const fn pool(&self) -> Option<&PoolStats> {
+        self.pool.as_ref()
+    }
+
+    #[must_use]
+    pub const fn routes(&self) -> &BTreeMap<String, RouteCounters> {
+        &self.routes
+    }
+
+    #[must_use]
+    pub fn in_flight(&self) -> u64 {
+        self.routes.values().map(RouteCounters::in_flight).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuntimeStats {
+    alive_tasks: usize,
+    global_queue_depth: usize,
+    workers: Vec<WorkerStats>,
+}
+
+impl RuntimeStats {
+    #[must_use]
+    pub const fn alive_tasks(&self) -> usize {
+        self.alive_tasks
+    }
+
+    #[must_use]
+    pub const fn global_queue_depth(&self) -> usize {
+        self.global_queue_depth
+    }
+
+    #[must_use]
+    pub fn workers(&self) -> &[WorkerStats] {
+        &self.workers
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WorkerStats {
+    busy: Duration,
+    utilization: f64,
+    parks: u64,
+}
+
+impl WorkerStats {
+    #[must_use]
+    pub const fn busy(&self) -> Duration {
+        self.busy
+    }
+
+    /// The share of the uptime this worker spent polling tasks, from 0 to 1.
+    #[must_use]
+    pub const fn utilization(&self) -> f64 {
+        self.utilization
+    }
+
+    #[must_use]
+    pub const fn parks(&self) -> u64 {
+        self.parks
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PoolStats {
+    size: u32,
+    idle: usize,
+    acquire_wait: Option<Duration>,
+}
+
+impl PoolStats {
+    #[must_use]
+    pub const fn size(&self) -> u32 {
+        self.size
+    }
+
+    #[must_use]
+    pub const fn idle(&self) -> usize {
+        self.idle
+    }
+
+    #[must_use]
+    pub fn active(&self) -> usize {
+        usize::try_from(self.size)
+            .unwrap_or(usize::MAX)
+            .saturating_sub(self.idle)
+    }
+
+    /// How long the probe waited for a connection, or `None` if it timed out.
+    #[must_use]
+    pub const fn acquire_wait(&self) -> Option<Duration> {
+        self.acquire_wait
+    }
+}
+
+fn runtime_stats(uptime: Duration) -> RuntimeStats {
+    let metrics = Handle::current().metrics();
+    let workers = (0..metrics.num_workers())
+        .map(|worker| {
+            let busy = metrics.worker_total_busy_duration(worker);
+            WorkerStats {
+                busy,
+                utilization: (busy.as_secs_f64() / uptime.as_secs_f64()).clamp(0.0, 1.0),
+                parks: metrics.worker_park_count(worker),
+            }
+        })
+        .collect();
+    RuntimeStats {
+        alive_tasks: metrics.num_alive_tasks(),
+        global_queue_depth: metrics.global_queue_depth(),
+        workers,
+    }
+}
+
+async fn pool_stats(pool: &SqlitePool) -> PoolStats {
+    let size = pool.size();
+    let idle = pool.num_idle();
+    let started = Instant::now();
+    let acquire_wait = pool.acquire().await.ok().map(|_| started.elapsed());
+    PoolStats {
+        size,
+        idle,
+        acquire_wait,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::runtime_metrics::RuntimeMetrics;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn in_flight_requests_are_counted_per_route() {
+        let metrics = RuntimeMetrics::new();
+        let first = metrics.start_request("/api/v1/authors");
+        let second = metrics.clone().start_request("/api/v1/authors");
+        let other = metrics.start_request("/api/v1/genres");
+        drop(first);
+
+        let snapshot = metrics.snapshot().await;
+        let authors = snapshot.routes()["/api/v1/authors"];
+        assert_eq!(
+            (1, 2, 1),
+            (authors.in_flight(), authors.peak(), authors.completed())
+        );
+        assert_eq!(2, snapshot.in_flight());
+        assert_eq!(2, snapshot.runtime().workers().len());
+        assert!(snapshot.pool().is_none());
+
+        drop((second, other));
+        assert_eq!(0, metrics.snapshot().await.in_flight());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::inbound::http::assets::ASSETS_PREFIX;
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, header};
+use axum::middleware::Next;
+use axum::response::Response;
+
+const DASHBOARD_PREFIX: &str = "/admin";
+const NOSNIFF: HeaderValue = HeaderValue::from_static("nosniff");
+
+/// Headers that depend on what a response is for. An empty value leaves the header out.
+#[derive(Debug, Clone)]
+pub struct SecurityHeaders {
+    content_security_policy: HeaderValue,
+    referrer_policy: HeaderValue,
+    frame_options: HeaderValue,
+}
+
+impl SecurityHeaders {
+    #[must_use]
+    pub const fn new(
+        content_security_policy: HeaderValue,
+        referrer_policy: HeaderValue,
+        frame_options: HeaderValue,
+    ) -> Self {
+        Self {
+            content_security_policy,
+            referrer_policy,
+            frame_options,
+        }
+    }
+
+    /// JSON is never rendered, so nothing may be loaded or framed.
+    #[must_use]
+    pub const fn api() -> Self {
+        Self::new(
+            HeaderValue::from_static("default-src 'none'; frame-ancestors 'none'"),
+            HeaderValue::from_static("no-referrer"),
+            HeaderValue::from_static("DENY"),
+        )
+    }
+
+    /// The admin pages load their stylesheet from this origin and only post forms back to it.
+    #[must_use]
+    pub const fn html() -> Self {
+        Self::new(
+            HeaderValue::from_static(
+                "default-src 'self'; object-src 'none'; base-uri 'none'; form-action 'self'; \
+                 frame-ancestors 'none'",
+            ),
+            HeaderValue::from_static("same-origin"),
+            HeaderValue::from_static("DENY"),
+        )
+    }
+
+    #[must_use]
+    pub const fn content_security_policy(&self) -> &HeaderValue {
+        &self.content_security_policy
+    }
+
+    #[must_use]
+    pub const fn referrer_policy(&self) -> &HeaderValue {
+        &self.referrer_policy
+    }
+
+    #[must_use]
+    pub const fn frame_options(&self) -> &HeaderValue {
+        &self.frame_options
+    }
+}
+
+/// Hardening headers added to every response, with one profile for the JSON API and another
+/// for the admin pages and their assets. Headers a handler set itself are kept.
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersConfig {
+    enabled: bool,
+    strict_transport_security: HeaderValue,
+    api: SecurityHeaders,
+    html: SecurityHeaders,
+}
+
+impl Default for SecurityHeadersConfig {
+    fn default() -> Self {
+        Self::new(
+            HeaderValue::from_static("max-age=31536000; includeSubDomains"),
+            SecurityHeaders::api(),
+            SecurityHeaders::html(),
+        )
+    }
+}
+
+impl SecurityHeadersConfig {
+    /// Browsers ignore `Strict-Transport-Security` over plain HTTP, so it is sent regardless of
+    /// whether TLS ends here or at a proxy in front.
+    #[must_use]
+    pub const fn new(
+        strict_transport_security: HeaderValue,
+        api: SecurityHeaders,
+        html: SecurityHeaders,
+    ) -> Self {
+        Self {
+            enabled: true,
+            strict_transport_security,
+            api,
+            html,
+        }
+    }
+
+    /// For when a proxy in front already sets these headers.
+    #[must_use]
+    pub const fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[must_use]
+    pub const fn api(&self) -> &SecurityHeaders {
+        &self.api
+    }
+
+    #[must_use]
+    pub const fn html(&self) -> &SecurityHeaders {
+        &self.html
+    }
+
+    fn profile(&self, path: &str) -> &SecurityHeaders {
+        let html = [DASHBOARD_PREFIX, ASSETS_PREFIX].iter().any(|prefix| {
+            path.strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        });
+        if html { &self.html } else { &self.api }
+    }
+}
+
+fn insert_missing(headers: &mut HeaderMap, name: HeaderName, value: &HeaderValue) {
+    if !value.is_empty() && !headers.contains_key(&name) {
+        headers.insert(name, value.clone());
+    }
+}
+
+pub async fn apply_security_headers(
+    State(config): State<SecurityHeadersConfig>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !config.enabled {
+        return next.run(request).await;
+    }
+    let profile = config.profile(request.uri().path()).clone();
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    insert_missing(
+        headers,
+        header::STRICT_TRANSPORT_SECURITY,
+        &config.strict_transport_security,
+    );
+    insert_missing(headers, header::X_CONTENT_TYPE_OPTIONS, &NOSNIFF);
+    insert_missing(
+        headers,
+        header::CONTENT_SECURITY_POLICY,
+        &profile.content_security_policy,
+    );
+    insert_missing(headers, header::REFERRER_POLICY, &profile.referrer_policy);
+    insert_missing(headers, header::X_FRAME_OPTIONS, &profile.frame_options);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::security::{
+        SecurityHeaders, SecurityHeadersConfig, apply_security_headers,
+    };
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::Request;
+    use axum::http::{HeaderValue, header};
+    use axum::middleware;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    fn router(config: SecurityHeadersConfig) -> Router {
+        Router::new()
+            .route("/api/v1/authors", get(|| async { "[]" }))
+            .route("/admin", get(|| async { "<html></html>" }))
+            .route(
+                "/admin/framed",
+                get(|| async { ([(header::X_FRAME_OPTIONS, "SAMEORIGIN")], "<html></html>") }),
+            )
+            .layer(middleware::from_fn_with_state(
+                config,
+                apply_security_headers,
+            ))
+    }
+
+    async fn get_headers(router: &Router, uri: &str) -> axum::http::HeaderMap {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        router
+            .clone()
+            .oneshot(request)
+            .await
+            .unwrap()
+            .headers()
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn api_and_admin_responses_get_their_own_profile() {
+        let router = router(SecurityHeadersConfig::default());
+
+        let api = get_headers(&router, "/api/v1/authors").await;
+        assert_eq!("nosniff", api[header::X_CONTENT_TYPE_OPTIONS]);
+        assert_eq!(
+            "max-age=31536000; includeSubDomains",
+            api[header::STRICT_TRANSPORT_SECURITY]
+        );
+        assert_eq!(
+            SecurityHeaders::api().content_security_policy(),
+            api[header::CONTENT_SECURITY_POLICY]
+        );
+        assert_eq!("no-referrer", api[header::REFERRER_POLICY]);
+        assert_eq!("DENY", api[header::X_FRAME_OPTIONS]);
+
+        let admin = get_headers(&router, "/admin").await;
+        assert_eq!(
+            SecurityHeaders::html().content_security_policy(),
+            admin[header::CONTENT_SECURITY_POLICY]
+        );
+        assert_eq!("same-origin", admin[header::REFERRER_POLICY]);
+
+        let framed = get_headers(&router, "/admin/framed").await;
+        assert_eq!("SAMEORIGIN", framed[header::X_FRAME_OPTIONS]);
+    }
+
+    #[tokio::test]
+    async fn empty_values_and_disabled_configs_leave_headers_out() {
+        let config = SecurityHeadersConfig::new(
+            HeaderValue::from_static(""),
+            SecurityHeaders::new(
+                HeaderValue::from_static(""),
+                HeaderValue::from_static("no-referrer"),
+                HeaderValue::from_static("DENY"),
+            ),
+            SecurityHeaders::html(),
+        );
+        let api = get_headers(&router(config.clone()), "/api/v1/authors").await;
+        assert!(!api.contains_key(header::STRICT_TRANSPORT_SECURITY));
+        assert!(!api.contains_key(header::CONTENT_SECURITY_POLICY));
+        assert_eq!("no-referrer", api[header::REFERRER_POLICY]);
+
+        let disabled = router(config.with_enabled(false));
+        let api = get_headers(&disabled, "/api/v1/authors").await;
+        assert!(!api.contains_key(header::X_CONTENT_TYPE_OPTIONS));
+    }
+}
@@ -0,0 +1,1007 @@
+use crate::domain::model::{
+    AdminRole, AuditContext, IdTokenClaims, OidcConfig, SessionId, StoredSession, TokenResponse,
+};
+use crate::domain::ports::{AuthorRepository, DynIdentityProvider, DynSessionStore};
+use crate::inbound::http::AppState;
+use crate::inbound::http::admin::{AdminAuth, tokens_match};
+use crate::inbound::http::handlers::HttpError;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use axum::body::Bytes;
+use axum::extract::{FromRequest, FromRequestParts, OriginalUri, Query, Request, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue, Method, header};
+use axum::response::{AppendHeaders, IntoResponse, Redirect, Response};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, TimeDelta, Utc};
+use maud::{DOCTYPE, Markup, html};
+use rand::RngCore;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+const SESSION_COOKIE: &str = "admin_session";
+const LOGIN_COOKIE: &str = "admin_login";
+const LOGIN_PATH: &str = "/admin/login";
+const DASHBOARD_PATH: &str = "/admin";
+/// How long a login may take between leaving for the provider and coming back.
+const LOGIN_TTL_SECS: u64 = 600;
+const NONCE_LEN: usize = 12;
+/// The form field carrying the session's CSRF token.
+pub const CSRF_FIELD: &str = "_csrf";
+
+/// When browsers send the session cookie along with requests started by other sites.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SameSite {
+    /// Never, so following a link to the admin pages from elsewhere asks to sign in again.
+    Strict,
+    /// Only when navigating to the admin pages, never with a form posted from another site.
+    #[default]
+    Lax,
+    /// Always; CSRF tokens are then the only protection, and browsers require `Secure`.
+    None,
+}
+
+impl SameSite {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Strict => "Strict",
+            Self::Lax => "Lax",
+            Self::None => "None",
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error(r#"expected "strict", "lax" or "none", got "{0}""#)]
+pub struct SameSiteError(String);
+
+impl FromStr for SameSite {
+    type Err = SameSiteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "strict" => Ok(Self::Strict),
+            "lax" => Ok(Self::Lax),
+            "none" => Ok(Self::None),
+            _ => Err(SameSiteError(s.into())),
+        }
+    }
+}
+
+/// Attributes of the admin cookies. `Secure` may only be turned off to try the pages over plain
+/// HTTP locally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionCookieConfig {
+    secure: bool,
+    same_site: SameSite,
+}
+
+impl Default for SessionCookieConfig {
+    fn default() -> Self {
+        Self {
+            secure: true,
+            same_site: SameSite::Lax,
+        }
+    }
+}
+
+impl SessionCookieConfig {
+    #[must_use]
+    pub const fn new(secure: bool, same_site: SameSite) -> Self {
+        Self { secure, same_site }
+    }
+
+    #[must_use]
+    pub const fn secure(&self) -> bool {
+        self.secure
+    }
+
+    #[must_use]
+    pub const fn same_site(&self) -> SameSite {
+        self.same_site
+    }
+
+    fn attributes(self, same_site: SameSite) -> String {
+        let secure = if self.secure { "; Secure" } else { "" };
+        format!(
+            "Path={DASHBOARD_PATH}; HttpOnly{secure}; SameSite={}",
+            same_site.as_str()
+        )
+    }
+
+    /// The login cookie has to come back with the provider's redirect, which a strict cookie
+    /// would not.
+    const fn login_same_site(self) -> SameSite {
+        match self.same_site {
+            SameSite::Strict => SameSite::Lax,
+            same_site => same_site,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Session {
+    subject: String,
+    name: Option<String>,
+    role: AdminRole,
+    csrf_token: String,
+    tokens_expire_at: DateTime<Utc>,
+    refresh_token: Option<String>,
+}
+
+impl Session {
+    fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("sessions serialize to JSON")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingLogin {
+    state: String,
+    nonce: String,
+    verifier: String,
+    return_to: String,
+}
+
+/// Signs admins in to the HTML pages through an OpenID provider and keeps them signed in,
+/// refreshing their tokens when they expire. Sessions live in a [`DynSessionStore`]; the browser
+/// only gets their id, in an encrypted cookie.
+pub struct AdminSessions {
+    provider: Box<dyn DynIdentityProvider>,
+    store: Box<dyn DynSessionStore>,
+    config: OidcConfig,
+    cookies: SessionCookieConfig,
+    ttl: Duration,
+    cipher: Aes256Gcm,
+}
+
+impl AdminSessions {
+    /// Sessions last eight hours unless changed with [`Self::with_ttl`].
+    #[must_use]
+    pub fn new(
+        provider: Box<dyn DynIdentityProvider>,
+        store: Box<dyn DynSessionStore>,
+        config: OidcConfig,
+    ) -> Self {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(config.session_key()));
+        Self {
+            provider,
+            store,
+            config,
+            cookies: SessionCookieConfig::default(),
+            ttl: Duration::from_secs(8 * 60 * 60),
+            cipher,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_cookies(mut self, cookies: SessionCookieConfig) -> Self {
+        self.cookies = cookies;
+        self
+    }
+
+    /// How long a session lasts after signing in, however often its tokens are refreshed.
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The cookie's name is bound to its value, so a login cookie cannot pass as a session.
+    fn seal(&self, name: &str, value: &impl Serialize) -> String {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let plaintext = serde_json::to_vec(value).expect("cookies serialize to JSON");
+        let payload = Payload {
+            msg: &plaintext,
+            aad: name.as_bytes(),
+        };
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, payload)
+            .expect("AES-GCM encrypts any payload that fits in memory");
+        let mut sealed = nonce.to_vec();
+        sealed.extend(ciphertext);
+        URL_SAFE_NO_PAD.encode(sealed)
+    }
+
+    fn open<T: DeserializeOwned>(&self, name: &str, headers: &HeaderMap) -> Option<T> {
+        let sealed = URL_SAFE_NO_PAD.decode(cookie(headers, name)?).ok()?;
+        if sealed.len() <= NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: name.as_bytes(),
+        };
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .ok()?;
+        serde_json::from_slice(&plaintext).ok()
+    }
+
+    fn set_cookie(
+        &self,
+        name: &str,
+        value: &impl Serialize,
+        same_site: SameSite,
+        max_age: Option<u64>,
+    ) -> HeaderValue {
+        let max_age = max_age.map_or(String::new(), |secs| format!("; Max-Age={secs}"));
+        let cookie = format!(
+            "{name}={}; {}{max_age}",
+            self.seal(name, value),
+            self.cookies.attributes(same_site)
+        );
+        HeaderValue::try_from(cookie).expect("sealed cookies are base64url")
+    }
+
+    fn clear_cookie(&self, name: &str) -> HeaderValue {
+        let cookie = format!(
+            "{name}=; {}; Max-Age=0",
+            self.cookies.attributes(self.cookies.same_site)
+        );
+        HeaderValue::try_from(cookie).expect("cookie names are ASCII")
+    }
+
+    fn start(
+        &self,
+        tokens: &TokenResponse,
+        nonce: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Session, HttpError> {
+        let id_token = tokens.id_token().ok_or_else(|| {
+            HttpError::unauthorized("the identity provider returned no ID token".into())
+        })?;
+        let claims = IdTokenClaims::decode(id_token, &self.config, Some(nonce), now)
+            .map_err(|err| HttpError::unauthorized(format!("{err:#}")))?;
+        let subject = claims
+            .subject()
+            .ok_or_else(|| HttpError::unauthorized("the ID token names no subject".into()))?;
+        let name = claims.display_name().map(str::to_string);
+        let role = self.config.role(&claims).ok_or_else(|| {
+            HttpError::forbidden(format!(
+                "{} has no admin role",
+                name.as_deref().unwrap_or(subject)
+            ))
+        })?;
+        Ok(Session {
+            subject: subject.to_string(),
+            name,
+            role,
+            csrf_token: random_token(),
+            tokens_expire_at: tokens_expire_at(tokens, now),
+            refresh_token: tokens.refresh_token().map(str::to_string),
+        })
+    }
+
+    /// Stores the session under a new id and returns the cookie carrying it.
+    async fn save(&self, session: &Session, now: DateTime<Utc>) -> Result<HeaderValue, HttpError> {
+        let id = SessionId::new(random_token());
+        let expires_at = now + TimeDelta::from_std(self.ttl).unwrap_or(TimeDelta::hours(8));
+        let stored = StoredSession::new(id.clone(), session.to_value(), expires_at);
+        self.store
+            .save_session(&stored)
+            .await
+            .map_err(|err| HttpError::internal(&err.0.context("Failed to save the session")))?;
+        Ok(self.set_cookie(SESSION_COOKIE, &id.as_str(), self.cookies.same_site, None))
+    }
+
+    /// A new ID token may change the admin's role, or take it away.
+    fn refreshed(
+        &self,
+        session: Session,
+        tokens: &TokenResponse,
+        now: DateTime<Utc>,
+    ) -> Option<Session> {
+        let role = match tokens.id_token() {
+            Some(id_token) => {
+                let claims = IdTokenClaims::decode(id_token, &self.config, None, now).ok()?;
+                if claims.subject() != Some(session.subject.as_str()) {
+                    return None;
+                }
+                self.config.role(&claims)?
+            }
+            None => session.role,
+        };
+        Some(Session {
+            role,
+            tokens_expire_at: tokens_expire_at(tokens, now),
+            refresh_token: tokens
+                .refresh_token()
+                .map(str::to_string)
+                .or(session.refresh_token),
+            ..session
+        })
+    }
+
+    /// The session whose id is in the request's cookie, refreshed if its tokens expired.
+    /// Sessions that can no longer be refreshed are deleted.
+    async fn resume(
+        &self,
+        headers: &HeaderMap,
+        now: DateTime<Utc>,
+    ) -> Option<(SessionId, Session)> {
+        let id = SessionId::new(self.open(SESSION_COOKIE, headers)?);
+        let stored = match self.store.find_session(&id, now).await {
+            Ok(stored) => stored?,
+            Err(err) => {
+                tracing::warn!("Failed to look up an admin session: {err:?}");
+                return None;
+            }
+        };
+        let session: Session = serde_json::from_value(stored.data().clone()).ok()?;
+        if session.tokens_expire_at > now {
+            return Some((id, session));
+        }
+        let refreshed = match session.refresh_token.as_deref() {
+            Some(refresh_token) => match self.provider.refresh(refresh_token).await {
+                Ok(tokens) => self.refreshed(session, &tokens, now),
+                Err(err) => {
+                    tracing::warn!("Failed to refresh an admin session: {err:?}");
+                    None
+                }
+            },
+            None => None,
+        };
+        let result = match &refreshed {
+            Some(session) => {
+                let stored =
+                    StoredSession::new(id.clone(), session.to_value(), stored.expires_at());
+                self.store.save_session(&stored).await
+            }
+            None => self.store.delete_session(&id).await,
+        };
+        if let Err(err) = result {
+            tracing::warn!("Failed to update an admin session: {err:?}");
+            return None;
+        }
+        refreshed.map(|session| (id, session))
+    }
+}
+
+fn tokens_expire_at(tokens: &TokenResponse, now: DateTime<Utc>) -> DateTime<Utc> {
+    now + TimeDelta::from_std(tokens.expires_in()).unwrap_or(TimeDelta::hours(1))
+}
+
+/// A fresh random value for `state`, `nonce` and PKCE verifiers.
+fn random_token() -> String {
+    let mut bytes = [0; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// The `S256` PKCE challenge for `verifier`.
+fn code_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+fn cookie<'h>(headers: &'h HeaderMap, name: &str) -> Option<&'h str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .find_map(|pair| {
+            let (key, value) = pair.trim().split_once('=')?;
+            (key == name).then_some(value)
+        })
+}
+
+/// Only pages of this app are returned to, so the login cannot be used as an open redirect.
+fn is_dashboard_path(path: &str) -> bool {
+    (path == DASHBOARD_PATH || path.starts_with("/admin/")) && !path.contains("//")
+}
+
+/// Who is using the admin pages: someone signed in through OIDC, or a client holding the admin
+/// token, which may do everything.
+#[derive(Debug, Clone)]
+pub struct AdminSession {
+    id: Option<SessionId>,
+    subject: Option<String>,
+    name: Option<String>,
+    role: AdminRole,
+    csrf_token: Option<String>,
+}
+
+impl AdminSession {
+    const fn token() -> Self {
+        Self {
+            id: None,
+            subject: None,
+            name: None,
+            role: AdminRole::Editor,
+            csrf_token: None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref().or(self.subject.as_deref())
+    }
+
+    #[must_use]
+    pub fn can(&self, role: AdminRole) -> bool {
+        self.role >= role
+    }
+
+    pub fn require(&self, role: AdminRole) -> Result<(), HttpError> {
+        if self.can(role) {
+            Ok(())
+        } else {
+            Err(HttpError::forbidden(format!(
+                "the {} role is required",
+                role.as_str()
+            )))
+        }
+    }
+
+    /// The token forms must post back in [`CSRF_FIELD`]. The admin token is never sent by a
+    /// browser on its own, so requests holding it have none.
+    #[must_use]
+    pub fn csrf_token(&self) -> Option<&str> {
+        self.csrf_token.as_deref()
+    }
+
+    fn verify_csrf(&self, provided: Option<&str>) -> Result<(), HttpError> {
+        match (&self.csrf_token, provided) {
+            (None, _) => Ok(()),
+            (Some(expected), Some(provided)) if tokens_match(provided, expected) => Ok(()),
+            (Some(_), _) => Err(HttpError::forbidden(
+                "the form's CSRF token does not match the session, reload the page and try again"
+                    .into(),
+            )),
+        }
+    }
+
+    /// Records changes under the signed-in subject instead of the `X-Actor` header.
+    #[must_use]
+    pub fn audit_context(&self, ctx: AuditContext) -> AuditContext {
+        match &self.subject {
+            Some(subject) => AuditContext::new(subject.clone(), ctx.request_id().map(Into::into)),
+            None => ctx,
+        }
+    }
+}
+
+impl<R: AuthorRepository> FromRequestParts<AppState<R>> for AdminSession {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState<R>) -> Result<Self, Response> {
+        let rejection = match AdminAuth::from_request_parts(parts, state).await {
+            Ok(AdminAuth) => return Ok(Self::token()),
+            Err(rejection) => rejection,
+        };
+        let Some(sessions) = &state.admin_sessions else {
+            return Err(rejection);
+        };
+        if let Some((id, session)) = sessions.resume(&parts.headers, Utc::now()).await {
+            return Ok(Self {
+                id: Some(id),
+                subject: Some(session.subject),
+                name: session.name,
+                role: session.role,
+                csrf_token: Some(session.csrf_token),
+            });
+        }
+        if parts.method != Method::GET && parts.method != Method::HEAD {
+            let err = HttpError::unauthorized("sign in to the admin pages first".into());
+            return Err(err.into_response());
+        }
+        let path = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map_or(&parts.uri, |uri| &uri.0)
+            .path_and_query()
+            .map_or(DASHBOARD_PATH, |path| path.as_str());
+        let return_to: String = url::form_urlencoded::byte_serialize(path.as_bytes()).collect();
+        Err(Redirect::to(&format!("{LOGIN_PATH}?return_to={return_to}")).into_response())
+    }
+}
+
+/// A form posted from the admin pages, along with who posted it. The form must carry the
+/// session's CSRF token, which is taken out before the remaining fields are decoded.
+#[derive(Debug)]
+pub struct AdminForm<T>(pub AdminSession, pub T);
+
+impl<R: AuthorRepository, T: DeserializeOwned> FromRequest<AppState<R>> for AdminForm<T> {
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &AppState<R>) -> Result<Self, Response> {
+        let (mut parts, body) = req.into_parts();
+        let session = AdminSession::from_request_parts(&mut parts, state).await?;
+        let body = Bytes::from_request(Request::from_parts(parts, body), state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let mut csrf_token = None;
+        let mut fields = url::form_urlencoded::Serializer::new(String::new());
+        for (key, value) in url::form_urlencoded::parse(&body) {
+            if key == CSRF_FIELD {
+                csrf_token = Some(value);
+            } else {
+                fields.append_pair(&key, &value);
+            }
+        }
+        session
+            .verify_csrf(csrf_token.as_deref())
+            .map_err(IntoResponse::into_response)?;
+        let form = serde_urlencoded::from_str(&fields.finish()).map_err(|err| {
+            HttpError::invalid_request(format!("failed to decode the form: {err}")).into_response()
+        })?;
+        Ok(Self(session, form))
+    }
+}
+
+/// A form with nothing in it but the CSRF token.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmptyForm {}
+
+fn admin_sessions<R: AuthorRepository>(
+    state: &AppState<R>,
+) -> Result<&Arc<AdminSessions>, HttpError> {
+    state
+        .admin_sessions
+        .as_ref()
+        .ok_or_else(|| HttpError::route_not_found("signing in with OIDC is disabled".into()))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LoginParams {
+    return_to: Option<String>,
+}
+
+/// Sends the browser to the provider, remembering the PKCE verifier, `state` and `nonce` in a
+/// short-lived cookie until it comes back.
+pub async fn login<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    Query(params): Query<LoginParams>,
+) -> Result<Response, HttpError> {
+    let sessions = admin_sessions(&state)?;
+    let login = PendingLogin {
+        state: random_token(),
+        nonce: random_token(),
+        verifier: random_token(),
+        return_to: params
+            .return_to
+            .filter(|path| is_dashboard_path(path))
+            .unwrap_or_else(|| DASHBOARD_PATH.to_string()),
+    };
+    let url = sessions
+        .provider
+        .authorization_url(&login.state, &login.nonce, &code_challenge(&login.verifier))
+        .await
+        .map_err(|err| HttpError::internal(&err.context("Failed to start the OIDC login")))?;
+    let cookie = sessions.set_cookie(
+        LOGIN_COOKIE,
+        &login,
+        sessions.cookies.login_same_site(),
+        Some(LOGIN_TTL_SECS),
+    );
+    Ok((
+        AppendHeaders([(header::SET_COOKIE, cookie)]),
+        Redirect::to(url.as_str()),
+    )
+        .into_response())
+}
+
+/// Providers add their own parameters, such as Keycloak's `session_state`, so unknown ones are
+/// ignored.
+#[derive(Debug, Deserialize)]
+pub struct CallbackParams {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+pub async fn callback<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    headers: HeaderMap,
+    Query(params): Query<CallbackParams>,
+) -> Result<Response, HttpError> {
+    let sessions = admin_sessions(&state)?;
+    if let Some(error) = params.error {
+        return Err(HttpError::unauthorized(format!(
+            "the identity provider refused the login: {error}"
+        )));
+    }
+    let login: PendingLogin = sessions
+        .open(LOGIN_COOKIE, &headers)
+        .ok_or_else(|| HttpError::unauthorized("the login expired, sign in again".into()))?;
+    if params.state.as_deref() != Some(login.state.as_str()) {
+        return Err(HttpError::unauthorized(
+            "the login does not match this browser, sign in again".into(),
+        ));
+    }
+    let code = params
+        .code
+        .ok_or_else(|| HttpError::invalid_request("the callback has no code".into()))?;
+    let tokens = sessions
+        .provider
+        .exchange_code(&code, &login.verifier)
+        .await
+        .map_err(|err| {
+            HttpError::internal(&err.context("Failed to exchange the authorization code"))
+        })?;
+    let now = Utc::now();
+    let session = sessions.start(&tokens, &login.nonce, now)?;
+    let session_cookie = sessions.save(&session, now).await?;
+    tracing::info!(
+        subject = session.subject,
+        role = session.role.as_str(),
+        "Admin signed in"
+    );
+    let cookies = [
+        (header::SET_COOKIE, sessions.clear_cookie(LOGIN_COOKIE)),
+        (header::SET_COOKIE, session_cookie),
+    ];
+    Ok((AppendHeaders(cookies), Redirect::to(&login.return_to)).into_response())
+}
+
+/// Ends the session here; the provider may still remember the admin and sign them straight back
+/// in.
+pub async fn logout<R: AuthorRepository>(
+    State(state): State<AppState<R>>,
+    AdminForm(session, EmptyForm {}): AdminForm<EmptyForm>,
+) -> Result<Response, HttpError> {
+    let sessions = admin_sessions(&state)?;
+    if let Some(id) = &session.id {
+        sessions
+            .store
+            .delete_session(id)
+            .await
+            .map_err(|err| HttpError::internal(&err.0.context("Failed to delete the session")))?;
+    }
+    let page: Markup = html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                title { "Signed out · Admin" }
+            }
+            body {
+                p { "You are signed out. " a href=(LOGIN_PATH) { "Sign in again" } }
+            }
+        }
+    };
+    let cookie = sessions.clear_cookie(SESSION_COOKIE);
+    Ok((AppendHeaders([(header::SET_COOKIE, cookie)]), page).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{OidcConfig, TokenResponse};
+    use crate::domain::ports::IdentityProvider;
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::session::{
+        AdminSessions, SameSite, SessionCookieConfig, code_challenge,
+    };
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{HeaderMap, Method, StatusCode, header};
+    use axum::response::Response;
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use chrono::{TimeDelta, Utc};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use url::Url;
+
+    const ISSUER: &str = "https://id.example.com";
+    const AUTHOR_FORM: &str = "name=Ada&email=ada%40example.com";
+
+    /// Hands out tokens for `role`, remembering the nonce and challenge of the last login.
+    #[derive(Default)]
+    struct FakeProvider {
+        logins: Mutex<Vec<(String, String)>>,
+        role: Mutex<&'static str>,
+        expires_in: u64,
+        refreshes: AtomicUsize,
+    }
+
+    impl FakeProvider {
+        fn tokens(&self, nonce: Option<&str>) -> TokenResponse {
+            let claims = serde_json::json!({
+                "iss": ISSUER,
+                "aud": "hexarch",
+                "sub": "f3a9",
+                "name": "Ada Lovelace",
+                "exp": (Utc::now() + TimeDelta::minutes(5)).timestamp(),
+                "nonce": nonce,
+                "groups": [*self.role.lock().unwrap()],
+            });
+            let id_token = format!(
+                "e30.{}.",
+                URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims).unwrap())
+            );
+            TokenResponse::new(Some(id_token), Some("r-1".into()), Some(self.expires_in))
+        }
+    }
+
+    impl IdentityProvider for Arc<FakeProvider> {
+        async fn authorization_url(
+            &self,
+            state: &str,
+            nonce: &str,
+            code_challenge: &str,
+        ) -> anyhow::Result<Url> {
+            self.logins
+                .lock()
+                .unwrap()
+                .push((nonce.to_string(), code_challenge.to_string()));
+            Ok(format!("{ISSUER}/authorize?state={state}").parse()?)
+        }
+
+        async fn exchange_code(
+            &self,
+            code: &str,
+            code_verifier: &str,
+        ) -> anyhow::Result<TokenResponse> {
+            let (nonce, challenge) = self.logins.lock().unwrap().last().cloned().unwrap();
+            anyhow::ensure!(code == "c-1", "unknown code");
+            anyhow::ensure!(code_challenge(code_verifier) == challenge, "PKCE mismatch");
+            Ok(self.tokens(Some(&nonce)))
+        }
+
+        async fn refresh(&self, refresh_token: &str) -> anyhow::Result<TokenResponse> {
+            anyhow::ensure!(refresh_token == "r-1", "unknown refresh token");
+            self.refreshes.fetch_add(1, Ordering::SeqCst);
+            Ok(self.tokens(None))
+        }
+    }
+
+    fn router(provider: &Arc<FakeProvider>, cookies: SessionCookieConfig) -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let config = OidcConfig::new(
+            ISSUER.parse().unwrap(),
+            "hexarch".into(),
+            "http://localhost/admin/callback".parse().unwrap(),
+            [9; 32],
+        )
+        .unwrap()
+        .with_roles(
+            "groups".into(),
+            "editors=editor,staff=viewer".parse().unwrap(),
+        );
+        let sessions = AdminSessions::new(Box::new(Arc::clone(provider)), Box::new(repo), config)
+            .with_cookies(cookies);
+        let state = AppState::new(service).with_admin_sessions(sessions);
+        routes(&CacheControlConfig::default()).with_state(state)
+    }
+
+    async fn send(
+        router: &Router,
+        method: Method,
+        uri: &str,
+        cookies: &[String],
+        form: &str,
+    ) -> Response {
+        let mut request = Request::builder().method(method).uri(uri);
+        if !cookies.is_empty() {
+            request = request.header(header::COOKIE, cookies.join("; "));
+        }
+        let request = request
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(form.to_string()))
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    /// The `name=value` pairs the response sets, leaving out cleared cookies.
+    fn cookies(headers: &HeaderMap) -> HashMap<String, String> {
+        headers
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().unwrap().split(';').next())
+            .filter_map(|pair| pair.split_once('='))
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| (name.to_string(), format!("{name}={value}")))
+            .collect()
+    }
+
+    /// The CSRF token the dashboard renders into its forms.
+    async fn csrf_token(router: &Router, session: &str) -> String {
+        let page = send(router, Method::GET, "/admin", &[session.to_string()], "").await;
+        assert_eq!(StatusCode::OK, page.status());
+        let body = to_bytes(page.into_body(), usize::MAX).await.unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        let (_, rest) = html
+            .split_once(r#"name="_csrf" value=""#)
+            .unwrap_or_else(|| panic!("expected a CSRF field in {html}"));
+        rest.split_once('"').unwrap().0.to_string()
+    }
+
+    /// Signs in, returning the session cookie and every cookie set on the way.
+    async fn sign_in(router: &Router) -> (String, Vec<String>) {
+        let redirected = send(router, Method::GET, "/admin", &[], "").await;
+        assert_eq!(StatusCode::SEE_OTHER, redirected.status());
+        assert_eq!(
+            "/admin/login?return_to=%2Fadmin",
+            redirected.headers()[header::LOCATION]
+        );
+
+        let login = send(
+            router,
+            Method::GET,
+            "/admin/login?return_to=%2Fadmin",
+            &[],
+            "",
+        )
+        .await;
+        assert_eq!(StatusCode::SEE_OTHER, login.status());
+        let location = login.headers()[header::LOCATION].to_str().unwrap();
+        let state = location.split_once("state=").unwrap().1.to_string();
+        let login_cookie = cookies(login.headers())["admin_login"].clone();
+
+        let forged = send(
+            router,
+            Method::GET,
+            "/admin/callback?code=c-1&state=forged",
+            std::slice::from_ref(&login_cookie),
+            "",
+        )
+        .await;
+        assert_eq!(StatusCode::UNAUTHORIZED, forged.status());
+
+        let callback = send(
+            router,
+            Method::GET,
+            &format!("/admin/callback?code=c-1&state={state}&session_state=x"),
+            &[login_cookie],
+            "",
+        )
+        .await;
+        assert_eq!(StatusCode::SEE_OTHER, callback.status());
+        assert_eq!("/admin", callback.headers()[header::LOCATION]);
+        let set_cookies = [login.headers(), callback.headers()]
+            .into_iter()
+            .flat_map(|headers| headers.get_all(header::SET_COOKIE))
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect();
+        (
+            cookies(callback.headers())["admin_session"].clone(),
+            set_cookies,
+        )
+    }
+
+    #[tokio::test]
+    async fn admins_sign_in_with_oidc_and_get_the_mapped_role() {
+        let provider = Arc::new(FakeProvider {
+            role: Mutex::new("staff"),
+            expires_in: 300,
+            ..FakeProvider::default()
+        });
+        let router = router(&provider, SessionCookieConfig::default());
+        let (session, _) = sign_in(&router).await;
+
+        let token = csrf_token(&router, &session).await;
+        let form = format!("{AUTHOR_FORM}&_csrf={token}");
+        let create = send(&router, Method::POST, "/admin/authors", &[session], &form).await;
+        assert_eq!(StatusCode::FORBIDDEN, create.status());
+
+        *provider.role.lock().unwrap() = "nobody";
+        let login = send(&router, Method::GET, "/admin/login", &[], "").await;
+        let state = login.headers()[header::LOCATION]
+            .to_str()
+            .unwrap()
+            .split_once("state=")
+            .unwrap()
+            .1
+            .to_string();
+        let login_cookie = cookies(login.headers())["admin_login"].clone();
+        let uri = format!("/admin/callback?code=c-1&state={state}");
+        let refused = send(&router, Method::GET, &uri, &[login_cookie], "").await;
+        assert_eq!(StatusCode::FORBIDDEN, refused.status());
+
+        let tampered = "admin_session=AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".to_string();
+        let anonymous = send(&router, Method::POST, "/admin/authors", &[tampered], &form).await;
+        assert_eq!(StatusCode::UNAUTHORIZED, anonymous.status());
+    }
+
+    #[tokio::test]
+    async fn forms_need_the_csrf_token_and_signing_out_ends_the_session() {
+        let provider = Arc::new(FakeProvider {
+            role: Mutex::new("editors"),
+            expires_in: 300,
+            ..FakeProvider::default()
+        });
+        let cookie_config = SessionCookieConfig::new(false, SameSite::Strict);
+        let router = router(&provider, cookie_config);
+        let (session, set_cookies) = sign_in(&router).await;
+        assert!(
+            set_cookies.iter().all(|cookie| !cookie.contains("Secure")),
+            "{set_cookies:?}"
+        );
+        assert!(
+            set_cookies
+                .iter()
+                .any(|cookie| cookie.starts_with("admin_login=") && cookie.contains("SameSite=Lax")),
+            "{set_cookies:?}"
+        );
+        assert!(
+            set_cookies
+                .iter()
+                .any(|cookie| cookie.starts_with("admin_session=")
+                    && cookie.contains("SameSite=Strict")),
+            "{set_cookies:?}"
+        );
+
+        let cookies = std::slice::from_ref(&session);
+        let missing = send(
+            &router,
+            Method::POST,
+            "/admin/authors",
+            cookies,
+            AUTHOR_FORM,
+        )
+        .await;
+        assert_eq!(StatusCode::FORBIDDEN, missing.status());
+        let forged = format!("{AUTHOR_FORM}&_csrf=forged");
+        let forged = send(&router, Method::POST, "/admin/authors", cookies, &forged).await;
+        assert_eq!(StatusCode::FORBIDDEN, forged.status());
+
+        let token = csrf_token(&router, &session).await;
+        let form = format!("{AUTHOR_FORM}&_csrf={token}");
+        let create = send(&router, Method::POST, "/admin/authors", cookies, &form).await;
+        assert_eq!(StatusCode::SEE_OTHER, create.status());
+
+        let form = format!("_csrf={token}");
+        let logout = send(&router, Method::POST, "/admin/logout", cookies, &form).await;
+        assert_eq!(StatusCode::OK, logout.status());
+        assert!(
+            logout.headers()[header::SET_COOKIE]
+                .to_str()
+                .unwrap()
+                .starts_with("admin_session=;")
+        );
+        let signed_out = send(&router, Method::GET, "/admin", cookies, "").await;
+        assert_eq!(StatusCode::SEE_OTHER, signed_out.status());
+    }
+
+    #[tokio::test]
+    async fn expired_sessions_are_refreshed() {
+        let provider = Arc::new(FakeProvider {
+            role: Mutex::new("editors"),
+            expires_in: 0,
+            ..FakeProvider::default()
+        });
+        let router = router(&provider, SessionCookieConfig::default());
+        let (session, _) = sign_in(&router).await;
+
+        let token = csrf_token(&router, &session).await;
+        let form = format!("{AUTHOR_FORM}&_csrf={token}");
+        let create = send(&router, Method::POST, "/admin/authors", &[session], &form).await;
+        assert_eq!(StatusCode::SEE_OTHER, create.status());
+        assert_eq!(2, provider.refreshes.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn code_challenges_follow_rfc_7636() {
+        assert_eq!(
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+            code_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk")
+        );
+    }
+}
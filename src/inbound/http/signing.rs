@@ -0,0 +1,326 @@
+use crate::inbound::http::MAX_AVATAR_BODY_BYTES;
+use crate::inbound::http::handlers::HttpError;
+use axum::body::{Body, to_bytes};
+use axum::extract::{OriginalUri, Request, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const CLIENT_ID_HEADER: &str = "x-client-id";
+pub const TIMESTAMP_HEADER: &str = "x-timestamp";
+pub const SIGNATURE_HEADER: &str = "x-signature";
+
+/// Must match what `hexarch_example_client::RequestSigner` signs: the method, the path with its
+/// query, the Unix timestamp and the SHA-256 of the body, one per line.
+fn string_to_sign(method: &str, path_and_query: &str, timestamp: i64, body: &[u8]) -> String {
+    let body_hash = hex::encode(Sha256::digest(body));
+    format!("{method}\n{path_and_query}\n{timestamp}\n{body_hash}")
+}
+
+/// The client whose signature the request carried, for handlers that record who did what.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedClient(pub String);
+
+/// What the signature headers say, checked as far as can be without the body.
+struct SignatureHeaders<'a> {
+    client_id: String,
+    secret: &'a [u8],
+    timestamp: i64,
+    signature: Vec<u8>,
+}
+
+/// Verifies HMAC-SHA256 signatures from machine-to-machine callers, each holding a secret
+/// shared with the server. A signed request that was captured can be replayed until its
+/// timestamp falls outside the tolerance, so the tolerance should stay short.
+#[derive(Clone)]
+pub struct RequestSigning {
+    clients: Arc<HashMap<String, Vec<u8>>>,
+    tolerance: Duration,
+    required: bool,
+    max_body_bytes: usize,
+}
+
+impl Default for RequestSigning {
+    fn default() -> Self {
+        Self::new(HashMap::new())
+    }
+}
+
+impl RequestSigning {
+    /// Shared secrets by client id. With none, signatures are ignored.
+    #[must_use]
+    pub fn new(clients: HashMap<String, Vec<u8>>) -> Self {
+        Self {
+            clients: Arc::new(clients),
+            tolerance: Duration::from_secs(300),
+            required: false,
+            max_body_bytes: MAX_AVATAR_BODY_BYTES,
+        }
+    }
+
+    /// How far a request's timestamp may be from the server's clock, either way.
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Rejects unsigned requests to the JSON API. The admin pages sign in people, not machines,
+    /// so they are left alone.
+    #[must_use]
+    pub const fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Signed bodies are buffered to be hashed, so they are refused above this size. It defaults
+    /// to the largest body an API route takes besides backup restores, which need it raised to be
+    /// signed.
+    #[must_use]
+    pub const fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        !self.clients.is_empty()
+    }
+
+    /// Everything but the signature itself, so strangers are turned away before their body is
+    /// read.
+    fn check_headers(&self, headers: &HeaderMap) -> Result<SignatureHeaders<'_>, HttpError> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .ok_or_else(|| HttpError::unauthorized(format!("the {name} header is missing")))
+        };
+        let client_id = header(CLIENT_ID_HEADER)?;
+        let secret = self.clients.get(client_id).ok_or_else(|| {
+            HttpError::unauthorized(format!(r#"no signing secret for client "{client_id}""#))
+        })?;
+        let timestamp: i64 = header(TIMESTAMP_HEADER)?.parse().map_err(|_| {
+            HttpError::unauthorized(format!("{TIMESTAMP_HEADER} must be Unix seconds"))
+        })?;
+        let skew = Utc::now().timestamp().abs_diff(timestamp);
+        if skew > self.tolerance.as_secs() {
+            return Err(HttpError::unauthorized(
+                "the signature timestamp is too far from the server's clock".into(),
+            ));
+        }
+        let signature = hex::decode(header(SIGNATURE_HEADER)?).map_err(|_| {
+            HttpError::unauthorized(format!("{SIGNATURE_HEADER} must be hex-encoded"))
+        })?;
+        Ok(SignatureHeaders {
+            client_id: client_id.to_string(),
+            secret,
+            timestamp,
+            signature,
+        })
+    }
+}
+
+impl SignatureHeaders<'_> {
+    fn verify(self, method: &str, path: &str, body: &[u8]) -> Result<String, HttpError> {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(self.secret)
+            .expect("HMAC accepts keys of any length");
+        mac.update(string_to_sign(method, path, self.timestamp, body).as_bytes());
+        mac.verify_slice(&self.signature)
+            .map_err(|_| HttpError::unauthorized("the request signature does not match".into()))?;
+        Ok(self.client_id)
+    }
+}
+
+impl fmt::Debug for RequestSigning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut clients: Vec<_> = self.clients.keys().collect();
+        clients.sort();
+        f.debug_struct("RequestSigning")
+            .field("clients", &clients)
+            .field("tolerance", &self.tolerance)
+            .field("required", &self.required)
+            .field("max_body_bytes", &self.max_body_bytes)
+            .finish()
+    }
+}
+
+/// Checks signed requests and marks them with a [`SignedClient`]. The body is buffered to be
+/// hashed once the headers check out. The path checked is the one the client sent, before any
+/// path normalization rewrote it.
+pub async fn verify_signature(
+    State(signing): State<RequestSigning>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !signing.is_enabled() {
+        return next.run(request).await;
+    }
+    if !request.headers().contains_key(SIGNATURE_HEADER) {
+        if signing.required && request.uri().path().starts_with("/api/") {
+            let err = HttpError::unauthorized("requests to the API must be signed".into());
+            return err.into_response();
+        }
+        return next.run(request).await;
+    }
+    let signature = match signing.check_headers(request.headers()) {
+        Ok(signature) => signature,
+        Err(err) => return err.into_response(),
+    };
+    let (mut parts, body) = request.into_parts();
+    let Ok(body) = to_bytes(body, signing.max_body_bytes).await else {
+        let message = format!(
+            "signed bodies may not be larger than {} bytes",
+            signing.max_body_bytes
+        );
+        return HttpError::payload_too_large(message).into_response();
+    };
+    let uri = parts
+        .extensions
+        .get::<OriginalUri>()
+        .map_or(&parts.uri, |original| &original.0);
+    let path = uri
+        .path_and_query()
+        .map_or(uri.path(), |path| path.as_str());
+    match signature.verify(parts.method.as_str(), path, &body) {
+        Ok(client_id) => {
+            parts.extensions.insert(SignedClient(client_id));
+            next.run(Request::from_parts(parts, Body::from(body))).await
+        }
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inbound::http::normalize::{NormalizeMode, PathNormalization, normalize_path};
+    use crate::inbound::http::signing::{RequestSigning, SignedClient, verify_signature};
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::StatusCode;
+    use axum::routing::post;
+    use axum::{Extension, Router, middleware};
+    use chrono::Utc;
+    use futures::StreamExt;
+    use hexarch_example_client::RequestSigner;
+    use std::collections::HashMap;
+    use std::convert::Infallible;
+    use tower::ServiceExt;
+
+    fn router(required: bool) -> Router {
+        let signing =
+            RequestSigning::new(HashMap::from([("billing".to_string(), b"s3cret".to_vec())]))
+                .with_required(required);
+        Router::new()
+            .route(
+                "/api/v1/authors",
+                post(
+                    |client: Option<Extension<SignedClient>>, body: String| async move {
+                        let client = client.map_or("unsigned".into(), |client| client.0.0);
+                        format!("{client}: {body}")
+                    },
+                ),
+            )
+            .layer(middleware::from_fn_with_state(signing, verify_signature))
+    }
+
+    async fn send(
+        router: &Router,
+        signer: Option<&RequestSigner>,
+        timestamp: i64,
+        body: &str,
+    ) -> (StatusCode, String) {
+        let uri = "/api/v1/authors?dry_run=true";
+        let mut request = Request::post(uri);
+        if let Some(signer) = signer {
+            let signature = signer.sign("POST", uri, timestamp, body.as_bytes());
+            request = request
+                .header("x-client-id", signer.client_id())
+                .header("x-timestamp", timestamp.to_string())
+                .header("x-signature", signature);
+        }
+        let request = request.body(Body::from(body.to_string())).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn signed_requests_are_verified_and_stale_ones_rejected() {
+        let optional = router(false);
+        let signer = RequestSigner::new("billing", "s3cret");
+        let now = Utc::now().timestamp();
+
+        let signed = send(&optional, Some(&signer), now, "{}").await;
+        assert_eq!((StatusCode::OK, "billing: {}".to_string()), signed);
+        let unsigned = send(&optional, None, now, "{}").await;
+        assert_eq!((StatusCode::OK, "unsigned: {}".to_string()), unsigned);
+
+        let stale = send(&optional, Some(&signer), now - 600, "{}").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, stale.0);
+        let forged = RequestSigner::new("billing", "guess");
+        let forged = send(&optional, Some(&forged), now, "{}").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, forged.0);
+        let unknown = RequestSigner::new("payroll", "s3cret");
+        let unknown = send(&optional, Some(&unknown), now, "{}").await;
+        assert_eq!(StatusCode::UNAUTHORIZED, unknown.0);
+
+        let required = router(true);
+        assert_eq!(
+            StatusCode::UNAUTHORIZED,
+            send(&required, None, now, "{}").await.0
+        );
+        assert_eq!(
+            StatusCode::OK,
+            send(&required, Some(&signer), now, "{}").await.0
+        );
+    }
+
+    #[tokio::test]
+    async fn bodies_are_only_read_for_known_clients_and_within_limits() {
+        let signer = RequestSigner::new("billing", "s3cret");
+        let now = Utc::now().timestamp();
+        let oversized = |client: &str| {
+            let chunks = futures::stream::repeat_with(|| Ok::<_, Infallible>(vec![0_u8; 1024]));
+            Request::post("/api/v1/authors")
+                .header("x-client-id", client)
+                .header("x-timestamp", now.to_string())
+                .header(
+                    "x-signature",
+                    signer.sign("POST", "/api/v1/authors", now, b""),
+                )
+                .body(Body::from_stream(chunks.take(4 * 1024)))
+                .unwrap()
+        };
+        let unknown = router(false).oneshot(oversized("payroll")).await.unwrap();
+        assert_eq!(StatusCode::UNAUTHORIZED, unknown.status());
+        let known = router(false).oneshot(oversized("billing")).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, known.status());
+
+        let normalization = PathNormalization::new(NormalizeMode::Rewrite);
+        let normalized =
+            Router::new()
+                .fallback_service(router(true))
+                .layer(middleware::from_fn_with_state(
+                    normalization,
+                    normalize_path,
+                ));
+        let uri = "/api/v1/authors/";
+        let request = Request::post(uri)
+            .header("x-client-id", signer.client_id())
+            .header("x-timestamp", now.to_string())
+            .header("x-signature", signer.sign("POST", uri, now, b"{}"))
+            .body(Body::from("{}"))
+            .unwrap();
+        let response = normalized.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+    }
+}
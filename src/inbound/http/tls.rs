@@ -0,0 +1,61 @@
+use crate::inbound::http::TlsConfig;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::crypto::ring;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+/// Returns the validity window of the first certificate in the chain, which is the server's own.
+pub fn certificate_validity(config: &TlsConfig) -> anyhow::Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let cert = CertificateDer::from_pem_file(config.cert_path()).with_context(|| {
+        format!(
+            "Failed to read TLS certificate from {}",
+            config.cert_path().display()
+        )
+    })?;
+    let (_, cert) = x509_parser::parse_x509_certificate(&cert)
+        .map_err(|err| anyhow::anyhow!("Failed to parse TLS certificate: {err}"))?;
+    let validity = cert.validity();
+    let timestamp = |time: x509_parser::time::ASN1Time| {
+        DateTime::from_timestamp(time.timestamp(), 0)
+            .context("TLS certificate date is out of range")
+    };
+    Ok((
+        timestamp(validity.not_before)?,
+        timestamp(validity.not_after)?,
+    ))
+}
+
+pub fn acceptor(config: &TlsConfig, http2: bool) -> anyhow::Result<TlsAcceptor> {
+    let certs = CertificateDer::pem_file_iter(config.cert_path())
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .with_context(|| {
+            format!(
+                "Failed to read TLS certificates from {}",
+                config.cert_path().display()
+            )
+        })?;
+    let key = PrivateKeyDer::from_pem_file(config.key_path()).with_context(|| {
+        format!(
+            "Failed to read TLS private key from {}",
+            config.key_path().display()
+        )
+    })?;
+
+    let mut server = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .context("Failed to select TLS protocol versions")?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .context("Failed to load TLS certificate")?;
+    server.alpn_protocols = if http2 {
+        vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+    } else {
+        vec![b"http/1.1".to_vec()]
+    };
+
+    Ok(TlsAcceptor::from(Arc::new(server)))
+}
@@ -0,0 +1,229 @@
+use crate::inbound::http::flags::{RequestFlags, V2_ENVELOPE};
+use crate::inbound::http::handlers::HttpError;
+use anyhow::anyhow;
+use axum::body::{Body, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::header::{self, HeaderName};
+use axum::http::{HeaderMap, HeaderValue};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+pub const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+pub const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    V1,
+    V2,
+}
+
+impl ApiVersion {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+
+    fn of(path: &str) -> Option<Self> {
+        let rest = path.strip_prefix("/api/")?;
+        let version = rest.split('/').next()?;
+        match version {
+            "v1" => Some(Self::V1),
+            "v2" => Some(Self::V2),
+            _ => None,
+        }
+    }
+}
+
+/// When v1 was deprecated and when it will stop being served; unset means v1 is still current.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiDeprecation {
+    deprecated_at: Option<DateTime<Utc>>,
+    sunset_at: Option<DateTime<Utc>>,
+}
+
+impl ApiDeprecation {
+    #[must_use]
+    pub const fn new(
+        deprecated_at: Option<DateTime<Utc>>,
+        sunset_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            deprecated_at,
+            sunset_at,
+        }
+    }
+
+    /// Adds the RFC 9745 `Deprecation` and RFC 8594 `Sunset` headers, pointing at v2 as successor.
+    fn apply(self, headers: &mut HeaderMap) {
+        if let Some(deprecated_at) = self.deprecated_at {
+            let value = format!("@{}", deprecated_at.timestamp());
+            headers.insert(
+                DEPRECATION,
+                HeaderValue::from_str(&value).expect("timestamp is a valid header value"),
+            );
+            headers.append(
+                header::LINK,
+                HeaderValue::from_static(r#"</api/v2>; rel="successor-version""#),
+            );
+        }
+        if let Some(sunset_at) = self.sunset_at {
+            let value = sunset_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+            headers.insert(
+                SUNSET,
+                HeaderValue::from_str(&value).expect("HTTP date is a valid header value"),
+            );
+        }
+    }
+}
+
+/// Counts requests per API version and marks v1 responses as deprecated once configured.
+pub async fn track_api_version(
+    State(deprecation): State<ApiDeprecation>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(version) = ApiVersion::of(request.uri().path()) else {
+        return next.run(request).await;
+    };
+    metrics::counter!("http_api_requests_total", "version" => version.as_str()).increment(1);
+    let mut response = next.run(request).await;
+    if version == ApiVersion::V1 {
+        deprecation.apply(response.headers_mut());
+    }
+    response
+}
+
+/// Wraps successful v2 JSON bodies as `{"data": ..., "meta": {...}}`; errors keep their format.
+/// Clients the [`V2_ENVELOPE`] flag is off for get bare bodies, as from v1.
+pub async fn envelope(request: Request, next: Next) -> Response {
+    let wrap = request
+        .extensions()
+        .get::<RequestFlags>()
+        .is_none_or(|flags| flags.is_enabled(V2_ENVELOPE, true));
+    let response = next.run(request).await;
+    if !wrap {
+        return response;
+    }
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|value| value.as_bytes().starts_with(b"application/json"));
+    if !response.status().is_success() || !is_json {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let err = anyhow!(err).context("Failed to read the response body");
+            return HttpError::internal(&err).into_response();
+        }
+    };
+    if bytes.is_empty() {
+        return Response::from_parts(parts, Body::empty());
+    }
+    let data: Value = match serde_json::from_slice(&bytes) {
+        Ok(data) => data,
+        Err(err) => {
+            let err = anyhow!(err).context("Failed to parse the response body");
+            return HttpError::internal(&err).into_response();
+        }
+    };
+    let body = json!({
+        "data": data,
+        "meta": { "api_version": ApiVersion::V2.as_str() },
+    });
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::versioning::{ApiDeprecation, ApiVersion, track_api_version};
+    use crate::inbound::http::{AppState, CacheControlConfig, routes};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::body::{Body, to_bytes};
+    use axum::extract::Request;
+    use axum::http::{StatusCode, header};
+    use axum::response::Response;
+    use axum::{Router, middleware};
+    use chrono::{TimeZone, Utc};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn router(deprecation: ApiDeprecation) -> Router {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        routes(&CacheControlConfig::default())
+            .layer(middleware::from_fn_with_state(
+                deprecation,
+                track_api_version,
+            ))
+            .with_state(AppState::new(service))
+    }
+
+    async fn json(response: Response) -> Value {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn versions_are_read_from_the_path_prefix() {
+        assert_eq!(Some(ApiVersion::V1), ApiVersion::of("/api/v1/authors"));
+        assert_eq!(Some(ApiVersion::V2), ApiVersion::of("/api/v2/authors/1"));
+        assert_eq!(None, ApiVersion::of("/api/v3/authors"));
+        assert_eq!(None, ApiVersion::of("/admin"));
+    }
+
+    #[tokio::test]
+    async fn v1_is_deprecated_and_v2_wraps_bodies_in_an_envelope() {
+        let deprecated_at = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+        let sunset_at = Utc.with_ymd_and_hms(2027, 4, 1, 0, 0, 0).unwrap();
+        let router = router(ApiDeprecation::new(Some(deprecated_at), Some(sunset_at)));
+
+        let create = Request::post("/api/v2/authors")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"name":"Ada","email":"ada@example.com"}"#))
+            .unwrap();
+        let created = router.clone().oneshot(create).await.unwrap();
+        assert_eq!(StatusCode::CREATED, created.status());
+        assert!(created.headers().get("deprecation").is_none());
+        assert_eq!(
+            json!({"data": {"id": 1}, "meta": {"api_version": "v2"}}),
+            json(created).await
+        );
+
+        let v1 = Request::get("/api/v1/authors/1")
+            .body(Body::empty())
+            .unwrap();
+        let v1 = router.clone().oneshot(v1).await.unwrap();
+        assert_eq!(StatusCode::OK, v1.status());
+        assert_eq!("@1790812800", v1.headers()["deprecation"]);
+        assert_eq!("Thu, 01 Apr 2027 00:00:00 GMT", v1.headers()["sunset"]);
+        assert_eq!(
+            r#"</api/v2>; rel="successor-version""#,
+            v1.headers()[header::LINK]
+        );
+        assert_eq!("Ada", json(v1).await["name"]);
+
+        let missing = Request::get("/api/v2/authors/9")
+            .body(Body::empty())
+            .unwrap();
+        let missing = router.oneshot(missing).await.unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, missing.status());
+        assert_eq!("author-not-found", json(missing).await["code"]);
+    }
+}
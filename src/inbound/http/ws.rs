@@ -0,0 +1,228 @@
+use crate::domain::model::{AuthorEvent, AuthorId};
+use crate::domain::ports::AuthorRepository;
+use crate::domain::service::AuthorService;
+use crate::inbound::http::handlers::FindAuthorHttpResponse;
+use crate::inbound::http::{AppState, shutdown_requested};
+use anyhow::Context;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::extract::ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code};
+use axum::response::Response;
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, watch};
+
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_WRITE_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AuthorUpdateMessage {
+    Snapshot {
+        authors: Vec<FindAuthorHttpResponse>,
+    },
+    Created {
+        author: FindAuthorHttpResponse,
+    },
+    Updated {
+        author: FindAuthorHttpResponse,
+    },
+    Deleted {
+        id: AuthorId,
+    },
+}
+
+impl From<AuthorEvent> for AuthorUpdateMessage {
+    fn from(value: AuthorEvent) -> Self {
+        match value {
+            AuthorEvent::Created(author) => Self::Created {
+                author: author.into(),
+            },
+            AuthorEvent::Updated(author) => Self::Updated {
+                author: author.into(),
+            },
+            AuthorEvent::Deleted { id } => Self::Deleted { id },
+        }
+    }
+}
+
+struct AuthorUpdateSession<R> {
+    socket: WebSocket,
+    service: AuthorService<R>,
+    events: broadcast::Receiver<AuthorEvent>,
+    shutdown: watch::Receiver<bool>,
+}
+
+impl<R: AuthorRepository> AuthorUpdateSession<R> {
+    async fn run(mut self) -> anyhow::Result<()> {
+        self.send_snapshot().await?;
+
+        let mut ping = tokio::time::interval(PING_INTERVAL);
+        ping.tick().await;
+        let mut awaiting_pong = false;
+        loop {
+            tokio::select! {
+                event = self.events.recv() => match event {
+                    Ok(event) => self.send(&AuthorUpdateMessage::from(event)).await?,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::debug!(skipped, "Author update socket lagged, resending snapshot");
+                        self.send_snapshot().await?;
+                    }
+                    Err(RecvError::Closed) => return self.close(close_code::AWAY, "").await,
+                },
+                message = self.socket.recv() => match message {
+                    Some(Ok(Message::Pong(_))) => awaiting_pong = false,
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => return Err(err.into()),
+                },
+                _ = ping.tick() => {
+                    if awaiting_pong {
+                        return self.close(close_code::POLICY, "pong not received").await;
+                    }
+                    awaiting_pong = true;
+                    self.send_message(Message::Ping(Bytes::new())).await?;
+                }
+                () = shutdown_requested(&mut self.shutdown) => {
+                    return self.close(close_code::AWAY, "server shutting down").await;
+                }
+            }
+        }
+    }
+
+    async fn send_snapshot(&mut self) -> anyhow::Result<()> {
+        let authors = match self.service.find_all_authors().await {
+            Ok(authors) => authors,
+            Err(err) => {
+                self.close(close_code::ERROR, "internal server error")
+                    .await?;
+                return Err(err.0);
+            }
+        };
+        let authors = authors.into_iter().map(Into::into).collect();
+        self.send(&AuthorUpdateMessage::Snapshot { authors }).await
+    }
+
+    async fn send(&mut self, message: &AuthorUpdateMessage) -> anyhow::Result<()> {
+        let text = serde_json::to_string(message).context("Failed to encode author update")?;
+        self.send_message(Message::Text(text.into())).await
+    }
+
+    async fn send_message(&mut self, message: Message) -> anyhow::Result<()> {
+        tokio::time::timeout(SEND_TIMEOUT, self.socket.send(message))
+            .await
+            .context("Timed out sending author update")?
+            .context("Failed to send author update")
+    }
+
+    async fn close(&mut self, code: u16, reason: &'static str) -> anyhow::Result<()> {
+        let frame = CloseFrame {
+            code,
+            reason: reason.into(),
+        };
+        self.send_message(Message::Close(Some(frame))).await
+    }
+}
+
+pub async fn author_updates<R: AuthorRepository>(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState<R>>,
+) -> Response {
+    let events = state.author_events.subscribe();
+    let shutdown = state.shutdown.subscribe();
+    ws.max_write_buffer_size(MAX_WRITE_BUFFER_SIZE)
+        .on_upgrade(move |socket| async move {
+            let session = AuthorUpdateSession {
+                socket,
+                service: state.author_service,
+                events,
+                shutdown,
+            };
+            if let Err(err) = session.run().await {
+                tracing::debug!("Author update socket closed: {err:?}");
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuditContext, AuthorName, CreateAuthorRequest, EmailAddress};
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{AppState, HttpServer, HttpServerConfig};
+    use crate::outbound::events::BroadcastEventPublisher;
+    use crate::outbound::memory::InMemoryRepository;
+    use futures::StreamExt;
+    use tokio::net::TcpStream;
+    use tokio::sync::oneshot;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+    use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+    type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    fn create_request(name: &str, email: &str) -> CreateAuthorRequest {
+        CreateAuthorRequest::new(
+            AuthorName::new(name).unwrap(),
+            EmailAddress::new(email).unwrap(),
+        )
+    }
+
+    async fn next_json(socket: &mut Socket) -> serde_json::Value {
+        loop {
+            if let Message::Text(text) = socket.next().await.unwrap().unwrap() {
+                return serde_json::from_str(text.as_str()).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_followed_by_changes_until_shutdown() {
+        let repo = InMemoryRepository::new();
+        let events = BroadcastEventPublisher::new(repo.clone());
+        let sender = events.sender();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            events,
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+        let tolkien = create_request("JRR Tolkien", "jrr.tolkien@example.com");
+        service.create_author(&tolkien, &ctx).await.unwrap();
+
+        let state = AppState::new(service.clone()).with_author_events(sender);
+        let server = HttpServer::builder(state, HttpServerConfig::new(0))
+            .build()
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let (stop, stopped) = oneshot::channel::<()>();
+        let running = tokio::spawn(server.run_until(async {
+            let _ = stopped.await;
+        }));
+
+        let url = format!("ws://{addr}/api/v1/authors/ws");
+        let (mut socket, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        let snapshot = next_json(&mut socket).await;
+        assert_eq!("snapshot", snapshot["type"]);
+        assert_eq!("JRR Tolkien", snapshot["authors"][0]["name"]);
+
+        let le_guin = create_request("Ursula K. Le Guin", "ursula.le.guin@example.com");
+        service.create_author(&le_guin, &ctx).await.unwrap();
+        let created = next_json(&mut socket).await;
+        assert_eq!("created", created["type"]);
+        assert_eq!("Ursula K. Le Guin", created["author"]["name"]);
+
+        stop.send(()).unwrap();
+        let Message::Close(Some(frame)) = socket.next().await.unwrap().unwrap() else {
+            panic!("expected a close frame");
+        };
+        assert_eq!(CloseCode::Away, frame.code);
+        running.await.unwrap().unwrap();
+    }
+}
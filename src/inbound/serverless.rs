@@ -0,0 +1,75 @@
+use crate::domain::ports::AuthorRepository;
+use crate::inbound::http::{AppState, HttpServerConfig, build_router};
+
+/// Whether the process was started by the AWS Lambda runtime rather than as a long-running server.
+#[must_use]
+pub fn is_lambda() -> bool {
+    std::env::var_os("AWS_LAMBDA_RUNTIME_API").is_some()
+}
+
+/// Answers API Gateway, function URL and ALB invocations with the same router `HttpServer` serves.
+/// Only the middleware settings of `config` apply; Lambda owns the listener and connections.
+pub async fn run_lambda<R: AuthorRepository>(
+    state: AppState<R>,
+    config: &HttpServerConfig,
+) -> anyhow::Result<()> {
+    lambda_http::run(build_router(state, config))
+        .await
+        .map_err(|err| anyhow::anyhow!(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::service::AuthorService;
+    use crate::inbound::http::{AppState, HttpServerConfig, build_router};
+    use crate::outbound::memory::InMemoryRepository;
+    use axum::http::StatusCode;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn api_gateway_events_reach_the_router() {
+        let repo = InMemoryRepository::new();
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo,
+        );
+        let router = build_router(AppState::new(service), &HttpServerConfig::new(0));
+        let event = r#"{
+            "version": "2.0",
+            "routeKey": "$default",
+            "rawPath": "/api/v1/authors",
+            "rawQueryString": "",
+            "headers": {"content-type": "application/json", "host": "example.lambda-url.aws"},
+            "requestContext": {
+                "accountId": "123456789012",
+                "apiId": "api-id",
+                "domainName": "example.lambda-url.aws",
+                "domainPrefix": "example",
+                "http": {
+                    "method": "POST",
+                    "path": "/api/v1/authors",
+                    "protocol": "HTTP/1.1",
+                    "sourceIp": "192.0.2.1",
+                    "userAgent": "curl"
+                },
+                "requestId": "id",
+                "routeKey": "$default",
+                "stage": "$default",
+                "time": "15/Oct/2026:12:00:00 +0000",
+                "timeEpoch": 1792065600000
+            },
+            "body": "{\"name\":\"Ursula K. Le Guin\",\"email\":\"ursula@example.com\"}",
+            "isBase64Encoded": false
+        }"#;
+        let request = lambda_http::request::from_str(event).unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::CREATED, response.status());
+        assert!(response.headers().contains_key("x-request-id"));
+    }
+}
@@ -1,5 +1,18 @@
+//! The domain (models, the ports it depends on and the service using them) is surrounded by
+//! inbound adapters driving it and outbound adapters implementing its ports.
+
+pub mod backup;
 pub mod config;
-pub mod database;
-pub mod http;
-mod models;
-mod repositories;
+pub mod domain;
+pub mod inbound;
+pub mod logging;
+pub mod outbound;
+pub mod preflight;
+pub mod prelude;
+pub mod secrets;
+pub mod seed;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
+#[cfg(feature = "vault")]
+pub mod vault;
+pub mod verification;
@@ -0,0 +1,417 @@
+use anyhow::Context;
+use chrono::{SecondsFormat, Utc};
+use rand::Rng;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber, span};
+use tracing_subscriber::Layer;
+use tracing_subscriber::field::{MakeExt, RecordFields};
+use tracing_subscriber::fmt::format::{Writer, debug_fn};
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::layer::{self, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry, reload};
+
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+const REDACTED: &str = "[REDACTED]";
+const CORRELATION_FIELDS: &[&str] = &["request_id", "trace_id"];
+/// Where sqlx logs statements, as configured by `PoolConfig::with_query_log`.
+const QUERY_TARGET: &str = "sqlx::query";
+/// The field sqlx puts the full SQL of a statement in.
+const STATEMENT_FIELD: &str = "db.statement";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = LogFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(LogFormatError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid log format, expected one of "text" or "json""#)]
+pub struct LogFormatError(String);
+
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    format: LogFormat,
+    redact: Vec<String>,
+    query_sample_rate: f64,
+}
+
+impl LoggingConfig {
+    #[must_use]
+    pub const fn new(format: LogFormat, redact: Vec<String>) -> Self {
+        Self {
+            format,
+            redact,
+            query_sample_rate: 1.0,
+        }
+    }
+
+    /// The share of statements logged, from 0 to 1. Slow statements are always logged.
+    #[must_use]
+    pub const fn with_query_sample_rate(mut self, query_sample_rate: f64) -> Self {
+        self.query_sample_rate = query_sample_rate;
+        self
+    }
+}
+
+pub fn init(config: &LoggingConfig) -> anyhow::Result<LogFilterHandle> {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let (filter, handle) = reload::Layer::new(filter);
+    let redaction = Redaction(config.redact.clone().into());
+    let registry = tracing_subscriber::registry()
+        .with(filter)
+        .with(QuerySampling(config.query_sample_rate.clamp(0.0, 1.0)));
+    match config.format {
+        LogFormat::Text => registry
+            .with(tracing_subscriber::fmt::layer().fmt_fields(text_fields(redaction)))
+            .try_init(),
+        LogFormat::Json => registry
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .fmt_fields(JsonFields(redaction.clone()))
+                    .event_format(JsonFormat(redaction)),
+            )
+            .try_init(),
+    }
+    .context("Failed to install the tracing subscriber")?;
+    Ok(handle)
+}
+
+/// Drops all but a share of the statements sqlx logs, so logging every statement does not flood
+/// the logs under load. Slow statements are logged at `WARN` and always kept.
+struct QuerySampling(f64);
+
+impl<S: Subscriber> Layer<S> for QuerySampling {
+    fn event_enabled(&self, event: &Event<'_>, _: layer::Context<'_, S>) -> bool {
+        let metadata = event.metadata();
+        metadata.target() != QUERY_TARGET
+            || *metadata.level() <= Level::WARN
+            || rand::thread_rng().gen_bool(self.0)
+    }
+}
+
+/// Replaces string and number literals in SQL with `?`, in case a value was written into a
+/// statement instead of bound to it. Numbered parameters such as `?1` are left alone.
+fn redact_sql_literals(sql: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '?' | '$' | ':' | '@');
+    let mut redacted = String::with_capacity(sql.len());
+    let mut previous = ' ';
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // A doubled quote is a quote inside the literal.
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.peek() == Some(&'\'') => {
+                        chars.next();
+                    }
+                    Some('\'') | None => break,
+                    Some(_) => {}
+                }
+            }
+            redacted.push('?');
+            previous = '?';
+        } else if c.is_ascii_digit() && !is_word(previous) {
+            while chars.next_if(|c| c.is_ascii_digit() || *c == '.').is_some() {}
+            redacted.push('?');
+            previous = '?';
+        } else {
+            redacted.push(c);
+            previous = c;
+        }
+    }
+    redacted
+}
+
+#[derive(Debug, Clone)]
+struct Redaction(Arc<[String]>);
+
+impl Redaction {
+    fn applies(&self, field: &Field) -> bool {
+        self.0
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(field.name()))
+    }
+}
+
+fn text_fields(redaction: Redaction) -> impl for<'writer> FormatFields<'writer> {
+    debug_fn(move |writer, field, value| {
+        if redaction.applies(field) {
+            write!(writer, "{field}={REDACTED}")
+        } else if field.name() == STATEMENT_FIELD {
+            write!(
+                writer,
+                "{field}={}",
+                redact_sql_literals(&format!("{value:?}"))
+            )
+        } else if field.name() == "message" {
+            write!(writer, "{value:?}")
+        } else {
+            write!(writer, "{field}={value:?}")
+        }
+    })
+    .delimited(" ")
+}
+
+struct JsonVisitor<'a> {
+    redaction: &'a Redaction,
+    fields: Map<String, Value>,
+}
+
+impl<'a> JsonVisitor<'a> {
+    fn record(redaction: &'a Redaction, fields: &impl RecordFields) -> Map<String, Value> {
+        let mut visitor = Self {
+            redaction,
+            fields: Map::new(),
+        };
+        fields.record(&mut visitor);
+        visitor.fields
+    }
+
+    fn insert(&mut self, field: &Field, value: Value) {
+        let value = if self.redaction.applies(field) {
+            Value::from(REDACTED)
+        } else {
+            value
+        };
+        self.fields.insert(field.name().to_string(), value);
+    }
+}
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == STATEMENT_FIELD {
+            self.insert(field, Value::from(redact_sql_literals(value)));
+        } else {
+            self.insert(field, Value::from(value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, Value::from(format!("{value:?}")));
+    }
+}
+
+struct JsonFields(Redaction);
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let fields = JsonVisitor::record(&self.0, &fields);
+        writer.write_str(&Value::Object(fields).to_string())
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &span::Record<'_>,
+    ) -> fmt::Result {
+        let mut existing = span_fields(current);
+        existing.extend(JsonVisitor::record(&self.0, fields));
+        current.fields = Value::Object(existing).to_string();
+        Ok(())
+    }
+}
+
+fn span_fields(fields: &FormattedFields<JsonFields>) -> Map<String, Value> {
+    serde_json::from_str(&fields.fields).unwrap_or_default()
+}
+
+struct JsonFormat(Redaction);
+
+impl<S> FormatEvent<S, JsonFields> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, JsonFields>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let mut record = Map::new();
+        record.insert(
+            "timestamp".into(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Micros, true)
+                .into(),
+        );
+        record.insert("level".into(), metadata.level().as_str().into());
+        record.insert("target".into(), metadata.target().into());
+
+        let mut spans = Vec::new();
+        for span in ctx
+            .event_scope()
+            .into_iter()
+            .flat_map(|scope| scope.from_root())
+        {
+            let extensions = span.extensions();
+            let fields = extensions
+                .get::<FormattedFields<JsonFields>>()
+                .map(span_fields)
+                .unwrap_or_default();
+            for &name in CORRELATION_FIELDS {
+                if let Some(value) = fields.get(name) {
+                    record.insert(name.into(), value.clone());
+                }
+            }
+            let mut span_record = Map::new();
+            span_record.insert("name".into(), span.name().into());
+            span_record.insert("fields".into(), fields.into());
+            spans.push(Value::Object(span_record));
+        }
+
+        let mut fields = JsonVisitor::record(&self.0, event);
+        if let Some(message) = fields.remove("message") {
+            record.insert("message".into(), message);
+        }
+        record.insert("fields".into(), fields.into());
+        record.insert("spans".into(), spans.into());
+        writeln!(writer, "{}", Value::Object(record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logging::{JsonFields, JsonFormat, QuerySampling, Redaction, redact_sql_literals};
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing::Level;
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn json_records_carry_correlation_ids_and_redact_fields() {
+        let buffer = Buffer::default();
+        let redaction = Redaction(vec!["email".to_string()].into());
+        let layer = tracing_subscriber::fmt::layer()
+            .fmt_fields(JsonFields(redaction.clone()))
+            .event_format(JsonFormat(redaction))
+            .with_writer(buffer.clone());
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(
+                "http_request",
+                request_id = "req-1",
+                trace_id = tracing::field::Empty,
+                email = "jrr.tolkien@example.com"
+            );
+            span.record("trace_id", "4bf92f3577b34da6a3ce929d0e0e4736");
+            let _guard = span.enter();
+            tracing::info!(email = "jrr.tolkien@example.com", id = 1, "Created author");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("jrr.tolkien@example.com"), "{output}");
+        let record: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!("INFO", record["level"]);
+        assert_eq!("Created author", record["message"]);
+        assert_eq!("req-1", record["request_id"]);
+        assert_eq!("4bf92f3577b34da6a3ce929d0e0e4736", record["trace_id"]);
+        assert_eq!("[REDACTED]", record["fields"]["email"]);
+        assert_eq!(1, record["fields"]["id"]);
+        assert_eq!("[REDACTED]", record["spans"][0]["fields"]["email"]);
+    }
+
+    #[test]
+    fn statements_are_sampled_and_their_literals_redacted() {
+        assert_eq!(
+            "SELECT * FROM author WHERE name = ? AND id > ? LIMIT ?1 -- v2",
+            redact_sql_literals(
+                "SELECT * FROM author WHERE name = 'O''Brien' AND id > 41.5 LIMIT ?1 -- v2"
+            )
+        );
+
+        let buffer = Buffer::default();
+        let redaction = Redaction(Vec::new().into());
+        let layer = tracing_subscriber::fmt::layer()
+            .fmt_fields(JsonFields(redaction.clone()))
+            .event_format(JsonFormat(redaction))
+            .with_writer(buffer.clone());
+        let subscriber = tracing_subscriber::registry()
+            .with(QuerySampling(0.0))
+            .with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let sql = "SELECT id FROM author WHERE email = 'jrr.tolkien@example.com'";
+            tracing::event!(target: "sqlx::query", Level::INFO, db.statement = sql, "SELECT id FROM author");
+            tracing::event!(target: "sqlx::query", Level::WARN, db.statement = sql, "slow statement");
+            tracing::info!("Unrelated events are kept");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let records: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(2, records.len(), "{output}");
+        assert_eq!("slow statement", records[0]["message"]);
+        assert_eq!(
+            "SELECT id FROM author WHERE email = ?",
+            records[0]["fields"]["db.statement"]
+        );
+        assert_eq!("Unrelated events are kept", records[1]["message"]);
+    }
+}
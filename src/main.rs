@@ -1,18 +1,381 @@
-use hexarch_example::config::Config;
-use hexarch_example::database::{DefaultAuthorRepository, establish_pool};
-use hexarch_example::http::{AppState, HttpServer, HttpServerConfig};
+use chrono::Utc;
+use hexarch_example::backup::{BackupJob, BackupScheduleConfig};
+use hexarch_example::config::{AppEnv, Config};
+use hexarch_example::domain::ports::{BoxedAuthorRepository, DynUnitOfWork};
+use hexarch_example::domain::service::{AuthorService, RetentionJob};
+use hexarch_example::inbound::commands::{
+    CommandConsumer, CommandConsumerConfig, connect_command_queue,
+};
+use hexarch_example::inbound::http::{
+    AdminSessions, ApiDeprecation, AppState, Assets, CacheControlConfig, HttpServer,
+    HttpServerConfig, PathNormalization, RuntimeMetrics, TlsConfig,
+};
+use hexarch_example::logging::{self, LoggingConfig};
+use hexarch_example::outbound::blobs::{BlobBackend, BlobStorageConfig, connect_blob_storage};
+use hexarch_example::outbound::breaker::{CircuitBreaker, CircuitBreakerConfig};
+use hexarch_example::outbound::catalog::{BookCatalogConfig, connect_book_catalog};
+use hexarch_example::outbound::cipher::field_cipher;
+use hexarch_example::outbound::dual_write::{DualWriteAuthorRepository, DualWriteUnitOfWork};
+use hexarch_example::outbound::events::{
+    BroadcastEventPublisher, EventPublisherConfig, connect_event_publisher,
+};
+use hexarch_example::outbound::flags::FileFeatureFlags;
+use hexarch_example::outbound::identity::connect_identity_provider;
+use hexarch_example::outbound::ids::new_id_generator;
+use hexarch_example::outbound::instrumented::InstrumentedAuthorRepository;
+use hexarch_example::outbound::memory::InMemoryRepository;
+use hexarch_example::outbound::replicas::{ReplicaConfig, ReplicatedAuthorRepository};
+use hexarch_example::outbound::retry::{RetryConfig, RetryingAuthorRepository, RetryingUnitOfWork};
+use hexarch_example::outbound::sqlite::{
+    Backups, ConnectRetryConfig, DefaultAuditRecorder, DefaultAuthorRepository, DefaultCommandLog,
+    DefaultGenreRepository, DefaultPublisherRepository, DefaultSessionStore, DefaultUnitOfWork,
+    Migrations, PoolConfig, Retention, WalCheckpointJob, WriteQueue, establish_pool,
+};
+use hexarch_example::outbound::timeout::TimeoutAuthorRepository;
+use hexarch_example::preflight::{
+    Preflight, check_migrations, check_port_available, check_tls_certificate, check_writable_dir,
+};
+use hexarch_example::seed;
+use hexarch_example::verification::{
+    EmailVerificationConfig, EmailVerificationJob, connect_email_verifier,
+};
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let config = Config::from_env()?;
+    let config = Config::from_env().await?;
 
-    tracing_subscriber::fmt::init();
+    let logging_config =
+        LoggingConfig::new(config.log_format(), config.log_redact_fields().to_vec())
+            .with_query_sample_rate(config.query_log_sample_rate());
+    let log_filter = logging::init(&logging_config)?;
 
-    let pool = establish_pool(config.database_url()).await?;
-    let repo = DefaultAuthorRepository::new(pool);
-    let state = AppState::new(repo);
+    let retry_config = ConnectRetryConfig::new(
+        config.database_retry_initial_backoff(),
+        config.database_retry_max_backoff(),
+        config.database_retry_max_wait(),
+    );
+    let pool_config = PoolConfig::new(
+        config.database_min_connections(),
+        config.database_max_connections(),
+        config.database_acquire_timeout(),
+    )
+    .with_statement_cache_capacity(config.database_statement_cache_capacity())
+    .with_auto_migrate(config.database_auto_migrate())
+    .with_busy_timeout(config.database_busy_timeout())
+    .with_synchronous(config.database_synchronous())
+    .with_cache_size(config.database_cache_size())
+    .with_mmap_size(config.database_mmap_size())
+    .with_auto_vacuum(config.database_auto_vacuum())
+    .with_query_log(config.query_log_level(), config.query_log_slow_threshold());
+    let pool = establish_pool(config.database_url(), &retry_config, &pool_config).await;
+    let tls_config = match (config.server_tls_cert_path(), config.server_tls_key_path()) {
+        (Some(cert), Some(key)) => Some(TlsConfig::new(cert.to_path_buf(), key.to_path_buf())),
+        (None, None) => None,
+        _ => anyhow::bail!("SERVER_TLS_CERT_PATH and SERVER_TLS_KEY_PATH must be set together"),
+    };
 
-    let server_config = HttpServerConfig::new(config.server_port());
-    let http_server = HttpServer::new(state, server_config).await?;
+    let mut preflight = Preflight::new();
+    preflight.check("server port", check_port_available(config.server_port()));
+    preflight.check(
+        "database",
+        match &pool {
+            Ok(pool) => check_migrations(&Migrations::new(pool.clone())).await,
+            Err(err) => Err(anyhow::anyhow!("{err:#}")),
+        },
+    );
+    if config.blob_backend() == BlobBackend::Local {
+        preflight.check("blob storage", check_writable_dir(config.blob_path()).await);
+    }
+    if let Some(tls) = &tls_config {
+        preflight.check("tls certificate", check_tls_certificate(tls, Utc::now()));
+    }
+    preflight.finish()?;
+    let pool = pool?;
+    let write_queue = config
+        .database_write_queue_capacity()
+        .map(WriteQueue::spawn);
+    let cipher = field_cipher(config.email_encryption());
+    let mut primary = DefaultAuthorRepository::new(pool.clone(), config.author_id_strategy())
+        .with_cipher(Arc::clone(&cipher));
+    let mut uow = DefaultUnitOfWork::new(pool.clone(), config.author_id_strategy())
+        .with_cipher(Arc::clone(&cipher));
+    if let Some(writes) = write_queue {
+        primary = primary.with_write_queue(writes.clone());
+        uow = uow.with_write_queue(writes);
+    }
+    // Emails stored before they were normalized would be missed by lookups and the unique check.
+    let normalized = primary.normalize_emails().await?;
+    if normalized > 0 {
+        tracing::info!("Normalized {normalized} author emails");
+    }
+    // Emails stored before encryption was configured are indexed by their plaintext, which
+    // lookups by blind index and the unique check would miss.
+    if config.email_encryption().is_some() {
+        let rewritten = primary.reencrypt_emails().await?;
+        tracing::info!("Re-encrypted {rewritten} author and audit emails with the current key");
+    }
+    let replica_config = ReplicaConfig::new(
+        config.database_replica_selection(),
+        config.database_replica_max_lag(),
+        config.database_replica_health_check_interval(),
+    );
+    let mut repo = ReplicatedAuthorRepository::new(
+        primary,
+        DefaultAuditRecorder::new(pool.clone()).with_cipher(Arc::clone(&cipher)),
+        replica_config,
+    );
+    let replica_pool_config = pool_config.clone().with_auto_migrate(false);
+    for url in config.database_replica_urls() {
+        let replica = establish_pool(url, &retry_config, &replica_pool_config).await?;
+        repo = repo.with_replica(
+            DefaultAuthorRepository::new(replica.clone(), config.author_id_strategy())
+                .with_cipher(Arc::clone(&cipher)),
+            DefaultAuditRecorder::new(replica).with_cipher(Arc::clone(&cipher)),
+        );
+    }
+    if !config.database_replica_urls().is_empty() {
+        tokio::spawn(repo.clone().run_health_checks());
+    }
+    let (repo, uow) = match config.database_shadow_url() {
+        Some(url) => {
+            let shadow_pool = establish_pool(url, &retry_config, &pool_config).await?;
+            let shadow =
+                DefaultAuthorRepository::new(shadow_pool.clone(), config.author_id_strategy())
+                    .with_cipher(Arc::clone(&cipher));
+            if config.email_encryption().is_some() {
+                let rewritten = shadow.reencrypt_emails().await?;
+                tracing::info!("Re-encrypted {rewritten} shadow author and audit emails");
+            }
+            tracing::info!("Mirroring author writes to a shadow database");
+            let shadow_authors =
+                DefaultAuthorRepository::new(shadow_pool, config.author_id_strategy())
+                    .with_cipher(Arc::clone(&cipher));
+            let uow: Box<dyn DynUnitOfWork> =
+                Box::new(DualWriteUnitOfWork::new(uow, shadow_authors));
+            let repo = BoxedAuthorRepository::new(
+                DualWriteAuthorRepository::new(repo, shadow)
+                    .with_read_comparison(config.database_shadow_compare_reads()),
+            );
+            (repo, uow)
+        }
+        None => {
+            let uow: Box<dyn DynUnitOfWork> = Box::new(uow);
+            (BoxedAuthorRepository::new(repo), uow)
+        }
+    };
+    if let Some(interval) = config.database_wal_checkpoint_interval() {
+        let job = WalCheckpointJob::new(
+            pool.clone(),
+            interval,
+            config.database_wal_checkpoint_mode(),
+        );
+        tokio::spawn(job.run());
+    }
+    let breaker_config = CircuitBreakerConfig::new(
+        config.database_breaker_failure_threshold(),
+        config.database_breaker_cooldown(),
+    );
+    let retry_policy = RetryConfig::new(
+        config.repository_retry_max_attempts(),
+        config.repository_retry_initial_backoff(),
+        config.repository_retry_max_backoff(),
+    );
+    let breaker = CircuitBreaker::new(
+        TimeoutAuthorRepository::new(
+            RetryingAuthorRepository::new(repo, retry_policy.clone()),
+            config.repository_operation_timeout(),
+        ),
+        breaker_config,
+    );
+    let uow = InstrumentedAuthorRepository::new(breaker.share(TimeoutAuthorRepository::new(
+        RetryingUnitOfWork::new(uow, retry_policy),
+        config.repository_operation_timeout(),
+    )))
+    .with_slow_threshold(config.repository_slow_operation_threshold());
+    let repo = InstrumentedAuthorRepository::new(breaker)
+        .with_slow_threshold(config.repository_slow_operation_threshold());
+    if config.app_env() == AppEnv::Development {
+        let authors = seed::load_seed(config.seed_path())?;
+        if let Some(report) = seed::seed_if_empty(&repo, &authors).await? {
+            tracing::info!(created = report.created(), "Seeded sample authors");
+        }
+    }
+    let audit = DefaultAuditRecorder::new(pool.clone()).with_cipher(Arc::clone(&cipher));
+    let genres = DefaultGenreRepository::new(pool.clone()).with_cipher(cipher);
+    let publishers = DefaultPublisherRepository::new(pool.clone());
+    let migrations = Migrations::new(pool.clone());
+    let backups = Backups::new(pool.clone());
+    let retention = Retention::new(pool.clone(), *config.retention_policy());
+
+    let event_config = EventPublisherConfig::new(
+        config.event_brokers().to_vec(),
+        config.event_topic_prefix().to_string(),
+        config.event_username().map(str::to_string),
+        config.event_password().map(str::to_string),
+    );
+    let events = connect_event_publisher(config.event_backend(), event_config.clone()).await?;
+    let events = BroadcastEventPublisher::new(events);
+    let author_events = events.sender();
+
+    let blob_config = BlobStorageConfig::new(
+        config.blob_path().to_path_buf(),
+        config.blob_bucket().map(str::to_string),
+        config.blob_endpoint().map(str::to_string),
+    );
+    let blobs = connect_blob_storage(config.blob_backend(), blob_config.clone())?;
+
+    let mut service = AuthorService::new(repo, audit, uow, events, blobs, genres, publishers)
+        .with_name_policy(config.name_policy())
+        .with_create_on_missing(config.authors_create_on_missing())
+        .with_stats_ttl(config.authors_stats_ttl())
+        .with_operation_store(InMemoryRepository::new())
+        .with_id_generator(new_id_generator(
+            config.author_id_strategy(),
+            config.author_id_node(),
+        )?);
+    if let Some(url) = config.book_catalog_url() {
+        let catalog_config = BookCatalogConfig::new(
+            url.clone(),
+            config.book_catalog_timeout(),
+            RetryConfig::new(
+                config.book_catalog_max_attempts(),
+                config.repository_retry_initial_backoff(),
+                config.repository_retry_max_backoff(),
+            ),
+            CircuitBreakerConfig::new(
+                config.book_catalog_breaker_failure_threshold(),
+                config.book_catalog_breaker_cooldown(),
+            ),
+        );
+        let catalog = connect_book_catalog(catalog_config)?;
+        service = service.with_book_catalog(catalog, config.book_catalog_cache_ttl());
+    }
+    let email_verifier = connect_email_verifier(config.email_verifier())?;
+    let verifies_emails = email_verifier.is_some();
+    if let Some(verifier) = email_verifier {
+        service = service.with_email_verifier(verifier);
+    }
+
+    if config.commands_enabled() {
+        let queue = connect_command_queue(
+            &event_config,
+            config.commands_stream(),
+            config.commands_subject(),
+        )
+        .await?;
+        let consumer_config = CommandConsumerConfig::new(
+            config.commands_max_attempts(),
+            config.commands_redelivery_delay(),
+        );
+        let consumer = CommandConsumer::new(
+            service.clone(),
+            queue,
+            DefaultCommandLog::new(pool.clone()),
+            consumer_config,
+        );
+        tokio::spawn(async move {
+            if let Err(err) = consumer.run().await {
+                tracing::error!("Author command consumer stopped: {err:?}");
+            }
+        });
+    }
+
+    if let Some(interval) = config.backup_interval() {
+        let job = BackupJob::new(
+            backups.clone(),
+            connect_blob_storage(config.blob_backend(), blob_config)?,
+            BackupScheduleConfig::new(interval, config.backup_retention()),
+        );
+        tokio::spawn(async move {
+            if let Err(err) = job.run().await {
+                tracing::error!("Database backup job stopped: {err:?}");
+            }
+        });
+    }
+
+    if let Some(interval) = config.retention_interval() {
+        tokio::spawn(RetentionJob::new(retention.clone(), interval).run());
+    }
+
+    if verifies_emails {
+        let job = EmailVerificationJob::new(
+            service.clone(),
+            EmailVerificationConfig::new(
+                config.email_verification_interval(),
+                config.email_verification_batch_size(),
+            ),
+        );
+        tokio::spawn(job.run());
+    }
+
+    let mut state = AppState::new(service)
+        .with_author_events(author_events.clone())
+        .with_admin_token(config.admin_token().map(Into::into))
+        .with_log_filter(log_filter)
+        .with_migrations(migrations)
+        .with_backups(backups)
+        .with_retention(retention)
+        .with_assets(Assets::load(config.assets_dir())?)
+        .with_runtime_metrics(RuntimeMetrics::new().with_pool(pool.clone()));
+    if let Some(oidc) = config.oidc() {
+        let provider = connect_identity_provider(oidc)?;
+        let store = Box::new(DefaultSessionStore::new(pool.clone()));
+        let sessions = AdminSessions::new(provider, store, oidc.clone())
+            .with_cookies(config.admin_cookies())
+            .with_ttl(config.admin_session_ttl());
+        state = state.with_admin_sessions(sessions);
+    }
+    if let Some(path) = config.feature_flags_path() {
+        let flags = FileFeatureFlags::load(path.to_path_buf())
+            .await?
+            .with_check_interval(config.feature_flags_check_interval());
+        state = state.with_feature_flags(flags);
+    }
+    if let Some(guard) = config.abuse_guard() {
+        state = state.with_abuse_guard(guard.clone());
+    }
+
+    let cache_control = CacheControlConfig::new(
+        config.cache_control_authors().clone(),
+        config.cache_control_author().clone(),
+        config.cache_control_audit_log().clone(),
+        config.cache_control_avatar().clone(),
+    )
+    .with_response_cache(config.response_cache().clone());
+    if config.response_cache().is_enabled() {
+        let invalidations = author_events.subscribe();
+        tokio::spawn(config.response_cache().clone().invalidate_on(invalidations));
+    }
+    let server_config = HttpServerConfig::new(config.server_port())
+        .with_http2(config.server_http2())
+        .with_keep_alive(
+            config.server_keep_alive(),
+            config.server_keep_alive_timeout(),
+        )
+        .with_http2_keep_alive_interval(config.server_http2_keep_alive_interval())
+        .with_http2_max_concurrent_streams(config.server_http2_max_concurrent_streams())
+        .with_tcp_nodelay(config.server_tcp_nodelay())
+        .with_tcp_backlog(config.server_tcp_backlog())
+        .with_shutdown_timeout(config.server_shutdown_timeout())
+        .with_request_timeout(config.server_request_timeout())
+        .with_default_locale(config.default_locale())
+        .with_api_deprecation(ApiDeprecation::new(
+            config.api_v1_deprecated_at(),
+            config.api_v1_sunset_at(),
+        ))
+        .with_cache_control(cache_control)
+        .with_path_normalization(
+            PathNormalization::new(config.server_path_normalization())
+                .with_lowercase(config.server_lowercase_paths()),
+        )
+        .with_security_headers(config.security_headers().clone())
+        .with_request_signing(config.request_signing().clone())
+        .with_tls(tls_config);
+    #[cfg(feature = "serverless")]
+    if hexarch_example::inbound::serverless::is_lambda() {
+        return hexarch_example::inbound::serverless::run_lambda(state, &server_config).await;
+    }
+    let http_server = HttpServer::builder(state, server_config).build().await?;
     http_server.run().await
 }
@@ -0,0 +1,30 @@
+pub mod blobs;
+pub mod breaker;
+pub mod catalog;
+pub mod cipher;
+#[cfg(feature = "dns")]
+pub mod dns;
+pub mod dual_write;
+pub mod events;
+pub mod flags;
+pub mod identity;
+pub mod ids;
+pub mod instrumented;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod memory;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "oidc")]
+pub mod oidc;
+#[cfg(feature = "openlibrary")]
+pub mod openlibrary;
+pub mod replicas;
+pub mod retry;
+#[cfg(feature = "s3")]
+pub mod s3;
+pub mod sqlite;
+pub mod timeout;
+pub mod transfer;
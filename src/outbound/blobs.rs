@@ -0,0 +1,151 @@
+use crate::domain::model::{Blob, DeleteBlobError, GetBlobError, PutBlobError};
+use crate::domain::ports::{BlobStorage, DynBlobStorage};
+use anyhow::Context;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlobBackend {
+    Local,
+    S3,
+}
+
+impl FromStr for BlobBackend {
+    type Err = BlobBackendError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Self::Local),
+            "s3" => Ok(Self::S3),
+            _ => Err(BlobBackendError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid blob storage backend, expected one of "local" or "s3""#)]
+pub struct BlobBackendError(String);
+
+#[derive(Debug, Clone)]
+pub struct BlobStorageConfig {
+    path: PathBuf,
+    bucket: Option<String>,
+    endpoint: Option<String>,
+}
+
+impl BlobStorageConfig {
+    #[must_use]
+    pub const fn new(path: PathBuf, bucket: Option<String>, endpoint: Option<String>) -> Self {
+        Self {
+            path,
+            bucket,
+            endpoint,
+        }
+    }
+
+    #[must_use]
+    pub fn bucket(&self) -> Option<&str> {
+        self.bucket.as_deref()
+    }
+
+    #[must_use]
+    pub fn endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalBlobStorage {
+    root: PathBuf,
+}
+
+impl LocalBlobStorage {
+    #[must_use]
+    pub const fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+        let data = self.root.join(key);
+        let meta = self.root.join(format!("{key}.content-type"));
+        (data, meta)
+    }
+}
+
+impl BlobStorage for LocalBlobStorage {
+    async fn put(&self, key: &str, blob: &Blob) -> Result<(), PutBlobError> {
+        let (data, meta) = self.paths(key);
+        if let Some(parent) = data.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+
+        let tmp = data.with_extension("tmp");
+        tokio::fs::write(&tmp, blob.bytes())
+            .await
+            .with_context(|| format!("Failed to write blob to {}", tmp.display()))?;
+        tokio::fs::write(&meta, blob.content_type())
+            .await
+            .with_context(|| format!("Failed to write blob metadata to {}", meta.display()))?;
+        tokio::fs::rename(&tmp, &data)
+            .await
+            .with_context(|| format!("Failed to move blob into place at {}", data.display()))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, GetBlobError> {
+        let (data, meta) = self.paths(key);
+        let bytes = match tokio::fs::read(&data).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                return Err(GetBlobError::NotFound { key: key.into() });
+            }
+            Err(err) => {
+                return Err(anyhow::Error::from(err)
+                    .context(format!("Failed to read blob from {}", data.display()))
+                    .into());
+            }
+        };
+        let content_type = tokio::fs::read_to_string(&meta)
+            .await
+            .with_context(|| format!("Failed to read blob metadata from {}", meta.display()))?;
+
+        Ok(Blob::new(content_type, bytes))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DeleteBlobError> {
+        let (data, meta) = self.paths(key);
+        for path in [data, meta] {
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(anyhow::Error::from(err)
+                        .context(format!("Failed to remove blob at {}", path.display()))
+                        .into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn connect_blob_storage(
+    backend: BlobBackend,
+    config: BlobStorageConfig,
+) -> anyhow::Result<Box<dyn DynBlobStorage>> {
+    match backend {
+        BlobBackend::Local => Ok(Box::new(LocalBlobStorage::new(config.path))),
+        #[cfg(feature = "s3")]
+        BlobBackend::S3 => {
+            let storage = crate::outbound::s3::S3BlobStorage::new(&config)?;
+            Ok(Box::new(storage))
+        }
+        #[cfg(not(feature = "s3"))]
+        BlobBackend::S3 => anyhow::bail!("Blob backend {backend:?} is not enabled in this build"),
+    }
+}
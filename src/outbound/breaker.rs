@@ -0,0 +1,522 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, ExternalWork, FindAllAuthorsError,
+    FindAuthorByEmailError, FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest,
+    FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest, FindExternalWorksError,
+    FindProjectedAuthorsRequest, FindSortedAuthorsRequest, ProjectedAuthor, RemoveAuthorAliasError,
+    RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor,
+    SearchAuthorsRequest, SetAuthorStatusRequest, SetEmailVerificationError,
+    SetEmailVerificationRequest, UnavailableError, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuthorRepository, BookCatalogClient, DynAuditRecorder, DynAuthorRepository, DynGenreRepository,
+    DynPublisherRepository, Transaction, TransactionAuthors, UnitOfWork,
+};
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl CircuitBreakerConfig {
+    #[must_use]
+    pub const fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreakerState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    /// A single trial call is in flight; others are rejected until it reports back, or until a
+    /// cooldown passes in case the trial was cancelled.
+    HalfOpen {
+        since: Instant,
+    },
+}
+
+/// Fails fast with [`UnavailableError`] once the wrapped repository has returned too many
+/// unexpected errors in a row, giving a struggling database room to recover.
+#[derive(Debug)]
+pub struct CircuitBreaker<R> {
+    inner: R,
+    config: CircuitBreakerConfig,
+    state: Arc<Mutex<BreakerState>>,
+    name: &'static str,
+}
+
+impl<R> CircuitBreaker<R> {
+    pub fn new(inner: R, config: CircuitBreakerConfig) -> Self {
+        Self {
+            inner,
+            config,
+            state: Arc::new(Mutex::new(BreakerState::Closed { failures: 0 })),
+            name: "author repository",
+        }
+    }
+
+    /// Guards `inner` with this breaker's state, so failures through either count towards
+    /// opening both. Used to guard a unit of work with the same breaker as its repository.
+    #[must_use]
+    pub fn share<S>(&self, inner: S) -> CircuitBreaker<S> {
+        CircuitBreaker {
+            inner,
+            config: self.config.clone(),
+            state: Arc::clone(&self.state),
+            name: self.name,
+        }
+    }
+
+    /// Names the guarded dependency in logs.
+    #[must_use]
+    pub fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        !matches!(*self.lock(), BreakerState::Closed { .. })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn permit(&self) -> anyhow::Result<()> {
+        let mut state = self.lock();
+        let now = Instant::now();
+        match *state {
+            BreakerState::Closed { .. } => Ok(()),
+            BreakerState::Open { until } if now >= until => {
+                *state = BreakerState::HalfOpen { since: now };
+                Ok(())
+            }
+            BreakerState::HalfOpen { since } if now >= since + self.config.cooldown => {
+                *state = BreakerState::HalfOpen { since: now };
+                Ok(())
+            }
+            BreakerState::Open { until } => Err(UnavailableError::new(until - now).into()),
+            BreakerState::HalfOpen { since } => {
+                let retry_after = (since + self.config.cooldown).saturating_duration_since(now);
+                Err(UnavailableError::new(retry_after).into())
+            }
+        }
+    }
+
+    fn record(&self, failed: bool) {
+        let mut state = self.lock();
+        *state = match (*state, failed) {
+            (_, false) => BreakerState::Closed { failures: 0 },
+            (BreakerState::Closed { failures }, true)
+                if failures + 1 < self.config.failure_threshold =>
+            {
+                BreakerState::Closed {
+                    failures: failures + 1,
+                }
+            }
+            (_, true) => {
+                tracing::warn!(
+                    cooldown_secs = self.config.cooldown.as_secs(),
+                    "Opening the {} circuit breaker",
+                    self.name
+                );
+                BreakerState::Open {
+                    until: Instant::now() + self.config.cooldown,
+                }
+            }
+        };
+    }
+}
+
+impl<R: AuthorRepository> AuthorRepository for CircuitBreaker<R> {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.permit()?;
+        let result = self.inner.create_author(req).await;
+        self.record(matches!(&result, Err(CreateAuthorError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.permit()?;
+        let result = self.inner.find_author(req).await;
+        self.record(matches!(result, Err(FindAuthorError::Other(_))));
+        result
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.permit()?;
+        let result = self.inner.find_author_by_email(req).await;
+        self.record(matches!(result, Err(FindAuthorByEmailError::Other(_))));
+        result
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.find_all_authors().await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.find_authors_by_ids(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.find_sorted_authors(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.find_projected_authors(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    /// Only the permit is checked: errors part-way through a stream are left to the caller.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        match self.permit() {
+            Ok(()) => self.inner.stream_all_authors().await,
+            Err(err) => futures::stream::once(async { Err(FindAllAuthorsError(err)) }).boxed(),
+        }
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.count_authors().await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.permit()?;
+        let result = self.inner.author_exists(req).await;
+        self.record(matches!(result, Err(FindAuthorError::Other(_))));
+        result
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.permit()?;
+        let result = self.inner.update_author(req).await;
+        self.record(matches!(&result, Err(UpdateAuthorError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.permit()?;
+        let result = self.inner.upsert_author(req).await;
+        self.record(matches!(&result, Err(ReplaceAuthorError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.permit()?;
+        let result = self.inner.upsert_author_by_email(req).await;
+        self.record(matches!(&result, Err(UpsertAuthorError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.permit()?;
+        let result = self.inner.set_author_status(req).await;
+        self.record(matches!(&result, Err(ChangeAuthorStatusError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.permit()?;
+        let result = self.inner.delete_author(req).await;
+        self.record(matches!(&result, Err(DeleteAuthorError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.permit()?;
+        let result = self.inner.add_author_alias(req).await;
+        self.record(matches!(&result, Err(AddAuthorAliasError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.permit()?;
+        let result = self.inner.remove_author_alias(req).await;
+        self.record(matches!(&result, Err(RemoveAuthorAliasError::Other(err)) if !is_shed(err)));
+        result
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.permit()?;
+        let result = self.inner.find_author_aliases(req).await;
+        self.record(matches!(result, Err(FindAuthorError::Other(_))));
+        result
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.search_authors(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.author_stats(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.permit()?;
+        let result = self.inner.find_authors_by_verification(req).await;
+        self.record(result.is_err());
+        result
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.permit()?;
+        let result = self.inner.set_email_verification(req).await;
+        self.record(matches!(&result, Err(SetEmailVerificationError(err)) if !is_shed(err)));
+        result
+    }
+}
+
+impl<U: UnitOfWork> UnitOfWork for CircuitBreaker<U> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        self.permit()?;
+        let tx = self.inner.begin().await;
+        self.record(matches!(&tx, Err(err) if !is_shed(err)));
+        Ok(Box::new(self.share(TransactionAuthors::new(tx?))))
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        self.inner.retry_delay(attempt, err)
+    }
+}
+
+/// Guards the transaction's authors and its commit, like the repository it shares a breaker
+/// with. Genres, the audit log and publishers are passed through.
+impl Transaction for CircuitBreaker<TransactionAuthors> {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self.inner.transaction().genres()
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self.inner.transaction().audit()
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self.inner.transaction().publishers()
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        let breaker = self.share(());
+        let commit = self.inner.into_inner().commit();
+        Box::pin(async move {
+            let result = commit.await;
+            breaker.record(matches!(&result, Err(err) if !is_shed(err)));
+            result
+        })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        self.inner.into_inner().rollback()
+    }
+}
+
+/// Writes refused by a full write queue say nothing about the database's health.
+fn is_shed(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<UnavailableError>().is_some()
+}
+
+impl<C: BookCatalogClient> BookCatalogClient for CircuitBreaker<C> {
+    async fn find_works(
+        &self,
+        author: &AuthorName,
+    ) -> Result<Vec<ExternalWork>, FindExternalWorksError> {
+        self.permit()
+            .map_err(|err| match err.downcast::<UnavailableError>() {
+                Ok(err) => FindExternalWorksError::Unavailable {
+                    retry_after: err.retry_after(),
+                },
+                Err(err) => err.into(),
+            })?;
+        let result = self.inner.find_works(author).await;
+        self.record(matches!(
+            result,
+            Err(FindExternalWorksError::Other(_) | FindExternalWorksError::TimedOut)
+        ));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AuthorId, AuthorName, CreateAuthorError, CreateAuthorRequest, EmailAddress,
+        FindAllAuthorsError, FindAuthorError, FindAuthorRequest, UnavailableError,
+    };
+    use crate::domain::ports::{AuthorRepository, UnitOfWork};
+    use crate::outbound::breaker::{CircuitBreaker, CircuitBreakerConfig};
+    use crate::outbound::mock::MockAuthorRepository;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn opens_after_consecutive_failures_and_fails_fast() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_count()
+            .returning(|()| Err(FindAllAuthorsError(anyhow::anyhow!("database is down"))))
+            .times(2);
+        let breaker = CircuitBreaker::new(
+            repo.clone(),
+            CircuitBreakerConfig::new(2, Duration::from_secs(30)),
+        );
+
+        for _ in 0..2 {
+            assert!(breaker.count_authors().await.is_err());
+        }
+        assert!(breaker.is_open());
+
+        let err = breaker.count_authors().await.unwrap_err();
+        let unavailable = err.0.downcast_ref::<UnavailableError>();
+        assert!(
+            unavailable.is_some_and(|err| err.retry_after() <= Duration::from_secs(30)),
+            "expected the breaker to fail fast, but got {err:?}"
+        );
+        repo.verify();
+    }
+
+    #[tokio::test]
+    async fn half_opens_after_the_cooldown_and_closes_on_success() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_count()
+            .returning(|()| Err(FindAllAuthorsError(anyhow::anyhow!("database is down"))))
+            .times(1);
+        let breaker = CircuitBreaker::new(
+            repo.clone(),
+            CircuitBreakerConfig::new(1, Duration::from_millis(20)),
+        );
+        assert!(breaker.count_authors().await.is_err());
+        assert!(breaker.is_open());
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        repo.expect_count().returning(|()| Ok(3)).times(1);
+        assert_eq!(3, breaker.count_authors().await.unwrap());
+        assert!(!breaker.is_open());
+    }
+
+    #[tokio::test]
+    async fn expected_errors_do_not_trip_the_breaker() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find()
+            .returning(|req| Err(FindAuthorError::NotFound { id: req.id() }));
+        let breaker =
+            CircuitBreaker::new(repo, CircuitBreakerConfig::new(1, Duration::from_secs(30)));
+
+        let req = FindAuthorRequest::new(AuthorId::new(1));
+        for _ in 0..3 {
+            let result = breaker.find_author(&req).await;
+            assert!(matches!(result, Err(FindAuthorError::NotFound { .. })));
+        }
+        assert!(!breaker.is_open());
+    }
+
+    #[tokio::test]
+    async fn transactions_share_the_breaker_of_the_repository() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_create()
+            .returning(|_| {
+                Err(CreateAuthorError::Other(anyhow::anyhow!(
+                    "database is down"
+                )))
+            })
+            .times(1);
+        let breaker = CircuitBreaker::new(
+            repo.clone(),
+            CircuitBreakerConfig::new(1, Duration::from_secs(30)),
+        );
+        let uow = breaker.share(repo.clone());
+
+        let tx = uow.begin().await.unwrap();
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        assert!(tx.authors().create_author(&req).await.is_err());
+        tx.rollback().await.unwrap();
+        assert!(breaker.is_open());
+
+        let begin = uow.begin().await;
+        assert!(begin.is_err_and(|err| err.downcast_ref::<UnavailableError>().is_some()));
+        repo.verify();
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::ports::DynBookCatalogClient;
+use crate::outbound::breaker::CircuitBreakerConfig;
+use crate::outbound::retry::RetryConfig;
+use std::time::Duration;
+use url::Url;
+
+#[derive(Debug, Clone)]
+pub struct BookCatalogConfig {
+    base_url: Url,
+    timeout: Duration,
+    retry: RetryConfig,
+    breaker: CircuitBreakerConfig,
+    max_works: u32,
+}
+
+impl BookCatalogConfig {
+    #[must_use]
+    pub const fn new(
+        base_url: Url,
+        timeout: Duration,
+        retry: RetryConfig,
+        breaker: CircuitBreakerConfig,
+    ) -> Self {
+        Self {
+            base_url,
+            timeout,
+            retry,
+            breaker,
+            max_works: 20,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_max_works(mut self, max_works: u32) -> Self {
+        self.max_works = max_works;
+        self
+    }
+
+    #[must_use]
+    pub const fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Bounds each attempt; the request deadline can only shorten it.
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    #[must_use]
+    pub const fn retry(&self) -> &RetryConfig {
+        &self.retry
+    }
+
+    #[must_use]
+    pub const fn breaker(&self) -> &CircuitBreakerConfig {
+        &self.breaker
+    }
+
+    #[must_use]
+    pub const fn max_works(&self) -> u32 {
+        self.max_works
+    }
+}
+
+/// Connects the OpenLibrary-compatible catalog at `base_url`, guarded by a circuit breaker.
+pub fn connect_book_catalog(
+    config: BookCatalogConfig,
+) -> anyhow::Result<Box<dyn DynBookCatalogClient>> {
+    #[cfg(feature = "openlibrary")]
+    {
+        use crate::outbound::breaker::CircuitBreaker;
+        let breaker = config.breaker().clone();
+        let client = crate::outbound::openlibrary::OpenLibraryClient::new(config)?;
+        Ok(Box::new(
+            CircuitBreaker::new(client, breaker).with_name("book catalog"),
+        ))
+    }
+    #[cfg(not(feature = "openlibrary"))]
+    {
+        let _ = config;
+        anyhow::bail!("The book catalog client is not enabled in this build")
+    }
+}
@@ -0,0 +1,257 @@
+use crate::domain::model::CipherError;
+use crate::domain::ports::FieldCipher;
+use crate::secrets::Secrets;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{Context, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+
+const CIPHERTEXT_PREFIX: &str = "aes256gcm:";
+const NONCE_LEN: usize = 12;
+
+/// Stores values as they are; the blind index is the value itself. Used when no encryption keys
+/// are configured, which keeps databases written before encryption readable.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlaintextCipher;
+
+impl FieldCipher for PlaintextCipher {
+    fn encrypt(&self, plaintext: &str) -> Result<String, CipherError> {
+        Ok(plaintext.to_string())
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String, CipherError> {
+        if ciphertext.starts_with(CIPHERTEXT_PREFIX) {
+            return Err(
+                anyhow!("Value is encrypted, but no encryption keys are configured").into(),
+            );
+        }
+        Ok(ciphertext.to_string())
+    }
+
+    fn blind_index(&self, plaintext: &str) -> String {
+        plaintext.to_string()
+    }
+
+    fn needs_rotation(&self, _: &str) -> bool {
+        false
+    }
+}
+
+/// Encryption keys, current one first, and the key the blind index is computed with. The index
+/// key never rotates: changing it would change every index.
+#[derive(Clone)]
+pub struct FieldCipherKeys {
+    keys: Vec<(String, [u8; 32])>,
+    index_key: Vec<u8>,
+}
+
+impl FieldCipherKeys {
+    /// Parses `keys` as comma-separated `<id>:<base64 of 32 bytes>` pairs and `index_key` as base64.
+    pub fn parse(keys: &str, index_key: &str) -> anyhow::Result<Self> {
+        let keys = keys
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(|key| {
+                let (id, encoded) = key
+                    .split_once(':')
+                    .context("Encryption keys must be written as <id>:<base64 key>")?;
+                anyhow::ensure!(!id.is_empty(), "Encryption key ids must not be empty");
+                let key = STANDARD
+                    .decode(encoded)
+                    .ok()
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .with_context(|| format!(r#"Encryption key "{id}" must be 32 base64 bytes"#))?;
+                Ok((id.to_string(), key))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        anyhow::ensure!(!keys.is_empty(), "At least one encryption key is required");
+        let index_key = STANDARD
+            .decode(index_key.trim())
+            .context("The blind index key must be base64")?;
+        anyhow::ensure!(
+            index_key.len() >= 32,
+            "The blind index key must be at least 32 bytes"
+        );
+        Ok(Self { keys, index_key })
+    }
+
+    /// Reads `EMAIL_ENCRYPTION_KEYS` and `EMAIL_BLIND_INDEX_KEY`; neither set means no encryption.
+    pub async fn from_secrets(secrets: &Secrets) -> anyhow::Result<Option<Self>> {
+        let Some(keys) = secrets.get("EMAIL_ENCRYPTION_KEYS").await? else {
+            return Ok(None);
+        };
+        let index_key = secrets.require("EMAIL_BLIND_INDEX_KEY").await?;
+        Self::parse(&keys, &index_key)
+            .context("Failed to load EMAIL_ENCRYPTION_KEYS")
+            .map(Some)
+    }
+
+    #[must_use]
+    pub fn current_key_id(&self) -> &str {
+        &self.keys[0].0
+    }
+}
+
+impl std::fmt::Debug for FieldCipherKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids: Vec<_> = self.keys.iter().map(|(id, _)| id).collect();
+        f.debug_struct("FieldCipherKeys")
+            .field("keys", &ids)
+            .finish_non_exhaustive()
+    }
+}
+
+/// AES-256-GCM with a random nonce per value, stored as `aes256gcm:<key id>:<base64 nonce and
+/// ciphertext>`. Values without the prefix were written before encryption and are returned as
+/// they are, so existing databases keep working until they are re-encrypted.
+pub struct AesGcmCipher {
+    keys: Vec<(String, Aes256Gcm)>,
+    index_key: Vec<u8>,
+}
+
+impl AesGcmCipher {
+    #[must_use]
+    pub fn new(keys: &FieldCipherKeys) -> Self {
+        Self {
+            keys: keys
+                .keys
+                .iter()
+                .map(|(id, key)| {
+                    (
+                        id.clone(),
+                        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+                    )
+                })
+                .collect(),
+            index_key: keys.index_key.clone(),
+        }
+    }
+
+    fn key(&self, id: &str) -> Option<&Aes256Gcm> {
+        self.keys
+            .iter()
+            .find_map(|(key_id, key)| (key_id == id).then_some(key))
+    }
+}
+
+impl FieldCipher for AesGcmCipher {
+    fn encrypt(&self, plaintext: &str) -> Result<String, CipherError> {
+        let (id, key) = &self.keys[0];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = key
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow!("Failed to encrypt value"))?;
+        let mut sealed = nonce.to_vec();
+        sealed.extend(ciphertext);
+        Ok(format!(
+            "{CIPHERTEXT_PREFIX}{id}:{}",
+            STANDARD.encode(sealed)
+        ))
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String, CipherError> {
+        let Some(sealed) = ciphertext.strip_prefix(CIPHERTEXT_PREFIX) else {
+            return Ok(ciphertext.to_string());
+        };
+        let (id, encoded) = sealed
+            .split_once(':')
+            .context("Encrypted value has no key id")?;
+        let key = self
+            .key(id)
+            .with_context(|| format!(r#"Encryption key "{id}" is not configured"#))?;
+        let sealed = STANDARD
+            .decode(encoded)
+            .context("Encrypted value is not base64")?;
+        if sealed.len() <= NONCE_LEN {
+            return Err(anyhow!("Encrypted value is truncated").into());
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = key
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!(r#"Failed to decrypt value with key "{id}""#))?;
+        String::from_utf8(plaintext)
+            .context("Decrypted value is not UTF-8")
+            .map_err(CipherError::from)
+    }
+
+    fn blind_index(&self, plaintext: &str) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.index_key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(plaintext.as_bytes());
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+
+    fn needs_rotation(&self, ciphertext: &str) -> bool {
+        let current = format!("{CIPHERTEXT_PREFIX}{}:", self.keys[0].0);
+        !ciphertext.starts_with(&current)
+    }
+}
+
+/// AES-GCM when `keys` are configured, plaintext otherwise.
+#[must_use]
+pub fn field_cipher(keys: Option<&FieldCipherKeys>) -> Arc<dyn FieldCipher> {
+    match keys {
+        Some(keys) => Arc::new(AesGcmCipher::new(keys)),
+        None => Arc::new(PlaintextCipher),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::ports::FieldCipher;
+    use crate::outbound::cipher::{AesGcmCipher, FieldCipherKeys, PlaintextCipher};
+
+    const OLD_KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const NEW_KEY: &str = "ICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6Ozw9Pj8=";
+    const INDEX_KEY: &str = "QEFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaW1xdXl8=";
+
+    #[test]
+    fn values_round_trip_and_rotate_to_the_current_key() {
+        let old = AesGcmCipher::new(
+            &FieldCipherKeys::parse(&format!("k1:{OLD_KEY}"), INDEX_KEY).unwrap(),
+        );
+        let keys =
+            FieldCipherKeys::parse(&format!("k2:{NEW_KEY},k1:{OLD_KEY}"), INDEX_KEY).unwrap();
+        let new = AesGcmCipher::new(&keys);
+        let email = "jrr.tolkien@example.com";
+
+        let first = old.encrypt(email).unwrap();
+        let second = old.encrypt(email).unwrap();
+        assert_ne!(first, second, "expected a fresh nonce per value");
+        assert!(!first.contains(email));
+        assert_eq!(email, new.decrypt(&first).unwrap());
+        assert!(new.needs_rotation(&first));
+        assert!(!new.needs_rotation(&new.encrypt(email).unwrap()));
+        assert!(
+            new.needs_rotation(email),
+            "expected plaintext to need encryption"
+        );
+        assert_eq!(email, new.decrypt(email).unwrap());
+        assert_eq!(old.blind_index(email), new.blind_index(email));
+        assert_ne!(
+            new.blind_index(email),
+            new.blind_index("cs.lewis@example.com")
+        );
+
+        let mut tampered = first.clone();
+        tampered.pop();
+        tampered.push(if first.ends_with('A') { 'B' } else { 'A' });
+        assert!(new.decrypt(&tampered).is_err());
+        assert!(old.decrypt(&new.encrypt(email).unwrap()).is_err());
+        assert!(PlaintextCipher.decrypt(&first).is_err());
+        assert!(!format!("{keys:?}").contains(NEW_KEY));
+    }
+
+    #[test]
+    fn malformed_keys_are_rejected() {
+        assert!(FieldCipherKeys::parse("", INDEX_KEY).is_err());
+        assert!(FieldCipherKeys::parse(NEW_KEY, INDEX_KEY).is_err());
+        assert!(FieldCipherKeys::parse("k1:c2hvcnQ=", INDEX_KEY).is_err());
+        assert!(FieldCipherKeys::parse(&format!("k1:{NEW_KEY}"), "c2hvcnQ=").is_err());
+    }
+}
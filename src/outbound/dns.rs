@@ -0,0 +1,64 @@
+use crate::domain::model::{EmailAddress, EmailVerification, VerifyEmailError};
+use crate::domain::ports::EmailVerifier;
+use anyhow::{Context, anyhow};
+use hickory_resolver::TokioResolver;
+use hickory_resolver::proto::rr::RData;
+
+/// Accepts addresses whose domain can receive mail according to DNS, per RFC 5321 section 5.1:
+/// the domain has MX records, or failing that an address record. A lone null MX (RFC 7505) or a
+/// domain that does not exist is rejected.
+#[derive(Debug)]
+pub struct MxEmailVerifier {
+    resolver: TokioResolver,
+}
+
+impl MxEmailVerifier {
+    /// Uses the system resolver configuration.
+    pub fn new() -> anyhow::Result<Self> {
+        let resolver = TokioResolver::builder_tokio()
+            .context("Failed to read the system DNS configuration")?
+            .build()
+            .context("Failed to build the DNS resolver")?;
+        Ok(Self { resolver })
+    }
+}
+
+impl EmailVerifier for MxEmailVerifier {
+    async fn verify_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<EmailVerification, VerifyEmailError> {
+        let domain = email.domain();
+        if domain.starts_with('[') {
+            return Ok(EmailVerification::Verified);
+        }
+        // A trailing dot keeps the resolver from trying search domains.
+        let fqdn = format!("{domain}.");
+        match self.resolver.mx_lookup(fqdn.as_str()).await {
+            Ok(lookup) => {
+                let accepts_mail = lookup
+                    .answers()
+                    .iter()
+                    .any(|record| matches!(&record.data, RData::MX(mx) if !mx.exchange.is_root()));
+                Ok(if accepts_mail {
+                    EmailVerification::Verified
+                } else {
+                    EmailVerification::Rejected
+                })
+            }
+            Err(err) if err.is_nx_domain() => Ok(EmailVerification::Rejected),
+            Err(err) if err.is_no_records_found() => {
+                match self.resolver.lookup_ip(fqdn.as_str()).await {
+                    Ok(_) => Ok(EmailVerification::Verified),
+                    Err(err) if err.is_no_records_found() => Ok(EmailVerification::Rejected),
+                    Err(err) => Err(anyhow!(err)
+                        .context(format!("Failed to resolve {domain}"))
+                        .into()),
+                }
+            }
+            Err(err) => Err(anyhow!(err)
+                .context(format!("Failed to look up MX records of {domain}"))
+                .into()),
+        }
+    }
+}
@@ -0,0 +1,760 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, FindAllAuthorsError, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+    ProjectedAuthor, RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError,
+    ReplaceAuthorRequest, ReplacedAuthor, SearchAuthorsRequest, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuthorRepository, DynAuditRecorder, DynAuthorRepository, DynGenreRepository,
+    DynPublisherRepository, Transaction, UnitOfWork,
+};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Serves every call from the primary and mirrors successful writes to a secondary backend, so
+/// a new store can be filled and checked before traffic moves to it. Reads are repeated against
+/// the secondary and compared; mismatches and failed mirror writes are logged and counted in
+/// `author_repository_divergences_total`, but never change what callers see. Writes made in
+/// transactions are mirrored by [`DualWriteUnitOfWork`].
+#[derive(Debug)]
+pub struct DualWriteAuthorRepository<A, B> {
+    primary: A,
+    secondary: B,
+    compare_reads: bool,
+}
+
+impl<A: AuthorRepository, B: AuthorRepository> DualWriteAuthorRepository<A, B> {
+    pub const fn new(primary: A, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary,
+            compare_reads: true,
+        }
+    }
+
+    /// Skipping comparisons keeps reads at primary latency while writes are still mirrored.
+    #[must_use]
+    pub const fn with_read_comparison(mut self, compare_reads: bool) -> Self {
+        self.compare_reads = compare_reads;
+        self
+    }
+
+    async fn compare<T: Same, E: Display>(
+        &self,
+        operation: &'static str,
+        primary: &Result<T, E>,
+        secondary: impl Future<Output = Result<T, E>>,
+    ) {
+        if !self.compare_reads {
+            return;
+        }
+        match (primary, secondary.await) {
+            (Ok(primary), Ok(secondary)) if !primary.same(&secondary) => {
+                diverged(operation, "read", "results differ");
+            }
+            (Ok(_), Err(err)) => diverged(operation, "read", err),
+            (Err(err), Ok(_)) => {
+                diverged(operation, "read", format!("only the primary failed: {err}"))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn diverged(operation: &'static str, kind: &'static str, detail: impl Display) {
+    metrics::counter!(
+        "author_repository_divergences_total",
+        "operation" => operation,
+        "kind" => kind
+    )
+    .increment(1);
+    tracing::warn!(
+        operation,
+        kind,
+        "Secondary author repository diverged: {detail}"
+    );
+}
+
+async fn mirror<T, E: Display>(operation: &'static str, write: impl Future<Output = Result<T, E>>) {
+    if let Err(err) = write.await {
+        diverged(operation, "write", err);
+    }
+}
+
+/// Creates and updates are mirrored as an upsert of the primary's result, so the secondary keeps
+/// the primary's ids and does not evaluate preconditions against its own timestamps.
+async fn copy_author(secondary: &impl AuthorRepository, operation: &'static str, author: &Author) {
+    let req = ReplaceAuthorRequest::new(author.id(), author.name().clone(), author.email().clone())
+        .with_profile(author.profile().clone());
+    mirror(operation, secondary.upsert_author(&req)).await;
+}
+
+/// Whether a read returned the same data from both backends.
+trait Same {
+    fn same(&self, other: &Self) -> bool;
+}
+
+/// Timestamps are left out, as each backend stamps its own writes.
+impl Same for Author {
+    fn same(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.email() == other.email()
+            && self.status() == other.status()
+            && self.email_verification() == other.email_verification()
+            && self.profile() == other.profile()
+    }
+}
+
+impl Same for Vec<Author> {
+    fn same(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.same(b))
+    }
+}
+
+/// Projections including timestamps can differ on them alone, as above.
+impl Same for ProjectedAuthor {
+    fn same(&self, other: &Self) -> bool {
+        self.id() == other.id()
+            && self.name() == other.name()
+            && self.email() == other.email()
+            && self.status() == other.status()
+            && self.is_verified() == other.is_verified()
+            && self.profile() == other.profile()
+    }
+}
+
+impl Same for Vec<ProjectedAuthor> {
+    fn same(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.same(b))
+    }
+}
+
+impl Same for Vec<AuthorName> {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Same for AuthorStats {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Same for u64 {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl Same for bool {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<A: AuthorRepository, B: AuthorRepository> AuthorRepository
+    for DualWriteAuthorRepository<A, B>
+{
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        let author = self.primary.create_author(req).await?;
+        copy_author(&self.secondary, "create_author", &author).await;
+        Ok(author)
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        let result = self.primary.find_author(req).await;
+        self.compare("find_author", &result, self.secondary.find_author(req))
+            .await;
+        result
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        let result = self.primary.find_author_by_email(req).await;
+        let secondary = self.secondary.find_author_by_email(req);
+        self.compare("find_author_by_email", &result, secondary)
+            .await;
+        result
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let result = self.primary.find_all_authors().await;
+        let secondary = self.secondary.find_all_authors();
+        self.compare("find_all_authors", &result, secondary).await;
+        result
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let result = self.primary.find_authors_by_ids(req).await;
+        let secondary = self.secondary.find_authors_by_ids(req);
+        self.compare("find_authors_by_ids", &result, secondary)
+            .await;
+        result
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let result = self.primary.find_sorted_authors(req).await;
+        let secondary = self.secondary.find_sorted_authors(req);
+        self.compare("find_sorted_authors", &result, secondary)
+            .await;
+        result
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        let result = self.primary.find_projected_authors(req).await;
+        let secondary = self.secondary.find_projected_authors(req);
+        self.compare("find_projected_authors", &result, secondary)
+            .await;
+        result
+    }
+
+    /// Streams are not compared, as that would mean buffering both of them.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.primary.stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        let result = self.primary.count_authors().await;
+        self.compare("count_authors", &result, self.secondary.count_authors())
+            .await;
+        result
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        let result = self.primary.author_exists(req).await;
+        let secondary = self.secondary.author_exists(req);
+        self.compare("author_exists", &result, secondary).await;
+        result
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        let author = self.primary.update_author(req).await?;
+        copy_author(&self.secondary, "update_author", &author).await;
+        Ok(author)
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        let author = self.primary.upsert_author(req).await?;
+        copy_author(&self.secondary, "upsert_author", &author).await;
+        Ok(author)
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        let upserted = self.primary.upsert_author_by_email(req).await?;
+        copy_author(&self.secondary, "upsert_author_by_email", upserted.author()).await;
+        Ok(upserted)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.primary.set_author_status(req).await?;
+        mirror("set_author_status", self.secondary.set_author_status(req)).await;
+        Ok(())
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.primary.delete_author(req).await?;
+        mirror("delete_author", self.secondary.delete_author(req)).await;
+        Ok(())
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.primary.add_author_alias(req).await?;
+        mirror("add_author_alias", self.secondary.add_author_alias(req)).await;
+        Ok(())
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.primary.remove_author_alias(req).await?;
+        mirror(
+            "remove_author_alias",
+            self.secondary.remove_author_alias(req),
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        let result = self.primary.find_author_aliases(req).await;
+        let secondary = self.secondary.find_author_aliases(req);
+        self.compare("find_author_aliases", &result, secondary)
+            .await;
+        result
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let result = self.primary.search_authors(req).await;
+        let secondary = self.secondary.search_authors(req);
+        self.compare("search_authors", &result, secondary).await;
+        result
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        let result = self.primary.author_stats(req).await;
+        let secondary = self.secondary.author_stats(req);
+        self.compare("author_stats", &result, secondary).await;
+        result
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let result = self.primary.find_authors_by_verification(req).await;
+        let secondary = self.secondary.find_authors_by_verification(req);
+        self.compare("find_authors_by_verification", &result, secondary)
+            .await;
+        result
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.primary.set_email_verification(req).await?;
+        let secondary = self.secondary.set_email_verification(req);
+        mirror("set_email_verification", secondary).await;
+        Ok(())
+    }
+}
+
+/// Mirrors writes made in transactions to the secondary of a [`DualWriteAuthorRepository`]. They
+/// are held back until the primary commits, so rolled-back work never reaches the secondary, and
+/// reads in the transaction are not compared, as the secondary cannot see its uncommitted writes.
+#[derive(Debug)]
+pub struct DualWriteUnitOfWork<U, B> {
+    primary: U,
+    secondary: Arc<B>,
+}
+
+impl<U: UnitOfWork, B: AuthorRepository> DualWriteUnitOfWork<U, B> {
+    pub fn new(primary: U, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary: Arc::new(secondary),
+        }
+    }
+}
+
+impl<U: UnitOfWork, B: AuthorRepository> UnitOfWork for DualWriteUnitOfWork<U, B> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        let tx = self.primary.begin().await?;
+        Ok(Box::new(DualWriteTransaction {
+            tx,
+            secondary: Arc::clone(&self.secondary),
+            pending: Mutex::new(Vec::new()),
+        }))
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        self.primary.retry_delay(attempt, err)
+    }
+}
+
+/// A write made in a transaction, to repeat on the secondary once it commits.
+#[derive(Debug)]
+enum PendingWrite {
+    Copy(&'static str, Author),
+    SetStatus(SetAuthorStatusRequest),
+    Delete(DeleteAuthorRequest),
+    AddAlias(AddAuthorAliasRequest),
+    RemoveAlias(RemoveAuthorAliasRequest),
+    SetVerification(SetEmailVerificationRequest),
+}
+
+impl PendingWrite {
+    async fn replay(self, secondary: &impl AuthorRepository) {
+        match self {
+            Self::Copy(operation, author) => copy_author(secondary, operation, &author).await,
+            Self::SetStatus(req) => {
+                mirror("set_author_status", secondary.set_author_status(&req)).await;
+            }
+            Self::Delete(req) => mirror("delete_author", secondary.delete_author(&req)).await,
+            Self::AddAlias(req) => {
+                mirror("add_author_alias", secondary.add_author_alias(&req)).await;
+            }
+            Self::RemoveAlias(req) => {
+                mirror("remove_author_alias", secondary.remove_author_alias(&req)).await;
+            }
+            Self::SetVerification(req) => {
+                let write = secondary.set_email_verification(&req);
+                mirror("set_email_verification", write).await;
+            }
+        }
+    }
+}
+
+struct DualWriteTransaction<B> {
+    tx: Box<dyn Transaction>,
+    secondary: Arc<B>,
+    pending: Mutex<Vec<PendingWrite>>,
+}
+
+impl<B> DualWriteTransaction<B> {
+    fn hold(&self, write: PendingWrite) {
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(write);
+    }
+}
+
+impl<B: AuthorRepository> Transaction for DualWriteTransaction<B> {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self.tx.genres()
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self.tx.audit()
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self.tx.publishers()
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        let Self {
+            tx,
+            secondary,
+            pending,
+        } = *self;
+        let pending = pending.into_inner().unwrap_or_else(PoisonError::into_inner);
+        let commit = tx.commit();
+        Box::pin(async move {
+            commit.await?;
+            for write in pending {
+                write.replay(secondary.as_ref()).await;
+            }
+            Ok(())
+        })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        self.tx.rollback()
+    }
+}
+
+impl<B: AuthorRepository> AuthorRepository for DualWriteTransaction<B> {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        let author = self.tx.authors().create_author(req).await?;
+        self.hold(PendingWrite::Copy("create_author", author.clone()));
+        Ok(author)
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.tx.authors().find_author(req).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.tx.authors().find_author_by_email(req).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.tx.authors().find_all_authors().await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.tx.authors().find_authors_by_ids(req).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.tx.authors().find_sorted_authors(req).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.tx.authors().find_projected_authors(req).await
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.tx.authors().stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.tx.authors().count_authors().await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.tx.authors().author_exists(req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        let author = self.tx.authors().update_author(req).await?;
+        self.hold(PendingWrite::Copy("update_author", author.clone()));
+        Ok(author)
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        let author = self.tx.authors().upsert_author(req).await?;
+        self.hold(PendingWrite::Copy("upsert_author", author.clone()));
+        Ok(author)
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        let upserted = self.tx.authors().upsert_author_by_email(req).await?;
+        let author = upserted.author().clone();
+        self.hold(PendingWrite::Copy("upsert_author_by_email", author));
+        Ok(upserted)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.tx.authors().set_author_status(req).await?;
+        let req = SetAuthorStatusRequest::new(req.id(), req.status());
+        self.hold(PendingWrite::SetStatus(req));
+        Ok(())
+    }
+
+    /// The precondition was checked by the primary, against its own timestamps.
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.tx.authors().delete_author(req).await?;
+        self.hold(PendingWrite::Delete(DeleteAuthorRequest::new(req.id())));
+        Ok(())
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.tx.authors().add_author_alias(req).await?;
+        let req = AddAuthorAliasRequest::new(req.author_id(), req.alias().clone());
+        self.hold(PendingWrite::AddAlias(req));
+        Ok(())
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.tx.authors().remove_author_alias(req).await?;
+        let req = RemoveAuthorAliasRequest::new(req.author_id(), req.alias().clone());
+        self.hold(PendingWrite::RemoveAlias(req));
+        Ok(())
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.tx.authors().find_author_aliases(req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.tx.authors().search_authors(req).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.tx.authors().author_stats(req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.tx.authors().find_authors_by_verification(req).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.tx.authors().set_email_verification(req).await?;
+        let req =
+            SetEmailVerificationRequest::new(req.id(), req.email().clone(), req.verification());
+        self.hold(PendingWrite::SetVerification(req));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AddAuthorAliasRequest, AuditContext, AuthorName, CreateAuthorRequest, EmailAddress,
+        FindAuthorRequest, UpdateAuthorRequest,
+    };
+    use crate::domain::ports::{AuthorRepository, UnitOfWork};
+    use crate::domain::service::AuthorService;
+    use crate::outbound::dual_write::{DualWriteAuthorRepository, DualWriteUnitOfWork};
+    use crate::outbound::memory::InMemoryRepository;
+
+    fn create_request(name: &str, email: &str) -> CreateAuthorRequest {
+        CreateAuthorRequest::new(
+            AuthorName::new(name).unwrap(),
+            EmailAddress::new(email).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn writes_are_mirrored_to_the_secondary_with_the_primarys_ids() {
+        let (primary, secondary) = (InMemoryRepository::new(), InMemoryRepository::new());
+        primary
+            .create_author(&create_request("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        let repo = DualWriteAuthorRepository::new(primary.clone(), secondary.clone());
+
+        let author = repo
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let req = UpdateAuthorRequest::builder(author.id())
+            .name(AuthorName::new("J.R.R. Tolkien").unwrap())
+            .build()
+            .unwrap();
+        repo.update_author(&req).await.unwrap();
+
+        let mirrored = secondary
+            .find_author(&FindAuthorRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!("J.R.R. Tolkien", mirrored.name().as_str());
+        assert_eq!(1, secondary.count_authors().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn failed_mirror_writes_and_mismatched_reads_do_not_reach_callers() {
+        let (primary, secondary) = (InMemoryRepository::new(), InMemoryRepository::new());
+        primary
+            .create_author(&create_request("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        secondary
+            .create_author(&create_request("JRR Tolkien", "other@example.com"))
+            .await
+            .unwrap();
+        let repo = DualWriteAuthorRepository::new(primary.clone(), secondary.clone());
+
+        let author = repo
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let found = repo
+            .find_author(&FindAuthorRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!(author.email(), found.email());
+        assert_eq!(2, repo.count_authors().await.unwrap());
+        assert!(
+            !secondary
+                .author_exists(&FindAuthorRequest::new(author.id()))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_through_the_service_reach_the_secondary_once_committed() {
+        let (primary, secondary) = (InMemoryRepository::new(), InMemoryRepository::new());
+        let service = AuthorService::new(
+            DualWriteAuthorRepository::new(primary.clone(), secondary.clone()),
+            primary.clone(),
+            DualWriteUnitOfWork::new(primary.clone(), secondary.clone()),
+            primary.clone(),
+            primary.clone(),
+            primary.clone(),
+            primary.clone(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+
+        let req = create_request("JRR Tolkien", "jrr.tolkien@example.com");
+        let author = service.create_author(&req, &ctx).await.unwrap();
+        let alias = AddAuthorAliasRequest::new(author.id(), AuthorName::new("Tollers").unwrap());
+        service.add_author_alias(&alias, &ctx).await.unwrap();
+
+        let find = FindAuthorRequest::new(author.id());
+        let mirrored = secondary.find_author(&find).await.unwrap();
+        assert_eq!(author.email(), mirrored.email());
+        let aliases = secondary.find_author_aliases(&find).await.unwrap();
+        assert_eq!(vec![AuthorName::new("Tollers").unwrap()], aliases);
+    }
+
+    #[tokio::test]
+    async fn rolled_back_writes_do_not_reach_the_secondary() {
+        let (primary, secondary) = (InMemoryRepository::new(), InMemoryRepository::new());
+        let uow = DualWriteUnitOfWork::new(primary.clone(), secondary.clone());
+
+        let tx = uow.begin().await.unwrap();
+        tx.authors()
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+
+        assert_eq!(0, primary.count_authors().await.unwrap());
+        assert_eq!(0, secondary.count_authors().await.unwrap());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::domain::model::{
+    AuthorEvent, AuthorId, AuthorName, AuthorStatus, EmailAddress, PublishEventError,
+};
+use crate::domain::ports::{DynEventPublisher, EventPublisher};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::str::FromStr;
+use thiserror::Error;
+use tokio::sync::broadcast;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventBackend {
+    Log,
+    Nats,
+    Kafka,
+}
+
+impl FromStr for EventBackend {
+    type Err = EventBackendError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "log" => Ok(Self::Log),
+            "nats" => Ok(Self::Nats),
+            "kafka" => Ok(Self::Kafka),
+            _ => Err(EventBackendError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid event backend, expected one of "log", "nats" or "kafka""#)]
+pub struct EventBackendError(String);
+
+#[derive(Debug, Clone)]
+pub struct EventPublisherConfig {
+    brokers: Vec<String>,
+    topic_prefix: String,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl EventPublisherConfig {
+    #[must_use]
+    pub const fn new(
+        brokers: Vec<String>,
+        topic_prefix: String,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Self {
+        Self {
+            brokers,
+            topic_prefix,
+            username,
+            password,
+        }
+    }
+
+    #[must_use]
+    pub fn brokers(&self) -> &[String] {
+        &self.brokers
+    }
+
+    #[must_use]
+    pub fn topic_prefix(&self) -> &str {
+        &self.topic_prefix
+    }
+
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    #[must_use]
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    #[must_use]
+    pub fn topic(&self, event: &AuthorEvent) -> String {
+        format!("{}.{}", self.topic_prefix, event.name())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EventMessage {
+    event: &'static str,
+    author_id: AuthorId,
+    author: Option<AuthorMessage>,
+    occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+struct AuthorMessage {
+    id: AuthorId,
+    name: AuthorName,
+    email: EmailAddress,
+    status: AuthorStatus,
+}
+
+impl From<&AuthorEvent> for EventMessage {
+    fn from(value: &AuthorEvent) -> Self {
+        let author = match value {
+            AuthorEvent::Created(author) | AuthorEvent::Updated(author) => Some(AuthorMessage {
+                id: author.id(),
+                name: author.name().clone(),
+                email: author.email().clone(),
+                status: author.status(),
+            }),
+            AuthorEvent::Deleted { .. } => None,
+        };
+        Self {
+            event: value.name(),
+            author_id: value.author_id(),
+            author,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+pub fn encode_event(event: &AuthorEvent) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec(&EventMessage::from(event))
+}
+
+#[derive(Debug, Default)]
+pub struct LogEventPublisher;
+
+impl EventPublisher for LogEventPublisher {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        tracing::info!(
+            event = event.name(),
+            author_id = %event.author_id(),
+            "Published author event"
+        );
+        Ok(())
+    }
+}
+
+const BROADCAST_CAPACITY: usize = 64;
+
+#[derive(Debug)]
+pub struct BroadcastEventPublisher<P> {
+    inner: P,
+    sender: broadcast::Sender<AuthorEvent>,
+}
+
+impl<P: EventPublisher> BroadcastEventPublisher<P> {
+    #[must_use]
+    pub fn new(inner: P) -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self { inner, sender }
+    }
+
+    #[must_use]
+    pub fn sender(&self) -> broadcast::Sender<AuthorEvent> {
+        self.sender.clone()
+    }
+}
+
+impl<P: EventPublisher> EventPublisher for BroadcastEventPublisher<P> {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        let _ = self.sender.send(event.clone());
+        self.inner.publish(event).await
+    }
+}
+
+pub async fn connect_event_publisher(
+    backend: EventBackend,
+    config: EventPublisherConfig,
+) -> anyhow::Result<Box<dyn DynEventPublisher>> {
+    match backend {
+        EventBackend::Log => Ok(Box::new(LogEventPublisher)),
+        #[cfg(feature = "nats")]
+        EventBackend::Nats => {
+            let publisher = crate::outbound::nats::NatsEventPublisher::connect(config).await?;
+            Ok(Box::new(publisher))
+        }
+        #[cfg(feature = "kafka")]
+        EventBackend::Kafka => {
+            let publisher = crate::outbound::kafka::KafkaEventPublisher::new(config)?;
+            Ok(Box::new(publisher))
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            drop(config);
+            anyhow::bail!("Event backend {backend:?} is not enabled in this build")
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::domain::model::{FeatureFlag, FindFeatureFlagsError};
+use crate::domain::ports::FeatureFlags;
+use anyhow::Context;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Flags set in code, for tests and for running without a flags file.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryFeatureFlags {
+    flags: Arc<RwLock<BTreeMap<String, FeatureFlag>>>,
+}
+
+impl InMemoryFeatureFlags {
+    #[must_use]
+    pub fn new(flags: impl IntoIterator<Item = FeatureFlag>) -> Self {
+        let flags = flags
+            .into_iter()
+            .map(|flag| (flag.name().to_string(), flag))
+            .collect();
+        Self {
+            flags: Arc::new(RwLock::new(flags)),
+        }
+    }
+
+    /// Adds the flag or replaces the one with the same name.
+    pub fn set(&self, flag: FeatureFlag) {
+        let mut flags = self.flags.write().unwrap_or_else(PoisonError::into_inner);
+        flags.insert(flag.name().to_string(), flag);
+    }
+}
+
+impl FeatureFlags for InMemoryFeatureFlags {
+    async fn find_flags(&self) -> Result<Vec<FeatureFlag>, FindFeatureFlagsError> {
+        let flags = self.flags.read().unwrap_or_else(PoisonError::into_inner);
+        Ok(flags.values().cloned().collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FlagFile {
+    enabled: bool,
+    #[serde(default = "full_rollout")]
+    rollout: u8,
+}
+
+const fn full_rollout() -> u8 {
+    100
+}
+
+#[derive(Debug)]
+struct Loaded {
+    modified: Option<SystemTime>,
+    checked: Instant,
+    flags: Vec<FeatureFlag>,
+}
+
+/// Reads flags from a TOML file with one table per flag:
+///
+/// ```toml
+/// [v2_envelope]
+/// enabled = true
+/// rollout = 25
+/// ```
+///
+/// The file is checked for changes at most once per interval and reloaded when its modification
+/// time moves. A file that fails to parse is logged and the flags read before it are kept.
+#[derive(Debug, Clone)]
+pub struct FileFeatureFlags {
+    path: PathBuf,
+    check_interval: Duration,
+    loaded: Arc<RwLock<Loaded>>,
+}
+
+impl FileFeatureFlags {
+    /// Fails when the file cannot be read, so a typo in its path stops startup.
+    pub async fn load(path: PathBuf) -> anyhow::Result<Self> {
+        let modified = modified(&path).await;
+        let flags = read_flags(&path).await?;
+        Ok(Self {
+            path,
+            check_interval: Duration::from_secs(5),
+            loaded: Arc::new(RwLock::new(Loaded {
+                modified,
+                checked: Instant::now(),
+                flags,
+            })),
+        })
+    }
+
+    #[must_use]
+    pub const fn with_check_interval(mut self, check_interval: Duration) -> Self {
+        self.check_interval = check_interval;
+        self
+    }
+
+    async fn reload_if_changed(&self) {
+        let previous = {
+            let mut loaded = self.loaded.write().unwrap_or_else(PoisonError::into_inner);
+            if loaded.checked.elapsed() < self.check_interval {
+                return;
+            }
+            loaded.checked = Instant::now();
+            loaded.modified
+        };
+        let modified = modified(&self.path).await;
+        if modified == previous {
+            return;
+        }
+        match read_flags(&self.path).await {
+            Ok(flags) => {
+                tracing::info!(path = %self.path.display(), "Reloaded {} feature flags", flags.len());
+                let mut loaded = self.loaded.write().unwrap_or_else(PoisonError::into_inner);
+                loaded.modified = modified;
+                loaded.flags = flags;
+            }
+            Err(err) => tracing::warn!("Keeping the previous feature flags: {err:?}"),
+        }
+    }
+}
+
+impl FeatureFlags for FileFeatureFlags {
+    async fn find_flags(&self) -> Result<Vec<FeatureFlag>, FindFeatureFlagsError> {
+        self.reload_if_changed().await;
+        let loaded = self.loaded.read().unwrap_or_else(PoisonError::into_inner);
+        Ok(loaded.flags.clone())
+    }
+}
+
+async fn modified(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+async fn read_flags(path: &Path) -> anyhow::Result<Vec<FeatureFlag>> {
+    let source = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read feature flags from {}", path.display()))?;
+    let flags: BTreeMap<String, FlagFile> = toml::from_str(&source)
+        .with_context(|| format!("Failed to parse feature flags in {}", path.display()))?;
+    Ok(flags
+        .into_iter()
+        .map(|(name, flag)| FeatureFlag::new(name, flag.enabled).with_rollout(flag.rollout))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::FeatureFlag;
+    use crate::domain::ports::FeatureFlags;
+    use crate::outbound::flags::FileFeatureFlags;
+    use std::time::{Duration, SystemTime};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn flag_files_are_reloaded_when_they_change() {
+        let path = std::env::temp_dir().join(format!("hexarch-flags-{}.toml", Uuid::now_v7()));
+        std::fs::write(&path, "[v2_envelope]\nenabled = true\nrollout = 25\n").unwrap();
+        let flags = FileFeatureFlags::load(path.clone())
+            .await
+            .unwrap()
+            .with_check_interval(Duration::ZERO);
+        let expected = FeatureFlag::new("v2_envelope".into(), true).with_rollout(25);
+        assert_eq!(vec![expected], flags.find_flags().await.unwrap());
+
+        std::fs::write(&path, "[v2_envelope]\nenabled = false\n").unwrap();
+        let file = std::fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        let expected = FeatureFlag::new("v2_envelope".into(), false);
+        assert_eq!(vec![expected.clone()], flags.find_flags().await.unwrap());
+
+        std::fs::write(&path, "[v2_envelope\n").unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(2))
+            .unwrap();
+        assert_eq!(vec![expected], flags.find_flags().await.unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::model::OidcConfig;
+use crate::domain::ports::DynIdentityProvider;
+
+/// Connects the OpenID provider the admin pages sign in with.
+pub fn connect_identity_provider(
+    config: &OidcConfig,
+) -> anyhow::Result<Box<dyn DynIdentityProvider>> {
+    #[cfg(feature = "oidc")]
+    {
+        Ok(Box::new(crate::outbound::oidc::OidcClient::new(
+            config.clone(),
+        )?))
+    }
+    #[cfg(not(feature = "oidc"))]
+    {
+        let _ = config;
+        anyhow::bail!("Signing in to the admin pages with OIDC requires the oidc feature")
+    }
+}
@@ -0,0 +1,138 @@
+use crate::domain::model::{AuthorId, AuthorIdStrategy};
+use crate::domain::ports::IdGenerator;
+use chrono::Utc;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use thiserror::Error;
+
+/// Leaves every id to the repository, which numbers authors in the order they are stored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DatabaseIds;
+
+impl IdGenerator for DatabaseIds {
+    fn next_author_id(&self) -> Option<AuthorId> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV7Ids;
+
+impl IdGenerator for UuidV7Ids {
+    fn next_author_id(&self) -> Option<AuthorId> {
+        Some(AuthorId::new_v7())
+    }
+}
+
+/// Milliseconds since 2024-01-01, where the timestamp in a Snowflake id starts.
+const SNOWFLAKE_EPOCH_MS: i64 = 1_704_067_200_000;
+const SNOWFLAKE_NODE_BITS: u32 = 10;
+const SNOWFLAKE_SEQUENCE_BITS: u32 = 12;
+const SNOWFLAKE_MAX_SEQUENCE: i64 = (1 << SNOWFLAKE_SEQUENCE_BITS) - 1;
+
+#[derive(Error, Debug)]
+#[error("Snowflake node {0} is out of range, expected 0 to 1023")]
+pub struct SnowflakeNodeError(u16);
+
+/// Integer ids made of the milliseconds since 2024, the node and a sequence within the
+/// millisecond, so nodes with distinct numbers never hand out the same id. Ids keep increasing
+/// when the clock steps back or a millisecond runs out of sequence numbers, by running ahead of
+/// the clock until it catches up.
+#[derive(Debug)]
+pub struct SnowflakeIds {
+    node: i64,
+    last: Mutex<(i64, i64)>,
+}
+
+impl SnowflakeIds {
+    pub fn new(node: u16) -> Result<Self, SnowflakeNodeError> {
+        if node >= 1 << SNOWFLAKE_NODE_BITS {
+            return Err(SnowflakeNodeError(node));
+        }
+        Ok(Self {
+            node: i64::from(node),
+            last: Mutex::new((0, -1)),
+        })
+    }
+
+    fn next_at(&self, now_ms: i64) -> i64 {
+        let mut last = self.last.lock().unwrap_or_else(PoisonError::into_inner);
+        let (last_ms, sequence) = *last;
+        let elapsed = (now_ms - SNOWFLAKE_EPOCH_MS).max(last_ms);
+        *last = if elapsed > last_ms {
+            (elapsed, 0)
+        } else if sequence < SNOWFLAKE_MAX_SEQUENCE {
+            (last_ms, sequence + 1)
+        } else {
+            (last_ms + 1, 0)
+        };
+        let (elapsed, sequence) = *last;
+        elapsed << (SNOWFLAKE_NODE_BITS + SNOWFLAKE_SEQUENCE_BITS)
+            | self.node << SNOWFLAKE_SEQUENCE_BITS
+            | sequence
+    }
+}
+
+impl IdGenerator for SnowflakeIds {
+    fn next_author_id(&self) -> Option<AuthorId> {
+        Some(AuthorId::new(self.next_at(Utc::now().timestamp_millis())))
+    }
+}
+
+/// Counts up from a known id, for tests that want to know the ids they will get.
+#[derive(Debug)]
+pub struct SequentialIds {
+    next: AtomicI64,
+}
+
+impl SequentialIds {
+    #[must_use]
+    pub const fn starting_at(first: i64) -> Self {
+        Self {
+            next: AtomicI64::new(first),
+        }
+    }
+}
+
+impl IdGenerator for SequentialIds {
+    fn next_author_id(&self) -> Option<AuthorId> {
+        Some(AuthorId::new(self.next.fetch_add(1, Ordering::Relaxed)))
+    }
+}
+
+/// `node` tells apart the processes handing out Snowflake ids and is ignored by the others.
+pub fn new_id_generator(
+    strategy: AuthorIdStrategy,
+    node: u16,
+) -> anyhow::Result<Box<dyn IdGenerator>> {
+    match strategy {
+        AuthorIdStrategy::Integer => Ok(Box::new(DatabaseIds)),
+        AuthorIdStrategy::UuidV7 => Ok(Box::new(UuidV7Ids)),
+        AuthorIdStrategy::Snowflake => Ok(Box::new(SnowflakeIds::new(node)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::outbound::ids::{SNOWFLAKE_EPOCH_MS, SnowflakeIds};
+
+    #[test]
+    fn snowflake_ids_increase_and_carry_their_node() {
+        let ids = SnowflakeIds::new(5).unwrap();
+        let now = SNOWFLAKE_EPOCH_MS + 1_000;
+        let first = ids.next_at(now);
+        assert_eq!(1_000 << 22 | 5 << 12, first);
+        assert_eq!(first + 1, ids.next_at(now));
+        assert_eq!(first + 2, ids.next_at(now - 500), "the clock stepped back");
+
+        let mut previous = first + 2;
+        for _ in 0..5_000 {
+            let next = ids.next_at(now);
+            assert!(next > previous);
+            assert_eq!(5, next >> 12 & 0x3ff);
+            previous = next;
+        }
+        assert_eq!(1_001, previous >> 22, "ran ahead once the sequence ran out");
+        assert!(SnowflakeIds::new(1_024).is_err());
+    }
+}
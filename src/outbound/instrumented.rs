@@ -0,0 +1,438 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, FindAllAuthorsError, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+    ProjectedAuthor, RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError,
+    ReplaceAuthorRequest, ReplacedAuthor, SearchAuthorsRequest, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuthorRepository, DynAuditRecorder, DynAuthorRepository, DynGenreRepository,
+    DynPublisherRepository, Transaction, TransactionAuthors, UnitOfWork,
+};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::{Instrument, Span};
+
+/// Wraps every operation in an `author_repository` span, records its latency in the
+/// `author_repository_duration_seconds` histogram, counts failures in
+/// `author_repository_errors_total`, and warns about operations slower than a threshold. As a
+/// unit of work it also observes `begin`, `commit` and the authors of each transaction.
+#[derive(Debug)]
+pub struct InstrumentedAuthorRepository<R> {
+    inner: R,
+    backend: &'static str,
+    slow_threshold: Duration,
+}
+
+impl<R> InstrumentedAuthorRepository<R> {
+    pub const DEFAULT_SLOW_THRESHOLD: Duration = Duration::from_millis(500);
+
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            backend: "primary",
+            slow_threshold: Self::DEFAULT_SLOW_THRESHOLD,
+        }
+    }
+
+    /// Labels spans and metrics, to tell apart several instrumented repositories.
+    #[must_use]
+    pub const fn with_backend(mut self, backend: &'static str) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_slow_threshold(mut self, slow_threshold: Duration) -> Self {
+        self.slow_threshold = slow_threshold;
+        self
+    }
+
+    /// Instruments `inner` with the same backend label and threshold.
+    const fn wrap<S>(&self, inner: S) -> InstrumentedAuthorRepository<S> {
+        InstrumentedAuthorRepository {
+            inner,
+            backend: self.backend,
+            slow_threshold: self.slow_threshold,
+        }
+    }
+
+    fn span(&self, operation: &'static str) -> Span {
+        tracing::info_span!("author_repository", operation, backend = self.backend)
+    }
+
+    async fn observe<T, E>(
+        &self,
+        operation: &'static str,
+        fut: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E>
+    where
+        E: std::fmt::Display,
+    {
+        let span = self.span(operation);
+        let started = Instant::now();
+        let result = fut.instrument(span.clone()).await;
+        let elapsed = started.elapsed();
+        let outcome = if result.is_ok() { "ok" } else { "error" };
+        metrics::histogram!(
+            "author_repository_duration_seconds",
+            "operation" => operation,
+            "backend" => self.backend,
+            "outcome" => outcome
+        )
+        .record(elapsed.as_secs_f64());
+        span.in_scope(|| {
+            if let Err(err) = &result {
+                metrics::counter!(
+                    "author_repository_errors_total",
+                    "operation" => operation,
+                    "backend" => self.backend
+                )
+                .increment(1);
+                tracing::debug!("Author repository operation failed: {err}");
+            }
+            if elapsed >= self.slow_threshold {
+                tracing::warn!(
+                    elapsed_ms = elapsed.as_millis(),
+                    "Slow author repository operation"
+                );
+            }
+        });
+        result
+    }
+}
+
+impl<R: AuthorRepository> AuthorRepository for InstrumentedAuthorRepository<R> {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.observe("create_author", self.inner.create_author(req))
+            .await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.observe("find_author", self.inner.find_author(req))
+            .await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.observe("find_author_by_email", self.inner.find_author_by_email(req))
+            .await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.observe("find_all_authors", self.inner.find_all_authors())
+            .await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.observe("find_authors_by_ids", self.inner.find_authors_by_ids(req))
+            .await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.observe("find_sorted_authors", self.inner.find_sorted_authors(req))
+            .await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.observe(
+            "find_projected_authors",
+            self.inner.find_projected_authors(req),
+        )
+        .await
+    }
+
+    /// Only opening the stream is timed, as it is consumed at the caller's pace.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        let span = self.span("stream_all_authors");
+        self.inner.stream_all_authors().instrument(span).await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.observe("count_authors", self.inner.count_authors())
+            .await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.observe("author_exists", self.inner.author_exists(req))
+            .await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.observe("update_author", self.inner.update_author(req))
+            .await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.observe("upsert_author", self.inner.upsert_author(req))
+            .await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.observe(
+            "upsert_author_by_email",
+            self.inner.upsert_author_by_email(req),
+        )
+        .await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.observe("set_author_status", self.inner.set_author_status(req))
+            .await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.observe("delete_author", self.inner.delete_author(req))
+            .await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.observe("add_author_alias", self.inner.add_author_alias(req))
+            .await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.observe("remove_author_alias", self.inner.remove_author_alias(req))
+            .await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.observe("find_author_aliases", self.inner.find_author_aliases(req))
+            .await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.observe("search_authors", self.inner.search_authors(req))
+            .await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.observe("author_stats", self.inner.author_stats(req))
+            .await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.observe(
+            "find_authors_by_verification",
+            self.inner.find_authors_by_verification(req),
+        )
+        .await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.observe(
+            "set_email_verification",
+            self.inner.set_email_verification(req),
+        )
+        .await
+    }
+}
+
+impl<U: UnitOfWork> UnitOfWork for InstrumentedAuthorRepository<U> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        let tx = self.observe("begin", self.inner.begin()).await?;
+        Ok(Box::new(self.wrap(TransactionAuthors::new(tx))))
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        self.inner.retry_delay(attempt, err)
+    }
+}
+
+/// Observes the transaction's authors and its commit, like the repository outside transactions.
+/// Genres, the audit log and publishers are passed through, as is the rollback.
+impl Transaction for InstrumentedAuthorRepository<TransactionAuthors> {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self.inner.transaction().genres()
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self.inner.transaction().audit()
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self.inner.transaction().publishers()
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        let observer = self.wrap(());
+        let commit = self.inner.into_inner().commit();
+        Box::pin(async move { observer.observe("commit", commit).await })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        self.inner.into_inner().rollback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        Author, AuthorId, AuthorName, CreateAuthorRequest, EmailAddress, FindAuthorError,
+        FindAuthorRequest,
+    };
+    use crate::domain::ports::{AuthorRepository, UnitOfWork};
+    use crate::outbound::instrumented::InstrumentedAuthorRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use chrono::Utc;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn slow_operations_are_logged_in_their_span() {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer({
+                let buffer = buffer.clone();
+                move || buffer.clone()
+            })
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let repo = MockAuthorRepository::new();
+        repo.expect_find()
+            .returning(|req| Err(FindAuthorError::NotFound { id: req.id() }));
+        repo.expect_count().returning(|_| Ok(3));
+        let instrumented = InstrumentedAuthorRepository::new(repo.clone())
+            .with_backend("mock")
+            .with_slow_threshold(Duration::ZERO);
+
+        let actual = instrumented
+            .find_author(&FindAuthorRequest::new(AuthorId::new(7)))
+            .await;
+        assert!(
+            matches!(actual, Err(FindAuthorError::NotFound { .. })),
+            "expected the error to pass through, but got {actual:?}"
+        );
+        assert_eq!(3, instrumented.count_authors().await.unwrap());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let slow: Vec<_> = output
+            .lines()
+            .filter(|line| line.contains("Slow author repository operation"))
+            .collect();
+        assert_eq!(2, slow.len(), "{output}");
+        assert!(
+            slow[0].contains(r#"author_repository{operation="find_author" backend="mock"}"#),
+            "{output}"
+        );
+        assert!(slow[1].contains(r#"operation="count_authors""#), "{output}");
+    }
+
+    #[tokio::test]
+    async fn writes_in_transactions_are_observed() {
+        let buffer = Buffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer({
+                let buffer = buffer.clone();
+                move || buffer.clone()
+            })
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let repo = MockAuthorRepository::new();
+        repo.expect_create().returning(|req| {
+            let now = Utc::now();
+            Ok(Author::new(
+                AuthorId::new(1),
+                req.name().clone(),
+                req.email().clone(),
+                now,
+                now,
+            ))
+        });
+        let instrumented = InstrumentedAuthorRepository::new(repo.clone())
+            .with_backend("mock")
+            .with_slow_threshold(Duration::ZERO);
+
+        let tx = instrumented.begin().await.unwrap();
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        tx.authors().create_author(&req).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let operations: Vec<_> = output
+            .lines()
+            .filter(|line| line.contains("Slow author repository operation"))
+            .collect();
+        assert_eq!(3, operations.len(), "{output}");
+        assert!(
+            operations[0].contains(r#"author_repository{operation="begin" backend="mock"}"#),
+            "{output}"
+        );
+        assert!(
+            operations[1].contains(r#"operation="create_author""#),
+            "{output}"
+        );
+        assert!(operations[2].contains(r#"operation="commit""#), "{output}");
+    }
+}
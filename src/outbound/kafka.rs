@@ -0,0 +1,49 @@
+use crate::domain::model::{AuthorEvent, PublishEventError};
+use crate::domain::ports::EventPublisher;
+use crate::outbound::events::{EventPublisherConfig, encode_event};
+use anyhow::{Context, anyhow};
+use rdkafka::ClientConfig;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use std::time::Duration;
+
+pub struct KafkaEventPublisher {
+    producer: FutureProducer,
+    config: EventPublisherConfig,
+}
+
+impl KafkaEventPublisher {
+    pub fn new(config: EventPublisherConfig) -> anyhow::Result<Self> {
+        let mut client_config = ClientConfig::new();
+        client_config
+            .set("bootstrap.servers", config.brokers().join(","))
+            .set("message.timeout.ms", "5000")
+            .set("acks", "all");
+        if let (Some(username), Some(password)) = (config.username(), config.password()) {
+            client_config
+                .set("security.protocol", "SASL_SSL")
+                .set("sasl.mechanisms", "PLAIN")
+                .set("sasl.username", username)
+                .set("sasl.password", password);
+        }
+        let producer = client_config
+            .create()
+            .context("Failed to create Kafka producer")?;
+
+        Ok(Self { producer, config })
+    }
+}
+
+impl EventPublisher for KafkaEventPublisher {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        let topic = self.config.topic(event);
+        let key = event.author_id().to_string();
+        let payload = encode_event(event).context("Failed to serialize author event")?;
+        let record = FutureRecord::to(&topic).key(&key).payload(&payload);
+        self.producer
+            .send(record, Duration::from_secs(5))
+            .await
+            .map_err(|(err, _)| anyhow!(err))
+            .with_context(|| format!("Failed to deliver author event to {topic}"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,1522 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditEntry, Author, AuthorEvent,
+    AuthorGenreRequest, AuthorId, AuthorName, AuthorStats, AuthorStatsRequest, AuthorStatus, Blob,
+    ChangeAuthorStatusError, CommandLogError, Contract, ContractId, CreateAuthorError,
+    CreateAuthorRequest, CreateContractError, CreateContractRequest, CreateGenreError,
+    CreateGenreRequest, CreatePublisherError, CreatePublisherRequest, DeleteAuthorError,
+    DeleteAuthorRequest, DeleteBlobError, DeleteContractError, DeleteContractRequest,
+    DeleteGenreError, DeleteGenreRequest, DeletePublisherError, DeletePublisherRequest,
+    DetachGenreError, ERASURE_LOG_GENESIS, EmailVerification, ErasureRecord, FindAllAuthorsError,
+    FindAllGenresError, FindAllPublishersError, FindAuditLogError, FindAuditLogRequest,
+    FindAuthorByEmailError, FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest,
+    FindAuthorsByGenreRequest, FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest,
+    FindChangesRequest, FindOperationError, FindProjectedAuthorsRequest, FindPublisherError,
+    FindPublisherRequest, FindSortedAuthorsRequest, Genre, GenreId, GetBlobError, Operation,
+    OperationId, ProjectedAuthor, PublishEventError, Publisher, PublisherId, PutBlobError,
+    RecordAuditError, RecordAuditRequest, RecordErasureRequest, RecordSecurityEventRequest,
+    RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest,
+    ReplacedAuthor, SaveOperationError, SearchAuthorsRequest, SecurityEvent, SessionId,
+    SessionStoreError, SetAuthorStatusRequest, SetEmailVerificationError,
+    SetEmailVerificationRequest, StoredSession, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, BlobStorage, CommandLog, DynAuditRecorder,
+    DynAuthorRepository, DynGenreRepository, DynPublisherRepository, EventPublisher,
+    GenreRepository, OperationStore, PublisherRepository, SessionStore, Transaction, UnitOfWork,
+};
+use crate::inbound::commands::{CommandDelivery, CommandQueue};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedMutexGuard, mpsc};
+
+#[derive(Debug, Clone, Default)]
+struct Tables {
+    next_author_id: i64,
+    authors: BTreeMap<AuthorId, Author>,
+    aliases: BTreeMap<String, AuthorId>,
+    next_genre_id: i64,
+    genres: BTreeMap<GenreId, Genre>,
+    author_genres: BTreeSet<(AuthorId, GenreId)>,
+    next_publisher_id: i64,
+    publishers: BTreeMap<PublisherId, Publisher>,
+    next_contract_id: i64,
+    contracts: BTreeMap<ContractId, Contract>,
+    audit_log: Vec<AuditEntry>,
+    erasure_log: Vec<ErasureRecord>,
+    security_events: Vec<SecurityEvent>,
+    processed_commands: HashSet<String>,
+}
+
+impl Tables {
+    /// Numbers authors after the largest integer id, as the SQLite adapter does, unless the
+    /// request brings its own id. `Err` carries a requested id that is already taken.
+    fn new_author_id(&mut self, req: &CreateAuthorRequest) -> Result<AuthorId, AuthorId> {
+        let Some(id) = req.id() else {
+            self.next_author_id += 1;
+            return Ok(AuthorId::new(self.next_author_id));
+        };
+        if self.authors.contains_key(&id) {
+            return Err(id);
+        }
+        if let AuthorId::Integer(id) = id {
+            self.next_author_id = self.next_author_id.max(id);
+        }
+        Ok(id)
+    }
+
+    fn create_author(&mut self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        let name = req.name().to_string();
+        if self.authors.values().any(|a| a.name().as_str() == name) {
+            return Err(CreateAuthorError::Duplicate { name });
+        }
+        let email = req.email().to_string();
+        if self.authors.values().any(|a| a.email().as_str() == email) {
+            return Err(CreateAuthorError::DuplicateEmail { email });
+        }
+
+        let id = self
+            .new_author_id(req)
+            .map_err(|id| CreateAuthorError::IdTaken { id })?;
+        let now = Utc::now();
+        let author = Author::new(id, req.name().clone(), req.email().clone(), now, now)
+            .with_profile(req.profile().clone());
+        self.authors.insert(author.id(), author.clone());
+        Ok(author)
+    }
+
+    fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.authors
+            .get(&req.id())
+            .cloned()
+            .ok_or(FindAuthorError::NotFound { id: req.id() })
+    }
+
+    fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.authors
+            .values()
+            .find(|author| author.email() == req.email())
+            .cloned()
+            .ok_or_else(|| FindAuthorByEmailError::NotFound {
+                email: req.email().to_string(),
+            })
+    }
+
+    fn find_all_authors(&self) -> Vec<Author> {
+        self.authors.values().cloned().collect()
+    }
+
+    fn find_authors_by_ids(&self, req: &FindAuthorsByIdsRequest) -> Vec<Author> {
+        self.authors
+            .values()
+            .filter(|author| req.ids().contains(&author.id()))
+            .cloned()
+            .collect()
+    }
+
+    fn find_sorted_authors(&self, req: &FindSortedAuthorsRequest) -> Vec<Author> {
+        let mut authors = self.find_all_authors();
+        req.sort().apply(&mut authors);
+        authors
+    }
+
+    fn find_projected_authors(&self, req: &FindProjectedAuthorsRequest) -> Vec<ProjectedAuthor> {
+        self.find_all_authors()
+            .iter()
+            .map(|author| req.fields().project(author))
+            .collect()
+    }
+
+    fn update_author(&mut self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        if let Some(email) = req.email()
+            && self
+                .authors
+                .values()
+                .any(|a| a.id() != req.id() && a.email() == email)
+        {
+            return Err(UpdateAuthorError::DuplicateEmail {
+                email: email.to_string(),
+            });
+        }
+        let author = self
+            .authors
+            .get_mut(&req.id())
+            .ok_or(UpdateAuthorError::NotFound { id: req.id() })?;
+        let name = req.name().unwrap_or(author.name()).clone();
+        let email = req.email().unwrap_or(author.email()).clone();
+        let email_verification = if email == *author.email() {
+            author.email_verification()
+        } else {
+            EmailVerification::Pending
+        };
+        *author = Author::new(req.id(), name, email, author.created_at(), Utc::now())
+            .with_status(author.status())
+            .with_email_verification(email_verification)
+            .with_profile(author.profile().updated(req));
+        Ok(author.clone())
+    }
+
+    fn upsert_author(&mut self, req: &ReplaceAuthorRequest) -> Result<Author, ReplaceAuthorError> {
+        let others = || self.authors.values().filter(|a| a.id() != req.id());
+        let name = req.name().to_string();
+        if others().any(|a| a.name().as_str() == name) {
+            return Err(ReplaceAuthorError::Duplicate { name });
+        }
+        let email = req.email().to_string();
+        if others().any(|a| a.email().as_str() == email) {
+            return Err(ReplaceAuthorError::DuplicateEmail { email });
+        }
+
+        if let AuthorId::Integer(id) = req.id() {
+            self.next_author_id = self.next_author_id.max(id);
+        }
+        let now = Utc::now();
+        let created_at = self.authors.get(&req.id()).map_or(now, Author::created_at);
+        let email_verification = self
+            .authors
+            .get(&req.id())
+            .filter(|author| author.email() == req.email())
+            .map_or(EmailVerification::Pending, Author::email_verification);
+        let author = Author::new(
+            req.id(),
+            req.name().clone(),
+            req.email().clone(),
+            created_at,
+            now,
+        )
+        .with_email_verification(email_verification)
+        .with_profile(req.profile().clone());
+        self.authors.insert(author.id(), author.clone());
+        Ok(author)
+    }
+
+    fn upsert_author_by_email(
+        &mut self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        let name = req.name().to_string();
+        if self
+            .authors
+            .values()
+            .any(|a| a.email() != req.email() && a.name().as_str() == name)
+        {
+            return Err(UpsertAuthorError::Duplicate { name });
+        }
+
+        let now = Utc::now();
+        let existing = self
+            .authors
+            .values()
+            .find(|author| author.email() == req.email())
+            .cloned();
+        let Some(existing) = existing else {
+            let id = self
+                .new_author_id(req)
+                .map_err(|id| UpsertAuthorError::IdTaken { id })?;
+            let author = Author::new(id, req.name().clone(), req.email().clone(), now, now)
+                .with_profile(req.profile().clone());
+            self.authors.insert(author.id(), author.clone());
+            return Ok(ReplacedAuthor::Created(author));
+        };
+        let author = Author::new(
+            existing.id(),
+            req.name().clone(),
+            req.email().clone(),
+            existing.created_at(),
+            now,
+        )
+        .with_status(existing.status())
+        .with_email_verification(existing.email_verification())
+        .with_profile(req.profile().clone());
+        self.authors.insert(author.id(), author.clone());
+        Ok(ReplacedAuthor::Replaced(author))
+    }
+
+    fn set_author_status(
+        &mut self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        let author = self
+            .authors
+            .get_mut(&req.id())
+            .ok_or(ChangeAuthorStatusError::NotFound { id: req.id() })?;
+        *author = author.clone().with_status(req.status());
+        Ok(())
+    }
+
+    fn find_authors_by_verification(&self, req: &FindAuthorsByVerificationRequest) -> Vec<Author> {
+        self.authors
+            .values()
+            .filter(|author| req.states().contains(&author.email_verification()))
+            .take(req.limit().unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    fn set_email_verification(&mut self, req: &SetEmailVerificationRequest) {
+        if let Some(author) = self.authors.get_mut(&req.id())
+            && author.email() == req.email()
+        {
+            *author = author.clone().with_email_verification(req.verification());
+        }
+    }
+
+    fn delete_author(&mut self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.authors
+            .remove(&req.id())
+            .ok_or(DeleteAuthorError::NotFound { id: req.id() })?;
+        self.aliases.retain(|_, author_id| *author_id != req.id());
+        self.author_genres
+            .retain(|(author_id, _)| *author_id != req.id());
+        self.contracts
+            .retain(|_, contract| contract.author_id() != req.id());
+        Ok(())
+    }
+
+    fn add_author_alias(&mut self, req: &AddAuthorAliasRequest) -> Result<(), AddAuthorAliasError> {
+        if !self.authors.contains_key(&req.author_id()) {
+            return Err(AddAuthorAliasError::NotFound {
+                id: req.author_id(),
+            });
+        }
+        let alias = req.alias().to_string();
+        if self.aliases.contains_key(&alias) {
+            return Err(AddAuthorAliasError::DuplicateAlias {
+                alias: req.alias().clone(),
+            });
+        }
+        self.aliases.insert(alias, req.author_id());
+        Ok(())
+    }
+
+    fn remove_author_alias(
+        &mut self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        let alias = req.alias().to_string();
+        if self.aliases.get(&alias) != Some(&req.author_id()) {
+            return Err(RemoveAuthorAliasError::NotFound {
+                id: req.author_id(),
+                alias: req.alias().clone(),
+            });
+        }
+        self.aliases.remove(&alias);
+        Ok(())
+    }
+
+    fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        if !self.authors.contains_key(&req.id()) {
+            return Err(FindAuthorError::NotFound { id: req.id() });
+        }
+        Ok(self
+            .aliases
+            .iter()
+            .filter(|(_, author_id)| **author_id == req.id())
+            .map(|(alias, _)| AuthorName::new_unchecked(alias))
+            .collect())
+    }
+
+    fn search_authors(&self, req: &SearchAuthorsRequest) -> Vec<Author> {
+        let query = req.query().to_lowercase();
+        let matches = |name: &str| name.to_lowercase().contains(&query);
+        self.authors
+            .values()
+            .filter(|author| {
+                matches(author.name().as_str())
+                    || self
+                        .aliases
+                        .iter()
+                        .any(|(alias, author_id)| *author_id == author.id() && matches(alias))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn author_stats(&self, req: &AuthorStatsRequest) -> AuthorStats {
+        let count = |n: usize| n as u64;
+        let archived = self
+            .authors
+            .values()
+            .filter(|author| author.status() == AuthorStatus::Archived)
+            .count();
+        let mut by_email_domain = BTreeMap::<String, u64>::new();
+        let mut created_per_day = BTreeMap::new();
+        for author in self.authors.values() {
+            let email = author.email().to_string();
+            let domain = email.split_once('@').map_or("", |(_, domain)| domain);
+            *by_email_domain.entry(domain.to_string()).or_default() += 1;
+            let day = author.created_at().date_naive();
+            if day >= req.since() {
+                *created_per_day.entry(day).or_default() += 1;
+            }
+        }
+        let mut by_email_domain: Vec<_> = by_email_domain.into_iter().collect();
+        by_email_domain.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        let total = self.authors.len();
+        AuthorStats::new(count(total), count(total - archived), count(archived))
+            .with_by_email_domain(by_email_domain)
+            .with_created_per_day(created_per_day.into_iter().collect())
+    }
+
+    fn create_genre(&mut self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        let name = req.name().to_string();
+        if self
+            .genres
+            .values()
+            .any(|genre| genre.name().to_string().eq_ignore_ascii_case(&name))
+        {
+            return Err(CreateGenreError::Duplicate {
+                name: req.name().clone(),
+            });
+        }
+        self.next_genre_id += 1;
+        let genre = Genre::new(GenreId::new(self.next_genre_id), req.name().clone());
+        self.genres.insert(genre.id(), genre.clone());
+        Ok(genre)
+    }
+
+    fn find_all_genres(&self) -> Vec<Genre> {
+        let mut genres: Vec<_> = self.genres.values().cloned().collect();
+        genres.sort_by_key(|genre| genre.name().to_string().to_ascii_lowercase());
+        genres
+    }
+
+    fn delete_genre(&mut self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        if !self.genres.contains_key(&req.id()) {
+            return Err(DeleteGenreError::NotFound { id: req.id() });
+        }
+        if self
+            .author_genres
+            .iter()
+            .any(|(_, genre_id)| *genre_id == req.id())
+        {
+            return Err(DeleteGenreError::InUse { id: req.id() });
+        }
+        self.genres.remove(&req.id());
+        Ok(())
+    }
+
+    fn attach_genre(&mut self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        if !self.authors.contains_key(&req.author_id()) {
+            return Err(AttachGenreError::AuthorNotFound {
+                id: req.author_id(),
+            });
+        }
+        if !self.genres.contains_key(&req.genre_id()) {
+            return Err(AttachGenreError::GenreNotFound { id: req.genre_id() });
+        }
+        self.author_genres.insert((req.author_id(), req.genre_id()));
+        Ok(())
+    }
+
+    fn detach_genre(&mut self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        if !self
+            .author_genres
+            .remove(&(req.author_id(), req.genre_id()))
+        {
+            return Err(DetachGenreError::NotFound {
+                author_id: req.author_id(),
+                genre_id: req.genre_id(),
+            });
+        }
+        Ok(())
+    }
+
+    fn find_author_genres(&self, req: &FindAuthorRequest) -> Result<Vec<Genre>, FindAuthorError> {
+        if !self.authors.contains_key(&req.id()) {
+            return Err(FindAuthorError::NotFound { id: req.id() });
+        }
+        Ok(self
+            .find_all_genres()
+            .into_iter()
+            .filter(|genre| self.author_genres.contains(&(req.id(), genre.id())))
+            .collect())
+    }
+
+    fn find_authors_by_genre(&self, req: &FindAuthorsByGenreRequest) -> Vec<Author> {
+        self.authors
+            .values()
+            .filter(|author| self.author_genres.contains(&(author.id(), req.genre_id())))
+            .cloned()
+            .collect()
+    }
+
+    fn create_publisher(
+        &mut self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        let name = req.name().to_string();
+        if self
+            .publishers
+            .values()
+            .any(|publisher| publisher.name().to_string().eq_ignore_ascii_case(&name))
+        {
+            return Err(CreatePublisherError::Duplicate {
+                name: req.name().clone(),
+            });
+        }
+        self.next_publisher_id += 1;
+        let publisher =
+            Publisher::new(PublisherId::new(self.next_publisher_id), req.name().clone());
+        self.publishers.insert(publisher.id(), publisher.clone());
+        Ok(publisher)
+    }
+
+    fn find_publisher(&self, req: &FindPublisherRequest) -> Result<Publisher, FindPublisherError> {
+        self.publishers
+            .get(&req.id())
+            .cloned()
+            .ok_or(FindPublisherError::NotFound { id: req.id() })
+    }
+
+    fn find_all_publishers(&self) -> Vec<Publisher> {
+        let mut publishers: Vec<_> = self.publishers.values().cloned().collect();
+        publishers.sort_by_key(|publisher| publisher.name().to_string().to_ascii_lowercase());
+        publishers
+    }
+
+    fn delete_publisher(
+        &mut self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        if !self.publishers.contains_key(&req.id()) {
+            return Err(DeletePublisherError::NotFound { id: req.id() });
+        }
+        if self
+            .contracts
+            .values()
+            .any(|contract| contract.publisher_id() == req.id())
+        {
+            return Err(DeletePublisherError::HasContracts { id: req.id() });
+        }
+        self.publishers.remove(&req.id());
+        Ok(())
+    }
+
+    fn create_contract(
+        &mut self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        if !self.authors.contains_key(&req.author_id()) {
+            return Err(CreateContractError::AuthorNotFound {
+                id: req.author_id(),
+            });
+        }
+        if !self.publishers.contains_key(&req.publisher_id()) {
+            return Err(CreateContractError::PublisherNotFound {
+                id: req.publisher_id(),
+            });
+        }
+        self.next_contract_id += 1;
+        let contract = Contract::new(
+            ContractId::new(self.next_contract_id),
+            req.author_id(),
+            req.publisher_id(),
+            *req.term(),
+            req.royalty(),
+        );
+        self.contracts.insert(contract.id(), contract.clone());
+        Ok(contract)
+    }
+
+    fn contracts_where(&self, predicate: impl Fn(&Contract) -> bool) -> Vec<Contract> {
+        let mut contracts: Vec<_> = self
+            .contracts
+            .values()
+            .filter(|contract| predicate(contract))
+            .cloned()
+            .collect();
+        contracts.sort_by_key(|contract| (contract.term().starts_on(), contract.id()));
+        contracts
+    }
+
+    fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        if !self.authors.contains_key(&req.id()) {
+            return Err(FindAuthorError::NotFound { id: req.id() });
+        }
+        Ok(self.contracts_where(|contract| contract.author_id() == req.id()))
+    }
+
+    fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        if !self.publishers.contains_key(&req.id()) {
+            return Err(FindPublisherError::NotFound { id: req.id() });
+        }
+        Ok(self.contracts_where(|contract| contract.publisher_id() == req.id()))
+    }
+
+    fn delete_contract(&mut self, req: &DeleteContractRequest) -> Result<(), DeleteContractError> {
+        let owned = self
+            .contracts
+            .get(&req.contract_id())
+            .is_some_and(|contract| contract.author_id() == req.author_id());
+        if !owned {
+            return Err(DeleteContractError::NotFound {
+                author_id: req.author_id(),
+                contract_id: req.contract_id(),
+            });
+        }
+        self.contracts.remove(&req.contract_id());
+        Ok(())
+    }
+
+    fn record_audit(&mut self, req: &RecordAuditRequest) -> AuditEntry {
+        let id = i64::try_from(self.audit_log.len()).unwrap_or(i64::MAX) + 1;
+        let entry = AuditEntry::new(id, req.clone(), Utc::now());
+        self.audit_log.push(entry.clone());
+        entry
+    }
+
+    fn find_audit_log(&self, req: &FindAuditLogRequest) -> Vec<AuditEntry> {
+        self.audit_log
+            .iter()
+            .filter(|entry| entry.author_id() == req.author_id())
+            .cloned()
+            .collect()
+    }
+
+    fn find_changes(&self, req: &FindChangesRequest) -> Vec<AuditEntry> {
+        self.audit_log
+            .iter()
+            .filter(|entry| entry.id() > req.after())
+            .take(usize::try_from(req.limit()).unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    fn latest_change_id(&self) -> Option<i64> {
+        self.audit_log.last().map(AuditEntry::id)
+    }
+
+    fn erase_audit_snapshots(&mut self, req: &FindAuditLogRequest) -> u64 {
+        let mut erased = 0;
+        for entry in &mut self.audit_log {
+            if entry.author_id() == req.author_id() {
+                *entry = entry.without_snapshots();
+                erased += 1;
+            }
+        }
+        erased
+    }
+
+    fn record_erasure(&mut self, req: &RecordErasureRequest) -> ErasureRecord {
+        let id = i64::try_from(self.erasure_log.len()).unwrap_or(i64::MAX) + 1;
+        let previous_hash = self
+            .erasure_log
+            .last()
+            .map_or(ERASURE_LOG_GENESIS, ErasureRecord::hash)
+            .to_string();
+        let erased_at = Utc::now();
+        let hash = req.hash(&previous_hash, erased_at);
+        let record = ErasureRecord::new(id, req.clone(), erased_at, previous_hash, hash);
+        self.erasure_log.push(record.clone());
+        record
+    }
+
+    fn record_security_event(&mut self, req: &RecordSecurityEventRequest) -> SecurityEvent {
+        let id = i64::try_from(self.security_events.len()).unwrap_or(i64::MAX) + 1;
+        let event = SecurityEvent::new(id, req.clone(), Utc::now());
+        self.security_events.push(event.clone());
+        event
+    }
+
+    fn find_security_events(&self, limit: u32) -> Vec<SecurityEvent> {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        self.security_events
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryRepository {
+    tables: Arc<Mutex<Tables>>,
+    events: Arc<Mutex<Vec<AuthorEvent>>>,
+    blobs: Arc<Mutex<HashMap<String, Blob>>>,
+    operations: Arc<Mutex<HashMap<OperationId, Operation>>>,
+    sessions: Arc<Mutex<HashMap<SessionId, StoredSession>>>,
+}
+
+impl InMemoryRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn published_events(&self) -> Vec<AuthorEvent> {
+        self.events.lock().await.clone()
+    }
+}
+
+impl AuthorRepository for InMemoryRepository {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.tables.lock().await.create_author(req)
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.tables.lock().await.find_author(req)
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.tables.lock().await.find_author_by_email(req)
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_all_authors())
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_authors_by_ids(req))
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_sorted_authors(req))
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_projected_authors(req))
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.authors.len() as u64)
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        Ok(self.tables.lock().await.authors.contains_key(&req.id()))
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        let authors = self.tables.lock().await.find_all_authors();
+        stream::iter(authors.into_iter().map(Ok)).boxed()
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.tables.lock().await.update_author(req)
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.tables.lock().await.upsert_author(req)
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.tables.lock().await.upsert_author_by_email(req)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.tables.lock().await.set_author_status(req)
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.tables.lock().await.delete_author(req)
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.tables.lock().await.add_author_alias(req)
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.tables.lock().await.remove_author_alias(req)
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.tables.lock().await.find_author_aliases(req)
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.search_authors(req))
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.author_stats(req))
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_authors_by_verification(req))
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.tables.lock().await.set_email_verification(req);
+        Ok(())
+    }
+}
+
+impl GenreRepository for InMemoryRepository {
+    async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        self.tables.lock().await.create_genre(req)
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        Ok(self.tables.lock().await.find_all_genres())
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        self.tables.lock().await.delete_genre(req)
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        self.tables.lock().await.attach_genre(req)
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        self.tables.lock().await.detach_genre(req)
+    }
+
+    async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        self.tables.lock().await.find_author_genres(req)
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.tables.lock().await.find_authors_by_genre(req))
+    }
+}
+
+impl PublisherRepository for InMemoryRepository {
+    async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        self.tables.lock().await.create_publisher(req)
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        self.tables.lock().await.find_publisher(req)
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        Ok(self.tables.lock().await.find_all_publishers())
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        self.tables.lock().await.delete_publisher(req)
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        self.tables.lock().await.create_contract(req)
+    }
+
+    async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        self.tables.lock().await.find_author_contracts(req)
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        self.tables.lock().await.find_publisher_contracts(req)
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        self.tables.lock().await.delete_contract(req)
+    }
+}
+
+impl AuditRecorder for InMemoryRepository {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        Ok(self.tables.lock().await.record_audit(req))
+    }
+
+    async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(self.tables.lock().await.find_audit_log(req))
+    }
+
+    async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(self.tables.lock().await.find_changes(req))
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        Ok(self.tables.lock().await.latest_change_id())
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        Ok(self.tables.lock().await.erase_audit_snapshots(req))
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        Ok(self.tables.lock().await.record_erasure(req))
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        Ok(self.tables.lock().await.erasure_log.clone())
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        Ok(self.tables.lock().await.record_security_event(req))
+    }
+
+    async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        Ok(self.tables.lock().await.find_security_events(limit))
+    }
+}
+
+impl EventPublisher for InMemoryRepository {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        self.events.lock().await.push(event.clone());
+        Ok(())
+    }
+}
+
+impl CommandLog for InMemoryRepository {
+    async fn is_processed(&self, command_id: &str) -> Result<bool, CommandLogError> {
+        Ok(self
+            .tables
+            .lock()
+            .await
+            .processed_commands
+            .contains(command_id))
+    }
+
+    async fn mark_processed(&self, command_id: &str) -> Result<(), CommandLogError> {
+        self.tables
+            .lock()
+            .await
+            .processed_commands
+            .insert(command_id.to_string());
+        Ok(())
+    }
+}
+
+impl BlobStorage for InMemoryRepository {
+    async fn put(&self, key: &str, blob: &Blob) -> Result<(), PutBlobError> {
+        self.blobs.lock().await.insert(key.into(), blob.clone());
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, GetBlobError> {
+        self.blobs
+            .lock()
+            .await
+            .get(key)
+            .cloned()
+            .ok_or_else(|| GetBlobError::NotFound { key: key.into() })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DeleteBlobError> {
+        self.blobs.lock().await.remove(key);
+        Ok(())
+    }
+}
+
+/// Operations live as long as the process, so a restart forgets them along with their tasks.
+impl OperationStore for InMemoryRepository {
+    async fn save_operation(&self, operation: &Operation) -> Result<(), SaveOperationError> {
+        self.operations
+            .lock()
+            .await
+            .insert(operation.id(), operation.clone());
+        Ok(())
+    }
+
+    async fn find_operation(&self, id: OperationId) -> Result<Operation, FindOperationError> {
+        self.operations
+            .lock()
+            .await
+            .get(&id)
+            .cloned()
+            .ok_or(FindOperationError::NotFound { id })
+    }
+}
+
+impl SessionStore for InMemoryRepository {
+    async fn save_session(&self, session: &StoredSession) -> Result<(), SessionStoreError> {
+        self.sessions
+            .lock()
+            .await
+            .insert(session.id().clone(), session.clone());
+        Ok(())
+    }
+
+    async fn find_session(
+        &self,
+        id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Result<Option<StoredSession>, SessionStoreError> {
+        let mut sessions = self.sessions.lock().await;
+        sessions.retain(|_, session| !session.is_expired(now));
+        Ok(sessions.get(id).cloned())
+    }
+
+    async fn delete_session(&self, id: &SessionId) -> Result<(), SessionStoreError> {
+        self.sessions.lock().await.remove(id);
+        Ok(())
+    }
+}
+
+impl UnitOfWork for InMemoryRepository {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        let guard = Arc::clone(&self.tables).lock_owned().await;
+        let working = guard.clone();
+        Ok(Box::new(InMemoryTransaction {
+            guard,
+            working: Mutex::new(working),
+        }))
+    }
+}
+
+struct InMemoryTransaction {
+    guard: OwnedMutexGuard<Tables>,
+    working: Mutex<Tables>,
+}
+
+impl Transaction for InMemoryTransaction {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            let Self { mut guard, working } = *self;
+            *guard = working.into_inner();
+            Ok(())
+        })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl AuthorRepository for InMemoryTransaction {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.working.lock().await.create_author(req)
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.working.lock().await.find_author(req)
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.working.lock().await.find_author_by_email(req)
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_all_authors())
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_authors_by_ids(req))
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_sorted_authors(req))
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_projected_authors(req))
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        Ok(self.working.lock().await.authors.len() as u64)
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        Ok(self.working.lock().await.authors.contains_key(&req.id()))
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        let authors = self.working.lock().await.find_all_authors();
+        stream::iter(authors.into_iter().map(Ok)).boxed()
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.working.lock().await.update_author(req)
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.working.lock().await.upsert_author(req)
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.working.lock().await.upsert_author_by_email(req)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.working.lock().await.set_author_status(req)
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.working.lock().await.delete_author(req)
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.working.lock().await.add_author_alias(req)
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.working.lock().await.remove_author_alias(req)
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.working.lock().await.find_author_aliases(req)
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.search_authors(req))
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        Ok(self.working.lock().await.author_stats(req))
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_authors_by_verification(req))
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.working.lock().await.set_email_verification(req);
+        Ok(())
+    }
+}
+
+impl GenreRepository for InMemoryTransaction {
+    async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        self.working.lock().await.create_genre(req)
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        Ok(self.working.lock().await.find_all_genres())
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        self.working.lock().await.delete_genre(req)
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        self.working.lock().await.attach_genre(req)
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        self.working.lock().await.detach_genre(req)
+    }
+
+    async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        self.working.lock().await.find_author_genres(req)
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(self.working.lock().await.find_authors_by_genre(req))
+    }
+}
+
+impl PublisherRepository for InMemoryTransaction {
+    async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        self.working.lock().await.create_publisher(req)
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        self.working.lock().await.find_publisher(req)
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        Ok(self.working.lock().await.find_all_publishers())
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        self.working.lock().await.delete_publisher(req)
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        self.working.lock().await.create_contract(req)
+    }
+
+    async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        self.working.lock().await.find_author_contracts(req)
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        self.working.lock().await.find_publisher_contracts(req)
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        self.working.lock().await.delete_contract(req)
+    }
+}
+
+impl AuditRecorder for InMemoryTransaction {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        Ok(self.working.lock().await.record_audit(req))
+    }
+
+    async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(self.working.lock().await.find_audit_log(req))
+    }
+
+    async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(self.working.lock().await.find_changes(req))
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        Ok(self.working.lock().await.latest_change_id())
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        Ok(self.working.lock().await.erase_audit_snapshots(req))
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        Ok(self.working.lock().await.record_erasure(req))
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        Ok(self.working.lock().await.erasure_log.clone())
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        Ok(self.working.lock().await.record_security_event(req))
+    }
+
+    async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        Ok(self.working.lock().await.find_security_events(limit))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryCommandQueue {
+    sender: mpsc::UnboundedSender<QueuedCommand>,
+    receiver: Arc<Mutex<mpsc::UnboundedReceiver<QueuedCommand>>>,
+    closed: Arc<AtomicBool>,
+}
+
+#[derive(Debug)]
+struct QueuedCommand {
+    payload: Vec<u8>,
+    attempt: u32,
+}
+
+impl InMemoryCommandQueue {
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver: Arc::new(Mutex::new(receiver)),
+            closed: Arc::default(),
+        }
+    }
+
+    pub fn send(&self, payload: Vec<u8>) {
+        let _ = self.sender.send(QueuedCommand {
+            payload,
+            attempt: 1,
+        });
+    }
+
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Default for InMemoryCommandQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandQueue for InMemoryCommandQueue {
+    async fn receive(&self) -> anyhow::Result<Option<Box<dyn CommandDelivery>>> {
+        let mut receiver = self.receiver.lock().await;
+        let next = if self.closed.load(Ordering::SeqCst) {
+            receiver.try_recv().ok()
+        } else {
+            receiver.recv().await
+        };
+        Ok(next.map(|command| {
+            Box::new(InMemoryDelivery {
+                command,
+                sender: self.sender.clone(),
+            }) as Box<dyn CommandDelivery>
+        }))
+    }
+}
+
+struct InMemoryDelivery {
+    command: QueuedCommand,
+    sender: mpsc::UnboundedSender<QueuedCommand>,
+}
+
+impl CommandDelivery for InMemoryDelivery {
+    fn payload(&self) -> &[u8] {
+        &self.command.payload
+    }
+
+    fn attempt(&self) -> u32 {
+        self.command.attempt
+    }
+
+    fn ack(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn retry(self: Box<Self>, delay: Duration) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            let _ = self.sender.send(QueuedCommand {
+                payload: self.command.payload,
+                attempt: self.command.attempt + 1,
+            });
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuthorName, CreateAuthorRequest, EmailAddress};
+    use crate::domain::ports::contract::{
+        genre_repository_contract_tests, publisher_repository_contract_tests,
+        repository_contract_tests,
+    };
+    use crate::domain::ports::{AuthorRepository, UnitOfWork};
+    use crate::outbound::memory::InMemoryRepository;
+
+    fn create_request(name: &str) -> CreateAuthorRequest {
+        CreateAuthorRequest::new(
+            AuthorName::new(name).unwrap(),
+            EmailAddress::new("author@example.com").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn author_repository_conforms() {
+        repository_contract_tests(InMemoryRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn genre_repository_conforms() {
+        let repo = InMemoryRepository::new();
+        genre_repository_contract_tests(&repo, &repo).await;
+    }
+
+    #[tokio::test]
+    async fn publisher_repository_conforms() {
+        let repo = InMemoryRepository::new();
+        publisher_repository_contract_tests(&repo, &repo).await;
+    }
+
+    #[tokio::test]
+    async fn transaction_commit_persists_changes() {
+        let repo = InMemoryRepository::new();
+        let tx = repo.begin().await.unwrap();
+        tx.authors()
+            .create_author(&create_request("JRR Tolkien"))
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let authors = repo.find_all_authors().await.unwrap();
+        assert_eq!(1, authors.len(), "expected committed author to be visible");
+    }
+
+    #[tokio::test]
+    async fn transaction_rollback_discards_changes() {
+        let repo = InMemoryRepository::new();
+        let tx = repo.begin().await.unwrap();
+        tx.authors()
+            .create_author(&create_request("JRR Tolkien"))
+            .await
+            .unwrap();
+        tx.rollback().await.unwrap();
+
+        let authors = repo.find_all_authors().await.unwrap();
+        assert!(authors.is_empty(), "expected rolled back author to be gone");
+    }
+}
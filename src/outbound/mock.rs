@@ -0,0 +1,774 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditEntry, Author,
+    AuthorGenreRequest, AuthorName, AuthorStats, AuthorStatsRequest, ChangeAuthorStatusError,
+    Contract, CreateAuthorError, CreateAuthorRequest, CreateContractError, CreateContractRequest,
+    CreateGenreError, CreateGenreRequest, CreatePublisherError, CreatePublisherRequest,
+    DeleteAuthorError, DeleteAuthorRequest, DeleteContractError, DeleteContractRequest,
+    DeleteGenreError, DeleteGenreRequest, DeletePublisherError, DeletePublisherRequest,
+    DetachGenreError, ERASURE_LOG_GENESIS, ErasureRecord, FindAllAuthorsError, FindAllGenresError,
+    FindAllPublishersError, FindAuditLogError, FindAuditLogRequest, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByGenreRequest,
+    FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest, FindChangesRequest,
+    FindProjectedAuthorsRequest, FindPublisherError, FindPublisherRequest,
+    FindSortedAuthorsRequest, Genre, ProjectedAuthor, Publisher, RecordAuditError,
+    RecordAuditRequest, RecordErasureRequest, RecordSecurityEventRequest, RemoveAuthorAliasError,
+    RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor,
+    SearchAuthorsRequest, SecurityEvent, SetAuthorStatusRequest, SetEmailVerificationError,
+    SetEmailVerificationRequest, UpdateAuthorError, UpdateAuthorRequest, UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, DynAuditRecorder, DynAuthorRepository, DynGenreRepository,
+    DynPublisherRepository, GenreRepository, PublisherRepository, Transaction, UnitOfWork,
+};
+use anyhow::anyhow;
+use chrono::Utc;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+type Responder<Req, Res> = Box<dyn Fn(&Req) -> Res + Send + Sync>;
+
+struct ExpectationState<Req, Res> {
+    responder: Option<Responder<Req, Res>>,
+    times: Option<usize>,
+    calls: usize,
+}
+
+/// Canned behaviour for one `AuthorRepository` method. Handles returned by the `expect_*`
+/// methods share state with the mock, so they can be configured and inspected after the mock
+/// has been handed to a service.
+pub struct Expectation<Req, Res> {
+    method: &'static str,
+    fallback: fn() -> Res,
+    state: Arc<Mutex<ExpectationState<Req, Res>>>,
+}
+
+impl<Req, Res> Expectation<Req, Res> {
+    fn new(method: &'static str, fallback: fn() -> Res) -> Self {
+        Self {
+            method,
+            fallback,
+            state: Arc::new(Mutex::new(ExpectationState {
+                responder: None,
+                times: None,
+                calls: 0,
+            })),
+        }
+    }
+
+    /// Answers every call with the result of `responder`. Unconfigured methods fail with an
+    /// internal error.
+    pub fn returning<F>(self, responder: F) -> Self
+    where
+        F: Fn(&Req) -> Res + Send + Sync + 'static,
+    {
+        self.lock().responder = Some(Box::new(responder));
+        self
+    }
+
+    /// Expects exactly `times` calls by the next [`MockAuthorRepository::verify`].
+    pub fn times(self, times: usize) -> Self {
+        self.lock().times = Some(times);
+        self
+    }
+
+    #[must_use]
+    pub fn calls(&self) -> usize {
+        self.lock().calls
+    }
+
+    fn call(&self, req: &Req) -> Res {
+        let mut state = self.lock();
+        state.calls += 1;
+        state
+            .responder
+            .as_ref()
+            .map_or_else(|| (self.fallback)(), |responder| responder(req))
+    }
+
+    fn verify(&self) {
+        let state = self.lock();
+        if let Some(times) = state.times {
+            assert_eq!(
+                times, state.calls,
+                "expected {} to be called {times} time(s), but it was called {} time(s)",
+                self.method, state.calls
+            );
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ExpectationState<Req, Res>> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl<Req, Res> Clone for Expectation<Req, Res> {
+    fn clone(&self) -> Self {
+        Self {
+            method: self.method,
+            fallback: self.fallback,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// An `AuthorRepository` with scripted responses. It also acts as a no-op audit recorder and
+/// unit of work, so a single mock can back an `AuthorService`.
+#[derive(Clone)]
+pub struct MockAuthorRepository {
+    create: Expectation<CreateAuthorRequest, Result<Author, CreateAuthorError>>,
+    find: Expectation<FindAuthorRequest, Result<Author, FindAuthorError>>,
+    find_by_email: Expectation<FindAuthorByEmailRequest, Result<Author, FindAuthorByEmailError>>,
+    find_all: Expectation<(), Result<Vec<Author>, FindAllAuthorsError>>,
+    find_by_ids: Expectation<FindAuthorsByIdsRequest, Result<Vec<Author>, FindAllAuthorsError>>,
+    find_sorted: Expectation<FindSortedAuthorsRequest, Result<Vec<Author>, FindAllAuthorsError>>,
+    find_projected:
+        Expectation<FindProjectedAuthorsRequest, Result<Vec<ProjectedAuthor>, FindAllAuthorsError>>,
+    stream_all: Expectation<(), Result<Vec<Author>, FindAllAuthorsError>>,
+    count: Expectation<(), Result<u64, FindAllAuthorsError>>,
+    exists: Expectation<FindAuthorRequest, Result<bool, FindAuthorError>>,
+    update: Expectation<UpdateAuthorRequest, Result<Author, UpdateAuthorError>>,
+    upsert: Expectation<ReplaceAuthorRequest, Result<Author, ReplaceAuthorError>>,
+    upsert_by_email: Expectation<CreateAuthorRequest, Result<ReplacedAuthor, UpsertAuthorError>>,
+    set_status: Expectation<SetAuthorStatusRequest, Result<(), ChangeAuthorStatusError>>,
+    delete: Expectation<DeleteAuthorRequest, Result<(), DeleteAuthorError>>,
+    add_alias: Expectation<AddAuthorAliasRequest, Result<(), AddAuthorAliasError>>,
+    remove_alias: Expectation<RemoveAuthorAliasRequest, Result<(), RemoveAuthorAliasError>>,
+    find_aliases: Expectation<FindAuthorRequest, Result<Vec<AuthorName>, FindAuthorError>>,
+    search: Expectation<SearchAuthorsRequest, Result<Vec<Author>, FindAllAuthorsError>>,
+    stats: Expectation<AuthorStatsRequest, Result<AuthorStats, FindAllAuthorsError>>,
+    find_by_verification:
+        Expectation<FindAuthorsByVerificationRequest, Result<Vec<Author>, FindAllAuthorsError>>,
+    set_verification:
+        Expectation<SetEmailVerificationRequest, Result<(), SetEmailVerificationError>>,
+}
+
+impl MockAuthorRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            create: Expectation::new("create_author", || {
+                Err(CreateAuthorError::Other(anyhow!("substitute error")))
+            }),
+            find: Expectation::new("find_author", || {
+                Err(FindAuthorError::Other(anyhow!("substitute error")))
+            }),
+            find_by_email: Expectation::new("find_author_by_email", || {
+                Err(FindAuthorByEmailError::Other(anyhow!("substitute error")))
+            }),
+            find_all: Expectation::new("find_all_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            find_by_ids: Expectation::new("find_authors_by_ids", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            find_sorted: Expectation::new("find_sorted_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            find_projected: Expectation::new("find_projected_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            stream_all: Expectation::new("stream_all_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            count: Expectation::new("count_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            exists: Expectation::new("author_exists", || {
+                Err(FindAuthorError::Other(anyhow!("substitute error")))
+            }),
+            update: Expectation::new("update_author", || {
+                Err(UpdateAuthorError::Other(anyhow!("substitute error")))
+            }),
+            upsert: Expectation::new("upsert_author", || {
+                Err(ReplaceAuthorError::Other(anyhow!("substitute error")))
+            }),
+            upsert_by_email: Expectation::new("upsert_author_by_email", || {
+                Err(UpsertAuthorError::Other(anyhow!("substitute error")))
+            }),
+            set_status: Expectation::new("set_author_status", || {
+                Err(ChangeAuthorStatusError::Other(anyhow!("substitute error")))
+            }),
+            delete: Expectation::new("delete_author", || {
+                Err(DeleteAuthorError::Other(anyhow!("substitute error")))
+            }),
+            add_alias: Expectation::new("add_author_alias", || {
+                Err(AddAuthorAliasError::Other(anyhow!("substitute error")))
+            }),
+            remove_alias: Expectation::new("remove_author_alias", || {
+                Err(RemoveAuthorAliasError::Other(anyhow!("substitute error")))
+            }),
+            find_aliases: Expectation::new("find_author_aliases", || {
+                Err(FindAuthorError::Other(anyhow!("substitute error")))
+            }),
+            search: Expectation::new("search_authors", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            stats: Expectation::new("author_stats", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            find_by_verification: Expectation::new("find_authors_by_verification", || {
+                Err(FindAllAuthorsError(anyhow!("substitute error")))
+            }),
+            set_verification: Expectation::new("set_email_verification", || {
+                Err(SetEmailVerificationError(anyhow!("substitute error")))
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn expect_create(
+        &self,
+    ) -> Expectation<CreateAuthorRequest, Result<Author, CreateAuthorError>> {
+        self.create.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find(&self) -> Expectation<FindAuthorRequest, Result<Author, FindAuthorError>> {
+        self.find.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find_by_email(
+        &self,
+    ) -> Expectation<FindAuthorByEmailRequest, Result<Author, FindAuthorByEmailError>> {
+        self.find_by_email.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find_all(&self) -> Expectation<(), Result<Vec<Author>, FindAllAuthorsError>> {
+        self.find_all.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find_by_ids(
+        &self,
+    ) -> Expectation<FindAuthorsByIdsRequest, Result<Vec<Author>, FindAllAuthorsError>> {
+        self.find_by_ids.clone()
+    }
+
+    pub fn expect_find_sorted(
+        &self,
+    ) -> Expectation<FindSortedAuthorsRequest, Result<Vec<Author>, FindAllAuthorsError>> {
+        self.find_sorted.clone()
+    }
+
+    pub fn expect_find_projected(
+        &self,
+    ) -> Expectation<FindProjectedAuthorsRequest, Result<Vec<ProjectedAuthor>, FindAllAuthorsError>>
+    {
+        self.find_projected.clone()
+    }
+
+    /// Streamed authors are scripted as a whole list; an error ends the stream after one item.
+    #[must_use]
+    pub fn expect_stream_all(&self) -> Expectation<(), Result<Vec<Author>, FindAllAuthorsError>> {
+        self.stream_all.clone()
+    }
+
+    #[must_use]
+    pub fn expect_count(&self) -> Expectation<(), Result<u64, FindAllAuthorsError>> {
+        self.count.clone()
+    }
+
+    #[must_use]
+    pub fn expect_exists(&self) -> Expectation<FindAuthorRequest, Result<bool, FindAuthorError>> {
+        self.exists.clone()
+    }
+
+    #[must_use]
+    pub fn expect_update(
+        &self,
+    ) -> Expectation<UpdateAuthorRequest, Result<Author, UpdateAuthorError>> {
+        self.update.clone()
+    }
+
+    #[must_use]
+    pub fn expect_upsert(
+        &self,
+    ) -> Expectation<ReplaceAuthorRequest, Result<Author, ReplaceAuthorError>> {
+        self.upsert.clone()
+    }
+
+    #[must_use]
+    pub fn expect_upsert_by_email(
+        &self,
+    ) -> Expectation<CreateAuthorRequest, Result<ReplacedAuthor, UpsertAuthorError>> {
+        self.upsert_by_email.clone()
+    }
+
+    #[must_use]
+    pub fn expect_set_status(
+        &self,
+    ) -> Expectation<SetAuthorStatusRequest, Result<(), ChangeAuthorStatusError>> {
+        self.set_status.clone()
+    }
+
+    #[must_use]
+    pub fn expect_delete(&self) -> Expectation<DeleteAuthorRequest, Result<(), DeleteAuthorError>> {
+        self.delete.clone()
+    }
+
+    #[must_use]
+    pub fn expect_add_alias(
+        &self,
+    ) -> Expectation<AddAuthorAliasRequest, Result<(), AddAuthorAliasError>> {
+        self.add_alias.clone()
+    }
+
+    #[must_use]
+    pub fn expect_remove_alias(
+        &self,
+    ) -> Expectation<RemoveAuthorAliasRequest, Result<(), RemoveAuthorAliasError>> {
+        self.remove_alias.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find_aliases(
+        &self,
+    ) -> Expectation<FindAuthorRequest, Result<Vec<AuthorName>, FindAuthorError>> {
+        self.find_aliases.clone()
+    }
+
+    #[must_use]
+    pub fn expect_search(
+        &self,
+    ) -> Expectation<SearchAuthorsRequest, Result<Vec<Author>, FindAllAuthorsError>> {
+        self.search.clone()
+    }
+
+    #[must_use]
+    pub fn expect_stats(
+        &self,
+    ) -> Expectation<AuthorStatsRequest, Result<AuthorStats, FindAllAuthorsError>> {
+        self.stats.clone()
+    }
+
+    #[must_use]
+    pub fn expect_find_by_verification(
+        &self,
+    ) -> Expectation<FindAuthorsByVerificationRequest, Result<Vec<Author>, FindAllAuthorsError>>
+    {
+        self.find_by_verification.clone()
+    }
+
+    #[must_use]
+    pub fn expect_set_verification(
+        &self,
+    ) -> Expectation<SetEmailVerificationRequest, Result<(), SetEmailVerificationError>> {
+        self.set_verification.clone()
+    }
+
+    /// Panics if any method configured with [`Expectation::times`] was called a different
+    /// number of times.
+    pub fn verify(&self) {
+        self.create.verify();
+        self.find.verify();
+        self.find_all.verify();
+        self.find_by_ids.verify();
+        self.stream_all.verify();
+        self.count.verify();
+        self.exists.verify();
+        self.update.verify();
+        self.upsert.verify();
+        self.upsert_by_email.verify();
+        self.set_status.verify();
+        self.delete.verify();
+        self.add_alias.verify();
+        self.remove_alias.verify();
+        self.find_aliases.verify();
+        self.search.verify();
+        self.stats.verify();
+        self.find_by_verification.verify();
+        self.set_verification.verify();
+    }
+}
+
+impl Default for MockAuthorRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthorRepository for MockAuthorRepository {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.create.call(req)
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.find.call(req)
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.find_by_email.call(req)
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.find_all.call(&())
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.find_by_ids.call(req)
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.find_sorted.call(req)
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.find_projected.call(req)
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        match self.stream_all.call(&()) {
+            Ok(authors) => stream::iter(authors.into_iter().map(Ok)).boxed(),
+            Err(err) => stream::once(async { Err(err) }).boxed(),
+        }
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.count.call(&())
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.exists.call(req)
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.update.call(req)
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.upsert.call(req)
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.upsert_by_email.call(req)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.set_status.call(req)
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.delete.call(req)
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.add_alias.call(req)
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.remove_alias.call(req)
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.find_aliases.call(req)
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.search.call(req)
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.stats.call(req)
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.find_by_verification.call(req)
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.set_verification.call(req)
+    }
+}
+
+impl AuditRecorder for MockAuthorRepository {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        Ok(AuditEntry::new(1, req.clone(), Utc::now()))
+    }
+
+    async fn find_audit_log(
+        &self,
+        _: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_changes(
+        &self,
+        _: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        Ok(Vec::new())
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        Ok(None)
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        _: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        Ok(0)
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        let erased_at = Utc::now();
+        let previous_hash = ERASURE_LOG_GENESIS.to_string();
+        let hash = req.hash(&previous_hash, erased_at);
+        Ok(ErasureRecord::new(
+            1,
+            req.clone(),
+            erased_at,
+            previous_hash,
+            hash,
+        ))
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        Ok(Vec::new())
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        Ok(SecurityEvent::new(1, req.clone(), Utc::now()))
+    }
+
+    async fn find_security_events(&self, _: u32) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        Ok(Vec::new())
+    }
+}
+
+/// The mock has no genres, so authors never have any and genres are never found.
+impl GenreRepository for MockAuthorRepository {
+    async fn create_genre(&self, _: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        Err(anyhow!("genres are not mocked").into())
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        Err(DeleteGenreError::NotFound { id: req.id() })
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        Err(AttachGenreError::GenreNotFound { id: req.genre_id() })
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        Err(DetachGenreError::NotFound {
+            author_id: req.author_id(),
+            genre_id: req.genre_id(),
+        })
+    }
+
+    async fn find_author_genres(
+        &self,
+        _: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        _: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        Ok(Vec::new())
+    }
+}
+
+/// The mock has no publishers, so every lookup misses.
+impl PublisherRepository for MockAuthorRepository {
+    async fn create_publisher(
+        &self,
+        _: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        Err(anyhow!("publishers are not mocked").into())
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        Err(FindPublisherError::NotFound { id: req.id() })
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        Err(DeletePublisherError::NotFound { id: req.id() })
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        Err(CreateContractError::PublisherNotFound {
+            id: req.publisher_id(),
+        })
+    }
+
+    async fn find_author_contracts(
+        &self,
+        _: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        Err(FindPublisherError::NotFound { id: req.id() })
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        Err(DeleteContractError::NotFound {
+            author_id: req.author_id(),
+            contract_id: req.contract_id(),
+        })
+    }
+}
+
+impl UnitOfWork for MockAuthorRepository {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        Ok(Box::new(self.clone()))
+    }
+}
+
+impl Transaction for MockAuthorRepository {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        Author, AuthorId, AuthorName, CreateAuthorRequest, EmailAddress, FindAuthorError,
+        FindAuthorRequest,
+    };
+    use crate::domain::ports::AuthorRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn expectations_answer_calls_and_count_them() {
+        let mock = MockAuthorRepository::new();
+        mock.expect_create()
+            .returning(|req| {
+                let now = Utc::now();
+                Ok(Author::new(
+                    AuthorId::new(1),
+                    req.name().clone(),
+                    req.email().clone(),
+                    now,
+                    now,
+                ))
+            })
+            .times(1);
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+
+        let author = mock.create_author(&req).await.unwrap();
+        assert_eq!("JRR Tolkien", author.name().to_string());
+        let actual = mock.find_author(&FindAuthorRequest::new(author.id())).await;
+        assert!(
+            matches!(actual, Err(FindAuthorError::Other(_))),
+            "expected unconfigured method to fail, but got {actual:?}"
+        );
+        assert_eq!(1, mock.expect_find().calls());
+        mock.verify();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected create_author to be called 2 time(s)")]
+    fn verify_panics_on_unmet_call_counts() {
+        let mock = MockAuthorRepository::new();
+        mock.expect_create().times(2);
+        mock.verify();
+    }
+}
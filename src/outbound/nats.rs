@@ -0,0 +1,155 @@
+use crate::domain::model::{AuthorEvent, PublishEventError};
+use crate::domain::ports::EventPublisher;
+use crate::inbound::commands::{CommandDelivery, CommandQueue};
+use crate::outbound::events::{EventPublisherConfig, encode_event};
+use anyhow::{Context, anyhow};
+use async_nats::jetstream;
+use async_nats::jetstream::AckKind;
+use async_nats::jetstream::consumer::{AckPolicy, pull};
+use async_nats::{Client, ServerAddr};
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+pub struct NatsEventPublisher {
+    jetstream: jetstream::Context,
+    config: EventPublisherConfig,
+}
+
+impl NatsEventPublisher {
+    pub async fn connect(config: EventPublisherConfig) -> anyhow::Result<Self> {
+        let client = connect_client(&config).await?;
+        Ok(Self {
+            jetstream: jetstream::new(client),
+            config,
+        })
+    }
+}
+
+impl EventPublisher for NatsEventPublisher {
+    async fn publish(&self, event: &AuthorEvent) -> Result<(), PublishEventError> {
+        let subject = self.config.topic(event);
+        let payload = encode_event(event).context("Failed to serialize author event")?;
+        self.jetstream
+            .publish(subject.clone(), payload.into())
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| format!("Failed to publish author event to {subject}"))?
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| format!("NATS did not acknowledge author event on {subject}"))?;
+        Ok(())
+    }
+}
+
+pub struct NatsCommandQueue {
+    messages: Mutex<pull::Stream>,
+}
+
+impl NatsCommandQueue {
+    pub async fn connect(
+        config: &EventPublisherConfig,
+        stream: &str,
+        subject: &str,
+    ) -> anyhow::Result<Self> {
+        let client = connect_client(config).await?;
+        let stream = jetstream::new(client)
+            .get_or_create_stream(jetstream::stream::Config {
+                name: stream.to_string(),
+                subjects: vec![subject.to_string()],
+                ..Default::default()
+            })
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| format!("Failed to open NATS stream {stream}"))?;
+        let consumer = stream
+            .get_or_create_consumer(
+                "author-commands",
+                pull::Config {
+                    durable_name: Some("author-commands".to_string()),
+                    ack_policy: AckPolicy::Explicit,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|err| anyhow!(err))
+            .context("Failed to create NATS consumer for author commands")?;
+        let messages = consumer
+            .messages()
+            .await
+            .map_err(|err| anyhow!(err))
+            .context("Failed to subscribe to author commands")?;
+
+        Ok(Self {
+            messages: Mutex::new(messages),
+        })
+    }
+}
+
+impl CommandQueue for NatsCommandQueue {
+    async fn receive(&self) -> anyhow::Result<Option<Box<dyn CommandDelivery>>> {
+        let Some(message) = self.messages.lock().await.next().await else {
+            return Ok(None);
+        };
+        let message = message
+            .map_err(|err| anyhow!(err))
+            .context("Failed to receive author command")?;
+        Ok(Some(Box::new(NatsDelivery(message))))
+    }
+}
+
+struct NatsDelivery(jetstream::Message);
+
+impl CommandDelivery for NatsDelivery {
+    fn payload(&self) -> &[u8] {
+        &self.0.payload
+    }
+
+    fn attempt(&self) -> u32 {
+        self.0
+            .info()
+            .map_or(1, |info| u32::try_from(info.delivered).unwrap_or(u32::MAX))
+    }
+
+    fn ack(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            self.0
+                .ack()
+                .await
+                .map_err(|err| anyhow!(err))
+                .context("Failed to acknowledge author command")
+        })
+    }
+
+    fn retry(self: Box<Self>, delay: Duration) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            self.0
+                .ack_with(AckKind::Nak(Some(delay)))
+                .await
+                .map_err(|err| anyhow!(err))
+                .context("Failed to request redelivery of author command")
+        })
+    }
+}
+
+async fn connect_client(config: &EventPublisherConfig) -> anyhow::Result<Client> {
+    let servers = config
+        .brokers()
+        .iter()
+        .map(|broker| {
+            broker
+                .parse::<ServerAddr>()
+                .with_context(|| format!("Invalid NATS server address {broker}"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut options = async_nats::ConnectOptions::new();
+    if let (Some(username), Some(password)) = (config.username(), config.password()) {
+        options = options.user_and_password(username.into(), password.into());
+    }
+    options
+        .connect(servers)
+        .await
+        .context("Failed to connect to NATS")
+}
@@ -0,0 +1,360 @@
+use crate::domain::model::{OidcConfig, TokenResponse};
+use crate::domain::ports::IdentityProvider;
+use anyhow::Context;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::signature::{
+    ECDSA_P256_SHA256_FIXED, ED25519, RSA_PKCS1_2048_8192_SHA256, RsaPublicKeyComponents,
+    UnparsedPublicKey,
+};
+use serde::Deserialize;
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::sync::OnceCell;
+use url::Url;
+
+#[derive(Debug, Deserialize)]
+struct ProviderMetadata {
+    authorization_endpoint: Url,
+    token_endpoint: Url,
+    jwks_uri: Url,
+}
+
+#[derive(Debug, Deserialize)]
+struct JwtHeader {
+    alg: String,
+    kid: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonWebKeySet {
+    keys: Vec<JsonWebKey>,
+}
+
+/// A public key from the provider's JWKS. Only the members needed to check RS256, ES256 and
+/// EdDSA signatures are read.
+#[derive(Debug, Deserialize)]
+struct JsonWebKey {
+    kty: String,
+    kid: Option<String>,
+    crv: Option<String>,
+    n: Option<String>,
+    e: Option<String>,
+    x: Option<String>,
+    y: Option<String>,
+}
+
+impl JsonWebKey {
+    fn member(&self, member: &Option<String>, name: &str) -> anyhow::Result<Vec<u8>> {
+        let value = member
+            .as_deref()
+            .with_context(|| format!("Signing key has no {name}"))?;
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .with_context(|| format!("Signing key {name} is not base64url"))
+    }
+
+    /// Whether `signature` over `message` was made with this key using `alg`.
+    fn verify(&self, alg: &str, message: &[u8], signature: &[u8]) -> anyhow::Result<bool> {
+        let verified = match (alg, self.kty.as_str(), self.crv.as_deref()) {
+            ("RS256", "RSA", _) => RsaPublicKeyComponents {
+                n: self.member(&self.n, "n")?,
+                e: self.member(&self.e, "e")?,
+            }
+            .verify(&RSA_PKCS1_2048_8192_SHA256, message, signature),
+            ("ES256", "EC", Some("P-256")) => {
+                let mut point = vec![0x04];
+                point.extend(self.member(&self.x, "x")?);
+                point.extend(self.member(&self.y, "y")?);
+                UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, point).verify(message, signature)
+            }
+            ("EdDSA", "OKP", Some("Ed25519")) => {
+                UnparsedPublicKey::new(&ED25519, self.member(&self.x, "x")?)
+                    .verify(message, signature)
+            }
+            _ => return Ok(false),
+        };
+        Ok(verified.is_ok())
+    }
+}
+
+/// Talks to an OpenID provider found through its discovery document, which is fetched once per
+/// process. ID tokens are only handed on once their signature checks out against the provider's
+/// JWKS, which is fetched again when a token names a key it does not hold, so rotated keys are
+/// picked up.
+#[derive(Debug)]
+pub struct OidcClient {
+    http: reqwest::Client,
+    config: OidcConfig,
+    metadata: OnceCell<ProviderMetadata>,
+    keys: RwLock<Option<Arc<JsonWebKeySet>>>,
+}
+
+impl OidcClient {
+    pub fn new(config: OidcConfig) -> anyhow::Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("hexarch-example/", env!("CARGO_PKG_VERSION")))
+            .timeout(config.timeout())
+            .build()
+            .context("Failed to build the OIDC HTTP client")?;
+        Ok(Self {
+            http,
+            config,
+            metadata: OnceCell::new(),
+            keys: RwLock::new(None),
+        })
+    }
+
+    async fn metadata(&self) -> anyhow::Result<&ProviderMetadata> {
+        self.metadata
+            .get_or_try_init(|| async {
+                let issuer = self.config.issuer().as_str().trim_end_matches('/');
+                let url = format!("{issuer}/.well-known/openid-configuration");
+                self.http
+                    .get(url)
+                    .send()
+                    .await
+                    .context("Failed to reach the OpenID provider")?
+                    .error_for_status()
+                    .context("OpenID provider refused the discovery request")?
+                    .json()
+                    .await
+                    .context("Failed to decode the OpenID provider metadata")
+            })
+            .await
+    }
+
+    async fn fetch_keys(&self) -> anyhow::Result<Arc<JsonWebKeySet>> {
+        let url = self.metadata().await?.jwks_uri.clone();
+        let keys: JsonWebKeySet = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .context("Failed to reach the OpenID provider's signing keys")?
+            .error_for_status()
+            .context("OpenID provider refused the signing keys request")?
+            .json()
+            .await
+            .context("Failed to decode the OpenID provider's signing keys")?;
+        let keys = Arc::new(keys);
+        *self.keys.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::clone(&keys));
+        Ok(keys)
+    }
+
+    async fn verify_signature(&self, id_token: &str) -> anyhow::Result<()> {
+        let (message, signature) = id_token.rsplit_once('.').context("ID token is not a JWT")?;
+        let header = message.split('.').next().unwrap_or_default();
+        let header: JwtHeader = serde_json::from_slice(
+            &URL_SAFE_NO_PAD
+                .decode(header)
+                .context("ID token header is not base64url")?,
+        )
+        .context("ID token header is not JSON")?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .context("ID token signature is not base64url")?;
+        let holds_key = |keys: &JsonWebKeySet| {
+            header.kid.is_none()
+                || keys
+                    .keys
+                    .iter()
+                    .any(|key| key.kid.as_ref() == header.kid.as_ref())
+        };
+
+        let cached = self
+            .keys
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let keys = match cached {
+            Some(keys) if holds_key(&keys) => keys,
+            _ => self.fetch_keys().await?,
+        };
+        for key in keys
+            .keys
+            .iter()
+            .filter(|key| header.kid.is_none() || key.kid == header.kid)
+        {
+            if key.verify(&header.alg, message.as_bytes(), &signature)? {
+                return Ok(());
+            }
+        }
+        anyhow::bail!(
+            "ID token signature does not match any {} key of the OpenID provider",
+            header.alg
+        )
+    }
+
+    async fn token(&self, grant: &[(&str, &str)]) -> anyhow::Result<TokenResponse> {
+        let endpoint = self.metadata().await?.token_endpoint.clone();
+        let mut form = vec![("client_id", self.config.client_id())];
+        form.extend_from_slice(grant);
+        if let Some(secret) = self.config.client_secret() {
+            form.push(("client_secret", secret));
+        }
+        let tokens: TokenResponse = self
+            .http
+            .post(endpoint)
+            .form(&form)
+            .send()
+            .await
+            .context("Failed to reach the token endpoint")?
+            .error_for_status()
+            .context("Token endpoint refused the grant")?
+            .json()
+            .await
+            .context("Failed to decode the token response")?;
+        if let Some(id_token) = tokens.id_token() {
+            self.verify_signature(id_token).await?;
+        }
+        Ok(tokens)
+    }
+}
+
+impl IdentityProvider for OidcClient {
+    async fn authorization_url(
+        &self,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> anyhow::Result<Url> {
+        let mut url = self.metadata().await?.authorization_endpoint.clone();
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", self.config.client_id())
+            .append_pair("redirect_uri", self.config.redirect_url().as_str())
+            .append_pair("scope", self.config.scopes())
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", "S256");
+        Ok(url)
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> anyhow::Result<TokenResponse> {
+        self.token(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.config.redirect_url().as_str()),
+            ("code_verifier", code_verifier),
+        ])
+        .await
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> anyhow::Result<TokenResponse> {
+        self.token(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::OidcConfig;
+    use crate::domain::ports::IdentityProvider;
+    use crate::outbound::oidc::OidcClient;
+    use axum::routing::{get, post};
+    use axum::{Form, Json, Router};
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use std::collections::HashMap;
+
+    fn key_pair() -> Ed25519KeyPair {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+    }
+
+    fn sign(key: &Ed25519KeyPair, kid: &str) -> String {
+        let header = serde_json::json!({ "alg": "EdDSA", "kid": kid });
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(r#"{"sub":"f3a9"}"#),
+        );
+        let signature = URL_SAFE_NO_PAD.encode(key.sign(message.as_bytes()));
+        format!("{message}.{signature}")
+    }
+
+    #[tokio::test]
+    async fn codes_are_exchanged_at_the_discovered_token_endpoint() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let metadata = serde_json::json!({
+            "issuer": issuer,
+            "authorization_endpoint": format!("{issuer}/authorize"),
+            "token_endpoint": format!("{issuer}/token"),
+            "jwks_uri": format!("{issuer}/jwks"),
+        });
+        let key = key_pair();
+        let jwks = serde_json::json!({ "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "kid": "k-1",
+            "x": URL_SAFE_NO_PAD.encode(key.public_key()),
+        }]});
+        let signed = sign(&key, "k-1");
+        let forged = sign(&key_pair(), "k-1");
+        let id_token = signed.clone();
+        let router = Router::new()
+            .route(
+                "/.well-known/openid-configuration",
+                get(move || async move { Json(metadata) }),
+            )
+            .route("/jwks", get(move || async move { Json(jwks) }))
+            .route(
+                "/token",
+                post(
+                    move |Form(form): Form<HashMap<String, String>>| async move {
+                        let ok = form["grant_type"] == "authorization_code"
+                            && form["code"] == "c-1"
+                            && form["code_verifier"] == "v-1"
+                            && form["client_secret"] == "shh";
+                        Json(serde_json::json!({
+                            "id_token": if ok { id_token } else { forged },
+                            "refresh_token": "r-1",
+                            "expires_in": 300,
+                        }))
+                    },
+                ),
+            );
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        let config = OidcConfig::new(
+            issuer.parse().unwrap(),
+            "hexarch".into(),
+            "http://localhost/admin/callback".parse().unwrap(),
+            [0; 32],
+        )
+        .unwrap()
+        .with_client_secret(Some("shh".into()));
+        let client = OidcClient::new(config).unwrap();
+
+        let url = client
+            .authorization_url("s-1", "n-1", "ch-1")
+            .await
+            .unwrap();
+        assert!(url.as_str().starts_with(&format!("{issuer}/authorize?")));
+        let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!("S256", query["code_challenge_method"]);
+        assert_eq!("ch-1", query["code_challenge"]);
+        assert_eq!("http://localhost/admin/callback", query["redirect_uri"]);
+
+        let tokens = client.exchange_code("c-1", "v-1").await.unwrap();
+        assert_eq!(Some(signed.as_str()), tokens.id_token());
+        assert_eq!(Some("r-1"), tokens.refresh_token());
+        assert_eq!(300, tokens.expires_in().as_secs());
+
+        let forged = client.refresh("r-1").await;
+        assert!(
+            forged.is_err(),
+            "signed with a key the provider does not publish"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::domain::model::{AuthorName, Deadline, ExternalWork, FindExternalWorksError};
+use crate::domain::ports::BookCatalogClient;
+use crate::outbound::catalog::BookCatalogConfig;
+use anyhow::{Context, anyhow};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    docs: Vec<SearchDoc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchDoc {
+    key: String,
+    title: String,
+    first_publish_year: Option<i32>,
+}
+
+/// Queries the OpenLibrary search API, retrying timeouts, connection failures and 5xx answers.
+#[derive(Debug)]
+pub struct OpenLibraryClient {
+    http: reqwest::Client,
+    config: BookCatalogConfig,
+}
+
+impl OpenLibraryClient {
+    pub fn new(config: BookCatalogConfig) -> anyhow::Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("hexarch-example/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .context("Failed to build the book catalog HTTP client")?;
+        Ok(Self { http, config })
+    }
+
+    async fn search(&self, author: &AuthorName) -> Result<SearchResponse, Attempt> {
+        let timeout = Deadline::current().map_or(self.config.timeout(), |deadline| {
+            deadline.remaining().min(self.config.timeout())
+        });
+        if timeout.is_zero() {
+            return Err(Attempt::TimedOut);
+        }
+        let url = self
+            .config
+            .base_url()
+            .join("search.json")
+            .map_err(|err| Attempt::Failed(anyhow!(err)))?;
+        let name = author.to_string();
+        let limit = self.config.max_works().to_string();
+        let response = self
+            .http
+            .get(url)
+            .query(&[
+                ("author", name.as_str()),
+                ("fields", "key,title,first_publish_year"),
+                ("limit", &limit),
+            ])
+            .timeout(timeout)
+            .send()
+            .await
+            .map_err(Attempt::from)?;
+        let status = response.status();
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Attempt::Transient(anyhow!(
+                "Book catalog answered with {status}"
+            )));
+        }
+        let response = response
+            .error_for_status()
+            .map_err(|err| Attempt::Failed(anyhow!(err)))?;
+        response.json().await.map_err(Attempt::from)
+    }
+
+    fn work(&self, doc: SearchDoc) -> Option<ExternalWork> {
+        let url = self.config.base_url().join(doc.key.trim_start_matches('/'));
+        url.ok()
+            .map(|url| ExternalWork::new(doc.title, doc.first_publish_year, url))
+    }
+}
+
+enum Attempt {
+    TimedOut,
+    Transient(anyhow::Error),
+    Failed(anyhow::Error),
+}
+
+impl From<reqwest::Error> for Attempt {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::TimedOut
+        } else if err.is_connect() || err.is_request() {
+            Self::Transient(anyhow!(err))
+        } else {
+            Self::Failed(anyhow!(err))
+        }
+    }
+}
+
+impl BookCatalogClient for OpenLibraryClient {
+    async fn find_works(
+        &self,
+        author: &AuthorName,
+    ) -> Result<Vec<ExternalWork>, FindExternalWorksError> {
+        let retry = self.config.retry();
+        let mut attempt = 1;
+        let response = loop {
+            let err = match self.search(author).await {
+                Ok(response) => break response,
+                Err(Attempt::Failed(err)) => {
+                    return Err(err.context("Failed to query the book catalog").into());
+                }
+                Err(err) if attempt >= retry.max_attempts() => {
+                    return Err(match err {
+                        Attempt::Transient(err) | Attempt::Failed(err) => {
+                            err.context("Failed to query the book catalog").into()
+                        }
+                        Attempt::TimedOut => FindExternalWorksError::TimedOut,
+                    });
+                }
+                Err(err) => err,
+            };
+            let delay = retry.backoff(attempt);
+            metrics::counter!("book_catalog_retries_total").increment(1);
+            let reason = match &err {
+                Attempt::TimedOut => "timed out".to_string(),
+                Attempt::Transient(err) | Attempt::Failed(err) => err.to_string(),
+            };
+            tracing::warn!(
+                attempt,
+                delay_ms = delay.as_millis(),
+                "Retrying book catalog request: {reason}"
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        };
+        Ok(response
+            .docs
+            .into_iter()
+            .filter_map(|doc| self.work(doc))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{AuthorName, FindExternalWorksError};
+    use crate::domain::ports::BookCatalogClient;
+    use crate::outbound::breaker::CircuitBreakerConfig;
+    use crate::outbound::catalog::BookCatalogConfig;
+    use crate::outbound::openlibrary::OpenLibraryClient;
+    use crate::outbound::retry::RetryConfig;
+    use axum::Router;
+    use axum::extract::Query;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    async fn serve(router: Router) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        Url::parse(&format!("http://{addr}/")).unwrap()
+    }
+
+    fn client(base_url: Url, timeout: Duration) -> OpenLibraryClient {
+        let retry = RetryConfig::new(3, Duration::from_millis(1), Duration::from_millis(1));
+        let breaker = CircuitBreakerConfig::new(5, Duration::from_secs(30));
+        OpenLibraryClient::new(BookCatalogConfig::new(base_url, timeout, retry, breaker)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn works_are_fetched_by_author_name_after_transient_failures() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let router = Router::new().route(
+            "/search.json",
+            get({
+                let calls = Arc::clone(&calls);
+                move |Query(params): Query<HashMap<String, String>>| async move {
+                    if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                        return Err(StatusCode::BAD_GATEWAY);
+                    }
+                    assert_eq!("Ursula K. Le Guin", params["author"]);
+                    Ok(axum::Json(json!({"docs": [
+                        {"key": "/works/OL59863W", "title": "A Wizard of Earthsea", "first_publish_year": 1968},
+                        {"key": "/works/OL59896W", "title": "Untitled draft"},
+                    ]})))
+                }
+            }),
+        );
+        let client = client(serve(router).await, Duration::from_secs(5));
+
+        let author = AuthorName::new("Ursula K. Le Guin").unwrap();
+        let works = client.find_works(&author).await.unwrap();
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+        assert_eq!("A Wizard of Earthsea", works[0].title());
+        assert_eq!(Some(1968), works[0].first_published_year());
+        assert!(works[0].url().as_str().ends_with("/works/OL59863W"));
+        assert_eq!(None, works[1].first_published_year());
+    }
+
+    #[tokio::test]
+    async fn slow_catalogs_time_out_after_every_attempt() {
+        let router = Router::new().route(
+            "/search.json",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                "{}"
+            }),
+        );
+        let client = client(serve(router).await, Duration::from_millis(20));
+
+        let author = AuthorName::new("Octavia E. Butler").unwrap();
+        let actual = client.find_works(&author).await;
+        assert!(
+            matches!(actual, Err(FindExternalWorksError::TimedOut)),
+            "expected a timeout, but got {actual:?}"
+        );
+    }
+}
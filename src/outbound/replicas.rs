@@ -0,0 +1,524 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, FindAllAuthorsError, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+    ProjectedAuthor, RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError,
+    ReplaceAuthorRequest, ReplacedAuthor, SearchAuthorsRequest, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, DynAuditRecorder, DynAuthorRepository,
+};
+use futures::stream::BoxStream;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReplicaSelection {
+    #[default]
+    RoundRobin,
+    LeastLoaded,
+}
+
+impl FromStr for ReplicaSelection {
+    type Err = ReplicaSelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round-robin" => Ok(Self::RoundRobin),
+            "least-loaded" => Ok(Self::LeastLoaded),
+            _ => Err(ReplicaSelectionError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(
+    r#""{0}" is not a valid replica selection, expected one of "round-robin" or "least-loaded""#
+)]
+pub struct ReplicaSelectionError(String);
+
+#[derive(Debug, Clone)]
+pub struct ReplicaConfig {
+    selection: ReplicaSelection,
+    max_lag: i64,
+    health_check_interval: Duration,
+}
+
+impl ReplicaConfig {
+    /// `max_lag` is the number of audited changes a replica may trail the primary by before
+    /// reads stop going to it.
+    #[must_use]
+    pub const fn new(
+        selection: ReplicaSelection,
+        max_lag: i64,
+        health_check_interval: Duration,
+    ) -> Self {
+        Self {
+            selection,
+            max_lag,
+            health_check_interval,
+        }
+    }
+}
+
+struct Replica {
+    repo: Box<dyn DynAuthorRepository>,
+    changes: Arc<dyn DynAuditRecorder>,
+    healthy: AtomicBool,
+    in_flight: AtomicUsize,
+}
+
+impl Replica {
+    fn failed(&self, index: usize, err: &anyhow::Error) {
+        tracing::warn!(replica = index, "Falling back to the primary: {err:?}");
+        self.healthy.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Releases a replica's in-flight slot when the read finishes.
+struct Lease<'a> {
+    index: usize,
+    replica: &'a Replica,
+}
+
+impl Drop for Lease<'_> {
+    fn drop(&mut self) {
+        self.replica.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Sends writes to the primary and spreads reads across healthy replicas. A replica that fails
+/// a read or falls too far behind is skipped until a health check finds it caught up.
+#[derive(Clone)]
+pub struct ReplicatedAuthorRepository {
+    primary: Arc<dyn DynAuthorRepository>,
+    primary_changes: Arc<dyn DynAuditRecorder>,
+    replicas: Arc<Vec<Replica>>,
+    next: Arc<AtomicUsize>,
+    config: ReplicaConfig,
+}
+
+impl ReplicatedAuthorRepository {
+    pub fn new(
+        primary: impl AuthorRepository,
+        primary_changes: impl AuditRecorder,
+        config: ReplicaConfig,
+    ) -> Self {
+        Self {
+            primary: Arc::new(primary),
+            primary_changes: Arc::new(primary_changes),
+            replicas: Arc::new(Vec::new()),
+            next: Arc::new(AtomicUsize::new(0)),
+            config,
+        }
+    }
+
+    /// Replicas start out healthy; call before sharing the repository.
+    #[must_use]
+    pub fn with_replica(
+        mut self,
+        repo: impl AuthorRepository,
+        changes: impl AuditRecorder,
+    ) -> Self {
+        Arc::get_mut(&mut self.replicas)
+            .expect("replicas are added before the repository is shared")
+            .push(Replica {
+                repo: Box::new(repo),
+                changes: Arc::new(changes),
+                healthy: AtomicBool::new(true),
+                in_flight: AtomicUsize::new(0),
+            });
+        self
+    }
+
+    #[must_use]
+    pub fn healthy_replicas(&self) -> usize {
+        self.replicas
+            .iter()
+            .filter(|replica| replica.healthy.load(Ordering::Relaxed))
+            .count()
+    }
+
+    /// Probes every replica and marks it healthy only if it answers and is within the lag limit.
+    pub async fn check_replicas(&self) {
+        let primary = match self.primary_changes.latest_change_id().await {
+            Ok(id) => id.unwrap_or_default(),
+            Err(err) => {
+                tracing::warn!("Failed to read the primary's latest change: {err:?}");
+                return;
+            }
+        };
+        for (index, replica) in self.replicas.iter().enumerate() {
+            let healthy = match replica.changes.latest_change_id().await {
+                Ok(id) => {
+                    let lag = primary - id.unwrap_or_default();
+                    if lag > self.config.max_lag {
+                        tracing::warn!(replica = index, lag, "Replica is lagging");
+                    }
+                    lag <= self.config.max_lag
+                }
+                Err(err) => {
+                    tracing::warn!(replica = index, "Replica health check failed: {err:?}");
+                    false
+                }
+            };
+            replica.healthy.store(healthy, Ordering::Relaxed);
+        }
+    }
+
+    pub async fn run_health_checks(self) {
+        let mut ticker = tokio::time::interval(self.config.health_check_interval);
+        loop {
+            ticker.tick().await;
+            self.check_replicas().await;
+        }
+    }
+
+    fn replica(&self) -> Option<Lease<'_>> {
+        let healthy = self
+            .replicas
+            .iter()
+            .enumerate()
+            .filter(|(_, replica)| replica.healthy.load(Ordering::Relaxed));
+        let (index, replica) = match self.config.selection {
+            ReplicaSelection::RoundRobin => {
+                let healthy: Vec<_> = healthy.collect();
+                if healthy.is_empty() {
+                    return None;
+                }
+                healthy[self.next.fetch_add(1, Ordering::Relaxed) % healthy.len()]
+            }
+            ReplicaSelection::LeastLoaded => {
+                healthy.min_by_key(|(_, replica)| replica.in_flight.load(Ordering::Relaxed))?
+            }
+        };
+        replica.in_flight.fetch_add(1, Ordering::Relaxed);
+        Some(Lease { index, replica })
+    }
+}
+
+impl AuthorRepository for ReplicatedAuthorRepository {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.primary.create_author(req).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_author(req).await {
+                Err(FindAuthorError::Other(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_author(req).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_author_by_email(req).await {
+                Err(FindAuthorByEmailError::Other(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_author_by_email(req).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_all_authors().await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_all_authors().await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_authors_by_ids(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_authors_by_ids(req).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_sorted_authors(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_sorted_authors(req).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_projected_authors(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_projected_authors(req).await
+    }
+
+    /// Streams come from the primary: a replica failing mid-stream could not fall back cleanly.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.primary.stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.count_authors().await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.count_authors().await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.author_exists(req).await {
+                Err(FindAuthorError::Other(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.author_exists(req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.primary.update_author(req).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.primary.upsert_author(req).await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.primary.upsert_author_by_email(req).await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.primary.set_author_status(req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.primary.delete_author(req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.primary.add_author_alias(req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.primary.remove_author_alias(req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_author_aliases(req).await {
+                Err(FindAuthorError::Other(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_author_aliases(req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.search_authors(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.search_authors(req).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.author_stats(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.author_stats(req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        if let Some(lease) = self.replica() {
+            match lease.replica.repo.find_authors_by_verification(req).await {
+                Err(FindAllAuthorsError(err)) => lease.replica.failed(lease.index, &err),
+                result => return result,
+            }
+        }
+        self.primary.find_authors_by_verification(req).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.primary.set_email_verification(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AuditAction, AuditContext, AuthorName, CreateAuthorRequest, EmailAddress,
+        FindAllAuthorsError, RecordAuditRequest,
+    };
+    use crate::domain::ports::{AuditRecorder, AuthorRepository};
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use crate::outbound::replicas::{ReplicaConfig, ReplicaSelection, ReplicatedAuthorRepository};
+    use std::time::Duration;
+
+    fn create_request(name: &str, email: &str) -> CreateAuthorRequest {
+        CreateAuthorRequest::new(
+            AuthorName::new(name).unwrap(),
+            EmailAddress::new(email).unwrap(),
+        )
+    }
+
+    fn config(max_lag: i64) -> ReplicaConfig {
+        ReplicaConfig::new(
+            ReplicaSelection::RoundRobin,
+            max_lag,
+            Duration::from_secs(5),
+        )
+    }
+
+    async fn record_changes(repo: &InMemoryRepository, count: usize) {
+        let author = repo
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        for _ in 0..count {
+            let req = RecordAuditRequest::new(
+                author.id(),
+                AuditAction::Create,
+                AuditContext::new("admin".into(), None),
+                None,
+                None,
+            );
+            repo.record(&req).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_rotate_across_replicas_and_writes_go_to_the_primary() {
+        let primary = InMemoryRepository::new();
+        let (first, second) = (InMemoryRepository::new(), InMemoryRepository::new());
+        first
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        second
+            .create_author(&create_request("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        let repo = ReplicatedAuthorRepository::new(primary.clone(), primary.clone(), config(0))
+            .with_replica(first.clone(), first.clone())
+            .with_replica(second.clone(), second.clone());
+
+        let mut names: Vec<_> = Vec::new();
+        for _ in 0..2 {
+            let authors = repo.find_all_authors().await.unwrap();
+            names.push(authors[0].name().to_string());
+        }
+        names.sort();
+        assert_eq!(vec!["CS Lewis", "JRR Tolkien"], names);
+
+        repo.create_author(&create_request("Ursula Le Guin", "ursula@example.com"))
+            .await
+            .unwrap();
+        assert_eq!(1, primary.count_authors().await.unwrap());
+        assert_eq!(1, first.count_authors().await.unwrap());
+        assert_eq!(1, second.count_authors().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn failing_replica_falls_back_to_the_primary() {
+        let primary = InMemoryRepository::new();
+        primary
+            .create_author(&create_request("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+        let replica = MockAuthorRepository::new();
+        replica
+            .expect_count()
+            .returning(|()| Err(FindAllAuthorsError(anyhow::anyhow!("replica is down"))))
+            .times(1);
+        let repo = ReplicatedAuthorRepository::new(primary.clone(), primary, config(0))
+            .with_replica(replica.clone(), replica.clone());
+
+        assert_eq!(1, repo.count_authors().await.unwrap());
+        assert_eq!(0, repo.healthy_replicas());
+        assert_eq!(1, repo.count_authors().await.unwrap());
+        replica.verify();
+    }
+
+    #[tokio::test]
+    async fn lagging_replicas_are_skipped_until_they_catch_up() {
+        let primary = InMemoryRepository::new();
+        let replica = InMemoryRepository::new();
+        record_changes(&primary, 3).await;
+        let repo = ReplicatedAuthorRepository::new(primary.clone(), primary, config(1))
+            .with_replica(replica.clone(), replica.clone());
+
+        repo.check_replicas().await;
+        assert_eq!(0, repo.healthy_replicas());
+
+        record_changes(&replica, 2).await;
+        repo.check_replicas().await;
+        assert_eq!(1, repo.healthy_replicas());
+    }
+}
@@ -0,0 +1,536 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, FindAllAuthorsError, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+    ProjectedAuthor, RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError,
+    ReplaceAuthorRequest, ReplacedAuthor, SearchAuthorsRequest, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, UpdateAuthorError, UpdateAuthorRequest,
+    UpsertAuthorError,
+};
+use crate::domain::ports::{AuthorRepository, Transaction, UnitOfWork};
+use crate::outbound::sqlite::is_transient;
+use futures::stream::BoxStream;
+use rand::Rng;
+use std::future::Future;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryConfig {
+    /// `max_attempts` counts the first try, so `1` disables retries.
+    #[must_use]
+    pub const fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+}
+
+trait RetryableError {
+    fn is_retryable(&self) -> bool;
+}
+
+impl RetryableError for FindAuthorError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Other(err) if is_transient(err))
+    }
+}
+
+impl RetryableError for FindAuthorByEmailError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Other(err) if is_transient(err))
+    }
+}
+
+impl RetryableError for FindAllAuthorsError {
+    fn is_retryable(&self) -> bool {
+        is_transient(&self.0)
+    }
+}
+
+impl RetryableError for SetEmailVerificationError {
+    fn is_retryable(&self) -> bool {
+        is_transient(&self.0)
+    }
+}
+
+impl RetryableError for ReplaceAuthorError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Other(err) if is_transient(err))
+    }
+}
+
+impl RetryableError for UpsertAuthorError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Other(err) if is_transient(err))
+    }
+}
+
+/// Retries idempotent operations that fail with a transient error. Reads and upserts are
+/// repeated; other writes are passed through once, since a failure may hide a committed change.
+/// Writes made in a transaction are retried as a whole by [`RetryingUnitOfWork`].
+///
+/// Wrap it in a [`crate::outbound::breaker::CircuitBreaker`] so that only exhausted retries count as
+/// failures.
+#[derive(Debug)]
+pub struct RetryingAuthorRepository<R> {
+    inner: R,
+    config: RetryConfig,
+}
+
+impl<R: AuthorRepository> RetryingAuthorRepository<R> {
+    pub const fn new(inner: R, config: RetryConfig) -> Self {
+        Self { inner, config }
+    }
+
+    async fn retry<T, E, F, Fut>(&self, operation: &'static str, call: F) -> Result<T, E>
+    where
+        E: RetryableError,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match call().await {
+                Err(err) if err.is_retryable() && attempt < self.config.max_attempts => {
+                    let delay = self.config.backoff(attempt);
+                    metrics::counter!("author_repository_retries_total", "operation" => operation)
+                        .increment(1);
+                    tracing::warn!(
+                        operation,
+                        attempt,
+                        delay_ms = delay.as_millis(),
+                        "Retrying transient repository error"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) if err.is_retryable() => {
+                    metrics::counter!(
+                        "author_repository_retries_exhausted_total",
+                        "operation" => operation
+                    )
+                    .increment(1);
+                    return Err(err);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<R: AuthorRepository> AuthorRepository for RetryingAuthorRepository<R> {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.inner.create_author(req).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.retry("find_author", || self.inner.find_author(req))
+            .await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.retry("find_author_by_email", || {
+            self.inner.find_author_by_email(req)
+        })
+        .await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.retry("find_all_authors", || self.inner.find_all_authors())
+            .await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.retry("find_authors_by_ids", || {
+            self.inner.find_authors_by_ids(req)
+        })
+        .await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.retry("find_sorted_authors", || {
+            self.inner.find_sorted_authors(req)
+        })
+        .await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.retry("find_projected_authors", || {
+            self.inner.find_projected_authors(req)
+        })
+        .await
+    }
+
+    /// Streams are not retried, as items may already have been sent to the caller.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.inner.stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.retry("count_authors", || self.inner.count_authors())
+            .await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.retry("author_exists", || self.inner.author_exists(req))
+            .await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.inner.update_author(req).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.retry("upsert_author", || self.inner.upsert_author(req))
+            .await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.retry("upsert_author_by_email", || {
+            self.inner.upsert_author_by_email(req)
+        })
+        .await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.inner.set_author_status(req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.inner.delete_author(req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.inner.add_author_alias(req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.inner.remove_author_alias(req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.retry("find_author_aliases", || {
+            self.inner.find_author_aliases(req)
+        })
+        .await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.retry("search_authors", || self.inner.search_authors(req))
+            .await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.retry("author_stats", || self.inner.author_stats(req))
+            .await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.retry("find_authors_by_verification", || {
+            self.inner.find_authors_by_verification(req)
+        })
+        .await
+    }
+
+    /// Recording the same outcome twice is harmless, so this write is retried too.
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.retry("set_email_verification", || {
+            self.inner.set_email_verification(req)
+        })
+        .await
+    }
+}
+
+/// Has the service run a unit of work again, in a new transaction, when it fails with a transient
+/// error. Whole transactions are repeated rather than single statements: a failed transaction is
+/// rolled back, so its writes can be repeated however many of them had already run.
+#[derive(Debug)]
+pub struct RetryingUnitOfWork<U> {
+    inner: U,
+    config: RetryConfig,
+}
+
+impl<U: UnitOfWork> RetryingUnitOfWork<U> {
+    pub const fn new(inner: U, config: RetryConfig) -> Self {
+        Self { inner, config }
+    }
+}
+
+impl<U: UnitOfWork> UnitOfWork for RetryingUnitOfWork<U> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        self.inner.begin().await
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        if !is_transient(err) {
+            return None;
+        }
+        if attempt >= self.config.max_attempts {
+            metrics::counter!("unit_of_work_retries_exhausted_total").increment(1);
+            return None;
+        }
+        let delay = self.config.backoff(attempt);
+        metrics::counter!("unit_of_work_retries_total").increment(1);
+        tracing::warn!(
+            attempt,
+            delay_ms = delay.as_millis(),
+            "Retrying transaction after a transient error"
+        );
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AuditContext, AuthorId, AuthorName, CreateAuthorRequest, EmailAddress, FindAllAuthorsError,
+        FindAuthorError, FindAuthorRequest,
+    };
+    use crate::domain::ports::{self, AuthorRepository, Transaction, UnitOfWork};
+    use crate::domain::service::AuthorService;
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::mock::MockAuthorRepository;
+    use crate::outbound::retry::{RetryConfig, RetryingAuthorRepository, RetryingUnitOfWork};
+    use futures::future::BoxFuture;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn config(max_attempts: u32) -> RetryConfig {
+        RetryConfig::new(
+            max_attempts,
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn transient_errors_are_retried_until_success() {
+        let repo = MockAuthorRepository::new();
+        let failures = Arc::new(AtomicUsize::new(2));
+        let expectation = repo
+            .expect_count()
+            .returning(move |()| {
+                if failures.fetch_sub(1, Ordering::Relaxed) > 0 {
+                    Err(FindAllAuthorsError(sqlx::Error::PoolTimedOut.into()))
+                } else {
+                    Ok(7)
+                }
+            })
+            .times(3);
+        let retrying = RetryingAuthorRepository::new(repo.clone(), config(3));
+
+        assert_eq!(7, retrying.count_authors().await.unwrap());
+        assert_eq!(3, expectation.calls());
+        repo.verify();
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_max_attempts() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_count()
+            .returning(|()| Err(FindAllAuthorsError(sqlx::Error::PoolTimedOut.into())))
+            .times(2);
+        let retrying = RetryingAuthorRepository::new(repo.clone(), config(2));
+
+        assert!(retrying.count_authors().await.is_err());
+        repo.verify();
+    }
+
+    #[tokio::test]
+    async fn permanent_errors_are_not_retried() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find()
+            .returning(|_| Err(FindAuthorError::Other(anyhow::anyhow!("corrupt row"))))
+            .times(1);
+        let retrying = RetryingAuthorRepository::new(repo.clone(), config(3));
+
+        let req = FindAuthorRequest::new(AuthorId::new(1));
+        assert!(retrying.find_author(&req).await.is_err());
+        repo.verify();
+    }
+
+    /// Fails the commit of its first `failures` transactions with a transient error.
+    #[derive(Clone)]
+    struct FlakyCommits {
+        inner: InMemoryRepository,
+        failures: Arc<AtomicUsize>,
+        begun: Arc<AtomicUsize>,
+    }
+
+    impl UnitOfWork for FlakyCommits {
+        async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+            self.begun.fetch_add(1, Ordering::Relaxed);
+            let fail = self
+                .failures
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok();
+            Ok(Box::new(FlakyTransaction {
+                inner: self.inner.begin().await?,
+                fail,
+            }))
+        }
+    }
+
+    struct FlakyTransaction {
+        inner: Box<dyn Transaction>,
+        fail: bool,
+    }
+
+    impl Transaction for FlakyTransaction {
+        fn authors(&self) -> &dyn ports::DynAuthorRepository {
+            self.inner.authors()
+        }
+
+        fn genres(&self) -> &dyn ports::DynGenreRepository {
+            self.inner.genres()
+        }
+
+        fn audit(&self) -> &dyn ports::DynAuditRecorder {
+            self.inner.audit()
+        }
+
+        fn publishers(&self) -> &dyn ports::DynPublisherRepository {
+            self.inner.publishers()
+        }
+
+        fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+            if self.fail {
+                let rollback = self.inner.rollback();
+                return Box::pin(async move {
+                    rollback.await?;
+                    Err(sqlx::Error::PoolTimedOut.into())
+                });
+            }
+            self.inner.commit()
+        }
+
+        fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+            self.inner.rollback()
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_commit_failures_rerun_the_whole_unit_of_work() {
+        let repo = InMemoryRepository::new();
+        let uow = FlakyCommits {
+            inner: repo.clone(),
+            failures: Arc::new(AtomicUsize::new(2)),
+            begun: Arc::new(AtomicUsize::new(0)),
+        };
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            RetryingUnitOfWork::new(uow.clone(), config(3)),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+
+        let author = service.create_author(&req, &ctx).await.unwrap();
+        assert_eq!(3, uow.begun.load(Ordering::Relaxed));
+        let find = FindAuthorRequest::new(author.id());
+        assert_eq!(author.id(), repo.find_author(&find).await.unwrap().id());
+        assert_eq!(1, repo.count_authors().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn units_of_work_stop_after_max_attempts() {
+        let repo = InMemoryRepository::new();
+        let uow = FlakyCommits {
+            inner: repo.clone(),
+            failures: Arc::new(AtomicUsize::new(5)),
+            begun: Arc::new(AtomicUsize::new(0)),
+        };
+        let service = AuthorService::new(
+            repo.clone(),
+            repo.clone(),
+            RetryingUnitOfWork::new(uow.clone(), config(2)),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+            repo.clone(),
+        );
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let ctx = AuditContext::new("admin".into(), None);
+
+        assert!(service.create_author(&req, &ctx).await.is_err());
+        assert_eq!(2, uow.begun.load(Ordering::Relaxed));
+        assert_eq!(0, repo.count_authors().await.unwrap());
+    }
+}
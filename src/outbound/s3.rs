@@ -0,0 +1,79 @@
+use crate::domain::model::{Blob, DeleteBlobError, GetBlobError, PutBlobError};
+use crate::domain::ports::BlobStorage;
+use crate::outbound::blobs::BlobStorageConfig;
+use anyhow::{Context, anyhow};
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::path::Path;
+use object_store::{Attribute, Attributes, ObjectStore, ObjectStoreExt, PutOptions};
+
+#[derive(Debug)]
+pub struct S3BlobStorage {
+    store: AmazonS3,
+}
+
+impl S3BlobStorage {
+    pub fn new(config: &BlobStorageConfig) -> anyhow::Result<Self> {
+        let bucket = config
+            .bucket()
+            .context("BLOB_STORAGE_BUCKET is required for the s3 blob backend")?;
+        let mut builder = AmazonS3Builder::from_env().with_bucket_name(bucket);
+        if let Some(endpoint) = config.endpoint() {
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_allow_http(endpoint.starts_with("http://"));
+        }
+        let store = builder.build().context("Failed to configure S3 client")?;
+        Ok(Self { store })
+    }
+}
+
+impl BlobStorage for S3BlobStorage {
+    async fn put(&self, key: &str, blob: &Blob) -> Result<(), PutBlobError> {
+        let mut attributes = Attributes::new();
+        attributes.insert(
+            Attribute::ContentType,
+            blob.content_type().to_string().into(),
+        );
+        let opts = PutOptions {
+            attributes,
+            ..PutOptions::default()
+        };
+        self.store
+            .put_opts(&Path::from(key), blob.bytes().to_vec().into(), opts)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("Failed to upload blob {key}")))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Blob, GetBlobError> {
+        let result = match self.store.get(&Path::from(key)).await {
+            Ok(result) => result,
+            Err(object_store::Error::NotFound { .. }) => {
+                return Err(GetBlobError::NotFound { key: key.into() });
+            }
+            Err(err) => {
+                return Err(anyhow!(err)
+                    .context(format!("Failed to fetch blob {key}"))
+                    .into());
+            }
+        };
+        let content_type = result.attributes.get(&Attribute::ContentType).map_or_else(
+            || "application/octet-stream".to_string(),
+            |value| value.as_ref().to_string(),
+        );
+        let bytes = result
+            .bytes()
+            .await
+            .map_err(|err| anyhow!(err).context(format!("Failed to download blob {key}")))?;
+        Ok(Blob::new(content_type, bytes.to_vec()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DeleteBlobError> {
+        match self.store.delete(&Path::from(key)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(anyhow!(err)
+                .context(format!("Failed to delete blob {key}"))
+                .into()),
+        }
+    }
+}
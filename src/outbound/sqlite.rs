@@ -0,0 +1,4508 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, AttachGenreError, AuditContext, AuditEntry, Author,
+    AuthorField, AuthorFields, AuthorGenreRequest, AuthorId, AuthorIdStrategy, AuthorName,
+    AuthorProfile, AuthorSortField, AuthorStats, AuthorStatsRequest, AuthorStatus, Biography,
+    BirthDate, ChangeAuthorStatusError, CipherError, CommandLogError, Contract, ContractId,
+    ContractTerm, CountryCode, CreateAuthorError, CreateAuthorRequest, CreateBackupError,
+    CreateContractError, CreateContractRequest, CreateGenreError, CreateGenreRequest,
+    CreatePublisherError, CreatePublisherRequest, DeleteAuthorError, DeleteAuthorRequest,
+    DeleteContractError, DeleteContractRequest, DeleteGenreError, DeleteGenreRequest,
+    DeletePublisherError, DeletePublisherRequest, DetachGenreError, ERASURE_LOG_GENESIS,
+    EmailAddress, EmailVerification, ErasureRecord, FindAllAuthorsError, FindAllGenresError,
+    FindAllPublishersError, FindAuditLogError, FindAuditLogRequest, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByGenreRequest,
+    FindAuthorsByIdsRequest, FindAuthorsByVerificationRequest, FindChangesRequest,
+    FindMigrationsError, FindProjectedAuthorsRequest, FindPublisherError, FindPublisherRequest,
+    FindSortedAuthorsRequest, Genre, GenreId, GenreName, MigrationStatus, ProjectedAuthor,
+    Publisher, PublisherId, PublisherName, PurgeExpiredError, RecordAuditError, RecordAuditRequest,
+    RecordErasureRequest, RecordSecurityEventRequest, RemoveAuthorAliasError,
+    RemoveAuthorAliasRequest, ReplaceAuthorError, ReplaceAuthorRequest, ReplacedAuthor,
+    RestoreBackupError, RetentionPolicy, RetentionReport, RoyaltyPercent, SearchAuthorsRequest,
+    SecurityEvent, SecurityEventKind, SessionId, SessionStoreError, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, StoredSession, UpdateAuthorError,
+    UpdateAuthorRequest, UpsertAuthorError, WebsiteUrl,
+};
+use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, BackupStore, CommandLog, DynAuditRecorder,
+    DynAuthorRepository, DynGenreRepository, DynPublisherRepository, FieldCipher, GenreRepository,
+    MigrationStore, PublisherRepository, RetentionStore, SessionStore, Transaction, UnitOfWork,
+};
+use crate::outbound::cipher::PlaintextCipher;
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use log::LevelFilter;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::query::QueryAs;
+use sqlx::sqlite::{
+    SqliteArgumentValue, SqliteArguments, SqliteAutoVacuum, SqliteConnectOptions, SqliteConnection,
+    SqliteJournalMode, SqlitePoolOptions, SqliteRow, SqliteSynchronous, SqliteTypeInfo,
+    SqliteValueRef,
+};
+use sqlx::{
+    ConnectOptions, Connection, Decode, Encode, FromRow, QueryBuilder, Row, Sqlite, SqliteExecutor,
+    SqlitePool, Type, TypeInfo, ValueRef,
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::{Mutex, mpsc};
+use uuid::Uuid;
+
+mod write_queue;
+
+pub use write_queue::{WriteQueue, WriteTurn};
+
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+const FIND_AUTHOR_SQL: &str = "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at FROM author WHERE id = ?";
+const FIND_AUTHOR_BY_EMAIL_SQL: &str = "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at FROM author WHERE email_index = ?";
+const STREAM_BUFFER: usize = 64;
+const SQLITE_BUSY: i32 = 5;
+const SQLITE_LOCKED: i32 = 6;
+const FIND_ALL_AUTHORS_SQL: &str = "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at FROM author ORDER BY id";
+const COUNT_AUTHORS_SQL: &str = "SELECT COUNT(*) FROM author";
+const AUTHOR_STATUS_COUNTS_SQL: &str = "SELECT COUNT(*) AS total, \
+     COALESCE(SUM(status = 'active'), 0) AS active, \
+     COALESCE(SUM(status = 'archived'), 0) AS archived FROM author";
+const AUTHORS_BY_EMAIL_DOMAIN_SQL: &str = "SELECT email_domain AS domain, \
+     COUNT(*) AS count FROM author GROUP BY domain ORDER BY count DESC, domain";
+const AUTHORS_CREATED_PER_DAY_SQL: &str = "SELECT date(created_at) AS day, COUNT(*) AS count \
+     FROM author WHERE created_at >= ? GROUP BY day ORDER BY day";
+const AUTHOR_EXISTS_SQL: &str = "SELECT EXISTS(SELECT 1 FROM author WHERE id = ?)";
+const FIND_AUTHOR_ALIASES_SQL: &str =
+    "SELECT alias FROM author_alias WHERE author_id = ? ORDER BY alias";
+const SEARCH_AUTHORS_SQL: &str = "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at \
+     FROM author WHERE name LIKE ?1 ESCAPE '\\' \
+     OR id IN (SELECT author_id FROM author_alias WHERE alias LIKE ?1 ESCAPE '\\') ORDER BY id";
+const FIND_ALL_GENRES_SQL: &str = "SELECT id, name FROM genre ORDER BY name";
+const FIND_AUTHOR_GENRES_SQL: &str = "SELECT genre.id, genre.name FROM author_genre \
+     JOIN genre ON genre.id = author_genre.genre_id WHERE author_genre.author_id = ? \
+     ORDER BY genre.name";
+const FIND_AUTHORS_BY_GENRE_SQL: &str = "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at \
+     FROM author WHERE id IN (SELECT author_id FROM author_genre WHERE genre_id = ?) ORDER BY id";
+const FIND_ALL_PUBLISHERS_SQL: &str = "SELECT id, name FROM publisher ORDER BY name";
+const FIND_AUTHOR_CONTRACTS_SQL: &str = "SELECT id, author_id, publisher_id, starts_on, ends_on, \
+     royalty_basis_points FROM contract WHERE author_id = ? ORDER BY starts_on, id";
+const FIND_PUBLISHER_CONTRACTS_SQL: &str = "SELECT id, author_id, publisher_id, starts_on, \
+     ends_on, royalty_basis_points FROM contract WHERE publisher_id = ? ORDER BY starts_on, id";
+const FIND_AUDIT_LOG_SQL: &str = "SELECT id, author_id, action, actor, request_id, before, after, \
+     recorded_at FROM audit_log WHERE author_id = ? ORDER BY id";
+const FIND_CHANGES_SQL: &str = "SELECT id, author_id, action, actor, request_id, before, after, \
+     recorded_at FROM audit_log WHERE id > ? ORDER BY id LIMIT ?";
+const FIND_ERASURES_SQL: &str = "SELECT id, author_id, actor, request_id, anonymized_entries, \
+     erased_at, previous_hash, hash FROM erasure_log ORDER BY id";
+
+const FIND_SECURITY_EVENTS_SQL: &str = "SELECT id, kind, client, reason, expires_at, recorded_at \
+     FROM security_event ORDER BY id DESC LIMIT ?";
+
+#[derive(Debug, Clone)]
+pub struct ConnectRetryConfig {
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_wait: Duration,
+}
+
+impl ConnectRetryConfig {
+    #[must_use]
+    pub const fn new(initial_backoff: Duration, max_backoff: Duration, max_wait: Duration) -> Self {
+        Self {
+            initial_backoff,
+            max_backoff,
+            max_wait,
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    min_connections: u32,
+    max_connections: u32,
+    acquire_timeout: Duration,
+    statement_cache_capacity: usize,
+    auto_migrate: bool,
+    busy_timeout: Duration,
+    synchronous: SqliteSynchronous,
+    cache_size: Option<i64>,
+    mmap_size: Option<u64>,
+    auto_vacuum: SqliteAutoVacuum,
+    statements_level: LevelFilter,
+    slow_statement_threshold: Duration,
+}
+
+impl PoolConfig {
+    pub const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+    pub const DEFAULT_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+    pub const DEFAULT_SLOW_STATEMENT_THRESHOLD: Duration = Duration::from_secs(1);
+
+    #[must_use]
+    pub const fn new(
+        min_connections: u32,
+        max_connections: u32,
+        acquire_timeout: Duration,
+    ) -> Self {
+        Self {
+            min_connections,
+            max_connections,
+            acquire_timeout,
+            statement_cache_capacity: Self::DEFAULT_STATEMENT_CACHE_CAPACITY,
+            auto_migrate: true,
+            busy_timeout: Self::DEFAULT_BUSY_TIMEOUT,
+            synchronous: SqliteSynchronous::Full,
+            cache_size: None,
+            mmap_size: None,
+            auto_vacuum: SqliteAutoVacuum::None,
+            statements_level: LevelFilter::Debug,
+            slow_statement_threshold: Self::DEFAULT_SLOW_STATEMENT_THRESHOLD,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_statement_cache_capacity(mut self, capacity: usize) -> Self {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
+    /// Turn off for deployments where a separate job applies migrations.
+    #[must_use]
+    pub const fn with_auto_migrate(mut self, auto_migrate: bool) -> Self {
+        self.auto_migrate = auto_migrate;
+        self
+    }
+
+    /// How long a connection waits for another connection's write lock before failing with
+    /// `SQLITE_BUSY`.
+    #[must_use]
+    pub const fn with_busy_timeout(mut self, busy_timeout: Duration) -> Self {
+        self.busy_timeout = busy_timeout;
+        self
+    }
+
+    /// `NORMAL` is durable against application crashes in WAL mode and avoids an fsync per commit.
+    #[must_use]
+    pub const fn with_synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = synchronous;
+        self
+    }
+
+    /// Positive values are pages, negative values KiB, as in `PRAGMA cache_size`.
+    #[must_use]
+    pub const fn with_cache_size(mut self, cache_size: Option<i64>) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_mmap_size(mut self, mmap_size: Option<u64>) -> Self {
+        self.mmap_size = mmap_size;
+        self
+    }
+
+    /// Only takes effect on an existing database after a `VACUUM`.
+    #[must_use]
+    pub const fn with_auto_vacuum(mut self, auto_vacuum: SqliteAutoVacuum) -> Self {
+        self.auto_vacuum = auto_vacuum;
+        self
+    }
+
+    /// Logs every statement under the `sqlx::query` target with its SQL, duration and row
+    /// counts, at `level`, and statements slower than `slow_threshold` at `WARN`. Bound values
+    /// are never part of the SQL, so they are never logged.
+    #[must_use]
+    pub const fn with_query_log(mut self, level: LevelFilter, slow_threshold: Duration) -> Self {
+        self.statements_level = level;
+        self.slow_statement_threshold = slow_threshold;
+        self
+    }
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self::new(0, 10, Duration::from_secs(30))
+    }
+}
+
+pub async fn establish_pool(
+    path: &str,
+    retry: &ConnectRetryConfig,
+    pool_config: &PoolConfig,
+) -> anyhow::Result<SqlitePool> {
+    let mut opts = SqliteConnectOptions::from_str(path)
+        .with_context(|| format!("Invalid database path {path}"))?
+        .foreign_keys(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .statement_cache_capacity(pool_config.statement_cache_capacity)
+        .busy_timeout(pool_config.busy_timeout)
+        .synchronous(pool_config.synchronous)
+        .auto_vacuum(pool_config.auto_vacuum)
+        .log_statements(pool_config.statements_level)
+        .log_slow_statements(LevelFilter::Warn, pool_config.slow_statement_threshold);
+    if let Some(cache_size) = pool_config.cache_size {
+        opts = opts.pragma("cache_size", cache_size.to_string());
+    }
+    if let Some(mmap_size) = pool_config.mmap_size {
+        opts = opts.pragma("mmap_size", mmap_size.to_string());
+    }
+    let pool_opts = SqlitePoolOptions::new()
+        .min_connections(pool_config.min_connections)
+        .max_connections(pool_config.max_connections)
+        .acquire_timeout(pool_config.acquire_timeout);
+
+    let started = Instant::now();
+    let mut attempt = 1;
+    let pool = loop {
+        match pool_opts.clone().connect_with(opts.clone()).await {
+            Ok(pool) => break pool,
+            Err(err) => {
+                let backoff = retry.backoff(attempt);
+                let delay = backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0));
+                if started.elapsed() + delay > retry.max_wait {
+                    return Err(anyhow!(err)).with_context(|| {
+                        format!("Failed to open database at {path} after {attempt} attempts")
+                    });
+                }
+                tracing::warn!(
+                    attempt,
+                    delay_ms = delay.as_millis(),
+                    "Failed to open database at {path}: {err}"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    };
+
+    if pool_config.auto_migrate {
+        MIGRATOR.run(&pool).await?;
+    }
+
+    Ok(pool)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalCheckpointMode {
+    #[default]
+    Passive,
+    Full,
+    Restart,
+    Truncate,
+}
+
+impl WalCheckpointMode {
+    const fn pragma(self) -> &'static str {
+        match self {
+            Self::Passive => "PRAGMA wal_checkpoint(PASSIVE)",
+            Self::Full => "PRAGMA wal_checkpoint(FULL)",
+            Self::Restart => "PRAGMA wal_checkpoint(RESTART)",
+            Self::Truncate => "PRAGMA wal_checkpoint(TRUNCATE)",
+        }
+    }
+}
+
+impl FromStr for WalCheckpointMode {
+    type Err = WalCheckpointModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "passive" => Ok(Self::Passive),
+            "full" => Ok(Self::Full),
+            "restart" => Ok(Self::Restart),
+            "truncate" => Ok(Self::Truncate),
+            _ => Err(WalCheckpointModeError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(
+    r#""{0}" is not a valid WAL checkpoint mode, expected one of "passive", "full", "restart" or "truncate""#
+)]
+pub struct WalCheckpointModeError(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalCheckpoint {
+    busy: bool,
+    log_frames: i64,
+    checkpointed_frames: i64,
+}
+
+impl WalCheckpoint {
+    /// Set when a reader or writer kept the checkpoint from completing.
+    #[must_use]
+    pub const fn busy(&self) -> bool {
+        self.busy
+    }
+
+    #[must_use]
+    pub const fn log_frames(&self) -> i64 {
+        self.log_frames
+    }
+
+    #[must_use]
+    pub const fn checkpointed_frames(&self) -> i64 {
+        self.checkpointed_frames
+    }
+}
+
+/// Checkpoints the write-ahead log on a schedule, so it is folded back into the database even when
+/// readers are rarely idle long enough for SQLite's automatic checkpoint to catch up.
+#[derive(Debug, Clone)]
+pub struct WalCheckpointJob {
+    pool: SqlitePool,
+    interval: Duration,
+    mode: WalCheckpointMode,
+}
+
+impl WalCheckpointJob {
+    #[must_use]
+    pub const fn new(pool: SqlitePool, interval: Duration, mode: WalCheckpointMode) -> Self {
+        Self {
+            pool,
+            interval,
+            mode,
+        }
+    }
+
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match self.run_once().await {
+                Ok(checkpoint) if checkpoint.busy => tracing::warn!(
+                    log_frames = checkpoint.log_frames,
+                    checkpointed_frames = checkpoint.checkpointed_frames,
+                    "WAL checkpoint could not complete while the database was busy"
+                ),
+                Ok(checkpoint) => tracing::debug!(
+                    log_frames = checkpoint.log_frames,
+                    checkpointed_frames = checkpoint.checkpointed_frames,
+                    "Checkpointed the WAL"
+                ),
+                Err(err) => tracing::error!("WAL checkpoint failed: {err:?}"),
+            }
+        }
+    }
+
+    pub async fn run_once(&self) -> anyhow::Result<WalCheckpoint> {
+        let (busy, log_frames, checkpointed_frames): (i64, i64, i64) =
+            sqlx::query_as(self.mode.pragma())
+                .fetch_one(&self.pool)
+                .await
+                .context("Failed to checkpoint the WAL")?;
+        Ok(WalCheckpoint {
+            busy: busy != 0,
+            log_frames,
+            checkpointed_frames,
+        })
+    }
+}
+
+/// Applies, reverts and reports on the migrations embedded in the binary.
+#[derive(Debug, Clone)]
+pub struct Migrations {
+    pool: SqlitePool,
+}
+
+impl Migrations {
+    #[must_use]
+    pub const fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn run(&self) -> anyhow::Result<()> {
+        MIGRATOR
+            .run(&self.pool)
+            .await
+            .context("Failed to apply migrations")
+    }
+
+    /// Reverts the most recently applied migration and returns its version, if any was applied.
+    pub async fn undo_last(&self) -> anyhow::Result<Option<i64>> {
+        let applied = self.applied_versions().await?;
+        let Some((&last, earlier)) = applied.split_last() else {
+            return Ok(None);
+        };
+        let target = earlier.last().copied().unwrap_or(0);
+        MIGRATOR
+            .undo(&self.pool, target)
+            .await
+            .with_context(|| format!("Failed to revert migration {last}"))?;
+        Ok(Some(last))
+    }
+
+    async fn applied_versions(&self) -> anyhow::Result<Vec<i64>> {
+        let mut conn = self.pool.acquire().await?;
+        conn.ensure_migrations_table().await?;
+        let mut versions: Vec<_> = conn
+            .list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+        versions.sort_unstable();
+        Ok(versions)
+    }
+}
+
+impl MigrationStore for Migrations {
+    async fn find_migrations(&self) -> Result<Vec<MigrationStatus>, FindMigrationsError> {
+        let applied = self.applied_versions().await?;
+        let status = MIGRATOR
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .map(|migration| {
+                MigrationStatus::new(
+                    migration.version,
+                    migration.description.to_string(),
+                    applied.contains(&migration.version),
+                )
+            })
+            .collect();
+        Ok(status)
+    }
+}
+
+const BACKUP_TABLES: &[&str] = &[
+    "author",
+    "author_alias",
+    "genre",
+    "author_genre",
+    "publisher",
+    "contract",
+    "audit_log",
+    "erasure_log",
+    "processed_command",
+];
+
+/// Deletes rows that have outlived their [`RetentionPolicy`]. The erasure log is append-only
+/// and never purged.
+#[derive(Debug, Clone)]
+pub struct Retention {
+    pool: SqlitePool,
+    policy: RetentionPolicy,
+}
+
+impl Retention {
+    #[must_use]
+    pub const fn new(pool: SqlitePool, policy: RetentionPolicy) -> Self {
+        Self { pool, policy }
+    }
+
+    #[must_use]
+    pub const fn policy(&self) -> &RetentionPolicy {
+        &self.policy
+    }
+
+    async fn run(&self, now: DateTime<Utc>, dry_run: bool) -> anyhow::Result<RetentionReport> {
+        let mut tx = self.pool.begin().await?;
+        let mut expire = async |table: &str, condition: &str, retain: Option<Duration>| {
+            let Some(cutoff) = retain
+                .and_then(|retain| chrono::TimeDelta::from_std(retain).ok())
+                .and_then(|retain| now.checked_sub_signed(retain))
+            else {
+                return Ok::<_, anyhow::Error>(0);
+            };
+            let expired = if dry_run {
+                let sql = format!("SELECT COUNT(*) FROM {table} WHERE {condition}");
+                let count: i64 = sqlx::query_scalar(&sql)
+                    .bind(cutoff)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                u64::try_from(count).unwrap_or_default()
+            } else {
+                let sql = format!("DELETE FROM {table} WHERE {condition}");
+                sqlx::query(&sql)
+                    .bind(cutoff)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected()
+            };
+            Ok(expired)
+        };
+        let report = RetentionReport::new(
+            expire(
+                "author",
+                "status = 'archived' AND updated_at < ?",
+                self.policy.archived_authors(),
+            )
+            .await
+            .context("Failed to purge archived authors")?,
+            expire("audit_log", "recorded_at < ?", self.policy.audit_log())
+                .await
+                .context("Failed to purge the audit log")?,
+            expire(
+                "processed_command",
+                "processed_at < ?",
+                self.policy.processed_commands(),
+            )
+            .await
+            .context("Failed to purge processed commands")?,
+            dry_run,
+        );
+        if dry_run {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+        }
+        Ok(report)
+    }
+}
+
+impl RetentionStore for Retention {
+    async fn preview_purge(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<RetentionReport, PurgeExpiredError> {
+        Ok(self.run(now, true).await?)
+    }
+
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<RetentionReport, PurgeExpiredError> {
+        Ok(self.run(now, false).await?)
+    }
+}
+
+/// Takes consistent snapshots of the live database and restores them in place. Requires a
+/// file-backed database; SQLite keeps an in-memory database's copies in memory too.
+#[derive(Debug, Clone)]
+pub struct Backups {
+    pool: SqlitePool,
+}
+
+impl Backups {
+    #[must_use]
+    pub const fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl BackupStore for Backups {
+    /// `VACUUM INTO` copies the database inside a single read transaction, so writers are not
+    /// blocked and the snapshot never contains a partial write.
+    async fn snapshot(&self) -> Result<Vec<u8>, CreateBackupError> {
+        let path = scratch_path("backup");
+        sqlx::query("VACUUM INTO ?")
+            .bind(path.to_string_lossy().into_owned())
+            .execute(&self.pool)
+            .await
+            .context("Failed to snapshot the database")?;
+        let bytes = tokio::fs::read(&path).await;
+        let _ = tokio::fs::remove_file(&path).await;
+        Ok(
+            bytes
+                .with_context(|| format!("Failed to read database snapshot {}", path.display()))?,
+        )
+    }
+
+    async fn restore(&self, snapshot: &[u8]) -> Result<(), RestoreBackupError> {
+        let path = scratch_path("restore");
+        tokio::fs::write(&path, snapshot)
+            .await
+            .with_context(|| format!("Failed to write backup to {}", path.display()))?;
+        let mut conn = self.pool.acquire().await.map_err(anyhow::Error::from)?;
+        let attached = sqlx::query("ATTACH DATABASE ? AS backup")
+            .bind(path.to_string_lossy().into_owned())
+            .execute(&mut *conn)
+            .await;
+        if let Err(err) = attached {
+            let _ = tokio::fs::remove_file(&path).await;
+            return Err(RestoreBackupError::Invalid(err.to_string()));
+        }
+        let restored = restore_attached(&mut conn).await;
+        let detached = sqlx::query("DETACH DATABASE backup")
+            .execute(&mut *conn)
+            .await
+            .context("Failed to detach backup");
+        let _ = tokio::fs::remove_file(&path).await;
+        restored?;
+        detached?;
+        Ok(())
+    }
+}
+
+fn scratch_path(purpose: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("hexarch-{purpose}-{}.sqlite", Uuid::now_v7()))
+}
+
+async fn restore_attached(conn: &mut SqliteConnection) -> Result<(), RestoreBackupError> {
+    let integrity: String = sqlx::query_scalar("PRAGMA backup.integrity_check")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|err| RestoreBackupError::Invalid(err.to_string()))?;
+    if integrity != "ok" {
+        return Err(RestoreBackupError::Invalid(integrity));
+    }
+    let version_sql = |schema: &str| {
+        format!("SELECT MAX(version) FROM {schema}._sqlx_migrations WHERE success = true")
+    };
+    let expected: Option<i64> = sqlx::query_scalar(&version_sql("main"))
+        .fetch_one(&mut *conn)
+        .await
+        .context("Failed to read the current migration version")?;
+    let actual: Option<i64> = sqlx::query_scalar(&version_sql("backup"))
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|err| RestoreBackupError::Invalid(err.to_string()))?;
+    if actual != expected {
+        return Err(RestoreBackupError::Invalid(format!(
+            "backup is at migration {}, but the database is at {}",
+            actual.unwrap_or_default(),
+            expected.unwrap_or_default()
+        )));
+    }
+
+    let mut tx = conn.begin().await.map_err(anyhow::Error::from)?;
+    for table in BACKUP_TABLES {
+        sqlx::query(&format!("DELETE FROM main.{table}"))
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Failed to clear table {table}"))?;
+        sqlx::query(&format!(
+            "INSERT INTO main.{table} SELECT * FROM backup.{table}"
+        ))
+        .execute(&mut *tx)
+        .await
+        .with_context(|| format!("Failed to restore table {table}"))?;
+    }
+    tx.commit().await.map_err(anyhow::Error::from)?;
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct DefaultAuthorRepository {
+    pool: SqlitePool,
+    id_strategy: AuthorIdStrategy,
+    writes: Option<WriteQueue>,
+    cipher: Arc<dyn FieldCipher>,
+}
+
+impl DefaultAuthorRepository {
+    #[must_use]
+    pub fn new(pool: SqlitePool, id_strategy: AuthorIdStrategy) -> Self {
+        Self {
+            pool,
+            id_strategy,
+            writes: None,
+            cipher: Arc::new(PlaintextCipher),
+        }
+    }
+
+    /// Takes a turn from `writes` before every mutation; reads keep using the pool directly.
+    #[must_use]
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
+    }
+
+    /// Encrypts email addresses with `cipher` and looks them up by its blind index.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn FieldCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    async fn write_turn(&self) -> anyhow::Result<Option<WriteTurn>> {
+        write_turn(self.writes.as_ref()).await
+    }
+
+    /// Re-encrypts every email not encrypted with the current key, including ones stored before
+    /// encryption was configured, and recomputes their blind index. The emails in audit
+    /// snapshots are re-encrypted too. Returns how many authors and audit entries were
+    /// rewritten. Must run before serving with a newly configured cipher, since plaintext rows
+    /// are indexed by the plaintext and neither lookups by email nor the unique check see them.
+    pub async fn reencrypt_emails(&self) -> anyhow::Result<u64> {
+        let _turn = self.write_turn().await?;
+        let mut tx = self.pool.begin().await?;
+        let rows: Vec<(AuthorId, String)> = sqlx::query_as("SELECT id, email FROM author")
+            .fetch_all(&mut *tx)
+            .await
+            .context("Failed to retrieve author emails")?;
+        let mut rewritten = 0;
+        for (id, stored) in rows {
+            if !self.cipher.needs_rotation(&stored) {
+                continue;
+            }
+            let email = self
+                .cipher
+                .decrypt(&stored)
+                .with_context(|| format!(r#"Failed to decrypt email of author with id "{id}""#))?;
+            let sealed =
+                SealedEmail::seal(self.cipher.as_ref(), &EmailAddress::new_unchecked(&email))?;
+            sqlx::query(
+                "UPDATE author SET email = ?, email_index = ?, email_domain = ? WHERE id = ?",
+            )
+            .bind(sealed.ciphertext)
+            .bind(sealed.index)
+            .bind(sealed.domain)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!(r#"Failed to re-encrypt email of author with id "{id}""#))?;
+            rewritten += 1;
+        }
+
+        let entries: Vec<(i64, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT id, before, after FROM audit_log WHERE before IS NOT NULL OR after IS NOT NULL",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .context("Failed to retrieve audit snapshots")?;
+        for (id, before, after) in entries {
+            let reencrypt = |stored: Option<&str>| {
+                self.reencrypt_snapshot(stored)
+                    .with_context(|| format!("Failed to re-encrypt audit entry {id}"))
+            };
+            let (sealed_before, sealed_after) =
+                (reencrypt(before.as_deref())?, reencrypt(after.as_deref())?);
+            if sealed_before.is_none() && sealed_after.is_none() {
+                continue;
+            }
+            sqlx::query("UPDATE audit_log SET before = ?, after = ? WHERE id = ?")
+                .bind(sealed_before.or(before))
+                .bind(sealed_after.or(after))
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .with_context(|| format!("Failed to re-encrypt audit entry {id}"))?;
+            rewritten += 1;
+        }
+        tx.commit().await?;
+        Ok(rewritten)
+    }
+
+    /// The snapshot with its email re-encrypted, or `None` if it does not need to be.
+    fn reencrypt_snapshot(&self, stored: Option<&str>) -> anyhow::Result<Option<String>> {
+        let Some(stored) = stored else {
+            return Ok(None);
+        };
+        let mut snapshot: serde_json::Value = serde_json::from_str(stored)?;
+        let mut rotated = false;
+        map_snapshot_email(&mut snapshot, |email| {
+            if !self.cipher.needs_rotation(email) {
+                return Ok(email.to_string());
+            }
+            rotated = true;
+            self.cipher.encrypt(&self.cipher.decrypt(email)?)
+        })?;
+        Ok(rotated.then(|| snapshot.to_string()))
+    }
+
+    /// Rewrites emails stored before [`EmailAddress`] normalized them, or under older rules, in
+    /// the form it gives them now. Returns how many were rewritten. Fails without changing any
+    /// when two authors would end up sharing an address, naming them so they can be merged.
+    /// Emails it rejects altogether are left alone.
+    pub async fn normalize_emails(&self) -> anyhow::Result<u64> {
+        let _turn = self.write_turn().await?;
+        let mut tx = self.pool.begin().await?;
+        let rows: Vec<(AuthorId, String)> = sqlx::query_as("SELECT id, email FROM author")
+            .fetch_all(&mut *tx)
+            .await
+            .context("Failed to retrieve author emails")?;
+        let mut owners = HashMap::with_capacity(rows.len());
+        let mut changed = Vec::new();
+        for (id, stored) in rows {
+            let email = self
+                .cipher
+                .decrypt(&stored)
+                .with_context(|| format!(r#"Failed to decrypt email of author with id "{id}""#))?;
+            let Ok(normalized) = EmailAddress::new(&email) else {
+                tracing::warn!(%id, "Leaving an invalid author email as it is");
+                continue;
+            };
+            if let Some(owner) = owners.insert(normalized.as_str().to_string(), id) {
+                anyhow::bail!(
+                    r#"Authors with ids "{owner}" and "{id}" share the email address "{normalized}" once normalized; merge them or change one of their emails"#
+                );
+            }
+            if normalized.as_str() != email {
+                changed.push((id, normalized));
+            }
+        }
+        let rewritten = changed.len() as u64;
+        for (id, email) in changed {
+            let sealed = SealedEmail::seal(self.cipher.as_ref(), &email)?;
+            sqlx::query(
+                "UPDATE author SET email = ?, email_index = ?, email_domain = ? WHERE id = ?",
+            )
+            .bind(sealed.ciphertext)
+            .bind(sealed.index)
+            .bind(sealed.domain)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!(r#"Failed to normalize email of author with id "{id}""#))?;
+        }
+        tx.commit().await?;
+        Ok(rewritten)
+    }
+}
+
+async fn write_turn(writes: Option<&WriteQueue>) -> anyhow::Result<Option<WriteTurn>> {
+    match writes {
+        Some(writes) => Ok(Some(writes.acquire().await?)),
+        None => Ok(None),
+    }
+}
+
+impl Type<Sqlite> for AuthorId {
+    fn type_info() -> SqliteTypeInfo {
+        <i64 as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <i64 as Type<Sqlite>>::compatible(ty) || <String as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for AuthorId {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        match self {
+            Self::Integer(id) => <i64 as Encode<Sqlite>>::encode_by_ref(id, buf),
+            Self::Uuid(id) => <String as Encode<Sqlite>>::encode(id.to_string(), buf),
+        }
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for AuthorId {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.type_info().name() == "TEXT" {
+            let id = <&str as Decode<Sqlite>>::decode(value)?;
+            Ok(Self::Uuid(id.parse()?))
+        } else {
+            Ok(Self::Integer(<i64 as Decode<Sqlite>>::decode(value)?))
+        }
+    }
+}
+
+/// An author row whose email is still as stored; [`SealedAuthor::open`] decrypts it.
+struct SealedAuthor {
+    id: AuthorId,
+    name: AuthorName,
+    email: String,
+    status: AuthorStatus,
+    email_verification: EmailVerification,
+    profile: AuthorProfile,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl SealedAuthor {
+    fn open(self, cipher: &dyn FieldCipher) -> Result<Author, CipherError> {
+        let email = cipher.decrypt(&self.email)?;
+        Ok(Author::new(
+            self.id,
+            self.name,
+            EmailAddress::new_unchecked(&email),
+            self.created_at,
+            self.updated_at,
+        )
+        .with_status(self.status)
+        .with_email_verification(self.email_verification)
+        .with_profile(self.profile))
+    }
+}
+
+fn open_authors(rows: Vec<SealedAuthor>, cipher: &dyn FieldCipher) -> anyhow::Result<Vec<Author>> {
+    rows.into_iter()
+        .map(|row| row.open(cipher).context("Failed to decrypt author email"))
+        .collect()
+}
+
+/// An email as written to the author table: encrypted, with the blind index lookups and
+/// uniqueness use and the domain the statistics group by.
+struct SealedEmail {
+    ciphertext: String,
+    index: String,
+    domain: String,
+}
+
+impl SealedEmail {
+    fn seal(cipher: &dyn FieldCipher, email: &EmailAddress) -> anyhow::Result<Self> {
+        Ok(Self {
+            ciphertext: cipher
+                .encrypt(email.as_str())
+                .context("Failed to encrypt author email")?,
+            index: cipher.blind_index(email.as_str()),
+            domain: email.domain().to_string(),
+        })
+    }
+}
+
+/// Audit snapshots hold the author's email, which is encrypted like the author row's so the
+/// audit log keeps no plaintext copy of it.
+fn seal_snapshot(
+    snapshot: Option<&serde_json::Value>,
+    cipher: &dyn FieldCipher,
+) -> Result<Option<String>, CipherError> {
+    let Some(snapshot) = snapshot else {
+        return Ok(None);
+    };
+    let mut snapshot = snapshot.clone();
+    map_snapshot_email(&mut snapshot, |email| cipher.encrypt(email))?;
+    Ok(Some(snapshot.to_string()))
+}
+
+fn open_audit_entries(
+    entries: Vec<AuditEntry>,
+    cipher: &dyn FieldCipher,
+) -> anyhow::Result<Vec<AuditEntry>> {
+    let open = |snapshot: Option<&serde_json::Value>| {
+        let Some(mut snapshot) = snapshot.cloned() else {
+            return Ok(None);
+        };
+        map_snapshot_email(&mut snapshot, |email| cipher.decrypt(email))?;
+        Ok::<_, CipherError>(Some(snapshot))
+    };
+    entries
+        .into_iter()
+        .map(|entry| {
+            let req = RecordAuditRequest::new(
+                entry.author_id(),
+                entry.action(),
+                entry.context().clone(),
+                open(entry.before())?,
+                open(entry.after())?,
+            );
+            Ok(AuditEntry::new(entry.id(), req, entry.recorded_at()))
+        })
+        .collect::<Result<_, CipherError>>()
+        .context("Failed to decrypt audit snapshot email")
+}
+
+fn map_snapshot_email(
+    snapshot: &mut serde_json::Value,
+    f: impl FnOnce(&str) -> Result<String, CipherError>,
+) -> Result<(), CipherError> {
+    if let Some(serde_json::Value::String(email)) = snapshot.get_mut("email") {
+        *email = f(email)?;
+    }
+    Ok(())
+}
+
+impl<'r> FromRow<'r, SqliteRow> for SealedAuthor {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let name = row.try_get("name")?;
+        let email = row.try_get("email")?;
+        let status: &str = row.try_get("status")?;
+        let email_verification: &str = row.try_get("email_verification")?;
+        let bio: Option<&str> = row.try_get("bio")?;
+        let birth_date: Option<NaiveDate> = row.try_get("birth_date")?;
+        let website: Option<&str> = row.try_get("website_url")?;
+        let country: Option<&str> = row.try_get("country")?;
+        let created_at = row.try_get("created_at")?;
+        let updated_at = row.try_get("updated_at")?;
+
+        let name = AuthorName::new_unchecked(name);
+        let status = status.parse().map_err(|err| sqlx::Error::ColumnDecode {
+            index: "status".into(),
+            source: Box::new(err),
+        })?;
+        let email_verification =
+            email_verification
+                .parse()
+                .map_err(|err| sqlx::Error::ColumnDecode {
+                    index: "email_verification".into(),
+                    source: Box::new(err),
+                })?;
+        let profile = AuthorProfile::default()
+            .with_bio(bio.map(Biography::new_unchecked))
+            .with_birth_date(birth_date.map(BirthDate::new_unchecked))
+            .with_website(website.map(WebsiteUrl::new_unchecked))
+            .with_country(country.map(CountryCode::new_unchecked));
+        Ok(Self {
+            id,
+            name,
+            email,
+            status,
+            email_verification,
+            profile,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl AuthorRepository for DefaultAuthorRepository {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        let _turn = self.write_turn().await?;
+        create_author(&self.pool, req, self.id_strategy, self.cipher.as_ref()).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        find_author(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        find_author_by_email(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        find_all_authors(&self.pool, self.cipher.as_ref()).await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        find_authors_by_ids(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        find_sorted_authors(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        find_projected_authors(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        stream_all_authors(self.pool.clone(), Arc::clone(&self.cipher))
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        count_authors(&self.pool).await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        author_exists(&self.pool, req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        let _turn = self.write_turn().await?;
+        update_author(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        let _turn = self.write_turn().await?;
+        upsert_author(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        let _turn = self.write_turn().await?;
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        let cipher = self.cipher.as_ref();
+        let author = upsert_author_by_email(&mut tx, req, self.id_strategy, cipher).await?;
+        tx.commit().await.map_err(anyhow::Error::from)?;
+        Ok(author)
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        let _turn = self.write_turn().await?;
+        set_author_status(&self.pool, req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        let _turn = self.write_turn().await?;
+        delete_author(&self.pool, req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        let _turn = self.write_turn().await?;
+        add_author_alias(&self.pool, req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        let _turn = self.write_turn().await?;
+        remove_author_alias(&self.pool, req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        find_author_aliases(&mut tx, req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        search_authors(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        author_stats(&mut tx, req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        find_authors_by_verification(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        let _turn = self.write_turn().await?;
+        set_email_verification(&self.pool, req, self.cipher.as_ref()).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultGenreRepository {
+    pool: SqlitePool,
+    cipher: Arc<dyn FieldCipher>,
+}
+
+impl DefaultGenreRepository {
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            cipher: Arc::new(PlaintextCipher),
+        }
+    }
+
+    /// Decrypts the emails of the authors it returns with `cipher`.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn FieldCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for Genre {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let name = row.try_get("name")?;
+        Ok(Self::new(GenreId::new(id), GenreName::new_unchecked(name)))
+    }
+}
+
+impl GenreRepository for DefaultGenreRepository {
+    async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        create_genre(&self.pool, req).await
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        find_all_genres(&self.pool).await
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        delete_genre(&self.pool, req).await
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        attach_genre(&mut tx, req).await?;
+        tx.commit().await.map_err(anyhow::Error::from)?;
+        Ok(())
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        detach_genre(&self.pool, req).await
+    }
+
+    async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        find_author_genres(&mut tx, req).await
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        find_authors_by_genre(&self.pool, req, self.cipher.as_ref()).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultPublisherRepository {
+    pool: SqlitePool,
+}
+
+impl DefaultPublisherRepository {
+    #[must_use]
+    pub const fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for Publisher {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let name = row.try_get("name")?;
+        Ok(Self::new(
+            PublisherId::new(id),
+            PublisherName::new_unchecked(name),
+        ))
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for Contract {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let author_id = row.try_get("author_id")?;
+        let publisher_id = row.try_get("publisher_id")?;
+        let starts_on = row.try_get("starts_on")?;
+        let ends_on = row.try_get("ends_on")?;
+        let royalty = row.try_get("royalty_basis_points")?;
+        Ok(Self::new(
+            ContractId::new(id),
+            author_id,
+            PublisherId::new(publisher_id),
+            ContractTerm::new_unchecked(starts_on, ends_on),
+            RoyaltyPercent::new_unchecked(royalty),
+        ))
+    }
+}
+
+impl PublisherRepository for DefaultPublisherRepository {
+    async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        create_publisher(&self.pool, req).await
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        find_publisher(&self.pool, req).await
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        find_all_publishers(&self.pool).await
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        delete_publisher(&self.pool, req).await
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        let contract = create_contract(&mut tx, req).await?;
+        tx.commit().await.map_err(anyhow::Error::from)?;
+        Ok(contract)
+    }
+
+    async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        find_author_contracts(&mut tx, req).await
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        find_publisher_contracts(&mut tx, req).await
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        delete_contract(&self.pool, req).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultAuditRecorder {
+    pool: SqlitePool,
+    cipher: Arc<dyn FieldCipher>,
+}
+
+impl DefaultAuditRecorder {
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            cipher: Arc::new(PlaintextCipher),
+        }
+    }
+
+    /// Encrypts the emails in audit snapshots with `cipher`.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn FieldCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for AuditEntry {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let author_id = row.try_get("author_id")?;
+        let action: &str = row.try_get("action")?;
+        let actor = row.try_get("actor")?;
+        let request_id = row.try_get("request_id")?;
+        let before: Option<&str> = row.try_get("before")?;
+        let after: Option<&str> = row.try_get("after")?;
+        let recorded_at = row.try_get("recorded_at")?;
+
+        let action = action.parse().map_err(|err| sqlx::Error::ColumnDecode {
+            index: "action".into(),
+            source: Box::new(err),
+        })?;
+        let before = decode_json("before", before)?;
+        let after = decode_json("after", after)?;
+        let req = RecordAuditRequest::new(
+            author_id,
+            action,
+            AuditContext::new(actor, request_id),
+            before,
+            after,
+        );
+        Ok(Self::new(id, req, recorded_at))
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for ErasureRecord {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let author_id = row.try_get("author_id")?;
+        let actor = row.try_get("actor")?;
+        let request_id = row.try_get("request_id")?;
+        let anonymized_entries: i64 = row.try_get("anonymized_entries")?;
+        let erased_at = row.try_get("erased_at")?;
+        let previous_hash = row.try_get("previous_hash")?;
+        let hash = row.try_get("hash")?;
+
+        let anonymized_entries =
+            u64::try_from(anonymized_entries).map_err(|err| sqlx::Error::ColumnDecode {
+                index: "anonymized_entries".into(),
+                source: Box::new(err),
+            })?;
+        let req = RecordErasureRequest::new(
+            author_id,
+            AuditContext::new(actor, request_id),
+            anonymized_entries,
+        );
+        Ok(Self::new(id, req, erased_at, previous_hash, hash))
+    }
+}
+
+impl<'r> FromRow<'r, SqliteRow> for SecurityEvent {
+    fn from_row(row: &'r SqliteRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get("id")?;
+        let kind: String = row.try_get("kind")?;
+        let client = row.try_get("client")?;
+        let reason = row.try_get("reason")?;
+        let expires_at = row.try_get("expires_at")?;
+        let recorded_at = row.try_get("recorded_at")?;
+
+        let kind = kind
+            .parse::<SecurityEventKind>()
+            .map_err(|err| sqlx::Error::ColumnDecode {
+                index: "kind".into(),
+                source: Box::new(err),
+            })?;
+        let req = RecordSecurityEventRequest::new(kind, client, reason, expires_at);
+        Ok(Self::new(id, req, recorded_at))
+    }
+}
+
+impl AuditRecorder for DefaultAuditRecorder {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        record_audit(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        find_audit_log(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        find_changes(&self.pool, req, self.cipher.as_ref()).await
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        latest_change_id(&self.pool).await
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        erase_audit_snapshots(&self.pool, req).await
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        let mut tx = self.pool.begin().await.map_err(anyhow::Error::from)?;
+        let record = record_erasure(&mut tx, req).await?;
+        tx.commit().await.map_err(anyhow::Error::from)?;
+        Ok(record)
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        find_erasures(&self.pool).await
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        record_security_event(&self.pool, req).await
+    }
+
+    async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        find_security_events(&self.pool, limit).await
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultCommandLog {
+    pool: SqlitePool,
+}
+
+impl DefaultCommandLog {
+    #[must_use]
+    pub const fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl CommandLog for DefaultCommandLog {
+    async fn is_processed(&self, command_id: &str) -> Result<bool, CommandLogError> {
+        let processed = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM processed_command WHERE command_id = ?)",
+        )
+        .bind(command_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to look up command with id "{command_id}""#
+            ))
+        })?;
+
+        Ok(processed)
+    }
+
+    async fn mark_processed(&self, command_id: &str) -> Result<(), CommandLogError> {
+        sqlx::query(
+            "INSERT INTO processed_command (command_id, processed_at) VALUES (?, ?) \
+             ON CONFLICT (command_id) DO NOTHING",
+        )
+        .bind(command_id)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to mark command with id "{command_id}" as processed"#
+            ))
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Sessions are keyed by a hash of their id, so reading the table does not sign anyone in.
+#[derive(Debug)]
+pub struct DefaultSessionStore {
+    pool: SqlitePool,
+}
+
+impl DefaultSessionStore {
+    #[must_use]
+    pub const fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn session_id_hash(id: &SessionId) -> String {
+    format!("{:x}", Sha256::digest(id.as_str()))
+}
+
+impl SessionStore for DefaultSessionStore {
+    /// Also drops sessions that expired, which would otherwise pile up from browsers that never
+    /// signed out.
+    async fn save_session(&self, session: &StoredSession) -> Result<(), SessionStoreError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+        sqlx::query("DELETE FROM admin_session WHERE expires_at <= ?")
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await
+            .context("Failed to delete expired sessions")?;
+        sqlx::query(
+            "INSERT INTO admin_session (id_hash, data, expires_at) VALUES (?, ?, ?) \
+             ON CONFLICT (id_hash) DO UPDATE SET data = excluded.data, \
+             expires_at = excluded.expires_at",
+        )
+        .bind(session_id_hash(session.id()))
+        .bind(session.data().to_string())
+        .bind(session.expires_at())
+        .execute(&mut *tx)
+        .await
+        .context("Failed to save session")?;
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    async fn find_session(
+        &self,
+        id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Result<Option<StoredSession>, SessionStoreError> {
+        let row: Option<(String, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT data, expires_at FROM admin_session WHERE id_hash = ? AND expires_at > ?",
+        )
+        .bind(session_id_hash(id))
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to find session")?;
+        let Some((data, expires_at)) = row else {
+            return Ok(None);
+        };
+        let data = serde_json::from_str(&data).context("Failed to decode session")?;
+
+        Ok(Some(StoredSession::new(id.clone(), data, expires_at)))
+    }
+
+    async fn delete_session(&self, id: &SessionId) -> Result<(), SessionStoreError> {
+        sqlx::query("DELETE FROM admin_session WHERE id_hash = ?")
+            .bind(session_id_hash(id))
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete session")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultUnitOfWork {
+    pool: SqlitePool,
+    id_strategy: AuthorIdStrategy,
+    writes: Option<WriteQueue>,
+    cipher: Arc<dyn FieldCipher>,
+}
+
+impl DefaultUnitOfWork {
+    #[must_use]
+    pub fn new(pool: SqlitePool, id_strategy: AuthorIdStrategy) -> Self {
+        Self {
+            pool,
+            id_strategy,
+            writes: None,
+            cipher: Arc::new(PlaintextCipher),
+        }
+    }
+
+    /// Holds a turn from `writes` for the lifetime of each transaction.
+    #[must_use]
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
+    }
+
+    /// Encrypts email addresses written in each transaction with `cipher`.
+    #[must_use]
+    pub fn with_cipher(mut self, cipher: Arc<dyn FieldCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+}
+
+impl UnitOfWork for DefaultUnitOfWork {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        let turn = write_turn(self.writes.as_ref()).await?;
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+        Ok(Box::new(DefaultTransaction {
+            tx: Mutex::new(tx),
+            id_strategy: self.id_strategy,
+            cipher: Arc::clone(&self.cipher),
+            _turn: turn,
+        }))
+    }
+}
+
+struct DefaultTransaction {
+    tx: Mutex<sqlx::Transaction<'static, Sqlite>>,
+    id_strategy: AuthorIdStrategy,
+    cipher: Arc<dyn FieldCipher>,
+    _turn: Option<WriteTurn>,
+}
+
+impl Transaction for DefaultTransaction {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            self.tx
+                .into_inner()
+                .commit()
+                .await
+                .context("Failed to commit transaction")
+        })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        Box::pin(async move {
+            self.tx
+                .into_inner()
+                .rollback()
+                .await
+                .context("Failed to roll back transaction")
+        })
+    }
+}
+
+impl AuthorRepository for DefaultTransaction {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        let mut tx = self.tx.lock().await;
+        create_author(&mut **tx, req, self.id_strategy, self.cipher.as_ref()).await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        let mut tx = self.tx.lock().await;
+        find_author(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        let mut tx = self.tx.lock().await;
+        find_author_by_email(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_all_authors(&mut **tx, self.cipher.as_ref()).await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_authors_by_ids(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_sorted_authors(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_projected_authors(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        let authors = AuthorRepository::find_all_authors(self).await;
+        match authors {
+            Ok(authors) => stream::iter(authors.into_iter().map(Ok)).boxed(),
+            Err(err) => stream::once(async { Err(err) }).boxed(),
+        }
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        count_authors(&mut **tx).await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        let mut tx = self.tx.lock().await;
+        author_exists(&mut **tx, req).await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        let mut tx = self.tx.lock().await;
+        update_author(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        let mut tx = self.tx.lock().await;
+        upsert_author(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        let mut tx = self.tx.lock().await;
+        let cipher = self.cipher.as_ref();
+        upsert_author_by_email(&mut tx, req, self.id_strategy, cipher).await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        let mut tx = self.tx.lock().await;
+        set_author_status(&mut **tx, req).await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        let mut tx = self.tx.lock().await;
+        delete_author(&mut **tx, req).await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        let mut tx = self.tx.lock().await;
+        add_author_alias(&mut **tx, req).await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        let mut tx = self.tx.lock().await;
+        remove_author_alias(&mut **tx, req).await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        let mut tx = self.tx.lock().await;
+        find_author_aliases(&mut tx, req).await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        search_authors(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        author_stats(&mut tx, req).await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_authors_by_verification(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        let mut tx = self.tx.lock().await;
+        set_email_verification(&mut **tx, req, self.cipher.as_ref()).await
+    }
+}
+
+impl GenreRepository for DefaultTransaction {
+    async fn create_genre(&self, req: &CreateGenreRequest) -> Result<Genre, CreateGenreError> {
+        let mut tx = self.tx.lock().await;
+        create_genre(&mut **tx, req).await
+    }
+
+    async fn find_all_genres(&self) -> Result<Vec<Genre>, FindAllGenresError> {
+        let mut tx = self.tx.lock().await;
+        find_all_genres(&mut **tx).await
+    }
+
+    async fn delete_genre(&self, req: &DeleteGenreRequest) -> Result<(), DeleteGenreError> {
+        let mut tx = self.tx.lock().await;
+        delete_genre(&mut **tx, req).await
+    }
+
+    async fn attach_genre(&self, req: &AuthorGenreRequest) -> Result<(), AttachGenreError> {
+        let mut tx = self.tx.lock().await;
+        attach_genre(&mut tx, req).await
+    }
+
+    async fn detach_genre(&self, req: &AuthorGenreRequest) -> Result<(), DetachGenreError> {
+        let mut tx = self.tx.lock().await;
+        detach_genre(&mut **tx, req).await
+    }
+
+    async fn find_author_genres(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Genre>, FindAuthorError> {
+        let mut tx = self.tx.lock().await;
+        find_author_genres(&mut tx, req).await
+    }
+
+    async fn find_authors_by_genre(
+        &self,
+        req: &FindAuthorsByGenreRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        let mut tx = self.tx.lock().await;
+        find_authors_by_genre(&mut **tx, req, self.cipher.as_ref()).await
+    }
+}
+
+impl AuditRecorder for DefaultTransaction {
+    async fn record(&self, req: &RecordAuditRequest) -> Result<AuditEntry, RecordAuditError> {
+        let mut tx = self.tx.lock().await;
+        record_audit(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_audit_log(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        let mut tx = self.tx.lock().await;
+        find_audit_log(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn find_changes(
+        &self,
+        req: &FindChangesRequest,
+    ) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+        let mut tx = self.tx.lock().await;
+        find_changes(&mut **tx, req, self.cipher.as_ref()).await
+    }
+
+    async fn latest_change_id(&self) -> Result<Option<i64>, FindAuditLogError> {
+        let mut tx = self.tx.lock().await;
+        latest_change_id(&mut **tx).await
+    }
+
+    async fn erase_audit_snapshots(
+        &self,
+        req: &FindAuditLogRequest,
+    ) -> Result<u64, RecordAuditError> {
+        let mut tx = self.tx.lock().await;
+        erase_audit_snapshots(&mut **tx, req).await
+    }
+
+    async fn record_erasure(
+        &self,
+        req: &RecordErasureRequest,
+    ) -> Result<ErasureRecord, RecordAuditError> {
+        let mut tx = self.tx.lock().await;
+        record_erasure(&mut tx, req).await
+    }
+
+    async fn find_erasures(&self) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+        let mut tx = self.tx.lock().await;
+        find_erasures(&mut **tx).await
+    }
+
+    async fn record_security_event(
+        &self,
+        req: &RecordSecurityEventRequest,
+    ) -> Result<SecurityEvent, RecordAuditError> {
+        let mut tx = self.tx.lock().await;
+        record_security_event(&mut **tx, req).await
+    }
+
+    async fn find_security_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+        let mut tx = self.tx.lock().await;
+        find_security_events(&mut **tx, limit).await
+    }
+}
+
+impl PublisherRepository for DefaultTransaction {
+    async fn create_publisher(
+        &self,
+        req: &CreatePublisherRequest,
+    ) -> Result<Publisher, CreatePublisherError> {
+        let mut tx = self.tx.lock().await;
+        create_publisher(&mut **tx, req).await
+    }
+
+    async fn find_publisher(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Publisher, FindPublisherError> {
+        let mut tx = self.tx.lock().await;
+        find_publisher(&mut **tx, req).await
+    }
+
+    async fn find_all_publishers(&self) -> Result<Vec<Publisher>, FindAllPublishersError> {
+        let mut tx = self.tx.lock().await;
+        find_all_publishers(&mut **tx).await
+    }
+
+    async fn delete_publisher(
+        &self,
+        req: &DeletePublisherRequest,
+    ) -> Result<(), DeletePublisherError> {
+        let mut tx = self.tx.lock().await;
+        delete_publisher(&mut **tx, req).await
+    }
+
+    async fn create_contract(
+        &self,
+        req: &CreateContractRequest,
+    ) -> Result<Contract, CreateContractError> {
+        let mut tx = self.tx.lock().await;
+        create_contract(&mut tx, req).await
+    }
+
+    async fn find_author_contracts(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<Contract>, FindAuthorError> {
+        let mut tx = self.tx.lock().await;
+        find_author_contracts(&mut tx, req).await
+    }
+
+    async fn find_publisher_contracts(
+        &self,
+        req: &FindPublisherRequest,
+    ) -> Result<Vec<Contract>, FindPublisherError> {
+        let mut tx = self.tx.lock().await;
+        find_publisher_contracts(&mut tx, req).await
+    }
+
+    async fn delete_contract(
+        &self,
+        req: &DeleteContractRequest,
+    ) -> Result<(), DeleteContractError> {
+        let mut tx = self.tx.lock().await;
+        delete_contract(&mut **tx, req).await
+    }
+}
+
+/// The id the request asks for, or one the strategy picks up front. `None` numbers the author
+/// after the largest integer id in the table, which is also what becomes of Snowflake authors
+/// created without the service, since only its generator knows the node.
+fn new_author_id(req: &CreateAuthorRequest, id_strategy: AuthorIdStrategy) -> Option<AuthorId> {
+    req.id().or_else(|| match id_strategy {
+        AuthorIdStrategy::Integer | AuthorIdStrategy::Snowflake => None,
+        AuthorIdStrategy::UuidV7 => Some(AuthorId::new_v7()),
+    })
+}
+
+/// Inserts an author; an author without a chosen id is numbered after the largest integer id,
+/// otherwise the id is bound first. Ends without a semicolon, so a conflict or `RETURNING`
+/// clause can follow.
+fn insert_author_sql(chosen_id: bool) -> String {
+    let id = if chosen_id {
+        "?"
+    } else {
+        "(SELECT COALESCE(MAX(id), 0) + 1 FROM author WHERE typeof(id) = 'integer')"
+    };
+    format!(
+        "INSERT INTO author \
+         (id, name, email, email_index, email_domain, bio, birth_date, website_url, country, \
+         created_at, updated_at) \
+         VALUES ({id}, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+}
+
+#[tracing::instrument(name = "db.create_author", skip_all, fields(name = %req.name()))]
+async fn create_author<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &CreateAuthorRequest,
+    id_strategy: AuthorIdStrategy,
+    cipher: &dyn FieldCipher,
+) -> Result<Author, CreateAuthorError> {
+    let id = new_author_id(req, id_strategy);
+    let sql = format!("{} RETURNING *", insert_author_sql(id.is_some()));
+    let mut query = sqlx::query_as(&sql);
+    if let Some(id) = id {
+        query = query.bind(id);
+    }
+    let now = Utc::now();
+    let email = SealedEmail::seal(cipher, req.email())?;
+    let query = query
+        .bind(req.name().as_str())
+        .bind(email.ciphertext)
+        .bind(email.index)
+        .bind(email.domain);
+    let author: SealedAuthor = bind_profile(query, req.profile())
+        .bind(now)
+        .bind(now)
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "author.email_index") {
+                CreateAuthorError::DuplicateEmail {
+                    email: req.email().to_string(),
+                }
+            } else if is_unique_violation(&err, "author.name") {
+                CreateAuthorError::Duplicate {
+                    name: req.name().to_string(),
+                }
+            } else if let Some(id) = id.filter(|_| is_unique_violation(&err, "author.id")) {
+                CreateAuthorError::IdTaken { id }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to create author with name "{}""#,
+                    req.name()
+                ));
+                CreateAuthorError::Other(err)
+            }
+        })?;
+
+    Ok(author
+        .open(cipher)
+        .context("Failed to decrypt author email")?)
+}
+
+#[tracing::instrument(name = "db.find_author", skip_all, fields(id = %req.id()))]
+async fn find_author<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Author, FindAuthorError> {
+    let author: SealedAuthor = sqlx::query_as(FIND_AUTHOR_SQL)
+        .bind(req.id())
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                FindAuthorError::NotFound { id: req.id() }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to retrieve author with id "{}""#,
+                    req.id()
+                ));
+                FindAuthorError::Other(err)
+            }
+        })?;
+
+    Ok(author
+        .open(cipher)
+        .context("Failed to decrypt author email")?)
+}
+
+/// Looks the address up by its blind index, so it never has to be decrypted to be found.
+#[tracing::instrument(name = "db.find_author_by_email", skip_all)]
+async fn find_author_by_email<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorByEmailRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Author, FindAuthorByEmailError> {
+    let author: SealedAuthor = sqlx::query_as(FIND_AUTHOR_BY_EMAIL_SQL)
+        .bind(cipher.blind_index(req.email().as_str()))
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                FindAuthorByEmailError::NotFound {
+                    email: req.email().to_string(),
+                }
+            } else {
+                let err = anyhow!(err).context("Failed to retrieve author by email");
+                FindAuthorByEmailError::Other(err)
+            }
+        })?;
+
+    Ok(author
+        .open(cipher)
+        .context("Failed to decrypt author email")?)
+}
+
+#[tracing::instrument(name = "db.find_all_authors", skip_all)]
+async fn find_all_authors<'e>(
+    executor: impl SqliteExecutor<'e>,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    let authors = sqlx::query_as(FIND_ALL_AUTHORS_SQL)
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to retrieve all authors");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+#[tracing::instrument(name = "db.find_authors_by_ids", skip_all, fields(count = req.ids().len()))]
+async fn find_authors_by_ids<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorsByIdsRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    if req.ids().is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at FROM author WHERE id IN (",
+    );
+    let mut ids = query.separated(", ");
+    for id in req.ids() {
+        ids.push_bind(*id);
+    }
+    query.push(") ORDER BY id");
+
+    let authors = query
+        .build_query_as()
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to retrieve authors by id");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+/// The sort keys come from a fixed list of columns, so they are written into the query rather
+/// than bound.
+#[tracing::instrument(name = "db.find_sorted_authors", skip_all)]
+async fn find_sorted_authors<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindSortedAuthorsRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, country, created_at, \
+     updated_at FROM author ORDER BY ",
+    );
+    let mut keys = query.separated(", ");
+    for key in req.sort().keys_with_tiebreaker() {
+        keys.push(sort_column(key.field()));
+        keys.push_unseparated(if key.descending() { " DESC" } else { " ASC" });
+    }
+
+    let authors = query
+        .build_query_as()
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to retrieve sorted authors");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+/// Only the columns behind the requested fields are selected, so an author's email is neither
+/// read nor decrypted unless it was asked for. The id is always selected.
+#[tracing::instrument(name = "db.find_projected_authors", skip_all)]
+async fn find_projected_authors<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindProjectedAuthorsRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+    let fields = req.fields();
+    let mut query = QueryBuilder::<Sqlite>::new("SELECT id");
+    for column in fields
+        .fields()
+        .iter()
+        .filter_map(|field| projection_column(*field))
+    {
+        query.push(", ").push(column);
+    }
+    query.push(" FROM author ORDER BY id");
+
+    let rows = query.build().fetch_all(executor).await.map_err(|err| {
+        let err = anyhow!(err).context("Failed to retrieve projected authors");
+        FindAllAuthorsError(err)
+    })?;
+
+    rows.iter()
+        .map(|row| project_row(row, fields, cipher))
+        .collect::<anyhow::Result<_>>()
+        .map_err(FindAllAuthorsError)
+}
+
+const fn projection_column(field: AuthorField) -> Option<&'static str> {
+    match field {
+        AuthorField::Id => None,
+        AuthorField::Name => Some("name"),
+        AuthorField::Email => Some("email"),
+        AuthorField::Verified => Some("email_verification"),
+        AuthorField::Status => Some("status"),
+        AuthorField::Bio => Some("bio"),
+        AuthorField::BirthDate => Some("birth_date"),
+        AuthorField::WebsiteUrl => Some("website_url"),
+        AuthorField::Country => Some("country"),
+        AuthorField::CreatedAt => Some("created_at"),
+        AuthorField::UpdatedAt => Some("updated_at"),
+    }
+}
+
+fn project_row(
+    row: &SqliteRow,
+    fields: &AuthorFields,
+    cipher: &dyn FieldCipher,
+) -> anyhow::Result<ProjectedAuthor> {
+    let mut author = ProjectedAuthor::new(row.try_get("id")?);
+    let mut profile = AuthorProfile::default();
+    for field in fields.fields() {
+        author = match field {
+            AuthorField::Id => author,
+            AuthorField::Name => author.with_name(AuthorName::new_unchecked(row.try_get("name")?)),
+            AuthorField::Email => {
+                let email = cipher
+                    .decrypt(row.try_get("email")?)
+                    .context("Failed to decrypt author email")?;
+                author.with_email(EmailAddress::new_unchecked(&email))
+            }
+            AuthorField::Verified => {
+                let verification: &str = row.try_get("email_verification")?;
+                author.with_email_verification(verification.parse()?)
+            }
+            AuthorField::Status => {
+                let status: &str = row.try_get("status")?;
+                author.with_status(status.parse()?)
+            }
+            AuthorField::Bio => {
+                let bio: Option<&str> = row.try_get("bio")?;
+                profile = profile.with_bio(bio.map(Biography::new_unchecked));
+                author
+            }
+            AuthorField::BirthDate => {
+                let birth_date: Option<NaiveDate> = row.try_get("birth_date")?;
+                profile = profile.with_birth_date(birth_date.map(BirthDate::new_unchecked));
+                author
+            }
+            AuthorField::WebsiteUrl => {
+                let website: Option<&str> = row.try_get("website_url")?;
+                profile = profile.with_website(website.map(WebsiteUrl::new_unchecked));
+                author
+            }
+            AuthorField::Country => {
+                let country: Option<&str> = row.try_get("country")?;
+                profile = profile.with_country(country.map(CountryCode::new_unchecked));
+                author
+            }
+            AuthorField::CreatedAt => author.with_created_at(row.try_get("created_at")?),
+            AuthorField::UpdatedAt => author.with_updated_at(row.try_get("updated_at")?),
+        };
+    }
+    Ok(author.with_profile(profile))
+}
+
+/// `NOCASE` matches how the in-memory adapter compares names.
+const fn sort_column(field: AuthorSortField) -> &'static str {
+    match field {
+        AuthorSortField::Id => "id",
+        AuthorSortField::Name => "name COLLATE NOCASE",
+        AuthorSortField::CreatedAt => "created_at",
+        AuthorSortField::UpdatedAt => "updated_at",
+        AuthorSortField::BirthDate => "birth_date",
+        AuthorSortField::Country => "country",
+    }
+}
+
+#[tracing::instrument(name = "db.count_authors", skip_all)]
+async fn count_authors<'e>(executor: impl SqliteExecutor<'e>) -> Result<u64, FindAllAuthorsError> {
+    let count: i64 = sqlx::query_scalar(COUNT_AUTHORS_SQL)
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to count authors");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(u64::try_from(count).expect("COUNT(*) is never negative"))
+}
+
+/// Takes a connection so that the adapter can run every aggregate in one read transaction.
+#[tracing::instrument(name = "db.author_stats", skip_all, fields(since = %req.since()))]
+async fn author_stats(
+    conn: &mut SqliteConnection,
+    req: &AuthorStatsRequest,
+) -> Result<AuthorStats, FindAllAuthorsError> {
+    let context = |err: sqlx::Error| {
+        FindAllAuthorsError(anyhow!(err).context("Failed to compute author statistics"))
+    };
+    let count = |count: i64| u64::try_from(count).expect("COUNT(*) is never negative");
+    let (total, active, archived): (i64, i64, i64) = sqlx::query_as(AUTHOR_STATUS_COUNTS_SQL)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(context)?;
+    let by_email_domain: Vec<(String, i64)> = sqlx::query_as(AUTHORS_BY_EMAIL_DOMAIN_SQL)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(context)?;
+    let created_per_day: Vec<(NaiveDate, i64)> = sqlx::query_as(AUTHORS_CREATED_PER_DAY_SQL)
+        .bind(req.since())
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(context)?;
+
+    Ok(
+        AuthorStats::new(count(total), count(active), count(archived))
+            .with_by_email_domain(
+                by_email_domain
+                    .into_iter()
+                    .map(|(domain, n)| (domain, count(n)))
+                    .collect(),
+            )
+            .with_created_per_day(
+                created_per_day
+                    .into_iter()
+                    .map(|(day, n)| (day, count(n)))
+                    .collect(),
+            ),
+    )
+}
+
+#[tracing::instrument(name = "db.author_exists", skip_all, fields(id = %req.id()))]
+async fn author_exists<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorRequest,
+) -> Result<bool, FindAuthorError> {
+    let exists = sqlx::query_scalar(AUTHOR_EXISTS_SQL)
+        .bind(req.id())
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context(format!(
+                r#"Failed to check whether author with id "{}" exists"#,
+                req.id()
+            ));
+            FindAuthorError::Other(err)
+        })?;
+
+    Ok(exists)
+}
+
+#[tracing::instrument(name = "db.stream_all_authors", skip_all)]
+fn stream_all_authors(
+    pool: SqlitePool,
+    cipher: Arc<dyn FieldCipher>,
+) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+    let (sender, receiver) = mpsc::channel(STREAM_BUFFER);
+    tokio::spawn(async move {
+        let mut rows = sqlx::query_as(FIND_ALL_AUTHORS_SQL).fetch(&pool);
+        while let Some(row) = rows.next().await {
+            let row = row
+                .map_err(|err| anyhow!(err).context("Failed to stream authors"))
+                .and_then(|row: SealedAuthor| {
+                    row.open(cipher.as_ref())
+                        .context("Failed to decrypt author email")
+                })
+                .map_err(FindAllAuthorsError);
+            let failed = row.is_err();
+            if sender.send(row).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    stream::unfold(receiver, |mut receiver| async move {
+        let row = receiver.recv().await?;
+        Some((row, receiver))
+    })
+    .boxed()
+}
+
+#[tracing::instrument(name = "db.update_author", skip_all, fields(id = %req.id()))]
+async fn update_author<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &UpdateAuthorRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Author, UpdateAuthorError> {
+    if req.name().is_none() && req.email().is_none() && !req.changes_profile() {
+        return Err(UpdateAuthorError::NothingToUpdate { id: req.id() });
+    }
+    let email = req
+        .email()
+        .map(|email| SealedEmail::seal(cipher, email))
+        .transpose()?;
+
+    let mut query = update_author_query(req, email, Utc::now());
+    query.push(" RETURNING *");
+    let author: Option<SealedAuthor> = query
+        .build_query_as()
+        .fetch_optional(executor)
+        .await
+        .map_err(|err| {
+            if let Some(email) = req.email()
+                && is_unique_violation(&err, "author.email_index")
+            {
+                UpdateAuthorError::DuplicateEmail {
+                    email: email.to_string(),
+                }
+            } else {
+                let err = anyhow!(err)
+                    .context(format!(r#"Failed to update author with id "{}""#, req.id()));
+                UpdateAuthorError::Other(err)
+            }
+        })?;
+    let author = author.ok_or(UpdateAuthorError::NotFound { id: req.id() })?;
+
+    Ok(author
+        .open(cipher)
+        .context("Failed to decrypt author email")?)
+}
+
+/// Assigns only the columns the request changes, always in the same order, with every value
+/// bound rather than written into the statement.
+fn update_author_query(
+    req: &UpdateAuthorRequest,
+    email: Option<SealedEmail>,
+    now: DateTime<Utc>,
+) -> QueryBuilder<'_, Sqlite> {
+    let mut query = QueryBuilder::<Sqlite>::new("UPDATE author SET ");
+    let mut assignments = query.separated(", ");
+    if let Some(name) = req.name() {
+        assignments.push("name = ");
+        assignments.push_bind_unseparated(name.as_str());
+    }
+    if let Some(email) = email {
+        assignments.push("email_verification = CASE WHEN email_index = ");
+        assignments.push_bind_unseparated(email.index.clone());
+        assignments.push_unseparated(" THEN email_verification ELSE 'pending' END");
+        assignments.push("email = ");
+        assignments.push_bind_unseparated(email.ciphertext);
+        assignments.push("email_index = ");
+        assignments.push_bind_unseparated(email.index);
+        assignments.push("email_domain = ");
+        assignments.push_bind_unseparated(email.domain);
+    }
+    if !req.bio().is_unchanged() {
+        assignments.push("bio = ");
+        assignments.push_bind_unseparated(req.bio().apply(None).map(|bio| bio.to_string()));
+    }
+    if !req.birth_date().is_unchanged() {
+        assignments.push("birth_date = ");
+        assignments.push_bind_unseparated(req.birth_date().apply(None).map(BirthDate::date));
+    }
+    if !req.website().is_unchanged() {
+        assignments.push("website_url = ");
+        assignments.push_bind_unseparated(req.website().apply(None).map(|url| url.to_string()));
+    }
+    if !req.country().is_unchanged() {
+        assignments.push("country = ");
+        assignments.push_bind_unseparated(req.country().apply(None).map(|code| code.to_string()));
+    }
+    assignments.push("updated_at = ");
+    assignments.push_bind_unseparated(now);
+    query.push(" WHERE id = ").push_bind(req.id());
+    query
+}
+
+#[tracing::instrument(name = "db.upsert_author", skip_all, fields(id = %req.id()))]
+async fn upsert_author<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &ReplaceAuthorRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Author, ReplaceAuthorError> {
+    let now = Utc::now();
+    let email = SealedEmail::seal(cipher, req.email())?;
+    let query = sqlx::query_as(
+        "INSERT INTO author \
+         (id, name, email, email_index, email_domain, bio, birth_date, website_url, country, \
+         created_at, updated_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+         ON CONFLICT (id) DO UPDATE SET \
+         name = excluded.name, email = excluded.email, email_index = excluded.email_index, \
+         email_domain = excluded.email_domain, bio = excluded.bio, \
+         birth_date = excluded.birth_date, website_url = excluded.website_url, \
+         country = excluded.country, updated_at = excluded.updated_at, \
+         email_verification = CASE WHEN author.email_index = excluded.email_index \
+         THEN author.email_verification ELSE 'pending' END \
+         RETURNING *",
+    )
+    .bind(req.id())
+    .bind(req.name().as_str())
+    .bind(email.ciphertext)
+    .bind(email.index)
+    .bind(email.domain);
+    let author: SealedAuthor = bind_profile(query, req.profile())
+        .bind(now)
+        .bind(now)
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "author.email_index") {
+                ReplaceAuthorError::DuplicateEmail {
+                    email: req.email().to_string(),
+                }
+            } else if is_unique_violation(&err, "author.name") {
+                ReplaceAuthorError::Duplicate {
+                    name: req.name().to_string(),
+                }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to replace author with id "{}""#,
+                    req.id()
+                ));
+                ReplaceAuthorError::Other(err)
+            }
+        })?;
+
+    Ok(author
+        .open(cipher)
+        .context("Failed to decrypt author email")?)
+}
+
+/// Takes a connection rather than an executor, as SQLite does not say whether an upsert inserted
+/// or updated: whether the address is taken is read first, within the caller's transaction.
+#[tracing::instrument(name = "db.upsert_author_by_email", skip_all)]
+async fn upsert_author_by_email(
+    conn: &mut SqliteConnection,
+    req: &CreateAuthorRequest,
+    id_strategy: AuthorIdStrategy,
+    cipher: &dyn FieldCipher,
+) -> Result<ReplacedAuthor, UpsertAuthorError> {
+    let context = || format!(r#"Failed to upsert author with name "{}""#, req.name());
+    let email = SealedEmail::seal(cipher, req.email())?;
+    let existed: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM author WHERE email_index = ?)")
+            .bind(email.index.as_str())
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|err| anyhow!(err).context(context()))?;
+
+    let id = new_author_id(req, id_strategy);
+    let sql = format!(
+        "{} ON CONFLICT (email_index) DO UPDATE SET \
+         name = excluded.name, email = excluded.email, email_domain = excluded.email_domain, \
+         bio = excluded.bio, birth_date = excluded.birth_date, \
+         website_url = excluded.website_url, country = excluded.country, \
+         updated_at = excluded.updated_at \
+         RETURNING *",
+        insert_author_sql(id.is_some())
+    );
+    let mut query = sqlx::query_as(&sql);
+    if let Some(id) = id {
+        query = query.bind(id);
+    }
+    let now = Utc::now();
+    let query = query
+        .bind(req.name().as_str())
+        .bind(email.ciphertext)
+        .bind(email.index)
+        .bind(email.domain);
+    let author: SealedAuthor = bind_profile(query, req.profile())
+        .bind(now)
+        .bind(now)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "author.name") {
+                UpsertAuthorError::Duplicate {
+                    name: req.name().to_string(),
+                }
+            } else if let Some(id) = id.filter(|_| is_unique_violation(&err, "author.id")) {
+                UpsertAuthorError::IdTaken { id }
+            } else {
+                UpsertAuthorError::Other(anyhow!(err).context(context()))
+            }
+        })?;
+
+    let author = author
+        .open(cipher)
+        .context("Failed to decrypt author email")?;
+    Ok(if existed {
+        ReplacedAuthor::Replaced(author)
+    } else {
+        ReplacedAuthor::Created(author)
+    })
+}
+
+#[tracing::instrument(name = "db.set_author_status", skip_all, fields(id = %req.id(), status = %req.status()))]
+async fn set_author_status<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &SetAuthorStatusRequest,
+) -> Result<(), ChangeAuthorStatusError> {
+    let result = sqlx::query("UPDATE author SET status = ?, updated_at = ? WHERE id = ?")
+        .bind(req.status().as_str())
+        .bind(Utc::now())
+        .bind(req.id())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to change status of author with id "{}""#,
+                req.id()
+            ))
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(ChangeAuthorStatusError::NotFound { id: req.id() });
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.find_authors_by_verification", skip_all)]
+async fn find_authors_by_verification<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorsByVerificationRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    if req.states().is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut query = QueryBuilder::<Sqlite>::new(
+        "SELECT id, name, email, status, email_verification, bio, birth_date, website_url, \
+     country, created_at, updated_at FROM author WHERE email_verification IN (",
+    );
+    let mut states = query.separated(", ");
+    for state in req.states() {
+        states.push_bind(state.as_str());
+    }
+    query.push(") ORDER BY id");
+    if let Some(limit) = req.limit() {
+        query
+            .push(" LIMIT ")
+            .push_bind(i64::try_from(limit).unwrap_or(i64::MAX));
+    }
+
+    let authors = query
+        .build_query_as()
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to retrieve authors by email verification");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+#[tracing::instrument(
+    name = "db.set_email_verification",
+    skip_all,
+    fields(id = %req.id(), verification = %req.verification())
+)]
+async fn set_email_verification<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &SetEmailVerificationRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<(), SetEmailVerificationError> {
+    sqlx::query(
+        "UPDATE author SET email_verification = ?, updated_at = ? WHERE id = ? AND email_index = ?",
+    )
+    .bind(req.verification().as_str())
+    .bind(Utc::now())
+    .bind(req.id())
+    .bind(cipher.blind_index(req.email().as_str()))
+    .execute(executor)
+    .await
+    .map_err(|err| {
+        anyhow!(err).context(format!(
+            r#"Failed to record email verification of author with id "{}""#,
+            req.id()
+        ))
+    })?;
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.delete_author", skip_all, fields(id = %req.id()))]
+async fn delete_author<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &DeleteAuthorRequest,
+) -> Result<(), DeleteAuthorError> {
+    let result = sqlx::query("DELETE FROM author WHERE id = ?")
+        .bind(req.id())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(r#"Failed to delete author with id "{}""#, req.id()))
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(DeleteAuthorError::NotFound { id: req.id() });
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.add_author_alias", skip_all, fields(author_id = %req.author_id()))]
+async fn add_author_alias<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &AddAuthorAliasRequest,
+) -> Result<(), AddAuthorAliasError> {
+    sqlx::query("INSERT INTO author_alias (alias, author_id) VALUES (?, ?)")
+        .bind(req.alias().as_str())
+        .bind(req.author_id())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "author_alias.alias") {
+                AddAuthorAliasError::DuplicateAlias {
+                    alias: req.alias().clone(),
+                }
+            } else if is_foreign_key_violation(&err) {
+                AddAuthorAliasError::NotFound {
+                    id: req.author_id(),
+                }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to add alias to author with id "{}""#,
+                    req.author_id()
+                ));
+                AddAuthorAliasError::Other(err)
+            }
+        })?;
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.remove_author_alias", skip_all, fields(author_id = %req.author_id()))]
+async fn remove_author_alias<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &RemoveAuthorAliasRequest,
+) -> Result<(), RemoveAuthorAliasError> {
+    let result = sqlx::query("DELETE FROM author_alias WHERE alias = ? AND author_id = ?")
+        .bind(req.alias().as_str())
+        .bind(req.author_id())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to remove alias from author with id "{}""#,
+                req.author_id()
+            ))
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(RemoveAuthorAliasError::NotFound {
+            id: req.author_id(),
+            alias: req.alias().clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Takes a connection rather than an executor, as it runs two queries: an author without aliases
+/// must be told apart from a missing one.
+#[tracing::instrument(name = "db.find_author_aliases", skip_all, fields(id = %req.id()))]
+async fn find_author_aliases(
+    conn: &mut SqliteConnection,
+    req: &FindAuthorRequest,
+) -> Result<Vec<AuthorName>, FindAuthorError> {
+    if !author_exists(&mut *conn, req).await? {
+        return Err(FindAuthorError::NotFound { id: req.id() });
+    }
+    let aliases: Vec<String> = sqlx::query_scalar(FIND_AUTHOR_ALIASES_SQL)
+        .bind(req.id())
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to retrieve aliases of author with id "{}""#,
+                req.id()
+            ))
+        })?;
+
+    Ok(aliases
+        .iter()
+        .map(|alias| AuthorName::new_unchecked(alias))
+        .collect())
+}
+
+#[tracing::instrument(name = "db.search_authors", skip_all)]
+async fn search_authors<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &SearchAuthorsRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    let escaped = req
+        .query()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    let authors = sqlx::query_as(SEARCH_AUTHORS_SQL)
+        .bind(format!("%{escaped}%"))
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context("Failed to search authors");
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+#[tracing::instrument(name = "db.create_genre", skip_all)]
+async fn create_genre<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &CreateGenreRequest,
+) -> Result<Genre, CreateGenreError> {
+    let id = sqlx::query_scalar("INSERT INTO genre (name) VALUES (?) RETURNING id")
+        .bind(req.name().to_string())
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "genre.name") {
+                CreateGenreError::Duplicate {
+                    name: req.name().clone(),
+                }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to save genre with name "{}""#,
+                    req.name()
+                ));
+                CreateGenreError::Other(err)
+            }
+        })?;
+
+    Ok(Genre::new(GenreId::new(id), req.name().clone()))
+}
+
+#[tracing::instrument(name = "db.find_all_genres", skip_all)]
+async fn find_all_genres<'e>(
+    executor: impl SqliteExecutor<'e>,
+) -> Result<Vec<Genre>, FindAllGenresError> {
+    let genres = sqlx::query_as(FIND_ALL_GENRES_SQL)
+        .fetch_all(executor)
+        .await
+        .map_err(|err| FindAllGenresError(anyhow!(err).context("Failed to retrieve genres")))?;
+
+    Ok(genres)
+}
+
+#[tracing::instrument(name = "db.delete_genre", skip_all, fields(id = %req.id()))]
+async fn delete_genre<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &DeleteGenreRequest,
+) -> Result<(), DeleteGenreError> {
+    let result = sqlx::query("DELETE FROM genre WHERE id = ?")
+        .bind(req.id().get())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            if is_foreign_key_violation(&err) {
+                DeleteGenreError::InUse { id: req.id() }
+            } else {
+                let err = anyhow!(err)
+                    .context(format!(r#"Failed to delete genre with id "{}""#, req.id()));
+                DeleteGenreError::Other(err)
+            }
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(DeleteGenreError::NotFound { id: req.id() });
+    }
+
+    Ok(())
+}
+
+/// Takes a connection rather than an executor, as SQLite does not say which foreign key a
+/// failed insert violated: both ends are looked up first.
+#[tracing::instrument(name = "db.attach_genre", skip_all, fields(author_id = %req.author_id(), genre_id = %req.genre_id()))]
+async fn attach_genre(
+    conn: &mut SqliteConnection,
+    req: &AuthorGenreRequest,
+) -> Result<(), AttachGenreError> {
+    let context = || {
+        format!(
+            r#"Failed to attach genre with id "{}" to author with id "{}""#,
+            req.genre_id(),
+            req.author_id()
+        )
+    };
+    let find = FindAuthorRequest::new(req.author_id());
+    let author_exists = author_exists(&mut *conn, &find)
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+    if !author_exists {
+        return Err(AttachGenreError::AuthorNotFound {
+            id: req.author_id(),
+        });
+    }
+    let genre_exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM genre WHERE id = ?)")
+        .bind(req.genre_id().get())
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+    if !genre_exists {
+        return Err(AttachGenreError::GenreNotFound { id: req.genre_id() });
+    }
+    sqlx::query("INSERT OR IGNORE INTO author_genre (author_id, genre_id) VALUES (?, ?)")
+        .bind(req.author_id())
+        .bind(req.genre_id().get())
+        .execute(&mut *conn)
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.detach_genre", skip_all, fields(author_id = %req.author_id(), genre_id = %req.genre_id()))]
+async fn detach_genre<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &AuthorGenreRequest,
+) -> Result<(), DetachGenreError> {
+    let result = sqlx::query("DELETE FROM author_genre WHERE author_id = ? AND genre_id = ?")
+        .bind(req.author_id())
+        .bind(req.genre_id().get())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to detach genre with id "{}" from author with id "{}""#,
+                req.genre_id(),
+                req.author_id()
+            ))
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(DetachGenreError::NotFound {
+            author_id: req.author_id(),
+            genre_id: req.genre_id(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Takes a connection for the same reason as [`find_author_aliases`].
+#[tracing::instrument(name = "db.find_author_genres", skip_all, fields(id = %req.id()))]
+async fn find_author_genres(
+    conn: &mut SqliteConnection,
+    req: &FindAuthorRequest,
+) -> Result<Vec<Genre>, FindAuthorError> {
+    if !author_exists(&mut *conn, req).await? {
+        return Err(FindAuthorError::NotFound { id: req.id() });
+    }
+    let genres = sqlx::query_as(FIND_AUTHOR_GENRES_SQL)
+        .bind(req.id())
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to retrieve genres of author with id "{}""#,
+                req.id()
+            ))
+        })?;
+
+    Ok(genres)
+}
+
+#[tracing::instrument(name = "db.find_authors_by_genre", skip_all, fields(genre_id = %req.genre_id()))]
+async fn find_authors_by_genre<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuthorsByGenreRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<Author>, FindAllAuthorsError> {
+    let authors = sqlx::query_as(FIND_AUTHORS_BY_GENRE_SQL)
+        .bind(req.genre_id().get())
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            let err = anyhow!(err).context(format!(
+                r#"Failed to retrieve authors with genre id "{}""#,
+                req.genre_id()
+            ));
+            FindAllAuthorsError(err)
+        })?;
+
+    Ok(open_authors(authors, cipher)?)
+}
+
+#[tracing::instrument(name = "db.create_publisher", skip_all)]
+async fn create_publisher<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &CreatePublisherRequest,
+) -> Result<Publisher, CreatePublisherError> {
+    let id = sqlx::query_scalar("INSERT INTO publisher (name) VALUES (?) RETURNING id")
+        .bind(req.name().to_string())
+        .fetch_one(executor)
+        .await
+        .map_err(|err| {
+            if is_unique_violation(&err, "publisher.name") {
+                CreatePublisherError::Duplicate {
+                    name: req.name().clone(),
+                }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to save publisher with name "{}""#,
+                    req.name()
+                ));
+                CreatePublisherError::Other(err)
+            }
+        })?;
+
+    Ok(Publisher::new(PublisherId::new(id), req.name().clone()))
+}
+
+#[tracing::instrument(name = "db.find_publisher", skip_all, fields(id = %req.id()))]
+async fn find_publisher<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindPublisherRequest,
+) -> Result<Publisher, FindPublisherError> {
+    sqlx::query_as("SELECT id, name FROM publisher WHERE id = ?")
+        .bind(req.id().get())
+        .fetch_optional(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to retrieve publisher with id "{}""#,
+                req.id()
+            ))
+        })?
+        .ok_or(FindPublisherError::NotFound { id: req.id() })
+}
+
+#[tracing::instrument(name = "db.find_all_publishers", skip_all)]
+async fn find_all_publishers<'e>(
+    executor: impl SqliteExecutor<'e>,
+) -> Result<Vec<Publisher>, FindAllPublishersError> {
+    let publishers = sqlx::query_as(FIND_ALL_PUBLISHERS_SQL)
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            FindAllPublishersError(anyhow!(err).context("Failed to retrieve publishers"))
+        })?;
+
+    Ok(publishers)
+}
+
+#[tracing::instrument(name = "db.delete_publisher", skip_all, fields(id = %req.id()))]
+async fn delete_publisher<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &DeletePublisherRequest,
+) -> Result<(), DeletePublisherError> {
+    let result = sqlx::query("DELETE FROM publisher WHERE id = ?")
+        .bind(req.id().get())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            if is_foreign_key_violation(&err) {
+                DeletePublisherError::HasContracts { id: req.id() }
+            } else {
+                let err = anyhow!(err).context(format!(
+                    r#"Failed to delete publisher with id "{}""#,
+                    req.id()
+                ));
+                DeletePublisherError::Other(err)
+            }
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(DeletePublisherError::NotFound { id: req.id() });
+    }
+
+    Ok(())
+}
+
+async fn publisher_exists<'e>(
+    executor: impl SqliteExecutor<'e>,
+    id: PublisherId,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM publisher WHERE id = ?)")
+        .bind(id.get())
+        .fetch_one(executor)
+        .await
+}
+
+/// Takes a connection for the same reason as [`attach_genre`].
+#[tracing::instrument(name = "db.create_contract", skip_all, fields(author_id = %req.author_id(), publisher_id = %req.publisher_id()))]
+async fn create_contract(
+    conn: &mut SqliteConnection,
+    req: &CreateContractRequest,
+) -> Result<Contract, CreateContractError> {
+    let context = || {
+        format!(
+            r#"Failed to save contract between author with id "{}" and publisher with id "{}""#,
+            req.author_id(),
+            req.publisher_id()
+        )
+    };
+    let find = FindAuthorRequest::new(req.author_id());
+    let author_exists = author_exists(&mut *conn, &find)
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+    if !author_exists {
+        return Err(CreateContractError::AuthorNotFound {
+            id: req.author_id(),
+        });
+    }
+    let publisher_exists = publisher_exists(&mut *conn, req.publisher_id())
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+    if !publisher_exists {
+        return Err(CreateContractError::PublisherNotFound {
+            id: req.publisher_id(),
+        });
+    }
+    let id = sqlx::query_scalar(
+        "INSERT INTO contract \
+         (author_id, publisher_id, starts_on, ends_on, royalty_basis_points) \
+         VALUES (?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(req.author_id())
+    .bind(req.publisher_id().get())
+    .bind(req.term().starts_on())
+    .bind(req.term().ends_on())
+    .bind(req.royalty().basis_points())
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|err| anyhow!(err).context(context()))?;
+
+    Ok(Contract::new(
+        ContractId::new(id),
+        req.author_id(),
+        req.publisher_id(),
+        *req.term(),
+        req.royalty(),
+    ))
+}
+
+/// Takes a connection for the same reason as [`find_author_aliases`].
+#[tracing::instrument(name = "db.find_author_contracts", skip_all, fields(id = %req.id()))]
+async fn find_author_contracts(
+    conn: &mut SqliteConnection,
+    req: &FindAuthorRequest,
+) -> Result<Vec<Contract>, FindAuthorError> {
+    if !author_exists(&mut *conn, req).await? {
+        return Err(FindAuthorError::NotFound { id: req.id() });
+    }
+    let contracts = sqlx::query_as(FIND_AUTHOR_CONTRACTS_SQL)
+        .bind(req.id())
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to retrieve contracts of author with id "{}""#,
+                req.id()
+            ))
+        })?;
+
+    Ok(contracts)
+}
+
+/// Takes a connection for the same reason as [`find_author_aliases`].
+#[tracing::instrument(name = "db.find_publisher_contracts", skip_all, fields(id = %req.id()))]
+async fn find_publisher_contracts(
+    conn: &mut SqliteConnection,
+    req: &FindPublisherRequest,
+) -> Result<Vec<Contract>, FindPublisherError> {
+    let context = || {
+        format!(
+            r#"Failed to retrieve contracts of publisher with id "{}""#,
+            req.id()
+        )
+    };
+    let exists = publisher_exists(&mut *conn, req.id())
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+    if !exists {
+        return Err(FindPublisherError::NotFound { id: req.id() });
+    }
+    let contracts = sqlx::query_as(FIND_PUBLISHER_CONTRACTS_SQL)
+        .bind(req.id().get())
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|err| anyhow!(err).context(context()))?;
+
+    Ok(contracts)
+}
+
+#[tracing::instrument(name = "db.delete_contract", skip_all, fields(author_id = %req.author_id(), contract_id = %req.contract_id()))]
+async fn delete_contract<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &DeleteContractRequest,
+) -> Result<(), DeleteContractError> {
+    let result = sqlx::query("DELETE FROM contract WHERE id = ? AND author_id = ?")
+        .bind(req.contract_id().get())
+        .bind(req.author_id())
+        .execute(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to delete contract with id "{}" of author with id "{}""#,
+                req.contract_id(),
+                req.author_id()
+            ))
+        })?;
+    if result.rows_affected() == 0 {
+        return Err(DeleteContractError::NotFound {
+            author_id: req.author_id(),
+            contract_id: req.contract_id(),
+        });
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "db.record_audit", skip_all, fields(author_id = %req.author_id(), action = %req.action()))]
+async fn record_audit<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &RecordAuditRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<AuditEntry, RecordAuditError> {
+    let seal =
+        |snapshot| seal_snapshot(snapshot, cipher).context("Failed to encrypt audit snapshot");
+    let (before, after) = (seal(req.before())?, seal(req.after())?);
+    let recorded_at = Utc::now();
+    let id = sqlx::query_scalar(
+        "INSERT INTO audit_log (author_id, action, actor, request_id, before, after, recorded_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(req.author_id())
+    .bind(req.action().as_str())
+    .bind(req.context().actor())
+    .bind(req.context().request_id())
+    .bind(before)
+    .bind(after)
+    .bind(recorded_at)
+    .fetch_one(executor)
+    .await
+    .map_err(|err| {
+        anyhow!(err).context(format!(
+            r#"Failed to record audit entry for author with id "{}""#,
+            req.author_id()
+        ))
+    })?;
+
+    Ok(AuditEntry::new(id, req.clone(), recorded_at))
+}
+
+#[tracing::instrument(name = "db.find_audit_log", skip_all, fields(author_id = %req.author_id()))]
+async fn find_audit_log<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuditLogRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+    let entries = sqlx::query_as(FIND_AUDIT_LOG_SQL)
+        .bind(req.author_id())
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                r#"Failed to retrieve audit log for author with id "{}""#,
+                req.author_id()
+            ))
+        })?;
+
+    Ok(open_audit_entries(entries, cipher)?)
+}
+
+#[tracing::instrument(name = "db.find_changes", skip_all, fields(after = req.after()))]
+async fn find_changes<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindChangesRequest,
+    cipher: &dyn FieldCipher,
+) -> Result<Vec<AuditEntry>, FindAuditLogError> {
+    let entries = sqlx::query_as(FIND_CHANGES_SQL)
+        .bind(req.after())
+        .bind(req.limit())
+        .fetch_all(executor)
+        .await
+        .map_err(|err| {
+            anyhow!(err).context(format!(
+                "Failed to retrieve audit log entries after id {}",
+                req.after()
+            ))
+        })?;
+
+    Ok(open_audit_entries(entries, cipher)?)
+}
+
+#[tracing::instrument(name = "db.latest_change_id", skip_all)]
+async fn latest_change_id<'e>(
+    executor: impl SqliteExecutor<'e>,
+) -> Result<Option<i64>, FindAuditLogError> {
+    let id = sqlx::query_scalar("SELECT MAX(id) FROM audit_log")
+        .fetch_one(executor)
+        .await
+        .context("Failed to retrieve latest audit log id")?;
+
+    Ok(id)
+}
+
+#[tracing::instrument(name = "db.erase_audit_snapshots", skip_all, fields(author_id = %req.author_id()))]
+async fn erase_audit_snapshots<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &FindAuditLogRequest,
+) -> Result<u64, RecordAuditError> {
+    let result =
+        sqlx::query("UPDATE audit_log SET before = NULL, after = NULL WHERE author_id = ?")
+            .bind(req.author_id())
+            .execute(executor)
+            .await
+            .map_err(|err| {
+                anyhow!(err).context(format!(
+                    r#"Failed to erase audit snapshots of author with id "{}""#,
+                    req.author_id()
+                ))
+            })?;
+
+    Ok(result.rows_affected())
+}
+
+/// Reads the latest hash and appends in one transaction, so concurrent purges cannot fork the
+/// chain.
+#[tracing::instrument(name = "db.record_erasure", skip_all, fields(author_id = %req.author_id()))]
+async fn record_erasure(
+    conn: &mut SqliteConnection,
+    req: &RecordErasureRequest,
+) -> Result<ErasureRecord, RecordAuditError> {
+    let context = || {
+        format!(
+            r#"Failed to record erasure of author with id "{}""#,
+            req.author_id()
+        )
+    };
+    let previous_hash: Option<String> =
+        sqlx::query_scalar("SELECT hash FROM erasure_log ORDER BY id DESC LIMIT 1")
+            .fetch_optional(&mut *conn)
+            .await
+            .with_context(context)?;
+    let previous_hash = previous_hash.unwrap_or_else(|| ERASURE_LOG_GENESIS.to_string());
+    let erased_at = Utc::now();
+    let hash = req.hash(&previous_hash, erased_at);
+    let id = sqlx::query_scalar(
+        "INSERT INTO erasure_log (author_id, actor, request_id, anonymized_entries, erased_at, \
+         previous_hash, hash) VALUES (?, ?, ?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(req.author_id())
+    .bind(req.context().actor())
+    .bind(req.context().request_id())
+    .bind(i64::try_from(req.anonymized_entries()).unwrap_or(i64::MAX))
+    .bind(erased_at)
+    .bind(&previous_hash)
+    .bind(&hash)
+    .fetch_one(&mut *conn)
+    .await
+    .with_context(context)?;
+
+    Ok(ErasureRecord::new(
+        id,
+        req.clone(),
+        erased_at,
+        previous_hash,
+        hash,
+    ))
+}
+
+#[tracing::instrument(name = "db.find_erasures", skip_all)]
+async fn find_erasures<'e>(
+    executor: impl SqliteExecutor<'e>,
+) -> Result<Vec<ErasureRecord>, FindAuditLogError> {
+    let records = sqlx::query_as(FIND_ERASURES_SQL)
+        .fetch_all(executor)
+        .await
+        .context("Failed to retrieve erasure log")?;
+
+    Ok(records)
+}
+
+async fn record_security_event<'e>(
+    executor: impl SqliteExecutor<'e>,
+    req: &RecordSecurityEventRequest,
+) -> Result<SecurityEvent, RecordAuditError> {
+    let recorded_at = Utc::now();
+    let id = sqlx::query_scalar(
+        "INSERT INTO security_event (kind, client, reason, expires_at, recorded_at) \
+         VALUES (?, ?, ?, ?, ?) RETURNING id",
+    )
+    .bind(req.kind().as_str())
+    .bind(req.client())
+    .bind(req.reason())
+    .bind(req.expires_at())
+    .bind(recorded_at)
+    .fetch_one(executor)
+    .await
+    .with_context(|| {
+        format!(
+            r#"Failed to record security event about "{}""#,
+            req.client()
+        )
+    })?;
+
+    Ok(SecurityEvent::new(id, req.clone(), recorded_at))
+}
+
+async fn find_security_events<'e>(
+    executor: impl SqliteExecutor<'e>,
+    limit: u32,
+) -> Result<Vec<SecurityEvent>, FindAuditLogError> {
+    let events = sqlx::query_as(FIND_SECURITY_EVENTS_SQL)
+        .bind(limit)
+        .fetch_all(executor)
+        .await
+        .context("Failed to retrieve security events")?;
+
+    Ok(events)
+}
+
+fn decode_json(
+    column: &str,
+    value: Option<&str>,
+) -> Result<Option<serde_json::Value>, sqlx::Error> {
+    value
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|err| sqlx::Error::ColumnDecode {
+            index: column.into(),
+            source: Box::new(err),
+        })
+}
+
+/// Whether `err` was caused by a condition that may clear on its own, such as a locked database
+/// or a dropped connection, so the operation is worth repeating.
+pub fn is_transient(err: &anyhow::Error) -> bool {
+    err.chain()
+        .filter_map(|cause| cause.downcast_ref::<sqlx::Error>())
+        .any(|err| match err {
+            sqlx::Error::Database(db_err) => db_err
+                .code()
+                .and_then(|code| code.parse::<i32>().ok())
+                .is_some_and(|code| matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED)),
+            sqlx::Error::Io(io_err) => matches!(
+                io_err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::TimedOut
+            ),
+            sqlx::Error::PoolTimedOut => true,
+            _ => false,
+        })
+}
+
+fn bind_profile<'q, O>(
+    query: QueryAs<'q, Sqlite, O, SqliteArguments<'q>>,
+    profile: &AuthorProfile,
+) -> QueryAs<'q, Sqlite, O, SqliteArguments<'q>> {
+    query
+        .bind(profile.bio().map(ToString::to_string))
+        .bind(profile.birth_date().map(BirthDate::date))
+        .bind(profile.website().map(ToString::to_string))
+        .bind(profile.country().map(|code| code.to_string()))
+}
+
+fn is_unique_violation(err: &sqlx::Error, column: &str) -> bool {
+    if let sqlx::Error::Database(db_err) = err {
+        return db_err.is_unique_violation() && db_err.message().contains(column);
+    }
+
+    false
+}
+
+fn is_foreign_key_violation(err: &sqlx::Error) -> bool {
+    if let sqlx::Error::Database(db_err) = err {
+        return db_err.is_foreign_key_violation();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AuditAction, AuditContext, Author, AuthorId, AuthorIdStrategy, AuthorName, Biography,
+        BirthDate, CountryCode, CreateAuthorError, CreateAuthorRequest, ERASURE_LOG_GENESIS,
+        EmailAddress, EmailVerification, ErasureRecord, FieldUpdate, FindAuditLogRequest,
+        FindAuthorByEmailRequest, FindAuthorRequest, MigrationStatus, RecordAuditRequest,
+        RecordErasureRequest, RestoreBackupError, RetentionPolicy, SessionId,
+        SetEmailVerificationRequest, StoredSession, UpdateAuthorRequest, WebsiteUrl,
+    };
+    use crate::domain::ports::contract::{
+        genre_repository_contract_tests, publisher_repository_contract_tests,
+        repository_contract_tests,
+    };
+    use crate::domain::ports::{
+        AuditRecorder, AuthorRepository, BackupStore, MigrationStore, RetentionStore, SessionStore,
+        UnitOfWork,
+    };
+    use crate::outbound::cipher::{FieldCipherKeys, PlaintextCipher, field_cipher};
+    use crate::outbound::sqlite::{
+        AUTHOR_EXISTS_SQL, AUTHORS_CREATED_PER_DAY_SQL, Backups, ConnectRetryConfig,
+        DefaultAuditRecorder, DefaultAuthorRepository, DefaultGenreRepository,
+        DefaultPublisherRepository, DefaultSessionStore, DefaultUnitOfWork, FIND_ALL_AUTHORS_SQL,
+        FIND_AUDIT_LOG_SQL, FIND_AUTHOR_ALIASES_SQL, FIND_AUTHOR_BY_EMAIL_SQL,
+        FIND_AUTHOR_CONTRACTS_SQL, FIND_AUTHOR_GENRES_SQL, FIND_AUTHOR_SQL,
+        FIND_AUTHORS_BY_GENRE_SQL, FIND_CHANGES_SQL, FIND_PUBLISHER_CONTRACTS_SQL, MIGRATOR,
+        Migrations, PoolConfig, Retention, SealedEmail, WalCheckpointJob, WalCheckpointMode,
+        WriteQueue, establish_pool, is_transient, update_author_query,
+    };
+    use anyhow::Context;
+    use chrono::{NaiveDate, TimeDelta, Utc};
+    use futures::StreamExt;
+    use serde_json::json;
+    use sqlx::sqlite::{
+        SqliteAutoVacuum, SqliteConnectOptions, SqliteConnection, SqlitePoolOptions,
+        SqliteSynchronous,
+    };
+    use sqlx::{Connection, Row, SqlitePool};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        MIGRATOR.run(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn updates_assign_and_bind_every_combination_of_fields() {
+        let repo = DefaultAuthorRepository::new(test_pool().await, AuthorIdStrategy::Integer);
+        let columns: [&[&str]; 6] = [
+            &["name = ?"],
+            &[
+                "email_verification = CASE WHEN email_index = ? THEN email_verification ELSE \
+                 'pending' END",
+                "email = ?",
+                "email_index = ?",
+                "email_domain = ?",
+            ],
+            &["bio = ?"],
+            &["birth_date = ?"],
+            &["website_url = ?"],
+            &["country = ?"],
+        ];
+        let bio = Biography::new("Wrote about hobbits").unwrap();
+        let birth_date = BirthDate::new(NaiveDate::from_ymd_opt(1892, 1, 3).unwrap()).unwrap();
+        let website = WebsiteUrl::new("https://example.com/tolkien").unwrap();
+        let country = CountryCode::new("GB").unwrap();
+
+        for fields in 1..64_u8 {
+            let changes = |field: usize| fields & (1 << field) != 0;
+            let created = CreateAuthorRequest::new(
+                AuthorName::new(&format!("Author {fields}")).unwrap(),
+                EmailAddress::new(&format!("author{fields}@example.com")).unwrap(),
+            );
+            let author = repo.create_author(&created).await.unwrap();
+            let name = AuthorName::new(&format!("Renamed {fields}")).unwrap();
+            let email = EmailAddress::new(&format!("renamed{fields}@example.org")).unwrap();
+            let mut builder = UpdateAuthorRequest::builder(author.id());
+            if changes(0) {
+                builder = builder.name(name.clone());
+            }
+            if changes(1) {
+                builder = builder.email(email.clone());
+            }
+            if changes(2) {
+                builder = builder.bio(FieldUpdate::Set(bio.clone()));
+            }
+            if changes(3) {
+                builder = builder.birth_date(FieldUpdate::Set(birth_date));
+            }
+            if changes(4) {
+                builder = builder.website(FieldUpdate::Set(website.clone()));
+            }
+            if changes(5) {
+                builder = builder.country(FieldUpdate::Set(country));
+            }
+            let req = builder.build().unwrap();
+
+            let sealed = req
+                .email()
+                .map(|email| SealedEmail::seal(&PlaintextCipher, email).unwrap());
+            let query = update_author_query(&req, sealed, Utc::now());
+            let assignments: Vec<_> = (0..columns.len())
+                .filter(|field| changes(*field))
+                .flat_map(|field| columns[field].iter().copied())
+                .chain(["updated_at = ?"])
+                .collect();
+            let expected = format!("UPDATE author SET {} WHERE id = ?", assignments.join(", "));
+            assert_eq!(expected, query.sql(), "fields {fields:06b}");
+
+            repo.update_author(&req).await.unwrap();
+            let updated = repo
+                .find_author(&FindAuthorRequest::new(author.id()))
+                .await
+                .unwrap();
+            let profile = updated.profile();
+            let expected_name = if changes(0) { &name } else { author.name() };
+            let expected_email = if changes(1) { &email } else { author.email() };
+            assert_eq!(expected_name, updated.name(), "fields {fields:06b}");
+            assert_eq!(expected_email, updated.email(), "fields {fields:06b}");
+            assert_eq!(changes(2).then_some(&bio), profile.bio());
+            assert_eq!(changes(3).then_some(birth_date), profile.birth_date());
+            assert_eq!(changes(4).then_some(&website), profile.website());
+            assert_eq!(changes(5).then_some(country), profile.country());
+        }
+    }
+
+    #[tokio::test]
+    async fn backups_restore_a_consistent_snapshot() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool = establish_pool(&url, &retry, &PoolConfig::default())
+            .await
+            .unwrap();
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let backups = Backups::new(pool.clone());
+        let create = |name: &str, email: &str| {
+            CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            )
+        };
+        repo.create_author(&create("JRR Tolkien", "jrr.tolkien@example.com"))
+            .await
+            .unwrap();
+
+        let snapshot = backups.snapshot().await.unwrap();
+        repo.create_author(&create("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        backups.restore(&snapshot).await.unwrap();
+        let names: Vec<_> = repo
+            .find_all_authors()
+            .await
+            .unwrap()
+            .iter()
+            .map(|author| author.name().to_string())
+            .collect();
+        assert_eq!(vec!["JRR Tolkien"], names);
+
+        let actual = backups.restore(b"not a database").await;
+        assert!(
+            matches!(actual, Err(RestoreBackupError::Invalid(_))),
+            "expected garbage to be rejected, but got {actual:?}"
+        );
+        assert_eq!(1, repo.find_all_authors().await.unwrap().len());
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[tokio::test]
+    async fn pool_applies_configured_pragmas_and_checkpoints_the_wal() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool_config = PoolConfig::new(0, 2, Duration::from_secs(5))
+            .with_busy_timeout(Duration::from_millis(1_500))
+            .with_synchronous(SqliteSynchronous::Normal)
+            .with_cache_size(Some(-4_096))
+            .with_mmap_size(Some(1 << 20))
+            .with_auto_vacuum(SqliteAutoVacuum::Incremental);
+        let pool = establish_pool(&url, &retry, &pool_config).await.unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        for (pragma, expected) in [
+            ("busy_timeout", 1_500),
+            ("synchronous", 1),
+            ("cache_size", -4_096),
+            ("mmap_size", 1 << 20),
+            ("auto_vacuum", 2),
+        ] {
+            let actual: i64 = sqlx::query_scalar(&format!("PRAGMA {pragma}"))
+                .fetch_one(&mut *conn)
+                .await
+                .unwrap();
+            assert_eq!(expected, actual, "{pragma}");
+        }
+        drop(conn);
+
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        repo.create_author(&CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        ))
+        .await
+        .unwrap();
+        let job = WalCheckpointJob::new(
+            pool.clone(),
+            Duration::from_secs(60),
+            WalCheckpointMode::Truncate,
+        );
+        let checkpoint = job.run_once().await.unwrap();
+        assert!(!checkpoint.busy());
+        assert_eq!(0, checkpoint.log_frames());
+        let wal = std::fs::metadata(format!("{}-wal", path.display())).unwrap();
+        assert_eq!(0, wal.len());
+        assert!("TRUNCATE".parse::<WalCheckpointMode>().is_err());
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[tokio::test]
+    async fn queued_writers_do_not_contend_for_the_lock() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool_config =
+            PoolConfig::new(0, 8, Duration::from_secs(5)).with_busy_timeout(Duration::ZERO);
+        let pool = establish_pool(&url, &retry, &pool_config).await.unwrap();
+        let writes = WriteQueue::spawn(32);
+        let repo = Arc::new(
+            DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+                .with_write_queue(writes.clone()),
+        );
+        let uow = DefaultUnitOfWork::new(pool.clone(), AuthorIdStrategy::Integer)
+            .with_write_queue(writes);
+
+        let writes = (0..16).map(|i| {
+            let req = CreateAuthorRequest::new(
+                AuthorName::new(&format!("Author {i}")).unwrap(),
+                EmailAddress::new(&format!("author{i}@example.com")).unwrap(),
+            );
+            let repo = Arc::clone(&repo);
+            async move { repo.create_author(&req).await }
+        });
+        for result in futures::future::join_all(writes).await {
+            result.unwrap();
+        }
+        assert_eq!(16, repo.count_authors().await.unwrap());
+
+        let tx = uow.begin().await.unwrap();
+        let queued = tokio::spawn({
+            let repo = Arc::clone(&repo);
+            let req = CreateAuthorRequest::new(
+                AuthorName::new("CS Lewis").unwrap(),
+                EmailAddress::new("cs.lewis@example.com").unwrap(),
+            );
+            async move { repo.create_author(&req).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!queued.is_finished());
+        tx.rollback().await.unwrap();
+        queued.await.unwrap().unwrap();
+
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[tokio::test]
+    async fn busy_database_errors_are_transient() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let opts = SqliteConnectOptions::new()
+            .filename(&path)
+            .create_if_missing(true)
+            .busy_timeout(Duration::ZERO);
+        let mut writer = SqliteConnection::connect_with(&opts).await.unwrap();
+        let mut blocked = SqliteConnection::connect_with(&opts).await.unwrap();
+        sqlx::query("CREATE TABLE t (id INTEGER)")
+            .execute(&mut writer)
+            .await
+            .unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut writer)
+            .await
+            .unwrap();
+
+        let err = sqlx::query("INSERT INTO t VALUES (1)")
+            .execute(&mut blocked)
+            .await
+            .context("Failed to insert row")
+            .unwrap_err();
+        assert!(is_transient(&err), "expected {err:?} to be transient");
+        assert!(!is_transient(&anyhow::anyhow!("corrupt row")));
+
+        drop((writer, blocked));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn migrations_can_be_reverted_and_reapplied() {
+        let migrations = Migrations::new(test_pool().await);
+        let status = migrations.find_migrations().await.unwrap();
+        assert!(status.iter().all(MigrationStatus::applied));
+        let latest = status.last().unwrap().version();
+
+        assert_eq!(Some(latest), migrations.undo_last().await.unwrap());
+        let status = migrations.find_migrations().await.unwrap();
+        let pending: Vec<_> = status
+            .iter()
+            .filter(|migration| !migration.applied())
+            .map(MigrationStatus::version)
+            .collect();
+        assert_eq!(vec![latest], pending);
+
+        migrations.run().await.unwrap();
+        let status = migrations.find_migrations().await.unwrap();
+        assert!(status.iter().all(MigrationStatus::applied));
+    }
+
+    #[test]
+    fn connect_retry_backoff_grows_exponentially_until_capped() {
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            Duration::from_secs(30),
+        );
+        let actual: Vec<_> = (1..=5).map(|attempt| retry.backoff(attempt)).collect();
+        let expected = [100, 200, 400, 500, 500].map(Duration::from_millis);
+        assert_eq!(expected.as_slice(), actual, "unexpected backoff sequence");
+    }
+
+    async fn query_plan(pool: &SqlitePool, sql: &str) -> Vec<String> {
+        sqlx::query(&format!("EXPLAIN QUERY PLAN {sql}"))
+            .fetch_all(pool)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.get("detail"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn hot_queries_use_indexes() {
+        let pool = test_pool().await;
+        let cases: [(&str, &[&str]); 12] = [
+            (
+                FIND_AUTHOR_SQL,
+                &["SEARCH author USING INDEX sqlite_autoindex_author_1 (id=?)"],
+            ),
+            (
+                FIND_AUTHOR_BY_EMAIL_SQL,
+                &["SEARCH author USING INDEX author_email_index_idx (email_index=?)"],
+            ),
+            (
+                FIND_ALL_AUTHORS_SQL,
+                &["SCAN author USING INDEX sqlite_autoindex_author_1"],
+            ),
+            (
+                AUTHOR_EXISTS_SQL,
+                &[
+                    "SCAN CONSTANT ROW",
+                    "SCALAR SUBQUERY 1",
+                    "SEARCH author USING COVERING INDEX sqlite_autoindex_author_1 (id=?)",
+                ],
+            ),
+            (
+                AUTHORS_CREATED_PER_DAY_SQL,
+                &[
+                    "SEARCH author USING COVERING INDEX author_created_at_idx (created_at>?)",
+                    "USE TEMP B-TREE FOR GROUP BY",
+                ],
+            ),
+            (
+                FIND_AUTHOR_ALIASES_SQL,
+                &[
+                    "SEARCH author_alias USING INDEX author_alias_author_id_idx (author_id=?)",
+                    "USE TEMP B-TREE FOR ORDER BY",
+                ],
+            ),
+            (
+                FIND_AUTHOR_GENRES_SQL,
+                &[
+                    "SEARCH author_genre USING COVERING INDEX sqlite_autoindex_author_genre_1 (author_id=?)",
+                    "SEARCH genre USING INTEGER PRIMARY KEY (rowid=?)",
+                    "USE TEMP B-TREE FOR ORDER BY",
+                ],
+            ),
+            (
+                FIND_AUTHORS_BY_GENRE_SQL,
+                &[
+                    "SEARCH author USING INDEX sqlite_autoindex_author_1 (id=?)",
+                    "LIST SUBQUERY 1",
+                    "SEARCH author_genre USING INDEX author_genre_genre_id_idx (genre_id=?)",
+                ],
+            ),
+            (
+                FIND_AUTHOR_CONTRACTS_SQL,
+                &["SEARCH contract USING INDEX contract_author_id_idx (author_id=?)"],
+            ),
+            (
+                FIND_PUBLISHER_CONTRACTS_SQL,
+                &["SEARCH contract USING INDEX contract_publisher_id_idx (publisher_id=?)"],
+            ),
+            (
+                FIND_AUDIT_LOG_SQL,
+                &["SEARCH audit_log USING INDEX audit_log_author_id_idx (author_id=?)"],
+            ),
+            (
+                FIND_CHANGES_SQL,
+                &["SEARCH audit_log USING INTEGER PRIMARY KEY (rowid>?)"],
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(expected, query_plan(&pool, sql).await, "for {sql}");
+        }
+    }
+
+    #[tokio::test]
+    async fn repeated_queries_reuse_cached_statements() {
+        let pool = test_pool().await;
+        let cached = pool.acquire().await.unwrap().cached_statements_size();
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let req = FindAuthorRequest::new(AuthorId::new(1));
+        let _ = repo.find_author(&req).await;
+        let _ = repo.find_author(&req).await;
+
+        let conn = pool.acquire().await.unwrap();
+        assert_eq!(cached + 1, conn.cached_statements_size());
+    }
+
+    #[tokio::test]
+    async fn genre_repository_conforms() {
+        let pool = test_pool().await;
+        let authors = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        genre_repository_contract_tests(&authors, &DefaultGenreRepository::new(pool)).await;
+    }
+
+    #[tokio::test]
+    async fn publisher_repository_conforms() {
+        let pool = test_pool().await;
+        let authors = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let publishers = DefaultPublisherRepository::new(pool);
+        publisher_repository_contract_tests(&authors, &publishers).await;
+    }
+
+    #[tokio::test]
+    async fn author_repository_conforms() {
+        let repo = DefaultAuthorRepository::new(test_pool().await, AuthorIdStrategy::Integer);
+        repository_contract_tests(repo).await;
+    }
+
+    #[tokio::test]
+    async fn stream_all_authors_yields_rows_in_id_order() {
+        let repo = DefaultAuthorRepository::new(test_pool().await, AuthorIdStrategy::Integer);
+        for i in 1..=100 {
+            let req = CreateAuthorRequest::new(
+                AuthorName::new(&format!("Author {i}")).unwrap(),
+                EmailAddress::new(&format!("author{i}@example.com")).unwrap(),
+            );
+            repo.create_author(&req).await.unwrap();
+        }
+
+        let ids: Vec<_> = repo
+            .stream_all_authors()
+            .await
+            .map(|author| author.unwrap().id())
+            .collect()
+            .await;
+        let expected: Vec<_> = (1..=100).map(AuthorId::new).collect();
+        assert_eq!(expected, ids);
+    }
+
+    #[tokio::test]
+    async fn retention_previews_then_purges_expired_rows() {
+        let pool = test_pool().await;
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let now = Utc::now();
+        let old = now - TimeDelta::days(40);
+        let mut ids = Vec::new();
+        for (name, email) in [
+            ("JRR Tolkien", "jrr.tolkien@example.com"),
+            ("CS Lewis", "cs.lewis@example.com"),
+            ("Terry Pratchett", "terry.pratchett@example.com"),
+        ] {
+            let req = CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            );
+            ids.push(repo.create_author(&req).await.unwrap().id());
+        }
+        sqlx::query("UPDATE author SET updated_at = ?")
+            .bind(old)
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (id, updated_at) in [(ids[0], old), (ids[1], now)] {
+            sqlx::query("UPDATE author SET status = 'archived', updated_at = ? WHERE id = ?")
+                .bind(updated_at)
+                .bind(id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        for recorded_at in [old, now] {
+            sqlx::query(
+                "INSERT INTO audit_log (author_id, action, actor, recorded_at) \
+                 VALUES (1, 'create', 'anonymous', ?)",
+            )
+            .bind(recorded_at)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("INSERT INTO processed_command (command_id, processed_at) VALUES ('c1', ?)")
+            .bind(old)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let month = Some(Duration::from_secs(30 * 24 * 60 * 60));
+        let retention = Retention::new(
+            pool.clone(),
+            RetentionPolicy::default()
+                .with_archived_authors(month)
+                .with_audit_log(month),
+        );
+
+        let preview = retention.preview_purge(now).await.unwrap();
+        assert!(preview.dry_run());
+        assert_eq!(
+            (1, 1, 0),
+            (
+                preview.archived_authors(),
+                preview.audit_log(),
+                preview.processed_commands()
+            )
+        );
+        assert_eq!(3, repo.find_all_authors().await.unwrap().len());
+
+        let purged = retention.purge_expired(now).await.unwrap();
+        assert!(!purged.dry_run());
+        assert_eq!(preview.total(), purged.total());
+        let remaining: Vec<_> = repo
+            .find_all_authors()
+            .await
+            .unwrap()
+            .iter()
+            .map(Author::id)
+            .collect();
+        assert_eq!(ids[1..], remaining[..]);
+        let commands: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM processed_command")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(1, commands);
+        assert_eq!(0, retention.preview_purge(now).await.unwrap().total());
+    }
+
+    #[tokio::test]
+    async fn erasure_log_is_hash_chained_and_append_only() {
+        let pool = test_pool().await;
+        let audit = DefaultAuditRecorder::new(pool.clone());
+        let ctx = AuditContext::new("admin".into(), Some("req-1".into()));
+        for id in 1..=3 {
+            let req = RecordErasureRequest::new(AuthorId::Integer(id), ctx.clone(), 2);
+            audit.record_erasure(&req).await.unwrap();
+        }
+
+        let records = audit.find_erasures().await.unwrap();
+        assert_eq!(3, records.len());
+        assert_eq!(ERASURE_LOG_GENESIS, records[0].previous_hash());
+        ErasureRecord::verify_chain(&records).unwrap();
+
+        let updated = sqlx::query("UPDATE erasure_log SET actor = 'someone else' WHERE id = 1")
+            .execute(&pool)
+            .await;
+        assert!(updated.is_err(), "expected the update to be refused");
+        sqlx::query("DELETE FROM erasure_log WHERE id = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let records = audit.find_erasures().await.unwrap();
+        let broken = ErasureRecord::verify_chain(&records).unwrap_err();
+        assert_eq!(3, broken.id());
+    }
+
+    #[tokio::test]
+    async fn transaction_rollback_discards_changes() {
+        let pool = test_pool().await;
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let uow = DefaultUnitOfWork::new(pool, AuthorIdStrategy::Integer);
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+
+        let tx = uow.begin().await.unwrap();
+        tx.authors().create_author(&req).await.unwrap();
+        tx.rollback().await.unwrap();
+
+        let authors = repo.find_all_authors().await.unwrap();
+        assert!(authors.is_empty(), "expected rolled back author to be gone");
+    }
+
+    #[tokio::test]
+    async fn duplicate_emails_are_rejected_case_insensitively() {
+        let repo = DefaultAuthorRepository::new(test_pool().await, AuthorIdStrategy::Integer);
+        let first = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        repo.create_author(&first).await.unwrap();
+
+        let second = CreateAuthorRequest::new(
+            AuthorName::new("John Ronald Reuel Tolkien").unwrap(),
+            EmailAddress::new(" JRR.Tolkien@Example.COM ").unwrap(),
+        );
+        let actual = repo.create_author(&second).await;
+        assert!(
+            matches!(&actual, Err(CreateAuthorError::DuplicateEmail { email }) if email == "jrr.tolkien@example.com"),
+            "expected duplicate email error, but got {actual:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn emails_are_normalized_unless_authors_would_share_one() {
+        let repo = DefaultAuthorRepository::new(test_pool().await, AuthorIdStrategy::Integer);
+        let create = |name: &str, email: &str| {
+            CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new_unchecked(email),
+            )
+        };
+        repo.create_author(&create("JRR Tolkien", "JRR.Tolkien@Example.COM"))
+            .await
+            .unwrap();
+        assert_eq!(1, repo.normalize_emails().await.unwrap());
+        assert_eq!(0, repo.normalize_emails().await.unwrap());
+        let by_email =
+            FindAuthorByEmailRequest::new(EmailAddress::new("jrr.tolkien@example.com").unwrap());
+        let found = repo.find_author_by_email(&by_email).await.unwrap();
+        assert_eq!("jrr.tolkien@example.com", found.email().as_str());
+
+        repo.create_author(&create("CS Lewis", "cs.lewis@example.com"))
+            .await
+            .unwrap();
+        let clive = repo
+            .create_author(&create("Clive Hamilton", "CS.Lewis@example.com"))
+            .await
+            .unwrap();
+        let err = repo.normalize_emails().await.unwrap_err();
+        assert!(
+            err.to_string().contains("cs.lewis@example.com"),
+            "unexpected error: {err}"
+        );
+        let clive = repo
+            .find_author(&FindAuthorRequest::new(clive.id()))
+            .await
+            .unwrap();
+        assert_eq!("CS.Lewis@example.com", clive.email().as_str());
+    }
+
+    #[tokio::test]
+    async fn plaintext_emails_are_found_once_sealed() {
+        const KEY: &str = "k1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+        const INDEX_KEY: &str = "QEFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaW1xdXl8=";
+        let pool = test_pool().await;
+        let email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("JRR Tolkien").unwrap(),
+                email.clone(),
+            ))
+            .await
+            .unwrap();
+
+        let keys = FieldCipherKeys::parse(KEY, INDEX_KEY).unwrap();
+        let repo = DefaultAuthorRepository::new(pool, AuthorIdStrategy::Integer)
+            .with_cipher(field_cipher(Some(&keys)));
+        let by_email = FindAuthorByEmailRequest::new(email.clone());
+        assert!(repo.find_author_by_email(&by_email).await.is_err());
+        assert_eq!(1, repo.reencrypt_emails().await.unwrap());
+
+        let found = repo.find_author_by_email(&by_email).await.unwrap();
+        assert_eq!("JRR Tolkien", found.name().as_str());
+        let duplicate = repo
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("John Ronald Reuel Tolkien").unwrap(),
+                email,
+            ))
+            .await;
+        assert!(
+            matches!(duplicate, Err(CreateAuthorError::DuplicateEmail { .. })),
+            "expected duplicate email error, but got {duplicate:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn emails_are_encrypted_at_rest_and_rotated() {
+        const OLD_KEY: &str = "k1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+        const NEW_KEY: &str = "k2:ICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6Ozw9Pj8=";
+        const INDEX_KEY: &str = "QEFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaW1xdXl8=";
+        let pool = test_pool().await;
+        let keys = FieldCipherKeys::parse(OLD_KEY, INDEX_KEY).unwrap();
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .with_cipher(field_cipher(Some(&keys)));
+        let stored_email = |id: i64| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, String>("SELECT email FROM author WHERE id = ?")
+                    .bind(id)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+        DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("CS Lewis").unwrap(),
+                EmailAddress::new("cs.lewis@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let email = EmailAddress::new("jrr.tolkien@example.com").unwrap();
+        let author = repo
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("JRR Tolkien").unwrap(),
+                email.clone(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(&email, author.email());
+        assert!(stored_email(2).await.starts_with("aes256gcm:k1:"));
+        let found = repo
+            .find_author(&FindAuthorRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert_eq!(&email, found.email());
+        let duplicate = repo
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("John Ronald Reuel Tolkien").unwrap(),
+                email.clone(),
+            ))
+            .await;
+        assert!(matches!(
+            duplicate,
+            Err(CreateAuthorError::DuplicateEmail { .. })
+        ));
+        repo.set_email_verification(&SetEmailVerificationRequest::new(
+            author.id(),
+            email.clone(),
+            EmailVerification::Verified,
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(1, repo.reencrypt_emails().await.unwrap());
+        assert!(stored_email(1).await.starts_with("aes256gcm:k1:"));
+        let keys = FieldCipherKeys::parse(&format!("{NEW_KEY},{OLD_KEY}"), INDEX_KEY).unwrap();
+        let rotated = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .with_cipher(field_cipher(Some(&keys)));
+        assert_eq!(2, rotated.reencrypt_emails().await.unwrap());
+        assert_eq!(0, rotated.reencrypt_emails().await.unwrap());
+        assert!(stored_email(2).await.starts_with("aes256gcm:k2:"));
+        let authors = rotated.find_all_authors().await.unwrap();
+        let emails: Vec<_> = authors
+            .iter()
+            .map(|author| author.email().as_str())
+            .collect();
+        assert_eq!(
+            vec!["cs.lewis@example.com", "jrr.tolkien@example.com"],
+            emails
+        );
+        assert_eq!(EmailVerification::Verified, authors[1].email_verification());
+    }
+
+    #[tokio::test]
+    async fn audit_snapshot_emails_are_encrypted_at_rest_and_rotated() {
+        const KEY: &str = "k1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+        const INDEX_KEY: &str = "QEFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaW1xdXl8=";
+        let pool = test_pool().await;
+        let author = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let snapshot = json!({ "name": "JRR Tolkien", "email": "jrr.tolkien@example.com" });
+        let req = RecordAuditRequest::new(
+            author.id(),
+            AuditAction::Create,
+            AuditContext::new("admin".into(), None),
+            None,
+            Some(snapshot.clone()),
+        );
+        let stored_snapshots = || async {
+            sqlx::query_scalar::<_, String>("SELECT after FROM audit_log ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap()
+        };
+        DefaultAuditRecorder::new(pool.clone())
+            .record(&req)
+            .await
+            .unwrap();
+
+        let keys = FieldCipherKeys::parse(KEY, INDEX_KEY).unwrap();
+        let audit = DefaultAuditRecorder::new(pool.clone()).with_cipher(field_cipher(Some(&keys)));
+        audit.record(&req).await.unwrap();
+        let stored = stored_snapshots().await;
+        assert!(stored[0].contains("jrr.tolkien@example.com"));
+        assert!(!stored[1].contains("jrr.tolkien@example.com"));
+        let entries = audit
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert!(entries.iter().all(|entry| entry.after() == Some(&snapshot)));
+
+        let repo = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer)
+            .with_cipher(field_cipher(Some(&keys)));
+        assert_eq!(2, repo.reencrypt_emails().await.unwrap());
+        assert_eq!(0, repo.reencrypt_emails().await.unwrap());
+        let stored = stored_snapshots().await;
+        assert!(
+            stored
+                .iter()
+                .all(|after| !after.contains("jrr.tolkien@example.com"))
+        );
+        let entries = audit
+            .find_audit_log(&FindAuditLogRequest::new(author.id()))
+            .await
+            .unwrap();
+        assert!(entries.iter().all(|entry| entry.after() == Some(&snapshot)));
+    }
+
+    #[tokio::test]
+    async fn integer_and_uuid_ids_coexist() {
+        let pool = test_pool().await;
+        let integer = DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer);
+        let uuid = DefaultAuthorRepository::new(pool, AuthorIdStrategy::UuidV7);
+
+        let first = integer
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("JRR Tolkien").unwrap(),
+                EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let second = uuid
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("Ursula K. Le Guin").unwrap(),
+                EmailAddress::new("ursula@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let third = integer
+            .create_author(&CreateAuthorRequest::new(
+                AuthorName::new("Terry Pratchett").unwrap(),
+                EmailAddress::new("terry@example.com").unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(AuthorId::new(1), first.id());
+        assert!(matches!(second.id(), AuthorId::Uuid(_)));
+        assert_eq!(AuthorId::new(2), third.id());
+
+        let found = integer
+            .find_author(&FindAuthorRequest::new(second.id()))
+            .await
+            .unwrap();
+        assert_eq!(second.id(), found.id());
+    }
+
+    #[tokio::test]
+    async fn sessions_are_found_until_they_expire_or_are_deleted() {
+        let pool = test_pool().await;
+        let store = DefaultSessionStore::new(pool.clone());
+        let now = Utc::now();
+        let id = SessionId::new("s-1".into());
+        let data = serde_json::json!({"subject": "f3a9"});
+        let session = StoredSession::new(id.clone(), data, now + TimeDelta::hours(1));
+        store.save_session(&session).await.unwrap();
+
+        let found = store.find_session(&id, now).await.unwrap();
+        assert_eq!(Some(&session), found.as_ref());
+        let later = now + TimeDelta::hours(2);
+        assert_eq!(None, store.find_session(&id, later).await.unwrap());
+        let stored_id: String = sqlx::query_scalar("SELECT id_hash FROM admin_session")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_ne!("s-1", stored_id);
+
+        store.delete_session(&id).await.unwrap();
+        assert_eq!(None, store.find_session(&id, now).await.unwrap());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::model::UnavailableError;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+/// How long callers are told to wait after a write is shed.
+const RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Hands out write turns one at a time from a single task. SQLite allows only one writer, so
+/// concurrent writers otherwise queue on each other's locks inside the pool, holding connections
+/// that readers need and failing with `SQLITE_BUSY` once the busy timeout runs out.
+#[derive(Debug, Clone)]
+pub struct WriteQueue {
+    waiters: mpsc::Sender<oneshot::Sender<WriteTurn>>,
+}
+
+impl WriteQueue {
+    /// Spawns the writer task. Once `capacity` writes are waiting, new ones are shed with
+    /// [`UnavailableError`] instead of waiting.
+    #[must_use]
+    pub fn spawn(capacity: usize) -> Self {
+        let (waiters, mut queued) = mpsc::channel::<oneshot::Sender<WriteTurn>>(capacity);
+        tokio::spawn(async move {
+            while let Some(waiter) = queued.recv().await {
+                record_depth(queued.len());
+                let (done, finished) = oneshot::channel();
+                if waiter.send(WriteTurn { _done: done }).is_ok() {
+                    let _ = finished.await;
+                }
+            }
+        });
+        Self { waiters }
+    }
+
+    /// Waits for this caller's turn to write; the turn ends when the returned guard is dropped.
+    pub async fn acquire(&self) -> Result<WriteTurn, UnavailableError> {
+        let (waiter, turn) = oneshot::channel();
+        if self.waiters.try_send(waiter).is_err() {
+            metrics::counter!("sqlite_write_queue_shed_total").increment(1);
+            return Err(UnavailableError::new(RETRY_AFTER));
+        }
+        record_depth(self.waiters.max_capacity() - self.waiters.capacity());
+        turn.await.map_err(|_| UnavailableError::new(RETRY_AFTER))
+    }
+}
+
+fn record_depth(depth: usize) {
+    let depth = u32::try_from(depth).unwrap_or(u32::MAX);
+    metrics::gauge!("sqlite_write_queue_depth").set(f64::from(depth));
+}
+
+/// Exclusive permission to write until dropped.
+#[derive(Debug)]
+pub struct WriteTurn {
+    _done: oneshot::Sender<()>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::outbound::sqlite::WriteQueue;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn writes_take_turns_and_overflow_is_shed() {
+        let queue = WriteQueue::spawn(1);
+        let first = queue.acquire().await.unwrap();
+        let second = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.acquire().await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let shed = queue.acquire().await.unwrap_err();
+        assert_eq!(Duration::from_secs(1), shed.retry_after());
+        assert!(!second.is_finished());
+
+        drop(first);
+        let second = second.await.unwrap().unwrap();
+        drop(second);
+        queue.acquire().await.unwrap();
+    }
+}
@@ -0,0 +1,400 @@
+use crate::domain::model::{
+    AddAuthorAliasError, AddAuthorAliasRequest, Author, AuthorName, AuthorStats,
+    AuthorStatsRequest, ChangeAuthorStatusError, CreateAuthorError, CreateAuthorRequest, Deadline,
+    DeleteAuthorError, DeleteAuthorRequest, FindAllAuthorsError, FindAuthorByEmailError,
+    FindAuthorByEmailRequest, FindAuthorError, FindAuthorRequest, FindAuthorsByIdsRequest,
+    FindAuthorsByVerificationRequest, FindProjectedAuthorsRequest, FindSortedAuthorsRequest,
+    ProjectedAuthor, RemoveAuthorAliasError, RemoveAuthorAliasRequest, ReplaceAuthorError,
+    ReplaceAuthorRequest, ReplacedAuthor, SearchAuthorsRequest, SetAuthorStatusRequest,
+    SetEmailVerificationError, SetEmailVerificationRequest, TimedOutError, UpdateAuthorError,
+    UpdateAuthorRequest, UpsertAuthorError,
+};
+use crate::domain::ports::{
+    AuthorRepository, DynAuditRecorder, DynAuthorRepository, DynGenreRepository,
+    DynPublisherRepository, Transaction, TransactionAuthors, UnitOfWork,
+};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::future::Future;
+use std::time::Duration;
+
+/// Bounds every operation by a fixed timeout, shortened further by the current [`Deadline`].
+#[derive(Debug)]
+pub struct TimeoutAuthorRepository<R> {
+    inner: R,
+    timeout: Duration,
+}
+
+impl<R> TimeoutAuthorRepository<R> {
+    pub const fn new(inner: R, timeout: Duration) -> Self {
+        Self { inner, timeout }
+    }
+
+    fn budget(&self) -> Duration {
+        Deadline::current().map_or(self.timeout, |deadline| {
+            deadline.remaining().min(self.timeout)
+        })
+    }
+
+    async fn bounded<T, E>(
+        &self,
+        operation: &'static str,
+        fut: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E>
+    where
+        E: From<anyhow::Error>,
+    {
+        within(self.budget(), operation, fut).await
+    }
+}
+
+async fn within<T, E>(
+    budget: Duration,
+    operation: &'static str,
+    fut: impl Future<Output = Result<T, E>>,
+) -> Result<T, E>
+where
+    E: From<anyhow::Error>,
+{
+    let timed_out = || Err(anyhow::Error::from(TimedOutError::new(operation)).into());
+    if budget.is_zero() {
+        return timed_out();
+    }
+    tokio::time::timeout(budget, fut)
+        .await
+        .unwrap_or_else(|_| timed_out())
+}
+
+impl<R: AuthorRepository> AuthorRepository for TimeoutAuthorRepository<R> {
+    async fn create_author(&self, req: &CreateAuthorRequest) -> Result<Author, CreateAuthorError> {
+        self.bounded("create_author", self.inner.create_author(req))
+            .await
+    }
+
+    async fn find_author(&self, req: &FindAuthorRequest) -> Result<Author, FindAuthorError> {
+        self.bounded("find_author", self.inner.find_author(req))
+            .await
+    }
+
+    async fn find_author_by_email(
+        &self,
+        req: &FindAuthorByEmailRequest,
+    ) -> Result<Author, FindAuthorByEmailError> {
+        self.bounded("find_author_by_email", self.inner.find_author_by_email(req))
+            .await
+    }
+
+    async fn find_all_authors(&self) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.bounded("find_all_authors", self.inner.find_all_authors())
+            .await
+    }
+
+    async fn find_authors_by_ids(
+        &self,
+        req: &FindAuthorsByIdsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.bounded("find_authors_by_ids", self.inner.find_authors_by_ids(req))
+            .await
+    }
+
+    async fn find_sorted_authors(
+        &self,
+        req: &FindSortedAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.bounded("find_sorted_authors", self.inner.find_sorted_authors(req))
+            .await
+    }
+
+    async fn find_projected_authors(
+        &self,
+        req: &FindProjectedAuthorsRequest,
+    ) -> Result<Vec<ProjectedAuthor>, FindAllAuthorsError> {
+        self.bounded(
+            "find_projected_authors",
+            self.inner.find_projected_authors(req),
+        )
+        .await
+    }
+
+    /// Streams are unbounded: exports may legitimately outlive any single-query timeout.
+    async fn stream_all_authors(&self) -> BoxStream<'static, Result<Author, FindAllAuthorsError>> {
+        self.inner.stream_all_authors().await
+    }
+
+    async fn count_authors(&self) -> Result<u64, FindAllAuthorsError> {
+        self.bounded("count_authors", self.inner.count_authors())
+            .await
+    }
+
+    async fn author_exists(&self, req: &FindAuthorRequest) -> Result<bool, FindAuthorError> {
+        self.bounded("author_exists", self.inner.author_exists(req))
+            .await
+    }
+
+    async fn update_author(&self, req: &UpdateAuthorRequest) -> Result<Author, UpdateAuthorError> {
+        self.bounded("update_author", self.inner.update_author(req))
+            .await
+    }
+
+    async fn upsert_author(
+        &self,
+        req: &ReplaceAuthorRequest,
+    ) -> Result<Author, ReplaceAuthorError> {
+        self.bounded("upsert_author", self.inner.upsert_author(req))
+            .await
+    }
+
+    async fn upsert_author_by_email(
+        &self,
+        req: &CreateAuthorRequest,
+    ) -> Result<ReplacedAuthor, UpsertAuthorError> {
+        self.bounded(
+            "upsert_author_by_email",
+            self.inner.upsert_author_by_email(req),
+        )
+        .await
+    }
+
+    async fn set_author_status(
+        &self,
+        req: &SetAuthorStatusRequest,
+    ) -> Result<(), ChangeAuthorStatusError> {
+        self.bounded("set_author_status", self.inner.set_author_status(req))
+            .await
+    }
+
+    async fn delete_author(&self, req: &DeleteAuthorRequest) -> Result<(), DeleteAuthorError> {
+        self.bounded("delete_author", self.inner.delete_author(req))
+            .await
+    }
+
+    async fn add_author_alias(
+        &self,
+        req: &AddAuthorAliasRequest,
+    ) -> Result<(), AddAuthorAliasError> {
+        self.bounded("add_author_alias", self.inner.add_author_alias(req))
+            .await
+    }
+
+    async fn remove_author_alias(
+        &self,
+        req: &RemoveAuthorAliasRequest,
+    ) -> Result<(), RemoveAuthorAliasError> {
+        self.bounded("remove_author_alias", self.inner.remove_author_alias(req))
+            .await
+    }
+
+    async fn find_author_aliases(
+        &self,
+        req: &FindAuthorRequest,
+    ) -> Result<Vec<AuthorName>, FindAuthorError> {
+        self.bounded("find_author_aliases", self.inner.find_author_aliases(req))
+            .await
+    }
+
+    async fn search_authors(
+        &self,
+        req: &SearchAuthorsRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.bounded("search_authors", self.inner.search_authors(req))
+            .await
+    }
+
+    async fn author_stats(
+        &self,
+        req: &AuthorStatsRequest,
+    ) -> Result<AuthorStats, FindAllAuthorsError> {
+        self.bounded("author_stats", self.inner.author_stats(req))
+            .await
+    }
+
+    async fn find_authors_by_verification(
+        &self,
+        req: &FindAuthorsByVerificationRequest,
+    ) -> Result<Vec<Author>, FindAllAuthorsError> {
+        self.bounded(
+            "find_authors_by_verification",
+            self.inner.find_authors_by_verification(req),
+        )
+        .await
+    }
+
+    async fn set_email_verification(
+        &self,
+        req: &SetEmailVerificationRequest,
+    ) -> Result<(), SetEmailVerificationError> {
+        self.bounded(
+            "set_email_verification",
+            self.inner.set_email_verification(req),
+        )
+        .await
+    }
+}
+
+impl<U: UnitOfWork> UnitOfWork for TimeoutAuthorRepository<U> {
+    async fn begin(&self) -> anyhow::Result<Box<dyn Transaction>> {
+        let tx = self.bounded("begin", self.inner.begin()).await?;
+        Ok(Box::new(TimeoutAuthorRepository::new(
+            TransactionAuthors::new(tx),
+            self.timeout,
+        )))
+    }
+
+    fn retry_delay(&self, attempt: u32, err: &anyhow::Error) -> Option<Duration> {
+        self.inner.retry_delay(attempt, err)
+    }
+}
+
+/// Bounds the transaction's authors and its commit, like the repository outside transactions.
+/// Genres, the audit log and publishers are passed through, as is the rollback.
+impl Transaction for TimeoutAuthorRepository<TransactionAuthors> {
+    fn authors(&self) -> &dyn DynAuthorRepository {
+        self
+    }
+
+    fn genres(&self) -> &dyn DynGenreRepository {
+        self.inner.transaction().genres()
+    }
+
+    fn audit(&self) -> &dyn DynAuditRecorder {
+        self.inner.transaction().audit()
+    }
+
+    fn publishers(&self) -> &dyn DynPublisherRepository {
+        self.inner.transaction().publishers()
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        let budget = self.budget();
+        let commit = self.inner.into_inner().commit();
+        Box::pin(within(budget, "commit", commit))
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, anyhow::Result<()>> {
+        self.inner.into_inner().rollback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::Deadline;
+    use crate::domain::model::{
+        AuthorId, AuthorIdStrategy, AuthorName, CreateAuthorError, CreateAuthorRequest,
+        EmailAddress, FindAuthorError, FindAuthorRequest, TimedOutError,
+    };
+    use crate::domain::ports::{AuthorRepository, UnitOfWork};
+    use crate::outbound::mock::MockAuthorRepository;
+    use crate::outbound::sqlite::{
+        ConnectRetryConfig, DefaultAuthorRepository, DefaultUnitOfWork, PoolConfig, establish_pool,
+    };
+    use crate::outbound::timeout::TimeoutAuthorRepository;
+    use std::time::{Duration, Instant};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn expired_deadlines_fail_without_calling_the_repository() {
+        let repo = MockAuthorRepository::new();
+        repo.expect_find().times(0);
+        let timeout = TimeoutAuthorRepository::new(repo.clone(), Duration::from_secs(5));
+
+        let req = FindAuthorRequest::new(AuthorId::new(1));
+        let actual = Deadline::after(Duration::ZERO)
+            .scope(timeout.find_author(&req))
+            .await;
+        assert!(
+            matches!(&actual, Err(FindAuthorError::Other(err)) if err.is::<TimedOutError>()),
+            "expected the operation to time out, but got {actual:?}"
+        );
+        repo.verify();
+    }
+
+    #[tokio::test]
+    async fn operations_blocked_past_the_timeout_are_abandoned() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool = establish_pool(&url, &retry, &PoolConfig::default())
+            .await
+            .unwrap();
+        let mut lock = pool.acquire().await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *lock)
+            .await
+            .unwrap();
+        let repo = TimeoutAuthorRepository::new(
+            DefaultAuthorRepository::new(pool.clone(), AuthorIdStrategy::Integer),
+            Duration::from_secs(5),
+        );
+
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let started = Instant::now();
+        let actual = Deadline::after(Duration::from_millis(50))
+            .scope(repo.create_author(&req))
+            .await;
+        assert!(
+            matches!(&actual, Err(CreateAuthorError::Other(err)) if err.is::<TimedOutError>()),
+            "expected the operation to time out, but got {actual:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        sqlx::query("ROLLBACK").execute(&mut *lock).await.unwrap();
+        drop(lock);
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[tokio::test]
+    async fn writes_in_transactions_blocked_past_the_timeout_are_abandoned() {
+        let path = std::env::temp_dir().join(format!("hexarch-test-{}.sqlite", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool = establish_pool(&url, &retry, &PoolConfig::default())
+            .await
+            .unwrap();
+        let mut lock = pool.acquire().await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *lock)
+            .await
+            .unwrap();
+        let uow = TimeoutAuthorRepository::new(
+            DefaultUnitOfWork::new(pool.clone(), AuthorIdStrategy::Integer),
+            Duration::from_secs(5),
+        );
+
+        let req = CreateAuthorRequest::new(
+            AuthorName::new("JRR Tolkien").unwrap(),
+            EmailAddress::new("jrr.tolkien@example.com").unwrap(),
+        );
+        let started = Instant::now();
+        let tx = uow.begin().await.unwrap();
+        let actual = Deadline::after(Duration::from_millis(50))
+            .scope(tx.authors().create_author(&req))
+            .await;
+        assert!(
+            matches!(&actual, Err(CreateAuthorError::Other(err)) if err.is::<TimedOutError>()),
+            "expected the operation to time out, but got {actual:?}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        sqlx::query("ROLLBACK").execute(&mut *lock).await.unwrap();
+        drop(lock);
+        tx.rollback().await.unwrap();
+        pool.close().await;
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use crate::domain::model::{
+    AddAuthorAliasRequest, Author, AuthorId, AuthorName, EmailVerification, FindAuthorRequest,
+    FindAuthorsByIdsRequest, RemoveAuthorAliasRequest, ReplaceAuthorRequest,
+    SetAuthorStatusRequest, SetEmailVerificationRequest,
+};
+use crate::domain::ports::AuthorRepository;
+use anyhow::Context;
+use futures::TryStreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferOptions {
+    batch_size: usize,
+    dry_run: bool,
+}
+
+impl TransferOptions {
+    pub const DEFAULT_BATCH_SIZE: usize = 500;
+
+    #[must_use]
+    pub const fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            dry_run: false,
+        }
+    }
+
+    /// Compares both stores and reports what would be copied, without writing anything.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    #[must_use]
+    pub const fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+impl Default for TransferOptions {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_BATCH_SIZE)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferReport {
+    copied: usize,
+    unchanged: usize,
+    source_checksum: String,
+    target_checksum: String,
+}
+
+impl TransferReport {
+    /// Authors written to the target, or that would be in a dry run.
+    #[must_use]
+    pub const fn copied(&self) -> usize {
+        self.copied
+    }
+
+    /// Authors the target already held, such as those copied by an earlier, interrupted run.
+    #[must_use]
+    pub const fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+
+    #[must_use]
+    pub fn source_checksum(&self) -> &str {
+        &self.source_checksum
+    }
+
+    #[must_use]
+    pub fn target_checksum(&self) -> &str {
+        &self.target_checksum
+    }
+
+    #[must_use]
+    pub fn is_verified(&self) -> bool {
+        self.source_checksum == self.target_checksum
+    }
+}
+
+/// An author and its aliases as stored, leaving out timestamps since the target stamps its own
+/// writes.
+#[derive(Debug)]
+struct Snapshot {
+    author: Author,
+    aliases: Vec<AuthorName>,
+}
+
+impl Snapshot {
+    async fn load(repo: &impl AuthorRepository, author: Author) -> anyhow::Result<Self> {
+        let aliases = repo
+            .find_author_aliases(&FindAuthorRequest::new(author.id()))
+            .await
+            .with_context(|| format!("Failed to read aliases of author {}", author.id()))?;
+        Ok(Self { author, aliases })
+    }
+
+    fn fingerprint(&self) -> anyhow::Result<Vec<u8>> {
+        let mut record = serde_json::to_value(&self.author)?;
+        if let Some(fields) = record.as_object_mut() {
+            fields.remove("created_at");
+            fields.remove("updated_at");
+            fields.insert("aliases".into(), serde_json::to_value(&self.aliases)?);
+        }
+        Ok(serde_json::to_vec(&record)?)
+    }
+}
+
+/// Copies every author, with its status, email verification and aliases, from `source` to
+/// `target` in batches, keeping ids. Authors the target already holds unchanged are skipped, so
+/// an interrupted transfer can be resumed by running it again. Both stores are checksummed
+/// afterwards; authors only the target holds make the checksums differ.
+pub async fn transfer_authors(
+    source: &impl AuthorRepository,
+    target: &impl AuthorRepository,
+    options: &TransferOptions,
+) -> anyhow::Result<TransferReport> {
+    anyhow::ensure!(options.batch_size > 0, "The batch size must be positive");
+    let mut copied = 0;
+    let mut unchanged = 0;
+    let mut batches = source
+        .stream_all_authors()
+        .await
+        .try_chunks(options.batch_size);
+    while let Some(batch) = batches
+        .try_next()
+        .await
+        .map_err(|err| err.1)
+        .context("Failed to read authors from the source")?
+    {
+        let existing: HashMap<_, _> = target
+            .find_authors_by_ids(&FindAuthorsByIdsRequest::new(batch.iter().map(Author::id)))
+            .await
+            .context("Failed to read authors from the target")?
+            .into_iter()
+            .map(|author| (author.id(), author))
+            .collect();
+        for author in batch {
+            let wanted = Snapshot::load(source, author).await?;
+            let current = match existing.get(&wanted.author.id()) {
+                Some(author) => Some(Snapshot::load(target, author.clone()).await?),
+                None => None,
+            };
+            let current_fingerprint = current.as_ref().map(Snapshot::fingerprint).transpose()?;
+            if current_fingerprint == Some(wanted.fingerprint()?) {
+                unchanged += 1;
+                continue;
+            }
+            if !options.dry_run {
+                copy_author(target, &wanted, current.as_ref())
+                    .await
+                    .with_context(|| format!("Failed to copy author {}", wanted.author.id()))?;
+            }
+            copied += 1;
+        }
+        tracing::info!(copied, unchanged, "Transferred a batch of authors");
+    }
+    Ok(TransferReport {
+        copied,
+        unchanged,
+        source_checksum: checksum(source).await?,
+        target_checksum: checksum(target).await?,
+    })
+}
+
+async fn copy_author(
+    target: &impl AuthorRepository,
+    wanted: &Snapshot,
+    current: Option<&Snapshot>,
+) -> anyhow::Result<()> {
+    let author = &wanted.author;
+    let req = ReplaceAuthorRequest::new(author.id(), author.name().clone(), author.email().clone())
+        .with_profile(author.profile().clone());
+    let copied = target.upsert_author(&req).await?;
+    if copied.status() != author.status() {
+        let req = SetAuthorStatusRequest::new(author.id(), author.status());
+        target.set_author_status(&req).await?;
+    }
+    if copied.email_verification() != author.email_verification()
+        && author.email_verification() != EmailVerification::Pending
+    {
+        let req = SetEmailVerificationRequest::new(
+            author.id(),
+            author.email().clone(),
+            author.email_verification(),
+        );
+        target.set_email_verification(&req).await?;
+    }
+    let current_aliases = current.map_or(&[][..], |current| &current.aliases);
+    for alias in current_aliases {
+        if !wanted.aliases.contains(alias) {
+            let req = RemoveAuthorAliasRequest::new(author.id(), alias.clone());
+            target.remove_author_alias(&req).await?;
+        }
+    }
+    for alias in &wanted.aliases {
+        if !current_aliases.contains(alias) {
+            let req = AddAuthorAliasRequest::new(author.id(), alias.clone());
+            target.add_author_alias(&req).await?;
+        }
+    }
+    Ok(())
+}
+
+/// A SHA-256 digest over every author and its aliases in id order, ignoring timestamps.
+pub async fn checksum(repo: &impl AuthorRepository) -> anyhow::Result<String> {
+    let mut fingerprints = BTreeMap::<AuthorId, Vec<u8>>::new();
+    let mut authors = repo.stream_all_authors().await;
+    while let Some(author) = authors
+        .try_next()
+        .await
+        .context("Failed to read authors for the checksum")?
+    {
+        let id = author.id();
+        fingerprints.insert(id, Snapshot::load(repo, author).await?.fingerprint()?);
+    }
+    let digest = fingerprints
+        .values()
+        .fold(Sha256::new(), |digest, fingerprint| {
+            digest.chain_update(fingerprint)
+        });
+    Ok(format!("{:x}", digest.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::{
+        AddAuthorAliasRequest, AuthorName, AuthorStatus, CreateAuthorRequest, EmailAddress,
+        FindAuthorRequest, SetAuthorStatusRequest,
+    };
+    use crate::domain::ports::AuthorRepository;
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::outbound::transfer::{TransferOptions, checksum, transfer_authors};
+
+    async fn source() -> InMemoryRepository {
+        let repo = InMemoryRepository::new();
+        for (name, email) in [
+            ("JRR Tolkien", "jrr.tolkien@example.com"),
+            ("CS Lewis", "cs.lewis@example.com"),
+            ("Ursula Le Guin", "ursula@example.com"),
+        ] {
+            let req = CreateAuthorRequest::new(
+                AuthorName::new(name).unwrap(),
+                EmailAddress::new(email).unwrap(),
+            );
+            repo.create_author(&req).await.unwrap();
+        }
+        let authors = repo.find_all_authors().await.unwrap();
+        let alias =
+            AddAuthorAliasRequest::new(authors[0].id(), AuthorName::new("Tollers").unwrap());
+        repo.add_author_alias(&alias).await.unwrap();
+        let status = SetAuthorStatusRequest::new(authors[1].id(), AuthorStatus::Archived);
+        repo.set_author_status(&status).await.unwrap();
+        repo
+    }
+
+    #[tokio::test]
+    async fn authors_are_copied_in_batches_and_verified_by_checksum() {
+        let (source, target) = (source().await, InMemoryRepository::new());
+
+        let dry_run = TransferOptions::new(2).with_dry_run(true);
+        let report = transfer_authors(&source, &target, &dry_run).await.unwrap();
+        assert_eq!((3, 0), (report.copied(), report.unchanged()));
+        assert!(!report.is_verified());
+        assert_eq!(0, target.count_authors().await.unwrap());
+
+        let report = transfer_authors(&source, &target, &TransferOptions::new(2))
+            .await
+            .unwrap();
+        assert_eq!((3, 0), (report.copied(), report.unchanged()));
+        assert!(report.is_verified());
+        let authors = source.find_all_authors().await.unwrap();
+        let aliases = target
+            .find_author_aliases(&FindAuthorRequest::new(authors[0].id()))
+            .await
+            .unwrap();
+        assert_eq!(vec![AuthorName::new("Tollers").unwrap()], aliases);
+        let archived = target
+            .find_author(&FindAuthorRequest::new(authors[1].id()))
+            .await
+            .unwrap();
+        assert_eq!(AuthorStatus::Archived, archived.status());
+    }
+
+    #[tokio::test]
+    async fn rerunning_a_transfer_only_copies_what_changed() {
+        let (source, target) = (source().await, InMemoryRepository::new());
+        transfer_authors(&source, &target, &TransferOptions::default())
+            .await
+            .unwrap();
+
+        let authors = source.find_all_authors().await.unwrap();
+        let alias = AddAuthorAliasRequest::new(authors[2].id(), AuthorName::new("UKLG").unwrap());
+        source.add_author_alias(&alias).await.unwrap();
+        assert_ne!(
+            checksum(&source).await.unwrap(),
+            checksum(&target).await.unwrap()
+        );
+
+        let report = transfer_authors(&source, &target, &TransferOptions::default())
+            .await
+            .unwrap();
+        assert_eq!((1, 2), (report.copied(), report.unchanged()));
+        assert!(report.is_verified());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::domain::ports::MigrationStore;
+use crate::inbound::http::TlsConfig;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use std::fmt::Write;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::Path;
+use thiserror::Error;
+use tokio::net::TcpSocket;
+use uuid::Uuid;
+
+/// Collects the outcome of every startup check, so a misconfigured deployment reports all of its
+/// problems at once instead of one per restart.
+#[derive(Debug, Default)]
+pub struct Preflight {
+    failures: Vec<PreflightFailure>,
+}
+
+impl Preflight {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check(&mut self, name: &'static str, result: anyhow::Result<()>) {
+        match result {
+            Ok(()) => tracing::debug!(check = name, "Preflight check passed"),
+            Err(error) => self.failures.push(PreflightFailure { name, error }),
+        }
+    }
+
+    pub fn finish(self) -> Result<(), PreflightError> {
+        if self.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(PreflightError(self.failures))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PreflightFailure {
+    name: &'static str,
+    error: anyhow::Error,
+}
+
+impl PreflightFailure {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub const fn error(&self) -> &anyhow::Error {
+        &self.error
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", report(.0))]
+pub struct PreflightError(pub Vec<PreflightFailure>);
+
+fn report(failures: &[PreflightFailure]) -> String {
+    let mut report = format!("{} preflight check(s) failed:", failures.len());
+    for failure in failures {
+        let _ = write!(report, "\n  - {}: {:#}", failure.name, failure.error);
+    }
+    report
+}
+
+pub fn check_port_available(port: u16) -> anyhow::Result<()> {
+    let socket = TcpSocket::new_v4()?;
+    socket.set_reuseaddr(true)?;
+    socket
+        .bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)))
+        .with_context(|| format!("Port {port} is not available"))
+}
+
+pub async fn check_migrations(migrations: &impl MigrationStore) -> anyhow::Result<()> {
+    let pending: Vec<_> = migrations
+        .find_migrations()
+        .await?
+        .into_iter()
+        .filter(|migration| !migration.applied())
+        .map(|migration| migration.version().to_string())
+        .collect();
+    anyhow::ensure!(
+        pending.is_empty(),
+        "Migrations {} are not applied, run `authorctl migrate` or set DATABASE_AUTO_MIGRATE=true",
+        pending.join(", ")
+    );
+    Ok(())
+}
+
+pub async fn check_writable_dir(path: &Path) -> anyhow::Result<()> {
+    let probe = path.join(format!(".preflight-{}", Uuid::now_v7()));
+    tokio::fs::create_dir_all(path)
+        .await
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    tokio::fs::write(&probe, b"")
+        .await
+        .with_context(|| format!("{} is not writable", path.display()))?;
+    tokio::fs::remove_file(&probe)
+        .await
+        .with_context(|| format!("Failed to remove {}", probe.display()))
+}
+
+/// Checks that the leaf certificate is valid at `now`.
+pub fn check_tls_certificate(config: &TlsConfig, now: DateTime<Utc>) -> anyhow::Result<()> {
+    #[cfg(feature = "tls")]
+    {
+        let (not_before, not_after) = crate::inbound::http::certificate_validity(config)?;
+        check_validity(not_before, not_after, now)
+    }
+    #[cfg(not(feature = "tls"))]
+    {
+        let _ = (config, now);
+        anyhow::bail!("Serving TLS requires the tls feature")
+    }
+}
+
+#[cfg_attr(not(feature = "tls"), allow(dead_code))]
+fn check_validity(
+    not_before: DateTime<Utc>,
+    not_after: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        now >= not_before,
+        "TLS certificate is not valid before {not_before}"
+    );
+    anyhow::ensure!(now <= not_after, "TLS certificate expired at {not_after}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::outbound::sqlite::{ConnectRetryConfig, Migrations, PoolConfig, establish_pool};
+    use crate::preflight::{
+        Preflight, check_migrations, check_port_available, check_validity, check_writable_dir,
+    };
+    use chrono::{Days, Utc};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn failed_checks_are_reported_together() {
+        let listener = TcpListener::bind("0.0.0.0:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let file = std::env::temp_dir().join(format!("hexarch-preflight-{}", Uuid::now_v7()));
+        std::fs::write(&file, b"").unwrap();
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            Duration::ZERO,
+        );
+        let pool_config = PoolConfig::new(0, 1, Duration::from_secs(5)).with_auto_migrate(false);
+        let pool = establish_pool("sqlite::memory:", &retry, &pool_config)
+            .await
+            .unwrap();
+
+        let mut preflight = Preflight::new();
+        preflight.check("server port", check_port_available(port));
+        preflight.check("blob storage", check_writable_dir(&file).await);
+        preflight.check("database", check_migrations(&Migrations::new(pool)).await);
+        let now = Utc::now();
+        let yesterday = now.checked_sub_days(Days::new(1)).unwrap();
+        preflight.check("tls certificate", check_validity(yesterday, now, now));
+        let err = preflight.finish().unwrap_err();
+
+        let failed: Vec<_> = err.0.iter().map(|failure| failure.name()).collect();
+        assert_eq!(vec!["server port", "blob storage", "database"], failed);
+        let report = err.to_string();
+        assert!(
+            report.starts_with("3 preflight check(s) failed:"),
+            "{report}"
+        );
+        assert!(report.contains(&format!("Port {port} is not available")));
+        assert!(report.contains("20240113083736"), "{report}");
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn passing_checks_finish_cleanly() {
+        let dir = std::env::temp_dir().join(format!("hexarch-preflight-{}", Uuid::now_v7()));
+        let mut preflight = Preflight::new();
+        preflight.check("server port", check_port_available(0));
+        preflight.check("blob storage", check_writable_dir(&dir).await);
+        let now = Utc::now();
+        let tomorrow = now.checked_add_days(Days::new(1)).unwrap();
+        assert!(check_validity(now, tomorrow, tomorrow).is_ok());
+        assert!(check_validity(tomorrow, tomorrow, now).is_err());
+        preflight.finish().unwrap();
+        assert_eq!(0, std::fs::read_dir(&dir).unwrap().count());
+        std::fs::remove_dir(dir).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+//! The types most embedders need to wire the service behind their own adapters.
+
+pub use crate::domain::model::{
+    AuditContext, Author, AuthorId, AuthorName, CreateAuthorError, CreateAuthorRequest,
+    DeleteAuthorError, DeleteAuthorRequest, EmailAddress, FindAuthorError, FindAuthorRequest,
+    UpdateAuthorError, UpdateAuthorRequest,
+};
+pub use crate::domain::ports::{
+    AuditRecorder, AuthorRepository, BlobStorage, BoxedAuthorRepository, EventPublisher,
+    GenreRepository, PublisherRepository, Transaction, UnitOfWork,
+};
+pub use crate::domain::service::AuthorService;
+pub use crate::inbound::http::{
+    AppState, HttpServer, HttpServerBuilder, HttpServerConfig, build_router,
+};
+pub use crate::outbound::memory::InMemoryRepository;
+pub use crate::outbound::sqlite::{
+    DefaultAuditRecorder, DefaultAuthorRepository, DefaultGenreRepository,
+    DefaultPublisherRepository, DefaultUnitOfWork,
+};
@@ -0,0 +1,239 @@
+use anyhow::Context;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use url::Url;
+
+pub trait SecretProvider: Send + Sync + 'static {
+    /// Returns `None` when the provider holds no value for `key`.
+    fn get_secret(&self, key: &str) -> impl Future<Output = anyhow::Result<Option<String>>> + Send;
+}
+
+/// Object-safe counterpart of [`SecretProvider`], implemented for every one.
+pub trait DynSecretProvider: Send + Sync + 'static {
+    fn get_secret<'a>(&'a self, key: &'a str) -> BoxFuture<'a, anyhow::Result<Option<String>>>;
+}
+
+impl<T: SecretProvider> DynSecretProvider for T {
+    fn get_secret<'a>(&'a self, key: &'a str) -> BoxFuture<'a, anyhow::Result<Option<String>>> {
+        Box::pin(SecretProvider::get_secret(self, key))
+    }
+}
+
+impl SecretProvider for Box<dyn DynSecretProvider> {
+    async fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+        self.as_ref().get_secret(key).await
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretBackend {
+    None,
+    Vault,
+}
+
+impl FromStr for SecretBackend {
+    type Err = SecretBackendError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "vault" => Ok(Self::Vault),
+            _ => Err(SecretBackendError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid secrets backend, expected one of "none" or "vault""#)]
+pub struct SecretBackendError(String);
+
+#[derive(Clone)]
+pub struct VaultConfig {
+    address: Url,
+    token: String,
+    mount: String,
+    path: String,
+    timeout: Duration,
+}
+
+impl VaultConfig {
+    #[must_use]
+    pub fn new(address: Url, token: String, path: String) -> Self {
+        Self {
+            address,
+            token,
+            mount: "secret".into(),
+            path,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[must_use]
+    pub fn with_mount(mut self, mount: String) -> Self {
+        self.mount = mount;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn address(&self) -> &Url {
+        &self.address
+    }
+
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The KV version 2 secrets engine the secret lives in.
+    #[must_use]
+    pub fn mount(&self) -> &str {
+        &self.mount
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl std::fmt::Debug for VaultConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultConfig")
+            .field("address", &self.address)
+            .field("mount", &self.mount)
+            .field("path", &self.path)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+pub fn connect_secret_provider(config: &VaultConfig) -> anyhow::Result<Box<dyn DynSecretProvider>> {
+    #[cfg(feature = "vault")]
+    {
+        Ok(Box::new(crate::vault::VaultSecretProvider::new(
+            config.clone(),
+        )?))
+    }
+    #[cfg(not(feature = "vault"))]
+    {
+        let _ = config;
+        anyhow::bail!("Loading secrets from Vault requires the vault feature")
+    }
+}
+
+/// Resolves sensitive settings from the variable itself, a file named by `<KEY>_FILE`, or the
+/// secret provider, in that order. Setting both `<KEY>` and `<KEY>_FILE` is an error.
+pub struct Secrets {
+    vars: HashMap<String, String>,
+    provider: Option<Box<dyn DynSecretProvider>>,
+}
+
+impl Secrets {
+    pub fn new(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            vars: vars.into_iter().collect(),
+            provider: None,
+        }
+    }
+
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::vars_os()
+                .filter_map(|(key, val)| Some((key.into_string().ok()?, val.into_string().ok()?))),
+        )
+    }
+
+    #[must_use]
+    pub fn with_provider(mut self, provider: impl SecretProvider) -> Self {
+        self.provider = Some(Box::new(provider));
+        self
+    }
+
+    pub async fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        let file_key = format!("{key}_FILE");
+        match (self.vars.get(key), self.vars.get(&file_key)) {
+            (Some(_), Some(_)) => anyhow::bail!("Only one of {key} and {file_key} may be set"),
+            (Some(val), None) => Ok(Some(val.clone())),
+            (None, Some(path)) => read_secret_file(Path::new(path))
+                .map(Some)
+                .with_context(|| format!("Failed to load {key} from {file_key}")),
+            (None, None) => match self.provider.as_deref() {
+                Some(provider) => provider
+                    .get_secret(key)
+                    .await
+                    .with_context(|| format!("Failed to load secret {key}")),
+                None => Ok(None),
+            },
+        }
+    }
+
+    pub async fn require(&self, key: &str) -> anyhow::Result<String> {
+        self.get(key)
+            .await?
+            .with_context(|| format!("Failed to load secret {key}, set {key} or {key}_FILE"))
+    }
+}
+
+/// Strips the trailing newline most editors and `echo` leave behind.
+fn read_secret_file(path: &Path) -> anyhow::Result<String> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read secret file {}", path.display()))?;
+    Ok(contents.trim_end_matches(['\n', '\r']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::secrets::{SecretProvider, Secrets};
+    use uuid::Uuid;
+
+    struct StaticProvider;
+
+    impl SecretProvider for StaticProvider {
+        async fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+            Ok((key == "ADMIN_TOKEN").then(|| "from-provider".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn secrets_prefer_variables_over_files_over_the_provider() {
+        let path = std::env::temp_dir().join(format!("hexarch-secret-{}", Uuid::now_v7()));
+        std::fs::write(&path, "s3cret\n").unwrap();
+        let file = path.to_str().unwrap().to_string();
+        let secrets = Secrets::new([
+            (
+                "DATABASE_URL".to_string(),
+                "sqlite://authors.db".to_string(),
+            ),
+            ("EVENTS_PASSWORD_FILE".to_string(), file.clone()),
+            ("EVENTS_USERNAME".to_string(), "authors".to_string()),
+            ("EVENTS_USERNAME_FILE".to_string(), file),
+        ])
+        .with_provider(StaticProvider);
+
+        let database_url = secrets.require("DATABASE_URL").await.unwrap();
+        assert_eq!("sqlite://authors.db", database_url);
+        let password = secrets.get("EVENTS_PASSWORD").await.unwrap();
+        assert_eq!(Some("s3cret".to_string()), password);
+        let token = secrets.get("ADMIN_TOKEN").await.unwrap();
+        assert_eq!(Some("from-provider".to_string()), token);
+        assert!(secrets.get("EVENTS_USERNAME").await.is_err());
+        assert!(secrets.require("SERVER_TLS_KEY").await.is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
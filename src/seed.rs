@@ -0,0 +1,115 @@
+use crate::domain::model::{AuthorName, CreateAuthorError, CreateAuthorRequest, EmailAddress};
+use crate::domain::ports::AuthorRepository;
+use anyhow::Context;
+use serde::Deserialize;
+use std::path::Path;
+
+const DEFAULT_SEED: &str = include_str!("../seeds/authors.json");
+
+#[derive(Debug, Deserialize)]
+struct SeedAuthor {
+    name: String,
+    email: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeedReport {
+    created: usize,
+    skipped: usize,
+}
+
+impl SeedReport {
+    #[must_use]
+    pub const fn created(&self) -> usize {
+        self.created
+    }
+
+    #[must_use]
+    pub const fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+/// Reads sample authors from `path`, or the set embedded in the binary when no path is given.
+pub fn load_seed(path: Option<&Path>) -> anyhow::Result<Vec<CreateAuthorRequest>> {
+    match path {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read seed file {}", path.display()))?;
+            parse_seed(&json).with_context(|| format!("Invalid seed file {}", path.display()))
+        }
+        None => parse_seed(DEFAULT_SEED),
+    }
+}
+
+fn parse_seed(json: &str) -> anyhow::Result<Vec<CreateAuthorRequest>> {
+    let authors: Vec<SeedAuthor> = serde_json::from_str(json)?;
+    authors
+        .iter()
+        .enumerate()
+        .map(|(index, author)| {
+            let name = AuthorName::new(&author.name)
+                .with_context(|| format!("Invalid name for seed author {index}"))?;
+            let email = EmailAddress::new(&author.email)
+                .with_context(|| format!("Invalid email for seed author {index}"))?;
+            Ok(CreateAuthorRequest::new(name, email))
+        })
+        .collect()
+}
+
+/// Creates each author, skipping those whose name or email is already taken, so seeding can be
+/// repeated safely.
+pub async fn seed_authors(
+    repo: &impl AuthorRepository,
+    authors: &[CreateAuthorRequest],
+) -> anyhow::Result<SeedReport> {
+    let mut report = SeedReport::default();
+    for author in authors {
+        match repo.create_author(author).await {
+            Ok(_) => report.created += 1,
+            Err(CreateAuthorError::Duplicate { .. } | CreateAuthorError::DuplicateEmail { .. }) => {
+                report.skipped += 1;
+            }
+            Err(err) => return Err(err).context("Failed to seed authors"),
+        }
+    }
+    Ok(report)
+}
+
+/// Seeds only an empty author table, returning `None` when authors already exist.
+pub async fn seed_if_empty(
+    repo: &impl AuthorRepository,
+    authors: &[CreateAuthorRequest],
+) -> anyhow::Result<Option<SeedReport>> {
+    if repo.count_authors().await? > 0 {
+        return Ok(None);
+    }
+    seed_authors(repo, authors).await.map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::outbound::memory::InMemoryRepository;
+    use crate::seed::{load_seed, parse_seed, seed_authors, seed_if_empty};
+
+    #[tokio::test]
+    async fn embedded_seed_loads_once_into_an_empty_table() {
+        let repo = InMemoryRepository::new();
+        let authors = load_seed(None).unwrap();
+        assert_eq!(5, authors.len());
+
+        let report = seed_if_empty(&repo, &authors).await.unwrap().unwrap();
+        assert_eq!(5, report.created());
+        assert!(seed_if_empty(&repo, &authors).await.unwrap().is_none());
+
+        let report = seed_authors(&repo, &authors).await.unwrap();
+        assert_eq!((0, 5), (report.created(), report.skipped()));
+    }
+
+    #[test]
+    fn invalid_seed_authors_are_reported_by_index() {
+        let json = r#"[{"name":"JRR Tolkien","email":"jrr.tolkien@example.com"},{"name":"CS Lewis","email":"nope"}]"#;
+        let err = parse_seed(json).unwrap_err();
+        assert_eq!("Invalid email for seed author 1", err.to_string());
+    }
+}
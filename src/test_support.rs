@@ -0,0 +1,114 @@
+use crate::domain::model::AuthorIdStrategy;
+use crate::domain::service::AuthorService;
+use crate::inbound::http::{AppState, HttpServer, HttpServerConfig};
+use crate::outbound::events::{BroadcastEventPublisher, LogEventPublisher};
+use crate::outbound::memory::InMemoryRepository;
+use crate::outbound::sqlite::{
+    ConnectRetryConfig, DefaultAuditRecorder, DefaultAuthorRepository, DefaultGenreRepository,
+    DefaultPublisherRepository, DefaultUnitOfWork, PoolConfig, establish_pool,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default)]
+pub struct TestServerBuilder {
+    admin_token: Option<String>,
+    create_on_missing: bool,
+}
+
+impl TestServerBuilder {
+    #[must_use]
+    pub fn with_admin_token(mut self, token: &str) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn with_create_on_missing(mut self, create_on_missing: bool) -> Self {
+        self.create_on_missing = create_on_missing;
+        self
+    }
+
+    pub async fn spawn(self) -> anyhow::Result<TestServer> {
+        let db_path = std::env::temp_dir().join(format!("hexarch-example-{}.db", Uuid::now_v7()));
+        let url = format!("sqlite://{}?mode=rwc", db_path.display());
+        let retry = ConnectRetryConfig::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        );
+        let pool = establish_pool(&url, &retry, &PoolConfig::default()).await?;
+        let strategy = AuthorIdStrategy::Integer;
+        let events = BroadcastEventPublisher::new(LogEventPublisher);
+        let author_events = events.sender();
+        let service = AuthorService::new(
+            DefaultAuthorRepository::new(pool.clone(), strategy),
+            DefaultAuditRecorder::new(pool.clone()),
+            DefaultUnitOfWork::new(pool.clone(), strategy),
+            events,
+            InMemoryRepository::new(),
+            DefaultGenreRepository::new(pool.clone()),
+            DefaultPublisherRepository::new(pool),
+        )
+        .with_create_on_missing(self.create_on_missing);
+        let state = AppState::new(service)
+            .with_author_events(author_events)
+            .with_admin_token(self.admin_token.map(Into::into));
+
+        let config = HttpServerConfig::new(0).with_shutdown_timeout(Duration::from_secs(1));
+        let server = HttpServer::builder(state, config).build().await?;
+        let base_url = format!("http://127.0.0.1:{}", server.local_addr()?.port());
+        let (shutdown, signal) = oneshot::channel();
+        tokio::spawn(server.run_until(async {
+            let _ = signal.await;
+        }));
+
+        Ok(TestServer {
+            base_url,
+            db_path,
+            shutdown: Some(shutdown),
+        })
+    }
+}
+
+pub struct TestServer {
+    base_url: String,
+    db_path: PathBuf,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl TestServer {
+    #[must_use]
+    pub fn builder() -> TestServerBuilder {
+        TestServerBuilder::default()
+    }
+
+    pub async fn spawn() -> anyhow::Result<Self> {
+        Self::builder().spawn().await
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    #[must_use]
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        for suffix in ["", "-wal", "-shm"] {
+            let mut path = self.db_path.clone().into_os_string();
+            path.push(suffix);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::secrets::{SecretProvider, VaultConfig};
+use anyhow::Context;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tokio::sync::OnceCell;
+
+#[derive(Debug, Deserialize)]
+struct ReadSecretResponse {
+    data: SecretData,
+}
+
+#[derive(Debug, Deserialize)]
+struct SecretData {
+    data: HashMap<String, serde_json::Value>,
+}
+
+/// Reads a single KV version 2 secret from a Vault-compatible server and answers every key from
+/// its fields, so the secret is fetched once per process.
+#[derive(Debug)]
+pub struct VaultSecretProvider {
+    http: reqwest::Client,
+    config: VaultConfig,
+    fields: OnceCell<HashMap<String, serde_json::Value>>,
+}
+
+impl VaultSecretProvider {
+    pub fn new(config: VaultConfig) -> anyhow::Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("hexarch-example/", env!("CARGO_PKG_VERSION")))
+            .timeout(config.timeout())
+            .build()
+            .context("Failed to build the Vault HTTP client")?;
+        Ok(Self {
+            http,
+            config,
+            fields: OnceCell::new(),
+        })
+    }
+
+    async fn read_secret(&self) -> anyhow::Result<HashMap<String, serde_json::Value>> {
+        let url = self.config.address().join(&format!(
+            "v1/{}/data/{}",
+            self.config.mount().trim_matches('/'),
+            self.config.path().trim_matches('/')
+        ))?;
+        let response = self
+            .http
+            .get(url)
+            .header("X-Vault-Token", self.config.token())
+            .send()
+            .await
+            .context("Failed to reach Vault")?
+            .error_for_status()
+            .context("Vault refused to read the secret")?;
+        let secret: ReadSecretResponse = response
+            .json()
+            .await
+            .context("Failed to decode the Vault secret")?;
+        Ok(secret.data.data)
+    }
+}
+
+impl SecretProvider for VaultSecretProvider {
+    async fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+        let fields = self.fields.get_or_try_init(|| self.read_secret()).await?;
+        Ok(fields.get(key).map(|val| match val {
+            serde_json::Value::String(val) => val.clone(),
+            val => val.to_string(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::secrets::{SecretProvider, VaultConfig};
+    use crate::vault::VaultSecretProvider;
+    use axum::Router;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::get;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    #[tokio::test]
+    async fn secrets_are_read_once_from_the_kv_engine() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let router = Router::new().route(
+            "/v1/kv/data/hexarch/prod",
+            get({
+                let calls = Arc::clone(&calls);
+                move |headers: HeaderMap| async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    if headers["x-vault-token"] != "s.token" {
+                        return Err(StatusCode::FORBIDDEN);
+                    }
+                    Ok(axum::Json(json!({"data": {
+                        "data": {"DATABASE_URL": "sqlite://authors.db", "EVENTS_PASSWORD": 1234},
+                        "metadata": {"version": 3},
+                    }})))
+                }
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        let address = Url::parse(&format!("http://{addr}/")).unwrap();
+        let config = VaultConfig::new(address, "s.token".into(), "hexarch/prod".into())
+            .with_mount("kv".into());
+        let provider = VaultSecretProvider::new(config).unwrap();
+
+        let database_url = provider.get_secret("DATABASE_URL").await.unwrap();
+        assert_eq!(Some("sqlite://authors.db".to_string()), database_url);
+        let password = provider.get_secret("EVENTS_PASSWORD").await.unwrap();
+        assert_eq!(Some("1234".to_string()), password);
+        assert_eq!(None, provider.get_secret("ADMIN_TOKEN").await.unwrap());
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::ports::DynEmailVerifier;
+use crate::domain::service::AuthorService;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailVerifierBackend {
+    None,
+    Mx,
+}
+
+impl FromStr for EmailVerifierBackend {
+    type Err = EmailVerifierBackendError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "mx" => Ok(Self::Mx),
+            _ => Err(EmailVerifierBackendError(s.into())),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(r#""{0}" is not a valid email verifier, expected one of "none" or "mx""#)]
+pub struct EmailVerifierBackendError(String);
+
+pub fn connect_email_verifier(
+    backend: EmailVerifierBackend,
+) -> anyhow::Result<Option<Box<dyn DynEmailVerifier>>> {
+    match backend {
+        EmailVerifierBackend::None => Ok(None),
+        #[cfg(feature = "dns")]
+        EmailVerifierBackend::Mx => Ok(Some(Box::new(
+            crate::outbound::dns::MxEmailVerifier::new()?
+        ))),
+        #[cfg(not(feature = "dns"))]
+        EmailVerifierBackend::Mx => {
+            anyhow::bail!("Email verifier {backend:?} is not enabled in this build")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailVerificationConfig {
+    interval: Duration,
+    batch_size: usize,
+}
+
+impl EmailVerificationConfig {
+    #[must_use]
+    pub const fn new(interval: Duration, batch_size: usize) -> Self {
+        Self {
+            interval,
+            batch_size,
+        }
+    }
+}
+
+/// Verifies pending addresses in batches, right after authors are created or change address and
+/// again every `interval` to pick up checks that were inconclusive.
+pub struct EmailVerificationJob {
+    service: AuthorService,
+    config: EmailVerificationConfig,
+}
+
+impl EmailVerificationJob {
+    #[must_use]
+    pub const fn new(service: AuthorService, config: EmailVerificationConfig) -> Self {
+        Self { service, config }
+    }
+
+    pub async fn run(self) {
+        let mut ticker = tokio::time::interval(self.config.interval);
+        loop {
+            tokio::select! {
+                () = self.service.email_verification_requested() => {}
+                _ = ticker.tick() => {}
+            }
+            loop {
+                match self
+                    .service
+                    .verify_pending_emails(self.config.batch_size)
+                    .await
+                {
+                    Ok(count) if count == self.config.batch_size => {}
+                    Ok(count) => {
+                        if count > 0 {
+                            tracing::info!(count, "Verified author email addresses");
+                        }
+                        break;
+                    }
+                    Err(err) => {
+                        tracing::error!("Email verification run failed: {:?}", err.0);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
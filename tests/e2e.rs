@@ -0,0 +1,275 @@
+use hexarch_example::test_support::TestServer;
+use hexarch_example_client::{
+    AuthorId, AuthorPatch, AuthorStatus, Client, ClientError, ErrorCode, NewAuthor,
+};
+use reqwest::header::{self, HeaderMap};
+use reqwest::{Method, Response, StatusCode};
+use serde_json::Value;
+
+fn client(server: &TestServer) -> Client {
+    Client::builder(server.base_url()).build().unwrap()
+}
+
+async fn send(server: &TestServer, method: Method, path: &str) -> Response {
+    reqwest::Client::new()
+        .request(method, server.url(path))
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn send_json(server: &TestServer, method: Method, path: &str, body: &str) -> Response {
+    reqwest::Client::new()
+        .request(method, server.url(path))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn error_body(response: Response) -> Value {
+    response.json().await.unwrap()
+}
+
+fn code(err: &ClientError) -> &ErrorCode {
+    err.code()
+        .unwrap_or_else(|| panic!("expected an API error, but got {err:?}"))
+}
+
+fn multipart_avatar(bytes: &[u8]) -> (String, Vec<u8>) {
+    let boundary = "hexarch-example-boundary";
+    let mut body = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"avatar\"; filename=\"avatar\"\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(bytes);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+    (format!("multipart/form-data; boundary={boundary}"), body)
+}
+
+#[tokio::test]
+async fn author_lifecycle_through_the_typed_client() {
+    let server = TestServer::spawn().await.unwrap();
+    let client = client(&server);
+
+    let created = client
+        .create_author(&NewAuthor::new("JRR Tolkien", "jrr.tolkien@example.com"))
+        .await
+        .unwrap();
+    let id = created.id;
+    let author = client.find_author(id).await.unwrap();
+    assert_eq!("JRR Tolkien", author.name);
+    assert_eq!(AuthorStatus::Active, author.status);
+    assert_eq!(1, client.find_all_authors().await.unwrap().len());
+
+    let updated = client
+        .update_author(id, &AuthorPatch::default().name("J.R.R. Tolkien"))
+        .await
+        .unwrap();
+    assert_eq!("J.R.R. Tolkien", updated.name);
+    let replaced = client
+        .replace_author(id, &NewAuthor::new("John Tolkien", "john@example.com"))
+        .await
+        .unwrap();
+    assert_eq!("john@example.com", replaced.email);
+
+    let archived = client.archive_author(id).await.unwrap();
+    assert_eq!(AuthorStatus::Archived, archived.status);
+    let err = client
+        .update_author(id, &AuthorPatch::default().name("Tolkien"))
+        .await
+        .unwrap_err();
+    assert_eq!(&ErrorCode::AuthorArchived, code(&err));
+    let err = client.archive_author(id).await.unwrap_err();
+    assert_eq!(&ErrorCode::InvalidStatusTransition, code(&err));
+    client.unarchive_author(id).await.unwrap();
+
+    let actions: Vec<_> = client
+        .find_audit_log(id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.action)
+        .collect();
+    assert_eq!(
+        vec!["create", "update", "update", "update", "update"],
+        actions
+    );
+
+    client.delete_author(id).await.unwrap();
+    let err = client.find_author(id).await.unwrap_err();
+    assert_eq!(&ErrorCode::AuthorNotFound, code(&err));
+    let err = client.delete_author(id).await.unwrap_err();
+    assert_eq!(&ErrorCode::AuthorNotFound, code(&err));
+}
+
+#[tokio::test]
+async fn invalid_author_requests_are_rejected() {
+    let server = TestServer::spawn().await.unwrap();
+    let client = client(&server);
+
+    let err = client
+        .create_author(&NewAuthor::new(" ", "not-an-email"))
+        .await
+        .unwrap_err();
+    let ClientError::Api(err) = err else {
+        panic!("expected an API error, but got {err:?}");
+    };
+    assert_eq!(&ErrorCode::InvalidRequest, err.code());
+    assert!(err.fields().contains_key("name"));
+    assert!(err.fields().contains_key("email"));
+
+    let author = NewAuthor::new("JRR Tolkien", "jrr.tolkien@example.com");
+    let id = client.create_author(&author).await.unwrap().id;
+    let same_name = NewAuthor::new("JRR Tolkien", "tolkien@example.com");
+    let err = client.create_author(&same_name).await.unwrap_err();
+    assert_eq!(&ErrorCode::DuplicateAuthor, code(&err));
+    let other = NewAuthor::new("CS Lewis", "jrr.tolkien@example.com");
+    let err = client.create_author(&other).await.unwrap_err();
+    assert_eq!(&ErrorCode::DuplicateEmail, code(&err));
+
+    let err = client
+        .update_author(id, &AuthorPatch::default())
+        .await
+        .unwrap_err();
+    assert_eq!(&ErrorCode::NothingToUpdate, code(&err));
+    let err = client.replace_author(AuthorId::Integer(42), &other).await;
+    assert_eq!(&ErrorCode::AuthorNotFound, code(&err.unwrap_err()));
+
+    let response = send(&server, Method::GET, "/api/v1/authors/not-an-id").await;
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    assert_eq!("invalid-id", error_body(response).await["code"]);
+
+    let response = reqwest::Client::new()
+        .patch(server.url(&format!("/api/v1/authors/{id}")))
+        .header(header::CONTENT_TYPE, "text/plain")
+        .body("name=Tolkien")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, response.status());
+    assert!(response.headers().contains_key("accept-patch"));
+    assert_eq!(
+        "unsupported-patch-format",
+        error_body(response).await["code"]
+    );
+
+    let response = reqwest::Client::new()
+        .delete(server.url(&format!("/api/v1/authors/{id}")))
+        .header(header::IF_UNMODIFIED_SINCE, "Sun, 06 Nov 1994 08:49:37 GMT")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(StatusCode::PRECONDITION_FAILED, response.status());
+    assert_eq!("precondition-failed", error_body(response).await["code"]);
+}
+
+#[tokio::test]
+async fn replace_creates_missing_authors_when_enabled() {
+    let server = TestServer::builder()
+        .with_create_on_missing(true)
+        .spawn()
+        .await
+        .unwrap();
+
+    let body = r#"{"name":"JRR Tolkien","email":"jrr.tolkien@example.com"}"#;
+    let created = send_json(&server, Method::PUT, "/api/v1/authors/7", body).await;
+    assert_eq!(StatusCode::CREATED, created.status());
+    let replaced = send_json(&server, Method::PUT, "/api/v1/authors/7", body).await;
+    assert_eq!(StatusCode::OK, replaced.status());
+    let author: Value = replaced.json().await.unwrap();
+    assert_eq!(7, author["id"]);
+}
+
+#[tokio::test]
+async fn avatars_round_trip() {
+    let server = TestServer::spawn().await.unwrap();
+    let id = client(&server)
+        .create_author(&NewAuthor::new("JRR Tolkien", "jrr.tolkien@example.com"))
+        .await
+        .unwrap()
+        .id;
+    let path = format!("/api/v1/authors/{id}/avatar");
+
+    let missing = send(&server, Method::GET, &path).await;
+    assert_eq!(StatusCode::NOT_FOUND, missing.status());
+    assert_eq!("avatar-not-found", error_body(missing).await["code"]);
+
+    let upload = |bytes: &[u8]| {
+        let (content_type, body) = multipart_avatar(bytes);
+        reqwest::Client::new()
+            .put(server.url(&path))
+            .header(header::CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+    };
+    let rejected = upload(b"plain text").await.unwrap();
+    assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, rejected.status());
+    let gif = b"GIF89a\x01\x00\x01\x00";
+    let uploaded = upload(gif).await.unwrap();
+    assert_eq!(StatusCode::NO_CONTENT, uploaded.status());
+
+    let avatar = send(&server, Method::GET, &path).await;
+    assert_eq!(StatusCode::OK, avatar.status());
+    assert_eq!("image/gif", avatar.headers()[header::CONTENT_TYPE]);
+    assert_eq!(&gif[..], &avatar.bytes().await.unwrap()[..]);
+}
+
+#[tokio::test]
+async fn unknown_routes_and_methods_are_structured_errors() {
+    let server = TestServer::spawn().await.unwrap();
+
+    let response = send(&server, Method::GET, "/api/v1/books").await;
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+    assert_eq!("route-not-found", error_body(response).await["code"]);
+
+    let response = send(&server, Method::POST, "/api/v1/authors/1").await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+    assert_eq!("method-not-allowed", error_body(response).await["code"]);
+
+    let response = send(&server, Method::OPTIONS, "/api/v1/authors/1").await;
+    assert_eq!(StatusCode::NO_CONTENT, response.status());
+    assert_eq!(
+        "GET,HEAD,PUT,PATCH,DELETE,OPTIONS",
+        response.headers()[header::ALLOW]
+    );
+}
+
+#[tokio::test]
+async fn streaming_routes_negotiate_their_protocols() {
+    let server = TestServer::spawn().await.unwrap();
+
+    let events = send(&server, Method::GET, "/api/v1/authors/events").await;
+    assert_eq!(StatusCode::OK, events.status());
+    assert_eq!("text/event-stream", events.headers()[header::CONTENT_TYPE]);
+
+    let ws = send(&server, Method::GET, "/api/v1/authors/ws").await;
+    assert!(ws.status().is_client_error(), "{}", ws.status());
+}
+
+#[tokio::test]
+async fn admin_routes_require_configuration_and_credentials() {
+    let server = TestServer::spawn().await.unwrap();
+    let response = send(&server, Method::GET, "/api/v1/admin/loglevel").await;
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+
+    let server = TestServer::builder()
+        .with_admin_token("secret")
+        .spawn()
+        .await
+        .unwrap();
+    let response = send(&server, Method::GET, "/api/v1/admin/loglevel").await;
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+    assert_eq!("unauthorized", error_body(response).await["code"]);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+    let response = reqwest::Client::new()
+        .get(server.url("/api/v1/admin/loglevel"))
+        .headers(headers)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(StatusCode::NOT_FOUND, response.status());
+}